@@ -1,5 +1,46 @@
 use espr::{ast::SyntaxTree, codegen::rust::*, ir::IR};
 use proc_macro::TokenStream;
+use syn::{
+    parse::{Parse, ParseStream},
+    parse_macro_input, Ident, LitStr, Token,
+};
+
+/// Arguments of [inline_express!], EXPRESS string and the names of optional generators
+struct Input {
+    express: LitStr,
+    generators: Generators,
+}
+
+impl Parse for Input {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let express = input.parse()?;
+        let mut generators = Generators::default();
+        while !input.is_empty() {
+            input.parse::<Token![,]>()?;
+            if input.is_empty() {
+                break;
+            }
+            let name: Ident = input.parse()?;
+            let enabled = match name.to_string().as_str() {
+                "builder" => &mut generators.builder,
+                "visit" => &mut generators.visit,
+                "reflection" => &mut generators.reflection,
+                "downcast" => &mut generators.downcast,
+                _ => {
+                    return Err(syn::Error::new(
+                        name.span(),
+                        "Unknown generator, expected one of `builder`, `visit`, `reflection` or `downcast`",
+                    ))
+                }
+            };
+            *enabled = true;
+        }
+        Ok(Input {
+            express,
+            generators,
+        })
+    }
+}
 
 /// Compile inline EXPRESS into Rust code, and expand it on the call site.
 ///
@@ -15,15 +56,43 @@ use proc_macro::TokenStream;
 /// END_SCHEMA;
 /// "#);
 /// ```
+///
+/// Optional items are generated when their names follow the EXPRESS string:
+///
+/// - `builder` for `Xxx::builder()` of each entity
+/// - `visit` for `visit::Visit` and `visit_mut::VisitMut` traits
+/// - `reflection` for `SCHEMA_INFO` and `entity_info`
+/// - `downcast` for `downcast_ref`, `is` and `TryFrom` of `XxxAny` enums, and `Tables::xxx_any_iter`
+///
+/// ```
+/// espr_derive::inline_express!(
+///     r#"
+///     SCHEMA explicit_draughting;
+///       ENTITY a;
+///         x: REAL;
+///       END_ENTITY;
+///     END_SCHEMA;
+///     "#,
+///     builder,
+///     reflection
+/// );
+///
+/// let a = explicit_draughting::A::builder().x(1.0).build().unwrap();
+/// assert_eq!(a.x, 1.0);
+/// assert_eq!(explicit_draughting::SCHEMA_INFO.name, "explicit_draughting");
+/// ```
 #[proc_macro]
 pub fn inline_express(input: TokenStream) -> TokenStream {
+    let Input {
+        express,
+        generators,
+    } = parse_macro_input!(input as Input);
     // FIXME Use proc-macro-error
     //
     // espr::Result does not match its requirement currently. We have to fix it.
     //
-    let input: syn::LitStr =
-        syn::parse(input).expect("inline_express! argument must be string literal");
-    let st = SyntaxTree::parse(&input.value()).expect("Tokenize failed");
+    let st = SyntaxTree::parse(&express.value()).expect("Tokenize failed");
     let ir = IR::from_syntax_tree(&st).expect("Failed in semantic analysis phase");
-    ir.to_token_stream(CratePrefix::External).into()
+    ir.to_token_stream_with(CratePrefix::External, generators)
+        .into()
 }
@@ -0,0 +1,557 @@
+use espr::{
+    ast::{self, AttributeDecl, SyntaxTree},
+    ir::{Named, Namespace, Path, Scope, SemanticError, IR},
+};
+use lsp_types::{
+    Diagnostic, DiagnosticSeverity, DocumentSymbol, Hover, HoverContents, Location, MarkupContent,
+    MarkupKind, Position, SymbolKind, Url,
+};
+use nom::error::{VerboseError, VerboseErrorKind};
+use std::{
+    collections::{BTreeMap, HashSet, VecDeque},
+    ops::Range,
+};
+
+/// Snapshot of opened documents analyzed together
+///
+/// Documents with syntax errors are excluded from the semantic analysis.
+pub struct Analysis<'a> {
+    documents: Vec<(&'a Url, &'a str)>,
+    /// Syntax tree merging all documents parsed successfully
+    st: SyntaxTree,
+    /// Index of the document declaring each schema in `st`
+    owners: Vec<usize>,
+    syntax_errors: Vec<(usize, Diagnostic)>,
+}
+
+impl<'a> Analysis<'a> {
+    pub fn new(documents: &'a BTreeMap<Url, String>) -> Self {
+        let mut analysis = Analysis {
+            documents: Vec::new(),
+            st: SyntaxTree {
+                schemas: Vec::new(),
+                remarks: Vec::new(),
+                sources: Vec::new(),
+            },
+            owners: Vec::new(),
+            syntax_errors: Vec::new(),
+        };
+        for (index, (uri, text)) in documents.iter().enumerate() {
+            analysis.documents.push((uri, text));
+            match SyntaxTree::parse(text) {
+                Ok(mut st) => {
+                    analysis
+                        .owners
+                        .extend(st.schemas.iter().map(|_schema| index));
+                    analysis.st.schemas.append(&mut st.schemas);
+                    analysis.st.remarks.append(&mut st.remarks);
+                    analysis.st.sources.append(&mut st.sources);
+                }
+                Err(err) => analysis
+                    .syntax_errors
+                    .push((index, syntax_error(text, err))),
+            }
+        }
+        analysis
+    }
+
+    /// Diagnostics for each document
+    ///
+    /// Semantic errors without location are reported at the first schema of each document.
+    pub fn diagnostics(&self) -> Vec<(Url, Vec<Diagnostic>)> {
+        let mut diagnostics = vec![Vec::new(); self.documents.len()];
+        for (document, diagnostic) in &self.syntax_errors {
+            diagnostics[*document].push(diagnostic.clone());
+        }
+        if let Err(err) = IR::from_syntax_tree(&self.st) {
+            for err in err.errors() {
+                match err {
+                    SemanticError::Located {
+                        scope,
+                        span,
+                        source,
+                    } => {
+                        if let Some(document) =
+                            scope.schema_name().and_then(|name| self.owner(name))
+                        {
+                            let text = self.documents[document].1;
+                            diagnostics[document]
+                                .push(error(self.range(document, span.range(text)), &source));
+                        }
+                    }
+                    err => {
+                        for (schema, &document) in self.st.schemas.iter().zip(&self.owners) {
+                            if diagnostics[document]
+                                .iter()
+                                .any(|d| d.message == err.to_string())
+                            {
+                                continue;
+                            }
+                            let text = self.documents[document].1;
+                            let range = name_range(text, schema.span.range(text));
+                            diagnostics[document].push(error(self.range(document, range), &err));
+                        }
+                    }
+                }
+            }
+        }
+        self.documents
+            .iter()
+            .zip(diagnostics)
+            .map(|((uri, _text), diagnostics)| ((*uri).clone(), diagnostics))
+            .collect()
+    }
+
+    /// Location of the declaration of the entity or type at `position`
+    pub fn definition(&self, uri: &Url, position: Position) -> Option<Location> {
+        let ns = Namespace::new(&self.st).ok()?;
+        let (_token, index) = self.target(&ns, uri, position)?;
+        let (document, _decl, name) = self.declaration(&ns, index)?;
+        Some(self.location(document, name))
+    }
+
+    /// Declaration of the entity or type at `position`
+    ///
+    /// For an entity, this shows its explicit attributes
+    /// and the attributes inherited from each supertype.
+    pub fn hover(&self, uri: &Url, position: Position) -> Option<Hover> {
+        let ns = Namespace::new(&self.st).ok()?;
+        let (token, index) = self.target(&ns, uri, position)?;
+        let (document, decl, _name) = self.declaration(&ns, index)?;
+        let text = self.documents[document].1;
+        let value = match ns[index].1 {
+            Named::Entity(entity) => self.entity_summary(&ns, &ns[index].0, entity),
+            Named::Type(_) => text[decl].to_string(),
+        };
+        Some(Hover {
+            contents: HoverContents::Markup(MarkupContent {
+                kind: MarkupKind::Markdown,
+                value: format!("```express\n{}\n```", value),
+            }),
+            range: Some(self.range(self.document(uri)?, token)),
+        })
+    }
+
+    /// Schemas, types, entities and their attributes declared in the document
+    pub fn document_symbols(&self, uri: &Url) -> Option<Vec<DocumentSymbol>> {
+        let document = self.document(uri)?;
+        let symbols = self
+            .st
+            .schemas
+            .iter()
+            .zip(&self.owners)
+            .filter(|(_schema, owner)| **owner == document)
+            .map(|(schema, _owner)| {
+                let mut children: Vec<_> = schema
+                    .types
+                    .iter()
+                    .map(|ty| {
+                        let kind = match ty.underlying_type {
+                            ast::Type::Enumeration { .. } => SymbolKind::ENUM,
+                            ast::Type::Select { .. } => SymbolKind::INTERFACE,
+                            _ => SymbolKind::TYPE_PARAMETER,
+                        };
+                        self.symbol(document, &ty.type_id, kind, ty.span, 1, Vec::new())
+                    })
+                    .chain(schema.entities.iter().map(|entity| {
+                        let attributes = entity
+                            .attributes
+                            .iter()
+                            .filter_map(|attr| match &attr.name {
+                                AttributeDecl::Reference(name) => Some(self.symbol(
+                                    document,
+                                    name,
+                                    SymbolKind::FIELD,
+                                    attr.span,
+                                    0,
+                                    Vec::new(),
+                                )),
+                                AttributeDecl::Qualified { .. } => None,
+                            })
+                            .collect();
+                        self.symbol(
+                            document,
+                            &entity.name,
+                            SymbolKind::CLASS,
+                            entity.span,
+                            1,
+                            attributes,
+                        )
+                    }))
+                    .collect();
+                children.sort_by_key(|symbol| symbol.range.start);
+                self.symbol(
+                    document,
+                    &schema.name,
+                    SymbolKind::NAMESPACE,
+                    schema.span,
+                    1,
+                    children,
+                )
+            })
+            .collect();
+        Some(symbols)
+    }
+
+    /// Locations where the entity or type at `position` is referred
+    pub fn references(
+        &self,
+        uri: &Url,
+        position: Position,
+        include_declaration: bool,
+    ) -> Option<Vec<Location>> {
+        let ns = Namespace::new(&self.st).ok()?;
+        let (_token, target) = self.target(&ns, uri, position)?;
+        let declaration = self
+            .declaration(&ns, target)
+            .map(|(document, _decl, name)| (document, name));
+        let mut locations = Vec::new();
+        for (schema, &document) in self.st.schemas.iter().zip(&self.owners) {
+            let text = self.documents[document].1;
+            let scope = Scope::root().schema(&schema.name);
+            for token in identifiers(text, schema.span.range(text)) {
+                if is_attribute_name(text, &token) {
+                    continue;
+                }
+                if !include_declaration && declaration == Some((document, token.clone())) {
+                    continue;
+                }
+                if matches!(ns.resolve(&scope, &text[token.clone()]), Ok((_, index)) if index == target)
+                {
+                    locations.push(self.location(document, token));
+                }
+            }
+        }
+        Some(locations)
+    }
+
+    fn document(&self, uri: &Url) -> Option<usize> {
+        self.documents.iter().position(|(u, _text)| *u == uri)
+    }
+
+    /// Index of the document where the schema of `name` is declared
+    fn owner(&self, name: &str) -> Option<usize> {
+        let index = self
+            .st
+            .schemas
+            .iter()
+            .position(|schema| schema.name == name)?;
+        Some(self.owners[index])
+    }
+
+    /// Identifier at `position` and the index of the entity or type in [Namespace] it refers
+    fn target(
+        &self,
+        ns: &Namespace,
+        uri: &Url,
+        position: Position,
+    ) -> Option<(Range<usize>, usize)> {
+        let document = self.document(uri)?;
+        let text = self.documents[document].1;
+        let offset = offset(text, position);
+        let schema = self
+            .st
+            .schemas
+            .iter()
+            .zip(&self.owners)
+            .find(|(schema, owner)| {
+                **owner == document && schema.span.range(text).contains(&offset)
+            })?
+            .0;
+        let token = identifiers(text, schema.span.range(text))
+            .into_iter()
+            .find(|token| token.start <= offset && offset <= token.end)?;
+        let (_path, index) = ns
+            .resolve(&Scope::root().schema(&schema.name), &text[token.clone()])
+            .ok()?;
+        Some((token, index))
+    }
+
+    /// Document, range of the declaration and range of the name of [Namespace] item
+    fn declaration(
+        &self,
+        ns: &Namespace,
+        index: usize,
+    ) -> Option<(usize, Range<usize>, Range<usize>)> {
+        let (path, named) = &ns[index];
+        let document = self.owner(path.scope.schema_name()?)?;
+        let text = self.documents[document].1;
+        let span = match named {
+            Named::Entity(entity) => entity.span,
+            Named::Type(ty) => ty.span,
+        };
+        let decl = span.range(text);
+        let name = name_range(text, decl.clone());
+        Some((document, decl, name))
+    }
+
+    fn entity_summary(&self, ns: &Namespace, path: &Path, entity: &ast::Entity) -> String {
+        let mut lines = vec![match &entity.subtype_of {
+            Some(subtype_of) => format!(
+                "ENTITY {} SUBTYPE OF ({});",
+                entity.name,
+                subtype_of.entity_references.join(", ")
+            ),
+            None => format!("ENTITY {};", entity.name),
+        }];
+        self.attribute_lines(path, entity, &mut lines);
+
+        // Inherited attributes from all supertypes, nearer one first
+        let mut visited = HashSet::new();
+        let mut queue = VecDeque::from(vec![(path.clone(), entity)]);
+        while let Some((path, entity)) = queue.pop_front() {
+            for name in entity
+                .subtype_of
+                .iter()
+                .flat_map(|subtype_of| &subtype_of.entity_references)
+            {
+                let (path, index) = match ns.resolve(&path.scope, name) {
+                    Ok(resolved) => resolved,
+                    Err(_) => continue,
+                };
+                if !visited.insert(index) {
+                    continue;
+                }
+                if let Named::Entity(supertype) = ns[index].1 {
+                    lines.push(format!("  -- inherited from {}", supertype.name));
+                    self.attribute_lines(&path, supertype, &mut lines);
+                    queue.push_back((path, supertype));
+                }
+            }
+        }
+        lines.push("END_ENTITY;".to_string());
+        lines.join("\n")
+    }
+
+    /// Push the declarations of explicit attributes with normalized whitespaces
+    fn attribute_lines(&self, path: &Path, entity: &ast::Entity, lines: &mut Vec<String>) {
+        let text = match path.scope.schema_name().and_then(|name| self.owner(name)) {
+            Some(document) => self.documents[document].1,
+            None => return,
+        };
+        let mut last = None;
+        for attr in &entity.attributes {
+            // Attributes declared together, e.g. `x, y: REAL;`, share the span
+            let range = attr.span.range(text);
+            if last.as_ref() == Some(&range) {
+                continue;
+            }
+            let words: Vec<_> = text[range.clone()].split_whitespace().collect();
+            lines.push(format!("  {}", words.join(" ")));
+            last = Some(range);
+        }
+    }
+
+    #[allow(deprecated)] // for DocumentSymbol::deprecated
+    fn symbol(
+        &self,
+        document: usize,
+        name: &str,
+        kind: SymbolKind,
+        span: ast::Span,
+        name_position: usize,
+        children: Vec<DocumentSymbol>,
+    ) -> DocumentSymbol {
+        let text = self.documents[document].1;
+        let range = span.range(text);
+        let selection = identifiers(text, range.clone())
+            .into_iter()
+            .skip(name_position)
+            .find(|token| &text[token.clone()] == name)
+            .unwrap_or_else(|| range.clone());
+        DocumentSymbol {
+            name: name.to_string(),
+            detail: None,
+            kind,
+            tags: None,
+            deprecated: None,
+            range: self.range(document, range),
+            selection_range: self.range(document, selection),
+            children: if children.is_empty() {
+                None
+            } else {
+                Some(children)
+            },
+        }
+    }
+
+    fn range(&self, document: usize, range: Range<usize>) -> lsp_types::Range {
+        let text = self.documents[document].1;
+        lsp_types::Range::new(position(text, range.start), position(text, range.end))
+    }
+
+    fn location(&self, document: usize, range: Range<usize>) -> Location {
+        Location::new(
+            self.documents[document].0.clone(),
+            self.range(document, range),
+        )
+    }
+}
+
+fn error(range: lsp_types::Range, err: &SemanticError) -> Diagnostic {
+    Diagnostic {
+        range,
+        severity: Some(DiagnosticSeverity::ERROR),
+        source: Some("espr".to_string()),
+        message: err.to_string(),
+        ..Default::default()
+    }
+}
+
+/// Diagnostic at the innermost position where the parser failed,
+/// or at the beginning of `text` if the position is not reported
+fn syntax_error(text: &str, err: VerboseError<&str>) -> Diagnostic {
+    let (code, kind) = match err.errors.first() {
+        Some(error) => error,
+        None => {
+            return Diagnostic {
+                range: lsp_types::Range::new(position(text, 0), position(text, 0)),
+                severity: Some(DiagnosticSeverity::ERROR),
+                source: Some("espr".to_string()),
+                message: "Syntax error".to_string(),
+                ..Default::default()
+            }
+        }
+    };
+    let start = text.len() - code.len();
+    let end = start + code.find('\n').unwrap_or(code.len());
+    let mut message = match kind {
+        VerboseErrorKind::Context(context) => format!("Syntax error while parsing {}", context),
+        VerboseErrorKind::Char(c) => format!("Syntax error: expected `{}`", c),
+        VerboseErrorKind::Nom(kind) => format!("Syntax error: failed in {:?}", kind),
+    };
+    if !matches!(kind, VerboseErrorKind::Context(_)) {
+        if let Some(context) = err.errors.iter().find_map(|(_code, kind)| match kind {
+            VerboseErrorKind::Context(context) => Some(context),
+            _ => None,
+        }) {
+            message += &format!(" while parsing {}", context);
+        }
+    }
+    Diagnostic {
+        range: lsp_types::Range::new(position(text, start), position(text, end)),
+        severity: Some(DiagnosticSeverity::ERROR),
+        source: Some("espr".to_string()),
+        message,
+        ..Default::default()
+    }
+}
+
+/// Byte ranges of identifiers and keywords in `text[range]`
+///
+/// Remarks and string literals are skipped.
+fn identifiers(text: &str, range: Range<usize>) -> Vec<Range<usize>> {
+    let bytes = text.as_bytes();
+    let mut tokens = Vec::new();
+    let mut i = range.start;
+    // Embedded remarks `(* ... *)` can be nested
+    let mut depth = 0;
+    while i < range.end {
+        let rest = &bytes[i..range.end];
+        if rest.starts_with(b"(*") {
+            depth += 1;
+            i += 2;
+        } else if depth > 0 {
+            if rest.starts_with(b"*)") {
+                depth -= 1;
+                i += 2;
+            } else {
+                i += 1;
+            }
+        } else if rest.starts_with(b"--") {
+            i += rest.iter().position(|&b| b == b'\n').unwrap_or(rest.len());
+        } else if rest[0] == b'\'' || rest[0] == b'"' {
+            i += rest[1..]
+                .iter()
+                .position(|&b| b == rest[0])
+                .map_or(rest.len(), |end| end + 2);
+        } else if rest[0].is_ascii_alphanumeric() || rest[0] == b'_' {
+            let len = rest
+                .iter()
+                .position(|&b| !(b.is_ascii_alphanumeric() || b == b'_'))
+                .unwrap_or(rest.len());
+            if rest[0].is_ascii_alphabetic() {
+                tokens.push(i..i + len);
+            }
+            i += len;
+        } else {
+            i += 1;
+        }
+    }
+    tokens
+}
+
+/// Range of the name in a declaration, e.g. `a` in `ENTITY a; ... END_ENTITY;`
+fn name_range(text: &str, decl: Range<usize>) -> Range<usize> {
+    identifiers(text, decl.clone())
+        .into_iter()
+        .nth(1)
+        .unwrap_or(decl)
+}
+
+/// Check if the identifier is followed by `:` like an attribute declaration `x: REAL;`
+fn is_attribute_name(text: &str, token: &Range<usize>) -> bool {
+    let rest = text[token.end..].trim_start();
+    rest.starts_with(':') && !rest.starts_with(":=")
+}
+
+/// Position in LSP, where the character offset is counted in UTF-16 code units
+fn position(text: &str, offset: usize) -> Position {
+    let before = &text[..offset];
+    let line = before.matches('\n').count();
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    let character = before[line_start..].encode_utf16().count();
+    Position::new(line as u32, character as u32)
+}
+
+/// Byte offset of LSP [Position]
+fn offset(text: &str, position: Position) -> usize {
+    let line_start: usize = text
+        .split_inclusive('\n')
+        .take(position.line as usize)
+        .map(str::len)
+        .sum();
+    let line = text[line_start..].split('\n').next().unwrap_or("");
+    let mut units = 0;
+    for (i, c) in line.char_indices() {
+        if units >= position.character as usize {
+            return line_start + i;
+        }
+        units += c.len_utf16();
+    }
+    line_start + line.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn identifiers() {
+        let text = "ENTITY a; (* b (* c *) *) x: STRING := 'd'; -- e\n y2: f_1; END_ENTITY;";
+        let tokens: Vec<_> = super::identifiers(text, 0..text.len())
+            .into_iter()
+            .map(|token| &text[token])
+            .collect();
+        assert_eq!(
+            tokens,
+            vec!["ENTITY", "a", "x", "STRING", "y2", "f_1", "END_ENTITY"]
+        );
+    }
+
+    #[test]
+    fn position_offset() {
+        let text = "ab\nαβc\n";
+        for offset in [0, 1, 3, 5, 7, 8] {
+            assert_eq!(super::offset(text, position(text, offset)), offset);
+        }
+        assert_eq!(position(text, 7), Position::new(1, 2));
+    }
+
+    #[test]
+    fn syntax_error_without_position() {
+        let diagnostic = syntax_error("SCHEMA s;", VerboseError { errors: Vec::new() });
+        assert_eq!(diagnostic.message, "Syntax error");
+        assert_eq!(diagnostic.range.start, Position::new(0, 0));
+    }
+}
@@ -0,0 +1,23 @@
+//! Language server for EXPRESS Language
+//!
+//! This crate provides `espr-lsp` executable which communicates with editors
+//! by [Language Server Protocol](https://microsoft.github.io/language-server-protocol/) over stdio.
+//! It supports
+//!
+//! - Diagnostics of syntax and semantic errors
+//! - Go to definition of entities and types
+//! - Hover showing attributes and supertypes of entities
+//! - Document symbols
+//! - Find references
+//!
+//! All opened documents are analyzed together,
+//! i.e. a schema can refer to entities interfaced by `USE FROM` or `REFERENCE FROM`
+//! from another schema declared in another opened document.
+
+#![deny(rustdoc::broken_intra_doc_links)]
+
+mod analysis;
+mod server;
+
+pub use analysis::*;
+pub use server::*;
@@ -0,0 +1,13 @@
+use espr_lsp::{capabilities, Server};
+use lsp_server::Connection;
+use std::error::Error;
+
+fn main() -> Result<(), Box<dyn Error + Sync + Send>> {
+    let (connection, io_threads) = Connection::stdio();
+    connection.initialize(serde_json::to_value(capabilities())?)?;
+    Server::default().run(&connection)?;
+    // IO threads stop after the connection is dropped
+    drop(connection);
+    io_threads.join()?;
+    Ok(())
+}
@@ -0,0 +1,152 @@
+use crate::Analysis;
+use lsp_server::{Connection, ErrorCode, Message, Notification, Request, Response};
+use lsp_types::{
+    notification::{
+        DidChangeTextDocument, DidCloseTextDocument, DidOpenTextDocument,
+        Notification as NotificationTrait, PublishDiagnostics,
+    },
+    request::{
+        DocumentSymbolRequest, GotoDefinition, HoverRequest, References, Request as RequestTrait,
+    },
+    DidChangeTextDocumentParams, DidCloseTextDocumentParams, DidOpenTextDocumentParams,
+    DocumentSymbolResponse, GotoDefinitionResponse, HoverProviderCapability, OneOf,
+    PublishDiagnosticsParams, ServerCapabilities, TextDocumentSyncCapability, TextDocumentSyncKind,
+    Url,
+};
+use std::{collections::BTreeMap, error::Error};
+
+/// Capabilities of this server sent in the response of `initialize` request
+pub fn capabilities() -> ServerCapabilities {
+    ServerCapabilities {
+        text_document_sync: Some(TextDocumentSyncCapability::Kind(TextDocumentSyncKind::FULL)),
+        definition_provider: Some(OneOf::Left(true)),
+        hover_provider: Some(HoverProviderCapability::Simple(true)),
+        document_symbol_provider: Some(OneOf::Left(true)),
+        references_provider: Some(OneOf::Left(true)),
+        ..Default::default()
+    }
+}
+
+/// Language server holding the text of opened documents
+#[derive(Debug, Default)]
+pub struct Server {
+    documents: BTreeMap<Url, String>,
+}
+
+impl Server {
+    /// Handle messages until `shutdown` request
+    ///
+    /// The connection must be initialized by [Connection::initialize] with [capabilities].
+    pub fn run(&mut self, connection: &Connection) -> Result<(), Box<dyn Error + Sync + Send>> {
+        for message in &connection.receiver {
+            match message {
+                Message::Request(request) => {
+                    if connection.handle_shutdown(&request)? {
+                        return Ok(());
+                    }
+                    connection
+                        .sender
+                        .send(Message::Response(self.request(request)))?;
+                }
+                Message::Notification(notification) => {
+                    self.notification(connection, notification)?
+                }
+                Message::Response(_) => {}
+            }
+        }
+        Ok(())
+    }
+
+    fn request(&self, request: Request) -> Response {
+        let analysis = Analysis::new(&self.documents);
+        match request.method.as_str() {
+            GotoDefinition::METHOD => respond::<GotoDefinition>(request, |params| {
+                let position = params.text_document_position_params;
+                analysis
+                    .definition(&position.text_document.uri, position.position)
+                    .map(GotoDefinitionResponse::Scalar)
+            }),
+            HoverRequest::METHOD => respond::<HoverRequest>(request, |params| {
+                let position = params.text_document_position_params;
+                analysis.hover(&position.text_document.uri, position.position)
+            }),
+            DocumentSymbolRequest::METHOD => respond::<DocumentSymbolRequest>(request, |params| {
+                analysis
+                    .document_symbols(&params.text_document.uri)
+                    .map(DocumentSymbolResponse::Nested)
+            }),
+            References::METHOD => respond::<References>(request, |params| {
+                let position = params.text_document_position;
+                analysis.references(
+                    &position.text_document.uri,
+                    position.position,
+                    params.context.include_declaration,
+                )
+            }),
+            _ => Response::new_err(
+                request.id,
+                ErrorCode::MethodNotFound as i32,
+                format!("Unsupported request: {}", request.method),
+            ),
+        }
+    }
+
+    fn notification(
+        &mut self,
+        connection: &Connection,
+        notification: Notification,
+    ) -> Result<(), Box<dyn Error + Sync + Send>> {
+        match notification.method.as_str() {
+            DidOpenTextDocument::METHOD => {
+                let params: DidOpenTextDocumentParams =
+                    serde_json::from_value(notification.params)?;
+                self.documents
+                    .insert(params.text_document.uri, params.text_document.text);
+            }
+            DidChangeTextDocument::METHOD => {
+                let mut params: DidChangeTextDocumentParams =
+                    serde_json::from_value(notification.params)?;
+                // Only full text synchronization is supported
+                if let Some(change) = params.content_changes.pop() {
+                    self.documents.insert(params.text_document.uri, change.text);
+                }
+            }
+            DidCloseTextDocument::METHOD => {
+                let params: DidCloseTextDocumentParams =
+                    serde_json::from_value(notification.params)?;
+                self.documents.remove(&params.text_document.uri);
+                // Clear diagnostics of the closed document
+                publish(connection, params.text_document.uri, Vec::new())?;
+            }
+            _ => return Ok(()),
+        }
+
+        // Every document is re-analyzed since a change may affect others through interfaces
+        for (uri, diagnostics) in Analysis::new(&self.documents).diagnostics() {
+            publish(connection, uri, diagnostics)?;
+        }
+        Ok(())
+    }
+}
+
+fn respond<R: RequestTrait>(request: Request, f: impl FnOnce(R::Params) -> R::Result) -> Response {
+    match serde_json::from_value(request.params) {
+        Ok(params) => Response::new_ok(request.id, f(params)),
+        Err(err) => Response::new_err(request.id, ErrorCode::InvalidParams as i32, err.to_string()),
+    }
+}
+
+fn publish(
+    connection: &Connection,
+    uri: Url,
+    diagnostics: Vec<lsp_types::Diagnostic>,
+) -> Result<(), Box<dyn Error + Sync + Send>> {
+    let params = PublishDiagnosticsParams::new(uri, diagnostics, None);
+    connection
+        .sender
+        .send(Message::Notification(Notification::new(
+            PublishDiagnostics::METHOD.to_string(),
+            params,
+        )))?;
+    Ok(())
+}
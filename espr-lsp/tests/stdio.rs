@@ -0,0 +1,290 @@
+//! Scripted LSP client communicating with `espr-lsp` executable over stdio
+
+use serde_json::{json, Value};
+use std::{
+    io::{BufRead, BufReader, Read, Write},
+    process::{Child, ChildStdin, ChildStdout, Command, Stdio},
+};
+
+const URI: &str = "file:///tmp/example.exp";
+
+const EXAMPLE: &str = r#"SCHEMA example;
+  TYPE label = STRING;
+  END_TYPE;
+
+  ENTITY base;
+    name: label;
+  END_ENTITY;
+
+  ENTITY point SUBTYPE OF (base);
+    x, y: REAL;
+  END_ENTITY;
+
+  ENTITY line;
+    start_point: point;
+    end_point: point;
+  END_ENTITY;
+END_SCHEMA;
+"#;
+
+struct Client {
+    server: Child,
+    stdin: ChildStdin,
+    stdout: BufReader<ChildStdout>,
+    id: i64,
+}
+
+impl Client {
+    fn start() -> Self {
+        let mut server = Command::new(env!("CARGO_BIN_EXE_espr-lsp"))
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn()
+            .unwrap();
+        let stdin = server.stdin.take().unwrap();
+        let stdout = BufReader::new(server.stdout.take().unwrap());
+        let mut client = Client {
+            server,
+            stdin,
+            stdout,
+            id: 0,
+        };
+        let result = client.request("initialize", json!({ "capabilities": {} }));
+        assert_eq!(result["capabilities"]["definitionProvider"], json!(true));
+        client.notify("initialized", json!({}));
+        client
+    }
+
+    fn send(&mut self, message: Value) {
+        let body = message.to_string();
+        write!(self.stdin, "Content-Length: {}\r\n\r\n{}", body.len(), body).unwrap();
+        self.stdin.flush().unwrap();
+    }
+
+    fn receive(&mut self) -> Value {
+        let mut length = None;
+        loop {
+            let mut header = String::new();
+            self.stdout.read_line(&mut header).unwrap();
+            let header = header.trim_end();
+            if header.is_empty() {
+                break;
+            }
+            if let Some(value) = header.strip_prefix("Content-Length: ") {
+                length = Some(value.parse().unwrap());
+            }
+        }
+        let mut body = vec![0; length.unwrap()];
+        self.stdout.read_exact(&mut body).unwrap();
+        serde_json::from_slice(&body).unwrap()
+    }
+
+    fn notify(&mut self, method: &str, params: Value) {
+        self.send(json!({ "jsonrpc": "2.0", "method": method, "params": params }));
+    }
+
+    /// Send a request and wait its result, ignoring notifications from server
+    fn request(&mut self, method: &str, params: Value) -> Value {
+        self.id += 1;
+        let id = self.id;
+        self.send(json!({ "jsonrpc": "2.0", "id": id, "method": method, "params": params }));
+        loop {
+            let message = self.receive();
+            if message["id"] == json!(id) {
+                assert!(message.get("error").is_none(), "{}", message);
+                return message["result"].clone();
+            }
+        }
+    }
+
+    /// Wait `textDocument/publishDiagnostics` notification for [URI]
+    fn diagnostics(&mut self) -> Vec<Value> {
+        loop {
+            let message = self.receive();
+            if message["method"] == "textDocument/publishDiagnostics"
+                && message["params"]["uri"] == URI
+            {
+                return message["params"]["diagnostics"].as_array().unwrap().clone();
+            }
+        }
+    }
+
+    fn open(&mut self, text: &str) -> Vec<Value> {
+        self.notify(
+            "textDocument/didOpen",
+            json!({
+                "textDocument": { "uri": URI, "languageId": "express", "version": 1, "text": text }
+            }),
+        );
+        self.diagnostics()
+    }
+
+    fn position(&mut self, method: &str, line: u32, character: u32) -> Value {
+        self.request(
+            method,
+            json!({
+                "textDocument": { "uri": URI },
+                "position": { "line": line, "character": character },
+                "context": { "includeDeclaration": true },
+            }),
+        )
+    }
+
+    fn shutdown(mut self) {
+        self.request("shutdown", Value::Null);
+        self.notify("exit", Value::Null);
+        assert!(self.server.wait().unwrap().success());
+    }
+}
+
+fn range(start: (u32, u32), end: (u32, u32)) -> Value {
+    json!({
+        "start": { "line": start.0, "character": start.1 },
+        "end": { "line": end.0, "character": end.1 },
+    })
+}
+
+#[test]
+fn diagnostics() {
+    let mut client = Client::start();
+
+    let diagnostics = client.open(
+        r#"SCHEMA example;
+  ENTITY a;
+    x: undefined_type;
+  END_ENTITY;
+END_SCHEMA;
+"#,
+    );
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0]["range"], range((2, 4), (2, 22)));
+    assert_eq!(
+        diagnostics[0]["message"],
+        "Not found the Type undefined_type referred in scope example"
+    );
+
+    // Syntax error
+    client.notify(
+        "textDocument/didChange",
+        json!({
+            "textDocument": { "uri": URI, "version": 2 },
+            "contentChanges": [{ "text": "SCHEMA example;\n  ENTITY a\nEND_SCHEMA;\n" }],
+        }),
+    );
+    let diagnostics = client.diagnostics();
+    assert_eq!(diagnostics.len(), 1);
+    assert!(diagnostics[0]["message"]
+        .as_str()
+        .unwrap()
+        .starts_with("Syntax error"));
+
+    // Fixed
+    client.notify(
+        "textDocument/didChange",
+        json!({
+            "textDocument": { "uri": URI, "version": 3 },
+            "contentChanges": [{ "text": EXAMPLE }],
+        }),
+    );
+    assert!(client.diagnostics().is_empty());
+
+    client.shutdown();
+}
+
+#[test]
+fn definition() {
+    let mut client = Client::start();
+    assert!(client.open(EXAMPLE).is_empty());
+
+    // `point` in `start_point: point;`
+    let location = client.position("textDocument/definition", 13, 18);
+    assert_eq!(location["uri"], URI);
+    assert_eq!(location["range"], range((8, 9), (8, 14)));
+
+    // `label` in `name: label;`
+    let location = client.position("textDocument/definition", 5, 12);
+    assert_eq!(location["range"], range((1, 7), (1, 12)));
+
+    // Attribute name is not an entity or type
+    let location = client.position("textDocument/definition", 13, 6);
+    assert_eq!(location, Value::Null);
+
+    client.shutdown();
+}
+
+#[test]
+fn hover() {
+    let mut client = Client::start();
+    assert!(client.open(EXAMPLE).is_empty());
+
+    let hover = client.position("textDocument/hover", 13, 18);
+    assert_eq!(
+        hover["contents"]["value"],
+        r#"```express
+ENTITY point SUBTYPE OF (base);
+  x, y: REAL;
+  -- inherited from base
+  name: label;
+END_ENTITY;
+```"#
+    );
+    assert_eq!(hover["range"], range((13, 17), (13, 22)));
+
+    let hover = client.position("textDocument/hover", 5, 12);
+    assert_eq!(
+        hover["contents"]["value"],
+        "```express\nTYPE label = STRING;\n  END_TYPE;\n```"
+    );
+
+    client.shutdown();
+}
+
+#[test]
+fn document_symbols() {
+    let mut client = Client::start();
+    assert!(client.open(EXAMPLE).is_empty());
+
+    let symbols = client.request(
+        "textDocument/documentSymbol",
+        json!({ "textDocument": { "uri": URI } }),
+    );
+    let symbols = symbols.as_array().unwrap();
+    assert_eq!(symbols.len(), 1);
+    assert_eq!(symbols[0]["name"], "example");
+    assert_eq!(symbols[0]["selectionRange"], range((0, 7), (0, 14)));
+
+    let children = symbols[0]["children"].as_array().unwrap();
+    let names: Vec<_> = children.iter().map(|symbol| &symbol["name"]).collect();
+    assert_eq!(names, vec!["label", "base", "point", "line"]);
+
+    let attributes = children[2]["children"].as_array().unwrap();
+    assert_eq!(attributes[0]["name"], "x");
+    assert_eq!(attributes[1]["name"], "y");
+    assert_eq!(attributes[1]["selectionRange"], range((9, 7), (9, 8)));
+
+    client.shutdown();
+}
+
+#[test]
+fn references() {
+    let mut client = Client::start();
+    assert!(client.open(EXAMPLE).is_empty());
+
+    let locations = client.position("textDocument/references", 8, 11);
+    let ranges: Vec<_> = locations
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|location| location["range"].clone())
+        .collect();
+    assert_eq!(
+        ranges,
+        vec![
+            range((8, 9), (8, 14)),
+            range((13, 17), (13, 22)),
+            range((14, 15), (14, 20)),
+        ]
+    );
+
+    client.shutdown();
+}
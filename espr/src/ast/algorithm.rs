@@ -75,6 +75,8 @@ pub struct Procedure {
     pub constants: Vec<Constant>,
     pub variables: Vec<LocalVariable>,
     pub statements: Vec<Statement>,
+
+    pub span: Span,
 }
 
 derive_ast_component!(Procedure, procedure_decl);
@@ -88,6 +90,8 @@ pub struct Function {
     pub variables: Vec<LocalVariable>,
     pub statements: Vec<Statement>,
     pub return_type: Type,
+
+    pub span: Span,
 }
 
 derive_ast_component!(Function, function_decl);
@@ -114,6 +118,8 @@ pub struct Constant {
     pub name: String,
     pub ty: Type,
     pub expr: Expression,
+
+    pub span: Span,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -125,6 +131,8 @@ pub struct Rule {
     pub variables: Vec<LocalVariable>,
     pub statements: Vec<Statement>,
     pub where_clause: WhereClause,
+
+    pub span: Span,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -155,6 +163,8 @@ pub struct WhereClause {
 pub struct DomainRule {
     pub label: Option<String>,
     pub expr: Expression,
+
+    pub span: Span,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -20,6 +20,8 @@ pub struct Entity {
     pub inverse_clause: Option<InverseClause>,
     pub unique_clause: Option<UniqueClause>,
     pub where_clause: Option<WhereClause>,
+
+    pub span: Span,
 }
 
 crate::derive_ast_component!(Entity, entity_decl);
@@ -75,6 +77,8 @@ pub struct EntityAttribute {
     pub name: AttributeDecl,
     pub ty: Type,
     pub optional: bool,
+    /// Span of the declaration, which may declare several attributes e.g. `x, y: REAL;`
+    pub span: Span,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -87,6 +91,7 @@ pub struct DerivedAttribute {
     pub attr: AttributeDecl,
     pub ty: Type,
     pub expr: Expression,
+    pub span: Span,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -141,6 +146,8 @@ pub struct InverseAttribute {
 
     /// Prefix of the attribute, used if the attribute is a sub-attribute of `dest` entity
     pub attribute_prefix: Option<String>,
+
+    pub span: Span,
 }
 
 #[derive(Debug, Clone, PartialEq)]
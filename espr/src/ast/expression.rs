@@ -1,34 +1,47 @@
 //! AST for expression
 
+use super::Span;
 use derive_more::From;
 
 #[cfg(doc)]
 use crate::parser::*;
 
+/// Expression with the [Span] of the whole expression in each variant
+///
+/// Expressions constructed by the functions of this type have the default span,
+/// or the span covering the operands for operators.
 #[derive(Debug, Clone, PartialEq)]
 pub enum Expression {
     Unary {
         op: UnaryOperator,
         arg: Box<Expression>,
+        span: Span,
     },
     Binary {
         op: BinaryOperator,
         arg1: Box<Expression>,
         arg2: Box<Expression>,
+        span: Span,
     },
     Relation {
         op: RelationOperator,
         lhs: Box<Expression>,
         rhs: Box<Expression>,
+        span: Span,
+    },
+    Literal {
+        literal: Literal,
+        span: Span,
     },
-    Literal(Literal),
     QualifiableFactor {
         factor: QualifiableFactor,
         qualifiers: Vec<Qualifier>,
+        span: Span,
     },
     EntityConstructor {
         name: String,
         values: Vec<Expression>,
+        span: Span,
     },
     Interval {
         op_low: IntervalOperator,
@@ -36,18 +49,22 @@ pub enum Expression {
         high: Box<Expression>,
         low: Box<Expression>,
         item: Box<Expression>,
+        span: Span,
     },
     EnumerationReference {
         ty: Option<String>,
         enum_ref: String,
+        span: Span,
     },
     AggregateInitializer {
         elements: Vec<Element>,
+        span: Span,
     },
     Query {
         variable: String,
         source: Box<Expression>,
         expr: Box<Expression>,
+        span: Span,
     },
 }
 
@@ -56,6 +73,7 @@ macro_rules! impl_relation_op_expression {
         pub fn $f(self, other: Self) -> Self {
             Expression::Relation {
                 op: $op,
+                span: self.span().to(other.span()),
                 lhs: Box::new(self),
                 rhs: Box::new(other),
             }
@@ -68,6 +86,7 @@ macro_rules! impl_binary_op_expression {
         pub fn $f(self, other: Self) -> Self {
             Expression::Binary {
                 op: $op,
+                span: self.span().to(other.span()),
                 arg1: Box::new(self),
                 arg2: Box::new(other),
             }
@@ -86,6 +105,7 @@ impl Expression {
         Expression::QualifiableFactor {
             factor: QualifiableFactor::BuiltInConstant(BuiltInConstant::Self_),
             qualifiers,
+            span: Span::default(),
         }
     }
 
@@ -94,12 +114,68 @@ impl Expression {
         Expression::QualifiableFactor {
             factor: QualifiableFactor::BuiltInConstant(BuiltInConstant::Indeterminate),
             qualifiers: Vec::new(),
+            span: Span::default(),
         }
     }
 
+    /// True if this is `?` constant
+    pub fn is_indeterminate(&self) -> bool {
+        matches!(
+            self,
+            Expression::QualifiableFactor {
+                factor: QualifiableFactor::BuiltInConstant(BuiltInConstant::Indeterminate),
+                qualifiers,
+                ..
+            } if qualifiers.is_empty()
+        )
+    }
+
     /// Real value literal
     pub fn real(value: f64) -> Self {
-        Expression::Literal(Literal::Real(value))
+        Expression::literal(Literal::Real(value))
+    }
+
+    /// Literal without span
+    pub fn literal(literal: Literal) -> Self {
+        Expression::Literal {
+            literal,
+            span: Span::default(),
+        }
+    }
+
+    /// Span of the whole expression
+    pub fn span(&self) -> Span {
+        use Expression::*;
+        match self {
+            Unary { span, .. }
+            | Binary { span, .. }
+            | Relation { span, .. }
+            | Literal { span, .. }
+            | QualifiableFactor { span, .. }
+            | EntityConstructor { span, .. }
+            | Interval { span, .. }
+            | EnumerationReference { span, .. }
+            | AggregateInitializer { span, .. }
+            | Query { span, .. } => *span,
+        }
+    }
+
+    /// Replace the span of the whole expression
+    pub fn with_span(mut self, new: Span) -> Self {
+        use Expression::*;
+        match &mut self {
+            Unary { span, .. }
+            | Binary { span, .. }
+            | Relation { span, .. }
+            | Literal { span, .. }
+            | QualifiableFactor { span, .. }
+            | EntityConstructor { span, .. }
+            | Interval { span, .. }
+            | EnumerationReference { span, .. }
+            | AggregateInitializer { span, .. }
+            | Query { span, .. } => *span = new,
+        }
+        self
     }
 
     impl_relation_op_expression!(leq, RelationOperator::Leq);
@@ -123,6 +199,7 @@ macro_rules! impl_binary_op_expression {
             fn $f(self, other: Expression) -> Self {
                 Expression::Binary {
                     op: $op,
+                    span: self.span().to(other.span()),
                     arg1: Box::new(self),
                     arg2: Box::new(other),
                 }
@@ -215,7 +292,7 @@ pub enum BuiltInConstant {
 }
 
 /// Relation operators parsed by [rel_op] and [rel_op_extended]
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum RelationOperator {
     /// `=`
     Equal,
@@ -242,7 +319,7 @@ pub enum RelationOperator {
 }
 
 /// Unary operators parsed by [unary_op]
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum UnaryOperator {
     /// `+`
     Plus,
@@ -253,7 +330,7 @@ pub enum UnaryOperator {
 }
 
 /// Binary operators parsed by [add_like_op], [multiplication_like_op], and [power_op]
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum BinaryOperator {
     /* Mul-like */
     /// `*`
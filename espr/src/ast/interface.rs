@@ -0,0 +1,159 @@
+//! Names visible in each schema, declared in it or interfaced into it
+//! by `USE FROM` and `REFERENCE FROM` (ISO 10303-11 §11)
+//!
+//! This is shared by [SyntaxTree::long_form] and [crate::ir::Namespace]
+//! to resolve interfaces in the same way.
+
+use super::*;
+use std::collections::{hash_map::Entry, HashMap};
+use thiserror::Error;
+
+/// Error while resolving the interfaces of schemas
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum InterfaceError {
+    #[error("Schema {name} interfaced into {referrer} is not found")]
+    SchemaNotFound { name: String, referrer: String },
+
+    #[error("{name} interfaced into {referrer} is not visible in schema {schema}")]
+    ResourceNotFound {
+        name: String,
+        schema: String,
+        referrer: String,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub(crate) enum Kind {
+    Constant,
+    Type,
+    Entity,
+    SubTypeConstraint,
+    Function,
+    Procedure,
+    Rule,
+}
+
+/// Declaration in a schema, identified by the indices of the schema and of the declaration
+pub(crate) type Item = (usize, Kind, usize);
+
+/// Names of declarations visible in a schema, with the declarations they refer to
+pub(crate) type Visible = HashMap<String, Item>;
+
+fn declared(schema_index: usize, schema: &Schema) -> Visible {
+    let mut names = Visible::new();
+    let mut insert = |kind, name: &str, index| {
+        names.insert(name.to_string(), (schema_index, kind, index));
+    };
+    for (i, constant) in schema.constants.iter().enumerate() {
+        insert(Kind::Constant, &constant.name, i);
+    }
+    for (i, ty) in schema.types.iter().enumerate() {
+        insert(Kind::Type, &ty.type_id, i);
+    }
+    for (i, entity) in schema.entities.iter().enumerate() {
+        insert(Kind::Entity, &entity.name, i);
+    }
+    for (i, function) in schema.functions.iter().enumerate() {
+        insert(Kind::Function, &function.name, i);
+    }
+    for (i, procedure) in schema.procedures.iter().enumerate() {
+        insert(Kind::Procedure, &procedure.name, i);
+    }
+    for (i, rule) in schema.rules.iter().enumerate() {
+        insert(Kind::Rule, &rule.name, i);
+    }
+    names
+}
+
+/// Foreign schema, resource list, and if `USE FROM` of an interface
+fn interface(spec: &InterfaceSpec) -> (&String, &[(String, Option<String>)], bool) {
+    match spec {
+        InterfaceSpec::Use { name, types } => (name, types, true),
+        InterfaceSpec::Reference { name, resources } => (name, resources, false),
+    }
+}
+
+/// `USE FROM` without resource list interfaces only entities and types
+///
+/// Other declarations listed explicitly in `USE FROM`, e.g. `using_representations` function
+/// in the short form of AP203, are interfaced as if by `REFERENCE FROM`.
+fn usable(item: &Item, is_use: bool) -> bool {
+    !is_use || matches!(item.1, Kind::Type | Kind::Entity)
+}
+
+/// Names visible in each schema of `st`, in the order of [SyntaxTree::schemas]
+///
+/// An interface without resource list interfaces every name visible in the foreign schema,
+/// and thus this iterates until no more names are added.
+/// If several declarations are interfaced by the same name, the declared one or the first one is used.
+pub(crate) fn visible(st: &SyntaxTree) -> Result<Vec<Visible>, InterfaceError> {
+    // Schema names are case-insensitive
+    let foreign_index = |referrer: &Schema, foreign: &str| {
+        st.schemas
+            .iter()
+            .position(|schema| schema.name.eq_ignore_ascii_case(foreign))
+            .ok_or_else(|| InterfaceError::SchemaNotFound {
+                name: foreign.to_string(),
+                referrer: referrer.name.clone(),
+            })
+    };
+
+    let mut visible: Vec<Visible> = st
+        .schemas
+        .iter()
+        .enumerate()
+        .map(|(i, schema)| declared(i, schema))
+        .collect();
+    loop {
+        let mut updated = false;
+        for (i, schema) in st.schemas.iter().enumerate() {
+            for spec in &schema.interfaces {
+                let (foreign, resources, is_use) = interface(spec);
+                let foreign = foreign_index(schema, foreign)?;
+                let new: Vec<(String, Item)> = if resources.is_empty() {
+                    visible[foreign]
+                        .iter()
+                        .filter(|(_, item)| usable(item, is_use))
+                        .map(|(name, item)| (name.clone(), *item))
+                        .collect()
+                } else {
+                    resources
+                        .iter()
+                        .filter_map(|(name, rename)| {
+                            let item = visible[foreign].get(name)?;
+                            Some((rename.as_ref().unwrap_or(name).clone(), *item))
+                        })
+                        .collect()
+                };
+                for (name, item) in new {
+                    if let Entry::Vacant(entry) = visible[i].entry(name) {
+                        entry.insert(item);
+                        updated = true;
+                    }
+                }
+            }
+        }
+        if !updated {
+            break;
+        }
+    }
+
+    // Resources are checked after all names are interfaced,
+    // since they may be interfaced into the foreign schema from another one.
+    for schema in &st.schemas {
+        for spec in &schema.interfaces {
+            let (foreign, resources, _is_use) = interface(spec);
+            let foreign = foreign_index(schema, foreign)?;
+            for (name, _rename) in resources {
+                if !visible[foreign].contains_key(name) {
+                    return Err(InterfaceError::ResourceNotFound {
+                        name: name.clone(),
+                        schema: st.schemas[foreign].name.clone(),
+                        referrer: schema.name.clone(),
+                    });
+                }
+            }
+        }
+    }
+    Ok(visible)
+}
@@ -0,0 +1,253 @@
+//! Load EXPRESS schemas spread over multiple files
+
+use super::*;
+use nom::error::VerboseErrorKind;
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    fs, io,
+    path::{Path, PathBuf},
+};
+use thiserror::Error;
+
+/// Error while loading EXPRESS files by [SyntaxTree::load]
+#[derive(Debug, Error)]
+pub enum LoadError {
+    #[error("Failed to read {path}: {source}")]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: io::Error,
+    },
+
+    /// Syntax error with the positions where the parser failed
+    #[error("Syntax error in {}", display_path(&.input.path))]
+    Syntax {
+        input: Source,
+        errors: Vec<(Span, VerboseErrorKind)>,
+    },
+
+    #[error("Schema {name} referred from {referrer} is not found in include directories")]
+    SchemaNotFound { name: String, referrer: String },
+}
+
+/// Path of [Source], or `<input>` if it is parsed from a string
+fn display_path(path: &Option<PathBuf>) -> String {
+    match path {
+        Some(path) => path.display().to_string(),
+        None => "<input>".to_string(),
+    }
+}
+
+/// Names of the schemas declared in an EXPRESS file
+///
+/// This does not parse the file entirely, but only scans tokens to find identifiers
+/// following `SCHEMA` keyword in any case, skipping remarks and string literals.
+fn declared_schemas(input: &str) -> Vec<&str> {
+    let is_ident = |c: char| c.is_ascii_alphanumeric() || c == '_';
+    let mut names = Vec::new();
+    let mut after_schema = false;
+    let mut rest = input;
+    while let Some(c) = rest.chars().next() {
+        let len = if rest.starts_with("(*") {
+            rest.find("*)").map_or(rest.len(), |end| end + 2)
+        } else if rest.starts_with("--") {
+            rest.find('\n').unwrap_or(rest.len())
+        } else if c == '\'' || c == '"' {
+            after_schema = false;
+            rest[1..].find(c).map_or(rest.len(), |end| end + 2)
+        } else if is_ident(c) {
+            let end = rest.find(|c| !is_ident(c)).unwrap_or(rest.len());
+            let token = &rest[..end];
+            if after_schema && c.is_ascii_alphabetic() {
+                names.push(token);
+            }
+            after_schema = token.eq_ignore_ascii_case("SCHEMA");
+            end
+        } else {
+            if !c.is_whitespace() {
+                after_schema = false;
+            }
+            c.len_utf8()
+        };
+        rest = &rest[len..];
+    }
+    names
+}
+
+/// Schema name in lower case to EXPRESS file declaring it, built lazily
+///
+/// Schema names are case-insensitive as other identifiers in EXPRESS.
+/// Files are looked up in the order of `include_dirs`, and in the order of file name in each directory.
+/// If a schema is declared in several files, the first one is used.
+/// Directories and files are scanned only until the requested schema is found.
+struct SchemaIndex<'a> {
+    dirs: std::slice::Iter<'a, PathBuf>,
+    files: VecDeque<PathBuf>,
+    index: HashMap<String, PathBuf>,
+}
+
+impl<'a> SchemaIndex<'a> {
+    fn new(include_dirs: &'a [PathBuf]) -> Self {
+        SchemaIndex {
+            dirs: include_dirs.iter(),
+            files: VecDeque::new(),
+            index: HashMap::new(),
+        }
+    }
+
+    /// File declaring the schema of `name`, or `None` if no file in the include directories declares it
+    fn get(&mut self, name: &str) -> Result<Option<&PathBuf>, LoadError> {
+        let name = name.to_lowercase();
+        while !self.index.contains_key(&name) {
+            if let Some(path) = self.files.pop_front() {
+                let input = read(&path)?;
+                for name in declared_schemas(&input) {
+                    self.index
+                        .entry(name.to_lowercase())
+                        .or_insert_with(|| path.clone());
+                }
+                continue;
+            }
+            let dir = match self.dirs.next() {
+                Some(dir) => dir,
+                None => return Ok(None),
+            };
+            let io_error = |source| LoadError::Io {
+                path: dir.clone(),
+                source,
+            };
+            let mut files = Vec::new();
+            for entry in fs::read_dir(dir).map_err(io_error)? {
+                let path = entry.map_err(io_error)?.path();
+                if path.extension().map_or(false, |ext| ext == "exp") {
+                    files.push(path);
+                }
+            }
+            files.sort();
+            self.files.extend(files);
+        }
+        Ok(self.index.get(&name))
+    }
+}
+
+fn read(path: &Path) -> Result<String, LoadError> {
+    fs::read_to_string(path).map_err(|source| LoadError::Io {
+        path: path.to_owned(),
+        source,
+    })
+}
+
+impl SyntaxTree {
+    /// Parse an EXPRESS file without loading the schemas interfaced from it
+    pub fn parse_file(path: &Path) -> Result<Self, LoadError> {
+        let input = read(path)?;
+        let mut st = match SyntaxTree::parse(&input) {
+            Ok(st) => st,
+            Err(err) => {
+                // Span of the first line of the input where the parser failed
+                let errors = err
+                    .errors
+                    .into_iter()
+                    .map(|(code, kind)| {
+                        let line = code.find('\n').unwrap_or(code.len());
+                        (Span::new(code.len(), code.len() - line), kind)
+                    })
+                    .collect();
+                return Err(LoadError::Syntax {
+                    input: Source {
+                        path: Some(path.to_owned()),
+                        text: input,
+                        schemas: Vec::new(),
+                    },
+                    errors,
+                });
+            }
+        };
+        for source in &mut st.sources {
+            source.path = Some(path.to_owned());
+        }
+        Ok(st)
+    }
+
+    /// Load EXPRESS files, and the files declaring the schemas interfaced from them
+    ///
+    /// Schemas referred by `USE FROM` or `REFERENCE FROM` and not declared in `sources`
+    /// are searched from `*.exp` files in `include_dirs`.
+    /// All loaded schemas are merged into a single [SyntaxTree].
+    ///
+    /// Error
+    /// ------
+    /// - [LoadError::SchemaNotFound] if an interfaced schema is not found in `include_dirs`
+    ///
+    pub fn load(sources: &[PathBuf], include_dirs: &[PathBuf]) -> Result<Self, LoadError> {
+        let mut st = SyntaxTree {
+            schemas: Vec::new(),
+            remarks: Vec::new(),
+            sources: Vec::new(),
+        };
+        let mut loaded = HashSet::new();
+        let mut queue: VecDeque<PathBuf> = sources.iter().cloned().collect();
+        let mut index = SchemaIndex::new(include_dirs);
+        while let Some(path) = queue.pop_front() {
+            // Same file may be specified by different paths
+            let canonical = path.canonicalize().unwrap_or_else(|_| path.clone());
+            if !loaded.insert(canonical) {
+                continue;
+            }
+            let mut parsed = Self::parse_file(&path)?;
+            st.schemas.append(&mut parsed.schemas);
+            st.remarks.append(&mut parsed.remarks);
+            st.sources.append(&mut parsed.sources);
+
+            for schema in &st.schemas {
+                for spec in &schema.interfaces {
+                    let name = match spec {
+                        InterfaceSpec::Use { name, .. } => name,
+                        InterfaceSpec::Reference { name, .. } => name,
+                    };
+                    if st
+                        .schemas
+                        .iter()
+                        .any(|schema| schema.name.eq_ignore_ascii_case(name))
+                    {
+                        continue;
+                    }
+                    match index.get(name)? {
+                        Some(path) => queue.push_back(path.clone()),
+                        None => {
+                            return Err(LoadError::SchemaNotFound {
+                                name: name.clone(),
+                                referrer: schema.name.clone(),
+                            })
+                        }
+                    }
+                }
+            }
+        }
+        Ok(st)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn declared_schemas() {
+        let input = r#"
+        (* SCHEMA in remark is ignored *)
+        SCHEMA first;
+        END_SCHEMA;
+          SCHEMA second_schema ;
+        END_SCHEMA;
+        SCHEMATIC;
+        schema
+          -- tail remark
+          (* embedded
+             remark *) Third; END_SCHEMA;
+        CONSTANT label : STRING := 'SCHEMA in string'; END_CONSTANT;
+        "#;
+        assert_eq!(
+            super::declared_schemas(input),
+            vec!["first", "second_schema", "Third"]
+        );
+    }
+}
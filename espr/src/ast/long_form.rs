@@ -0,0 +1,823 @@
+//! Expand a short form schema into the long form
+//!
+//! The long form of a schema is a single schema without interfaces,
+//! which consists of the declarations of the schema
+//! and the declarations interfaced into it by `USE FROM` and `REFERENCE FROM`.
+//! Declarations are collected as follows:
+//!
+//! - Every declaration in the schema and every explicitly interfaced declaration are included.
+//! - Declarations referred from included ones are implicitly interfaced (§11.4), and thus included.
+//! - Rules are included if all the entities in their `FOR` list are included,
+//!   and subtype constraints are included if their entity is included.
+//! - Declarations in interfaced schemas which are not referred in any of above are pruned.
+//!
+//! Names renamed by `AS` are replaced by the names visible in the expanded schema,
+//! and names qualified by schema in string literals, e.g. `'GEOMETRY_SCHEMA.CURVE'` for `TYPEOF`,
+//! are qualified by the expanded schema.
+//! Declarations of the same name redeclared in several schemas are expanded into one
+//! if they are the same, see [SyntaxTree::long_form].
+
+use super::{
+    interface::{visible, Item, Kind},
+    *,
+};
+use std::collections::{hash_map::Entry, BTreeSet, HashMap};
+use thiserror::Error;
+
+/// Error while expanding a schema by [SyntaxTree::long_form]
+#[derive(Debug, Error)]
+pub enum LongFormError {
+    #[error("Schema {name} is not found")]
+    SchemaNotFound { name: String },
+
+    #[error(transparent)]
+    Interface(#[from] InterfaceError),
+
+    #[error("Declarations in schema {first} and {second} are both expanded as {name}")]
+    NameConflict {
+        name: String,
+        first: String,
+        second: String,
+    },
+}
+
+/// Visit identifiers which may refer to declarations in schema, and string literals by `strings`
+///
+/// Names of attributes, parameters and local variables shadow the declarations in schema,
+/// and thus they are not visited.
+struct References<F, G> {
+    f: F,
+    strings: G,
+    locals: Vec<String>,
+}
+
+impl<F: FnMut(&mut String), G: FnMut(&mut String)> References<F, G> {
+    fn new(f: F, strings: G) -> Self {
+        References {
+            f,
+            strings,
+            locals: Vec::new(),
+        }
+    }
+
+    fn name(&mut self, name: &mut String) {
+        if !self.locals.contains(name) {
+            (self.f)(name)
+        }
+    }
+
+    fn scoped(&mut self, locals: impl IntoIterator<Item = String>, f: impl FnOnce(&mut Self)) {
+        let len = self.locals.len();
+        self.locals.extend(locals);
+        f(self);
+        self.locals.truncate(len);
+    }
+
+    fn ty(&mut self, ty: &mut Type) {
+        match ty {
+            Type::Named(name) => self.name(name),
+            Type::Set { base, bound } | Type::Bag { base, bound } => {
+                self.bound(bound);
+                self.ty(base);
+            }
+            Type::List { base, bound, .. } | Type::Array { base, bound, .. } => {
+                self.bound(bound);
+                self.ty(base);
+            }
+            Type::Enumeration { based_on, .. } => {
+                if let Some(based_on) = based_on {
+                    self.name(based_on);
+                }
+            }
+            Type::Select {
+                based_on, types, ..
+            } => {
+                if let Some(based_on) = based_on {
+                    self.name(based_on);
+                }
+                for ty in types {
+                    self.name(ty);
+                }
+            }
+            Type::Aggregate { base, .. } => self.ty(base),
+            Type::Simple(_) | Type::GenericEntity(_) | Type::Generic(_) => {}
+        }
+    }
+
+    fn bound(&mut self, bound: &mut Option<Bound>) {
+        if let Some(bound) = bound {
+            self.expr(&mut bound.lower);
+            self.expr(&mut bound.upper);
+        }
+    }
+
+    fn expr(&mut self, expr: &mut Expression) {
+        match expr {
+            Expression::Unary { arg, .. } => self.expr(arg),
+            Expression::Binary { arg1, arg2, .. } => {
+                self.expr(arg1);
+                self.expr(arg2);
+            }
+            Expression::Relation { lhs, rhs, .. } => {
+                self.expr(lhs);
+                self.expr(rhs);
+            }
+            Expression::Literal {
+                literal: Literal::String(value),
+                ..
+            } => (self.strings)(value),
+            Expression::Literal { .. } => {}
+            Expression::QualifiableFactor {
+                factor, qualifiers, ..
+            } => {
+                match factor {
+                    QualifiableFactor::Reference(name) => self.name(name),
+                    QualifiableFactor::BuiltInConstant(_) => {}
+                    QualifiableFactor::FunctionCall { name, args } => {
+                        if let FunctionCallName::Reference(name) = name {
+                            self.name(name);
+                        }
+                        for arg in args {
+                            self.expr(arg);
+                        }
+                    }
+                }
+                self.qualifiers(qualifiers);
+            }
+            Expression::EntityConstructor { name, values, .. } => {
+                self.name(name);
+                for value in values {
+                    self.expr(value);
+                }
+            }
+            Expression::Interval {
+                high, low, item, ..
+            } => {
+                self.expr(low);
+                self.expr(item);
+                self.expr(high);
+            }
+            Expression::EnumerationReference { ty, .. } => {
+                if let Some(ty) = ty {
+                    self.name(ty);
+                }
+            }
+            Expression::AggregateInitializer { elements, .. } => {
+                for element in elements {
+                    self.expr(&mut element.expr);
+                    if let Some(repetition) = &mut element.repetition {
+                        self.expr(repetition);
+                    }
+                }
+            }
+            Expression::Query {
+                variable,
+                source,
+                expr,
+                ..
+            } => {
+                self.expr(source);
+                self.scoped(Some(variable.clone()), |r| r.expr(expr));
+            }
+        }
+    }
+
+    fn qualifiers(&mut self, qualifiers: &mut [Qualifier]) {
+        for qualifier in qualifiers {
+            match qualifier {
+                Qualifier::Attribute(_) => {}
+                Qualifier::Group(name) => self.name(name),
+                Qualifier::Index(index) => self.expr(index),
+                Qualifier::Range { begin, end } => {
+                    self.expr(begin);
+                    self.expr(end);
+                }
+            }
+        }
+    }
+
+    fn where_clause(&mut self, where_clause: &mut WhereClause) {
+        for rule in &mut where_clause.rules {
+            self.expr(&mut rule.expr);
+        }
+    }
+
+    fn attribute_decl(&mut self, attr: &mut AttributeDecl) {
+        if let AttributeDecl::Qualified { group, .. } = attr {
+            self.name(group);
+        }
+    }
+
+    fn supertype_expr(&mut self, expr: &mut SuperTypeExpression) {
+        match expr {
+            SuperTypeExpression::Reference(name) => self.name(name),
+            SuperTypeExpression::AndOr { factors: exprs }
+            | SuperTypeExpression::And { terms: exprs }
+            | SuperTypeExpression::OneOf { exprs } => {
+                for expr in exprs {
+                    self.supertype_expr(expr);
+                }
+            }
+        }
+    }
+
+    fn type_decl(&mut self, decl: &mut TypeDecl) {
+        self.ty(&mut decl.underlying_type);
+        if let Some(where_clause) = &mut decl.where_clause {
+            self.where_clause(where_clause);
+        }
+    }
+
+    fn entity(&mut self, entity: &mut Entity) {
+        match &mut entity.constraint {
+            Some(Constraint::AbstractSuperType(Some(expr)))
+            | Some(Constraint::SuperTypeRule(expr)) => self.supertype_expr(expr),
+            _ => {}
+        }
+        if let Some(subtype_of) = &mut entity.subtype_of {
+            for name in &mut subtype_of.entity_references {
+                self.name(name);
+            }
+        }
+
+        let attribute_name = |attr: &AttributeDecl| match attr {
+            AttributeDecl::Reference(name) => name.clone(),
+            AttributeDecl::Qualified {
+                attribute, rename, ..
+            } => rename.as_ref().unwrap_or(attribute).clone(),
+        };
+        let mut attributes: Vec<String> = entity
+            .attributes
+            .iter()
+            .map(|attr| attribute_name(&attr.name))
+            .collect();
+        if let Some(derive) = &entity.derive_clause {
+            attributes.extend(
+                derive
+                    .attributes
+                    .iter()
+                    .map(|attr| attribute_name(&attr.attr)),
+            );
+        }
+        if let Some(inverse) = &entity.inverse_clause {
+            attributes.extend(
+                inverse
+                    .attributes
+                    .iter()
+                    .map(|attr| attribute_name(&attr.name)),
+            );
+        }
+
+        self.scoped(attributes, |r| {
+            for attr in &mut entity.attributes {
+                r.attribute_decl(&mut attr.name);
+                r.ty(&mut attr.ty);
+            }
+            if let Some(derive) = &mut entity.derive_clause {
+                for attr in &mut derive.attributes {
+                    r.attribute_decl(&mut attr.attr);
+                    r.ty(&mut attr.ty);
+                    r.expr(&mut attr.expr);
+                }
+            }
+            if let Some(inverse) = &mut entity.inverse_clause {
+                for attr in &mut inverse.attributes {
+                    r.attribute_decl(&mut attr.name);
+                    r.name(&mut attr.dest);
+                    if let Some(prefix) = &mut attr.attribute_prefix {
+                        r.name(prefix);
+                    }
+                    match &mut attr.dest_aggregation {
+                        AggregationOption::Set { bound } | AggregationOption::Bag { bound } => {
+                            r.bound(bound)
+                        }
+                        AggregationOption::None => {}
+                    }
+                }
+            }
+            if let Some(unique) = &mut entity.unique_clause {
+                for rule in &mut unique.rules {
+                    for attr in &mut rule.attributes {
+                        r.attribute_decl(attr);
+                    }
+                }
+            }
+            if let Some(where_clause) = &mut entity.where_clause {
+                r.where_clause(where_clause);
+            }
+        });
+    }
+
+    fn subtype_constraint(&mut self, constraint: &mut SubTypeConstraint) {
+        self.name(&mut constraint.entity);
+        for name in constraint.total_over.iter_mut().flatten() {
+            self.name(name);
+        }
+        if let Some(expr) = &mut constraint.expr {
+            self.supertype_expr(expr);
+        }
+    }
+
+    fn constants(&mut self, constants: &mut [Constant]) {
+        for constant in constants {
+            self.ty(&mut constant.ty);
+            self.expr(&mut constant.expr);
+        }
+    }
+
+    /// Declarations, constants, local variables and statements in function, procedure or rule
+    fn algorithm(
+        &mut self,
+        locals: Vec<String>,
+        declarations: &mut [Declaration],
+        constants: &mut [Constant],
+        variables: &mut [LocalVariable],
+        statements: &mut [Statement],
+    ) {
+        let mut locals = locals;
+        locals.extend(declarations.iter().map(|declaration| match declaration {
+            Declaration::Entity(entity) => entity.name.clone(),
+            Declaration::Type(ty) => ty.type_id.clone(),
+            Declaration::Function(function) => function.name.clone(),
+            Declaration::Procedure(procedure) => procedure.name.clone(),
+            Declaration::Rule(rule) => rule.name.clone(),
+            Declaration::SubTypeConstraint(constraint) => constraint.name.clone(),
+        }));
+        locals.extend(constants.iter().map(|constant| constant.name.clone()));
+        locals.extend(variables.iter().map(|var| var.name.clone()));
+        self.scoped(locals, |r| {
+            for declaration in declarations {
+                match declaration {
+                    Declaration::Entity(entity) => r.entity(entity),
+                    Declaration::Type(ty) => r.type_decl(ty),
+                    Declaration::Function(function) => r.function(function),
+                    Declaration::Procedure(procedure) => r.procedure(procedure),
+                    Declaration::Rule(rule) => r.rule(rule),
+                    Declaration::SubTypeConstraint(constraint) => r.subtype_constraint(constraint),
+                }
+            }
+            r.constants(constants);
+            for var in variables {
+                r.ty(&mut var.ty);
+                if let Some(expr) = &mut var.expr {
+                    r.expr(expr);
+                }
+            }
+            r.statements(statements);
+        });
+    }
+
+    fn parameters(&mut self, parameters: &mut [FormalParameter]) -> Vec<String> {
+        for param in parameters.iter_mut() {
+            self.ty(&mut param.ty);
+        }
+        parameters.iter().map(|param| param.name.clone()).collect()
+    }
+
+    fn function(&mut self, function: &mut Function) {
+        self.ty(&mut function.return_type);
+        let locals = self.parameters(&mut function.parameters);
+        self.algorithm(
+            locals,
+            &mut function.declarations,
+            &mut function.constants,
+            &mut function.variables,
+            &mut function.statements,
+        );
+    }
+
+    fn procedure(&mut self, procedure: &mut Procedure) {
+        let locals = self.parameters(&mut procedure.parameters);
+        self.algorithm(
+            locals,
+            &mut procedure.declarations,
+            &mut procedure.constants,
+            &mut procedure.variables,
+            &mut procedure.statements,
+        );
+    }
+
+    fn rule(&mut self, rule: &mut Rule) {
+        for name in &mut rule.references {
+            self.name(name);
+        }
+        self.algorithm(
+            Vec::new(),
+            &mut rule.declarations,
+            &mut rule.constants,
+            &mut rule.variables,
+            &mut rule.statements,
+        );
+        // Constants and local variables are also visible in WHERE clause of rule
+        let constants = rule.constants.iter().map(|constant| constant.name.clone());
+        let variables = rule.variables.iter().map(|var| var.name.clone());
+        let locals: Vec<_> = constants.chain(variables).collect();
+        self.scoped(locals, |r| r.where_clause(&mut rule.where_clause));
+    }
+
+    fn statements(&mut self, statements: &mut [Statement]) {
+        for statement in statements {
+            self.statement(statement);
+        }
+    }
+
+    fn statement(&mut self, statement: &mut Statement) {
+        match statement {
+            Statement::Alias {
+                name,
+                dest,
+                qualifiers,
+                statements,
+            } => {
+                self.name(dest);
+                self.qualifiers(qualifiers);
+                self.scoped(Some(name.clone()), |r| r.statements(statements));
+            }
+            Statement::Assignment {
+                name,
+                qualifiers,
+                expr,
+            } => {
+                self.name(name);
+                self.qualifiers(qualifiers);
+                self.expr(expr);
+            }
+            Statement::Compound { statements } => self.statements(statements),
+            Statement::If {
+                condition,
+                then_branch,
+                else_branch,
+            } => {
+                self.expr(condition);
+                self.statements(then_branch);
+                if let Some(else_branch) = else_branch {
+                    self.statements(else_branch);
+                }
+            }
+            Statement::Case {
+                selector,
+                actions,
+                otherwise,
+            } => {
+                self.expr(selector);
+                for (labels, statement) in actions {
+                    for label in labels {
+                        self.expr(label);
+                    }
+                    self.statement(statement);
+                }
+                if let Some(otherwise) = otherwise {
+                    self.statement(otherwise);
+                }
+            }
+            Statement::Repeat {
+                control,
+                statements,
+            } => {
+                let mut locals = Vec::new();
+                if let Some(increment) = &mut control.increment {
+                    self.expr(&mut increment.begin);
+                    self.expr(&mut increment.end);
+                    if let Some(by) = &mut increment.increment {
+                        self.expr(by);
+                    }
+                    locals.push(increment.variable.clone());
+                }
+                self.scoped(locals, |r| {
+                    if let Some(while_) = &mut control.while_ {
+                        r.expr(while_);
+                    }
+                    if let Some(until) = &mut control.until {
+                        r.expr(until);
+                    }
+                    r.statements(statements);
+                });
+            }
+            Statement::Return { value } => {
+                if let Some(value) = value {
+                    self.expr(value);
+                }
+            }
+            Statement::ProcedureCall {
+                procedure,
+                parameters,
+            } => {
+                if let ProcedureCallName::Reference(name) = procedure {
+                    self.name(name);
+                }
+                for param in parameters.iter_mut().flatten() {
+                    self.expr(param);
+                }
+            }
+            Statement::Skip | Statement::Escape | Statement::Null => {}
+        }
+    }
+}
+
+/// Declaration copied from the syntax tree
+#[derive(PartialEq)]
+enum Decl {
+    Constant(Constant),
+    Type(TypeDecl),
+    Entity(Entity),
+    SubTypeConstraint(SubTypeConstraint),
+    Function(Function),
+    Procedure(Procedure),
+    Rule(Rule),
+}
+
+/// Declared name of the item
+fn declared_name(st: &SyntaxTree, (schema, kind, index): Item) -> &str {
+    let schema = &st.schemas[schema];
+    match kind {
+        Kind::Constant => &schema.constants[index].name,
+        Kind::Type => &schema.types[index].type_id,
+        Kind::Entity => &schema.entities[index].name,
+        Kind::SubTypeConstraint => &schema.subtype_constraints[index].name,
+        Kind::Function => &schema.functions[index].name,
+        Kind::Procedure => &schema.procedures[index].name,
+        Kind::Rule => &schema.rules[index].name,
+    }
+}
+
+impl Decl {
+    fn get(st: &SyntaxTree, (schema, kind, index): Item) -> Self {
+        let schema = &st.schemas[schema];
+        match kind {
+            Kind::Constant => Decl::Constant(schema.constants[index].clone()),
+            Kind::Type => Decl::Type(schema.types[index].clone()),
+            Kind::Entity => Decl::Entity(schema.entities[index].clone()),
+            Kind::SubTypeConstraint => {
+                Decl::SubTypeConstraint(schema.subtype_constraints[index].clone())
+            }
+            Kind::Function => Decl::Function(schema.functions[index].clone()),
+            Kind::Procedure => Decl::Procedure(schema.procedures[index].clone()),
+            Kind::Rule => Decl::Rule(schema.rules[index].clone()),
+        }
+    }
+
+    fn rename(&mut self, name: &str) {
+        *self.name_mut() = name.to_string();
+    }
+
+    fn name_mut(&mut self) -> &mut String {
+        match self {
+            Decl::Constant(constant) => &mut constant.name,
+            Decl::Type(ty) => &mut ty.type_id,
+            Decl::Entity(entity) => &mut entity.name,
+            Decl::SubTypeConstraint(constraint) => &mut constraint.name,
+            Decl::Function(function) => &mut function.name,
+            Decl::Procedure(procedure) => &mut procedure.name,
+            Decl::Rule(rule) => &mut rule.name,
+        }
+    }
+
+    /// Visit identifiers referring declarations in schema
+    fn references(&mut self, f: impl FnMut(&mut String)) {
+        self.visit(f, |_| {})
+    }
+
+    /// Visit identifiers referring declarations in schema, and string literals
+    fn visit(&mut self, f: impl FnMut(&mut String), strings: impl FnMut(&mut String)) {
+        let mut r = References::new(f, strings);
+        match self {
+            Decl::Constant(constant) => r.constants(std::slice::from_mut(constant)),
+            Decl::Type(ty) => r.type_decl(ty),
+            Decl::Entity(entity) => r.entity(entity),
+            Decl::SubTypeConstraint(constraint) => r.subtype_constraint(constraint),
+            Decl::Function(function) => r.function(function),
+            Decl::Procedure(procedure) => r.procedure(procedure),
+            Decl::Rule(rule) => r.rule(rule),
+        }
+    }
+}
+
+impl SyntaxTree {
+    /// Expand the schema of `name` into the long form
+    ///
+    /// ```
+    /// use espr::ast::*;
+    ///
+    /// let st = SyntaxTree::parse(
+    ///     r#"
+    ///     SCHEMA shape;
+    ///       USE FROM geometry (point AS vertex);
+    ///       ENTITY edge; head, tail: vertex; END_ENTITY;
+    ///     END_SCHEMA;
+    ///
+    ///     SCHEMA geometry;
+    ///       ENTITY point; x, y: REAL; END_ENTITY;
+    ///       ENTITY unused; END_ENTITY;
+    ///     END_SCHEMA;
+    ///     "#,
+    /// )
+    /// .unwrap();
+    /// let long_form = st.long_form("shape").unwrap();
+    /// assert!(long_form.interfaces.is_empty());
+    /// let entities: Vec<_> = long_form.entities.iter().map(|e| e.name.as_str()).collect();
+    /// assert_eq!(entities, vec!["edge", "vertex"]);
+    /// ```
+    ///
+    /// Error
+    /// ------
+    /// - [LongFormError::SchemaNotFound] if the schema is not in this syntax tree
+    /// - [LongFormError::Interface] if the interfaces of the schemas cannot be resolved,
+    ///   e.g. an interfaced schema or resource is not found
+    /// - [LongFormError::NameConflict] if different declarations are expanded into the same name.
+    ///   Constants, types and entities equal after the expansion are not regarded as different,
+    ///   and functions, procedures and rules of the same name are regarded as the same one.
+    ///
+    pub fn long_form(&self, name: &str) -> Result<Schema, LongFormError> {
+        let root = self
+            .schemas
+            .iter()
+            .position(|schema| schema.name.eq_ignore_ascii_case(name))
+            .ok_or_else(|| LongFormError::SchemaNotFound {
+                name: name.to_string(),
+            })?;
+        let visible = visible(self)?;
+
+        // Items declared in or interfaced into the root schema
+        let mut included: BTreeSet<Item> = visible[root].values().cloned().collect();
+        included.extend(
+            (0..self.schemas[root].subtype_constraints.len())
+                .map(|i| (root, Kind::SubTypeConstraint, i)),
+        );
+        let resolve = |(schema, _, _): Item, name: &str| visible[schema].get(name).cloned();
+        let mut stack: Vec<Item> = included.iter().cloned().collect();
+        loop {
+            while let Some(item) = stack.pop() {
+                Decl::get(self, item).references(|name| {
+                    if let Some(referred) = resolve(item, name) {
+                        if included.insert(referred) {
+                            stack.push(referred);
+                        }
+                    }
+                });
+            }
+            // Rules and subtype constraints on the included entities
+            for (i, schema) in self.schemas.iter().enumerate() {
+                let is_included = |name: &str| {
+                    visible[i]
+                        .get(name)
+                        .map_or(false, |item| included.contains(item))
+                };
+                for (j, rule) in schema.rules.iter().enumerate() {
+                    if rule.references.iter().all(|name| is_included(name)) {
+                        stack.push((i, Kind::Rule, j));
+                    }
+                }
+                for (j, constraint) in schema.subtype_constraints.iter().enumerate() {
+                    if is_included(&constraint.entity) {
+                        stack.push((i, Kind::SubTypeConstraint, j));
+                    }
+                }
+            }
+            stack.retain(|item| !included.contains(item));
+            if stack.is_empty() {
+                break;
+            }
+            included.extend(stack.iter().cloned());
+        }
+
+        // Names in the root schema are used for the renamed declarations.
+        // If a declaration is visible by several names, the declared name or the first one is used.
+        let mut names: HashMap<Item, String> = HashMap::new();
+        let mut root_names: Vec<_> = visible[root].iter().collect();
+        root_names.sort();
+        for (alias, item) in root_names {
+            if alias == declared_name(self, *item) {
+                names.insert(*item, alias.clone());
+            } else {
+                names.entry(*item).or_insert_with(|| alias.clone());
+            }
+        }
+        for item in &included {
+            names
+                .entry(*item)
+                .or_insert_with(|| declared_name(self, *item).to_string());
+        }
+        // Names qualified by schema in string literals, e.g. `'GEOMETRY_SCHEMA.CURVE'` for `TYPEOF`
+        // or `'GEOMETRY_SCHEMA.CURVE.ATTR'` for `USEDIN`, are qualified by the root schema.
+        // A schema name alone, e.g. `'GEOMETRY_SCHEMA.'` to be concatenated, is also replaced.
+        let root_name = self.schemas[root].name.to_uppercase();
+        let qualified = |value: &mut String| {
+            let (schema, rest) = match value.split_once('.') {
+                Some(pair) => pair,
+                None => return,
+            };
+            let schema = match self
+                .schemas
+                .iter()
+                .position(|s| s.name.eq_ignore_ascii_case(schema))
+            {
+                Some(schema) => schema,
+                None => return,
+            };
+            if rest.is_empty() {
+                *value = format!("{}.", root_name);
+                return;
+            }
+            let (name, attribute) = match rest.split_once('.') {
+                Some((name, attribute)) => (name, Some(attribute)),
+                None => (rest, None),
+            };
+            // Implicitly interfaced ones, e.g. supertypes, are not visible in the schema
+            let renamed = visible[schema]
+                .get(&name.to_lowercase())
+                .and_then(|item| names.get(item))
+                .or_else(|| names.values().find(|n| n.eq_ignore_ascii_case(name)));
+            if let Some(renamed) = renamed {
+                *value = match attribute {
+                    Some(attribute) => {
+                        format!("{}.{}.{}", root_name, renamed.to_uppercase(), attribute)
+                    }
+                    None => format!("{}.{}", root_name, renamed.to_uppercase()),
+                };
+            }
+        };
+
+        // Declaration renamed and referring the renamed declarations
+        let expand = |item: Item| {
+            let mut decl = Decl::get(self, item);
+            decl.rename(&names[&item]);
+            decl.visit(
+                |name| {
+                    if let Some(referred) = resolve(item, name) {
+                        if let Some(renamed) = names.get(&referred) {
+                            *name = renamed.clone();
+                        }
+                    }
+                },
+                qualified,
+            );
+            decl
+        };
+
+        // Schemas may redeclare a declaration of another schema,
+        // e.g. AIM schemas copy constants from integrated resources,
+        // and AIC schemas declare functions of the same name for their own entities.
+        // Such declarations are expanded into one, and the one in root schema or the first one is kept.
+        // Constants, types and entities are regarded as the same only if they are expanded equally,
+        // while functions, procedures and rules of the same name are regarded as the same algorithm.
+        // Copies are at different positions in the source, so their spans are not compared.
+        let is_same = |first: Item, second: Item| {
+            first.1 == second.1
+                && (matches!(first.1, Kind::Function | Kind::Procedure | Kind::Rule)
+                    || Span::ignored(|| expand(first) == expand(second)))
+        };
+        let mut owners: HashMap<(Kind, &str), Item> = HashMap::new();
+        // Declarations of the root schema come first
+        let mut items: Vec<Item> = Vec::new();
+        let mut ordered: Vec<Item> = included.iter().cloned().collect();
+        ordered.sort_by_key(|&(schema, kind, index)| (schema != root, schema, kind, index));
+        for item in ordered {
+            // Subtype constraints are not referred by name
+            let kind = match item.1 {
+                Kind::SubTypeConstraint => Kind::SubTypeConstraint,
+                _ => Kind::Entity,
+            };
+            match owners.entry((kind, names[&item].as_str())) {
+                Entry::Vacant(entry) => {
+                    entry.insert(item);
+                    items.push(item);
+                }
+                Entry::Occupied(entry) if is_same(*entry.get(), item) => {}
+                Entry::Occupied(entry) => {
+                    return Err(LongFormError::NameConflict {
+                        name: names[&item].clone(),
+                        first: self.schemas[entry.get().0].name.clone(),
+                        second: self.schemas[item.0].name.clone(),
+                    });
+                }
+            }
+        }
+
+        let mut long_form = Schema {
+            name: self.schemas[root].name.clone(),
+            entities: Vec::new(),
+            types: Vec::new(),
+            functions: Vec::new(),
+            procedures: Vec::new(),
+            rules: Vec::new(),
+            constants: Vec::new(),
+            interfaces: Vec::new(),
+            subtype_constraints: Vec::new(),
+            span: Span::default(),
+        };
+        for item in items {
+            match expand(item) {
+                Decl::Constant(constant) => long_form.constants.push(constant),
+                Decl::Type(ty) => long_form.types.push(ty),
+                Decl::Entity(entity) => long_form.entities.push(entity),
+                Decl::SubTypeConstraint(constraint) => {
+                    long_form.subtype_constraints.push(constraint)
+                }
+                Decl::Function(function) => long_form.functions.push(function),
+                Decl::Procedure(procedure) => long_form.procedures.push(procedure),
+                Decl::Rule(rule) => long_form.rules.push(rule),
+            }
+        }
+        Ok(long_form)
+    }
+}
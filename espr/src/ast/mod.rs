@@ -4,6 +4,10 @@ mod algorithm;
 mod entity;
 mod error;
 mod expression;
+pub(crate) mod interface;
+mod loader;
+mod long_form;
+mod printer;
 mod schema;
 mod types;
 
@@ -11,6 +15,9 @@ pub use algorithm::*;
 pub use entity::*;
 pub use error::*;
 pub use expression::*;
+pub use interface::InterfaceError;
+pub use loader::*;
+pub use long_form::*;
 pub use schema::*;
 pub use types::*;
 
@@ -50,11 +57,128 @@ pub struct Remark {
     pub remark: String,
 }
 
+impl Remark {
+    /// Check if this remark is tagged to the item of `path` in `schema`
+    ///
+    /// The tag `(*"circle.radius" ... *)` refers the path `["circle", "radius"]`,
+    /// and it may be qualified by the schema name as `(*"shapes.circle.radius" ... *)`.
+    pub fn is_tagged(&self, schema: &str, path: &[&str]) -> bool {
+        let tag = match &self.tag {
+            Some(tag) => tag.as_slice(),
+            None => return false,
+        };
+        let tag = match tag.split_first() {
+            Some((first, rest))
+                if tag.len() == path.len() + 1 && first.eq_ignore_ascii_case(schema) =>
+            {
+                rest
+            }
+            _ => tag,
+        };
+        tag.len() == path.len()
+            && tag
+                .iter()
+                .zip(path)
+                .all(|(tag, name)| tag.eq_ignore_ascii_case(name))
+    }
+}
+
+/// Position of an AST node in EXPRESS input
+///
+/// The parser only sees the remaining input,
+/// and thus the position is recorded as the lengths of the input remaining
+/// at the start and the end of the node.
+/// Use [Span::range] with the entire input to get the byte range.
+///
+/// AST nodes parsed from different inputs have different spans,
+/// and use [Span::ignored] to compare them regardless of their positions.
+#[derive(Debug, Clone, Copy, Default, Eq)]
+pub struct Span {
+    remaining_start: usize,
+    remaining_end: usize,
+}
+
+thread_local! {
+    static IGNORE_SPANS: std::cell::Cell<bool> = std::cell::Cell::new(false);
+}
+
+impl PartialEq for Span {
+    fn eq(&self, other: &Self) -> bool {
+        IGNORE_SPANS.with(|ignore| ignore.get())
+            || (self.remaining_start == other.remaining_start
+                && self.remaining_end == other.remaining_end)
+    }
+}
+
+impl Span {
+    pub fn new(remaining_start: usize, remaining_end: usize) -> Self {
+        Span {
+            remaining_start,
+            remaining_end,
+        }
+    }
+
+    /// Byte range in the entire `input` where this span is recorded
+    ///
+    /// ```
+    /// use espr::ast::*;
+    ///
+    /// let input = "SCHEMA s; ENTITY a; x: REAL; END_ENTITY; END_SCHEMA;";
+    /// let st = SyntaxTree::parse(input).unwrap();
+    /// let span = st.schemas[0].entities[0].span;
+    /// assert_eq!(&input[span.range(input)], "ENTITY a; x: REAL; END_ENTITY;");
+    /// ```
+    pub fn range(&self, input: &str) -> std::ops::Range<usize> {
+        let len = input.len();
+        len.saturating_sub(self.remaining_start)..len.saturating_sub(self.remaining_end)
+    }
+
+    /// Span from the start of this span to the end of `end`
+    pub fn to(&self, end: Span) -> Span {
+        Span::new(self.remaining_start, end.remaining_end)
+    }
+
+    /// Evaluate `f` regarding every span as equal,
+    /// e.g. to compare AST nodes parsed from different inputs
+    ///
+    /// ```
+    /// use espr::ast::*;
+    ///
+    /// let st1 = SyntaxTree::parse("SCHEMA s; ENTITY a; END_ENTITY; END_SCHEMA;").unwrap();
+    /// let st2 = SyntaxTree::parse("SCHEMA s;\n  ENTITY a;\n  END_ENTITY;\nEND_SCHEMA;").unwrap();
+    /// assert_ne!(st1.schemas, st2.schemas);
+    /// assert!(Span::ignored(|| st1.schemas == st2.schemas));
+    /// ```
+    pub fn ignored<T>(f: impl FnOnce() -> T) -> T {
+        // Restore the flag even if `f` panics, e.g. by a failed assertion
+        struct Restore(bool);
+        impl Drop for Restore {
+            fn drop(&mut self) {
+                IGNORE_SPANS.with(|ignore| ignore.set(self.0));
+            }
+        }
+        let _restore = Restore(IGNORE_SPANS.with(|ignore| ignore.replace(true)));
+        f()
+    }
+}
+
+/// EXPRESS input which [SyntaxTree] is parsed from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Source {
+    /// Path of the file, or `None` if parsed from a string by [SyntaxTree::parse]
+    pub path: Option<std::path::PathBuf>,
+    pub text: String,
+    /// Names of the schemas declared in this input
+    pub schemas: Vec<String>,
+}
+
 /// Entire syntax tree parsed from EXPRESS Language string
 #[derive(Debug, Clone, PartialEq)]
 pub struct SyntaxTree {
     pub schemas: Vec<Schema>,
     pub remarks: Vec<Remark>,
+    /// Inputs to resolve [Span] of AST nodes
+    pub sources: Vec<Source>,
 }
 
 impl SyntaxTree {
@@ -63,8 +187,41 @@ impl SyntaxTree {
             .map(|(_start_space, schemas, _end_space)| schemas)
             .parse(input)
             .finish()?;
-        assert!(residual.is_empty());
-        Ok(SyntaxTree { schemas, remarks })
+        if !residual.is_empty() {
+            // Remaining input cannot be parsed as a schema
+            return Err(nom::error::VerboseError {
+                errors: vec![(
+                    residual,
+                    nom::error::VerboseErrorKind::Context("schema_decl"),
+                )],
+            });
+        }
+        let source = Source {
+            path: None,
+            text: input.to_string(),
+            schemas: schemas.iter().map(|schema| schema.name.clone()).collect(),
+        };
+        Ok(SyntaxTree {
+            schemas,
+            remarks,
+            sources: vec![source],
+        })
+    }
+
+    /// Source where the schema of `name` is declared
+    pub fn source(&self, schema: &str) -> Option<&Source> {
+        self.sources
+            .iter()
+            .find(|source| source.schemas.iter().any(|name| name == schema))
+    }
+
+    /// Text of the remarks tagged to the item of `path` in `schema`, see [Remark::is_tagged]
+    pub fn remarks_of(&self, schema: &str, path: &[&str]) -> Vec<&str> {
+        self.remarks
+            .iter()
+            .filter(|remark| remark.is_tagged(schema, path))
+            .map(|remark| remark.remark.as_str())
+            .collect()
     }
 
     // Example syntax tree for easy testing
@@ -128,4 +285,34 @@ mod tests {
         dbg!(&st);
         assert_eq!(st.remarks.len(), 6);
     }
+
+    #[test]
+    fn remarks_of() {
+        let st = super::SyntaxTree::parse(
+            r#"
+            SCHEMA shapes;
+              ENTITY circle;
+                radius : REAL;
+              END_ENTITY;
+            END_SCHEMA;
+            (*"circle" Circle in a plane *)
+            (*"shapes.circle.radius" Radius of the circle *)
+            -- "circle.radius" must be positive
+            "#,
+        )
+        .unwrap();
+        assert_eq!(
+            st.remarks_of("shapes", &["circle"]),
+            vec!["Circle in a plane"]
+        );
+        assert_eq!(
+            st.remarks_of("shapes", &["circle", "radius"]),
+            vec!["Radius of the circle", "must be positive"]
+        );
+        // Unqualified tags are not distinguished by schemas
+        assert_eq!(
+            st.remarks_of("geometry", &["circle", "radius"]),
+            vec!["must be positive"]
+        );
+    }
 }
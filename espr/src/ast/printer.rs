@@ -0,0 +1,1068 @@
+//! Print AST as EXPRESS source in canonical layout
+//!
+//! Every AST node implements [Display] to print itself as EXPRESS source.
+//! Expressions and types are printed in a single line,
+//! and declarations are printed in multiple lines with two-space indentation:
+//!
+//! ```
+//! use espr::ast::*;
+//!
+//! let st = SyntaxTree::parse(
+//!     "SCHEMA s; ENTITY a SUBTYPE OF(b); x,y:REAL; WHERE (x+y)*2>0; END_ENTITY; END_SCHEMA;",
+//! )
+//! .unwrap();
+//! assert_eq!(
+//!     st.to_string(),
+//!     r#"SCHEMA s;
+//!
+//!   ENTITY a
+//!     SUBTYPE OF (b);
+//!     x : REAL;
+//!     y : REAL;
+//!   WHERE
+//!     (x + y) * 2 > 0;
+//!   END_ENTITY;
+//!
+//! END_SCHEMA;
+//! "#
+//! );
+//! ```
+//!
+//! Parsing the output yields the same AST.
+//! Since the positions of remarks are not recorded in AST,
+//! remarks in [SyntaxTree::remarks] are printed after all schemas.
+
+use super::*;
+use itertools::Itertools;
+use std::fmt::{self, Display, Formatter, Write};
+
+/// Declarations longer than this are broken into multiple lines if possible
+const WIDTH: usize = 80;
+
+/// Writer of multi-line declarations with indentation
+#[derive(Default)]
+struct Printer {
+    buf: String,
+    depth: usize,
+}
+
+impl Printer {
+    fn line(&mut self, line: impl Display) {
+        for _ in 0..self.depth {
+            self.buf.push_str("  ");
+        }
+        writeln!(self.buf, "{}", line).expect("Writing into String never fails");
+    }
+
+    fn blank(&mut self) {
+        self.buf.push('\n');
+    }
+
+    fn indented(&mut self, f: impl FnOnce(&mut Self)) {
+        self.depth += 1;
+        f(self);
+        self.depth -= 1;
+    }
+
+    fn syntax_tree(&mut self, st: &SyntaxTree) {
+        for (i, schema) in st.schemas.iter().enumerate() {
+            if i != 0 {
+                self.blank();
+            }
+            self.schema(schema);
+        }
+        if !st.remarks.is_empty() {
+            self.blank();
+        }
+        for remark in &st.remarks {
+            self.remark(remark);
+        }
+    }
+
+    fn schema(&mut self, schema: &Schema) {
+        self.line(format_args!("SCHEMA {};", schema.name));
+        self.indented(|p| {
+            if !schema.interfaces.is_empty() {
+                p.blank();
+            }
+            for interface in &schema.interfaces {
+                p.line(interface);
+            }
+            if !schema.constants.is_empty() {
+                p.blank();
+                p.constants(&schema.constants);
+            }
+            for ty in &schema.types {
+                p.blank();
+                p.type_decl(ty);
+            }
+            for entity in &schema.entities {
+                p.blank();
+                p.entity(entity);
+            }
+            for constraint in &schema.subtype_constraints {
+                p.blank();
+                p.subtype_constraint(constraint);
+            }
+            for function in &schema.functions {
+                p.blank();
+                p.function(function);
+            }
+            for procedure in &schema.procedures {
+                p.blank();
+                p.procedure(procedure);
+            }
+            for rule in &schema.rules {
+                p.blank();
+                p.rule(rule);
+            }
+        });
+        self.blank();
+        self.line("END_SCHEMA;");
+    }
+
+    fn remark(&mut self, remark: &Remark) {
+        let tag = match &remark.tag {
+            Some(tag) => format!("\"{}\" ", tag.join(".")),
+            None => String::new(),
+        };
+        // Tail remark cannot contain line break, and embedded remark cannot contain `*)` or single "`"
+        if remark.remark.contains('\n') {
+            self.line(format_args!("(* {}{} *)", tag, remark.remark));
+        } else {
+            self.line(format_args!("-- {}{}", tag, remark.remark));
+        }
+    }
+
+    fn constants(&mut self, constants: &[Constant]) {
+        self.line("CONSTANT");
+        self.indented(|p| {
+            for constant in constants {
+                p.line(format_args!(
+                    "{} : {} := {};",
+                    constant.name, constant.ty, constant.expr
+                ));
+            }
+        });
+        self.line("END_CONSTANT;");
+    }
+
+    fn declaration(&mut self, declaration: &Declaration) {
+        match declaration {
+            Declaration::Entity(entity) => self.entity(entity),
+            Declaration::Type(ty) => self.type_decl(ty),
+            Declaration::Function(function) => self.function(function),
+            Declaration::Procedure(procedure) => self.procedure(procedure),
+            Declaration::Rule(rule) => self.rule(rule),
+            Declaration::SubTypeConstraint(constraint) => self.subtype_constraint(constraint),
+        }
+    }
+
+    fn type_decl(&mut self, decl: &TypeDecl) {
+        let head = format!("TYPE {} = ", decl.type_id);
+        let underlying = decl.underlying_type.to_string();
+        match constructed_head(&decl.underlying_type) {
+            // Break enumeration items or select list into lines
+            Some((prefix, items))
+                if head.len() + underlying.len() + 1 > WIDTH && !items.is_empty() =>
+            {
+                self.line(format_args!("{}{}", head, prefix));
+                self.indented(|p| {
+                    for (i, item) in items.iter().enumerate() {
+                        let open = if i == 0 { "(" } else { " " };
+                        let close = if i + 1 == items.len() { ");" } else { "," };
+                        p.line(format_args!("{}{}{}", open, item, close));
+                    }
+                });
+            }
+            _ => self.line(format_args!("{}{};", head, underlying)),
+        }
+        if let Some(where_clause) = &decl.where_clause {
+            self.where_clause(where_clause);
+        }
+        self.line("END_TYPE;");
+    }
+
+    fn entity(&mut self, entity: &Entity) {
+        let mut head = Vec::new();
+        if let Some(constraint) = &entity.constraint {
+            head.push(constraint.to_string());
+        }
+        if let Some(subtype_of) = &entity.subtype_of {
+            head.push(format!(
+                "SUBTYPE OF ({})",
+                subtype_of.entity_references.join(", ")
+            ));
+        }
+        if head.is_empty() {
+            self.line(format_args!("ENTITY {};", entity.name));
+        } else {
+            self.line(format_args!("ENTITY {}", entity.name));
+            self.indented(|p| {
+                let last = head.len() - 1;
+                for (i, line) in head.iter().enumerate() {
+                    p.line(format_args!("{}{}", line, if i == last { ";" } else { "" }));
+                }
+            });
+        }
+
+        self.indented(|p| {
+            for attr in &entity.attributes {
+                p.line(format_args!(
+                    "{} : {}{};",
+                    attr.name,
+                    if attr.optional { "OPTIONAL " } else { "" },
+                    attr.ty
+                ));
+            }
+        });
+        if let Some(derive) = &entity.derive_clause {
+            self.line("DERIVE");
+            self.indented(|p| {
+                for attr in &derive.attributes {
+                    p.line(format_args!(
+                        "{} : {} := {};",
+                        attr.attr, attr.ty, attr.expr
+                    ));
+                }
+            });
+        }
+        if let Some(inverse) = &entity.inverse_clause {
+            self.line("INVERSE");
+            self.indented(|p| {
+                for attr in &inverse.attributes {
+                    p.line(attr);
+                }
+            });
+        }
+        if let Some(unique) = &entity.unique_clause {
+            self.line("UNIQUE");
+            self.indented(|p| {
+                for rule in &unique.rules {
+                    let label = match &rule.name {
+                        Some(name) => format!("{} : ", name),
+                        None => String::new(),
+                    };
+                    p.line(format_args!(
+                        "{}{};",
+                        label,
+                        rule.attributes.iter().join(", ")
+                    ));
+                }
+            });
+        }
+        if let Some(where_clause) = &entity.where_clause {
+            self.where_clause(where_clause);
+        }
+        self.line("END_ENTITY;");
+    }
+
+    fn subtype_constraint(&mut self, constraint: &SubTypeConstraint) {
+        self.line(format_args!(
+            "SUBTYPE_CONSTRAINT {} FOR {};",
+            constraint.name, constraint.entity
+        ));
+        self.indented(|p| {
+            if constraint.is_abstract {
+                p.line("ABSTRACT SUPERTYPE;");
+            }
+            if let Some(total_over) = &constraint.total_over {
+                p.line(format_args!("TOTAL_OVER ({});", total_over.join(", ")));
+            }
+            if let Some(expr) = &constraint.expr {
+                p.line(format_args!("{};", expr));
+            }
+        });
+        self.line("END_SUBTYPE_CONSTRAINT;");
+    }
+
+    fn where_clause(&mut self, where_clause: &WhereClause) {
+        self.line("WHERE");
+        self.indented(|p| {
+            for rule in &where_clause.rules {
+                match &rule.label {
+                    Some(label) => p.line(format_args!("{} : {};", label, rule.expr)),
+                    None => p.line(format_args!("{};", rule.expr)),
+                }
+            }
+        });
+    }
+
+    fn algorithm_head(
+        &mut self,
+        declarations: &[Declaration],
+        constants: &[Constant],
+        variables: &[LocalVariable],
+    ) {
+        for declaration in declarations {
+            self.declaration(declaration);
+        }
+        if !constants.is_empty() {
+            self.constants(constants);
+        }
+        if !variables.is_empty() {
+            self.line("LOCAL");
+            self.indented(|p| {
+                for var in variables {
+                    match &var.expr {
+                        Some(expr) => {
+                            p.line(format_args!("{} : {} := {};", var.name, var.ty, expr))
+                        }
+                        None => p.line(format_args!("{} : {};", var.name, var.ty)),
+                    }
+                }
+            });
+            self.line("END_LOCAL;");
+        }
+    }
+
+    fn function(&mut self, function: &Function) {
+        self.line(format_args!(
+            "FUNCTION {}{} : {};",
+            function.name,
+            parameters(&function.parameters),
+            function.return_type
+        ));
+        self.indented(|p| {
+            p.algorithm_head(
+                &function.declarations,
+                &function.constants,
+                &function.variables,
+            );
+            p.statements(&function.statements);
+        });
+        self.line("END_FUNCTION;");
+    }
+
+    fn procedure(&mut self, procedure: &Procedure) {
+        self.line(format_args!(
+            "PROCEDURE {}{};",
+            procedure.name,
+            parameters(&procedure.parameters)
+        ));
+        self.indented(|p| {
+            p.algorithm_head(
+                &procedure.declarations,
+                &procedure.constants,
+                &procedure.variables,
+            );
+            p.statements(&procedure.statements);
+        });
+        self.line("END_PROCEDURE;");
+    }
+
+    fn rule(&mut self, rule: &Rule) {
+        self.line(format_args!(
+            "RULE {} FOR ({});",
+            rule.name,
+            rule.references.join(", ")
+        ));
+        self.indented(|p| {
+            p.algorithm_head(&rule.declarations, &rule.constants, &rule.variables);
+            p.statements(&rule.statements);
+        });
+        self.where_clause(&rule.where_clause);
+        self.line("END_RULE;");
+    }
+
+    fn statements(&mut self, statements: &[Statement]) {
+        for statement in statements {
+            self.statement(statement);
+        }
+    }
+
+    fn statement(&mut self, statement: &Statement) {
+        match statement {
+            Statement::Alias {
+                name,
+                dest,
+                qualifiers,
+                statements,
+            } => {
+                self.line(format_args!(
+                    "ALIAS {} FOR {}{};",
+                    name,
+                    dest,
+                    qualifiers.iter().join("")
+                ));
+                self.indented(|p| p.statements(statements));
+                self.line("END_ALIAS;");
+            }
+            Statement::Assignment {
+                name,
+                qualifiers,
+                expr,
+            } => self.line(format_args!(
+                "{}{} := {};",
+                name,
+                qualifiers.iter().join(""),
+                expr
+            )),
+            Statement::Compound { statements } => {
+                self.line("BEGIN");
+                self.indented(|p| p.statements(statements));
+                self.line("END;");
+            }
+            Statement::If {
+                condition,
+                then_branch,
+                else_branch,
+            } => {
+                self.line(format_args!("IF {} THEN", condition));
+                self.indented(|p| p.statements(then_branch));
+                if let Some(else_branch) = else_branch {
+                    self.line("ELSE");
+                    self.indented(|p| p.statements(else_branch));
+                }
+                self.line("END_IF;");
+            }
+            Statement::Case {
+                selector,
+                actions,
+                otherwise,
+            } => {
+                self.line(format_args!("CASE {} OF", selector));
+                self.indented(|p| {
+                    for (labels, statement) in actions {
+                        p.line(format_args!("{} :", labels.iter().join(", ")));
+                        p.indented(|p| p.statement(statement));
+                    }
+                    if let Some(otherwise) = otherwise {
+                        p.line("OTHERWISE :");
+                        p.indented(|p| p.statement(otherwise));
+                    }
+                });
+                self.line("END_CASE;");
+            }
+            Statement::Repeat {
+                control,
+                statements,
+            } => {
+                let mut head = "REPEAT".to_string();
+                if let Some(increment) = &control.increment {
+                    head += &format!(
+                        " {} := {} TO {}",
+                        increment.variable,
+                        Prec(&increment.begin, SIMPLE),
+                        Prec(&increment.end, SIMPLE)
+                    );
+                    if let Some(by) = &increment.increment {
+                        head += &format!(" BY {}", Prec(by, SIMPLE));
+                    }
+                }
+                if let Some(while_) = &control.while_ {
+                    head += &format!(" WHILE {}", while_);
+                }
+                if let Some(until) = &control.until {
+                    head += &format!(" UNTIL {}", until);
+                }
+                self.line(format_args!("{};", head));
+                self.indented(|p| p.statements(statements));
+                self.line("END_REPEAT;");
+            }
+            Statement::Return { value } => match value {
+                Some(value) => self.line(format_args!("RETURN ({});", value)),
+                None => self.line("RETURN;"),
+            },
+            Statement::ProcedureCall {
+                procedure,
+                parameters,
+            } => {
+                let name = match procedure {
+                    ProcedureCallName::Reference(name) => name.as_str(),
+                    ProcedureCallName::Insert => "INSERT",
+                    ProcedureCallName::Remove => "REMOVE",
+                };
+                match parameters {
+                    Some(parameters) => {
+                        self.line(format_args!("{}({});", name, parameters.iter().join(", ")))
+                    }
+                    None => self.line(format_args!("{};", name)),
+                }
+            }
+            Statement::Skip => self.line("SKIP;"),
+            Statement::Escape => self.line("ESCAPE;"),
+            Statement::Null => self.line(";"),
+        }
+    }
+}
+
+/// Formal parameters of function or procedure, e.g. `(a : REAL; VAR b : INTEGER)`
+fn parameters(parameters: &[FormalParameter]) -> String {
+    if parameters.is_empty() {
+        return String::new();
+    }
+    let parameters = parameters.iter().map(|param| {
+        format!(
+            "{}{} : {}",
+            if param.is_variable { "VAR " } else { "" },
+            param.name,
+            param.ty
+        )
+    });
+    format!("({})", parameters.format("; "))
+}
+
+/// Head of enumeration or select type and its items, e.g. `SELECT BASED_ON a WITH` and `[b, c]`
+fn constructed_head(ty: &Type) -> Option<(String, &[String])> {
+    match ty {
+        Type::Enumeration {
+            extensibility,
+            based_on,
+            items,
+        } => {
+            let mut head = match extensibility {
+                Extensibility::None => String::new(),
+                _ => "EXTENSIBLE ".to_string(),
+            };
+            head.push_str("ENUMERATION");
+            match based_on {
+                Some(based_on) => {
+                    head.push_str(" BASED_ON ");
+                    head.push_str(based_on);
+                    if !items.is_empty() {
+                        head.push_str(" WITH");
+                    }
+                }
+                None => {
+                    if !items.is_empty() {
+                        head.push_str(" OF");
+                    }
+                }
+            }
+            Some((head, items))
+        }
+        Type::Select {
+            extensibility,
+            based_on,
+            types,
+            ..
+        } => {
+            let mut head = match extensibility {
+                Extensibility::None => String::new(),
+                Extensibility::Extensible => "EXTENSIBLE ".to_string(),
+                Extensibility::GenericEntity => "EXTENSIBLE GENERIC_ENTITY ".to_string(),
+            };
+            head.push_str("SELECT");
+            if let Some(based_on) = based_on {
+                head.push_str(" BASED_ON ");
+                head.push_str(based_on);
+                if !types.is_empty() {
+                    head.push_str(" WITH");
+                }
+            }
+            Some((head, types))
+        }
+        _ => None,
+    }
+}
+
+macro_rules! impl_display_by_printer {
+    ($ty:ty, $method:ident) => {
+        impl Display for $ty {
+            fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+                let mut printer = Printer::default();
+                printer.$method(self);
+                f.write_str(&printer.buf)
+            }
+        }
+    };
+}
+
+impl_display_by_printer!(SyntaxTree, syntax_tree);
+impl_display_by_printer!(Schema, schema);
+impl_display_by_printer!(Remark, remark);
+impl_display_by_printer!(TypeDecl, type_decl);
+impl_display_by_printer!(Entity, entity);
+impl_display_by_printer!(SubTypeConstraint, subtype_constraint);
+impl_display_by_printer!(WhereClause, where_clause);
+impl_display_by_printer!(Function, function);
+impl_display_by_printer!(Procedure, procedure);
+impl_display_by_printer!(Rule, rule);
+impl_display_by_printer!(Statement, statement);
+impl_display_by_printer!(Declaration, declaration);
+
+impl Display for InterfaceSpec {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let (keyword, name, resources) = match self {
+            InterfaceSpec::Use { name, types } => ("USE", name, types),
+            InterfaceSpec::Reference { name, resources } => ("REFERENCE", name, resources),
+        };
+        write!(f, "{} FROM {}", keyword, name)?;
+        if !resources.is_empty() {
+            let resources = resources.iter().map(|(name, rename)| match rename {
+                Some(rename) => format!("{} AS {}", name, rename),
+                None => name.clone(),
+            });
+            write!(f, " ({})", resources.format(", "))?;
+        }
+        write!(f, ";")
+    }
+}
+
+impl Display for Type {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        fn bound(bound: &Option<Bound>) -> String {
+            match bound {
+                Some(bound) => format!(" {}", bound),
+                None => String::new(),
+            }
+        }
+        fn label(label: &Option<String>) -> String {
+            match label {
+                Some(label) => format!(":{}", label),
+                None => String::new(),
+            }
+        }
+        match self {
+            Type::Simple(simple) => write!(f, "{}", simple),
+            Type::Named(name) => write!(f, "{}", name),
+            Type::Set { base, bound: b } => write!(f, "SET{} OF {}", bound(b), base),
+            Type::Bag { base, bound: b } => write!(f, "BAG{} OF {}", bound(b), base),
+            Type::List {
+                base,
+                bound: b,
+                unique,
+            } => write!(
+                f,
+                "LIST{} OF {}{}",
+                bound(b),
+                if *unique { "UNIQUE " } else { "" },
+                base
+            ),
+            Type::Array {
+                base,
+                bound: b,
+                unique,
+                optional,
+            } => write!(
+                f,
+                "ARRAY{} OF {}{}{}",
+                bound(b),
+                if *optional { "OPTIONAL " } else { "" },
+                if *unique { "UNIQUE " } else { "" },
+                base
+            ),
+            Type::Enumeration { .. } | Type::Select { .. } => {
+                let (head, items) = constructed_head(self).unwrap();
+                if items.is_empty() {
+                    write!(f, "{}", head)
+                } else {
+                    write!(f, "{} ({})", head, items.join(", "))
+                }
+            }
+            Type::Aggregate { base, label: l } => write!(f, "AGGREGATE{} OF {}", label(l), base),
+            Type::GenericEntity(l) => write!(f, "GENERIC_ENTITY{}", label(l)),
+            Type::Generic(l) => write!(f, "GENERIC{}", label(l)),
+        }
+    }
+}
+
+impl Display for SimpleType {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        fn width(width_spec: &Option<WidthSpec>) -> String {
+            match width_spec {
+                Some(WidthSpec { width, fixed }) => {
+                    format!("({}){}", width, if *fixed { " FIXED" } else { "" })
+                }
+                None => String::new(),
+            }
+        }
+        match self {
+            SimpleType::Number => write!(f, "NUMBER"),
+            SimpleType::Real => write!(f, "REAL"),
+            SimpleType::Integer => write!(f, "INTEGER"),
+            SimpleType::Logical => write!(f, "LOGICAL"),
+            SimpleType::Boolen => write!(f, "BOOLEAN"),
+            SimpleType::String_ { width_spec } => write!(f, "STRING{}", width(width_spec)),
+            SimpleType::Binary { width_spec } => write!(f, "BINARY{}", width(width_spec)),
+        }
+    }
+}
+
+impl Display for Bound {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "[{}:{}]",
+            Prec(&self.lower, SIMPLE),
+            Prec(&self.upper, SIMPLE)
+        )
+    }
+}
+
+impl Display for AttributeDecl {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            AttributeDecl::Reference(name) => write!(f, "{}", name),
+            AttributeDecl::Qualified {
+                group,
+                attribute,
+                rename,
+            } => {
+                write!(f, "SELF\\{}.{}", group, attribute)?;
+                if let Some(rename) = rename {
+                    write!(f, " RENAMED {}", rename)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl Display for InverseAttribute {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{} : ", self.name)?;
+        let (keyword, bound) = match &self.dest_aggregation {
+            AggregationOption::Set { bound } => ("SET", bound),
+            AggregationOption::Bag { bound } => ("BAG", bound),
+            AggregationOption::None => ("", &None),
+        };
+        if !keyword.is_empty() {
+            write!(f, "{} ", keyword)?;
+            if let Some(bound) = bound {
+                write!(f, "{} ", bound)?;
+            }
+            write!(f, "OF ")?;
+        }
+        write!(f, "{} FOR ", self.dest)?;
+        if let Some(prefix) = &self.attribute_prefix {
+            write!(f, "{}.", prefix)?;
+        }
+        write!(f, "{};", self.attribute)
+    }
+}
+
+impl Display for Constraint {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Constraint::AbstractEntity => write!(f, "ABSTRACT"),
+            Constraint::AbstractSuperType(None) => write!(f, "ABSTRACT SUPERTYPE"),
+            Constraint::AbstractSuperType(Some(expr)) => {
+                write!(f, "ABSTRACT SUPERTYPE OF ({})", expr)
+            }
+            Constraint::SuperTypeRule(expr) => write!(f, "SUPERTYPE OF ({})", expr),
+        }
+    }
+}
+
+impl Display for SuperTypeExpression {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // `ANDOR` binds weaker than `AND`
+        fn term(expr: &SuperTypeExpression) -> String {
+            match expr {
+                SuperTypeExpression::AndOr { .. } | SuperTypeExpression::And { .. } => {
+                    format!("({})", expr)
+                }
+                _ => expr.to_string(),
+            }
+        }
+        fn factor(expr: &SuperTypeExpression) -> String {
+            match expr {
+                SuperTypeExpression::AndOr { .. } => format!("({})", expr),
+                _ => expr.to_string(),
+            }
+        }
+        match self {
+            SuperTypeExpression::Reference(name) => write!(f, "{}", name),
+            SuperTypeExpression::AndOr { factors } => {
+                write!(f, "{}", factors.iter().map(factor).format(" ANDOR "))
+            }
+            SuperTypeExpression::And { terms } => {
+                write!(f, "{}", terms.iter().map(term).format(" AND "))
+            }
+            SuperTypeExpression::OneOf { exprs } => {
+                write!(f, "ONEOF ({})", exprs.iter().join(", "))
+            }
+        }
+    }
+}
+
+/* Expressions */
+
+// Precedence of expressions, see 12.1 "Operator precedence"
+const RELATION: u8 = 0;
+const SIMPLE: u8 = 1;
+const TERM: u8 = 2;
+const FACTOR: u8 = 3;
+const SIMPLE_FACTOR: u8 = 4;
+
+fn precedence(expr: &Expression) -> u8 {
+    match expr {
+        Expression::Relation { .. } => RELATION,
+        Expression::Binary { op, .. } => match op {
+            BinaryOperator::Add
+            | BinaryOperator::Sub
+            | BinaryOperator::Or
+            | BinaryOperator::Xor => SIMPLE,
+            BinaryOperator::Power => FACTOR,
+            _ => TERM,
+        },
+        _ => SIMPLE_FACTOR,
+    }
+}
+
+/// Expression enclosed by parentheses if its precedence is lower than required
+struct Prec<'a>(&'a Expression, u8);
+
+impl<'a> Display for Prec<'a> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        if precedence(self.0) < self.1 {
+            write!(f, "({})", self.0)
+        } else {
+            write!(f, "{}", self.0)
+        }
+    }
+}
+
+impl Display for Expression {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Expression::Unary { op, arg, .. } => {
+                write!(f, "{}", op)?;
+                // Operand of unary operator must be a primary or parenthesized
+                match arg.as_ref() {
+                    Expression::Literal { .. } | Expression::QualifiableFactor { .. } => {
+                        write!(f, "{}", arg)
+                    }
+                    _ => write!(f, "({})", arg),
+                }
+            }
+            Expression::Binary { op, arg1, arg2, .. } => {
+                let p = precedence(self);
+                if p == FACTOR {
+                    write!(
+                        f,
+                        "{} {} {}",
+                        Prec(arg1, SIMPLE_FACTOR),
+                        op,
+                        Prec(arg2, SIMPLE_FACTOR)
+                    )
+                } else {
+                    // left associative
+                    write!(f, "{} {} {}", Prec(arg1, p), op, Prec(arg2, p + 1))
+                }
+            }
+            Expression::Relation { op, lhs, rhs, .. } => {
+                write!(f, "{} {} {}", Prec(lhs, SIMPLE), op, Prec(rhs, SIMPLE))
+            }
+            Expression::Literal { literal, .. } => write!(f, "{}", literal),
+            Expression::QualifiableFactor {
+                factor, qualifiers, ..
+            } => {
+                write!(f, "{}{}", factor, qualifiers.iter().join(""))
+            }
+            Expression::EntityConstructor { name, values, .. } => {
+                write!(f, "{}({})", name, values.iter().join(", "))
+            }
+            Expression::Interval {
+                op_low,
+                op_high,
+                high,
+                low,
+                item,
+                ..
+            } => write!(
+                f,
+                "{{{} {} {} {} {}}}",
+                Prec(low, SIMPLE),
+                op_low,
+                Prec(item, SIMPLE),
+                op_high,
+                Prec(high, SIMPLE)
+            ),
+            Expression::EnumerationReference { ty, enum_ref, .. } => match ty {
+                Some(ty) => write!(f, "{}.{}", ty, enum_ref),
+                None => write!(f, "{}", enum_ref),
+            },
+            Expression::AggregateInitializer { elements, .. } => {
+                write!(f, "[{}]", elements.iter().join(", "))
+            }
+            Expression::Query {
+                variable,
+                source,
+                expr,
+                ..
+            } => write!(
+                f,
+                "QUERY({} <* {} | {})",
+                variable,
+                Prec(source, SIMPLE),
+                expr
+            ),
+        }
+    }
+}
+
+impl Display for Element {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match &self.repetition {
+            Some(repetition) => write!(f, "{} : {}", self.expr, Prec(repetition, SIMPLE)),
+            None => write!(f, "{}", self.expr),
+        }
+    }
+}
+
+impl Display for QualifiableFactor {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            QualifiableFactor::Reference(name) => write!(f, "{}", name),
+            QualifiableFactor::BuiltInConstant(constant) => write!(f, "{}", constant),
+            QualifiableFactor::FunctionCall { name, args } => {
+                write!(f, "{}({})", name, args.iter().join(", "))
+            }
+        }
+    }
+}
+
+impl Display for FunctionCallName {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            FunctionCallName::BuiltInFunction(function) => write!(f, "{}", function),
+            FunctionCallName::Reference(name) => write!(f, "{}", name),
+        }
+    }
+}
+
+impl Display for Qualifier {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Qualifier::Attribute(name) => write!(f, ".{}", name),
+            Qualifier::Group(name) => write!(f, "\\{}", name),
+            Qualifier::Index(index) => write!(f, "[{}]", Prec(index, SIMPLE)),
+            Qualifier::Range { begin, end } => {
+                write!(f, "[{}:{}]", Prec(begin, SIMPLE), Prec(end, SIMPLE))
+            }
+        }
+    }
+}
+
+impl Display for BuiltInConstant {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            BuiltInConstant::Napier => write!(f, "CONST_E"),
+            BuiltInConstant::Pi => write!(f, "PI"),
+            BuiltInConstant::Self_ => write!(f, "SELF"),
+            BuiltInConstant::Indeterminate => write!(f, "?"),
+        }
+    }
+}
+
+impl Display for BuiltInFunction {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let keyword = match self {
+            BuiltInFunction::ABS => "ABS",
+            BuiltInFunction::ACOS => "ACOS",
+            BuiltInFunction::ASIN => "ASIN",
+            BuiltInFunction::ATAN => "ATAN",
+            BuiltInFunction::BLENGTH => "BLENGTH",
+            BuiltInFunction::COS => "COS",
+            BuiltInFunction::EXISTS => "EXISTS",
+            BuiltInFunction::EXP => "EXP",
+            BuiltInFunction::FORMAT => "FORMAT",
+            BuiltInFunction::HIBOUND => "HIBOUND",
+            BuiltInFunction::HIINDEX => "HIINDEX",
+            BuiltInFunction::LENGTH => "LENGTH",
+            BuiltInFunction::LOBOUND => "LOBOUND",
+            BuiltInFunction::LOINDEX => "LOINDEX",
+            BuiltInFunction::LOG => "LOG",
+            BuiltInFunction::LOG2 => "LOG2",
+            BuiltInFunction::LOG10 => "LOG10",
+            BuiltInFunction::NVL => "NVL",
+            BuiltInFunction::ODD => "ODD",
+            BuiltInFunction::ROLESOF => "ROLESOF",
+            BuiltInFunction::SIN => "SIN",
+            BuiltInFunction::SIZEOF => "SIZEOF",
+            BuiltInFunction::SQRT => "SQRT",
+            BuiltInFunction::TAN => "TAN",
+            BuiltInFunction::TYPEOF => "TYPEOF",
+            BuiltInFunction::USEDIN => "USEDIN",
+            BuiltInFunction::VALUE => "VALUE",
+            BuiltInFunction::VALUE_IN => "VALUE_IN",
+            BuiltInFunction::VALUE_UNIQUE => "VALUE_UNIQUE",
+        };
+        write!(f, "{}", keyword)
+    }
+}
+
+impl Display for Literal {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            // Integer literals are also parsed as real numbers, e.g. `1` into `1.0`
+            Literal::Real(value) if value.fract() == 0.0 && value.abs() < 1e15 => {
+                write!(f, "{}", value)
+            }
+            Literal::Real(value) => {
+                // Real literal needs `.` before the exponent, e.g. `1.0e20` instead of `1e20`
+                let value = format!("{:?}", value);
+                match value.find('e') {
+                    Some(e) if !value.contains('.') => {
+                        write!(f, "{}.0{}", &value[..e], &value[e..])
+                    }
+                    _ => write!(f, "{}", value),
+                }
+            }
+            // `'` in string is escaped as `''`
+            Literal::String(value) => write!(f, "'{}'", value.replace('\'', "''")),
+            Literal::Logial(Logical::True) => write!(f, "TRUE"),
+            Literal::Logial(Logical::False) => write!(f, "FALSE"),
+            Literal::Logial(Logical::Unknown) => write!(f, "UNKNOWN"),
+        }
+    }
+}
+
+impl Display for UnaryOperator {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            UnaryOperator::Plus => write!(f, "+"),
+            UnaryOperator::Minus => write!(f, "-"),
+            UnaryOperator::Not => write!(f, "NOT "),
+        }
+    }
+}
+
+impl Display for BinaryOperator {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let op = match self {
+            BinaryOperator::Mul => "*",
+            BinaryOperator::RealDiv => "/",
+            BinaryOperator::IntegerDiv => "DIV",
+            BinaryOperator::Mod => "MOD",
+            BinaryOperator::And => "AND",
+            BinaryOperator::ComplexEntityInstanceConstruction => "||",
+            BinaryOperator::Add => "+",
+            BinaryOperator::Sub => "-",
+            BinaryOperator::Or => "OR",
+            BinaryOperator::Xor => "XOR",
+            BinaryOperator::Power => "**",
+        };
+        write!(f, "{}", op)
+    }
+}
+
+impl Display for RelationOperator {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let op = match self {
+            RelationOperator::Equal => "=",
+            RelationOperator::NotEqual => "<>",
+            RelationOperator::Lt => "<",
+            RelationOperator::Gt => ">",
+            RelationOperator::Leq => "<=",
+            RelationOperator::Geq => ">=",
+            RelationOperator::InstanceEqual => ":=:",
+            RelationOperator::InstanceNotEqual => ":<>:",
+            RelationOperator::In => "IN",
+            RelationOperator::Like => "LIKE",
+        };
+        write!(f, "{}", op)
+    }
+}
+
+impl Display for IntervalOperator {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            IntervalOperator::LessThan => write!(f, "<"),
+            IntervalOperator::LessThanEqual => write!(f, "<="),
+        }
+    }
+}
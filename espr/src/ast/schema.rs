@@ -1,6 +1,6 @@
 //! AST of schema declarations
 
-use crate::ast::{algorithm::*, entity::*, types::*};
+use crate::ast::{algorithm::*, entity::*, types::*, Span};
 
 /// Parsed result of EXPRESS's SCHEMA
 #[derive(Debug, Clone, PartialEq)]
@@ -14,4 +14,5 @@ pub struct Schema {
     pub constants: Vec<Constant>,
     pub interfaces: Vec<InterfaceSpec>,
     pub subtype_constraints: Vec<SubTypeConstraint>,
+    pub span: Span,
 }
@@ -1,7 +1,7 @@
 //! AST for type declaration
 
 use crate::{
-    ast::{algorithm::*, expression::*},
+    ast::{algorithm::*, expression::*, Span},
     derive_ast_component,
     parser::*,
 };
@@ -12,6 +12,7 @@ pub struct TypeDecl {
     pub type_id: String,
     pub underlying_type: Type,
     pub where_clause: Option<WhereClause>,
+    pub span: Span,
 }
 
 /// Parameter type appears when *using* the type
@@ -43,11 +44,17 @@ pub enum Type {
     // Constructed Types
     Enumeration {
         extensibility: Extensibility,
+        /// Name of base type for `ENUMERATION BASED_ON base WITH (...)`
+        based_on: Option<String>,
         items: Vec<String>,
     },
     Select {
         extensibility: Extensibility,
+        /// Name of base type for `SELECT BASED_ON base WITH (...)`
+        based_on: Option<String>,
         types: Vec<String>,
+        /// Span of each item in `types`
+        spans: Vec<Span>,
     },
 
     // Parameter Types
@@ -1,46 +1,525 @@
 //! Executable for espr EXPRESS language compiler
 
-use espr::{ast::SyntaxTree, codegen::rust::*, ir::IR};
-use std::{fs, path::*};
-use structopt::StructOpt;
+use espr::{
+    ast::SyntaxTree,
+    codegen::{dot::*, json_schema::*, rust::*, sql::*, typescript::*},
+    diagnostic::*,
+    diff::*,
+    doc::*,
+    ir::IR,
+};
+use std::{fs, path::*, process, str::FromStr};
+use structopt::{clap::AppSettings, StructOpt};
 
 #[derive(Debug, StructOpt)]
+#[structopt(setting = AppSettings::SubcommandsNegateReqs)]
 struct Arguments {
-    #[structopt(long = "num-error-lines", default_value = "10")]
+    #[structopt(
+        long = "num-error-lines",
+        default_value = "10",
+        global = true,
+        help = "Number of lines shown from the position of each error"
+    )]
     num_lines: usize,
+    #[structopt(
+        long = "builder",
+        global = true,
+        help = "Generate `Xxx::builder()` for each entity"
+    )]
+    builder: bool,
+    #[structopt(
+        long = "visit",
+        global = true,
+        help = "Generate `Visit` and `VisitMut` traits for each schema"
+    )]
+    visit: bool,
+    #[structopt(
+        long = "reflection",
+        global = true,
+        help = "Generate `SCHEMA_INFO` to inspect each schema at runtime"
+    )]
+    reflection: bool,
+    #[structopt(
+        long = "downcast",
+        global = true,
+        help = "Generate `downcast_ref` and `TryFrom` for `XxxAny` enums of supertypes"
+    )]
+    downcast: bool,
     #[structopt(long = "check", help = "Check input EXPRESS definitions can be parsed")]
     check: bool,
-    #[structopt(parse(from_os_str))]
-    source: PathBuf,
+    #[structopt(
+        short = "I",
+        long = "include",
+        number_of_values = 1,
+        parse(from_os_str),
+        help = "Directory to search EXPRESS files declaring schemas referred by USE FROM or REFERENCE FROM"
+    )]
+    include_dirs: Vec<PathBuf>,
+    #[structopt(
+        long = "roots",
+        use_delimiter = true,
+        help = "Generate only entities and types reachable from these comma-separated names"
+    )]
+    roots: Vec<String>,
+    #[structopt(
+        long = "target",
+        default_value = "rust",
+        help = "Output language, `rust`, `json-schema`, `typescript`, or `sql`"
+    )]
+    target: Target,
+    #[structopt(parse(from_os_str), required = true)]
+    sources: Vec<PathBuf>,
+    #[structopt(subcommand)]
+    command: Option<Command>,
 }
 
-fn main() {
-    let args = Arguments::from_args();
-    let src = fs::read_to_string(&args.source).expect("Failed to load EXPRESS source code");
-    let st = match SyntaxTree::parse(&src) {
+impl Arguments {
+    /// Optional items enabled by flags
+    fn generators(&self) -> Generators {
+        Generators {
+            builder: self.builder,
+            visit: self.visit,
+            reflection: self.reflection,
+            downcast: self.downcast,
+        }
+    }
+}
+
+/// Output of the compiler without subcommand
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Target {
+    Rust,
+    JsonSchema,
+    TypeScript,
+    Sql,
+}
+
+impl FromStr for Target {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "rust" => Ok(Target::Rust),
+            "json-schema" => Ok(Target::JsonSchema),
+            "typescript" => Ok(Target::TypeScript),
+            "sql" => Ok(Target::Sql),
+            _ => Err(format!("Unknown target: {}", s)),
+        }
+    }
+}
+
+#[derive(Debug, StructOpt)]
+enum Command {
+    /// Compare two versions of EXPRESS schemas
+    Diff {
+        #[structopt(long = "json", help = "Output changes in JSON")]
+        json: bool,
+        #[structopt(
+            short = "I",
+            long = "include",
+            number_of_values = 1,
+            parse(from_os_str),
+            help = "Directory to search EXPRESS files declaring schemas referred by USE FROM or REFERENCE FROM"
+        )]
+        include_dirs: Vec<PathBuf>,
+        #[structopt(parse(from_os_str))]
+        old: PathBuf,
+        #[structopt(parse(from_os_str))]
+        new: PathBuf,
+    },
+    /// Expand a short form schema into the long form, and print it in EXPRESS
+    LongForm {
+        #[structopt(
+            long = "schema",
+            help = "Schema to be expanded. The first schema in the first source is used by default"
+        )]
+        schema: Option<String>,
+        #[structopt(
+            short = "I",
+            long = "include",
+            number_of_values = 1,
+            parse(from_os_str),
+            help = "Directory to search EXPRESS files declaring schemas referred by USE FROM or REFERENCE FROM"
+        )]
+        include_dirs: Vec<PathBuf>,
+        #[structopt(parse(from_os_str), required = true)]
+        sources: Vec<PathBuf>,
+    },
+    /// Generate a documentation site of EXPRESS schemas
+    Doc {
+        #[structopt(
+            long = "format",
+            default_value = "html",
+            help = "Format of pages, `html` or `markdown`"
+        )]
+        format: DocFormat,
+        #[structopt(
+            short = "o",
+            long = "output",
+            default_value = "doc",
+            parse(from_os_str),
+            help = "Directory to write the site"
+        )]
+        output: PathBuf,
+        #[structopt(
+            short = "I",
+            long = "include",
+            number_of_values = 1,
+            parse(from_os_str),
+            help = "Directory to search EXPRESS files declaring schemas referred by USE FROM or REFERENCE FROM"
+        )]
+        include_dirs: Vec<PathBuf>,
+        #[structopt(parse(from_os_str), required = true)]
+        sources: Vec<PathBuf>,
+    },
+    /// Draw EXPRESS-G diagrams in DOT language
+    Graph {
+        #[structopt(
+            long = "focus",
+            help = "Draw only the neighborhood of the entity or type, specified by `name` or `schema.name`"
+        )]
+        focus: Option<String>,
+        #[structopt(
+            long = "depth",
+            default_value = "1",
+            help = "Number of lines from the focused declaration to be drawn"
+        )]
+        depth: usize,
+        #[structopt(
+            long = "page-size",
+            help = "Split diagrams into pages of at most this number of entities and types"
+        )]
+        page_size: Option<usize>,
+        #[structopt(
+            long = "svg",
+            parse(from_os_str),
+            help = "Render pages into `page_N.svg` files in the directory by Graphviz dot, instead of printing DOT"
+        )]
+        svg: Option<PathBuf>,
+        #[structopt(
+            short = "I",
+            long = "include",
+            number_of_values = 1,
+            parse(from_os_str),
+            help = "Directory to search EXPRESS files declaring schemas referred by USE FROM or REFERENCE FROM"
+        )]
+        include_dirs: Vec<PathBuf>,
+        #[structopt(parse(from_os_str), required = true)]
+        sources: Vec<PathBuf>,
+    },
+    /// Generate Rust code as a directory tree with a module for each cluster of entities and types
+    Split {
+        #[structopt(
+            short = "o",
+            long = "output",
+            parse(from_os_str),
+            help = "Directory to write the modules"
+        )]
+        output: PathBuf,
+        #[structopt(
+            long = "features",
+            help = "Enable each cluster by a cargo feature, listed in features.toml"
+        )]
+        features: bool,
+        #[structopt(
+            long = "internal",
+            help = "Refer ruststep as `crate` instead of `::ruststep` in the generated code"
+        )]
+        internal: bool,
+        #[structopt(
+            long = "roots",
+            use_delimiter = true,
+            help = "Generate only entities and types reachable from these comma-separated names"
+        )]
+        roots: Vec<String>,
+        #[structopt(
+            short = "I",
+            long = "include",
+            number_of_values = 1,
+            parse(from_os_str),
+            help = "Directory to search EXPRESS files declaring schemas referred by USE FROM or REFERENCE FROM"
+        )]
+        include_dirs: Vec<PathBuf>,
+        #[structopt(parse(from_os_str), required = true)]
+        sources: Vec<PathBuf>,
+    },
+}
+
+/// Load EXPRESS files, or exit with diagnostics
+fn load(sources: &[PathBuf], include_dirs: &[PathBuf], num_lines: usize) -> SyntaxTree {
+    match SyntaxTree::load(sources, include_dirs) {
         Ok(st) => st,
-        Err(e) => {
-            for (code, kind) in e.errors {
-                eprintln!(
-                    "Syntax Error occurred while parsing following line [{:?}]:",
-                    kind
-                );
-                for line in code.lines().take(args.num_lines) {
-                    eprintln!("> {}", line);
-                }
-                eprintln!();
-            }
-            panic!("Syntax Error");
+        Err(err) => {
+            let (files, diagnostic) = load_error(&err);
+            emit_with_lines(&files, &[diagnostic], num_lines);
+            process::exit(1);
+        }
+    }
+}
+
+/// Legalize syntax tree, or exit with diagnostics
+fn legalize(st: &SyntaxTree, num_lines: usize) -> IR {
+    match IR::from_syntax_tree(st) {
+        Ok(ir) => ir,
+        Err(err) => {
+            let files = Files::new(st);
+            let diagnostics = semantic_error(&files, err);
+            emit_with_lines(&files, &diagnostics, num_lines);
+            eprintln!(
+                "{} error(s) occurred in semantic analysis",
+                diagnostics.len()
+            );
+            process::exit(1);
         }
+    }
+}
+
+fn diff_command(old: &Path, new: &Path, include_dirs: &[PathBuf], json: bool, num_lines: usize) {
+    let old = legalize(&load(&[old.to_owned()], include_dirs, num_lines), num_lines);
+    let new = legalize(&load(&[new.to_owned()], include_dirs, num_lines), num_lines);
+    let changes = diff(&old, &new);
+    if json {
+        #[derive(serde::Serialize)]
+        struct Record<'a> {
+            severity: Severity,
+            #[serde(flatten)]
+            change: &'a Change,
+        }
+        let records: Vec<_> = changes
+            .iter()
+            .map(|change| Record {
+                severity: change.severity(),
+                change,
+            })
+            .collect();
+        println!("{}", serde_json::to_string_pretty(&records).unwrap());
+        return;
+    }
+    for change in &changes {
+        println!("{:>12}: {}", change.severity(), change);
+    }
+    let breaking = changes
+        .iter()
+        .filter(|change| change.severity() == Severity::Breaking)
+        .count();
+    eprintln!(
+        "{} breaking and {} non-breaking change(s)",
+        breaking,
+        changes.len() - breaking
+    );
+}
+
+fn long_form_command(
+    schema: &Option<String>,
+    sources: &[PathBuf],
+    include_dirs: &[PathBuf],
+    num_lines: usize,
+) {
+    let st = load(sources, include_dirs, num_lines);
+    let name = match schema {
+        Some(name) => name.as_str(),
+        None => match st.sources.first().and_then(|source| source.schemas.first()) {
+            Some(name) => name,
+            None => {
+                eprintln!("error: No schema is declared in {}", sources[0].display());
+                process::exit(1);
+            }
+        },
     };
+    match st.long_form(name) {
+        Ok(long_form) => print!("{}", long_form),
+        Err(err) => {
+            eprintln!("error: {}", err);
+            process::exit(1);
+        }
+    }
+}
+
+fn graph_command(
+    options: &GraphOptions,
+    svg_dir: Option<&Path>,
+    sources: &[PathBuf],
+    include_dirs: &[PathBuf],
+    num_lines: usize,
+) {
+    let ir = legalize(&load(sources, include_dirs, num_lines), num_lines);
+    let rendered = express_g(&ir, options).and_then(|pages| {
+        let svg_dir = match svg_dir {
+            Some(dir) => dir,
+            None => {
+                print!("{}", pages.join("\n"));
+                return Ok(());
+            }
+        };
+        for (i, page) in pages.iter().enumerate() {
+            write(&svg_dir.join(format!("page_{}.svg", i + 1)), &svg(page)?);
+        }
+        eprintln!("{} page(s) written in {}", pages.len(), svg_dir.display());
+        Ok(())
+    });
+    if let Err(err) = rendered {
+        eprintln!("error: {}", err);
+        process::exit(1);
+    }
+}
+
+fn doc_command(
+    format: DocFormat,
+    output: &Path,
+    sources: &[PathBuf],
+    include_dirs: &[PathBuf],
+    num_lines: usize,
+) {
+    let st = load(sources, include_dirs, num_lines);
+    let ir = legalize(&st, num_lines);
+    let pages = document(&st, &ir, format);
+    for page in &pages {
+        write(&output.join(&page.path), &page.content);
+    }
+    eprintln!("{} page(s) written in {}", pages.len(), output.display());
+}
+
+fn write(path: &Path, content: &str) {
+    let written = path
+        .parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|_| fs::write(path, content));
+    if let Err(err) = written {
+        eprintln!("error: Failed to write {}: {}", path.display(), err);
+        process::exit(1);
+    }
+}
+
+/// Keep only entities and types reachable from `roots` if given, or exit with an error
+fn subset(ir: &mut IR, roots: &[String]) {
+    if roots.is_empty() {
+        return;
+    }
+    let roots: Vec<&str> = roots.iter().map(String::as_str).collect();
+    if let Err(err) = ir.subset(&roots) {
+        eprintln!("error: {}", err);
+        process::exit(1);
+    }
+}
+
+fn split_command(
+    output: &Path,
+    options: SplitOptions,
+    roots: &[String],
+    sources: &[PathBuf],
+    include_dirs: &[PathBuf],
+    num_lines: usize,
+) {
+    let st = load(sources, include_dirs, num_lines);
+    let mut ir = legalize(&st, num_lines);
+    subset(&mut ir, roots);
+    let files = ir.to_split_modules(options);
+    for file in &files {
+        write(&output.join(&file.path), &rustfmt(file.tokens.to_string()));
+    }
+    if options.features {
+        write(&output.join("features.toml"), &ir.cargo_features());
+    }
+    eprintln!("{} file(s) written in {}", files.len(), output.display());
+}
+
+fn main() {
+    let args = Arguments::from_args();
+    match &args.command {
+        Some(Command::Diff {
+            json,
+            include_dirs,
+            old,
+            new,
+        }) => {
+            diff_command(old, new, include_dirs, *json, args.num_lines);
+            return;
+        }
+        Some(Command::LongForm {
+            schema,
+            include_dirs,
+            sources,
+        }) => {
+            long_form_command(schema, sources, include_dirs, args.num_lines);
+            return;
+        }
+        Some(Command::Doc {
+            format,
+            output,
+            include_dirs,
+            sources,
+        }) => {
+            doc_command(*format, output, sources, include_dirs, args.num_lines);
+            return;
+        }
+        Some(Command::Graph {
+            focus,
+            depth,
+            page_size,
+            svg,
+            include_dirs,
+            sources,
+        }) => {
+            let options = GraphOptions {
+                focus: focus.clone(),
+                depth: *depth,
+                page_size: *page_size,
+            };
+            graph_command(
+                &options,
+                svg.as_deref(),
+                sources,
+                include_dirs,
+                args.num_lines,
+            );
+            return;
+        }
+        Some(Command::Split {
+            output,
+            features,
+            internal,
+            roots,
+            include_dirs,
+            sources,
+        }) => {
+            let options = SplitOptions {
+                prefix: if *internal {
+                    CratePrefix::Internal
+                } else {
+                    CratePrefix::External
+                },
+                features: *features,
+                generators: args.generators(),
+            };
+            split_command(
+                output,
+                options,
+                roots,
+                sources,
+                include_dirs,
+                args.num_lines,
+            );
+            return;
+        }
+        None => {}
+    }
+
+    let st = load(&args.sources, &args.include_dirs, args.num_lines);
     if args.check {
         eprintln!("Parse succeeded");
         return;
     }
-
-    let ir = IR::from_syntax_tree(&st).expect("Failed in semantic analysis phase");
-    println!(
-        "#![allow(dead_code)]\n{}",
-        ir.to_token_stream(CratePrefix::Internal)
-    );
+    let mut ir = legalize(&st, args.num_lines);
+    subset(&mut ir, &args.roots);
+    match args.target {
+        Target::Rust => println!(
+            "#![allow(dead_code)]\n{}",
+            ir.to_token_stream_with(CratePrefix::Internal, args.generators())
+        ),
+        Target::JsonSchema => println!(
+            "{}",
+            serde_json::to_string_pretty(&json_schema(&ir)).unwrap()
+        ),
+        Target::TypeScript => print!("{}", typescript(&ir)),
+        Target::Sql => print!("{}", ddl(&ir)),
+    }
 }
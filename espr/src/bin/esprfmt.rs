@@ -0,0 +1,51 @@
+//! Executable for formatting EXPRESS files in canonical layout
+
+use espr::{ast::SyntaxTree, diagnostic::*};
+use std::{fs, path::*, process};
+use structopt::StructOpt;
+
+#[derive(Debug, StructOpt)]
+struct Arguments {
+    #[structopt(long = "write", help = "Overwrite input files by formatted ones")]
+    write: bool,
+    #[structopt(
+        long = "check",
+        conflicts_with = "write",
+        help = "Check input files are formatted, and exit with 1 if not"
+    )]
+    check: bool,
+    #[structopt(parse(from_os_str), required = true)]
+    sources: Vec<PathBuf>,
+}
+
+fn main() {
+    let args = Arguments::from_args();
+    let mut unformatted = false;
+    for path in &args.sources {
+        let st = match SyntaxTree::parse_file(path) {
+            Ok(st) => st,
+            Err(err) => {
+                let (files, diagnostic) = load_error(&err);
+                emit(&files, &[diagnostic]);
+                process::exit(1);
+            }
+        };
+        let formatted = st.to_string();
+        if args.check {
+            if st.sources[0].text != formatted {
+                eprintln!("{} is not formatted", path.display());
+                unformatted = true;
+            }
+        } else if args.write {
+            if let Err(err) = fs::write(path, formatted) {
+                eprintln!("Failed to write {}: {}", path.display(), err);
+                process::exit(1);
+            }
+        } else {
+            print!("{}", formatted);
+        }
+    }
+    if unformatted {
+        process::exit(1);
+    }
+}
@@ -0,0 +1,282 @@
+//! Generate Rust code from EXPRESS schemas in build scripts
+//!
+//! [Builder] loads EXPRESS files, legalizes them, and writes the generated Rust code
+//! formatted by `rustfmt` into `OUT_DIR`.
+//! If `rustfmt` is not installed, the code is written without formatting with a `cargo:warning`.
+//! It also prints `cargo:rerun-if-changed` for the input files and directories,
+//! so that cargo re-runs the build script only when the schemas are modified.
+//!
+//! ```no_run
+//! // in `main` of build.rs
+//! let result = espr::Builder::new()
+//!     .source("schemas/shape.exp")
+//!     .include_dir("schemas/include")
+//!     .generate();
+//! if let Err(err) = result {
+//!     // Show diagnostics with file and line
+//!     panic!("{}", err);
+//! }
+//! ```
+//!
+//! and then include the generated code in the crate,
+//! where each schema becomes a module named in snake case, e.g. `geometry` for `SCHEMA Geometry;`:
+//!
+//! ```ignore
+//! include!(concat!(env!("OUT_DIR"), "/express.rs"));
+//!
+//! pub fn origin() -> geometry::Point {
+//!     geometry::Point { x: 0.0, y: 0.0 }
+//! }
+//! ```
+//!
+//! `build_crate` in `tests/builder.rs` builds such a crate to check the generated code compiles.
+
+use crate::{
+    ast::{self, SyntaxTree},
+    codegen::rust::*,
+    diagnostic::*,
+    ir::{SubsetError, IR},
+};
+use std::{
+    collections::BTreeSet,
+    env, fs, io,
+    path::{Path, PathBuf},
+};
+use thiserror::Error;
+
+/// Error while generating code by [Builder::generate]
+#[derive(Debug, Error)]
+pub enum BuildError {
+    /// Failed to read or parse EXPRESS files, with rendered diagnostics
+    #[error("Failed to load EXPRESS schemas\n{diagnostics}")]
+    Load { diagnostics: String },
+
+    /// Semantic errors in EXPRESS schemas, with rendered diagnostics
+    #[error("Semantic errors in EXPRESS schemas\n{diagnostics}")]
+    Semantic { diagnostics: String },
+
+    #[error("Schema {name} is not found in the sources")]
+    SchemaNotFound { name: String },
+
+    #[error(transparent)]
+    Subset(#[from] SubsetError),
+
+    #[error("OUT_DIR is not set. Use Builder::out_dir outside of build scripts")]
+    OutDirNotSet,
+
+    #[error("Failed to access {path}: {source}")]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: io::Error,
+    },
+
+    #[error("Failed to format generated code by rustfmt: {0}")]
+    Rustfmt(#[source] io::Error),
+}
+
+/// Names in lower case of `schemas` and the schemas interfaced from them by `USE FROM` or `REFERENCE FROM`,
+/// which are needed by the generated code of `schemas`
+fn interfaced(st: &SyntaxTree, schemas: &[String]) -> BTreeSet<String> {
+    let mut selected = BTreeSet::new();
+    let mut stack: Vec<String> = schemas.iter().map(|name| name.to_lowercase()).collect();
+    while let Some(name) = stack.pop() {
+        if !selected.insert(name.clone()) {
+            continue;
+        }
+        let schema = st
+            .schemas
+            .iter()
+            .find(|schema| schema.name.eq_ignore_ascii_case(&name));
+        for spec in schema.into_iter().flat_map(|schema| &schema.interfaces) {
+            let foreign = match spec {
+                ast::InterfaceSpec::Use { name, .. } => name,
+                ast::InterfaceSpec::Reference { name, .. } => name,
+            };
+            stack.push(foreign.to_lowercase());
+        }
+    }
+    selected
+}
+
+/// Generate Rust code from EXPRESS schemas, see [module level document](self)
+#[derive(Debug, Clone)]
+pub struct Builder {
+    sources: Vec<PathBuf>,
+    include_dirs: Vec<PathBuf>,
+    prefix: CratePrefix,
+    generators: Generators,
+    schemas: Vec<String>,
+    roots: Vec<String>,
+    out_dir: Option<PathBuf>,
+    file_name: String,
+}
+
+impl Default for Builder {
+    fn default() -> Self {
+        Builder {
+            sources: Vec::new(),
+            include_dirs: Vec::new(),
+            prefix: CratePrefix::External,
+            generators: Generators::default(),
+            schemas: Vec::new(),
+            roots: Vec::new(),
+            out_dir: None,
+            file_name: "express.rs".to_string(),
+        }
+    }
+}
+
+impl Builder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add an EXPRESS file, or a directory whose `*.exp` files are all added
+    pub fn source(mut self, path: impl AsRef<Path>) -> Self {
+        self.sources.push(path.as_ref().to_owned());
+        self
+    }
+
+    /// Add a directory to search EXPRESS files declaring schemas
+    /// referred by `USE FROM` or `REFERENCE FROM`
+    pub fn include_dir(mut self, dir: impl AsRef<Path>) -> Self {
+        self.include_dirs.push(dir.as_ref().to_owned());
+        self
+    }
+
+    /// Path of ruststep crate in the generated code, [CratePrefix::External] by default
+    pub fn crate_prefix(mut self, prefix: CratePrefix) -> Self {
+        self.prefix = prefix;
+        self
+    }
+
+    /// Optional items to be generated, e.g. builders of entities, none by default
+    pub fn generators(mut self, generators: Generators) -> Self {
+        self.generators = generators;
+        self
+    }
+
+    /// Generate only the selected schemas and the schemas interfaced from them by `USE FROM` or `REFERENCE FROM`.
+    /// All schemas are generated if none is selected.
+    pub fn schema(mut self, name: &str) -> Self {
+        self.schemas.push(name.to_string());
+        self
+    }
+
+    /// Generate only entities and types reachable from the roots, see [IR::subset].
+    /// All entities and types are generated if no root is added.
+    pub fn root(mut self, name: &str) -> Self {
+        self.roots.push(name.to_string());
+        self
+    }
+
+    /// Directory to write the generated code, `OUT_DIR` environment variable by default
+    pub fn out_dir(mut self, dir: impl AsRef<Path>) -> Self {
+        self.out_dir = Some(dir.as_ref().to_owned());
+        self
+    }
+
+    /// Name of the generated file in [Builder::out_dir], `express.rs` by default
+    pub fn file_name(mut self, name: &str) -> Self {
+        self.file_name = name.to_string();
+        self
+    }
+
+    /// EXPRESS files in the sources, where a directory is expanded into its `*.exp` files
+    fn source_files(&self) -> Result<Vec<PathBuf>, BuildError> {
+        let mut files = Vec::new();
+        for path in &self.sources {
+            if !path.is_dir() {
+                files.push(path.clone());
+                continue;
+            }
+            let io_error = |source| BuildError::Io {
+                path: path.clone(),
+                source,
+            };
+            let mut entries = Vec::new();
+            for entry in fs::read_dir(path).map_err(io_error)? {
+                let entry = entry.map_err(io_error)?.path();
+                if entry.extension().map_or(false, |ext| ext == "exp") {
+                    entries.push(entry);
+                }
+            }
+            entries.sort();
+            files.append(&mut entries);
+        }
+        Ok(files)
+    }
+
+    /// Generate Rust code, and returns the path of the written file
+    pub fn generate(&self) -> Result<PathBuf, BuildError> {
+        // Printed before loading to re-run after the errors are fixed
+        let mut watched: BTreeSet<_> = self.sources.iter().chain(&self.include_dirs).collect();
+        for path in &watched {
+            println!("cargo:rerun-if-changed={}", path.display());
+        }
+
+        let st = SyntaxTree::load(&self.source_files()?, &self.include_dirs).map_err(|err| {
+            let (files, diagnostic) = load_error(&err);
+            BuildError::Load {
+                diagnostics: render(&files, &[diagnostic]),
+            }
+        })?;
+        for path in st.sources.iter().filter_map(|source| source.path.as_ref()) {
+            if watched.insert(path) {
+                println!("cargo:rerun-if-changed={}", path.display());
+            }
+        }
+
+        let mut ir = IR::from_syntax_tree(&st).map_err(|err| {
+            let files = Files::new(&st);
+            BuildError::Semantic {
+                diagnostics: render(&files, &semantic_error(&files, err)),
+            }
+        })?;
+        if !self.schemas.is_empty() {
+            for name in &self.schemas {
+                if !ir
+                    .schemas
+                    .iter()
+                    .any(|schema| schema.name.eq_ignore_ascii_case(name))
+                {
+                    return Err(BuildError::SchemaNotFound { name: name.clone() });
+                }
+            }
+            let selected = interfaced(&st, &self.schemas);
+            ir.schemas
+                .retain(|schema| selected.contains(&schema.name.to_lowercase()));
+        }
+        if !self.roots.is_empty() {
+            let roots: Vec<&str> = self.roots.iter().map(String::as_str).collect();
+            ir.subset(&roots)?;
+        }
+
+        let code = ir
+            .to_token_stream_with(self.prefix, self.generators)
+            .to_string();
+        let code = match try_rustfmt(code.clone()) {
+            Ok(formatted) => formatted,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                println!(
+                    "cargo:warning=rustfmt is not found, and the generated code is not formatted"
+                );
+                code
+            }
+            Err(err) => return Err(BuildError::Rustfmt(err)),
+        };
+        let out_dir = match &self.out_dir {
+            Some(dir) => dir.clone(),
+            None => env::var_os("OUT_DIR")
+                .map(PathBuf::from)
+                .ok_or(BuildError::OutDirNotSet)?,
+        };
+        let path = out_dir.join(&self.file_name);
+        fs::write(&path, code).map_err(|source| BuildError::Io {
+            path: path.clone(),
+            source,
+        })?;
+        Ok(path)
+    }
+}
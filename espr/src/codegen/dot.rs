@@ -0,0 +1,447 @@
+//! Generate EXPRESS-G diagrams in [DOT language](https://graphviz.org/doc/info/lang.html)
+//!
+//! Declarations in [IR] are drawn in the EXPRESS-G notation (ISO 10303-11 Annex D):
+//!
+//! - Entities are boxes, and defined types are dashed boxes.
+//!   Select and enumeration types have an additional line on the left and right, respectively.
+//! - Simple types are boxes with an additional line on the right.
+//! - Attributes are lines ending with a circle at their types, dashed if `OPTIONAL`.
+//!   Derived and inverse attributes are labeled with `(DER)` and `(INV)`.
+//! - Inheritance is a thick line from the supertype to the subtype.
+//!
+//! Large schemas, e.g. AP203, are hard to read in a single diagram.
+//! [GraphOptions::focus] restricts the diagram into the neighborhood of a declaration,
+//! and [GraphOptions::page_size] splits it into pages.
+//! Lines to a declaration on another page end with a page reference `page, name` as EXPRESS-G does.
+//! Each page can be rendered into SVG by [svg] if [Graphviz](https://graphviz.org/) is installed.
+//!
+//! ```
+//! use espr::{ast::SyntaxTree, codegen::dot::*, ir::IR};
+//!
+//! let st = SyntaxTree::parse(r#"
+//!   SCHEMA s;
+//!     ENTITY a; x: OPTIONAL b; END_ENTITY;
+//!     ENTITY b; END_ENTITY;
+//!   END_SCHEMA;
+//! "#).unwrap();
+//! let ir = IR::from_syntax_tree(&st).unwrap();
+//! let pages = express_g(&ir, &GraphOptions::default()).unwrap();
+//! assert_eq!(pages.len(), 1);
+//! assert!(pages[0].contains(r#""s.a" -> "s.b" [label="x", style=dashed];"#));
+//! ```
+
+use crate::ir::*;
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    io::{self, Write},
+    process::{Command, Stdio},
+};
+use thiserror::Error;
+
+/// Error while drawing diagrams by [express_g] or rendering them by [svg]
+#[derive(Debug, Error)]
+pub enum GraphError {
+    #[error("Declaration {name} to be focused is not found")]
+    FocusNotFound { name: String },
+
+    #[error("Failed to render SVG by Graphviz dot: {source}")]
+    Graphviz {
+        #[source]
+        source: io::Error,
+    },
+}
+
+/// Options for [express_g]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GraphOptions {
+    /// Entity or type to be focused, either `name` or `schema.name`.
+    /// All declarations are drawn if `None`.
+    pub focus: Option<String>,
+    /// Declarations within this number of lines from [GraphOptions::focus] are drawn
+    pub depth: usize,
+    /// Maximum number of entities and types in a page. All are drawn in a page if `None`.
+    pub page_size: Option<usize>,
+}
+
+impl Default for GraphOptions {
+    fn default() -> Self {
+        GraphOptions {
+            focus: None,
+            depth: 1,
+            page_size: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Shape {
+    Entity,
+    Defined,
+    Select,
+    Enumeration,
+    Simple,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Line {
+    Inheritance,
+    Attribute { optional: bool },
+    Underlying,
+}
+
+#[derive(Debug)]
+struct Node {
+    id: String,
+    label: String,
+    shape: Shape,
+}
+
+#[derive(Debug)]
+struct Edge {
+    from: String,
+    to: String,
+    label: String,
+    line: Line,
+}
+
+#[derive(Debug, Default)]
+struct Graph {
+    nodes: Vec<Node>,
+    index: HashMap<String, usize>,
+    edges: Vec<Edge>,
+}
+
+fn id(schema: &str, name: &str) -> String {
+    format!("{}.{}", schema, name)
+}
+
+/// Strip aggregations, e.g. `SET [1:?] OF LIST [2:2] OF point` into `S[1:?] L[2:2]` and `point`
+fn aggregation(ty: &TypeRef) -> (String, &TypeRef) {
+    let mut prefix = Vec::new();
+    let mut ty = ty;
+    loop {
+        let (kind, bound, base) = match ty {
+            TypeRef::Set { base, bound } => ("S", bound, base),
+            TypeRef::Bag { base, bound } => ("B", bound, base),
+            TypeRef::List { base, bound, .. } => ("L", bound, base),
+            TypeRef::Array { base, bound, .. } => ("A", bound, base),
+            _ => return (prefix.join(" "), ty),
+        };
+        prefix.push(match bound {
+            Some(bound) => format!("{}{}", kind, bound),
+            None => kind.to_string(),
+        });
+        ty = base;
+    }
+}
+
+impl Graph {
+    fn new(ir: &IR) -> Self {
+        let mut graph = Graph::default();
+        for schema in &ir.schemas {
+            for ty in &schema.types {
+                let shape = match ty {
+                    TypeDecl::Simple(_) | TypeDecl::Rename(_) => Shape::Defined,
+                    TypeDecl::Select(_) => Shape::Select,
+                    TypeDecl::Enumeration(_) => Shape::Enumeration,
+                };
+                graph.add_node(id(&schema.name, ty.id()), ty.id(), shape);
+            }
+            for entity in &schema.entities {
+                graph.add_node(id(&schema.name, &entity.name), &entity.name, Shape::Entity);
+            }
+        }
+        for schema in &ir.schemas {
+            for ty in &schema.types {
+                let from = id(&schema.name, ty.id());
+                match ty {
+                    TypeDecl::Simple(Simple { ty, .. }) => graph.add_edge(
+                        &from,
+                        &TypeRef::SimpleType(ty.clone()),
+                        "",
+                        Line::Underlying,
+                    ),
+                    TypeDecl::Rename(Rename { ty, .. }) => {
+                        graph.add_edge(&from, ty, "", Line::Underlying)
+                    }
+                    TypeDecl::Select(Select { types, .. }) => {
+                        for ty in types {
+                            graph.add_edge(&from, ty, "", Line::Underlying);
+                        }
+                    }
+                    TypeDecl::Enumeration(_) => {}
+                }
+            }
+            for entity in &schema.entities {
+                let from = id(&schema.name, &entity.name);
+                for supertype in &entity.supertypes {
+                    let sup = graph.node_id(supertype);
+                    graph.edges.push(Edge {
+                        from: sup,
+                        to: from.clone(),
+                        label: String::new(),
+                        line: Line::Inheritance,
+                    });
+                }
+                for attr in &entity.attributes {
+                    let line = Line::Attribute {
+                        optional: attr.optional,
+                    };
+                    graph.add_edge(&from, &attr.ty, &attr.name, line);
+                }
+                for attr in &entity.derived {
+                    let label = format!("(DER) {}", attr.name);
+                    let line = Line::Attribute { optional: false };
+                    graph.add_edge(&from, &attr.ty, &label, line);
+                }
+                for attr in &entity.inverse {
+                    let upper = match attr.upper {
+                        Some(upper) => upper.to_string(),
+                        None => "?".to_string(),
+                    };
+                    let label = format!("(INV) {} [{}:{}]", attr.name, attr.lower, upper);
+                    let line = Line::Attribute { optional: false };
+                    if let Some((dest, _)) = attr.referrers.first() {
+                        graph.add_edge(&from, dest, &label, line);
+                    }
+                }
+            }
+        }
+        graph
+    }
+
+    fn add_node(&mut self, id: String, label: &str, shape: Shape) {
+        if self.index.contains_key(&id) {
+            return;
+        }
+        self.index.insert(id.clone(), self.nodes.len());
+        self.nodes.push(Node {
+            id,
+            label: label.to_string(),
+            shape,
+        });
+    }
+
+    fn node_id(&mut self, ty: &TypeRef) -> String {
+        match ty {
+            TypeRef::Named { name, scope, .. } | TypeRef::Entity { name, scope, .. } => {
+                id(scope.schema_name().unwrap_or_default(), name)
+            }
+            _ => {
+                let id = ty.to_string();
+                self.add_node(id.clone(), &id, Shape::Simple);
+                id
+            }
+        }
+    }
+
+    /// Add a line from `from` to `ty`, with aggregations of `ty` appended to `label`
+    fn add_edge(&mut self, from: &str, ty: &TypeRef, label: &str, line: Line) {
+        let (prefix, base) = aggregation(ty);
+        let label = match (label.is_empty(), prefix.is_empty()) {
+            (_, true) => label.to_string(),
+            (true, false) => prefix,
+            (false, false) => format!("{} {}", label, prefix),
+        };
+        let to = self.node_id(base);
+        self.edges.push(Edge {
+            from: from.to_string(),
+            to,
+            label,
+            line,
+        });
+    }
+
+    /// Node of `id`, or `None` if the declaration is not in [IR],
+    /// e.g. removed by [IR::subset] or declared in a schema which is not loaded
+    fn node(&self, id: &str) -> Option<&Node> {
+        self.index.get(id).map(|i| &self.nodes[*i])
+    }
+
+    fn find(&self, name: &str) -> Option<&str> {
+        if let Some(i) = self.index.get(name) {
+            return Some(&self.nodes[*i].id);
+        }
+        self.nodes
+            .iter()
+            .find(|node| node.shape != Shape::Simple && node.label == name)
+            .map(|node| node.id.as_str())
+    }
+
+    /// Entities and types to be drawn, in the order of pages
+    fn select(&self, options: &GraphOptions) -> Result<Vec<&str>, GraphError> {
+        let focus = match &options.focus {
+            Some(focus) => self.find(focus).ok_or_else(|| GraphError::FocusNotFound {
+                name: focus.clone(),
+            })?,
+            None => {
+                return Ok(self
+                    .nodes
+                    .iter()
+                    .filter(|node| node.shape != Shape::Simple)
+                    .map(|node| node.id.as_str())
+                    .collect())
+            }
+        };
+
+        let mut neighbors: HashMap<&str, Vec<&str>> = HashMap::new();
+        for edge in &self.edges {
+            neighbors.entry(&edge.from).or_default().push(&edge.to);
+            neighbors.entry(&edge.to).or_default().push(&edge.from);
+        }
+        let mut selected = vec![focus];
+        let mut visited: HashSet<&str> = selected.iter().cloned().collect();
+        let mut queue = VecDeque::new();
+        queue.push_back((focus, 0));
+        while let Some((id, depth)) = queue.pop_front() {
+            if depth >= options.depth {
+                continue;
+            }
+            for next in neighbors.get(id).into_iter().flatten() {
+                let drawn = self
+                    .node(next)
+                    .map_or(false, |node| node.shape != Shape::Simple);
+                if !drawn || !visited.insert(next) {
+                    continue;
+                }
+                selected.push(next);
+                queue.push_back((next, depth + 1));
+            }
+        }
+        Ok(selected)
+    }
+
+    fn pages(&self, options: &GraphOptions) -> Result<Vec<String>, GraphError> {
+        let selected = self.select(options)?;
+        let page_size = options.page_size.unwrap_or(selected.len()).max(1);
+        let page_of: HashMap<&str, usize> = selected
+            .iter()
+            .enumerate()
+            .map(|(i, id)| (*id, i / page_size + 1))
+            .collect();
+
+        let mut pages = Vec::new();
+        for (i, chunk) in selected.chunks(page_size).enumerate() {
+            let page = i + 1;
+            let mut dot = format!("digraph page_{} {{\n", page);
+            dot += "  node [shape=box];\n";
+            dot += "  edge [arrowhead=odot];\n";
+            for node in chunk.iter().filter_map(|id| self.node(id)) {
+                dot += &node.to_dot();
+            }
+
+            let mut externals = Vec::new();
+            let mut lines = String::new();
+            for edge in &self.edges {
+                if page_of.get(edge.from.as_str()) != Some(&page) {
+                    continue;
+                }
+                // Lines to declarations not in IR are not drawn
+                let node = match self.node(&edge.to) {
+                    Some(node) => node,
+                    None => continue,
+                };
+                let to = match page_of.get(edge.to.as_str()) {
+                    Some(p) if *p == page => edge.to.clone(),
+                    Some(p) => {
+                        let reference = format!("{}@{}", edge.to, p);
+                        if !externals.contains(&reference) {
+                            dot += &format!(
+                                "  \"{}\" [label=\"{}, {}\", style=rounded];\n",
+                                reference, p, node.label
+                            );
+                            externals.push(reference.clone());
+                        }
+                        reference
+                    }
+                    None => {
+                        if node.shape != Shape::Simple {
+                            continue;
+                        }
+                        if !externals.contains(&edge.to) {
+                            dot += &node.to_dot();
+                            externals.push(edge.to.clone());
+                        }
+                        edge.to.clone()
+                    }
+                };
+                lines += &edge.to_dot(&to);
+            }
+            dot += &lines;
+            dot += "}\n";
+            pages.push(dot);
+        }
+        Ok(pages)
+    }
+}
+
+impl Node {
+    fn to_dot(&self) -> String {
+        let attrs = match self.shape {
+            Shape::Entity => format!("label=\"{}\"", self.label),
+            Shape::Defined => format!("label=\"{}\", style=dashed", self.label),
+            Shape::Select => format!("label=\"|{}\", shape=record, style=dashed", self.label),
+            Shape::Enumeration => format!("label=\"{}|\", shape=record, style=dashed", self.label),
+            Shape::Simple => format!("label=\"{}|\", shape=record", self.label),
+        };
+        format!("  \"{}\" [{}];\n", self.id, attrs)
+    }
+}
+
+impl Edge {
+    fn to_dot(&self, to: &str) -> String {
+        let mut attrs = Vec::new();
+        if !self.label.is_empty() {
+            attrs.push(format!("label=\"{}\"", self.label));
+        }
+        match self.line {
+            Line::Inheritance => attrs.push("penwidth=3".to_string()),
+            Line::Attribute { optional: true } => attrs.push("style=dashed".to_string()),
+            Line::Attribute { optional: false } | Line::Underlying => {}
+        }
+        if attrs.is_empty() {
+            format!("  \"{}\" -> \"{}\";\n", self.from, to)
+        } else {
+            format!(
+                "  \"{}\" -> \"{}\" [{}];\n",
+                self.from,
+                to,
+                attrs.join(", ")
+            )
+        }
+    }
+}
+
+/// Draw EXPRESS-G diagrams of [IR], and returns each page in DOT language
+pub fn express_g(ir: &IR, options: &GraphOptions) -> Result<Vec<String>, GraphError> {
+    Graph::new(ir).pages(options)
+}
+
+/// Render a page in DOT language into SVG by `dot` command of Graphviz run as external process
+pub fn svg(dot: &str) -> Result<String, GraphError> {
+    let graphviz = |source| GraphError::Graphviz { source };
+    let mut child = Command::new("dot")
+        .arg("-Tsvg")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .map_err(graphviz)?;
+
+    // Write input from another thread for avoiding deadlock as rustfmt in codegen::rust
+    let mut stdin = child.stdin.take().expect("Failed to open stdin");
+    let input = dot.to_string();
+    let writer = std::thread::spawn(move || stdin.write_all(input.as_bytes()));
+    let output = child.wait_with_output().map_err(graphviz)?;
+    writer
+        .join()
+        .expect("Writer thread panicked")
+        .map_err(graphviz)?;
+    if !output.status.success() {
+        return Err(graphviz(io::Error::new(
+            io::ErrorKind::Other,
+            format!("dot exited with {}", output.status),
+        )));
+    }
+    String::from_utf8(output.stdout)
+        .map_err(|err| graphviz(io::Error::new(io::ErrorKind::InvalidData, err)))
+}
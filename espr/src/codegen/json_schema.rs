@@ -0,0 +1,208 @@
+//! Generate [JSON Schema (draft 2020-12)](https://json-schema.org/draft/2020-12/json-schema-core.html)
+//! describing instances of EXPRESS schemas encoded in JSON
+//!
+//! Each entity and type in [IR] becomes a definition in `$defs` named as `schema.name`,
+//! since several schemas may declare the same name:
+//!
+//! - Entities are objects whose properties are the explicit attributes.
+//!   Attributes are `required` unless declared as `OPTIONAL` or redeclared as derived in subtypes,
+//!   and the supertypes are combined by `allOf`.
+//! - Selects are `anyOf` of the selected types, since a value may match several of them,
+//!   e.g. an entity and its supertype. Enumerations are `enum` of the items.
+//! - Aggregates are arrays with `minItems` and `maxItems` from their bounds.
+//!   `SET` and `UNIQUE` aggregates have `uniqueItems`.
+//!
+//! ```
+//! use espr::{ast::SyntaxTree, codegen::json_schema::*, ir::IR};
+//!
+//! let st = SyntaxTree::parse(r#"
+//!   SCHEMA s;
+//!     ENTITY a; x: OPTIONAL REAL; END_ENTITY;
+//!     ENTITY b SUBTYPE OF (a); y: LIST [1:?] OF a; END_ENTITY;
+//!   END_SCHEMA;
+//! "#).unwrap();
+//! let ir = IR::from_syntax_tree(&st).unwrap();
+//! let schema = json_schema(&ir);
+//! assert_eq!(
+//!     schema["$defs"]["s.b"],
+//!     serde_json::json!({
+//!         "allOf": [
+//!             { "$ref": "#/$defs/s.a" },
+//!             {
+//!                 "type": "object",
+//!                 "properties": {
+//!                     "y": { "type": "array", "items": { "$ref": "#/$defs/s.a" }, "minItems": 1 }
+//!                 },
+//!                 "required": ["y"]
+//!             }
+//!         ]
+//!     })
+//! );
+//! ```
+
+use crate::{ast, ir::*};
+use serde_json::{json, Map, Value};
+
+/// Draft of JSON Schema used in the output
+pub const DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// JSON Schema document with a definition for each entity and type in `ir`
+pub fn json_schema(ir: &IR) -> Value {
+    let mut defs = Map::new();
+    for schema in &ir.schemas {
+        for ty in &schema.types {
+            defs.insert(qualified(&schema.name, ty.id()), ty.json_schema());
+        }
+        for entity in &schema.entities {
+            defs.insert(qualified(&schema.name, &entity.name), entity.json_schema());
+        }
+    }
+    let names: Vec<&str> = ir.schemas.iter().map(|s| s.name.as_str()).collect();
+    json!({
+        "$schema": DIALECT,
+        "title": names.join(", "),
+        "$defs": defs,
+    })
+}
+
+/// Key of `$defs`, e.g. `shapes.point`
+fn qualified(schema: &str, name: &str) -> String {
+    format!("{}.{}", schema, name)
+}
+
+/// Reference to the definition of `name` declared in the schema of `scope`
+fn reference(name: &str, scope: &Scope) -> Value {
+    let name = match scope.schema_name() {
+        Some(schema) => qualified(schema, name),
+        None => name.to_string(),
+    };
+    json!({ "$ref": format!("#/$defs/{}", name) })
+}
+
+/// Add `description` from remarks if exists
+fn describe(mut value: Value, remarks: &[String]) -> Value {
+    if let (Value::Object(map), false) = (&mut value, remarks.is_empty()) {
+        map.insert("description".to_string(), remarks.join("\n").into());
+    }
+    value
+}
+
+fn simple_type(ty: &ast::SimpleType) -> Value {
+    match ty {
+        ast::SimpleType::Integer => json!({ "type": "integer" }),
+        ast::SimpleType::Real | ast::SimpleType::Number => json!({ "type": "number" }),
+        ast::SimpleType::Boolen => json!({ "type": "boolean" }),
+        // `UNKNOWN` is encoded as `null`
+        ast::SimpleType::Logical => json!({ "type": ["boolean", "null"] }),
+        ast::SimpleType::String_ { width_spec } => {
+            let mut value = json!({ "type": "string" });
+            if let Some(spec) = width_spec {
+                value["maxLength"] = spec.width.into();
+                if spec.fixed {
+                    value["minLength"] = spec.width.into();
+                }
+            }
+            value
+        }
+        // Hexadecimal digits as in exchange structure, e.g. `"0A3F"`
+        ast::SimpleType::Binary { .. } => json!({
+            "type": "string",
+            "pattern": "^[0-3][0-9A-F]*$",
+        }),
+    }
+}
+
+impl TypeRef {
+    fn json_schema(&self) -> Value {
+        match self {
+            TypeRef::SimpleType(SimpleType(ty)) => simple_type(ty),
+            TypeRef::Named { name, scope, .. } | TypeRef::Entity { name, scope, .. } => {
+                reference(name, scope)
+            }
+            TypeRef::Set { base, bound }
+            | TypeRef::Bag { base, bound }
+            | TypeRef::List { base, bound, .. }
+            | TypeRef::Array { base, bound, .. } => {
+                let items = match self {
+                    TypeRef::Array { optional: true, .. } => json!({
+                        "anyOf": [base.json_schema(), { "type": "null" }]
+                    }),
+                    _ => base.json_schema(),
+                };
+                let mut value = json!({ "type": "array", "items": items });
+                let (lower, upper) = match bound {
+                    Some(bound) => (bound.lower_value(), bound.upper_value()),
+                    None => (None, None),
+                };
+                match self {
+                    // Bound of array is of its index
+                    TypeRef::Array { .. } => {
+                        if let (Some(lower), Some(upper)) = (lower, upper) {
+                            value["minItems"] = (upper - lower + 1).into();
+                            value["maxItems"] = (upper - lower + 1).into();
+                        }
+                    }
+                    _ => {
+                        if let Some(lower) = lower.filter(|lower| *lower > 0) {
+                            value["minItems"] = lower.into();
+                        }
+                        if let Some(upper) = upper {
+                            value["maxItems"] = upper.into();
+                        }
+                    }
+                }
+                if let TypeRef::Set { .. }
+                | TypeRef::List { unique: true, .. }
+                | TypeRef::Array { unique: true, .. } = self
+                {
+                    value["uniqueItems"] = true.into();
+                }
+                value
+            }
+        }
+    }
+}
+
+impl TypeDecl {
+    fn json_schema(&self) -> Value {
+        let value = match self {
+            TypeDecl::Simple(simple) => simple_type(&simple.ty.0),
+            TypeDecl::Rename(rename) => rename.ty.json_schema(),
+            TypeDecl::Enumeration(e) => json!({ "type": "string", "enum": e.items }),
+            TypeDecl::Select(select) => {
+                let types: Vec<_> = select.types.iter().map(TypeRef::json_schema).collect();
+                json!({ "anyOf": types })
+            }
+        };
+        describe(value, &self.doc().remarks)
+    }
+}
+
+impl Entity {
+    fn json_schema(&self) -> Value {
+        let mut properties = Map::new();
+        let mut required = Vec::new();
+        for attr in &self.attributes {
+            properties.insert(
+                attr.name.clone(),
+                describe(attr.ty.json_schema(), &attr.remarks),
+            );
+            // Not given in the instances of subtypes redeclaring it as derived
+            if !attr.optional && !attr.redeclared_as_derived {
+                required.push(attr.name.clone());
+            }
+        }
+        let mut object = json!({ "type": "object", "properties": properties });
+        if !required.is_empty() {
+            object["required"] = required.into();
+        }
+        let value = if self.supertypes.is_empty() {
+            object
+        } else {
+            let mut all_of: Vec<_> = self.supertypes.iter().map(TypeRef::json_schema).collect();
+            all_of.push(object);
+            json!({ "allOf": all_of })
+        };
+        describe(value, &self.doc.remarks)
+    }
+}
@@ -1,3 +1,7 @@
 //! Code generation
 
+pub mod dot;
+pub mod json_schema;
 pub mod rust;
+pub mod sql;
+pub mod typescript;
@@ -0,0 +1,234 @@
+use crate::ir::*;
+
+use check_keyword::CheckKeyword;
+use inflector::Inflector;
+use proc_macro2::TokenStream;
+use quote::*;
+use std::collections::HashMap;
+
+/// Attribute of an entity or its supertypes, flattened into a builder
+struct BuilderAttribute<'ir> {
+    /// Entity declaring this attribute
+    entity: &'ir str,
+    attr: &'ir EntityAttribute,
+    /// Name of the setter and the field of the builder
+    setter: syn::Ident,
+}
+
+impl<'ir> BuilderAttribute<'ir> {
+    fn is_optional(&self) -> bool {
+        self.attr.optional || self.attr.redeclared_as_derived
+    }
+
+    fn key(&self) -> (&'ir str, &'ir str) {
+        (self.entity, &self.attr.name)
+    }
+}
+
+impl Entity {
+    /// Supertype entities declared in `schema`, or `None` if some of them are not found
+    fn supertype_entities<'ir>(&self, schema: &'ir Schema) -> Option<Vec<&'ir Entity>> {
+        self.supertypes
+            .iter()
+            .map(|ty| match ty {
+                TypeRef::Entity { name, .. } | TypeRef::Named { name, .. } => {
+                    schema.entities.iter().find(|e| &e.name == name)
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Attributes of this entity and its supertypes, where supertypes come first,
+    /// and attributes inherited through several paths appear once
+    fn flatten_attributes<'ir>(
+        &'ir self,
+        schema: &'ir Schema,
+        attrs: &mut Vec<(&'ir str, &'ir EntityAttribute)>,
+    ) -> Option<()> {
+        for supertype in self.supertype_entities(schema)? {
+            supertype.flatten_attributes(schema, attrs)?;
+        }
+        for attr in &self.attributes {
+            if !attrs
+                .iter()
+                .any(|(entity, a)| *entity == self.name && a.name == attr.name)
+            {
+                attrs.push((&self.name, attr));
+            }
+        }
+        Some(())
+    }
+
+    /// Count how many times each attribute is used to construct this entity
+    fn count_attributes<'ir>(
+        &'ir self,
+        schema: &'ir Schema,
+        counts: &mut HashMap<(&'ir str, &'ir str), usize>,
+    ) {
+        for supertype in self.supertype_entities(schema).unwrap_or_default() {
+            supertype.count_attributes(schema, counts);
+        }
+        for attr in &self.attributes {
+            *counts.entry((&self.name, &attr.name)).or_default() += 1;
+        }
+    }
+
+    /// Struct expression to construct this entity from the variables of attributes
+    ///
+    /// The variable is cloned except for its last use, since an attribute inherited through
+    /// several supertypes is stored in each of them.
+    fn construct<'ir>(
+        &'ir self,
+        schema: &'ir Schema,
+        attrs: &[BuilderAttribute<'ir>],
+        remaining: &mut HashMap<(&'ir str, &'ir str), usize>,
+    ) -> TokenStream {
+        let name = self.name_ident();
+        let mut fields = Vec::new();
+        for supertype in self.supertype_entities(schema).unwrap_or_default() {
+            let field = format_ident!("{}", supertype.name.as_str().into_safe());
+            let value = supertype.construct(schema, attrs, remaining);
+            fields.push(quote! { #field: #value });
+        }
+        for attr in &self.attributes {
+            let key = (self.name.as_str(), attr.name.as_str());
+            let setter = &attrs
+                .iter()
+                .find(|a| a.key() == key)
+                .expect("All attributes are flattened")
+                .setter;
+            let count = remaining.get_mut(&key).expect("All attributes are counted");
+            *count -= 1;
+            let field = format_ident!("{}", attr.name.as_str().into_safe());
+            fields.push(if *count > 0 {
+                quote! { #field: #setter.clone() }
+            } else if field == *setter {
+                quote! { #field }
+            } else {
+                quote! { #field: #setter }
+            });
+        }
+        quote! {
+            #name {
+                #(#fields,)*
+            }
+        }
+    }
+
+    /// Generate `XxxBuilder` struct and `Xxx::builder()` to construct this entity
+    ///
+    /// Attributes of supertypes are flattened into the builder,
+    /// and the setters accept any value convertible into the attribute by `Into`.
+    /// Returns `None` if some supertypes are not declared in `schema`,
+    /// or the entity has a derived or inverse attribute named `builder`.
+    ///
+    /// `ruststep` is the path to ruststep crate, i.e. `crate` or `::ruststep`.
+    pub(crate) fn generate_builder(
+        &self,
+        schema: &Schema,
+        ruststep: &syn::Path,
+    ) -> Option<TokenStream> {
+        let mut flatten = Vec::new();
+        self.flatten_attributes(schema, &mut flatten)?;
+
+        // `Xxx::builder()` conflicts with the method of a derived or inverse attribute
+        if self
+            .derived
+            .iter()
+            .map(|attr| &attr.name)
+            .chain(self.inverse.iter().map(|attr| &attr.name))
+            .any(|name| name == "builder")
+        {
+            return None;
+        }
+
+        // Attributes of the same name declared in different entities,
+        // or named `build` conflicting with `XxxBuilder::build`, are prefixed by the entity
+        let attrs: Vec<BuilderAttribute> = flatten
+            .iter()
+            .map(|(entity, attr)| {
+                let duplicated = flatten.iter().filter(|(_, a)| a.name == attr.name).count() > 1;
+                let setter = if duplicated || attr.name == "build" {
+                    format!("{}_{}", entity, attr.name)
+                } else {
+                    attr.name.clone()
+                };
+                BuilderAttribute {
+                    entity,
+                    attr,
+                    setter: format_ident!("{}", setter.as_str().into_safe()),
+                }
+            })
+            .collect();
+
+        let name = self.name_ident();
+        let builder = format_ident!("{}Builder", self.name.to_pascal_case());
+        let setters: Vec<_> = attrs.iter().map(|a| &a.setter).collect();
+        let types: Vec<_> = attrs.iter().map(|a| &a.attr.ty).collect();
+
+        let entity_name = &self.name;
+        let required = attrs.iter().filter(|a| !a.is_optional()).map(|a| {
+            let setter = &a.setter;
+            let attribute = &a.attr.name;
+            quote! {
+                let #setter = #setter.ok_or_else(|| #ruststep::error::Error::MissingAttribute {
+                    entity: #entity_name.to_string(),
+                    attribute: #attribute.to_string(),
+                })?;
+            }
+        });
+
+        let mut remaining = HashMap::new();
+        self.count_attributes(schema, &mut remaining);
+        let construct = self.construct(schema, &attrs, &mut remaining);
+
+        let builder_doc = format!(" Builder of [{}] created by [{}::builder]", name, name);
+        let method_doc = format!(
+            " Start to build [{}] with flattened attributes of supertypes",
+            name
+        );
+        let setter_docs = attrs.iter().map(|a| {
+            if a.is_optional() {
+                format!(
+                    " Set optional `{}` attribute of `{}`",
+                    a.attr.name, a.entity
+                )
+            } else {
+                format!(" Set `{}` attribute of `{}`", a.attr.name, a.entity)
+            }
+        });
+
+        Some(quote! {
+            #[doc = #builder_doc]
+            #[derive(Debug, Clone, Default)]
+            pub struct #builder {
+                #(#setters: Option<#types>,)*
+            }
+
+            impl #name {
+                #[doc = #method_doc]
+                pub fn builder() -> #builder {
+                    #builder::default()
+                }
+            }
+
+            impl #builder {
+                #(
+                #[doc = #setter_docs]
+                pub fn #setters(mut self, value: impl Into<#types>) -> Self {
+                    self.#setters = Some(value.into());
+                    self
+                }
+                )*
+
+                /// Build the entity, or returns an error if a required attribute is not set
+                pub fn build(self) -> #ruststep::error::Result<#name> {
+                    let #builder { #(#setters,)* } = self;
+                    #(#required)*
+                    Ok(#construct)
+                }
+            }
+        })
+    }
+}
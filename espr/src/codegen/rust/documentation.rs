@@ -0,0 +1,113 @@
+use crate::ir::*;
+
+use proc_macro2::TokenStream;
+use quote::*;
+use syn::parse_quote;
+
+/// Lines of a remark, where the indent common to the lines following the first one is removed
+///
+/// The first line usually follows the tag of the remark, e.g. `(*"point" ...`,
+/// and thus its leading spaces are trimmed separately.
+/// Only ASCII spaces and tabs are regarded as indent,
+/// and other whitespaces, e.g. U+3000 in Japanese text, are kept.
+fn dedent(remark: &str) -> Vec<&str> {
+    let indent = remark
+        .lines()
+        .skip(1)
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.len() - line.trim_start_matches(|c| c == ' ' || c == '\t').len())
+        .min()
+        .unwrap_or(0);
+    let mut lines: Vec<&str> = remark
+        .lines()
+        .enumerate()
+        .map(|(i, line)| {
+            if i == 0 || line.trim().is_empty() {
+                line.trim()
+            } else {
+                line[indent..].trim_end()
+            }
+        })
+        .collect();
+    while lines.last() == Some(&"") {
+        lines.pop();
+    }
+    let blank = lines.iter().take_while(|line| line.is_empty()).count();
+    lines.drain(..blank);
+    lines
+}
+
+/// Escape a line of remark not to be interpreted by rustdoc,
+/// i.e. `[...]` as an intra-doc link and a fence of backticks as a code block
+fn escape(line: &str) -> String {
+    let mut escaped = String::new();
+    let mut rest = line;
+    while let Some(c) = rest.chars().next() {
+        match c {
+            '[' | ']' => {
+                escaped.push('\\');
+                escaped.push(c);
+                rest = &rest[1..];
+            }
+            '`' => {
+                let run = rest.len() - rest.trim_start_matches('`').len();
+                if run >= 3 {
+                    escaped.push_str(&"\\`".repeat(run));
+                } else {
+                    escaped.push_str(&rest[..run]);
+                }
+                rest = &rest[run..];
+            }
+            _ => {
+                escaped.push(c);
+                rest = &rest[c.len_utf8()..];
+            }
+        }
+    }
+    escaped
+}
+
+/// `#[doc = "..."]` attributes consisting of remarks and the EXPRESS definition in a code block
+pub(super) fn doc_attributes(remarks: &[String], definition: Option<&str>) -> Vec<syn::Attribute> {
+    let mut lines: Vec<String> = Vec::new();
+    for remark in remarks {
+        if !lines.is_empty() {
+            lines.push(String::new());
+        }
+        lines.extend(dedent(remark).into_iter().map(escape));
+    }
+    if let Some(definition) = definition {
+        if !lines.is_empty() {
+            lines.push(String::new());
+        }
+        // Fence longer than backticks in the definition, e.g. in string literals
+        let longest = definition
+            .split(|c| c != '`')
+            .map(str::len)
+            .max()
+            .unwrap_or(0);
+        let fence = "`".repeat((longest + 1).max(3));
+        lines.push(format!("{}text", fence));
+        lines.extend(definition.lines().map(str::to_string));
+        lines.push(fence);
+    }
+    lines
+        .into_iter()
+        .map(|line| {
+            // `/// line` is expanded into `#[doc = " line"]`
+            let line = if line.is_empty() {
+                String::new()
+            } else {
+                format!(" {}", line)
+            };
+            parse_quote! { #[doc = #line] }
+        })
+        .collect()
+}
+
+impl ToTokens for Documentation {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let attributes = doc_attributes(&self.remarks, self.definition.as_deref());
+        tokens.append_all(quote! { #( #attributes )* });
+    }
+}
@@ -0,0 +1,167 @@
+use crate::ir::*;
+
+use proc_macro2::TokenStream;
+use quote::*;
+
+/// Variant of `XxxAny` enum, a subtype or the entity itself
+struct Variant<'ir> {
+    entity: &'ir Entity,
+    /// `Box<XxxAny>` if the subtype is also a supertype
+    nested: bool,
+}
+
+impl Entity {
+    /// Variants of `XxxAny` enum, or `None` if some subtypes are not found in `schema`
+    fn any_variants<'ir>(&'ir self, schema: &'ir Schema) -> Option<Vec<Variant<'ir>>> {
+        let mut variants = vec![Variant {
+            entity: self,
+            nested: false,
+        }];
+        for ty in &self.constraints {
+            if let TypeRef::Entity {
+                name, is_supertype, ..
+            } = ty
+            {
+                variants.push(Variant {
+                    entity: schema.entities.iter().find(|e| &e.name == name)?,
+                    nested: *is_supertype,
+                });
+            }
+        }
+        Some(variants)
+    }
+
+    /// Entities in the subtype tree of this entity including itself, without duplication
+    fn subtype_tree<'ir>(
+        &'ir self,
+        schema: &'ir Schema,
+        tree: &mut Vec<&'ir Entity>,
+    ) -> Option<()> {
+        for variant in self.any_variants(schema)? {
+            if variant.nested {
+                variant.entity.subtype_tree(schema, tree)?;
+            } else if !tree.iter().any(|e| e.name == variant.entity.name) {
+                tree.push(variant.entity);
+            }
+        }
+        Some(())
+    }
+
+    /// Generate `downcast_ref`, `is`, and `TryFrom<XxxAny>` for the entities in the subtype tree,
+    /// and an iterator over `XxxAny` in `Tables`
+    ///
+    /// `ruststep` is the path to ruststep crate, i.e. `crate` or `::ruststep`.
+    pub(crate) fn generate_downcast(
+        &self,
+        schema: &Schema,
+        ruststep: &syn::Path,
+    ) -> Option<TokenStream> {
+        if self.constraints.is_empty() {
+            return None;
+        }
+        let variants = self.any_variants(schema)?;
+        let mut tree = Vec::new();
+        self.subtype_tree(schema, &mut tree)?;
+
+        let any = self.any_ident();
+        let iter = format_ident!("{}_any_iter", self.name);
+
+        let downcast_arms = variants.iter().map(|v| {
+            let variant = v.entity.name_ident();
+            if v.nested {
+                quote! {
+                    #any::#variant(x) => (x.as_ref() as &dyn ::std::any::Any)
+                        .downcast_ref()
+                        .or_else(|| x.downcast_ref()),
+                }
+            } else {
+                quote! {
+                    #any::#variant(x) => (x.as_ref() as &dyn ::std::any::Any).downcast_ref(),
+                }
+            }
+        });
+
+        // Subtype tree of each nested variant, computed once for every entity in the tree
+        let variant_trees = variants
+            .iter()
+            .map(|v| {
+                let mut tree = Vec::new();
+                if v.nested {
+                    v.entity.subtype_tree(schema, &mut tree)?;
+                }
+                Some(tree)
+            })
+            .collect::<Option<Vec<_>>>()?;
+
+        let try_from =
+            tree.iter().map(|entity| {
+                let name = entity.name_ident();
+                let arms = variants.iter().zip(&variant_trees).filter_map(|(v, within)| {
+                let variant = v.entity.name_ident();
+                if !v.nested {
+                    return if v.entity.name == entity.name {
+                        Some(quote! { #any::#variant(x) => Ok(*x), })
+                    } else {
+                        None
+                    };
+                }
+                if within.iter().any(|e| e.name == entity.name) {
+                    Some(quote! {
+                        #any::#variant(x) => <#name as ::std::convert::TryFrom<_>>::try_from(*x)
+                            .map_err(|x| #any::#variant(Box::new(x))),
+                    })
+                } else {
+                    None
+                }
+            });
+                let arms: Vec<_> = arms.collect();
+                quote! {
+                    impl ::std::convert::TryFrom<#any> for #name {
+                        type Error = #any;
+                        fn try_from(any: #any) -> ::std::result::Result<Self, #any> {
+                            match any {
+                                #(#arms)*
+                                _ => Err(any),
+                            }
+                        }
+                    }
+                }
+            });
+
+        let doc = format!(
+            " Every instance of `{}` and its subtypes as the concrete entity wrapped in [{}]",
+            self.name, any
+        );
+        let doc_detail = format!(
+            " Shorthand of `EntityTable::<{}Holder>::owned_iter`, which yields the instances table by table, \
+              i.e. not in the order of their ids.",
+            any
+        );
+        Some(quote! {
+            impl #any {
+                /// Reference to the concrete entity if it is `T`, following nested `Any` enums of subtypes
+                pub fn downcast_ref<T: 'static>(&self) -> Option<&T> {
+                    match self {
+                        #(#downcast_arms)*
+                    }
+                }
+
+                /// Check if the concrete entity is `T`
+                pub fn is<T: 'static>(&self) -> bool {
+                    self.downcast_ref::<T>().is_some()
+                }
+            }
+
+            #(#try_from)*
+
+            impl Tables {
+                #[doc = #doc]
+                #[doc = ""]
+                #[doc = #doc_detail]
+                pub fn #iter(&self) -> impl Iterator<Item = #ruststep::error::Result<#any>> + '_ {
+                    #ruststep::tables::EntityTable::<#ruststep::as_holder!(#any)>::owned_iter(self)
+                }
+            }
+        })
+    }
+}
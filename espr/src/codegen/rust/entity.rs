@@ -1,3 +1,4 @@
+use super::{documentation::doc_attributes, schema::schema_module};
 use crate::ir::*;
 
 use check_keyword::CheckKeyword;
@@ -19,28 +20,43 @@ struct Field {
 ///
 /// - a simple type
 /// - an enumeration
-/// - a set or list whose base type use place holder
+/// - an aggregate whose base type use place holder
 ///
 fn use_place_holder(ty: &TypeRef) -> bool {
     match ty {
         TypeRef::SimpleType(..) => false,
         TypeRef::Named { is_enumerate, .. } => !*is_enumerate,
-        TypeRef::Set { base, .. } | TypeRef::List { base, .. } => use_place_holder(base),
+        TypeRef::Set { base, .. }
+        | TypeRef::Bag { base, .. }
+        | TypeRef::List { base, .. }
+        | TypeRef::Array { base, .. } => use_place_holder(base),
         _ => true,
     }
 }
 
 impl From<EntityAttribute> for Field {
     fn from(attr: EntityAttribute) -> Self {
-        let EntityAttribute { name, ty, optional } = attr;
+        let EntityAttribute {
+            name,
+            ty,
+            optional,
+            redeclared_as_derived,
+            remarks,
+        } = attr;
 
         let name = format_ident!("{}", name.into_safe());
-        let attributes = if use_place_holder(&ty) {
-            vec![parse_quote! { #[holder(use_place_holder)] }]
-        } else {
-            Vec::new()
-        };
-        let ty = if optional {
+        let mut attributes = doc_attributes(&remarks, None);
+        // Attribute redeclared as derived in subtypes may be `*` in exchange structure,
+        // and only such attributes of the supertype become `Option<T>` even if not `OPTIONAL`
+        if redeclared_as_derived && !optional {
+            let doc = " `None` in instances of a subtype redeclaring this attribute as derived";
+            attributes.push(parse_quote! { #[doc = #doc] });
+        }
+        if use_place_holder(&ty) {
+            attributes.push(parse_quote! { #[holder(use_place_holder)] });
+        }
+        attributes.extend(ty.size_attribute());
+        let ty = if optional || redeclared_as_derived {
             parse_quote! { Option<#ty> }
         } else {
             parse_quote! { #ty }
@@ -71,11 +87,11 @@ impl ToTokens for Field {
 
 // Additional functions to use in codegen/rust for ir::Entity.
 impl Entity {
-    fn name_ident(&self) -> syn::Ident {
+    pub(super) fn name_ident(&self) -> syn::Ident {
         format_ident!("{}", self.name.to_pascal_case())
     }
 
-    fn any_ident(&self) -> syn::Ident {
+    pub(super) fn any_ident(&self) -> syn::Ident {
         // `Any` indentifier must be appears if the entity is supertype
         assert!(!self.constraints.is_empty());
         format_ident!("{}Any", self.name.to_pascal_case())
@@ -233,6 +249,122 @@ impl Entity {
             .collect()
     }
 
+    /// Generate methods for derived attributes
+    ///
+    /// `ruststep` is the path to ruststep crate, i.e. `crate` or `::ruststep`.
+    pub(crate) fn generate_derived(&self, ruststep: &syn::Path) -> Option<TokenStream> {
+        if self.derived.is_empty() {
+            return None;
+        }
+        let name = self.name_ident();
+        let methods = self
+            .derived
+            .iter()
+            .map(|attr| attr.generate_method(&self.name, ruststep));
+        Some(quote! {
+            impl #name {
+                #(#methods)*
+            }
+        })
+    }
+
+    /// Generate methods for inverse attributes
+    ///
+    /// `schema` is the schema declaring this entity,
+    /// and `ruststep` is the path to ruststep crate, i.e. `crate` or `::ruststep`.
+    pub(crate) fn generate_inverse(
+        &self,
+        schema: &Schema,
+        ruststep: &syn::Path,
+    ) -> Option<TokenStream> {
+        if self.inverse.is_empty() {
+            return None;
+        }
+        let name = self.name_ident();
+
+        let mut methods = Vec::new();
+        let mut checks = Vec::new();
+        for inverse in &self.inverse {
+            let method = format_ident!("{}", inverse.name.as_str().into_safe());
+            let attribute = format_ident!("{}", inverse.attribute.as_str().into_safe());
+            let referrers = inverse.referrers.iter().map(|(ty, supertypes)| {
+                let table = match ty {
+                    // Referrers interfaced from another schema are held in its nested tables
+                    TypeRef::Entity { name, scope, .. } => match scope.schema_name() {
+                        Some(foreign) if foreign != schema.name => {
+                            let foreign = schema_module(foreign);
+                            let holders = format_ident!("{}_holders", name);
+                            quote! {
+                                #ruststep::tables::ForeignTable::<#foreign::Tables>::foreign_table(tables).#holders()
+                            }
+                        }
+                        _ => {
+                            let table = format_ident!("{}", name.as_str().into_safe());
+                            quote! { &tables.#table }
+                        }
+                    },
+                    _ => unreachable!(),
+                };
+                let referred =
+                    quote! { #ruststep::tables::Refer::referred_ids(&holder.#attribute, ids) };
+                if supertypes.is_empty() {
+                    return quote! {
+                        #ruststep::tables::index_referrers(&mut index, #table, |holder, ids| #referred);
+                    };
+                }
+                // Supertype part of the holder struct is checked only when it is owned
+                let supertypes = supertypes
+                    .iter()
+                    .map(|name| format_ident!("{}", name.as_str().into_safe()));
+                quote! {
+                    #ruststep::tables::index_referrers(&mut index, #table, |holder, ids| {
+                        if let Some(holder) = Some(holder)
+                            #(.and_then(|holder| holder.#supertypes.as_owned()))*
+                        {
+                            #referred
+                        }
+                    });
+                }
+            });
+            let key = format!("{}.{}", self.name, inverse.name);
+            methods.push(quote! {
+                pub fn #method(&self, id: u64, tables: &Tables) -> Vec<u64> {
+                    tables._inverse_index.lookup(#key, id, || {
+                        let mut index = ::std::collections::HashMap::new();
+                        #(#referrers)*
+                        index
+                    })
+                }
+            });
+
+            let attribute_name = &inverse.name;
+            let lower = inverse.lower;
+            let upper = match inverse.upper {
+                Some(upper) => quote! { Some(#upper) },
+                None => quote! { None },
+            };
+            checks.push(quote! {
+                #ruststep::tables::check_cardinality(
+                    #attribute_name,
+                    id,
+                    self.#method(id, tables).len(),
+                    #lower,
+                    #upper,
+                )?;
+            });
+        }
+
+        Some(quote! {
+            impl #name {
+                #(#methods)*
+                pub fn check_inverse(&self, id: u64, tables: &Tables) -> #ruststep::error::Result<()> {
+                    #(#checks)*
+                    Ok(())
+                }
+            }
+        })
+    }
+
     fn derives(&self) -> Vec<syn::Path> {
         let mut derives = vec![
             syn::parse_str("Debug").unwrap(),
@@ -270,8 +402,10 @@ impl ToTokens for Entity {
         let supertype_fields = self.supertype_fields();
 
         let derive = self.derives();
+        let doc = &self.doc;
 
         tokens.append_all(quote! {
+            #doc
             #( #[derive(#derive)] )*
             #[holder(table = Tables)]
             #[holder(field = #field_name)]
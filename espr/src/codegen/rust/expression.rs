@@ -0,0 +1,263 @@
+use check_keyword::CheckKeyword;
+use proc_macro2::{Literal as LitToken, TokenStream};
+use quote::*;
+
+use crate::{ast, ir::*};
+
+/// Rust value kind of a generated expression
+///
+/// Every numerical value is evaluated as `f64`, and casted at the end.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Number,
+    Bool,
+    String,
+    Aggregate,
+    Optional,
+}
+
+fn simple_kind(ty: &TypeRef) -> Option<Kind> {
+    use ast::SimpleType::*;
+    match ty {
+        TypeRef::SimpleType(SimpleType(ty)) => match ty {
+            Number | Real | Integer => Some(Kind::Number),
+            Boolen => Some(Kind::Bool),
+            String_ { .. } => Some(Kind::String),
+            _ => None,
+        },
+        TypeRef::Set { .. }
+        | TypeRef::Bag { .. }
+        | TypeRef::List { .. }
+        | TypeRef::Array { .. } => Some(Kind::Aggregate),
+        _ => None,
+    }
+}
+
+fn is_integer(ty: &TypeRef) -> bool {
+    matches!(
+        ty,
+        TypeRef::SimpleType(SimpleType(ast::SimpleType::Integer))
+    )
+}
+
+fn receiver(supertypes: &[String]) -> TokenStream {
+    let supertypes = supertypes
+        .iter()
+        .map(|name| format_ident!("{}", name.as_str().into_safe()));
+    quote! { self #(.#supertypes)* }
+}
+
+/// Translate EXPRESS expression into Rust expression
+///
+/// Returns `None` if the expression cannot be translated.
+fn translate(expr: &Expression) -> Option<(TokenStream, Kind)> {
+    use ast::{BinaryOperator::*, BuiltInFunction::*, RelationOperator::*, UnaryOperator::*};
+    Some(match expr {
+        Expression::Literal(Literal::Real(value)) => {
+            let value = LitToken::f64_suffixed(*value);
+            (quote! { #value }, Kind::Number)
+        }
+        Expression::Literal(Literal::String(value)) => {
+            (quote! { #value.to_string() }, Kind::String)
+        }
+        Expression::Literal(Literal::Logical(value)) => match value {
+            ast::Logical::True => (quote! { true }, Kind::Bool),
+            ast::Logical::False => (quote! { false }, Kind::Bool),
+            ast::Logical::Unknown => return None,
+        },
+        Expression::Attribute {
+            supertypes,
+            name,
+            ty,
+            optional,
+        } => {
+            let receiver = receiver(supertypes);
+            let name = format_ident!("{}", name.as_str().into_safe());
+            if *optional {
+                return Some((quote! { #receiver.#name }, Kind::Optional));
+            }
+            match simple_kind(ty)? {
+                Kind::Number if is_integer(ty) => {
+                    (quote! { (#receiver.#name as f64) }, Kind::Number)
+                }
+                Kind::Number | Kind::Bool => (quote! { #receiver.#name }, simple_kind(ty)?),
+                Kind::String => (quote! { #receiver.#name.clone() }, Kind::String),
+                kind => (quote! { #receiver.#name }, kind),
+            }
+        }
+        Expression::Derived {
+            supertypes,
+            name,
+            ty,
+        } => {
+            let receiver = receiver(supertypes);
+            let name = format_ident!("{}", name.as_str().into_safe());
+            match simple_kind(ty)? {
+                Kind::Number if is_integer(ty) => {
+                    (quote! { (#receiver.#name()? as f64) }, Kind::Number)
+                }
+                Kind::Aggregate => return None,
+                kind => (quote! { #receiver.#name()? }, kind),
+            }
+        }
+        Expression::Unary { op, arg } => {
+            let (arg, kind) = translate(arg)?;
+            match (op, kind) {
+                (Plus, Kind::Number) => (quote! { #arg }, Kind::Number),
+                (Minus, Kind::Number) => (quote! { (-#arg) }, Kind::Number),
+                (Not, Kind::Bool) => (quote! { (!#arg) }, Kind::Bool),
+                _ => return None,
+            }
+        }
+        Expression::Binary { op, arg1, arg2 } => {
+            let (a, kind1) = translate(arg1)?;
+            let (b, kind2) = translate(arg2)?;
+            match (op, kind1, kind2) {
+                (Add, Kind::Number, Kind::Number) => (quote! { (#a + #b) }, Kind::Number),
+                (Sub, Kind::Number, Kind::Number) => (quote! { (#a - #b) }, Kind::Number),
+                (Mul, Kind::Number, Kind::Number) => (quote! { (#a * #b) }, Kind::Number),
+                (RealDiv, Kind::Number, Kind::Number) => (quote! { (#a / #b) }, Kind::Number),
+                (IntegerDiv, Kind::Number, Kind::Number) => {
+                    (quote! { (#a / #b).trunc() }, Kind::Number)
+                }
+                // `MOD` is an integer operation whose result has the sign of the divisor
+                (Mod, Kind::Number, Kind::Number) => (
+                    quote! {
+                        ({
+                            let (a, b) = (#a as i64, #b as i64);
+                            let r = a.rem_euclid(b);
+                            (if b < 0 && r != 0 { r + b } else { r }) as f64
+                        })
+                    },
+                    Kind::Number,
+                ),
+                (Power, Kind::Number, Kind::Number) => (quote! { #a.powf(#b) }, Kind::Number),
+                (And, Kind::Bool, Kind::Bool) => (quote! { (#a && #b) }, Kind::Bool),
+                (Or, Kind::Bool, Kind::Bool) => (quote! { (#a || #b) }, Kind::Bool),
+                (Xor, Kind::Bool, Kind::Bool) => (quote! { (#a ^ #b) }, Kind::Bool),
+                (Add, Kind::String, Kind::String) => (quote! { (#a + &#b) }, Kind::String),
+                _ => return None,
+            }
+        }
+        Expression::Relation { op, lhs, rhs } => {
+            let (a, kind1) = translate(lhs)?;
+            let (b, kind2) = translate(rhs)?;
+            if kind1 != kind2 || !matches!(kind1, Kind::Number | Kind::Bool | Kind::String) {
+                return None;
+            }
+            let tt = match op {
+                Equal => quote! { (#a == #b) },
+                NotEqual => quote! { (#a != #b) },
+                Lt => quote! { (#a < #b) },
+                Gt => quote! { (#a > #b) },
+                Leq => quote! { (#a <= #b) },
+                Geq => quote! { (#a >= #b) },
+                _ => return None,
+            };
+            (tt, Kind::Bool)
+        }
+        Expression::BuiltInFunction { name, args } => {
+            let args = args.iter().map(translate).collect::<Option<Vec<_>>>()?;
+            let number = |f: &str| match args.as_slice() {
+                [(arg, Kind::Number)] => {
+                    let f = format_ident!("{}", f);
+                    Some((quote! { #arg.#f() }, Kind::Number))
+                }
+                _ => None,
+            };
+            match name {
+                ABS => number("abs")?,
+                ACOS => number("acos")?,
+                ASIN => number("asin")?,
+                COS => number("cos")?,
+                EXP => number("exp")?,
+                LOG => number("ln")?,
+                LOG2 => number("log2")?,
+                LOG10 => number("log10")?,
+                SIN => number("sin")?,
+                SQRT => number("sqrt")?,
+                TAN => number("tan")?,
+                ATAN => match args.as_slice() {
+                    [(a, Kind::Number), (b, Kind::Number)] => {
+                        (quote! { #a.atan2(#b) }, Kind::Number)
+                    }
+                    _ => return None,
+                },
+                ODD => match args.as_slice() {
+                    [(a, Kind::Number)] => (quote! { (#a as i64 % 2 != 0) }, Kind::Bool),
+                    _ => return None,
+                },
+                EXISTS => match args.as_slice() {
+                    [(a, Kind::Optional)] => (quote! { #a.is_some() }, Kind::Bool),
+                    [(_, _)] => (quote! { true }, Kind::Bool),
+                    _ => return None,
+                },
+                SIZEOF | HIINDEX => match args.as_slice() {
+                    [(a, Kind::Aggregate)] => (quote! { (#a.len() as f64) }, Kind::Number),
+                    _ => return None,
+                },
+                LENGTH => match args.as_slice() {
+                    [(a, Kind::String)] => (quote! { (#a.chars().count() as f64) }, Kind::Number),
+                    _ => return None,
+                },
+                _ => return None,
+            }
+        }
+        Expression::Unsupported => return None,
+    })
+}
+
+impl DerivedAttribute {
+    /// Generate a method evaluating this derived attribute of `entity`
+    ///
+    /// The method returns `Result<T>` where `T` is the type of the attribute.
+    /// If the expression or the type is not supported yet,
+    /// it returns `Error::UnsupportedDerivedAttribute` instead of the value.
+    /// `ruststep` is the path to ruststep crate, i.e. `crate` or `::ruststep`.
+    pub(crate) fn generate_method(&self, entity: &str, ruststep: &syn::Path) -> TokenStream {
+        let name = format_ident!("{}", self.name.as_str().into_safe());
+        let ty = &self.ty;
+        if let Some(body) = self.translate() {
+            return quote! {
+                pub fn #name(&self) -> #ruststep::error::Result<#ty> {
+                    Ok(#body)
+                }
+            };
+        }
+        let attribute = &self.name;
+        let doc = format!(
+            " Derived attribute `{}`, whose expression is not supported yet and always returns an error",
+            self.name
+        );
+        quote! {
+            #[doc = #doc]
+            pub fn #name(&self) -> #ruststep::error::Result<#ty> {
+                Err(#ruststep::error::Error::UnsupportedDerivedAttribute {
+                    entity: #entity.to_string(),
+                    attribute: #attribute.to_string(),
+                })
+            }
+        }
+    }
+
+    /// Body of the method, or `None` if it cannot be translated
+    fn translate(&self) -> Option<TokenStream> {
+        let (expr, kind) = translate(&self.expr)?;
+        // Value of the underlying simple type is wrapped by the named types from the innermost
+        let (value_ty, wrappers): (_, Vec<&TypeRef>) = match self.underlying.split_last() {
+            Some((simple, named)) => (simple, named.iter().rev().chain(Some(&self.ty)).collect()),
+            None => (&self.ty, Vec::new()),
+        };
+        let mut body = match (simple_kind(value_ty)?, kind) {
+            (Kind::Number, Kind::Number) if is_integer(value_ty) => quote! { #expr as i64 },
+            (Kind::Number, Kind::Number)
+            | (Kind::Bool, Kind::Bool)
+            | (Kind::String, Kind::String) => expr,
+            _ => return None,
+        };
+        for ty in wrappers {
+            body = quote! { #ty::from(#body) };
+        }
+        Some(body)
+    }
+}
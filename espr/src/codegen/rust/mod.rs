@@ -1,12 +1,20 @@
 //! Generate Rust code using proc-macro utility crates
 
+mod builder;
+mod documentation;
+mod downcast;
 mod entity;
+mod expression;
 mod format;
+mod reflection;
 mod schema;
 mod simple_type;
+mod split;
 mod type_decl;
 mod type_ref;
+mod visit;
 
 pub use entity::*;
-pub use format::rustfmt;
+pub use format::{rustfmt, try_rustfmt};
 pub use schema::*;
+pub use split::*;
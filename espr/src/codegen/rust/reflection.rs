@@ -0,0 +1,172 @@
+//! Generate `SCHEMA_INFO` describing the schema at runtime
+
+use crate::ir::*;
+
+use proc_macro2::TokenStream;
+use quote::*;
+
+/// `Option<i64>` literal
+fn option(value: Option<i64>) -> TokenStream {
+    match value {
+        Some(value) => quote! { Some(#value) },
+        None => quote! { None },
+    }
+}
+
+/// Names of entities or types referred by `types`
+fn names(types: &[TypeRef]) -> Vec<&str> {
+    types
+        .iter()
+        .filter_map(|ty| match ty {
+            TypeRef::Named { name, .. } | TypeRef::Entity { name, .. } => Some(name.as_str()),
+            _ => None,
+        })
+        .collect()
+}
+
+/// `AttributeType` expression for `ty`, where `info` is the path to `ruststep::reflection`
+fn attribute_type(ty: &TypeRef, info: &TokenStream) -> TokenStream {
+    match ty {
+        TypeRef::SimpleType(SimpleType(simple)) => {
+            let simple = simple.to_string();
+            quote! { #info::AttributeType::Simple(#simple) }
+        }
+        TypeRef::Named { name, .. } => quote! { #info::AttributeType::Named(#name) },
+        TypeRef::Entity { name, .. } => quote! { #info::AttributeType::Entity(#name) },
+        TypeRef::Set { base, bound }
+        | TypeRef::Bag { base, bound }
+        | TypeRef::List { base, bound, .. }
+        | TypeRef::Array { base, bound, .. } => {
+            let variant = match ty {
+                TypeRef::Set { .. } => format_ident!("Set"),
+                TypeRef::Bag { .. } => format_ident!("Bag"),
+                TypeRef::List { .. } => format_ident!("List"),
+                _ => format_ident!("Array"),
+            };
+            let base = attribute_type(base, info);
+            let lower = option(bound.as_ref().and_then(Bound::lower_value));
+            let upper = option(bound.as_ref().and_then(Bound::upper_value));
+            let optional = match ty {
+                TypeRef::Array { optional, .. } => quote! { optional: #optional, },
+                _ => TokenStream::new(),
+            };
+            quote! {
+                #info::AttributeType::#variant {
+                    base: &#base,
+                    lower: #lower,
+                    upper: #upper,
+                    #optional
+                }
+            }
+        }
+    }
+}
+
+/// `SubtypeConstraint` expression for `expr`
+fn subtype_constraint(expr: &ConstraintExpr, info: &TokenStream) -> TokenStream {
+    let (variant, exprs) = match expr {
+        ConstraintExpr::Reference(path) => {
+            let name = &path.name;
+            return quote! { #info::SubtypeConstraint::Reference(#name) };
+        }
+        ConstraintExpr::AndOr(exprs) => (format_ident!("AndOr"), exprs),
+        ConstraintExpr::And(exprs) => (format_ident!("And"), exprs),
+        ConstraintExpr::OneOf(exprs) => (format_ident!("OneOf"), exprs),
+    };
+    let exprs = exprs.iter().map(|e| subtype_constraint(e, info));
+    quote! { #info::SubtypeConstraint::#variant(&[#(#exprs),*]) }
+}
+
+impl Entity {
+    fn entity_info(&self, info: &TokenStream) -> TokenStream {
+        let name = &self.name;
+        let attributes = self.attributes.iter().map(|attr| {
+            let name = &attr.name;
+            let ty = attribute_type(&attr.ty, info);
+            let optional = attr.optional;
+            let redeclared_as_derived = attr.redeclared_as_derived;
+            quote! {
+                #info::AttributeInfo {
+                    name: #name,
+                    ty: #ty,
+                    optional: #optional,
+                    redeclared_as_derived: #redeclared_as_derived,
+                }
+            }
+        });
+        let supertypes = names(&self.supertypes);
+        let subtypes = names(&self.constraints);
+        let subtype_constraint = match &self.subtype_constraint {
+            Some(expr) => {
+                let expr = subtype_constraint(expr, info);
+                quote! { Some(#expr) }
+            }
+            None => quote! { None },
+        };
+        quote! {
+            #info::EntityInfo {
+                name: #name,
+                attributes: &[#(#attributes),*],
+                supertypes: &[#(#supertypes),*],
+                subtypes: &[#(#subtypes),*],
+                subtype_constraint: #subtype_constraint,
+            }
+        }
+    }
+}
+
+impl TypeDecl {
+    fn type_info(&self, info: &TokenStream) -> TokenStream {
+        let name = self.id();
+        let kind = match self {
+            TypeDecl::Simple(simple) => {
+                let ty = attribute_type(&TypeRef::SimpleType(simple.ty.clone()), info);
+                quote! { #info::TypeKind::Defined(#ty) }
+            }
+            TypeDecl::Rename(rename) => {
+                let ty = attribute_type(&rename.ty, info);
+                quote! { #info::TypeKind::Defined(#ty) }
+            }
+            TypeDecl::Enumeration(e) => {
+                let items = &e.items;
+                quote! { #info::TypeKind::Enumeration(&[#(#items),*]) }
+            }
+            TypeDecl::Select(select) => {
+                let names = names(&select.types);
+                quote! { #info::TypeKind::Select(&[#(#names),*]) }
+            }
+        };
+        quote! {
+            #info::TypeInfo {
+                name: #name,
+                kind: #kind,
+            }
+        }
+    }
+}
+
+impl Schema {
+    /// `SCHEMA_INFO` static and `entity_info` function to inspect this schema at runtime
+    ///
+    /// `ruststep` is the path to ruststep crate, i.e. `crate` or `::ruststep`.
+    pub(super) fn schema_info(&self, ruststep: &syn::Path) -> TokenStream {
+        let info = quote! { #ruststep::reflection };
+        let name = &self.name;
+        let entities = self.entities.iter().map(|e| e.entity_info(&info));
+        let types = self.types.iter().map(|t| t.type_info(&info));
+        let doc = format!(" Entities and types declared in `{}` schema", name);
+        quote! {
+            #[doc = #doc]
+            pub static SCHEMA_INFO: #info::SchemaInfo = #info::SchemaInfo {
+                name: #name,
+                entities: &[#(#entities),*],
+                types: &[#(#types),*],
+            };
+
+            /// Look up an entity in [SCHEMA_INFO] by name case-insensitively
+            pub fn entity_info(name: &str) -> Option<&'static #info::EntityInfo> {
+                SCHEMA_INFO.entity(name)
+            }
+        }
+    }
+}
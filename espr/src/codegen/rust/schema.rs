@@ -4,6 +4,7 @@ use check_keyword::CheckKeyword;
 use inflector::Inflector;
 use proc_macro2::TokenStream;
 use quote::*;
+use std::collections::{BTreeMap, BTreeSet};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum CratePrefix {
@@ -20,74 +21,318 @@ impl CratePrefix {
     }
 }
 
+/// Rust module of the schema `name`, e.g. `geometry` for `SCHEMA Geometry;`
+pub(super) fn schema_module(name: &str) -> syn::Ident {
+    format_ident!("{}", name.to_snake_case().into_safe())
+}
+
+/// Optional items generated in addition to the structs, enums and `Tables`,
+/// all disabled by default to keep the generated code small
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Generators {
+    /// `Xxx::builder()` and `XxxBuilder` for each entity
+    pub builder: bool,
+    /// `visit::Visit` and `visit_mut::VisitMut` traits for each schema
+    pub visit: bool,
+    /// `SCHEMA_INFO` and `entity_info` to inspect each schema at runtime
+    pub reflection: bool,
+    /// `downcast_ref`, `is`, `TryFrom<XxxAny>` and `Tables::xxx_any_iter` for each supertype
+    pub downcast: bool,
+}
+
+impl Generators {
+    /// Enable all generators
+    pub fn all() -> Self {
+        Generators {
+            builder: true,
+            visit: true,
+            reflection: true,
+            downcast: true,
+        }
+    }
+}
+
 impl IR {
+    /// Generate Rust code without optional items, see [IR::to_token_stream_with]
     pub fn to_token_stream(&self, prefix: CratePrefix) -> TokenStream {
+        self.to_token_stream_with(prefix, Generators::default())
+    }
+
+    /// Generate Rust code with the optional items enabled in `generators`
+    pub fn to_token_stream_with(&self, prefix: CratePrefix, generators: Generators) -> TokenStream {
         let schemas: Vec<_> = self
             .schemas
             .iter()
-            .map(|schema| schema.to_token_stream(prefix))
+            .map(|schema| schema.to_token_stream_with(prefix, generators))
             .collect();
         quote! { #(#schemas)* }
     }
 }
 
 impl Schema {
-    pub fn to_token_stream(&self, prefix: CratePrefix) -> TokenStream {
-        let name = format_ident!("{}", self.name);
-        let types = &self.types;
-        let entities = &self.entities;
-        let type_decls = self.types.iter().filter(|e| match e {
-            TypeDecl::Enumeration(_) => false,
-            _ => true,
+    /// Entities and types declared in other schemas and referred from this schema
+    /// by `USE FROM` or `REFERENCE FROM`, grouped by the name of the declaring schema
+    ///
+    /// Each name comes with its place holder, e.g. `XxxHolder`, except enumerations.
+    fn interfaced(&self) -> BTreeMap<&str, BTreeSet<String>> {
+        let mut interfaced: BTreeMap<&str, BTreeSet<String>> = BTreeMap::new();
+        let refs = self
+            .entities
+            .iter()
+            .flat_map(Entity::dependencies)
+            .chain(self.types.iter().flat_map(TypeDecl::dependencies));
+        for ty in refs {
+            let (name, scope, has_holder) = match ty {
+                TypeRef::Named {
+                    name,
+                    scope,
+                    is_enumerate,
+                    ..
+                } => (name.to_pascal_case(), scope, !is_enumerate),
+                TypeRef::Entity {
+                    name,
+                    scope,
+                    is_supertype,
+                } => {
+                    let name = if *is_supertype {
+                        format!("{}Any", name.to_pascal_case())
+                    } else {
+                        name.to_pascal_case()
+                    };
+                    (name, scope, true)
+                }
+                _ => continue,
+            };
+            let schema = match scope.schema_name() {
+                Some(schema) if schema != self.name => schema,
+                _ => continue,
+            };
+            let names = interfaced.entry(schema).or_default();
+            if has_holder {
+                names.insert(format!("{}Holder", name));
+            }
+            names.insert(name);
+        }
+        interfaced
+    }
+
+    /// `use` declarations importing the entities and types interfaced from other schemas,
+    /// and the modules of these schemas to refer their `Tables`
+    pub(super) fn imports(&self) -> TokenStream {
+        let imports = self.interfaced().into_iter().map(|(schema, names)| {
+            let schema = schema_module(schema);
+            let names = names.iter().map(|name| format_ident!("{}", name));
+            quote! { use super::#schema::{self, #(#names),*}; }
         });
-        let entity_types: Vec<_> = entities
+        quote! { #(#imports)* }
+    }
+
+    /// `Tables` struct holding all entities and types except enumerations
+    ///
+    /// `ruststep` is the path to ruststep crate, `vis` is the visibility of the fields, and
+    /// `cfg` returns attributes to put on the field and methods for each entity or type name,
+    /// e.g. `#[cfg(feature = "...")]`.
+    pub(super) fn tables(
+        &self,
+        ruststep: &syn::Path,
+        vis: TokenStream,
+        cfg: impl Fn(&str) -> TokenStream,
+    ) -> TokenStream {
+        let type_decls = self
+            .types
             .iter()
-            .map(|e| format_ident!("{}", e.name.to_pascal_case()))
-            .chain(
-                type_decls
-                    .clone()
-                    .map(|e| format_ident!("{}", e.id().to_pascal_case())),
-            )
+            .filter(|e| !matches!(e, TypeDecl::Enumeration(_)));
+        let names: Vec<&str> = self
+            .entities
+            .iter()
+            .map(|e| e.name.as_str())
+            .chain(type_decls.map(|e| e.id()))
             .collect();
-        let holder_name: Vec<_> = entities
+        let cfgs: Vec<_> = names.iter().map(|name| cfg(name)).collect();
+        let entity_types: Vec<_> = names
             .iter()
-            .map(|e| format_ident!("{}", e.name.as_str().into_safe()))
-            .chain(
-                type_decls
-                    .clone()
-                    .map(|e| format_ident!("{}", e.id().into_safe())),
-            )
+            .map(|name| format_ident!("{}", name.to_pascal_case()))
             .collect();
-        let holders_name: Vec<_> = entities
+        let holder_name: Vec<_> = names
             .iter()
-            .map(|e| format_ident!("{}_holders", e.name))
-            .chain(type_decls.map(|e| format_ident!("{}_holders", e.id())))
+            .map(|name| format_ident!("{}", name.into_safe()))
             .collect();
+        let holders_name: Vec<_> = names
+            .iter()
+            .map(|name| format_ident!("{}_holders", name))
+            .collect();
+
+        // Place holders of interfaced entities and types are resolved in the tables
+        // of their schemas, nested in this table
+        let foreign: Vec<&str> = self.interfaced().into_keys().collect();
+        let foreign_schemas: Vec<_> = foreign.iter().map(|schema| schema_module(schema)).collect();
+        let foreign_name: Vec<_> = foreign
+            .iter()
+            .map(|schema| format_ident!("_{}", schema.to_snake_case()))
+            .collect();
+        let foreign_tables_name: Vec<_> = foreign
+            .iter()
+            .map(|schema| format_ident!("{}_tables", schema.to_snake_case()))
+            .collect();
+
+        // Records of entities removed by `IR::subset` are kept untyped
+        let (untyped, untyped_holders) = if self.subset {
+            (
+                quote! {
+                    #[table_init(untyped)]
+                    #vis untyped: HashMap<u64, #ruststep::ast::Record>,
+                },
+                quote! {
+                    /// Records of entities not generated in this subset of the schema
+                    pub fn untyped_records(&self) -> &HashMap<u64, #ruststep::ast::Record> {
+                        &self.untyped
+                    }
+                },
+            )
+        } else {
+            (TokenStream::new(), TokenStream::new())
+        };
+
+        // Reverse index for inverse attributes, named not to conflict with EXPRESS identifiers
+        let (inverse_index, derive_partial_eq, partial_eq) =
+            if self.entities.iter().any(|e| !e.inverse.is_empty()) {
+                let untyped_eq = if self.subset {
+                    quote! { let equal = equal && self.untyped == other.untyped; }
+                } else {
+                    TokenStream::new()
+                };
+                (
+                    quote! {
+                        #[table_init(index)]
+                        #vis _inverse_index: #ruststep::tables::InverseIndex,
+                    },
+                    TokenStream::new(),
+                    // The index is a cache built from the records, and excluded from the equality
+                    quote! {
+                        impl PartialEq for Tables {
+                            fn eq(&self, other: &Self) -> bool {
+                                let equal = true;
+                                #(
+                                #cfgs
+                                let equal = equal && self.#holder_name == other.#holder_name;
+                                )*
+                                #(
+                                let equal = equal && self.#foreign_name == other.#foreign_name;
+                                )*
+                                #untyped_eq
+                                equal
+                            }
+                        }
+                    },
+                )
+            } else {
+                (
+                    TokenStream::new(),
+                    quote! { PartialEq, },
+                    TokenStream::new(),
+                )
+            };
+
+        quote! {
+            #[derive(Debug, Clone, #derive_partial_eq Default, TableInit)]
+            pub struct Tables {
+                #(
+                #cfgs
+                #vis #holder_name: HashMap<u64, as_holder!(#entity_types)>,
+                )*
+                #(
+                #[table_init(foreign)]
+                #vis #foreign_name: #foreign_schemas::Tables,
+                )*
+                #untyped
+                #inverse_index
+            }
+
+            #partial_eq
+
+            impl Tables {
+                #(
+                #cfgs
+                pub fn #holders_name(&self) -> &HashMap<u64, as_holder!(#entity_types)> {
+                    &self.#holder_name
+                }
+                )*
+                #(
+                pub fn #foreign_tables_name(&self) -> &#foreign_schemas::Tables {
+                    &self.#foreign_name
+                }
+                )*
+                #untyped_holders
+            }
+        }
+    }
+
+    pub fn to_token_stream(&self, prefix: CratePrefix) -> TokenStream {
+        self.to_token_stream_with(prefix, Generators::default())
+    }
 
+    pub fn to_token_stream_with(&self, prefix: CratePrefix, generators: Generators) -> TokenStream {
+        let name = schema_module(&self.name);
+        let types = &self.types;
+        let entities = &self.entities;
         let ruststep_path = prefix.as_path();
+        let imports = self.imports();
+        let tables = self.tables(&ruststep_path, TokenStream::new(), |_| TokenStream::new());
+        let derived = entities
+            .iter()
+            .filter_map(|e| e.generate_derived(&ruststep_path));
+        let inverses = entities
+            .iter()
+            .filter_map(|e| e.generate_inverse(self, &ruststep_path));
+        let builders = entities
+            .iter()
+            .filter(|_| generators.builder)
+            .filter_map(|e| e.generate_builder(self, &ruststep_path));
+        let downcasts = entities
+            .iter()
+            .filter(|_| generators.downcast)
+            .filter_map(|e| e.generate_downcast(self, &ruststep_path));
+        let schema_info = if generators.reflection {
+            self.schema_info(&ruststep_path)
+        } else {
+            TokenStream::new()
+        };
+        let visit = if generators.visit {
+            let visit = self.visitor(false, &|_| TokenStream::new());
+            let visit_mut = self.visitor(true, &|_| TokenStream::new());
+            quote! {
+                pub mod visit {
+                    use super::*;
+                    #visit
+                }
+
+                pub mod visit_mut {
+                    use super::*;
+                    #visit_mut
+                }
+            }
+        } else {
+            TokenStream::new()
+        };
 
         quote! {
             pub mod #name {
                 use #ruststep_path::{as_holder, Holder, TableInit, primitive::*, derive_more::*};
                 use std::collections::HashMap;
+                #imports
 
-                #[derive(Debug, Clone, PartialEq, Default, TableInit)]
-                pub struct Tables {
-                    #(
-                    #holder_name: HashMap<u64, as_holder!(#entity_types)>,
-                    )*
-                }
-
-                impl Tables {
-                    #(
-                    pub fn #holders_name(&self) -> &HashMap<u64, as_holder!(#entity_types)> {
-                        &self.#holder_name
-                    }
-                    )*
-                }
+                #tables
 
                 #(#types)*
                 #(#entities)*
+                #(#derived)*
+                #(#inverses)*
+                #(#builders)*
+                #(#downcasts)*
+
+                #schema_info
+
+                #visit
             }
         }
     }
@@ -0,0 +1,419 @@
+//! Generate Rust code as a directory tree with a module for each cluster of entities and types
+
+use super::{schema::schema_module, CratePrefix, Generators};
+use crate::ir::*;
+
+use check_keyword::CheckKeyword;
+use inflector::Inflector;
+use proc_macro2::TokenStream;
+use quote::*;
+use std::{
+    collections::{BTreeSet, HashMap, HashSet},
+    fmt::Write,
+    path::PathBuf,
+};
+
+/// Options for [IR::to_split_modules]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SplitOptions {
+    pub prefix: CratePrefix,
+    /// Put `#[cfg(feature = "schema-cluster")]` on each cluster module
+    pub features: bool,
+    pub generators: Generators,
+}
+
+/// A file of generated code, not formatted yet
+#[derive(Debug, Clone)]
+pub struct SourceFile {
+    /// Relative path from the output directory
+    pub path: PathBuf,
+    pub tokens: TokenStream,
+}
+
+/// Strongly connected entities and types, which have to be compiled together
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cluster {
+    /// Module name, the name of the first declared entity or type in the cluster.
+    /// Names of the other modules in the schema directory, e.g. `tables`, are suffixed by `_cluster`.
+    pub name: String,
+    /// Names of entities in declaration order
+    pub entities: Vec<String>,
+    /// Names of types in declaration order
+    pub types: Vec<String>,
+    /// Names of other clusters referred from this cluster
+    pub dependencies: Vec<String>,
+}
+
+/// Modules of the schema directory other than clusters, which cluster names must avoid
+const RESERVED_MODULES: &[&str] = &["mod", "tables", "visit", "visit_mut"];
+
+/// Tarjan's algorithm for strongly connected components,
+/// which returns components in reverse topological order, i.e. dependencies first
+struct Tarjan<'a> {
+    edges: &'a [Vec<usize>],
+    index: Vec<Option<usize>>,
+    low: Vec<usize>,
+    on_stack: Vec<bool>,
+    stack: Vec<usize>,
+    next: usize,
+    components: Vec<Vec<usize>>,
+}
+
+impl<'a> Tarjan<'a> {
+    fn run(edges: &'a [Vec<usize>]) -> Vec<Vec<usize>> {
+        let n = edges.len();
+        let mut tarjan = Tarjan {
+            edges,
+            index: vec![None; n],
+            low: vec![0; n],
+            on_stack: vec![false; n],
+            stack: Vec::new(),
+            next: 0,
+            components: Vec::new(),
+        };
+        for v in 0..n {
+            if tarjan.index[v].is_none() {
+                tarjan.visit(v);
+            }
+        }
+        tarjan.components
+    }
+
+    fn visit(&mut self, v: usize) {
+        self.index[v] = Some(self.next);
+        self.low[v] = self.next;
+        self.next += 1;
+        self.stack.push(v);
+        self.on_stack[v] = true;
+
+        for &w in &self.edges[v] {
+            match self.index[w] {
+                None => {
+                    self.visit(w);
+                    self.low[v] = self.low[v].min(self.low[w]);
+                }
+                Some(index) if self.on_stack[w] => self.low[v] = self.low[v].min(index),
+                Some(_) => {}
+            }
+        }
+
+        if Some(self.low[v]) == self.index[v] {
+            let mut component = Vec::new();
+            loop {
+                let w = self.stack.pop().unwrap();
+                self.on_stack[w] = false;
+                component.push(w);
+                if w == v {
+                    break;
+                }
+            }
+            component.sort_unstable();
+            self.components.push(component);
+        }
+    }
+}
+
+impl Schema {
+    /// Split entities and types into clusters of strongly connected ones,
+    /// where dependencies come before the clusters referring them
+    pub fn clusters(&self) -> Vec<Cluster> {
+        let names: Vec<&str> = self
+            .entities
+            .iter()
+            .map(|e| e.name.as_str())
+            .chain(self.types.iter().map(|t| t.id()))
+            .collect();
+        // Names are compared in PascalCase as in the generated code
+        let index: HashMap<String, usize> = names
+            .iter()
+            .enumerate()
+            .map(|(i, name)| (name.to_pascal_case(), i))
+            .collect();
+        let edges: Vec<Vec<usize>> = self
+            .entities
+            .iter()
+            .map(Entity::dependencies)
+            .chain(self.types.iter().map(TypeDecl::dependencies))
+            .map(|dependencies| {
+                dependencies
+                    .into_iter()
+                    // Types in other schemas are not in this graph
+                    .filter_map(|ty| match ty {
+                        TypeRef::Named { name, scope, .. }
+                        | TypeRef::Entity { name, scope, .. }
+                            if scope.schema_name() == Some(self.name.as_str()) =>
+                        {
+                            index.get(&name.to_pascal_case()).copied()
+                        }
+                        _ => None,
+                    })
+                    .collect::<BTreeSet<_>>()
+                    .into_iter()
+                    .collect()
+            })
+            .collect();
+
+        let components = Tarjan::run(&edges);
+        let mut cluster_of = vec![0; names.len()];
+        for (c, component) in components.iter().enumerate() {
+            for &v in component {
+                cluster_of[v] = c;
+            }
+        }
+        let snake_names: HashSet<String> = names.iter().map(|name| name.to_snake_case()).collect();
+        let cluster_names: Vec<String> = components
+            .iter()
+            .map(|component| {
+                let mut name = names[component[0]].to_snake_case();
+                if RESERVED_MODULES.contains(&name.as_str()) {
+                    name += "_cluster";
+                    while snake_names.contains(&name) {
+                        name.push('_');
+                    }
+                }
+                name
+            })
+            .collect();
+
+        components
+            .iter()
+            .enumerate()
+            .map(|(c, component)| {
+                let n_entities = self.entities.len();
+                let dependencies: BTreeSet<_> = component
+                    .iter()
+                    .flat_map(|&v| edges[v].iter().map(|&w| cluster_of[w]))
+                    .filter(|&d| d != c)
+                    .map(|d| cluster_names[d].clone())
+                    .collect();
+                Cluster {
+                    name: cluster_names[c].clone(),
+                    entities: component
+                        .iter()
+                        .filter(|&&v| v < n_entities)
+                        .map(|&v| names[v].to_string())
+                        .collect(),
+                    types: component
+                        .iter()
+                        .filter(|&&v| v >= n_entities)
+                        .map(|&v| names[v].to_string())
+                        .collect(),
+                    dependencies: dependencies.into_iter().collect(),
+                }
+            })
+            .collect()
+    }
+
+    /// Cargo feature name to enable the cluster
+    fn feature(&self, cluster: &str) -> String {
+        format!("{}-{}", self.name, cluster)
+    }
+
+    /// Files of the schema module placed in `{schema module}/` directory
+    pub fn to_split_modules(&self, options: SplitOptions) -> Vec<SourceFile> {
+        let dir = PathBuf::from(self.name.to_snake_case());
+        let ruststep_path = options.prefix.as_path();
+        let clusters = self.clusters();
+
+        let cfg = |cluster: &str| {
+            if options.features {
+                let feature = self.feature(cluster);
+                quote! { #[cfg(feature = #feature)] }
+            } else {
+                TokenStream::new()
+            }
+        };
+
+        let mut files = Vec::new();
+
+        let mut cluster_of = HashMap::new();
+        for cluster in &clusters {
+            for name in cluster.entities.iter().chain(&cluster.types) {
+                cluster_of.insert(name.as_str(), cluster.name.as_str());
+            }
+        }
+        let cluster_cfg = |name: &str| cfg(cluster_of[name]);
+        // Fields are accessed from the cluster modules
+        let tables = self.tables(&ruststep_path, quote! { pub(super) }, cluster_cfg);
+        files.push(SourceFile {
+            path: dir.join("tables.rs"),
+            tokens: quote! {
+                #![allow(unused_imports)]
+                use super::*;
+                use #ruststep_path::{as_holder, TableInit};
+                use std::collections::HashMap;
+
+                #tables
+            },
+        });
+
+        let visit_modules: &[_] = if options.generators.visit {
+            &[("visit", false), ("visit_mut", true)]
+        } else {
+            &[]
+        };
+        for &(module, mutable) in visit_modules {
+            let visitor = self.visitor(mutable, &cluster_cfg);
+            files.push(SourceFile {
+                path: dir.join(format!("{}.rs", module)),
+                tokens: quote! {
+                    #![allow(unused_imports)]
+                    use super::*;
+
+                    #visitor
+                },
+            });
+        }
+
+        let mut modules = Vec::new();
+        let mut sorted: Vec<&Cluster> = clusters.iter().collect();
+        sorted.sort_by(|a, b| a.name.cmp(&b.name));
+        for cluster in sorted {
+            let module = format_ident!("{}", cluster.name.as_str().into_safe());
+            let cfg = cfg(&cluster.name);
+            modules.push(quote! {
+                #cfg
+                mod #module;
+                #cfg
+                pub use #module::*;
+            });
+
+            let types = self
+                .types
+                .iter()
+                .filter(|t| cluster.types.iter().any(|name| name == t.id()));
+            let entities: Vec<&Entity> = self
+                .entities
+                .iter()
+                .filter(|e| cluster.entities.contains(&e.name))
+                .collect();
+            let derived = entities
+                .iter()
+                .filter_map(|e| e.generate_derived(&ruststep_path));
+            let inverses = entities
+                .iter()
+                .filter_map(|e| e.generate_inverse(self, &ruststep_path));
+            let builders = entities
+                .iter()
+                .filter(|_| options.generators.builder)
+                .filter_map(|e| e.generate_builder(self, &ruststep_path));
+            let downcasts = entities
+                .iter()
+                .filter(|_| options.generators.downcast)
+                .filter_map(|e| e.generate_downcast(self, &ruststep_path));
+            files.push(SourceFile {
+                path: dir.join(format!("{}.rs", cluster.name)),
+                tokens: quote! {
+                    #![allow(unused_imports)]
+                    use super::*;
+                    use #ruststep_path::{Holder, primitive::*, derive_more::*};
+
+                    #(#types)*
+                    #(#entities)*
+                    #(#derived)*
+                    #(#inverses)*
+                    #(#builders)*
+                    #(#downcasts)*
+                },
+            });
+        }
+
+        let schema_info = if options.generators.reflection {
+            self.schema_info(&ruststep_path)
+        } else {
+            TokenStream::new()
+        };
+        let visit = if options.generators.visit {
+            quote! {
+                pub mod visit;
+                pub mod visit_mut;
+            }
+        } else {
+            TokenStream::new()
+        };
+        let imports = self.imports();
+        files.insert(
+            0,
+            SourceFile {
+                path: dir.join("mod.rs"),
+                tokens: quote! {
+                    #imports
+
+                    mod tables;
+                    pub use tables::Tables;
+                    #visit
+
+                    #(#modules)*
+
+                    #schema_info
+                },
+            },
+        );
+        files
+    }
+}
+
+impl IR {
+    /// Generate a directory tree of modules instead of [IR::to_token_stream]
+    ///
+    /// A single module for large schemas like AP203 makes the compile time long,
+    /// since all derive macros are expanded in the module.
+    /// This splits each schema module in `{schema module}/` directory into
+    ///
+    /// - `tables.rs` for `Tables` struct,
+    /// - `visit.rs` and `visit_mut.rs` if [Generators::visit] is enabled, and
+    /// - a file for each [Cluster], i.e. entities and types referring each other,
+    ///
+    /// and optionally puts `#[cfg(feature = "{schema}-{cluster}")]` on the cluster modules,
+    /// where [IR::cargo_features] lists these features with their dependencies.
+    /// `mod.rs` in the root declares a module for each schema.
+    pub fn to_split_modules(&self, options: SplitOptions) -> Vec<SourceFile> {
+        let names = self
+            .schemas
+            .iter()
+            .map(|schema| schema_module(&schema.name));
+        let mut files = vec![SourceFile {
+            path: PathBuf::from("mod.rs"),
+            tokens: quote! { #(pub mod #names;)* },
+        }];
+        for schema in &self.schemas {
+            files.extend(schema.to_split_modules(options));
+        }
+        files
+    }
+
+    /// `[features]` table for `Cargo.toml` of the crate including the split modules
+    ///
+    /// Each cluster has a feature enabling the clusters it depends on,
+    /// and a feature named by the schema enables all clusters of the schema.
+    pub fn cargo_features(&self) -> String {
+        let mut toml = "[features]\n".to_string();
+        for schema in &self.schemas {
+            let mut clusters = schema.clusters();
+            clusters.sort_by(|a, b| a.name.cmp(&b.name));
+            let quoted = |names: &mut dyn Iterator<Item = &String>| {
+                names
+                    .map(|name| format!("\"{}\"", schema.feature(name)))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            };
+            writeln!(
+                toml,
+                "{} = [{}]",
+                schema.name,
+                quoted(&mut clusters.iter().map(|c| &c.name))
+            )
+            .unwrap();
+            for cluster in &clusters {
+                writeln!(
+                    toml,
+                    "{} = [{}]",
+                    schema.feature(&cluster.name),
+                    quoted(&mut cluster.dependencies.iter())
+                )
+                .unwrap();
+            }
+        }
+        toml
+    }
+}
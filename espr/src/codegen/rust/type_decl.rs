@@ -21,9 +21,12 @@ impl ToTokens for Simple {
         let id = format_ident!("{}", &self.id.to_pascal_case());
         let ty = &self.ty;
         let (derive, _) = simple_meta(&field_name);
+        let size = TypeRef::SimpleType(ty.clone()).size_attribute();
+        let doc = &self.doc;
         tokens.append_all(quote! {
+            #doc
             #derive
-            pub struct #id(pub #ty);
+            pub struct #id(#size pub #ty);
         });
     }
 }
@@ -46,9 +49,13 @@ impl ToTokens for Rename {
             _ => rename_meta(&field_name),
         };
 
+        let size = ty.size_attribute();
+        let doc = &self.doc;
+
         tokens.append_all(quote! {
+            #doc
             #derive
-            pub struct #id(#use_place_holder pub #ty);
+            pub struct #id(#use_place_holder #size pub #ty);
         });
     }
 }
@@ -85,7 +92,9 @@ impl ToTokens for Enumeration {
             .iter()
             .map(|i| format_ident!("{}", i.to_pascal_case()))
             .collect();
+        let doc = &self.doc;
         tokens.append_all(quote! {
+            #doc
             #[derive(Debug, Clone, PartialEq, ::serde::Deserialize)]
             pub enum #id {
                 #( #items ),*
@@ -100,6 +109,9 @@ impl ToTokens for Select {
         let mut entries = Vec::new();
         let mut entry_types = Vec::new();
         let mut use_place_holder = Vec::new();
+        // Type of each entry without `Box`, and whether it is boxed
+        let mut from_types = Vec::new();
+        let mut boxed = Vec::new();
         for ty in &self.types {
             match ty {
                 TypeRef::Entity {
@@ -112,6 +124,8 @@ impl ToTokens for Select {
                         entry_types.push(quote! { Box<#ty> });
                     }
                     use_place_holder.push(quote! { #[holder(use_place_holder)] });
+                    from_types.push(ty);
+                    boxed.push(!*is_supertype);
                 }
                 TypeRef::Named {
                     name, is_enumerate, ..
@@ -124,11 +138,15 @@ impl ToTokens for Select {
                         entry_types.push(quote! { Box<#ty> });
                         use_place_holder.push(quote! { #[holder(use_place_holder)] });
                     }
+                    from_types.push(ty);
+                    boxed.push(!*is_enumerate);
                 }
                 _ => unimplemented!(),
             }
         }
+        let doc = &self.doc;
         tokens.append_all(quote! {
+            #doc
             #[derive(Debug, Clone, PartialEq, Holder)]
             #[holder(table = Tables)]
             #[holder(generate_deserialize)]
@@ -139,5 +157,21 @@ impl ToTokens for Select {
                 ),*
             }
         });
+
+        // Entries are converted into select type by `Into`, e.g. in builders of entities
+        for ((entry, ty), boxed) in entries.iter().zip(from_types).zip(boxed) {
+            let value = if boxed {
+                quote! { Box::new(value) }
+            } else {
+                quote! { value }
+            };
+            tokens.append_all(quote! {
+                impl From<#ty> for #id {
+                    fn from(value: #ty) -> Self {
+                        #id::#entry(#value)
+                    }
+                }
+            });
+        }
     }
 }
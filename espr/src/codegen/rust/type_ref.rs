@@ -1,8 +1,9 @@
 use inflector::Inflector;
-use proc_macro2::TokenStream;
+use proc_macro2::{Literal, TokenStream};
 use quote::*;
+use syn::parse_quote;
 
-use crate::ir::*;
+use crate::{ast, ir::*};
 
 impl ToTokens for TypeRef {
     fn to_tokens(&self, tokens: &mut TokenStream) {
@@ -23,9 +24,65 @@ impl ToTokens for TypeRef {
                 };
                 tokens.append_all(quote! { #name });
             }
-            Set { base, .. } | List { base, .. } => {
+            Array {
+                base,
+                optional: true,
+                ..
+            } => {
+                tokens.append_all(quote! { Vec<Option<#base>> });
+            }
+            Set { base, .. } | Bag { base, .. } | List { base, .. } | Array { base, .. } => {
                 tokens.append_all(quote! { Vec<#base> });
             }
         }
     }
 }
+
+impl TypeRef {
+    /// `#[holder(size = ...)]` attribute to validate the size of aggregate,
+    /// or the number of bits for `BINARY(width)`
+    ///
+    /// Returns `None` if the type is not an aggregate or its size is not restricted.
+    pub(crate) fn size_attribute(&self) -> Option<syn::Attribute> {
+        if let TypeRef::SimpleType(SimpleType(ast::SimpleType::Binary {
+            width_spec: Some(ast::WidthSpec { width, fixed }),
+        })) = self
+        {
+            let width = Literal::usize_unsuffixed(*width);
+            return Some(if *fixed {
+                parse_quote! { #[holder(size = #width..=#width)] }
+            } else {
+                parse_quote! { #[holder(size = ..=#width)] }
+            });
+        }
+        let bound = self.bound()?;
+        let (lower, upper) = match self {
+            // Bound of array is the range of index, e.g. `ARRAY [1:3]` has just three elements
+            TypeRef::Array { .. } => {
+                let size = bound
+                    .upper_value()?
+                    .checked_sub(bound.lower_value()?)?
+                    .checked_add(1)?
+                    .max(0) as u64;
+                (size, Some(size))
+            }
+            _ => (
+                bound.lower_value().unwrap_or(0).max(0) as u64,
+                bound.upper_value().map(|upper| upper.max(0) as u64),
+            ),
+        };
+        let range = match upper {
+            Some(upper) => {
+                let lower = Literal::u64_unsuffixed(lower);
+                let upper = Literal::u64_unsuffixed(upper);
+                quote! { #lower..=#upper }
+            }
+            None if lower > 0 => {
+                let lower = Literal::u64_unsuffixed(lower);
+                quote! { #lower.. }
+            }
+            None => return None,
+        };
+        Some(parse_quote! { #[holder(size = #range)] })
+    }
+}
@@ -0,0 +1,274 @@
+//! Generate `Visit` and `VisitMut` traits walking the generated types of a schema
+
+use crate::ir::*;
+
+use check_keyword::CheckKeyword;
+use inflector::Inflector;
+use proc_macro2::TokenStream;
+use quote::*;
+use std::collections::HashSet;
+
+/// Name of the hook for a type, e.g. `visit_cartesian_point` or `visit_cartesian_point_mut`
+fn hook(name: &str, any: bool, mutable: bool) -> syn::Ident {
+    let any = if any { "_any" } else { "" };
+    let suffix = if mutable { "_mut" } else { "" };
+    format_ident!("visit_{}{}{}", name.to_snake_case(), any, suffix)
+}
+
+/// Generator of a walk function for a hook, shared by `Visit` and `VisitMut`
+struct Walker<'ir> {
+    /// Names of entities and types declared in the schema, in snake_case
+    declared: HashSet<String>,
+    mutable: bool,
+    /// Returns attributes to put on the items for each entity or type name
+    cfg: &'ir dyn Fn(&str) -> TokenStream,
+}
+
+impl<'ir> Walker<'ir> {
+    /// Statements to visit `node` of type `ty`
+    ///
+    /// `node` is a place expression of the value like `node.x` if `place`,
+    /// or a reference to the value otherwise.
+    /// Types declared in other schemas have no hook in this schema, and are not visited.
+    fn walk(&self, ty: &TypeRef, node: TokenStream, place: bool) -> TokenStream {
+        let mutable = self.mutable;
+        let reference = if place {
+            self.reference()
+        } else {
+            quote! {}
+        };
+        match ty {
+            TypeRef::SimpleType(_) => TokenStream::new(),
+            TypeRef::Named { name, .. } | TypeRef::Entity { name, .. } => {
+                if !self.declared.contains(&name.to_snake_case()) {
+                    return TokenStream::new();
+                }
+                let any = matches!(
+                    ty,
+                    TypeRef::Entity {
+                        is_supertype: true,
+                        ..
+                    }
+                );
+                let hook = hook(name, any, mutable);
+                quote! { v.#hook(#reference #node); }
+            }
+            TypeRef::Set { base, .. }
+            | TypeRef::Bag { base, .. }
+            | TypeRef::List { base, .. }
+            | TypeRef::Array { base, .. } => {
+                let walk = self.walk(base, quote! { item }, false);
+                if walk.is_empty() {
+                    return walk;
+                }
+                let items = match (ty, mutable) {
+                    (TypeRef::Array { optional: true, .. }, false) => {
+                        quote! { #node.iter().flatten() }
+                    }
+                    (TypeRef::Array { optional: true, .. }, true) => {
+                        quote! { #node.iter_mut().flatten() }
+                    }
+                    _ => quote! { #reference #node },
+                };
+                quote! {
+                    for item in #items {
+                        #walk
+                    }
+                }
+            }
+        }
+    }
+
+    /// Hook method in the trait and the walk function called by default
+    fn item(
+        &self,
+        name: &str,
+        any: bool,
+        ty: TokenStream,
+        body: TokenStream,
+    ) -> (TokenStream, TokenStream) {
+        let cfg = (self.cfg)(name);
+        let hook = hook(name, any, self.mutable);
+        // Leaves do not use the visitor and the node
+        let (v, node) = if body.is_empty() {
+            (quote! { _v }, quote! { _node })
+        } else {
+            (quote! { v }, quote! { node })
+        };
+        let (trait_method, walk_function) = if self.mutable {
+            (
+                quote! {
+                    fn #hook(&mut self, node: &mut #ty) {
+                        #hook(self, node)
+                    }
+                },
+                quote! {
+                    pub fn #hook<V: VisitMut + ?Sized>(#v: &mut V, #node: &mut #ty) {
+                        #body
+                    }
+                },
+            )
+        } else {
+            (
+                quote! {
+                    fn #hook(&mut self, node: &'ast #ty) {
+                        #hook(self, node)
+                    }
+                },
+                quote! {
+                    pub fn #hook<'ast, V: Visit<'ast> + ?Sized>(#v: &mut V, #node: &'ast #ty) {
+                        #body
+                    }
+                },
+            )
+        };
+        (
+            quote! { #cfg #trait_method },
+            quote! { #cfg #walk_function },
+        )
+    }
+
+    fn entity(&self, entity: &Entity) -> Vec<(TokenStream, TokenStream)> {
+        let reference = self.reference();
+        let mut body = TokenStream::new();
+        for ty in &entity.supertypes {
+            if let TypeRef::Named { name, .. } | TypeRef::Entity { name, .. } = ty {
+                let field = format_ident!("{}", name.as_str().into_safe());
+                let hook = hook(name, false, self.mutable);
+                body.append_all(quote! { v.#hook(#reference node.#field); });
+            }
+        }
+        for attr in &entity.attributes {
+            let field = format_ident!("{}", attr.name.as_str().into_safe());
+            if attr.optional || attr.redeclared_as_derived {
+                let walk = self.walk(&attr.ty, quote! { value }, false);
+                if !walk.is_empty() {
+                    body.append_all(quote! {
+                        if let Some(value) = #reference node.#field {
+                            #walk
+                        }
+                    });
+                }
+            } else {
+                body.append_all(self.walk(&attr.ty, quote! { node.#field }, true));
+            }
+        }
+        let name = entity.name_ident();
+        let mut items = vec![self.item(&entity.name, false, quote! { #name }, body)];
+
+        if !entity.constraints.is_empty() {
+            let any = format_ident!("{}Any", entity.name.to_pascal_case());
+            let self_hook = hook(&entity.name, false, self.mutable);
+            let mut arms = vec![quote! { #any::#name(x) => v.#self_hook(x), }];
+            for ty in &entity.constraints {
+                if let TypeRef::Entity {
+                    name, is_supertype, ..
+                } = ty
+                {
+                    let variant = format_ident!("{}", name.to_pascal_case());
+                    let hook = hook(name, *is_supertype, self.mutable);
+                    arms.push(quote! { #any::#variant(x) => v.#hook(x), });
+                }
+            }
+            let body = quote! {
+                match node {
+                    #(#arms)*
+                }
+            };
+            items.push(self.item(&entity.name, true, quote! { #any }, body));
+        }
+        items
+    }
+
+    fn type_decl(&self, decl: &TypeDecl) -> (TokenStream, TokenStream) {
+        let id = decl.id();
+        let ty_name = format_ident!("{}", id.to_pascal_case());
+        let body = match decl {
+            TypeDecl::Simple(_) | TypeDecl::Enumeration(_) => TokenStream::new(),
+            TypeDecl::Rename(rename) => self.walk(&rename.ty, quote! { node.0 }, true),
+            TypeDecl::Select(select) => {
+                let arms = select.types.iter().filter_map(|ty| match ty {
+                    TypeRef::Named { name, .. } | TypeRef::Entity { name, .. } => {
+                        let variant = format_ident!("{}", name.to_pascal_case());
+                        let walk = self.walk(ty, quote! { x }, false);
+                        if walk.is_empty() {
+                            Some(quote! { #ty_name::#variant(_) => {} })
+                        } else {
+                            Some(quote! { #ty_name::#variant(x) => { #walk } })
+                        }
+                    }
+                    _ => None,
+                });
+                let arms: Vec<_> = arms.collect();
+                // Empty enum of `EXTENSIBLE SELECT` without items has nothing to visit
+                if arms.is_empty() {
+                    TokenStream::new()
+                } else {
+                    quote! {
+                        match node {
+                            #(#arms)*
+                        }
+                    }
+                }
+            }
+        };
+        self.item(id, false, quote! { #ty_name }, body)
+    }
+
+    fn reference(&self) -> TokenStream {
+        if self.mutable {
+            quote! { &mut }
+        } else {
+            quote! { & }
+        }
+    }
+}
+
+impl Schema {
+    /// Contents of `visit` module, or `visit_mut` module if `mutable`
+    ///
+    /// The trait has a hook for each entity, `XxxAny` enum and type,
+    /// e.g. `visit_cartesian_point` or `visit_cartesian_point_mut`,
+    /// and the default implementation calls the function of the same name walking its children
+    /// as in `syn::visit`. `cfg` returns attributes to put on the items for each name.
+    pub(super) fn visitor(&self, mutable: bool, cfg: &dyn Fn(&str) -> TokenStream) -> TokenStream {
+        let declared = self
+            .entities
+            .iter()
+            .map(|e| e.name.to_snake_case())
+            .chain(self.types.iter().map(|t| t.id().to_snake_case()))
+            .collect();
+        let walker = Walker {
+            declared,
+            mutable,
+            cfg,
+        };
+
+        let (methods, functions): (Vec<_>, Vec<_>) = self
+            .types
+            .iter()
+            .map(|t| walker.type_decl(t))
+            .chain(self.entities.iter().flat_map(|e| walker.entity(e)))
+            .unzip();
+
+        let (doc, signature) = if mutable {
+            (
+                " Visitor mutating the values of entities and types in this schema",
+                quote! { pub trait VisitMut },
+            )
+        } else {
+            (
+                " Visitor borrowing the values of entities and types in this schema",
+                quote! { pub trait Visit<'ast> },
+            )
+        };
+        quote! {
+            #[doc = #doc]
+            #signature {
+                #(#methods)*
+            }
+
+            #(#functions)*
+        }
+    }
+}
@@ -0,0 +1,377 @@
+//! Generate relational tables in SQL DDL
+//!
+//! Each entity in [IR] is mapped into a table as follows:
+//!
+//! - Every table has `_file` column referring the [FILE_TABLE] to store several exchange structures.
+//!   Columns added by this mapping start with `_` or are in link tables,
+//!   since EXPRESS identifiers cannot start with `_`.
+//! - The table of an entity has (`_file`, `_id`) primary key, where `_id` is the entity instance name, e.g. `#12`.
+//!   An instance of a subtype has a row with the same key in the table of each supertype,
+//!   and the key of the subtype table refers the supertype tables.
+//!   Foreign keys are deferred to allow forward references in a transaction.
+//! - Each explicit attribute is a column, `NOT NULL` unless `OPTIONAL`.
+//!   A reference to an entity is a foreign key to the entity table.
+//! - Simple types, enumerations, and selects of entities are stored in the column directly.
+//!   Other selects are stored as text of the exchange structure encoding, e.g. `LABEL('A')`.
+//! - An aggregate attribute is stored in a link table named `entity.attribute`
+//!   with `owner`, `position` of the element, and its `value`.
+//!
+//! Tables are created by `CREATE TABLE IF NOT EXISTS` to append files into an existing database.
+//!
+//! ```
+//! use espr::{ast::SyntaxTree, codegen::sql::*, ir::IR};
+//!
+//! let st = SyntaxTree::parse(r#"
+//!   SCHEMA s;
+//!     ENTITY a; x: OPTIONAL REAL; END_ENTITY;
+//!     ENTITY b; y: a; END_ENTITY;
+//!   END_SCHEMA;
+//! "#).unwrap();
+//! let ir = IR::from_syntax_tree(&st).unwrap();
+//! let tables = tables(&ir);
+//! assert_eq!(tables[0].name, FILE_TABLE);
+//! assert_eq!(
+//!     tables[2].to_string(),
+//!     r#"CREATE TABLE IF NOT EXISTS "b" (
+//!   "_file" INTEGER NOT NULL,
+//!   "_id" INTEGER NOT NULL,
+//!   "y" INTEGER NOT NULL,
+//!   PRIMARY KEY ("_file", "_id"),
+//!   FOREIGN KEY ("_file") REFERENCES "_file" ("_id") DEFERRABLE INITIALLY DEFERRED,
+//!   FOREIGN KEY ("_file", "y") REFERENCES "a" ("_file", "_id") DEFERRABLE INITIALLY DEFERRED
+//! );
+//! "#
+//! );
+//! ```
+
+use crate::{ast, ir::*};
+use std::{collections::HashMap, fmt};
+
+/// Table of the exchange structures stored in the database, with `_id` and `name` columns
+pub const FILE_TABLE: &str = "_file";
+
+/// Column referring [FILE_TABLE] in every other table
+pub const FILE_COLUMN: &str = "_file";
+
+/// Column of the entity instance name, e.g. `12` of `#12`, in entity tables
+pub const ID_COLUMN: &str = "_id";
+
+/// Type of a column
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColumnType {
+    Integer,
+    Real,
+    Text,
+}
+
+impl fmt::Display for ColumnType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ColumnType::Integer => write!(f, "INTEGER"),
+            ColumnType::Real => write!(f, "REAL"),
+            ColumnType::Text => write!(f, "TEXT"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Column {
+    pub name: String,
+    pub ty: ColumnType,
+    pub nullable: bool,
+    /// Allowed values, e.g. items of an enumeration
+    pub values: Vec<String>,
+}
+
+impl Column {
+    fn new(name: &str, ty: ColumnType) -> Self {
+        Column {
+            name: name.to_string(),
+            ty,
+            nullable: false,
+            values: Vec::new(),
+        }
+    }
+}
+
+/// `FOREIGN KEY (columns) REFERENCES table (references)`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ForeignKey {
+    pub columns: Vec<String>,
+    pub table: String,
+    pub references: Vec<String>,
+}
+
+impl ForeignKey {
+    /// Reference to the instance of `(_file, column)` in the entity table
+    fn instance(column: &str, table: &str) -> Self {
+        ForeignKey {
+            columns: vec![FILE_COLUMN.to_string(), column.to_string()],
+            table: table.to_string(),
+            references: vec![FILE_COLUMN.to_string(), ID_COLUMN.to_string()],
+        }
+    }
+
+    /// Reference to the file of the row
+    fn file() -> Self {
+        ForeignKey {
+            columns: vec![FILE_COLUMN.to_string()],
+            table: FILE_TABLE.to_string(),
+            references: vec![ID_COLUMN.to_string()],
+        }
+    }
+}
+
+/// Table of an entity or a link table of an aggregate attribute
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Table {
+    pub name: String,
+    pub columns: Vec<Column>,
+    pub primary_key: Vec<String>,
+    pub foreign_keys: Vec<ForeignKey>,
+}
+
+impl Table {
+    pub fn column(&self, name: &str) -> Option<&Column> {
+        self.columns.iter().find(|c| c.name == name)
+    }
+}
+
+/// Quote an identifier
+fn ident(name: &str) -> String {
+    format!("\"{}\"", name.replace('"', "\"\""))
+}
+
+/// Quote a string literal
+pub fn literal(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "''"))
+}
+
+impl fmt::Display for Table {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut lines = Vec::new();
+        for column in &self.columns {
+            let mut line = format!("  {} {}", ident(&column.name), column.ty);
+            if !column.nullable {
+                line += " NOT NULL";
+            }
+            if !column.values.is_empty() {
+                let values: Vec<_> = column.values.iter().map(|v| literal(v)).collect();
+                line += &format!(
+                    " CHECK ({} IN ({}))",
+                    ident(&column.name),
+                    values.join(", ")
+                );
+            }
+            lines.push(line);
+        }
+        let idents = |names: &[String]| names.iter().map(|name| ident(name)).collect::<Vec<_>>();
+        lines.push(format!(
+            "  PRIMARY KEY ({})",
+            idents(&self.primary_key).join(", ")
+        ));
+        // Deferred to allow references to instances inserted later in a transaction
+        for key in &self.foreign_keys {
+            lines.push(format!(
+                "  FOREIGN KEY ({}) REFERENCES {} ({}) DEFERRABLE INITIALLY DEFERRED",
+                idents(&key.columns).join(", "),
+                ident(&key.table),
+                idents(&key.references).join(", ")
+            ));
+        }
+        writeln!(f, "CREATE TABLE IF NOT EXISTS {} (", ident(&self.name))?;
+        writeln!(f, "{}", lines.join(",\n"))?;
+        writeln!(f, ");")
+    }
+}
+
+/// Name of the link table for an aggregate attribute
+///
+/// `.` never appears in EXPRESS identifiers, and thus it does not collide with other tables.
+pub fn link_table_name(entity: &str, attribute: &str) -> String {
+    format!("{}.{}", entity, attribute)
+}
+
+/// Table of the exchange structures, referred by every other table
+pub fn file_table() -> Table {
+    Table {
+        name: FILE_TABLE.to_string(),
+        columns: vec![
+            Column::new(ID_COLUMN, ColumnType::Integer),
+            Column {
+                nullable: true,
+                ..Column::new("name", ColumnType::Text)
+            },
+        ],
+        primary_key: vec![ID_COLUMN.to_string()],
+        foreign_keys: Vec::new(),
+    }
+}
+
+struct Mapper<'ir> {
+    types: HashMap<&'ir str, &'ir TypeDecl>,
+}
+
+impl<'ir> Mapper<'ir> {
+    /// Column of `ty` named `name`, or of the element type if `ty` is an aggregate
+    ///
+    /// The entity is returned if the column refers an entity.
+    fn column(&self, name: &str, ty: &TypeRef) -> (Column, Option<String>) {
+        match ty {
+            TypeRef::SimpleType(SimpleType(simple)) => (simple_column(name, simple), None),
+            TypeRef::Entity { name: entity, .. } => {
+                (Column::new(name, ColumnType::Integer), Some(entity.clone()))
+            }
+            TypeRef::Named { name: ty, .. } => match self.types.get(ty.as_str()) {
+                Some(TypeDecl::Simple(simple)) => (simple_column(name, &simple.ty.0), None),
+                Some(TypeDecl::Rename(rename)) if self.aggregate(&rename.ty).is_none() => {
+                    self.column(name, &rename.ty)
+                }
+                Some(TypeDecl::Enumeration(e)) => (
+                    Column {
+                        values: e.items.clone(),
+                        ..Column::new(name, ColumnType::Text)
+                    },
+                    None,
+                ),
+                // Entity id without foreign key since it may be in any of the entity tables
+                Some(TypeDecl::Select(select)) if self.is_entity_select(select) => {
+                    (Column::new(name, ColumnType::Integer), None)
+                }
+                _ => (Column::new(name, ColumnType::Text), None),
+            },
+            // Nested aggregates are encoded as text
+            _ => (Column::new(name, ColumnType::Text), None),
+        }
+    }
+
+    /// Element type and whether elements may be indeterminate if `ty` is an aggregate
+    fn aggregate<'a>(&'a self, ty: &'a TypeRef) -> Option<(&'a TypeRef, bool)> {
+        match ty {
+            TypeRef::Set { base, .. } | TypeRef::Bag { base, .. } | TypeRef::List { base, .. } => {
+                Some((base, false))
+            }
+            TypeRef::Array { base, optional, .. } => Some((base, *optional)),
+            TypeRef::Named { name, .. } => match self.types.get(name.as_str()) {
+                Some(TypeDecl::Rename(rename)) => self.aggregate(&rename.ty),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Select whose members are all entities, including nested selects
+    fn is_entity_select(&self, select: &Select) -> bool {
+        select.types.iter().all(|ty| match ty {
+            TypeRef::Entity { .. } => true,
+            TypeRef::Named { name, .. } => {
+                matches!(self.types.get(name.as_str()), Some(TypeDecl::Select(s)) if self.is_entity_select(s))
+            }
+            _ => false,
+        })
+    }
+
+    fn tables(&self, entity: &Entity) -> Vec<Table> {
+        let mut table = Table {
+            name: entity.name.clone(),
+            columns: vec![
+                Column::new(FILE_COLUMN, ColumnType::Integer),
+                Column::new(ID_COLUMN, ColumnType::Integer),
+            ],
+            primary_key: vec![FILE_COLUMN.to_string(), ID_COLUMN.to_string()],
+            foreign_keys: vec![ForeignKey::file()],
+        };
+        for supertype in &entity.supertypes {
+            if let TypeRef::Entity { name, .. } = supertype {
+                table
+                    .foreign_keys
+                    .push(ForeignKey::instance(ID_COLUMN, name));
+            }
+        }
+        let mut links = Vec::new();
+        for attr in &entity.attributes {
+            match self.aggregate(&attr.ty) {
+                Some((base, optional)) => {
+                    let (mut value, references) = self.column("value", base);
+                    value.nullable = optional;
+                    let mut foreign_keys = vec![ForeignKey::instance("owner", &entity.name)];
+                    foreign_keys
+                        .extend(references.map(|table| ForeignKey::instance("value", &table)));
+                    links.push(Table {
+                        name: link_table_name(&entity.name, &attr.name),
+                        columns: vec![
+                            Column::new(FILE_COLUMN, ColumnType::Integer),
+                            Column::new("owner", ColumnType::Integer),
+                            Column::new("position", ColumnType::Integer),
+                            value,
+                        ],
+                        primary_key: vec![
+                            FILE_COLUMN.to_string(),
+                            "owner".to_string(),
+                            "position".to_string(),
+                        ],
+                        foreign_keys,
+                    });
+                }
+                None => {
+                    let (mut column, references) = self.column(&attr.name, &attr.ty);
+                    // `*` is stored as NULL
+                    column.nullable = attr.optional || attr.redeclared_as_derived;
+                    table.columns.push(column);
+                    table
+                        .foreign_keys
+                        .extend(references.map(|table| ForeignKey::instance(&attr.name, &table)));
+                }
+            }
+        }
+        let mut tables = vec![table];
+        tables.append(&mut links);
+        tables
+    }
+}
+
+fn simple_column(name: &str, ty: &ast::SimpleType) -> Column {
+    match ty {
+        ast::SimpleType::Integer | ast::SimpleType::Boolen => {
+            Column::new(name, ColumnType::Integer)
+        }
+        ast::SimpleType::Real | ast::SimpleType::Number => Column::new(name, ColumnType::Real),
+        ast::SimpleType::Logical => Column {
+            values: vec!["T".to_string(), "F".to_string(), "U".to_string()],
+            ..Column::new(name, ColumnType::Text)
+        },
+        // `BINARY` is stored as hexadecimal digits as in exchange structure
+        ast::SimpleType::String_ { .. } | ast::SimpleType::Binary { .. } => {
+            Column::new(name, ColumnType::Text)
+        }
+    }
+}
+
+/// [file_table] and tables of every entity in `ir`,
+/// each followed by the link tables of its aggregate attributes
+pub fn tables(ir: &IR) -> Vec<Table> {
+    let mapper = Mapper {
+        types: ir
+            .schemas
+            .iter()
+            .flat_map(|schema| schema.types.iter().map(|ty| (ty.id(), ty)))
+            .collect(),
+    };
+    std::iter::once(file_table())
+        .chain(
+            ir.schemas
+                .iter()
+                .flat_map(|schema| &schema.entities)
+                .flat_map(|entity| mapper.tables(entity)),
+        )
+        .collect()
+}
+
+/// `CREATE TABLE` statements of [tables]
+pub fn ddl(ir: &IR) -> String {
+    tables(ir)
+        .iter()
+        .map(Table::to_string)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -0,0 +1,180 @@
+//! Generate [TypeScript](https://www.typescriptlang.org/) type declarations
+//! of EXPRESS schemas encoded in JSON, in the same encoding as [json_schema](super::json_schema)
+//!
+//! Each schema in [IR] becomes a namespace, and its entities and types are declared in PascalCase
+//! as in the Rust code. References are always qualified by the schema, e.g. `shapes.Point`.
+//!
+//! - Entities are interfaces extending their supertypes.
+//!   Attributes declared as `OPTIONAL` or redeclared as derived in subtypes are optional properties.
+//! - Selects are unions of the selected types, and enumerations are unions of string literals.
+//! - Aggregates are arrays, and the bounds are not checked.
+//!
+//! ```
+//! use espr::{ast::SyntaxTree, codegen::typescript::*, ir::IR};
+//!
+//! let st = SyntaxTree::parse(r#"
+//!   SCHEMA s;
+//!     ENTITY a; x: OPTIONAL REAL; END_ENTITY;
+//!     ENTITY b SUBTYPE OF (a); y: LIST [1:?] OF a; END_ENTITY;
+//!   END_SCHEMA;
+//! "#).unwrap();
+//! let ir = IR::from_syntax_tree(&st).unwrap();
+//! assert_eq!(
+//!     typescript(&ir),
+//!     r#"export namespace s {
+//!   export interface A {
+//!     x?: number;
+//!   }
+//!
+//!   export interface B extends s.A {
+//!     y: s.A[];
+//!   }
+//! }
+//! "#
+//! );
+//! ```
+
+use crate::{ast, ir::*};
+use inflector::Inflector;
+use std::fmt::Write;
+
+/// Declarations of all schemas in `ir`
+pub fn typescript(ir: &IR) -> String {
+    let mut out = String::new();
+    for (i, schema) in ir.schemas.iter().enumerate() {
+        if i > 0 {
+            out.push('\n');
+        }
+        let decls: Vec<String> = schema
+            .types
+            .iter()
+            .map(TypeDecl::typescript)
+            .chain(schema.entities.iter().map(Entity::typescript))
+            .collect();
+        writeln!(out, "export namespace {} {{", schema.name).unwrap();
+        out.push_str(&decls.join("\n"));
+        out.push_str("}\n");
+    }
+    out
+}
+
+/// Name of `name` declared in the schema of `scope`
+fn reference(name: &str, scope: &Scope) -> String {
+    match scope.schema_name() {
+        Some(schema) => format!("{}.{}", schema, name.to_pascal_case()),
+        None => name.to_pascal_case(),
+    }
+}
+
+/// JSDoc comment from remarks, indented by `indent`
+fn describe(remarks: &[String], indent: &str) -> String {
+    if remarks.is_empty() {
+        return String::new();
+    }
+    let mut doc = format!("{}/**\n", indent);
+    for line in remarks.iter().flat_map(|remark| remark.lines()) {
+        writeln!(doc, "{} * {}", indent, line.trim()).unwrap();
+    }
+    writeln!(doc, "{} */", indent).unwrap();
+    doc
+}
+
+fn simple_type(ty: &ast::SimpleType) -> String {
+    match ty {
+        ast::SimpleType::Integer | ast::SimpleType::Real | ast::SimpleType::Number => {
+            "number".to_string()
+        }
+        ast::SimpleType::Boolen => "boolean".to_string(),
+        // `UNKNOWN` is encoded as `null`
+        ast::SimpleType::Logical => "boolean | null".to_string(),
+        // `BINARY` is encoded in hexadecimal digits
+        ast::SimpleType::String_ { .. } | ast::SimpleType::Binary { .. } => "string".to_string(),
+    }
+}
+
+impl TypeRef {
+    fn typescript(&self) -> String {
+        match self {
+            TypeRef::SimpleType(SimpleType(ty)) => simple_type(ty),
+            TypeRef::Named { name, scope, .. } | TypeRef::Entity { name, scope, .. } => {
+                reference(name, scope)
+            }
+            TypeRef::Set { base, .. }
+            | TypeRef::Bag { base, .. }
+            | TypeRef::List { base, .. }
+            | TypeRef::Array { base, .. } => {
+                let items = base.typescript();
+                let items = if let TypeRef::Array { optional: true, .. } = self {
+                    format!("{} | null", items)
+                } else {
+                    items
+                };
+                if items.contains('|') {
+                    format!("({})[]", items)
+                } else {
+                    format!("{}[]", items)
+                }
+            }
+        }
+    }
+}
+
+impl TypeDecl {
+    fn typescript(&self) -> String {
+        let ty = match self {
+            TypeDecl::Simple(simple) => simple_type(&simple.ty.0),
+            TypeDecl::Rename(rename) => rename.ty.typescript(),
+            TypeDecl::Enumeration(e) => {
+                let items: Vec<String> = e.items.iter().map(|item| format!("{:?}", item)).collect();
+                items.join(" | ")
+            }
+            TypeDecl::Select(select) => {
+                let types: Vec<String> = select.types.iter().map(TypeRef::typescript).collect();
+                types.join(" | ")
+            }
+        };
+        // Empty enumeration or select has no value
+        let ty = if ty.is_empty() {
+            "never".to_string()
+        } else {
+            ty
+        };
+        format!(
+            "{}  export type {} = {};\n",
+            describe(&self.doc().remarks, "  "),
+            self.id().to_pascal_case(),
+            ty
+        )
+    }
+}
+
+impl Entity {
+    fn typescript(&self) -> String {
+        let mut decl = describe(&self.doc.remarks, "  ");
+        write!(decl, "  export interface {}", self.name.to_pascal_case()).unwrap();
+        if !self.supertypes.is_empty() {
+            let supertypes: Vec<String> = self.supertypes.iter().map(TypeRef::typescript).collect();
+            write!(decl, " extends {}", supertypes.join(", ")).unwrap();
+        }
+        decl.push_str(" {\n");
+        for attr in &self.attributes {
+            decl.push_str(&describe(&attr.remarks, "    "));
+            // Not given in the instances of subtypes redeclaring it as derived
+            let optional = if attr.optional || attr.redeclared_as_derived {
+                "?"
+            } else {
+                ""
+            };
+            writeln!(
+                decl,
+                "    {}{}: {};",
+                attr.name,
+                optional,
+                attr.ty.typescript()
+            )
+            .unwrap();
+        }
+        decl.push_str("  }\n");
+        decl
+    }
+}
@@ -0,0 +1,182 @@
+//! Render errors in EXPRESS input with source locations
+//!
+//! ```
+//! use espr::{ast::SyntaxTree, diagnostic::*, ir::IR};
+//!
+//! let st = SyntaxTree::parse(
+//!     r#"
+//!     SCHEMA s;
+//!       ENTITY a;
+//!         x: undefined_type;
+//!       END_ENTITY;
+//!     END_SCHEMA;
+//!     "#,
+//! )
+//! .unwrap();
+//! let err = IR::from_syntax_tree(&st).unwrap_err();
+//!
+//! let files = Files::new(&st);
+//! let diagnostics = semantic_error(&files, err);
+//! assert_eq!(diagnostics.len(), 1);
+//! assert_eq!(
+//!     render(&files, &diagnostics),
+//!     r#"error: Not found the Type undefined_type referred in scope s
+//!   ┌─ <input>:4:9
+//!   │
+//! 4 │         x: undefined_type;
+//!   │         ^^^^^^^^^^^^^^^^^^
+//!
+//! "#
+//! );
+//! ```
+
+use crate::{
+    ast::{LoadError, Source, Span, SyntaxTree},
+    ir::SemanticError,
+};
+use codespan_reporting::{
+    diagnostic::Label,
+    files::SimpleFiles,
+    term::{self, termcolor},
+};
+use nom::error::VerboseErrorKind;
+
+/// Diagnostic with an index of [Files]
+pub type Diagnostic = codespan_reporting::diagnostic::Diagnostic<usize>;
+
+/// EXPRESS inputs to render [Diagnostic]
+pub struct Files {
+    files: SimpleFiles<String, String>,
+    /// Schema names declared in each file
+    schemas: Vec<Vec<String>>,
+}
+
+impl Files {
+    pub fn new(st: &SyntaxTree) -> Self {
+        let mut files = Files {
+            files: SimpleFiles::new(),
+            schemas: Vec::new(),
+        };
+        for source in &st.sources {
+            files.add(source);
+        }
+        files
+    }
+
+    fn add(&mut self, source: &Source) -> usize {
+        let name = match &source.path {
+            Some(path) => path.display().to_string(),
+            None => "<input>".to_string(),
+        };
+        self.schemas.push(source.schemas.clone());
+        self.files.add(name, source.text.clone())
+    }
+
+    /// Index of the file where the schema of `name` is declared
+    fn schema(&self, name: &str) -> Option<usize> {
+        self.schemas
+            .iter()
+            .position(|schemas| schemas.iter().any(|schema| schema == name))
+    }
+
+    fn label(&self, file_id: usize, span: Span) -> Label<usize> {
+        let text = self.files.get(file_id).unwrap().source();
+        Label::primary(file_id, span.range(text))
+    }
+}
+
+/// Diagnostics for each error in [SemanticError]
+///
+/// Errors located by [SemanticError::Located] are labeled with the span of the declaration.
+pub fn semantic_error(files: &Files, err: SemanticError) -> Vec<Diagnostic> {
+    err.errors()
+        .into_iter()
+        .map(|err| match err {
+            SemanticError::Located {
+                scope,
+                span,
+                source,
+            } => {
+                let diagnostic = Diagnostic::error().with_message(source.to_string());
+                match scope.schema_name().and_then(|name| files.schema(name)) {
+                    Some(file_id) => diagnostic.with_labels(vec![files.label(file_id, span)]),
+                    None => diagnostic,
+                }
+            }
+            err => Diagnostic::error().with_message(err.to_string()),
+        })
+        .collect()
+}
+
+/// Diagnostic for [LoadError], and [Files] to render it
+pub fn load_error(err: &LoadError) -> (Files, Diagnostic) {
+    let mut files = Files {
+        files: SimpleFiles::new(),
+        schemas: Vec::new(),
+    };
+    let diagnostic = match err {
+        LoadError::Syntax { input, errors } => {
+            let file_id = files.add(input);
+            // The first one is the innermost position where the parser failed
+            let labels = errors
+                .iter()
+                .enumerate()
+                .map(|(i, (span, kind))| {
+                    let label = files.label(file_id, *span);
+                    let label = if i == 0 {
+                        label
+                    } else {
+                        Label {
+                            style: codespan_reporting::diagnostic::LabelStyle::Secondary,
+                            ..label
+                        }
+                    };
+                    label.with_message(match kind {
+                        VerboseErrorKind::Context(context) => format!("while parsing {}", context),
+                        VerboseErrorKind::Char(c) => format!("expected `{}`", c),
+                        VerboseErrorKind::Nom(kind) => format!("failed in {:?}", kind),
+                    })
+                })
+                .collect();
+            Diagnostic::error()
+                .with_message("Syntax error")
+                .with_labels(labels)
+        }
+        err => Diagnostic::error().with_message(err.to_string()),
+    };
+    (files, diagnostic)
+}
+
+/// Render diagnostics into a string without color
+pub fn render(files: &Files, diagnostics: &[Diagnostic]) -> String {
+    let mut buffer = termcolor::NoColor::new(Vec::new());
+    let config = term::Config::default();
+    for diagnostic in diagnostics {
+        term::emit(&mut buffer, &config, &files.files, diagnostic)
+            .expect("Writing into Vec never fails");
+    }
+    String::from_utf8(buffer.into_inner()).expect("Rendered diagnostics must be UTF-8")
+}
+
+/// Emit diagnostics into stderr, colored if available
+pub fn emit(files: &Files, diagnostics: &[Diagnostic]) {
+    emit_with_lines(
+        files,
+        diagnostics,
+        term::Config::default().start_context_lines,
+    )
+}
+
+/// Emit diagnostics as [emit] showing `num_lines` lines from the beginning of each label,
+/// e.g. the rest of the input where the parser failed
+pub fn emit_with_lines(files: &Files, diagnostics: &[Diagnostic], num_lines: usize) {
+    let writer = termcolor::StandardStream::stderr(termcolor::ColorChoice::Auto);
+    let config = term::Config {
+        start_context_lines: num_lines,
+        ..Default::default()
+    };
+    for diagnostic in diagnostics {
+        // Ignore error in writing stderr since nothing can be done
+        let _ = term::emit(&mut writer.lock(), &config, &files.files, diagnostic);
+    }
+}
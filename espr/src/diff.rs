@@ -0,0 +1,511 @@
+//! Semantic comparison of two versions of EXPRESS schemas
+//!
+//! Schemas are compared on [IR] rather than on text,
+//! so that formatting, remarks and declaration order do not matter.
+//! Each [Change] is classified by its [Severity] for data compatibility,
+//! i.e. whether exchange structures valid under the old schema are still valid under the new one:
+//!
+//! ```
+//! use espr::{ast::SyntaxTree, diff::*, ir::IR};
+//!
+//! let legalize = |input| IR::from_syntax_tree(&SyntaxTree::parse(input).unwrap()).unwrap();
+//! let old = legalize("SCHEMA s; ENTITY a; x: REAL; END_ENTITY; END_SCHEMA;");
+//! let new = legalize("SCHEMA s; ENTITY a; x: OPTIONAL REAL; END_ENTITY; ENTITY b; END_ENTITY; END_SCHEMA;");
+//!
+//! let changes = diff(&old, &new);
+//! assert_eq!(changes.len(), 2);
+//! assert_eq!(changes[0].to_string(), "attribute s.a.x made OPTIONAL");
+//! assert_eq!(changes[1].to_string(), "entity s.b added");
+//! assert!(changes.iter().all(|change| change.severity() == Severity::NonBreaking));
+//! ```
+//!
+//! Since instances are encoded positionally in exchange structures,
+//! adding or removing an explicit attribute is breaking even if it is `OPTIONAL`.
+
+use crate::ir::*;
+use serde::Serialize;
+use std::fmt;
+
+/// Whether a [Change] keeps the data valid under the old schema valid under the new one
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Severity {
+    Breaking,
+    NonBreaking,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Severity::Breaking => f.pad("breaking"),
+            Severity::NonBreaking => f.pad("non-breaking"),
+        }
+    }
+}
+
+/// A change between two versions of schemas
+///
+/// `schema` is the name of the schema in the new version,
+/// or in the old version if the schema is removed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "change", rename_all = "snake_case")]
+pub enum Change {
+    SchemaAdded {
+        schema: String,
+    },
+    SchemaRemoved {
+        schema: String,
+    },
+    EntityAdded {
+        schema: String,
+        entity: String,
+    },
+    EntityRemoved {
+        schema: String,
+        entity: String,
+    },
+    TypeAdded {
+        schema: String,
+        ty: String,
+    },
+    TypeRemoved {
+        schema: String,
+        ty: String,
+    },
+    /// Underlying type of `TYPE` declaration is changed, e.g. `REAL` to `INTEGER`
+    TypeChanged {
+        schema: String,
+        ty: String,
+        old: String,
+        new: String,
+    },
+    AttributeAdded {
+        schema: String,
+        entity: String,
+        attribute: String,
+        ty: String,
+        optional: bool,
+    },
+    AttributeRemoved {
+        schema: String,
+        entity: String,
+        attribute: String,
+    },
+    AttributeTypeChanged {
+        schema: String,
+        entity: String,
+        attribute: String,
+        old: String,
+        new: String,
+    },
+    AttributeOptionalityChanged {
+        schema: String,
+        entity: String,
+        attribute: String,
+        optional: bool,
+    },
+    /// Order of explicit attributes kept in both versions is changed,
+    /// which breaks the positional parameters of exchange structure records
+    AttributesReordered {
+        schema: String,
+        entity: String,
+        old: Vec<String>,
+        new: Vec<String>,
+    },
+    /// Entities in `SUBTYPE OF` are changed
+    SupertypesChanged {
+        schema: String,
+        entity: String,
+        old: Vec<String>,
+        new: Vec<String>,
+    },
+    /// Subtypes instantiable as this supertype are changed by `SUPERTYPE OF` or `SUBTYPE_CONSTRAINT`
+    SupertypeConstraintChanged {
+        schema: String,
+        entity: String,
+        old: Vec<String>,
+        new: Vec<String>,
+    },
+    EnumerationItemAdded {
+        schema: String,
+        ty: String,
+        item: String,
+    },
+    EnumerationItemRemoved {
+        schema: String,
+        ty: String,
+        item: String,
+    },
+    SelectTypeAdded {
+        schema: String,
+        ty: String,
+        item: String,
+    },
+    SelectTypeRemoved {
+        schema: String,
+        ty: String,
+        item: String,
+    },
+}
+
+impl Change {
+    pub fn severity(&self) -> Severity {
+        use Change::*;
+        match self {
+            SchemaAdded { .. }
+            | EntityAdded { .. }
+            | TypeAdded { .. }
+            | AttributeOptionalityChanged { optional: true, .. }
+            | EnumerationItemAdded { .. }
+            | SelectTypeAdded { .. } => Severity::NonBreaking,
+            SupertypeConstraintChanged { old, new, .. }
+                if old.iter().all(|subtype| new.contains(subtype)) =>
+            {
+                Severity::NonBreaking
+            }
+            _ => Severity::Breaking,
+        }
+    }
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use Change::*;
+        match self {
+            SchemaAdded { schema } => write!(f, "schema {} added", schema),
+            SchemaRemoved { schema } => write!(f, "schema {} removed", schema),
+            EntityAdded { schema, entity } => write!(f, "entity {}.{} added", schema, entity),
+            EntityRemoved { schema, entity } => {
+                write!(f, "entity {}.{} removed", schema, entity)
+            }
+            TypeAdded { schema, ty } => write!(f, "type {}.{} added", schema, ty),
+            TypeRemoved { schema, ty } => write!(f, "type {}.{} removed", schema, ty),
+            TypeChanged {
+                schema,
+                ty,
+                old,
+                new,
+            } => write!(f, "type {}.{} changed from {} to {}", schema, ty, old, new),
+            AttributeAdded {
+                schema,
+                entity,
+                attribute,
+                ty,
+                optional,
+            } => write!(
+                f,
+                "attribute {}.{}.{} added as {}{}",
+                schema,
+                entity,
+                attribute,
+                if *optional { "OPTIONAL " } else { "" },
+                ty
+            ),
+            AttributeRemoved {
+                schema,
+                entity,
+                attribute,
+            } => write!(f, "attribute {}.{}.{} removed", schema, entity, attribute),
+            AttributeTypeChanged {
+                schema,
+                entity,
+                attribute,
+                old,
+                new,
+            } => write!(
+                f,
+                "attribute {}.{}.{} changed from {} to {}",
+                schema, entity, attribute, old, new
+            ),
+            AttributeOptionalityChanged {
+                schema,
+                entity,
+                attribute,
+                optional,
+            } => write!(
+                f,
+                "attribute {}.{}.{} made {}",
+                schema,
+                entity,
+                attribute,
+                if *optional { "OPTIONAL" } else { "mandatory" }
+            ),
+            AttributesReordered {
+                schema,
+                entity,
+                old,
+                new,
+            } => write!(
+                f,
+                "attributes of entity {}.{} reordered from ({}) to ({})",
+                schema,
+                entity,
+                old.join(", "),
+                new.join(", ")
+            ),
+            SupertypesChanged {
+                schema,
+                entity,
+                old,
+                new,
+            } => write!(
+                f,
+                "supertypes of entity {}.{} changed from ({}) to ({})",
+                schema,
+                entity,
+                old.join(", "),
+                new.join(", ")
+            ),
+            SupertypeConstraintChanged {
+                schema,
+                entity,
+                old,
+                new,
+            } => write!(
+                f,
+                "instantiable subtypes of entity {}.{} changed from ({}) to ({})",
+                schema,
+                entity,
+                old.join(", "),
+                new.join(", ")
+            ),
+            EnumerationItemAdded { schema, ty, item } => {
+                write!(f, "enumeration item {} added to {}.{}", item, schema, ty)
+            }
+            EnumerationItemRemoved { schema, ty, item } => {
+                write!(
+                    f,
+                    "enumeration item {} removed from {}.{}",
+                    item, schema, ty
+                )
+            }
+            SelectTypeAdded { schema, ty, item } => {
+                write!(f, "select item {} added to {}.{}", item, schema, ty)
+            }
+            SelectTypeRemoved { schema, ty, item } => {
+                write!(f, "select item {} removed from {}.{}", item, schema, ty)
+            }
+        }
+    }
+}
+
+/// Compare two versions of schemas
+///
+/// Schemas are matched by name.
+/// If each version consists of a single schema, they are compared even if their names differ,
+/// since schema names usually contain the edition, e.g. `ap214_..._mim_lf` for different editions.
+pub fn diff(old: &IR, new: &IR) -> Vec<Change> {
+    let mut changes = Vec::new();
+    if let ([old], [new]) = (old.schemas.as_slice(), new.schemas.as_slice()) {
+        diff_schema(&mut changes, old, new);
+        return changes;
+    }
+    for schema in &new.schemas {
+        match old.schemas.iter().find(|old| old.name == schema.name) {
+            Some(old) => diff_schema(&mut changes, old, schema),
+            None => changes.push(Change::SchemaAdded {
+                schema: schema.name.clone(),
+            }),
+        }
+    }
+    for schema in &old.schemas {
+        if !new.schemas.iter().any(|new| new.name == schema.name) {
+            changes.push(Change::SchemaRemoved {
+                schema: schema.name.clone(),
+            });
+        }
+    }
+    changes
+}
+
+fn diff_schema(changes: &mut Vec<Change>, old: &Schema, new: &Schema) {
+    let schema = &new.name;
+    for ty in &new.types {
+        match old.types.iter().find(|old| old.id() == ty.id()) {
+            Some(old) => diff_type(changes, schema, old, ty),
+            None => changes.push(Change::TypeAdded {
+                schema: schema.clone(),
+                ty: ty.id().to_string(),
+            }),
+        }
+    }
+    for ty in &old.types {
+        if !new.types.iter().any(|new| new.id() == ty.id()) {
+            changes.push(Change::TypeRemoved {
+                schema: schema.clone(),
+                ty: ty.id().to_string(),
+            });
+        }
+    }
+    for entity in &new.entities {
+        match old.entities.iter().find(|old| old.name == entity.name) {
+            Some(old) => diff_entity(changes, schema, old, entity),
+            None => changes.push(Change::EntityAdded {
+                schema: schema.clone(),
+                entity: entity.name.clone(),
+            }),
+        }
+    }
+    for entity in &old.entities {
+        if !new.entities.iter().any(|new| new.name == entity.name) {
+            changes.push(Change::EntityRemoved {
+                schema: schema.clone(),
+                entity: entity.name.clone(),
+            });
+        }
+    }
+}
+
+/// Items in `new` but not in `old`, and items in `old` but not in `new`
+fn added_removed(old: &[String], new: &[String]) -> (Vec<String>, Vec<String>) {
+    let added = new.iter().filter(|item| !old.contains(item)).cloned();
+    let removed = old.iter().filter(|item| !new.contains(item)).cloned();
+    (added.collect(), removed.collect())
+}
+
+fn underlying(ty: &TypeDecl) -> String {
+    match ty {
+        TypeDecl::Simple(Simple { ty, .. }) => ty.0.to_string(),
+        TypeDecl::Rename(Rename { ty, .. }) => ty.to_string(),
+        TypeDecl::Enumeration(_) => "ENUMERATION".to_string(),
+        TypeDecl::Select(_) => "SELECT".to_string(),
+    }
+}
+
+fn diff_type(changes: &mut Vec<Change>, schema: &str, old: &TypeDecl, new: &TypeDecl) {
+    let (added, removed) = match (old, new) {
+        (TypeDecl::Enumeration(old), TypeDecl::Enumeration(new)) => {
+            let (added, removed) = added_removed(&old.items, &new.items);
+            let added = added.into_iter().map(|item| Change::EnumerationItemAdded {
+                schema: schema.to_string(),
+                ty: new.id.clone(),
+                item,
+            });
+            let removed = removed
+                .into_iter()
+                .map(|item| Change::EnumerationItemRemoved {
+                    schema: schema.to_string(),
+                    ty: new.id.clone(),
+                    item,
+                });
+            (added.collect::<Vec<_>>(), removed.collect::<Vec<_>>())
+        }
+        (TypeDecl::Select(old), TypeDecl::Select(new)) => {
+            let names =
+                |types: &[TypeRef]| types.iter().map(ToString::to_string).collect::<Vec<_>>();
+            let (added, removed) = added_removed(&names(&old.types), &names(&new.types));
+            let added = added.into_iter().map(|item| Change::SelectTypeAdded {
+                schema: schema.to_string(),
+                ty: new.id.clone(),
+                item,
+            });
+            let removed = removed.into_iter().map(|item| Change::SelectTypeRemoved {
+                schema: schema.to_string(),
+                ty: new.id.clone(),
+                item,
+            });
+            (added.collect(), removed.collect())
+        }
+        _ => {
+            let (old_ty, new_ty) = (underlying(old), underlying(new));
+            if old_ty != new_ty {
+                changes.push(Change::TypeChanged {
+                    schema: schema.to_string(),
+                    ty: new.id().to_string(),
+                    old: old_ty,
+                    new: new_ty,
+                });
+            }
+            return;
+        }
+    };
+    changes.extend(added);
+    changes.extend(removed);
+}
+
+fn diff_entity(changes: &mut Vec<Change>, schema: &str, old: &Entity, new: &Entity) {
+    let names = |types: &[TypeRef]| types.iter().map(ToString::to_string).collect::<Vec<_>>();
+    let (old_supertypes, new_supertypes) = (names(&old.supertypes), names(&new.supertypes));
+    if old_supertypes != new_supertypes {
+        changes.push(Change::SupertypesChanged {
+            schema: schema.to_string(),
+            entity: new.name.clone(),
+            old: old_supertypes,
+            new: new_supertypes,
+        });
+    }
+    let (old_constraints, new_constraints) = (names(&old.constraints), names(&new.constraints));
+    let (added, removed) = added_removed(&old_constraints, &new_constraints);
+    if !added.is_empty() || !removed.is_empty() {
+        changes.push(Change::SupertypeConstraintChanged {
+            schema: schema.to_string(),
+            entity: new.name.clone(),
+            old: old_constraints,
+            new: new_constraints,
+        });
+    }
+
+    for attr in &new.attributes {
+        let old = match old.attributes.iter().find(|old| old.name == attr.name) {
+            Some(old) => old,
+            None => {
+                changes.push(Change::AttributeAdded {
+                    schema: schema.to_string(),
+                    entity: new.name.clone(),
+                    attribute: attr.name.clone(),
+                    ty: attr.ty.to_string(),
+                    optional: attr.optional,
+                });
+                continue;
+            }
+        };
+        let (old_ty, new_ty) = (old.ty.to_string(), attr.ty.to_string());
+        if old_ty != new_ty {
+            changes.push(Change::AttributeTypeChanged {
+                schema: schema.to_string(),
+                entity: new.name.clone(),
+                attribute: attr.name.clone(),
+                old: old_ty,
+                new: new_ty,
+            });
+        }
+        if old.optional != attr.optional {
+            changes.push(Change::AttributeOptionalityChanged {
+                schema: schema.to_string(),
+                entity: new.name.clone(),
+                attribute: attr.name.clone(),
+                optional: attr.optional,
+            });
+        }
+    }
+    for attr in &old.attributes {
+        if !new.attributes.iter().any(|new| new.name == attr.name) {
+            changes.push(Change::AttributeRemoved {
+                schema: schema.to_string(),
+                entity: new.name.clone(),
+                attribute: attr.name.clone(),
+            });
+        }
+    }
+
+    // Added and removed attributes are reported above, and compare the order of the others
+    let kept = |attrs: &[EntityAttribute], others: &[EntityAttribute]| {
+        attrs
+            .iter()
+            .filter(|attr| others.iter().any(|other| other.name == attr.name))
+            .map(|attr| attr.name.clone())
+            .collect::<Vec<_>>()
+    };
+    let (old_order, new_order) = (
+        kept(&old.attributes, &new.attributes),
+        kept(&new.attributes, &old.attributes),
+    );
+    if old_order != new_order {
+        changes.push(Change::AttributesReordered {
+            schema: schema.to_string(),
+            entity: new.name.clone(),
+            old: old_order,
+            new: new_order,
+        });
+    }
+}
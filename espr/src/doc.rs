@@ -0,0 +1,707 @@
+//! Generate a static documentation site of EXPRESS schemas
+//!
+//! The site consists of an index of schemas, an index of each schema,
+//! and a page for each entity and type declared in the schema:
+//!
+//! ```text
+//! index.html
+//! shapes/index.html
+//! shapes/circle.html
+//! shapes/shape_select.html
+//! ...
+//! ```
+//!
+//! Each page shows the structure resolved in [IR], e.g. inherited attributes and subtypes,
+//! together with `WHERE` rules, the EXPRESS definition,
+//! and the text of remarks tagged to the declaration, its attributes, rules and enumeration items
+//! (see [Remark::is_tagged](crate::ast::Remark::is_tagged)).
+//! Every entity and type appearing in a page is linked to its page.
+//!
+//! ```
+//! use espr::{ast::SyntaxTree, doc::*, ir::IR};
+//!
+//! let st = SyntaxTree::parse(r#"
+//!   SCHEMA s;
+//!     ENTITY a; x: b; END_ENTITY;
+//!     ENTITY b; END_ENTITY;
+//!   END_SCHEMA;
+//!   (*"a.x" Attribute referring b *)
+//! "#).unwrap();
+//! let ir = IR::from_syntax_tree(&st).unwrap();
+//! let pages = document(&st, &ir, DocFormat::Markdown);
+//! let a = pages.iter().find(|page| page.path.ends_with("s/a.md")).unwrap();
+//! assert!(a.content.contains("| x | [b](../s/b.md) | Attribute referring b |"));
+//! ```
+
+use crate::{ast, ir::*};
+use std::{collections::HashMap, fmt, path::PathBuf, str::FromStr};
+
+/// Output format of [document]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DocFormat {
+    Html,
+    Markdown,
+}
+
+impl FromStr for DocFormat {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "html" => Ok(DocFormat::Html),
+            "markdown" | "md" => Ok(DocFormat::Markdown),
+            _ => Err(format!("Unknown documentation format: {}", s)),
+        }
+    }
+}
+
+impl fmt::Display for DocFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DocFormat::Html => write!(f, "html"),
+            DocFormat::Markdown => write!(f, "markdown"),
+        }
+    }
+}
+
+/// Length of the longest run of backticks in `text`
+fn backticks(text: &str) -> usize {
+    text.split(|c| c != '`').map(str::len).max().unwrap_or(0)
+}
+
+impl DocFormat {
+    /// Extension of page files
+    pub fn extension(&self) -> &'static str {
+        match self {
+            DocFormat::Html => "html",
+            DocFormat::Markdown => "md",
+        }
+    }
+
+    fn escape(&self, text: &str) -> String {
+        match self {
+            DocFormat::Html => text
+                .replace('&', "&amp;")
+                .replace('<', "&lt;")
+                .replace('>', "&gt;")
+                .replace('"', "&quot;"),
+            DocFormat::Markdown => text
+                .replace('\\', "\\\\")
+                .replace('|', "\\|")
+                .replace('*', "\\*")
+                .replace('<', "&lt;"),
+        }
+    }
+
+    fn link(&self, text: &str, href: &str) -> String {
+        match self {
+            DocFormat::Html => format!("<a href=\"{}\">{}</a>", href, self.escape(text)),
+            DocFormat::Markdown => format!("[{}]({})", self.escape(text), href),
+        }
+    }
+
+    fn code(&self, text: &str) -> String {
+        match self {
+            DocFormat::Html => format!("<code>{}</code>", self.escape(text)),
+            DocFormat::Markdown => {
+                // Fence longer than backticks in the text, and spaces not to merge with them
+                let fence = "`".repeat(backticks(text) + 1);
+                let pad = if text.starts_with('`') || text.ends_with('`') {
+                    " "
+                } else {
+                    ""
+                };
+                format!("{0}{1}{2}{1}{0}", fence, pad, text.replace('|', "\\|"))
+            }
+        }
+    }
+
+    fn heading(&self, level: usize, text: &str) -> String {
+        match self {
+            DocFormat::Html => format!("<h{0}>{1}</h{0}>\n", level, text),
+            DocFormat::Markdown => format!("{} {}\n\n", "#".repeat(level), text),
+        }
+    }
+
+    /// Paragraphs of plain text, e.g. remarks
+    fn text(&self, text: &str) -> String {
+        if text.trim().is_empty() {
+            return String::new();
+        }
+        match self {
+            DocFormat::Html => text
+                .split("\n\n")
+                .map(|paragraph| format!("<p>{}</p>\n", self.escape(paragraph.trim())))
+                .collect(),
+            DocFormat::Markdown => format!("{}\n\n", text.trim()),
+        }
+    }
+
+    /// Paragraph of inline markups, e.g. links
+    fn paragraph(&self, inline: &str) -> String {
+        match self {
+            DocFormat::Html => format!("<p>{}</p>\n", inline),
+            DocFormat::Markdown => format!("{}\n\n", inline),
+        }
+    }
+
+    fn list(&self, items: &[String]) -> String {
+        match self {
+            DocFormat::Html => format!(
+                "<ul>\n{}</ul>\n",
+                items
+                    .iter()
+                    .map(|item| format!("<li>{}</li>\n", item))
+                    .collect::<String>()
+            ),
+            DocFormat::Markdown => format!(
+                "{}\n",
+                items
+                    .iter()
+                    .map(|item| format!("- {}\n", item))
+                    .collect::<String>()
+            ),
+        }
+    }
+
+    fn table(&self, header: &[&str], rows: &[Vec<String>]) -> String {
+        match self {
+            DocFormat::Html => {
+                let mut table = "<table>\n<tr>".to_string();
+                for column in header {
+                    table += &format!("<th>{}</th>", column);
+                }
+                table += "</tr>\n";
+                for row in rows {
+                    table += "<tr>";
+                    for cell in row {
+                        table += &format!("<td>{}</td>", cell);
+                    }
+                    table += "</tr>\n";
+                }
+                table + "</table>\n"
+            }
+            DocFormat::Markdown => {
+                let mut table = format!("| {} |\n", header.join(" | "));
+                table += &format!("|{}\n", " --- |".repeat(header.len()));
+                for row in rows {
+                    let cells: Vec<_> = row.iter().map(|cell| cell.replace('\n', " ")).collect();
+                    table += &format!("| {} |\n", cells.join(" | "));
+                }
+                table + "\n"
+            }
+        }
+    }
+
+    fn code_block(&self, text: &str) -> String {
+        match self {
+            DocFormat::Html => {
+                format!("<pre><code>{}</code></pre>\n", self.escape(text.trim_end()))
+            }
+            DocFormat::Markdown => {
+                let fence = "`".repeat((backticks(text) + 1).max(3));
+                format!("{0}express\n{1}\n{0}\n\n", fence, text.trim_end())
+            }
+        }
+    }
+
+    fn page(&self, title: &str, body: &str) -> String {
+        match self {
+            DocFormat::Html => format!(
+                "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n</head>\n<body>\n{}</body>\n</html>\n",
+                self.escape(title),
+                body
+            ),
+            DocFormat::Markdown => body.to_string(),
+        }
+    }
+}
+
+/// A page of the documentation site
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Page {
+    /// Path relative to the root of the site
+    pub path: PathBuf,
+    pub content: String,
+}
+
+/// Entity, type or schema identified by the schema name and its name
+type Key = (String, String);
+
+fn key(ty: &TypeRef) -> Option<Key> {
+    match ty {
+        TypeRef::Named { name, scope, .. } | TypeRef::Entity { name, scope, .. } => Some((
+            scope.schema_name().unwrap_or_default().to_string(),
+            name.clone(),
+        )),
+        _ => None,
+    }
+}
+
+/// Named type in `ty`, e.g. `point` of `LIST [2:?] OF point`
+fn named(ty: &TypeRef) -> Option<Key> {
+    match ty {
+        TypeRef::Set { base, .. }
+        | TypeRef::Bag { base, .. }
+        | TypeRef::List { base, .. }
+        | TypeRef::Array { base, .. } => named(base),
+        _ => key(ty),
+    }
+}
+
+/// Declaration referring another declaration, and the attribute through which it refers
+#[derive(Debug, Clone)]
+struct Referrer {
+    key: Key,
+    attribute: Option<String>,
+}
+
+struct Site<'a> {
+    st: &'a ast::SyntaxTree,
+    format: DocFormat,
+    entities: HashMap<Key, &'a Entity>,
+    subtypes: HashMap<Key, Vec<Key>>,
+    referrers: HashMap<Key, Vec<Referrer>>,
+}
+
+impl<'a> Site<'a> {
+    fn new(st: &'a ast::SyntaxTree, ir: &'a IR, format: DocFormat) -> Self {
+        let mut site = Site {
+            st,
+            format,
+            entities: HashMap::new(),
+            subtypes: HashMap::new(),
+            referrers: HashMap::new(),
+        };
+        for schema in &ir.schemas {
+            for ty in &schema.types {
+                let from = (schema.name.clone(), ty.id().to_string());
+                let refers = match ty {
+                    TypeDecl::Rename(Rename { ty, .. }) => vec![ty],
+                    TypeDecl::Select(Select { types, .. }) => types.iter().collect(),
+                    TypeDecl::Simple(_) | TypeDecl::Enumeration(_) => Vec::new(),
+                };
+                for ty in refers {
+                    site.refer(&from, ty, None);
+                }
+            }
+            for entity in &schema.entities {
+                let from = (schema.name.clone(), entity.name.clone());
+                site.entities.insert(from.clone(), entity);
+                for supertype in &entity.supertypes {
+                    if let Some(sup) = key(supertype) {
+                        site.subtypes.entry(sup).or_default().push(from.clone());
+                    }
+                }
+                for attr in &entity.attributes {
+                    site.refer(&from, &attr.ty, Some(&attr.name));
+                }
+                for attr in &entity.derived {
+                    site.refer(&from, &attr.ty, Some(&attr.name));
+                }
+            }
+        }
+        site
+    }
+
+    fn refer(&mut self, from: &Key, ty: &TypeRef, attribute: Option<&str>) {
+        if let Some(to) = named(ty) {
+            self.referrers.entry(to).or_default().push(Referrer {
+                key: from.clone(),
+                attribute: attribute.map(str::to_string),
+            });
+        }
+    }
+
+    fn remarks(&self, schema: &str, path: &[&str]) -> String {
+        self.st.remarks_of(schema, path).join("\n\n")
+    }
+
+    /// First line of the remarks for index pages
+    fn summary(&self, schema: &str, path: &[&str]) -> String {
+        let remarks = self.st.remarks_of(schema, path);
+        let line = remarks
+            .first()
+            .and_then(|remark| remark.lines().next())
+            .unwrap_or_default();
+        self.format.escape(line.trim())
+    }
+
+    /// Link from a page in a schema directory
+    fn link(&self, (schema, name): &Key) -> String {
+        let href = format!("../{}/{}.{}", schema, name, self.format.extension());
+        self.format.link(name, &href)
+    }
+
+    fn type_ref(&self, ty: &TypeRef) -> String {
+        let bound = |bound: &Option<Bound>| match bound {
+            Some(bound) => format!(" {}", bound),
+            None => String::new(),
+        };
+        match ty {
+            TypeRef::SimpleType(_) => self.format.escape(&ty.to_string()),
+            TypeRef::Named { .. } | TypeRef::Entity { .. } => self.link(&key(ty).unwrap()),
+            TypeRef::Set { base, bound: b } => {
+                format!("SET{} OF {}", bound(b), self.type_ref(base))
+            }
+            TypeRef::Bag { base, bound: b } => {
+                format!("BAG{} OF {}", bound(b), self.type_ref(base))
+            }
+            TypeRef::List {
+                base,
+                bound: b,
+                unique,
+            } => format!(
+                "LIST{} OF {}{}",
+                bound(b),
+                if *unique { "UNIQUE " } else { "" },
+                self.type_ref(base)
+            ),
+            TypeRef::Array {
+                base,
+                bound: b,
+                unique,
+                optional,
+            } => format!(
+                "ARRAY{} OF {}{}{}",
+                bound(b),
+                if *optional { "OPTIONAL " } else { "" },
+                if *unique { "UNIQUE " } else { "" },
+                self.type_ref(base)
+            ),
+        }
+    }
+
+    fn ast_schema(&self, schema: &str) -> Option<&'a ast::Schema> {
+        self.st.schemas.iter().find(|s| s.name == schema)
+    }
+
+    fn where_rules(&self, schema: &str, name: &str, clause: &Option<ast::WhereClause>) -> String {
+        let rules = match clause {
+            Some(clause) if !clause.rules.is_empty() => &clause.rules,
+            _ => return String::new(),
+        };
+        let rows: Vec<_> = rules
+            .iter()
+            .map(|rule| {
+                let label = rule.label.as_deref().unwrap_or_default();
+                vec![
+                    self.format.escape(label),
+                    self.format.code(&rule.expr.to_string()),
+                    self.format.escape(&self.remarks(schema, &[name, label])),
+                ]
+            })
+            .collect();
+        self.format.heading(2, "WHERE rules")
+            + &self
+                .format
+                .table(&["Label", "Expression", "Description"], &rows)
+    }
+
+    fn referrers(&self, key: &Key) -> String {
+        let referrers = match self.referrers.get(key) {
+            Some(referrers) => referrers,
+            None => return String::new(),
+        };
+        let items: Vec<_> = referrers
+            .iter()
+            .map(|referrer| match &referrer.attribute {
+                Some(attribute) => format!(
+                    "{}.{}",
+                    self.link(&referrer.key),
+                    self.format.escape(attribute)
+                ),
+                None => self.link(&referrer.key),
+            })
+            .collect();
+        self.format.heading(2, "Referenced by") + &self.format.list(&items)
+    }
+
+    /// Attributes of all supertypes, from the root of the inheritance
+    fn inherited(&self, entity: &Entity, visited: &mut Vec<Key>) -> Vec<Vec<String>> {
+        let mut rows = Vec::new();
+        for supertype in &entity.supertypes {
+            let sup = match key(supertype) {
+                Some(sup) if !visited.contains(&sup) => sup,
+                _ => continue,
+            };
+            visited.push(sup.clone());
+            let parent = match self.entities.get(&sup) {
+                Some(parent) => parent,
+                None => continue,
+            };
+            rows.append(&mut self.inherited(parent, visited));
+            for attr in &parent.attributes {
+                rows.push(vec![
+                    self.format.escape(&attr.name),
+                    self.attribute_type(&attr.ty, attr.optional),
+                    self.link(&sup),
+                ]);
+            }
+            for attr in &parent.derived {
+                rows.push(vec![
+                    format!("(DER) {}", self.format.escape(&attr.name)),
+                    self.type_ref(&attr.ty),
+                    self.link(&sup),
+                ]);
+            }
+        }
+        rows
+    }
+
+    fn attribute_type(&self, ty: &TypeRef, optional: bool) -> String {
+        if optional {
+            format!("OPTIONAL {}", self.type_ref(ty))
+        } else {
+            self.type_ref(ty)
+        }
+    }
+
+    fn entity_page(&self, schema: &str, entity: &Entity) -> Page {
+        let f = self.format;
+        let name = entity.name.as_str();
+        let this = (schema.to_string(), name.to_string());
+        let mut body = f.heading(1, &format!("ENTITY {}", f.escape(name)));
+        body += &f.paragraph(&format!(
+            "Schema: {}",
+            f.link(schema, &format!("index.{}", f.extension()))
+        ));
+        body += &f.text(&self.remarks(schema, &[name]));
+
+        if !entity.supertypes.is_empty() {
+            let items: Vec<_> = entity
+                .supertypes
+                .iter()
+                .map(|ty| self.type_ref(ty))
+                .collect();
+            body += &f.heading(2, "Supertypes");
+            body += &f.list(&items);
+        }
+        if let Some(subtypes) = self.subtypes.get(&this) {
+            let items: Vec<_> = subtypes.iter().map(|sub| self.link(sub)).collect();
+            body += &f.heading(2, "Subtypes");
+            body += &f.list(&items);
+        }
+
+        let description = |attr: &str| f.escape(&self.remarks(schema, &[name, attr]));
+        if !entity.attributes.is_empty() {
+            let rows: Vec<_> = entity
+                .attributes
+                .iter()
+                .map(|attr| {
+                    vec![
+                        f.escape(&attr.name),
+                        self.attribute_type(&attr.ty, attr.optional),
+                        description(&attr.name),
+                    ]
+                })
+                .collect();
+            body += &f.heading(2, "Attributes");
+            body += &f.table(&["Name", "Type", "Description"], &rows);
+        }
+        let inherited = self.inherited(entity, &mut Vec::new());
+        if !inherited.is_empty() {
+            body += &f.heading(2, "Inherited attributes");
+            body += &f.table(&["Name", "Type", "From"], &inherited);
+        }
+        if !entity.derived.is_empty() {
+            let rows: Vec<_> = entity
+                .derived
+                .iter()
+                .map(|attr| {
+                    vec![
+                        f.escape(&attr.name),
+                        self.type_ref(&attr.ty),
+                        description(&attr.name),
+                    ]
+                })
+                .collect();
+            body += &f.heading(2, "Derived attributes");
+            body += &f.table(&["Name", "Type", "Description"], &rows);
+        }
+        if !entity.inverse.is_empty() {
+            let rows: Vec<_> = entity
+                .inverse
+                .iter()
+                .map(|attr| {
+                    let upper = match attr.upper {
+                        Some(upper) => upper.to_string(),
+                        None => "?".to_string(),
+                    };
+                    let dest = match attr.referrers.first() {
+                        Some((dest, _)) => self.type_ref(dest),
+                        None => String::new(),
+                    };
+                    vec![
+                        f.escape(&attr.name),
+                        format!(
+                            "SET [{}:{}] OF {} FOR {}",
+                            attr.lower,
+                            upper,
+                            dest,
+                            f.escape(&attr.attribute)
+                        ),
+                        description(&attr.name),
+                    ]
+                })
+                .collect();
+            body += &f.heading(2, "Inverse attributes");
+            body += &f.table(&["Name", "Type", "Description"], &rows);
+        }
+
+        let decl = self
+            .ast_schema(schema)
+            .and_then(|s| s.entities.iter().find(|e| e.name == name));
+        if let Some(decl) = decl {
+            body += &self.where_rules(schema, name, &decl.where_clause);
+        }
+        body += &self.referrers(&this);
+        if let Some(decl) = decl {
+            body += &f.heading(2, "EXPRESS");
+            body += &f.code_block(&decl.to_string());
+        }
+        Page {
+            path: PathBuf::from(schema).join(format!("{}.{}", name, f.extension())),
+            content: f.page(name, &body),
+        }
+    }
+
+    fn type_page(&self, schema: &str, ty: &TypeDecl) -> Page {
+        let f = self.format;
+        let name = ty.id();
+        let this = (schema.to_string(), name.to_string());
+        let mut body = f.heading(1, &format!("TYPE {}", f.escape(name)));
+        body += &f.paragraph(&format!(
+            "Schema: {}",
+            f.link(schema, &format!("index.{}", f.extension()))
+        ));
+        body += &f.text(&self.remarks(schema, &[name]));
+
+        match ty {
+            TypeDecl::Simple(Simple { ty, .. }) => {
+                body += &f.heading(2, "Underlying type");
+                body += &f.paragraph(&f.escape(&TypeRef::SimpleType(ty.clone()).to_string()));
+            }
+            TypeDecl::Rename(Rename { ty, .. }) => {
+                body += &f.heading(2, "Underlying type");
+                body += &f.paragraph(&self.type_ref(ty));
+            }
+            TypeDecl::Enumeration(Enumeration { items, .. }) => {
+                let rows: Vec<_> = items
+                    .iter()
+                    .map(|item| {
+                        vec![
+                            f.escape(item),
+                            f.escape(&self.remarks(schema, &[name, item])),
+                        ]
+                    })
+                    .collect();
+                body += &f.heading(2, "Enumeration items");
+                body += &f.table(&["Item", "Description"], &rows);
+            }
+            TypeDecl::Select(Select { types, .. }) => {
+                let items: Vec<_> = types.iter().map(|ty| self.type_ref(ty)).collect();
+                body += &f.heading(2, "Select items");
+                body += &f.list(&items);
+            }
+        }
+
+        let decl = self
+            .ast_schema(schema)
+            .and_then(|s| s.types.iter().find(|t| t.type_id == name));
+        if let Some(decl) = decl {
+            body += &self.where_rules(schema, name, &decl.where_clause);
+        }
+        body += &self.referrers(&this);
+        if let Some(decl) = decl {
+            body += &f.heading(2, "EXPRESS");
+            body += &f.code_block(&decl.to_string());
+        }
+        Page {
+            path: PathBuf::from(schema).join(format!("{}.{}", name, f.extension())),
+            content: f.page(name, &body),
+        }
+    }
+
+    fn schema_page(&self, schema: &Schema) -> Page {
+        let f = self.format;
+        let name = schema.name.as_str();
+        let mut body = f.heading(1, &format!("SCHEMA {}", f.escape(name)));
+        body += &f.paragraph(&f.link("Schemas", &format!("../index.{}", f.extension())));
+        body += &f.text(&self.remarks(name, &[]));
+
+        let mut entities: Vec<_> = schema.entities.iter().map(|e| e.name.as_str()).collect();
+        entities.sort_unstable();
+        if !entities.is_empty() {
+            let rows: Vec<_> = entities
+                .iter()
+                .map(|entity| {
+                    vec![
+                        self.link(&(name.to_string(), entity.to_string())),
+                        self.summary(name, &[entity]),
+                    ]
+                })
+                .collect();
+            body += &f.heading(2, "Entities");
+            body += &f.table(&["Name", "Description"], &rows);
+        }
+
+        let mut types: Vec<_> = schema.types.iter().collect();
+        types.sort_unstable_by_key(|ty| ty.id());
+        if !types.is_empty() {
+            let rows: Vec<_> = types
+                .iter()
+                .map(|ty| {
+                    let kind = match ty {
+                        TypeDecl::Simple(_) | TypeDecl::Rename(_) => "defined",
+                        TypeDecl::Enumeration(_) => "enumeration",
+                        TypeDecl::Select(_) => "select",
+                    };
+                    vec![
+                        self.link(&(name.to_string(), ty.id().to_string())),
+                        kind.to_string(),
+                        self.summary(name, &[ty.id()]),
+                    ]
+                })
+                .collect();
+            body += &f.heading(2, "Types");
+            body += &f.table(&["Name", "Kind", "Description"], &rows);
+        }
+        Page {
+            path: PathBuf::from(name).join(format!("index.{}", f.extension())),
+            content: f.page(name, &body),
+        }
+    }
+
+    fn index_page(&self, ir: &IR) -> Page {
+        let f = self.format;
+        let rows: Vec<_> = ir
+            .schemas
+            .iter()
+            .map(|schema| {
+                let href = format!("{}/index.{}", schema.name, f.extension());
+                vec![f.link(&schema.name, &href), self.summary(&schema.name, &[])]
+            })
+            .collect();
+        let body = f.heading(1, "Schemas") + &f.table(&["Name", "Description"], &rows);
+        Page {
+            path: PathBuf::from(format!("index.{}", f.extension())),
+            content: f.page("Schemas", &body),
+        }
+    }
+}
+
+/// Generate pages of the documentation site
+pub fn document(st: &ast::SyntaxTree, ir: &IR, format: DocFormat) -> Vec<Page> {
+    let site = Site::new(st, ir, format);
+    let mut pages = vec![site.index_page(ir)];
+    for schema in &ir.schemas {
+        pages.push(site.schema_page(schema));
+        for ty in &schema.types {
+            pages.push(site.type_page(&schema.name, ty));
+        }
+        for entity in &schema.entities {
+            pages.push(site.entity_page(&schema.name, entity));
+        }
+    }
+    pages
+}
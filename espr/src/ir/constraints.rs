@@ -3,7 +3,7 @@
 use super::*;
 use crate::ast;
 use std::collections::hash_map::Entry;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 /// Expression appears in `SUBTYPE_CONSTRAINT` with resolved [Path]
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -97,6 +97,14 @@ pub struct Constraints {
     /// Each super-type can be instantiable as its subtypes,
     /// but possible subtypes cannot be determined from local description in EXPRESS.
     pub instantiables: HashMap<Path, Vec<Vec<Path>>>,
+    /// Combined `SUBTYPE_CONSTRAINT` of each super-type evaluated into [Constraints::instantiables]
+    pub exprs: HashMap<Path, ConstraintExpr>,
+    /// Explicit attributes redeclared as derived in some subtype,
+    /// e.g. `(base, x)` for `SELF\base.x : REAL := 1.0;` in `DERIVE` clause of a subtype of `base`
+    pub redeclared_as_derived: HashSet<(Path, String)>,
+    /// `ENUMERATION` and `SELECT` types declared as `BASED_ON` each type,
+    /// e.g. `base => [ext]` for `TYPE ext = SELECT BASED_ON base WITH (...);`
+    pub extensions: HashMap<Path, Vec<Path>>,
 }
 
 // Execute b), c), and d) steps of the algorithm described in the section B.3
@@ -221,17 +229,79 @@ pub fn gather_constraint_expr(
     Ok(exprs)
 }
 
+/// Gather explicit attributes redeclared as derived by `SELF\base.x` in `DERIVE` clauses
+pub fn gather_redeclared_as_derived(
+    ns: &Namespace,
+) -> Result<HashSet<(Path, String)>, SemanticError> {
+    let mut redeclared = HashSet::new();
+    for (path, named) in &ns.ast {
+        let derive = match named {
+            Named::Entity(ast::Entity {
+                derive_clause: Some(derive),
+                ..
+            }) => derive,
+            _ => continue,
+        };
+        for attr in &derive.attributes {
+            if let ast::AttributeDecl::Qualified {
+                group, attribute, ..
+            } = &attr.attr
+            {
+                let (entity, _) = ns
+                    .resolve(&path.scope, group)
+                    .map_err(|err| err.located(&path.scope, attr.span))?;
+                redeclared.insert((entity, attribute.clone()));
+            }
+        }
+    }
+    Ok(redeclared)
+}
+
+/// Gather the types declared as `BASED_ON` each `ENUMERATION` or `SELECT` type
+pub fn gather_extensions(ns: &Namespace) -> Result<HashMap<Path, Vec<Path>>, SemanticError> {
+    let mut extensions: HashMap<Path, Vec<Path>> = HashMap::new();
+    for (path, named) in &ns.ast {
+        let based_on = match named {
+            Named::Type(ast::TypeDecl {
+                underlying_type:
+                    ast::Type::Enumeration {
+                        based_on: Some(based_on),
+                        ..
+                    }
+                    | ast::Type::Select {
+                        based_on: Some(based_on),
+                        ..
+                    },
+                ..
+            }) => based_on,
+            _ => continue,
+        };
+        let (base, _index) = ns.resolve(&path.scope, based_on)?;
+        extensions.entry(base).or_default().push(path.clone());
+    }
+    Ok(extensions)
+}
+
 impl Constraints {
     pub fn new(ns: &Namespace, st: &SyntaxTree) -> Result<Self, SemanticError> {
         let exprs = gather_constraint_expr(ns, st)?;
         Ok(Constraints {
             instantiables: exprs
-                .into_iter()
-                .map(|(path, expr)| Ok((path, expr.as_instantiables(ns)?)))
+                .iter()
+                .map(|(path, expr)| Ok((path.clone(), expr.as_instantiables(ns)?)))
                 .collect::<Result<_, SemanticError>>()?,
+            exprs,
+            redeclared_as_derived: gather_redeclared_as_derived(ns)?,
+            extensions: gather_extensions(ns)?,
         })
     }
 
+    /// Check if an explicit attribute of the entity is redeclared as derived in some subtype
+    pub fn is_redeclared_as_derived(&self, entity: &Path, name: &str) -> bool {
+        self.redeclared_as_derived
+            .contains(&(entity.clone(), name.to_string()))
+    }
+
     pub fn is_supertype(&self, path: &Path) -> bool {
         self.instantiables.contains_key(path)
     }
@@ -404,7 +474,7 @@ mod tests {
     #[test]
     fn gather_constraint_expr_pet() {
         let st = ast::SyntaxTree::parse(PET).unwrap();
-        let ns = Namespace::new(&st);
+        let ns = Namespace::new(&st).unwrap();
         let exprs = gather_constraint_expr(&ns, &st).unwrap();
         let scope = Scope::root().schema("test_schema");
         let pet = Path::entity(&scope, "pet");
@@ -423,7 +493,7 @@ mod tests {
     #[test]
     fn gather_constraint_expr_person_andor() {
         let st = ast::SyntaxTree::parse(PERSON_ANDOR).unwrap();
-        let ns = Namespace::new(&st);
+        let ns = Namespace::new(&st).unwrap();
         let exprs = gather_constraint_expr(&ns, &st).unwrap();
         let scope = Scope::root().schema("test_schema");
         let person = Path::entity(&scope, "person");
@@ -441,7 +511,7 @@ mod tests {
     #[test]
     fn gather_constraint_expr_person_explicit() {
         let st = ast::SyntaxTree::parse(PERSON_ANDOR_SEPARATE).unwrap();
-        let ns = Namespace::new(&st);
+        let ns = Namespace::new(&st).unwrap();
         let exprs = gather_constraint_expr(&ns, &st).unwrap();
         let scope = Scope::root().schema("test_schema");
         let person = Path::entity(&scope, "person");
@@ -459,7 +529,7 @@ mod tests {
     #[test]
     fn gather_constraint_expr_person_default() {
         let st = ast::SyntaxTree::parse(PERSON_DEFAULT).unwrap();
-        let ns = Namespace::new(&st);
+        let ns = Namespace::new(&st).unwrap();
         let exprs = gather_constraint_expr(&ns, &st).unwrap();
         let scope = Scope::root().schema("test_schema");
         let person = Path::entity(&scope, "person");
@@ -477,7 +547,7 @@ mod tests {
     #[test]
     fn gather_constraint_expr_person_and() {
         let st = ast::SyntaxTree::parse(PERSON_AND).unwrap();
-        let ns = Namespace::new(&st);
+        let ns = Namespace::new(&st).unwrap();
         let exprs = gather_constraint_expr(&ns, &st).unwrap();
         let scope = Scope::root().schema("test_schema");
         let person = Path::entity(&scope, "person");
@@ -501,7 +571,7 @@ mod tests {
     #[test]
     fn gather_constraint_expr_person_and_separate() {
         let st = ast::SyntaxTree::parse(PERSON_AND_SEPARATE).unwrap();
-        let ns = Namespace::new(&st);
+        let ns = Namespace::new(&st).unwrap();
         let exprs = gather_constraint_expr(&ns, &st).unwrap();
         let scope = Scope::root().schema("test_schema");
         let person = Path::entity(&scope, "person");
@@ -525,19 +595,17 @@ mod tests {
     #[test]
     fn constraint_oneof() {
         let st = ast::SyntaxTree::parse(PET).unwrap();
-        let ns = Namespace::new(&st);
+        let ns = Namespace::new(&st).unwrap();
         let c = Constraints::new(&ns, &st).unwrap();
         let scope = Scope::root().schema("test_schema");
         assert_eq!(
-            dbg!(c),
-            Constraints {
-                instantiables: maplit::hashmap! {
-                    Path::entity(&scope, "pet") => vec![
-                        vec![Path::entity(&scope, "cat")],
-                        vec![Path::entity(&scope, "rabbit")],
-                        vec![Path::entity(&scope, "dog")],
-                    ]
-                }
+            dbg!(c.instantiables),
+            maplit::hashmap! {
+                Path::entity(&scope, "pet") => vec![
+                    vec![Path::entity(&scope, "cat")],
+                    vec![Path::entity(&scope, "rabbit")],
+                    vec![Path::entity(&scope, "dog")],
+                ]
             }
         );
     }
@@ -545,18 +613,16 @@ mod tests {
     #[test]
     fn supertype_of_oneof() {
         let st = ast::SyntaxTree::parse(SUPERTYPE_OF).unwrap();
-        let ns = Namespace::new(&st);
+        let ns = Namespace::new(&st).unwrap();
         let c = Constraints::new(&ns, &st).unwrap();
         let scope = Scope::root().schema("test_schema");
         assert_eq!(
-            dbg!(c),
-            Constraints {
-                instantiables: maplit::hashmap! {
-                    Path::entity(&scope, "base") => vec![
-                        vec![Path::entity(&scope, "sub1")],
-                        vec![Path::entity(&scope, "sub2")],
-                    ]
-                }
+            dbg!(c.instantiables),
+            maplit::hashmap! {
+                Path::entity(&scope, "base") => vec![
+                    vec![Path::entity(&scope, "sub1")],
+                    vec![Path::entity(&scope, "sub2")],
+                ]
             }
         );
     }
@@ -564,19 +630,17 @@ mod tests {
     #[test]
     fn supertype_of_andor() {
         let st = ast::SyntaxTree::parse(PERSON_ANDOR).unwrap();
-        let ns = Namespace::new(&st);
+        let ns = Namespace::new(&st).unwrap();
         let c = Constraints::new(&ns, &st).unwrap();
         let scope = Scope::root().schema("test_schema");
         assert_eq!(
-            dbg!(c),
-            Constraints {
-                instantiables: maplit::hashmap! {
-                    Path::entity(&scope, "person") => vec![
-                        vec![Path::entity(&scope, "employee")],
-                        vec![Path::entity(&scope, "student")],
-                        vec![Path::entity(&scope, "employee"), Path::entity(&scope, "student")],
-                    ]
-                }
+            dbg!(c.instantiables),
+            maplit::hashmap! {
+                Path::entity(&scope, "person") => vec![
+                    vec![Path::entity(&scope, "employee")],
+                    vec![Path::entity(&scope, "student")],
+                    vec![Path::entity(&scope, "employee"), Path::entity(&scope, "student")],
+                ]
             }
         );
     }
@@ -584,20 +648,18 @@ mod tests {
     #[test]
     fn supertype_of_and() {
         let st = ast::SyntaxTree::parse(PERSON_AND).unwrap();
-        let ns = Namespace::new(&st);
+        let ns = Namespace::new(&st).unwrap();
         let c = Constraints::new(&ns, &st).unwrap();
         let scope = Scope::root().schema("test_schema");
         assert_eq!(
-            dbg!(c),
-            Constraints {
-                instantiables: maplit::hashmap! {
-                    Path::entity(&scope, "person") => vec![
-                        vec![Path::entity(&scope, "male"), Path::entity(&scope, "citizen")],
-                        vec![Path::entity(&scope, "male"), Path::entity(&scope, "alien")],
-                        vec![Path::entity(&scope, "female"), Path::entity(&scope, "citizen")],
-                        vec![Path::entity(&scope, "female"), Path::entity(&scope, "alien")],
-                    ]
-                }
+            dbg!(c.instantiables),
+            maplit::hashmap! {
+                Path::entity(&scope, "person") => vec![
+                    vec![Path::entity(&scope, "male"), Path::entity(&scope, "citizen")],
+                    vec![Path::entity(&scope, "male"), Path::entity(&scope, "alien")],
+                    vec![Path::entity(&scope, "female"), Path::entity(&scope, "citizen")],
+                    vec![Path::entity(&scope, "female"), Path::entity(&scope, "alien")],
+                ]
             }
         );
     }
@@ -605,19 +667,17 @@ mod tests {
     #[test]
     fn default_constraint() {
         let st = ast::SyntaxTree::parse(PERSON_DEFAULT).unwrap();
-        let ns = Namespace::new(&st);
+        let ns = Namespace::new(&st).unwrap();
         let c = Constraints::new(&ns, &st).unwrap();
         let scope = Scope::root().schema("test_schema");
         assert_eq!(
-            dbg!(c),
-            Constraints {
-                instantiables: maplit::hashmap! {
-                    Path::entity(&scope, "person") => vec![
-                        vec![Path::entity(&scope, "employee")],
-                        vec![Path::entity(&scope, "student")],
-                        vec![Path::entity(&scope, "employee"), Path::entity(&scope, "student")],
-                    ]
-                }
+            dbg!(c.instantiables),
+            maplit::hashmap! {
+                Path::entity(&scope, "person") => vec![
+                    vec![Path::entity(&scope, "employee")],
+                    vec![Path::entity(&scope, "student")],
+                    vec![Path::entity(&scope, "employee"), Path::entity(&scope, "student")],
+                ]
             }
         );
     }
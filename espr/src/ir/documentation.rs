@@ -0,0 +1,136 @@
+use super::*;
+use crate::ast::Span;
+
+/// Remarks and the original definition of a declaration in EXPRESS input
+///
+/// These are attached after legalizing schemas,
+/// and emitted as doc comments of generated code.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Documentation {
+    /// Text of remarks tagged to the declaration, see [ast::Remark::is_tagged]
+    pub remarks: Vec<String>,
+    /// Definition in EXPRESS input, e.g. `ENTITY point; ... END_ENTITY;`
+    pub definition: Option<String>,
+}
+
+fn remarks(st: &SyntaxTree, schema: &str, path: &[&str]) -> Vec<String> {
+    st.remarks_of(schema, path)
+        .into_iter()
+        .map(str::to_string)
+        .collect()
+}
+
+/// Leading ASCII spaces and tabs of `line` in bytes
+fn indent_len(line: &str) -> usize {
+    line.len() - line.trim_start_matches(|c| c == ' ' || c == '\t').len()
+}
+
+/// Text of `span` in `text`,
+/// where the indent of the first line is removed from the following lines
+///
+/// Only ASCII spaces and tabs are regarded as indent.
+fn definition(text: &str, span: Span) -> String {
+    let range = span.range(text);
+    let line_start = text[..range.start].rfind('\n').map_or(0, |i| i + 1);
+    let indent = &text[line_start..range.start];
+    let indent = if indent_len(indent) == indent.len() {
+        indent.len()
+    } else {
+        0
+    };
+    text[range]
+        .lines()
+        .enumerate()
+        .map(|(i, line)| {
+            if i == 0 {
+                return line;
+            }
+            &line[indent_len(line).min(indent)..]
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+impl Schema {
+    /// Attach [Documentation] and remarks of attributes in `st` where this schema is legalized from
+    pub(crate) fn document(&mut self, st: &SyntaxTree) {
+        let Schema {
+            name,
+            entities,
+            types,
+            ..
+        } = self;
+        let text = st.source(name).map(|source| source.text.as_str());
+        let schema = st.schemas.iter().find(|schema| &schema.name == name);
+        let definition = |span: Span| text.map(|text| definition(text, span));
+
+        for entity in entities {
+            let span = schema
+                .and_then(|schema| schema.entities.iter().find(|e| e.name == entity.name))
+                .map(|e| e.span);
+            entity.doc = Documentation {
+                remarks: remarks(st, name, &[&entity.name]),
+                definition: span.and_then(definition),
+            };
+            for attr in &mut entity.attributes {
+                attr.remarks = remarks(st, name, &[&entity.name, &attr.name]);
+            }
+        }
+        for ty in types {
+            let id = ty.id().to_string();
+            let span = schema
+                .and_then(|schema| schema.types.iter().find(|t| t.type_id == id))
+                .map(|t| t.span);
+            *ty.doc_mut() = Documentation {
+                remarks: remarks(st, name, &[&id]),
+                definition: span.and_then(definition),
+            };
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn document() {
+        let st = SyntaxTree::parse(
+            r#"
+            SCHEMA shapes;
+              TYPE distance = REAL; END_TYPE;
+              ENTITY circle;
+                radius : distance; -- in millimetres
+              END_ENTITY;
+            END_SCHEMA;
+            (*"circle" Circle in a plane *)
+            (*"circle.radius" Radius of the circle *)
+            "#,
+        )
+        .unwrap();
+        let ir = IR::from_syntax_tree(&st).unwrap();
+        let schema = &ir.schemas[0];
+        assert_eq!(
+            schema.types[0].doc().definition.as_deref(),
+            Some("TYPE distance = REAL; END_TYPE;")
+        );
+        let circle = &schema.entities[0];
+        assert_eq!(circle.doc.remarks, vec!["Circle in a plane"]);
+        assert_eq!(
+            circle.doc.definition.as_deref(),
+            Some("ENTITY circle;\n  radius : distance; -- in millimetres\nEND_ENTITY;")
+        );
+        assert_eq!(circle.attributes[0].remarks, vec!["Radius of the circle"]);
+    }
+
+    #[test]
+    fn definition_ideographic_space() {
+        // U+3000 is whitespace but not indent, and must not be cut at its middle byte
+        let text = "  ENTITY a;\n\u{3000}x : REAL;\n  END_ENTITY;";
+        let span = Span::new(text.len() - 2, 0);
+        assert_eq!(
+            definition(text, span),
+            "ENTITY a;\n\u{3000}x : REAL;\nEND_ENTITY;"
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use super::{namespace::*, scope::*, type_ref::*, *};
+use super::{expression::*, namespace::*, scope::*, type_ref::*, *};
 use crate::ast;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -11,6 +11,10 @@ pub struct Entity {
     /// and `SUPERTYPE OF` declaration in EXPRESS schema
     pub constraints: Vec<TypeRef>,
 
+    /// Combined `SUBTYPE_CONSTRAINT` if this entity is a supertype,
+    /// which describes instantiable complex entities ignored in [Entity::constraints]
+    pub subtype_constraint: Option<ConstraintExpr>,
+
     /// List of types to be inherited by this entity
     ///
     /// When this entity is `sub` defined like:
@@ -23,6 +27,14 @@ pub struct Entity {
     /// then this `supertypes` is `[base]`.
     ///
     pub supertypes: Vec<TypeRef>,
+
+    /// Attributes declared in `DERIVE` clause
+    pub derived: Vec<DerivedAttribute>,
+
+    /// Attributes declared in `INVERSE` clause
+    pub inverse: Vec<InverseAttribute>,
+
+    pub doc: Documentation,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -30,6 +42,228 @@ pub struct EntityAttribute {
     pub name: String,
     pub ty: TypeRef,
     pub optional: bool,
+
+    /// True if a subtype redeclares this attribute as a derived attribute,
+    /// e.g. `SELF\base.x : REAL := 1.0;` in `DERIVE` clause of subtype.
+    ///
+    /// The value of such attribute is encoded as `*` in exchange structure
+    /// when the instance is of the subtype.
+    pub redeclared_as_derived: bool,
+
+    /// Text of remarks tagged to this attribute
+    pub remarks: Vec<String>,
+}
+
+/// Attribute declared in `DERIVE` clause
+///
+/// ```text
+/// ENTITY circle;
+///   centre : point;
+///   radius : REAL;
+/// DERIVE
+///   area : REAL := PI*radius**2;
+/// END_ENTITY;
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DerivedAttribute {
+    pub name: String,
+    pub ty: TypeRef,
+    pub expr: Expression,
+
+    /// Name of supertype if this redeclares an explicit attribute of supertype,
+    /// e.g. `named_unit` for `SELF\named_unit.dimensions`
+    pub redeclared: Option<String>,
+
+    /// Types underlying `ty` down to a simple type if `ty` is a named type of a simple type,
+    /// e.g. `[a, REAL]` for `b` declared by `TYPE b = a; END_TYPE;` and `TYPE a = REAL; END_TYPE;`.
+    /// Empty otherwise.
+    pub underlying: Vec<TypeRef>,
+}
+
+impl DerivedAttribute {
+    fn legalize(
+        ns: &Namespace,
+        ss: &Constraints,
+        scope: &Scope,
+        entity: &Path,
+        attr: &ast::DerivedAttribute,
+    ) -> Result<Self, SemanticError> {
+        let (name, redeclared) = match &attr.attr {
+            ast::AttributeDecl::Reference(name) => (name.clone(), None),
+            ast::AttributeDecl::Qualified {
+                group,
+                attribute,
+                rename,
+            } => (
+                rename.as_ref().unwrap_or(attribute).clone(),
+                Some(group.clone()),
+            ),
+        };
+        Ok(DerivedAttribute {
+            name,
+            ty: TypeRef::legalize(ns, ss, scope, &attr.ty)?,
+            expr: Expression::legalize_in_entity(ns, ss, entity, &attr.expr)?,
+            redeclared,
+            underlying: underlying_simple_type(ns, ss, scope, &attr.ty)?,
+        })
+    }
+}
+
+/// Follow named types declared as other named types until a simple type
+fn underlying_simple_type(
+    ns: &Namespace,
+    ss: &Constraints,
+    scope: &Scope,
+    ty: &ast::Type,
+) -> Result<Vec<TypeRef>, SemanticError> {
+    let mut underlying = Vec::new();
+    let mut path = match ty {
+        ast::Type::Named(name) => ns.resolve(scope, name)?.0,
+        _ => return Ok(underlying),
+    };
+    loop {
+        match ns.get(&path)?.0 {
+            Named::Type(ast::TypeDecl {
+                underlying_type: ast::Type::Named(name),
+                ..
+            }) => {
+                path = ns.resolve(&path.scope, name)?.0;
+                underlying.push(TypeRef::from_path(ns, ss, &path)?);
+            }
+            Named::Type(ast::TypeDecl {
+                underlying_type: ty @ ast::Type::Simple(_),
+                ..
+            }) => {
+                underlying.push(TypeRef::legalize(ns, ss, &path.scope, ty)?);
+                return Ok(underlying);
+            }
+            _ => return Ok(Vec::new()),
+        }
+    }
+}
+
+/// Attribute declared in `INVERSE` clause
+///
+/// ```text
+/// ENTITY face;
+/// INVERSE
+///   of_shell : SET [1:?] OF closed_shell FOR cfs_faces;
+/// END_ENTITY;
+/// ```
+///
+/// The inverse attribute is not stored in the instance,
+/// but is determined by the entities referring `SELF` through the attribute `cfs_faces`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InverseAttribute {
+    pub name: String,
+
+    /// The attribute of `dest` entity which refers `SELF`
+    pub attribute: String,
+
+    /// Entities which may refer `SELF`, i.e. `dest` entity and its subtypes,
+    /// with supertype names to go through to the entity declaring `attribute`
+    pub referrers: Vec<(TypeRef, Vec<String>)>,
+
+    /// Lower bound of the number of entities referring `SELF`
+    pub lower: u64,
+
+    /// Upper bound of the number of entities referring `SELF`, `None` for `?`
+    pub upper: Option<u64>,
+}
+
+/// Supertype names to go through from `sub` entity to `sup` entity
+fn supertype_chain(
+    ns: &Namespace,
+    sub: &Path,
+    sup: &Path,
+) -> Result<Option<Vec<String>>, SemanticError> {
+    if sub == sup {
+        return Ok(Some(Vec::new()));
+    }
+    let entity = match ns.get(sub)?.0 {
+        Named::Entity(entity) => entity,
+        Named::Type(_) => return Err(SemanticError::InvalidPath(sub.clone())),
+    };
+    if let Some(subtype_of) = &entity.subtype_of {
+        for supertype in &subtype_of.entity_references {
+            let (path, _index) = ns.resolve(&sub.scope, supertype)?;
+            if let Some(mut chain) = supertype_chain(ns, &path, sup)? {
+                chain.insert(0, path.name.clone());
+                return Ok(Some(chain));
+            }
+        }
+    }
+    Ok(None)
+}
+
+impl Legalize for InverseAttribute {
+    type Input = ast::InverseAttribute;
+
+    fn legalize(
+        ns: &Namespace,
+        ss: &Constraints,
+        scope: &Scope,
+        attr: &Self::Input,
+    ) -> Result<Self, SemanticError> {
+        let name = match &attr.name {
+            ast::AttributeDecl::Reference(name) => name.clone(),
+            ast::AttributeDecl::Qualified {
+                attribute, rename, ..
+            } => rename.as_ref().unwrap_or(attribute).clone(),
+        };
+
+        let (dest, _index) = ns.resolve(scope, &attr.dest)?;
+        let declared = match lookup_attribute(ns, &dest, &attr.attribute)? {
+            Some((_supertypes, declared, FoundAttribute::Explicit(_))) => declared,
+            _ => {
+                return Err(SemanticError::AttributeNotFound {
+                    name: attr.attribute.clone(),
+                    entity: dest,
+                })
+            }
+        };
+
+        // Components of complex entities are also candidates,
+        // since the component declaring or inheriting the attribute refers the entity
+        let mut candidates = vec![dest.clone()];
+        if let Some(instantiables) = ss.instantiables.get(&dest) {
+            for path in instantiables.iter().flatten() {
+                if !candidates.contains(path) {
+                    candidates.push(path.clone());
+                }
+            }
+        }
+        let mut referrers = Vec::new();
+        for path in &candidates {
+            if let Some(chain) = supertype_chain(ns, path, &declared)? {
+                referrers.push((TypeRef::from_path(ns, ss, path)?, chain));
+            }
+        }
+
+        let (lower, upper) = match &attr.dest_aggregation {
+            ast::AggregationOption::None => (1, Some(1)),
+            ast::AggregationOption::Set { bound } | ast::AggregationOption::Bag { bound } => {
+                match bound {
+                    Some(bound) => {
+                        let bound = Bound::legalize(ns, ss, scope, bound)?;
+                        (
+                            bound.lower_value().unwrap_or(0).max(0) as u64,
+                            bound.upper_value().map(|upper| upper.max(0) as u64),
+                        )
+                    }
+                    None => (0, None),
+                }
+            }
+        };
+
+        Ok(InverseAttribute {
+            name,
+            attribute: attr.attribute.clone(),
+            referrers,
+            lower,
+            upper,
+        })
+    }
 }
 
 impl Legalize for EntityAttribute {
@@ -50,6 +284,10 @@ impl Legalize for EntityAttribute {
             name,
             ty,
             optional: attr.optional,
+            // This will be set by Entity::legalize since it requires the entity path
+            redeclared_as_derived: false,
+            // This will be set by Schema::document since it requires the syntax tree
+            remarks: Vec::new(),
         })
     }
 }
@@ -64,11 +302,17 @@ impl Legalize for Entity {
         entity: &ast::Entity,
     ) -> Result<Self, SemanticError> {
         let name = entity.name.clone();
+        let path = Path::entity(scope, &entity.name);
         let attributes = entity
             .attributes
             .iter()
-            .map(|attr| EntityAttribute::legalize(ns, ss, scope, attr))
-            .collect::<Result<Vec<_>, _>>()?;
+            .map(|ast_attr| {
+                let mut attr = EntityAttribute::legalize(ns, ss, scope, ast_attr)
+                    .map_err(|err| err.located(scope, ast_attr.span))?;
+                attr.redeclared_as_derived = ss.is_redeclared_as_derived(&path, &attr.name);
+                Ok(attr)
+            })
+            .collect::<Result<Vec<_>, SemanticError>>()?;
 
         let supertypes = if let Some(supertypes) = &entity.subtype_of {
             supertypes
@@ -80,7 +324,6 @@ impl Legalize for Entity {
             Vec::new()
         };
 
-        let path = Path::entity(scope, &entity.name);
         let constraints = if let Some(instantiables) = ss.instantiables.get(&path) {
             instantiables
                 .iter()
@@ -93,12 +336,44 @@ impl Legalize for Entity {
         } else {
             Vec::new()
         };
+        let subtype_constraint = ss.exprs.get(&path).cloned();
+
+        let derived = if let Some(derive) = &entity.derive_clause {
+            derive
+                .attributes
+                .iter()
+                .map(|attr| {
+                    DerivedAttribute::legalize(ns, ss, scope, &path, attr)
+                        .map_err(|err| err.located(scope, attr.span))
+                })
+                .collect::<Result<Vec<_>, _>>()?
+        } else {
+            Vec::new()
+        };
+
+        let inverse = if let Some(inverse) = &entity.inverse_clause {
+            inverse
+                .attributes
+                .iter()
+                .map(|attr| {
+                    InverseAttribute::legalize(ns, ss, scope, attr)
+                        .map_err(|err| err.located(scope, attr.span))
+                })
+                .collect::<Result<Vec<_>, _>>()?
+        } else {
+            Vec::new()
+        };
 
         Ok(Entity {
             name,
             attributes,
             constraints,
+            subtype_constraint,
             supertypes,
+            derived,
+            inverse,
+            // This will be set by Schema::document since it requires the syntax tree
+            doc: Documentation::default(),
         })
     }
 }
@@ -110,7 +385,7 @@ mod tests {
     #[test]
     fn legalize() {
         let example = SyntaxTree::example();
-        let ns = Namespace::new(&example);
+        let ns = Namespace::new(&example).unwrap();
         let ss = Constraints::new(&ns, &example).unwrap();
         dbg!(&ns);
         let entity = &example.schemas[0].entities[0];
@@ -118,4 +393,44 @@ mod tests {
         let entity = Entity::legalize(&ns, &ss, &scope, entity).unwrap();
         dbg!(&entity);
     }
+    #[test]
+    fn complex_referrers() {
+        let st = SyntaxTree::parse(
+            r#"
+            SCHEMA s;
+              ENTITY target;
+              INVERSE
+                users: SET [0:?] OF user FOR t;
+              END_ENTITY;
+              ENTITY user ABSTRACT SUPERTYPE OF (left AND right);
+                t: target;
+              END_ENTITY;
+              ENTITY left SUBTYPE OF (user);
+              END_ENTITY;
+              ENTITY right SUBTYPE OF (user);
+              END_ENTITY;
+            END_SCHEMA;
+            "#,
+        )
+        .unwrap();
+        let ir = IR::from_syntax_tree(&st).unwrap();
+        let target = &ir.schemas[0].entities[0];
+        // `left` and `right` are instantiable only as a complex entity
+        let referrers: Vec<_> = target.inverse[0]
+            .referrers
+            .iter()
+            .map(|(ty, chain)| match ty {
+                TypeRef::Entity { name, .. } => (name.as_str(), chain.clone()),
+                _ => unreachable!(),
+            })
+            .collect();
+        assert_eq!(
+            referrers,
+            [
+                ("user", vec![]),
+                ("left", vec!["user".to_string()]),
+                ("right", vec!["user".to_string()]),
+            ]
+        );
+    }
 }
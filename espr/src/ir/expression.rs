@@ -0,0 +1,354 @@
+use super::{namespace::*, scope::*, type_ref::*, *};
+use crate::ast;
+
+use std::hash::{Hash, Hasher};
+
+/// Literal value in legalized [Expression]
+#[derive(Debug, Clone, PartialEq)]
+pub enum Literal {
+    Real(f64),
+    String(String),
+    Logical(ast::Logical),
+}
+
+// Literal does not contain NaN since it is parsed from EXPRESS
+impl Eq for Literal {}
+
+impl Hash for Literal {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Literal::Real(value) => value.to_bits().hash(state),
+            Literal::String(value) => value.hash(state),
+            Literal::Logical(value) => (value.clone() as u8).hash(state),
+        }
+    }
+}
+
+impl From<&ast::Literal> for Literal {
+    fn from(literal: &ast::Literal) -> Self {
+        match literal {
+            ast::Literal::Real(value) => Literal::Real(*value),
+            ast::Literal::String(value) => Literal::String(value.clone()),
+            ast::Literal::Logial(value) => Literal::Logical(value.clone()),
+        }
+    }
+}
+
+/// Legalized expression appears in `DERIVE` clause
+///
+/// Only the portion of EXPRESS expressions which can be evaluated locally,
+/// i.e. using attributes of `SELF` and built-in functions, are legalized.
+/// Others, e.g. user-defined function calls or queries,
+/// are kept as [Expression::Unsupported].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Expression {
+    Literal(Literal),
+
+    /// Explicit attribute of `SELF`, e.g. `SELF.x`, `SELF\base.x` or `x`
+    Attribute {
+        /// Supertype names to go through from `SELF` to the entity declaring the attribute
+        supertypes: Vec<String>,
+        name: String,
+        ty: TypeRef,
+        optional: bool,
+    },
+
+    /// Derived attribute of `SELF`
+    Derived {
+        supertypes: Vec<String>,
+        name: String,
+        ty: TypeRef,
+    },
+
+    Unary {
+        op: ast::UnaryOperator,
+        arg: Box<Expression>,
+    },
+    Binary {
+        op: ast::BinaryOperator,
+        arg1: Box<Expression>,
+        arg2: Box<Expression>,
+    },
+    Relation {
+        op: ast::RelationOperator,
+        lhs: Box<Expression>,
+        rhs: Box<Expression>,
+    },
+    BuiltInFunction {
+        name: ast::BuiltInFunction,
+        args: Vec<Expression>,
+    },
+
+    /// Expression cannot be legalized yet
+    Unsupported,
+}
+
+/// Attribute of an entity found by [lookup_attribute]
+pub(super) enum FoundAttribute<'st> {
+    Explicit(&'st ast::EntityAttribute),
+    Derived(&'st ast::DerivedAttribute),
+}
+
+/// Look up an attribute of the entity specified by `path` and its supertypes
+///
+/// Returns the supertype names to go through, and the path of the entity declaring the attribute.
+pub(super) fn lookup_attribute<'st>(
+    ns: &'st Namespace,
+    path: &Path,
+    name: &str,
+) -> Result<Option<(Vec<String>, Path, FoundAttribute<'st>)>, SemanticError> {
+    let entity = match ns.get(path)?.0 {
+        Named::Entity(entity) => entity,
+        Named::Type(_) => return Err(SemanticError::InvalidPath(path.clone())),
+    };
+    for attr in &entity.attributes {
+        if attr.name == name {
+            return Ok(Some((
+                Vec::new(),
+                path.clone(),
+                FoundAttribute::Explicit(attr),
+            )));
+        }
+    }
+    if let Some(derive) = &entity.derive_clause {
+        for attr in &derive.attributes {
+            if attr.attr == name {
+                return Ok(Some((
+                    Vec::new(),
+                    path.clone(),
+                    FoundAttribute::Derived(attr),
+                )));
+            }
+        }
+    }
+    if let Some(subtype_of) = &entity.subtype_of {
+        for supertype in &subtype_of.entity_references {
+            let (sup, _index) = ns.resolve(&path.scope, supertype)?;
+            if let Some((mut supertypes, declared, found)) = lookup_attribute(ns, &sup, name)? {
+                supertypes.insert(0, sup.name.clone());
+                return Ok(Some((supertypes, declared, found)));
+            }
+        }
+    }
+    Ok(None)
+}
+
+impl Expression {
+    fn attribute(
+        ns: &Namespace,
+        ss: &Constraints,
+        entity: &Path,
+        name: &str,
+    ) -> Result<Self, SemanticError> {
+        Ok(match lookup_attribute(ns, entity, name)? {
+            Some((supertypes, declared, FoundAttribute::Explicit(attr))) => Expression::Attribute {
+                supertypes,
+                name: name.to_string(),
+                ty: TypeRef::legalize(ns, ss, &declared.scope, &attr.ty)?,
+                // Redeclared attribute is stored as `Option<T>`
+                optional: attr.optional || ss.is_redeclared_as_derived(&declared, name),
+            },
+            Some((supertypes, declared, FoundAttribute::Derived(attr))) => Expression::Derived {
+                supertypes,
+                name: name.to_string(),
+                ty: TypeRef::legalize(ns, ss, &declared.scope, &attr.ty)?,
+            },
+            _ => Expression::Unsupported,
+        })
+    }
+
+    /// Legalize an expression appears in the entity specified by `entity` path
+    pub fn legalize_in_entity(
+        ns: &Namespace,
+        ss: &Constraints,
+        entity: &Path,
+        expr: &ast::Expression,
+    ) -> Result<Self, SemanticError> {
+        use ast::{BuiltInConstant, QualifiableFactor, Qualifier};
+        let legalize = |e: &ast::Expression| Self::legalize_in_entity(ns, ss, entity, e);
+        Ok(match expr {
+            ast::Expression::Literal { literal, .. } => Expression::Literal(literal.into()),
+            ast::Expression::Unary { op, arg, .. } => Expression::Unary {
+                op: *op,
+                arg: Box::new(legalize(arg)?),
+            },
+            ast::Expression::Binary { op, arg1, arg2, .. } => Expression::Binary {
+                op: *op,
+                arg1: Box::new(legalize(arg1)?),
+                arg2: Box::new(legalize(arg2)?),
+            },
+            ast::Expression::Relation { op, lhs, rhs, .. } => Expression::Relation {
+                op: *op,
+                lhs: Box::new(legalize(lhs)?),
+                rhs: Box::new(legalize(rhs)?),
+            },
+            ast::Expression::QualifiableFactor {
+                factor, qualifiers, ..
+            } => {
+                match (factor, qualifiers.as_slice()) {
+                    (QualifiableFactor::Reference(name), []) => {
+                        Self::attribute(ns, ss, entity, name)?
+                    }
+                    (
+                        QualifiableFactor::BuiltInConstant(BuiltInConstant::Self_),
+                        [Qualifier::Attribute(name)],
+                    ) => Self::attribute(ns, ss, entity, name)?,
+                    // Group qualifier is only required to avoid ambiguity,
+                    // and the attribute will be looked up through supertypes
+                    (
+                        QualifiableFactor::BuiltInConstant(BuiltInConstant::Self_),
+                        [Qualifier::Group(_group), Qualifier::Attribute(name)],
+                    ) => Self::attribute(ns, ss, entity, name)?,
+                    (QualifiableFactor::BuiltInConstant(BuiltInConstant::Pi), []) => {
+                        Expression::Literal(Literal::Real(std::f64::consts::PI))
+                    }
+                    (QualifiableFactor::BuiltInConstant(BuiltInConstant::Napier), []) => {
+                        Expression::Literal(Literal::Real(std::f64::consts::E))
+                    }
+                    (
+                        QualifiableFactor::FunctionCall {
+                            name: ast::FunctionCallName::BuiltInFunction(name),
+                            args,
+                        },
+                        [],
+                    ) => Expression::BuiltInFunction {
+                        name: name.clone(),
+                        args: args.iter().map(legalize).collect::<Result<_, _>>()?,
+                    },
+                    _ => Expression::Unsupported,
+                }
+            }
+            _ => Expression::Unsupported,
+        })
+    }
+
+    /// Legalize an expression which does not depend on any entity, e.g. bounds of aggregate types
+    ///
+    /// Only literals and arithmetic operations of them are legalized.
+    pub fn legalize_constant(expr: &ast::Expression) -> Self {
+        match expr {
+            ast::Expression::Literal { literal, .. } => Expression::Literal(literal.into()),
+            ast::Expression::Unary { op, arg, .. } => Expression::Unary {
+                op: *op,
+                arg: Box::new(Self::legalize_constant(arg)),
+            },
+            ast::Expression::Binary { op, arg1, arg2, .. } => Expression::Binary {
+                op: *op,
+                arg1: Box::new(Self::legalize_constant(arg1)),
+                arg2: Box::new(Self::legalize_constant(arg2)),
+            },
+            _ => Expression::Unsupported,
+        }
+    }
+
+    /// Evaluate as an integer constant if possible
+    ///
+    /// Returns `Ok(None)` if `self` is not a constant integer expression,
+    /// and [SemanticError::IntegerOverflow] if the evaluation overflows.
+    pub fn eval_integer(&self) -> Result<Option<i64>, SemanticError> {
+        use ast::{BinaryOperator::*, UnaryOperator::*};
+        let value = match self {
+            Expression::Literal(Literal::Real(value)) if value.fract() == 0.0 => {
+                if value.abs() >= i64::MAX as f64 {
+                    return Err(SemanticError::IntegerOverflow);
+                }
+                Some(*value as i64)
+            }
+            Expression::Unary { op: Plus, arg } => arg.eval_integer()?,
+            Expression::Unary { op: Minus, arg } => match arg.eval_integer()? {
+                Some(value) => Some(value.checked_neg().ok_or(SemanticError::IntegerOverflow)?),
+                None => None,
+            },
+            Expression::Binary { op, arg1, arg2 } => {
+                let (a, b) = match (arg1.eval_integer()?, arg2.eval_integer()?) {
+                    (Some(a), Some(b)) => (a, b),
+                    _ => return Ok(None),
+                };
+                let value = match op {
+                    Add => a.checked_add(b),
+                    Sub => a.checked_sub(b),
+                    Mul => a.checked_mul(b),
+                    IntegerDiv if b != 0 => a.checked_div(b),
+                    _ => return Ok(None),
+                };
+                Some(value.ok_or(SemanticError::IntegerOverflow)?)
+            }
+            _ => None,
+        };
+        Ok(value)
+    }
+
+    /// Returns `false` if `self` contains [Expression::Unsupported]
+    pub fn is_supported(&self) -> bool {
+        match self {
+            Expression::Unsupported => false,
+            Expression::Literal(_) | Expression::Attribute { .. } | Expression::Derived { .. } => {
+                true
+            }
+            Expression::Unary { arg, .. } => arg.is_supported(),
+            Expression::Binary { arg1, arg2, .. } => arg1.is_supported() && arg2.is_supported(),
+            Expression::Relation { lhs, rhs, .. } => lhs.is_supported() && rhs.is_supported(),
+            Expression::BuiltInFunction { args, .. } => args.iter().all(|arg| arg.is_supported()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn legalize_derived(input: &str) -> Vec<Expression> {
+        let st = ast::SyntaxTree::parse(input).unwrap();
+        let ns = Namespace::new(&st).unwrap();
+        let ss = Constraints::new(&ns, &st).unwrap();
+        let schema = &st.schemas[0];
+        let scope = Scope::root().schema(&schema.name);
+        let entity = schema.entities.last().unwrap();
+        let path = Path::entity(&scope, &entity.name);
+        entity
+            .derive_clause
+            .as_ref()
+            .unwrap()
+            .attributes
+            .iter()
+            .map(|attr| Expression::legalize_in_entity(&ns, &ss, &path, &attr.expr).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn self_attribute() {
+        let exprs = legalize_derived(
+            r#"
+            SCHEMA test_schema;
+              ENTITY base;
+                x: REAL;
+              END_ENTITY;
+              ENTITY sub SUBTYPE OF (base);
+                y: REAL;
+              DERIVE
+                a: REAL := SELF.y;
+                b: REAL := x;
+                c: REAL := SELF\base.x;
+                d: REAL := f(x);
+              END_ENTITY;
+            END_SCHEMA;
+            "#,
+        );
+        let y = Expression::Attribute {
+            supertypes: Vec::new(),
+            name: "y".to_string(),
+            ty: TypeRef::SimpleType(SimpleType(ast::SimpleType::Real)),
+            optional: false,
+        };
+        let x = Expression::Attribute {
+            supertypes: vec!["base".to_string()],
+            name: "x".to_string(),
+            ty: TypeRef::SimpleType(SimpleType(ast::SimpleType::Real)),
+            optional: false,
+        };
+        assert_eq!(exprs[0], y);
+        assert_eq!(exprs[1], x);
+        assert_eq!(exprs[2], x);
+        assert_eq!(exprs[3], Expression::Unsupported);
+    }
+}
@@ -167,23 +167,29 @@
 
 mod complex_entity;
 mod constraints;
+mod documentation;
 mod entity;
+mod expression;
 mod namespace;
 mod schema;
 mod scope;
+mod subset;
 mod type_decl;
 mod type_ref;
 
 pub use complex_entity::*;
 pub use constraints::*;
+pub use documentation::*;
 pub use entity::*;
+pub use expression::*;
 pub use namespace::*;
 pub use schema::*;
 pub use scope::*;
+pub use subset::*;
 pub use type_decl::*;
 pub use type_ref::*;
 
-use crate::ast::SyntaxTree;
+use crate::ast::{self, SyntaxTree};
 use thiserror::Error;
 
 /// Semantic errors
@@ -197,6 +203,78 @@ pub enum SemanticError {
 
     #[error("Same item ({0}) is declared multiple times")]
     DuplicatedDeclaration(Path),
+
+    #[error("Not found the attribute {name} of entity {entity}")]
+    AttributeNotFound { name: String, entity: Path },
+
+    #[error("BASED_ON chain of {0} is cyclic")]
+    CyclicBasedOn(Path),
+
+    #[error(transparent)]
+    Interface(#[from] ast::InterfaceError),
+
+    #[error("EXTENSIBLE GENERIC_ENTITY SELECT {0} is not extended by any type")]
+    EmptyGenericEntitySelect(Path),
+
+    #[error("{0} is not supported yet")]
+    NotSupportedYet(String),
+
+    #[error("Constant integer expression overflows")]
+    IntegerOverflow,
+
+    /// Error occurred in the declaration at `span` in `scope`
+    #[error("{source}")]
+    Located {
+        scope: Scope,
+        span: ast::Span,
+        source: Box<SemanticError>,
+    },
+
+    /// Errors occurred in several declarations
+    #[error("{} semantic errors occurred", .0.len())]
+    Multiple(Vec<SemanticError>),
+}
+
+impl SemanticError {
+    /// Locate this error at the declaration of `span` in `scope`
+    ///
+    /// Errors already located are kept as is, i.e. the innermost declaration is used.
+    pub fn located(self, scope: &Scope, span: ast::Span) -> Self {
+        match self {
+            SemanticError::Located { .. } => self,
+            SemanticError::Multiple(errors) => SemanticError::Multiple(
+                errors
+                    .into_iter()
+                    .map(|err| err.located(scope, span))
+                    .collect(),
+            ),
+            _ => SemanticError::Located {
+                scope: scope.clone(),
+                span,
+                source: Box::new(self),
+            },
+        }
+    }
+
+    /// Merge errors into one, or returns `None` if empty
+    pub fn merge(errors: Vec<SemanticError>) -> Option<Self> {
+        let mut errors: Vec<_> = errors.into_iter().flat_map(|err| err.errors()).collect();
+        match errors.len() {
+            0 => None,
+            1 => errors.pop(),
+            _ => Some(SemanticError::Multiple(errors)),
+        }
+    }
+
+    /// Flatten [SemanticError::Multiple] into each errors
+    pub fn errors(self) -> Vec<SemanticError> {
+        match self {
+            SemanticError::Multiple(errors) => {
+                errors.into_iter().flat_map(|err| err.errors()).collect()
+            }
+            _ => vec![self],
+        }
+    }
 }
 
 /// Legalize partial AST input into corresponding intermediate representation
@@ -220,7 +298,7 @@ pub struct IR {
 
 impl IR {
     pub fn from_syntax_tree(st: &SyntaxTree) -> Result<Self, SemanticError> {
-        let ns = Namespace::new(st);
+        let ns = Namespace::new(st)?;
         let ss = Constraints::new(&ns, st)?;
         let ir = Self::legalize(&ns, &ss, &Scope::root(), st)?;
         Ok(ir)
@@ -235,11 +313,21 @@ impl Legalize for IR {
         scope: &Scope,
         syn: &SyntaxTree,
     ) -> Result<Self, SemanticError> {
+        let mut errors = Vec::new();
         let schemas = syn
             .schemas
             .iter()
-            .map(|schema| Schema::legalize(ns, ss, scope, schema))
-            .collect::<Result<Vec<Schema>, SemanticError>>()?;
+            .filter_map(|schema| {
+                let mut schema = Schema::legalize(ns, ss, scope, schema)
+                    .map_err(|err| errors.push(err))
+                    .ok()?;
+                schema.document(syn);
+                Some(schema)
+            })
+            .collect();
+        if let Some(err) = SemanticError::merge(errors) {
+            return Err(err);
+        }
         Ok(IR { schemas })
     }
 }
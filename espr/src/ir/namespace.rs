@@ -1,5 +1,9 @@
 use super::{scope::*, SemanticError};
-use crate::ast::{self, SyntaxTree};
+use crate::ast::{
+    self,
+    interface::{self, Kind},
+    SyntaxTree,
+};
 
 use std::collections::HashMap;
 
@@ -18,9 +22,18 @@ pub enum Named<'st> {
 /// - Resolving name in each [Scope] into [Path]
 /// - Get a reference to AST portion corresponding to [Path]
 ///
+/// Names interfaced by `USE FROM` and `REFERENCE FROM` (ISO 10303-11 §11)
+/// are resolved into the [Path] where they are declared.
+/// Items referred in the declaration of interfaced items,
+/// e.g. the attribute types of an interfaced entity,
+/// are implicitly interfaced since they are resolved in the scope of the declaration.
+/// Interfaces are resolved by the same rules as [SyntaxTree::long_form].
+///
 #[derive(Debug, Clone)]
 pub struct Namespace<'st> {
     pub names: HashMap<Scope, Vec<(ScopeType, String, usize)>>,
+    /// Names interfaced into each schema, possibly renamed by `AS`
+    pub interfaces: HashMap<Scope, Vec<(ScopeType, String, usize)>>,
     /// Indexed AST portion
     pub ast: Vec<(Path, Named<'st>)>,
 }
@@ -40,32 +53,63 @@ impl<'st> std::ops::Index<&Scope> for Namespace<'st> {
 }
 
 impl<'st> Namespace<'st> {
-    pub fn new(st: &'st SyntaxTree) -> Self {
+    /// Index the declarations in `st`, and resolve the interfaces between schemas
+    ///
+    /// Error
+    /// ------
+    /// - [SemanticError::Interface] if an interfaced schema or resource is not found
+    ///
+    pub fn new(st: &'st SyntaxTree) -> Result<Self, SemanticError> {
         let mut names = HashMap::new();
         let mut ast = Vec::new();
+        // Index in `ast` of each declaration identified as in [interface::visible]
+        let mut indices = HashMap::new();
         let root = Scope::root();
 
-        for schema in &st.schemas {
+        for (i, schema) in st.schemas.iter().enumerate() {
             let here = root.pushed(ScopeType::Schema, &schema.name);
             let mut current_names = Vec::new();
-            for ty in &schema.types {
+            for (j, ty) in schema.types.iter().enumerate() {
                 let name = &ty.type_id;
                 let path = Path::new(&here, ScopeType::Type, name);
                 let index = ast.len();
                 ast.push((path, Named::Type(ty)));
+                indices.insert((i, Kind::Type, j), (ScopeType::Type, index));
                 current_names.push((ScopeType::Type, name.to_string(), index));
             }
-            for entity in &schema.entities {
+            for (j, entity) in schema.entities.iter().enumerate() {
                 let name = &entity.name;
                 let path = Path::new(&here, ScopeType::Entity, name);
                 let index = ast.len();
                 ast.push((path, Named::Entity(entity)));
+                indices.insert((i, Kind::Entity, j), (ScopeType::Entity, index));
                 current_names.push((ScopeType::Entity, name.to_string(), index));
             }
             names.insert(here, current_names);
         }
 
-        Namespace { names, ast }
+        // Only types and entities are indexed, and other interfaced names are not resolved here
+        let mut interfaces = HashMap::new();
+        for (i, visible) in interface::visible(st)?.into_iter().enumerate() {
+            let mut interfaced: Vec<_> = visible
+                .into_iter()
+                .filter(|(_name, item)| item.0 != i)
+                .filter_map(|(name, item)| {
+                    let (ty, index) = indices.get(&item)?;
+                    Some((*ty, name, *index))
+                })
+                .collect();
+            if !interfaced.is_empty() {
+                interfaced.sort_by(|a, b| a.1.cmp(&b.1));
+                interfaces.insert(root.schema(&st.schemas[i].name), interfaced);
+            }
+        }
+
+        Ok(Namespace {
+            names,
+            interfaces,
+            ast,
+        })
     }
 
     pub fn is_empty(&self) -> bool {
@@ -84,6 +128,10 @@ impl<'st> Namespace<'st> {
     /// - If no corresponding definition found.
     ///
     pub fn resolve(&self, scope: &Scope, name: &str) -> Result<(Path, usize), SemanticError> {
+        let not_found = || SemanticError::TypeNotFound {
+            scope: scope.clone(),
+            name: name.to_string(),
+        };
         let mut scope = scope.clone();
         loop {
             if let Some(names) = self.names.get(&scope) {
@@ -93,10 +141,14 @@ impl<'st> Namespace<'st> {
                     }
                 }
             }
-            scope = scope.popped().ok_or_else(|| SemanticError::TypeNotFound {
-                scope: scope.clone(),
-                name: name.to_string(),
-            })?;
+            if let Some(names) = self.interfaces.get(&scope) {
+                for (_ty, n, index) in names {
+                    if name == n {
+                        return Ok((self.ast[*index].0.clone(), *index));
+                    }
+                }
+            }
+            scope = scope.popped().ok_or_else(not_found)?;
         }
     }
 
@@ -143,7 +195,7 @@ mod tests {
             .trim(),
         )
         .unwrap();
-        let ns = Namespace::new(&st);
+        let ns = Namespace::new(&st).unwrap();
 
         assert_eq!(ns.names.len(), 2);
         let root = Scope::root();
@@ -157,6 +209,107 @@ mod tests {
         }
     }
 
+    #[test]
+    fn resolve_interface() {
+        let st = SyntaxTree::parse(
+            r#"
+            SCHEMA geometry;
+              TYPE distance = REAL;
+              END_TYPE;
+              ENTITY point;
+                x, y: distance;
+              END_ENTITY;
+              ENTITY curve;
+                points: LIST [2:?] OF point;
+              END_ENTITY;
+            END_SCHEMA;
+
+            SCHEMA topology;
+              USE FROM geometry (point AS vertex_point);
+              REFERENCE FROM geometry (curve);
+              ENTITY edge;
+                head, tail: vertex_point;
+              END_ENTITY;
+            END_SCHEMA;
+
+            SCHEMA shape;
+              USE FROM topology;
+            END_SCHEMA;
+            "#
+            .trim(),
+        )
+        .unwrap();
+        let ns = Namespace::new(&st).unwrap();
+        let root = Scope::root();
+        let geometry = root.schema("geometry");
+        let topology = root.schema("topology");
+        let shape = root.schema("shape");
+
+        // Renamed by `AS`
+        let (path, _) = ns.resolve(&topology, "vertex_point").unwrap();
+        assert_eq!(path, Path::entity(&geometry, "point"));
+        assert!(ns.resolve(&topology, "point").is_err());
+
+        let (path, _) = ns.resolve(&topology, "curve").unwrap();
+        assert_eq!(path, Path::entity(&geometry, "curve"));
+
+        // Implicitly interfaced items can be resolved only in the scope of their declaration
+        assert!(ns.resolve(&topology, "distance").is_err());
+
+        // Interface without resource list includes interfaced items of the foreign schema
+        let (path, _) = ns.resolve(&shape, "edge").unwrap();
+        assert_eq!(path, Path::entity(&topology, "edge"));
+        let (path, _) = ns.resolve(&shape, "vertex_point").unwrap();
+        assert_eq!(path, Path::entity(&geometry, "point"));
+    }
+
+    #[test]
+    fn interface_error() {
+        let namespace = |input: &str| {
+            let st = SyntaxTree::parse(input).unwrap();
+            Namespace::new(&st).map(|_| ()).unwrap_err()
+        };
+        let geometry = r#"
+            SCHEMA geometry;
+              CONSTANT origin : REAL := 0.0; END_CONSTANT;
+              ENTITY point; x: REAL; END_ENTITY;
+            END_SCHEMA;
+        "#;
+
+        let err = namespace(&format!(
+            "{} SCHEMA topology; REFERENCE FROM geometry (pont); END_SCHEMA;",
+            geometry
+        ));
+        assert_eq!(
+            err.to_string(),
+            "pont interfaced into topology is not visible in schema geometry"
+        );
+
+        // `USE FROM` without resource list interfaces only entities and types
+        let st = SyntaxTree::parse(&format!(
+            "{} SCHEMA topology; USE FROM geometry; END_SCHEMA;",
+            geometry
+        ))
+        .unwrap();
+        let visible = interface::visible(&st).unwrap();
+        assert!(visible[1].contains_key("point"));
+        assert!(!visible[1].contains_key("origin"));
+
+        // while the listed ones are interfaced as `REFERENCE FROM` if not entities or types
+        let st = SyntaxTree::parse(&format!(
+            "{} SCHEMA topology; USE FROM geometry (origin); END_SCHEMA;",
+            geometry
+        ))
+        .unwrap();
+        assert!(Namespace::new(&st).is_ok());
+
+        let err = namespace("SCHEMA topology; USE FROM geometry; END_SCHEMA;");
+        assert_eq!(
+            err.to_string(),
+            "Schema geometry interfaced into topology is not found"
+        );
+    }
+
     #[test]
     fn namespace_debug() {
         let st = ast::SyntaxTree::parse(
@@ -177,7 +330,7 @@ mod tests {
             "#,
         )
         .unwrap();
-        let ns = Namespace::new(&st);
+        let ns = Namespace::new(&st).unwrap();
 
         insta::assert_snapshot!(format!("{:#?}", ns), @r###"
         Namespace {
@@ -200,6 +353,7 @@ mod tests {
                     ),
                 ],
             },
+            interfaces: {},
             ast: [
                 (
                     Scope(test_schema[Schema]).base[Entity],
@@ -215,6 +369,10 @@ mod tests {
                                         Real,
                                     ),
                                     optional: false,
+                                    span: Span {
+                                        remaining_start: 267,
+                                        remaining_end: 259,
+                                    },
                                 },
                             ],
                             constraint: Some(
@@ -236,6 +394,10 @@ mod tests {
                             inverse_clause: None,
                             unique_clause: None,
                             where_clause: None,
+                            span: Span {
+                                remaining_start: 330,
+                                remaining_end: 233,
+                            },
                         },
                     ),
                 ),
@@ -253,6 +415,10 @@ mod tests {
                                         Real,
                                     ),
                                     optional: false,
+                                    span: Span {
+                                        remaining_start: 170,
+                                        remaining_end: 161,
+                                    },
                                 },
                             ],
                             constraint: None,
@@ -267,6 +433,10 @@ mod tests {
                             inverse_clause: None,
                             unique_clause: None,
                             where_clause: None,
+                            span: Span {
+                                remaining_start: 217,
+                                remaining_end: 135,
+                            },
                         },
                     ),
                 ),
@@ -284,6 +454,10 @@ mod tests {
                                         Real,
                                     ),
                                     optional: false,
+                                    span: Span {
+                                        remaining_start: 72,
+                                        remaining_end: 63,
+                                    },
                                 },
                             ],
                             constraint: None,
@@ -298,6 +472,10 @@ mod tests {
                             inverse_clause: None,
                             unique_clause: None,
                             where_clause: None,
+                            span: Span {
+                                remaining_start: 119,
+                                remaining_end: 37,
+                            },
                         },
                     ),
                 ),
@@ -6,6 +6,9 @@ pub struct Schema {
     pub name: String,
     pub entities: Vec<Entity>,
     pub types: Vec<TypeDecl>,
+    /// True if some entities and types are removed by [IR::subset],
+    /// and then records of unknown entities are kept untyped in the generated `Tables`
+    pub subset: bool,
 }
 
 impl Legalize for Schema {
@@ -18,20 +21,34 @@ impl Legalize for Schema {
     ) -> Result<Self, SemanticError> {
         let name = schema.name.clone();
         let here = scope.pushed(ScopeType::Schema, &name);
+        // Collect errors in all declarations
+        let mut errors = Vec::new();
         let entities = schema
             .entities
             .iter()
-            .map(|entity| Entity::legalize(ns, ss, &here, entity))
-            .collect::<Result<Vec<Entity>, _>>()?;
+            .filter_map(|entity| {
+                Entity::legalize(ns, ss, &here, entity)
+                    .map_err(|err| errors.push(err.located(&here, entity.span)))
+                    .ok()
+            })
+            .collect();
         let types = schema
             .types
             .iter()
-            .map(|entity| TypeDecl::legalize(ns, ss, &here, entity))
-            .collect::<Result<Vec<TypeDecl>, _>>()?;
+            .filter_map(|ty| {
+                TypeDecl::legalize(ns, ss, &here, ty)
+                    .map_err(|err| errors.push(err.located(&here, ty.span)))
+                    .ok()
+            })
+            .collect();
+        if let Some(err) = SemanticError::merge(errors) {
+            return Err(err);
+        }
         Ok(Schema {
             name,
             entities,
             types,
+            subset: false,
         })
     }
 }
@@ -43,7 +60,7 @@ mod tests {
     #[test]
     fn legalize() {
         let example = SyntaxTree::example();
-        let ns = Namespace::new(&example);
+        let ns = Namespace::new(&example).unwrap();
         let ss = Constraints::new(&ns, &example).unwrap();
         dbg!(&ns, &ss);
         let schema = &example.schemas[0];
@@ -108,6 +108,14 @@ impl Scope {
         new
     }
 
+    /// Name of the schema which this scope belongs to
+    pub fn schema_name(&self) -> Option<&str> {
+        self.0
+            .iter()
+            .find(|(ty, _name)| *ty == ScopeType::Schema)
+            .map(|(_ty, name)| name.as_str())
+    }
+
     add_scope!(entity, Entity);
     add_scope!(alias, Alias);
     add_scope!(function, Function);
@@ -0,0 +1,200 @@
+use super::*;
+use inflector::Inflector;
+use std::collections::{HashMap, HashSet};
+use thiserror::Error;
+
+/// Error in [IR::subset]
+#[derive(Debug, Error)]
+pub enum SubsetError {
+    #[error("Root {name} is not an entity or type in the schemas")]
+    RootNotFound { name: String },
+}
+
+/// Named types and entities in `ty`
+fn referred<'a>(ty: &'a TypeRef, refs: &mut Vec<&'a TypeRef>) {
+    match ty {
+        TypeRef::SimpleType(_) => {}
+        TypeRef::Named { .. } | TypeRef::Entity { .. } => refs.push(ty),
+        TypeRef::Set { base, .. }
+        | TypeRef::Bag { base, .. }
+        | TypeRef::List { base, .. }
+        | TypeRef::Array { base, .. } => referred(base, refs),
+    }
+}
+
+impl Entity {
+    /// Entities and types which the generated code of this entity refers,
+    /// as [TypeRef::Named] or [TypeRef::Entity]
+    ///
+    /// These are types of explicit and derived attributes, supertypes, subtypes in `XxxAny` enum,
+    /// and referrers of inverse attributes whose tables are looked up.
+    pub fn dependencies(&self) -> Vec<&TypeRef> {
+        let mut refs = Vec::new();
+        for attr in &self.attributes {
+            referred(&attr.ty, &mut refs);
+        }
+        for attr in &self.derived {
+            referred(&attr.ty, &mut refs);
+        }
+        for ty in self.supertypes.iter().chain(&self.constraints) {
+            referred(ty, &mut refs);
+        }
+        for inverse in &self.inverse {
+            for (ty, _) in &inverse.referrers {
+                referred(ty, &mut refs);
+            }
+        }
+        refs
+    }
+}
+
+impl TypeDecl {
+    /// Entities and types which the generated code of this type refers,
+    /// i.e. the underlying type or select members
+    pub fn dependencies(&self) -> Vec<&TypeRef> {
+        let mut refs = Vec::new();
+        match self {
+            TypeDecl::Simple(_) | TypeDecl::Enumeration(_) => {}
+            TypeDecl::Rename(rename) => referred(&rename.ty, &mut refs),
+            TypeDecl::Select(select) => {
+                for ty in &select.types {
+                    referred(ty, &mut refs);
+                }
+            }
+        }
+        refs
+    }
+}
+
+/// Key to identify entities and types over schemas,
+/// compared in snake_case since names may be in other cases
+fn key(schema: &str, name: &str) -> (String, String) {
+    (schema.to_lowercase(), name.to_snake_case())
+}
+
+impl IR {
+    /// Keep only entities and types reachable from `roots`, and remove the others
+    ///
+    /// Reachable ones are computed as the closure over attribute types, supertypes,
+    /// select members, subtypes needed by `XxxAny` enum, and referrers of inverse attributes,
+    /// including those in other schemas by `USE FROM` or `REFERENCE FROM`.
+    /// Schemas without any reachable one are removed,
+    /// and the others are marked [Schema::subset] to keep records of removed entities untyped.
+    pub fn subset(&mut self, roots: &[&str]) -> Result<(), SubsetError> {
+        let mut dependencies = HashMap::new();
+        for schema in &self.schemas {
+            let names = schema
+                .entities
+                .iter()
+                .map(|e| (e.name.as_str(), e.dependencies()))
+                .chain(schema.types.iter().map(|t| (t.id(), t.dependencies())));
+            for (name, refs) in names {
+                let refs: Vec<_> = refs
+                    .into_iter()
+                    .filter_map(|ty| match ty {
+                        TypeRef::Named { name, scope, .. }
+                        | TypeRef::Entity { name, scope, .. } => {
+                            Some(key(scope.schema_name()?, name))
+                        }
+                        _ => None,
+                    })
+                    .collect();
+                dependencies.insert(key(&schema.name, name), refs);
+            }
+        }
+
+        // Roots are searched in all schemas
+        let mut stack = Vec::new();
+        for root in roots {
+            let len = stack.len();
+            stack.extend(
+                dependencies
+                    .keys()
+                    .filter(|(_, name)| *name == root.to_snake_case())
+                    .cloned(),
+            );
+            if stack.len() == len {
+                return Err(SubsetError::RootNotFound {
+                    name: root.to_string(),
+                });
+            }
+        }
+        let mut reachable = HashSet::new();
+        while let Some(key) = stack.pop() {
+            if let Some(next) = dependencies.get(&key) {
+                if reachable.insert(key) {
+                    stack.extend(next.iter().cloned());
+                }
+            }
+        }
+
+        for schema in &mut self.schemas {
+            let entities = schema.entities.len();
+            let types = schema.types.len();
+            let name = schema.name.clone();
+            schema
+                .entities
+                .retain(|e| reachable.contains(&key(&name, &e.name)));
+            schema
+                .types
+                .retain(|t| reachable.contains(&key(&name, t.id())));
+            if schema.entities.len() < entities || schema.types.len() < types {
+                schema.subset = true;
+            }
+        }
+        self.schemas
+            .retain(|schema| !schema.entities.is_empty() || !schema.types.is_empty());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn subset() {
+        let st = SyntaxTree::parse(
+            r#"
+            SCHEMA shapes;
+              TYPE distance = REAL; END_TYPE;
+              TYPE color = ENUMERATION OF (red, green); END_TYPE;
+              ENTITY shape SUPERTYPE OF (ONEOF (circle, square));
+                fill : color;
+              END_ENTITY;
+              ENTITY circle SUBTYPE OF (shape);
+                radius : distance;
+              END_ENTITY;
+              ENTITY square SUBTYPE OF (shape); END_ENTITY;
+              ENTITY drawing;
+                shapes : SET [1:?] OF shape;
+              END_ENTITY;
+              ENTITY point; END_ENTITY;
+            END_SCHEMA;
+            "#,
+        )
+        .unwrap();
+        let full = IR::from_syntax_tree(&st).unwrap();
+
+        // Subtypes are needed by `ShapeAny`
+        let mut ir = full.clone();
+        ir.subset(&["shape"]).unwrap();
+        let schema = &ir.schemas[0];
+        let entities: Vec<_> = schema.entities.iter().map(|e| e.name.as_str()).collect();
+        let types: Vec<_> = schema.types.iter().map(|t| t.id()).collect();
+        assert_eq!(entities, ["shape", "circle", "square"]);
+        assert_eq!(types, ["distance", "color"]);
+        assert!(schema.subset);
+
+        // All reachable
+        let mut ir = full.clone();
+        ir.subset(&["drawing", "point"]).unwrap();
+        assert_eq!(ir, full);
+
+        let mut ir = full;
+        assert!(matches!(
+            ir.subset(&["line"]),
+            Err(SubsetError::RootNotFound { name }) if name == "line"
+        ));
+    }
+}
@@ -7,6 +7,7 @@ use crate::ast;
 pub struct Simple {
     pub id: String,
     pub ty: SimpleType,
+    pub doc: Documentation,
 }
 
 /// Rename of user defined type,
@@ -15,22 +16,31 @@ pub struct Simple {
 pub struct Rename {
     pub id: String,
     pub ty: TypeRef,
+    pub doc: Documentation,
 }
 
 /// Enumeration of values,
 /// e.g. `TYPE text_path = ENUMERATION OF (up, right, down, left); END_TYPE;`
+///
+/// `items` contains every item of the `BASED_ON` chain and of the extensions of this type.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Enumeration {
     pub id: String,
+    pub extensibility: ast::Extensibility,
     pub items: Vec<String>,
+    pub doc: Documentation,
 }
 
 /// Select of user defined types,
 /// e.g. `TYPE geometric_set_select = SELECT (point, curve); END_TYPE;`
+///
+/// `types` contains every type of the `BASED_ON` chain and of the extensions of this type.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Select {
     pub id: String,
+    pub extensibility: ast::Extensibility,
     pub types: Vec<TypeRef>,
+    pub doc: Documentation,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -50,6 +60,117 @@ impl TypeDecl {
             TypeDecl::Select(e) => &e.id,
         }
     }
+
+    pub fn doc(&self) -> &Documentation {
+        match self {
+            TypeDecl::Simple(e) => &e.doc,
+            TypeDecl::Rename(e) => &e.doc,
+            TypeDecl::Enumeration(e) => &e.doc,
+            TypeDecl::Select(e) => &e.doc,
+        }
+    }
+
+    pub(crate) fn doc_mut(&mut self) -> &mut Documentation {
+        match self {
+            TypeDecl::Simple(e) => &mut e.doc,
+            TypeDecl::Rename(e) => &mut e.doc,
+            TypeDecl::Enumeration(e) => &mut e.doc,
+            TypeDecl::Select(e) => &mut e.doc,
+        }
+    }
+}
+
+/// Base type name in `BASED_ON`, the items of `ENUMERATION` or `SELECT` type,
+/// and the spans of the items which only `SELECT` records
+type Constructed<'st> = (&'st Option<String>, &'st [String], &'st [ast::Span]);
+
+/// Member of `ENUMERATION` or `SELECT` type with the scope where it is declared
+type Member = (Scope, String, Option<ast::Span>);
+
+/// Get [Constructed] of the type declared at `path`, or `None` for other types
+fn constructed<'st>(
+    ns: &'st Namespace,
+    path: &Path,
+) -> Result<Option<Constructed<'st>>, SemanticError> {
+    Ok(match ns.get(path)?.0 {
+        Named::Type(ast::TypeDecl {
+            underlying_type:
+                ast::Type::Enumeration {
+                    based_on, items, ..
+                },
+            ..
+        }) => Some((based_on, items, &[])),
+        Named::Type(ast::TypeDecl {
+            underlying_type:
+                ast::Type::Select {
+                    based_on,
+                    types,
+                    spans,
+                    ..
+                },
+            ..
+        }) => Some((based_on, types, spans)),
+        _ => None,
+    })
+}
+
+/// Collect members of `ENUMERATION` or `SELECT` type declared at `path`
+///
+/// The members of the types in `BASED_ON` chain come first,
+/// and then the members of the types extending this type (ISO 10303-11 8.4.1, 8.4.2).
+fn members(ns: &Namespace, ss: &Constraints, path: &Path) -> Result<Vec<Member>, SemanticError> {
+    // `BASED_ON` chain from this type to the root
+    let mut chain = vec![path.clone()];
+    loop {
+        let current = chain.last().unwrap();
+        let based_on = match constructed(ns, current)? {
+            Some((Some(based_on), _items, _spans)) => based_on,
+            _ => break,
+        };
+        let (base, _index) = ns.resolve(&current.scope, based_on)?;
+        if chain.contains(&base) {
+            return Err(SemanticError::CyclicBasedOn(path.clone()));
+        }
+        chain.push(base);
+    }
+
+    let mut members = Vec::new();
+    for p in chain.iter().rev() {
+        push_members(ns, p, &mut members)?;
+    }
+    extensions(ns, ss, path, &mut members)?;
+    Ok(members)
+}
+
+/// Collect members of the types declared as `BASED_ON` the type at `path` recursively
+fn extensions(
+    ns: &Namespace,
+    ss: &Constraints,
+    path: &Path,
+    members: &mut Vec<Member>,
+) -> Result<(), SemanticError> {
+    for p in ss.extensions.get(path).into_iter().flatten() {
+        push_members(ns, p, members)?;
+        extensions(ns, ss, p, members)?;
+    }
+    Ok(())
+}
+
+/// Push the items declared in the type at `path` itself
+fn push_members(
+    ns: &Namespace,
+    path: &Path,
+    members: &mut Vec<Member>,
+) -> Result<(), SemanticError> {
+    if let Some((_based_on, items, spans)) = constructed(ns, path)? {
+        members.extend(
+            items
+                .iter()
+                .enumerate()
+                .map(|(i, item)| (path.scope.clone(), item.clone(), spans.get(i).copied())),
+        );
+    }
+    Ok(())
 }
 
 impl Legalize for TypeDecl {
@@ -66,67 +187,63 @@ impl Legalize for TypeDecl {
             Type::Simple(ty) => TypeDecl::Simple(Simple {
                 id,
                 ty: SimpleType(*ty),
+                doc: Documentation::default(),
             }),
             Type::Named(name) => {
                 let (path, _index) = ns.resolve(scope, name)?;
                 TypeDecl::Rename(Rename {
                     id,
                     ty: TypeRef::from_path(ns, ss, &path)?,
+                    doc: Documentation::default(),
                 })
             }
-            Type::Enumeration {
-                items,
-                extensibility: _,
-            } => TypeDecl::Enumeration(Enumeration {
-                id,
-                items: items.clone(),
-            }),
-            Type::Select {
-                types,
-                extensibility: _,
-            } => {
-                let types = types
+            Type::Enumeration { extensibility, .. } => {
+                let path = Path::new(scope, ScopeType::Type, &id);
+                let mut items = Vec::new();
+                for (_scope, item, _span) in members(ns, ss, &path)? {
+                    if !items.contains(&item) {
+                        items.push(item);
+                    }
+                }
+                TypeDecl::Enumeration(Enumeration {
+                    id,
+                    extensibility: extensibility.clone(),
+                    items,
+                    doc: Documentation::default(),
+                })
+            }
+            Type::Select { extensibility, .. } => {
+                let path = Path::new(scope, ScopeType::Type, &id);
+                let mut paths = Vec::new();
+                for (scope, ty, span) in members(ns, ss, &path)? {
+                    let (path, _index) = ns.resolve(&scope, &ty).map_err(|err| match span {
+                        Some(span) => err.located(&scope, span),
+                        None => err,
+                    })?;
+                    if !paths.contains(&path) {
+                        paths.push(path);
+                    }
+                }
+                // An empty Rust enum cannot hold any entity the select is declared for
+                if paths.is_empty() && *extensibility == ast::Extensibility::GenericEntity {
+                    return Err(SemanticError::EmptyGenericEntitySelect(path));
+                }
+                let types = paths
                     .iter()
-                    .map(|ty| {
-                        let (path, _index) = ns.resolve(scope, ty)?;
-                        TypeRef::from_path(ns, ss, &path)
-                    })
+                    .map(|path| TypeRef::from_path(ns, ss, path))
                     .collect::<Result<Vec<_>, _>>()?;
-                TypeDecl::Select(Select { id, types })
-            }
-            Type::Set { base, bound } => {
-                let base = TypeRef::legalize(ns, ss, scope, base.as_ref())?;
-                let bound = if let Some(bound) = bound {
-                    Some(Legalize::legalize(ns, ss, scope, bound)?)
-                } else {
-                    None
-                };
-                TypeDecl::Rename(Rename {
+                TypeDecl::Select(Select {
                     id,
-                    ty: TypeRef::Set {
-                        base: Box::new(base),
-                        bound,
-                    },
+                    extensibility: extensibility.clone(),
+                    types,
+                    doc: Documentation::default(),
                 })
             }
-            Type::List {
-                base,
-                bound,
-                unique,
-            } => {
-                let base = TypeRef::legalize(ns, ss, scope, base.as_ref())?;
-                let bound = if let Some(bound) = bound {
-                    Some(Legalize::legalize(ns, ss, scope, bound)?)
-                } else {
-                    None
-                };
+            Type::Set { .. } | Type::Bag { .. } | Type::List { .. } | Type::Array { .. } => {
                 TypeDecl::Rename(Rename {
                     id,
-                    ty: TypeRef::List {
-                        base: Box::new(base),
-                        bound,
-                        unique: *unique,
-                    },
+                    ty: TypeRef::legalize(ns, ss, scope, &type_decl.underlying_type)?,
+                    doc: Documentation::default(),
                 })
             }
             _ => panic!(),
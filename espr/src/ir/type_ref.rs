@@ -1,5 +1,6 @@
 use super::{namespace::*, scope::*, *};
 use crate::ast;
+use std::fmt;
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct SimpleType(pub ast::SimpleType);
@@ -16,8 +17,45 @@ impl Legalize for SimpleType {
     }
 }
 
+/// Bound of aggregation types, e.g. `[1:3]` of `ARRAY [1:3] OF REAL`
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct Bound {}
+pub struct Bound {
+    pub lower: Box<Expression>,
+    /// `None` if the upper bound is indeterminate, i.e. `?`
+    pub upper: Option<Box<Expression>>,
+}
+
+impl Bound {
+    /// Lower bound as a constant integer, `None` if it cannot be evaluated
+    ///
+    /// Overflow while evaluation is reported as [SemanticError::IntegerOverflow] in legalization.
+    pub fn lower_value(&self) -> Option<i64> {
+        self.lower.eval_integer().ok().flatten()
+    }
+
+    /// Upper bound as a constant integer, `None` if it is `?` or cannot be evaluated
+    pub fn upper_value(&self) -> Option<i64> {
+        self.upper
+            .as_ref()
+            .and_then(|upper| upper.eval_integer().ok().flatten())
+    }
+}
+
+/// Print as `[1:?]`, where a bound which cannot be evaluated is also printed as `?`
+impl fmt::Display for Bound {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let value = |value: Option<i64>| match value {
+            Some(value) => value.to_string(),
+            None => "?".to_string(),
+        };
+        write!(
+            f,
+            "[{}:{}]",
+            value(self.lower_value()),
+            value(self.upper_value())
+        )
+    }
+}
 
 impl Legalize for Bound {
     type Input = ast::Bound;
@@ -25,10 +63,22 @@ impl Legalize for Bound {
         _ns: &Namespace,
         _ss: &Constraints,
         _scope: &Scope,
-        _input: &Self::Input,
+        input: &Self::Input,
     ) -> Result<Self, SemanticError> {
-        // FIXME
-        Ok(Bound {})
+        let upper = if input.upper.is_indeterminate() {
+            None
+        } else {
+            Some(Box::new(Expression::legalize_constant(&input.upper)))
+        };
+        let bound = Bound {
+            lower: Box::new(Expression::legalize_constant(&input.lower)),
+            upper,
+        };
+        bound.lower.eval_integer()?;
+        if let Some(upper) = &bound.upper {
+            upper.eval_integer()?;
+        }
+        Ok(bound)
     }
 }
 
@@ -67,27 +117,52 @@ pub enum TypeRef {
         base: Box<TypeRef>,
         bound: Option<Bound>,
     },
+    Bag {
+        base: Box<TypeRef>,
+        bound: Option<Bound>,
+    },
     List {
         base: Box<TypeRef>,
         bound: Option<Bound>,
         unique: bool,
     },
+    Array {
+        base: Box<TypeRef>,
+        bound: Option<Bound>,
+        unique: bool,
+        /// Elements may be indeterminate, declared as `ARRAY [1:3] OF OPTIONAL REAL`
+        optional: bool,
+    },
 }
 
 impl TypeRef {
     /// Returns `true` iff `self` is:
     /// - a simple type,
     /// - a named type whose underlying type is simple, or,
-    /// - a set or list of a type `x` such that `x.is_simple() == true`.
+    /// - an aggregate of a type `x` such that `x.is_simple() == true`.
     pub fn is_simple(&self) -> bool {
         match self {
             TypeRef::SimpleType(..) => true,
             TypeRef::Named { is_simple, .. } => *is_simple,
-            TypeRef::Set { base, .. } | TypeRef::List { base, .. } => base.is_simple(),
+            TypeRef::Set { base, .. }
+            | TypeRef::Bag { base, .. }
+            | TypeRef::List { base, .. }
+            | TypeRef::Array { base, .. } => base.is_simple(),
             _ => false,
         }
     }
 
+    /// Bound of aggregate type, `None` for other types or aggregates without bound
+    pub fn bound(&self) -> Option<&Bound> {
+        match self {
+            TypeRef::Set { bound, .. }
+            | TypeRef::Bag { bound, .. }
+            | TypeRef::List { bound, .. }
+            | TypeRef::Array { bound, .. } => bound.as_ref(),
+            _ => None,
+        }
+    }
+
     pub fn from_path(ns: &Namespace, ss: &Constraints, path: &Path) -> Result<Self, SemanticError> {
         match path.ty {
             ScopeType::Entity => {
@@ -151,6 +226,62 @@ impl TypeRef {
     }
 }
 
+/// Print as EXPRESS, e.g. `SET [1:?] OF point`
+///
+/// Bounds which cannot be evaluated as integer constants are printed as `?`.
+impl fmt::Display for TypeRef {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fn bound(bound: &Option<Bound>) -> String {
+            match bound {
+                Some(bound) => format!(" {}", bound),
+                None => String::new(),
+            }
+        }
+        match self {
+            TypeRef::SimpleType(SimpleType(ty)) => write!(f, "{}", ty),
+            TypeRef::Named { name, .. } | TypeRef::Entity { name, .. } => write!(f, "{}", name),
+            TypeRef::Set { base, bound: b } => write!(f, "SET{} OF {}", bound(b), base),
+            TypeRef::Bag { base, bound: b } => write!(f, "BAG{} OF {}", bound(b), base),
+            TypeRef::List {
+                base,
+                bound: b,
+                unique,
+            } => write!(
+                f,
+                "LIST{} OF {}{}",
+                bound(b),
+                if *unique { "UNIQUE " } else { "" },
+                base
+            ),
+            TypeRef::Array {
+                base,
+                bound: b,
+                unique,
+                optional,
+            } => write!(
+                f,
+                "ARRAY{} OF {}{}{}",
+                bound(b),
+                if *optional { "OPTIONAL " } else { "" },
+                if *unique { "UNIQUE " } else { "" },
+                base
+            ),
+        }
+    }
+}
+
+fn legalize_bound(
+    ns: &Namespace,
+    ss: &Constraints,
+    scope: &Scope,
+    bound: &Option<ast::Bound>,
+) -> Result<Option<Bound>, SemanticError> {
+    bound
+        .as_ref()
+        .map(|bound| Bound::legalize(ns, ss, scope, bound))
+        .transpose()
+}
+
 impl Legalize for TypeRef {
     type Input = ast::Type;
 
@@ -167,36 +298,86 @@ impl Legalize for TypeRef {
                 let (path, _index) = ns.resolve(scope, name)?;
                 Self::from_path(ns, ss, &path)?
             }
-            Set { base, bound } => {
-                let base = TypeRef::legalize(ns, ss, scope, base.as_ref())?;
-                let bound = if let Some(bound) = bound {
-                    Some(Legalize::legalize(ns, ss, scope, bound)?)
-                } else {
-                    None
-                };
-                Self::Set {
-                    base: Box::new(base),
-                    bound,
-                }
-            }
+            Set { base, bound } => Self::Set {
+                base: Box::new(TypeRef::legalize(ns, ss, scope, base.as_ref())?),
+                bound: legalize_bound(ns, ss, scope, bound)?,
+            },
+            Bag { base, bound } => Self::Bag {
+                base: Box::new(TypeRef::legalize(ns, ss, scope, base.as_ref())?),
+                bound: legalize_bound(ns, ss, scope, bound)?,
+            },
             List {
                 base,
                 bound,
                 unique,
-            } => {
-                let base = TypeRef::legalize(ns, ss, scope, base.as_ref())?;
-                let bound = if let Some(bound) = bound {
-                    Some(Legalize::legalize(ns, ss, scope, bound)?)
-                } else {
-                    None
-                };
-                Self::List {
-                    base: Box::new(base),
-                    bound,
-                    unique: *unique,
-                }
+            } => Self::List {
+                base: Box::new(TypeRef::legalize(ns, ss, scope, base.as_ref())?),
+                bound: legalize_bound(ns, ss, scope, bound)?,
+                unique: *unique,
+            },
+            Array {
+                base,
+                bound,
+                unique,
+                optional,
+            } => Self::Array {
+                base: Box::new(TypeRef::legalize(ns, ss, scope, base.as_ref())?),
+                bound: legalize_bound(ns, ss, scope, bound)?,
+                unique: *unique,
+                optional: *optional,
+            },
+            Enumeration { .. } | Select { .. } => {
+                return Err(SemanticError::NotSupportedYet(
+                    "ENUMERATION or SELECT outside TYPE declaration".to_string(),
+                ))
+            }
+            Aggregate { .. } | GenericEntity(_) | Generic(_) => {
+                return Err(SemanticError::NotSupportedYet(format!(
+                    "Parameter type `{}`",
+                    ty
+                )))
             }
-            _ => todo!(),
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn legalize_error(input: &str) -> SemanticError {
+        let st = ast::SyntaxTree::parse(input).unwrap();
+        match IR::from_syntax_tree(&st).unwrap_err() {
+            SemanticError::Located { source, .. } => *source,
+            err => err,
+        }
+    }
+
+    #[test]
+    fn parameter_type() {
+        let err = legalize_error(
+            r#"
+            SCHEMA test_schema;
+              ENTITY a;
+                x: AGGREGATE OF REAL;
+              END_ENTITY;
+            END_SCHEMA;
+            "#,
+        );
+        assert!(matches!(err, SemanticError::NotSupportedYet(_)));
+    }
+
+    #[test]
+    fn bound_overflow() {
+        let err = legalize_error(
+            r#"
+            SCHEMA test_schema;
+              ENTITY a;
+                x: LIST [1:4611686018427387904 * 2] OF REAL;
+              END_ENTITY;
+            END_SCHEMA;
+            "#,
+        );
+        assert!(matches!(err, SemanticError::IntegerOverflow));
+    }
+}
@@ -34,6 +34,7 @@
 //!   - [ir] module defines IR structs, and they implements [ir::Legalize] trait for legalizing from AST
 //! - Code Generation
 //!   - [codegen::rust] module generates Rust code from IR
+//!   - [Builder] runs all steps above in build scripts
 //!
 //! Introduction to STEP
 //! ---------------------
@@ -152,6 +153,12 @@
 #![deny(rustdoc::broken_intra_doc_links)]
 
 pub mod ast;
+pub mod builder;
 pub mod codegen;
+pub mod diagnostic;
+pub mod diff;
+pub mod doc;
 pub mod ir;
 pub mod parser;
+
+pub use builder::{BuildError, Builder};
@@ -1,5 +1,5 @@
 use super::{combinator::RawParseResult, reserved::is_reserved};
-use nom::{branch::*, character::complete::*, multi::*, sequence::*, Parser};
+use nom::{branch::*, bytes::complete::tag, character::complete::*, multi::*, sequence::*, Parser};
 
 /// 128 letter = `a` | `b` | `c` | `d` | `e` | `f` | `g` | `h` | `i` | `j` | `k` | `l` |`m` | `n` | `o` | `p` | `q` | `r` | `s` | `t` | `u` | `v` | `w` | `x` |`y` | `z` .
 pub fn letter(input: &str) -> RawParseResult<char> {
@@ -49,7 +49,8 @@ pub fn encoded_string_literal(input: &str) -> RawParseResult<String> {
 
 /// 144 simple_string_literal = \q { ( \q \q ) | not_quote | \s | \x9 | \xA | \xD } \q .
 pub fn simple_string_literal(input: &str) -> RawParseResult<String> {
-    tuple((char('\''), many0(none_of("'")), char('\'')))
+    let quote = tag("''").map(|_| '\'');
+    tuple((char('\''), many0(alt((quote, none_of("'")))), char('\'')))
         .map(|(_open, chars, _close)| chars.into_iter().collect())
         .parse(input)
 }
@@ -134,6 +135,16 @@ mod tests {
         assert_eq!(residual, "");
     }
 
+    #[test]
+    fn simple_string_literal() {
+        let (residual, s) = super::simple_string_literal("'it''s' ''").finish().unwrap();
+        assert_eq!(s, "it's");
+        assert_eq!(residual, " ''");
+        let (residual, s) = super::simple_string_literal("''").finish().unwrap();
+        assert_eq!(s, "");
+        assert_eq!(residual, "");
+    }
+
     #[test]
     fn simple_id_valid() {
         let (residual, id) = super::simple_id("h").finish().unwrap();
@@ -1,7 +1,7 @@
 //! Parser combinators for EXPRESS language with remarks
 
 use super::remark::*;
-use crate::ast::Remark;
+use crate::ast::{Remark, Span};
 use nom::{error::VerboseError, sequence::pair, IResult};
 use std::marker::PhantomData;
 
@@ -83,6 +83,20 @@ where
     move |input| f.clone().map(|out| (out, Vec::new())).parse(input)
 }
 
+/// Attach [Span] of the input consumed by `f`
+pub fn spanned<'a, O, F>(f: F) -> impl EsprParser<'a, (O, Span)>
+where
+    F: EsprParser<'a, O>,
+{
+    move |input: &'a str| -> ParseResult<'a, (O, Span)> {
+        let (residual, (out, remarks)) = nom::Parser::parse(&mut f.clone(), input)?;
+        // Trailing spaces consumed by `f` are not included
+        let consumed = input[..input.len() - residual.len()].trim_end();
+        let span = Span::new(input.len(), input.len() - consumed.len());
+        Ok((residual, ((out, span), remarks)))
+    }
+}
+
 pub fn opt<'a, O, F>(f: F) -> impl EsprParser<'a, Option<O>>
 where
     F: EsprParser<'a, O>,
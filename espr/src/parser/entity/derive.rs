@@ -13,15 +13,22 @@ pub fn derive_clause(input: &str) -> ParseResult<DeriveClause> {
 
 /// 200 derived_attr = [attribute_decl] `:` [parameter_type] `:=` [expression] `;` .
 pub fn derived_attr(input: &str) -> ParseResult<DerivedAttribute> {
-    tuple((
+    spanned(tuple((
         attribute_decl,
         char(':'),
         parameter_type,
         tag(":="),
         expression,
         char(';'),
-    ))
-    .map(|(attr, _colon, ty, _equal, expr, _semicolon)| DerivedAttribute { attr, ty, expr })
+    )))
+    .map(
+        |((attr, _colon, ty, _equal, expr, _semicolon), span)| DerivedAttribute {
+            attr,
+            ty,
+            expr,
+            span,
+        },
+    )
     .parse(input)
 }
 
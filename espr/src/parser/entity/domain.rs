@@ -13,10 +13,10 @@ pub fn where_clause(input: &str) -> ParseResult<WhereClause> {
 
 /// 202 domain_rule = \[ [rule_label_id] `:` \] [expression] .
 pub fn domain_rule(input: &str) -> ParseResult<DomainRule> {
-    tuple((opt(tuple((rule_label_id, char(':')))), expression))
-        .map(|(opt, expr)| {
+    spanned(tuple((opt(tuple((rule_label_id, char(':')))), expression)))
+        .map(|((opt, expr), span)| {
             let label = opt.map(|(label, _colon)| label);
-            DomainRule { label, expr }
+            DomainRule { label, expr, span }
         })
         .parse(input)
 }
@@ -6,20 +6,21 @@ use crate::{
 
 /// 215 explicit_attr = [attribute_decl] { `,` [attribute_decl] } `:` \[ OPTIONAL \] [parameter_type] `;` .
 pub fn explicit_attr(input: &str) -> ParseResult<Vec<EntityAttribute>> {
-    tuple((
+    spanned(tuple((
         comma_separated(attribute_decl),
         char(':'),
         opt(tag("OPTIONAL")),
         parameter_type,
         char(';'),
-    ))
-    .map(|(attrs, _colon, optional, ty, _semicolon)| {
+    )))
+    .map(|((attrs, _colon, optional, ty, _semicolon), span)| {
         attrs
             .into_iter()
             .map(|name| EntityAttribute {
                 name,
                 ty: ty.clone(),
                 optional: optional.is_some(),
+                span,
             })
             .collect()
     })
@@ -61,9 +62,15 @@ pub fn entity_body(input: &str) -> ParseResult<EntityBody> {
 
 /// 206 entity_decl = [entity_head] [entity_body] END_ENTITY `;` .
 pub fn entity_decl(input: &str) -> ParseResult<Entity> {
-    tuple((entity_head, entity_body, tag("END_ENTITY"), char(';')))
-        .map(
-            |(
+    spanned(tuple((
+        entity_head,
+        entity_body,
+        tag("END_ENTITY"),
+        char(';'),
+    )))
+    .map(
+        |(
+            (
                 (name, constraint, subtype_of),
                 EntityBody {
                     attributes,
@@ -74,18 +81,21 @@ pub fn entity_decl(input: &str) -> ParseResult<Entity> {
                 },
                 _end,
                 _semicolon,
-            )| Entity {
-                name,
-                attributes,
-                constraint,
-                subtype_of,
-                derive_clause,
-                inverse_clause,
-                unique_clause,
-                where_clause,
-            },
-        )
-        .parse(input)
+            ),
+            span,
+        )| Entity {
+            name,
+            attributes,
+            constraint,
+            subtype_of,
+            derive_clause,
+            inverse_clause,
+            unique_clause,
+            where_clause,
+            span,
+        },
+    )
+    .parse(input)
 }
 
 #[cfg(test)]
@@ -37,7 +37,7 @@ pub fn inverse_attr(input: &str) -> ParseResult<InverseAttribute> {
     let attribute_prefix =
         opt(tuple((entity_ref, char('.')))).map(|opt| opt.map(|(prefix, _dot)| prefix));
 
-    tuple((
+    spanned(tuple((
         attribute_decl,
         char(':'),
         aggregation_option,
@@ -46,15 +46,19 @@ pub fn inverse_attr(input: &str) -> ParseResult<InverseAttribute> {
         attribute_prefix,
         attribute_ref,
         char(';'),
-    ))
+    )))
     .map(
-        |(name, _comma, dest_aggregation, dest, _for, attribute_prefix, attribute, _semicolon)| {
+        |(
+            (name, _comma, dest_aggregation, dest, _for, attribute_prefix, attribute, _semicolon),
+            span,
+        )| {
             InverseAttribute {
                 name,
                 dest,
                 dest_aggregation,
                 attribute,
                 attribute_prefix,
+                span,
             }
         },
     )
@@ -90,15 +94,17 @@ mod tests {
         assert_eq!(inv.name, "opens");
         assert_eq!(inv.dest, "door");
         assert_eq!(inv.attribute, "handle");
-        assert_eq!(
-            inv.dest_aggregation,
-            AggregationOption::Set {
-                bound: Some(Bound {
-                    upper: Expression::real(1.0),
-                    lower: Expression::real(0.0)
-                })
-            }
-        );
+        Span::ignored(|| {
+            assert_eq!(
+                inv.dest_aggregation,
+                AggregationOption::Set {
+                    bound: Some(Bound {
+                        upper: Expression::real(1.0),
+                        lower: Expression::real(0.0)
+                    })
+                }
+            )
+        });
         assert_eq!(inv.attribute_prefix, None);
     }
 }
@@ -3,12 +3,12 @@ use crate::ast::*;
 
 /// 169 aggregate_initializer = `[` \[ [element] { `,` [element] } \] `]` .
 pub fn aggregate_initializer(input: &str) -> ParseResult<Expression> {
-    tuple((
+    spanned(tuple((
         char('['),
         opt(comma_separated(element)).map(|opt| opt.unwrap_or_default()),
         char(']'),
-    ))
-    .map(|(_open, elements, _close)| Expression::AggregateInitializer { elements })
+    )))
+    .map(|((_open, elements, _close), span)| Expression::AggregateInitializer { elements, span })
     .parse(input)
 }
 
@@ -48,39 +48,44 @@ mod tests {
     fn aggregate_initializer() {
         let (res, (expr, _remarks)) = super::expression("[1, 3, 6, 9*8, -12]").finish().unwrap();
         assert_eq!(res, "");
-        assert_eq!(
-            expr,
-            Expression::AggregateInitializer {
-                elements: vec![
-                    Element {
-                        expr: Expression::Literal(Literal::Real(1.0)),
-                        repetition: None,
-                    },
-                    Element {
-                        expr: Expression::Literal(Literal::Real(3.0)),
-                        repetition: None,
-                    },
-                    Element {
-                        expr: Expression::Literal(Literal::Real(6.0)),
-                        repetition: None,
-                    },
-                    Element {
-                        expr: Expression::Binary {
-                            op: BinaryOperator::Mul,
-                            arg1: Box::new(Expression::Literal(Literal::Real(9.0))),
-                            arg2: Box::new(Expression::Literal(Literal::Real(8.0)))
+        Span::ignored(|| {
+            assert_eq!(
+                expr,
+                Expression::AggregateInitializer {
+                    elements: vec![
+                        Element {
+                            expr: Expression::real(1.0),
+                            repetition: None,
+                        },
+                        Element {
+                            expr: Expression::real(3.0),
+                            repetition: None,
+                        },
+                        Element {
+                            expr: Expression::real(6.0),
+                            repetition: None,
+                        },
+                        Element {
+                            expr: Expression::Binary {
+                                op: BinaryOperator::Mul,
+                                arg1: Box::new(Expression::real(9.0)),
+                                arg2: Box::new(Expression::real(8.0)),
+                                span: Span::default(),
+                            },
+                            repetition: None,
                         },
-                        repetition: None,
-                    },
-                    Element {
-                        expr: Expression::Unary {
-                            op: UnaryOperator::Minus,
-                            arg: Box::new(Expression::Literal(Literal::Real(12.0))),
+                        Element {
+                            expr: Expression::Unary {
+                                op: UnaryOperator::Minus,
+                                arg: Box::new(Expression::real(12.0)),
+                                span: Span::default(),
+                            },
+                            repetition: None,
                         },
-                        repetition: None,
-                    },
-                ]
-            }
-        );
+                    ],
+                    span: Span::default(),
+                }
+            )
+        });
     }
 }
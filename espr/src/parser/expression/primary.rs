@@ -8,9 +8,14 @@ use crate::ast::*;
 /// 269 primary = [literal] | ( [qualifiable_factor] { [qualifier] } ) .
 pub fn primary(input: &str) -> ParseResult<Expression> {
     alt((
-        literal.map(Expression::Literal),
-        tuple((qualifiable_factor, many0(qualifier)))
-            .map(|(factor, qualifiers)| Expression::QualifiableFactor { factor, qualifiers }),
+        spanned(literal).map(|(literal, span)| Expression::Literal { literal, span }),
+        spanned(tuple((qualifiable_factor, many0(qualifier)))).map(
+            |((factor, qualifiers), span)| Expression::QualifiableFactor {
+                factor,
+                qualifiers,
+                span,
+            },
+        ),
     ))
     .parse(input)
 }
@@ -223,7 +228,10 @@ mod tests {
     fn no_qualifier() {
         let (res, (q, _remarks)) = super::primary("x").finish().unwrap();
         assert_eq!(res, "");
-        if let Expression::QualifiableFactor { factor, qualifiers } = q {
+        if let Expression::QualifiableFactor {
+            factor, qualifiers, ..
+        } = q
+        {
             match factor {
                 QualifiableFactor::Reference(name) => {
                     assert_eq!(name, "x");
@@ -240,7 +248,10 @@ mod tests {
     fn simple() {
         let (res, (q, _remarks)) = super::primary(r"x\group.attr").finish().unwrap();
         assert_eq!(res, "");
-        if let Expression::QualifiableFactor { factor, qualifiers } = q {
+        if let Expression::QualifiableFactor {
+            factor, qualifiers, ..
+        } = q
+        {
             match factor {
                 QualifiableFactor::Reference(name) => {
                     assert_eq!(name, "x");
@@ -259,7 +270,10 @@ mod tests {
     fn function_call() {
         let (res, (q, _remarks)) = super::primary("f(x)").finish().unwrap();
         assert_eq!(res, "");
-        if let Expression::QualifiableFactor { factor, qualifiers } = q {
+        if let Expression::QualifiableFactor {
+            factor, qualifiers, ..
+        } = q
+        {
             match factor {
                 QualifiableFactor::FunctionCall { name, args } => {
                     assert_eq!(name, FunctionCallName::Reference("f".to_string()));
@@ -284,7 +298,10 @@ mod tests {
     fn index() {
         let (res, (q, _remarks)) = super::primary("x[2 * 2]").finish().unwrap();
         assert_eq!(res, "");
-        if let Expression::QualifiableFactor { factor, qualifiers } = q {
+        if let Expression::QualifiableFactor {
+            factor, qualifiers, ..
+        } = q
+        {
             match factor {
                 QualifiableFactor::Reference(name) => {
                     assert_eq!(name, "x");
@@ -302,7 +319,10 @@ mod tests {
     fn range() {
         let (res, (q, _remarks)) = super::primary("x[1:3]").finish().unwrap();
         assert_eq!(res, "");
-        if let Expression::QualifiableFactor { factor, qualifiers } = q {
+        if let Expression::QualifiableFactor {
+            factor, qualifiers, ..
+        } = q
+        {
             match factor {
                 QualifiableFactor::Reference(name) => {
                     assert_eq!(name, "x");
@@ -320,7 +340,10 @@ mod tests {
     fn indeterminate() {
         let (res, (q, _remarks)) = super::primary("x[1:?]").finish().unwrap();
         assert_eq!(res, "");
-        if let Expression::QualifiableFactor { factor, qualifiers } = q {
+        if let Expression::QualifiableFactor {
+            factor, qualifiers, ..
+        } = q
+        {
             match factor {
                 QualifiableFactor::Reference(name) => {
                     assert_eq!(name, "x");
@@ -330,16 +353,7 @@ mod tests {
             assert_eq!(qualifiers.len(), 1);
             match &qualifiers[0] {
                 Qualifier::Range { begin: _, end } => {
-                    use super::*;
-                    assert_eq!(
-                        end,
-                        &Expression::QualifiableFactor {
-                            factor: QualifiableFactor::BuiltInConstant(
-                                BuiltInConstant::Indeterminate
-                            ),
-                            qualifiers: Vec::new()
-                        }
-                    );
+                    assert!(end.is_indeterminate());
                 }
                 _ => panic!("Must be range"),
             }
@@ -11,6 +11,7 @@ fn create_tree(mut head: Expression, tails: Vec<(BinaryOperator, Expression)>) -
     for (op, expr) in tails {
         head = Expression::Binary {
             op,
+            span: head.span().to(expr.span()),
             arg1: Box::new(head),
             arg2: Box::new(expr),
         }
@@ -39,6 +40,7 @@ pub fn factor(input: &str) -> ParseResult<Expression> {
             if let Some((op, arg2)) = opt {
                 Expression::Binary {
                     op,
+                    span: arg1.span().to(arg2.span()),
                     arg1: Box::new(arg1),
                     arg2: Box::new(arg2),
                 }
@@ -58,16 +60,18 @@ pub fn factor(input: &str) -> ParseResult<Expression> {
 pub fn simple_factor(input: &str) -> ParseResult<Expression> {
     let paren_expr = tuple((char('('), expression, char(')'))).map(|(_open, e, _close)| e);
     // ( \[ unary_op \] ( `(` expression `)` | primary ) )
-    let unary = tuple((opt(unary_op), alt((paren_expr, primary)))).map(|(opt, expr)| {
-        if let Some(op) = opt {
-            Expression::Unary {
-                op,
-                arg: Box::new(expr),
+    let unary =
+        spanned(tuple((opt(unary_op), alt((paren_expr, primary))))).map(|((opt, expr), span)| {
+            if let Some(op) = opt {
+                Expression::Unary {
+                    op,
+                    arg: Box::new(expr),
+                    span,
+                }
+            } else {
+                expr
             }
-        } else {
-            expr
-        }
-    });
+        });
     alt((
         unary,
         aggregate_initializer,
@@ -89,6 +93,7 @@ pub fn expression(input: &str) -> ParseResult<Expression> {
         if let Some((op, rhs)) = opt {
             Expression::Relation {
                 op,
+                span: lhs.span().to(rhs.span()),
                 lhs: Box::new(lhs),
                 rhs: Box::new(rhs),
             }
@@ -101,17 +106,18 @@ pub fn expression(input: &str) -> ParseResult<Expression> {
 
 /// 212 enumeration_reference = \[ [type_ref] `.` \] [enumeration_ref] .
 pub fn enumeration_reference(input: &str) -> ParseResult<Expression> {
-    tuple((opt(tuple((type_ref, char('.')))), enumeration_ref))
-        .map(|(opt, enum_ref)| Expression::EnumerationReference {
+    spanned(tuple((opt(tuple((type_ref, char('.')))), enumeration_ref)))
+        .map(|((opt, enum_ref), span)| Expression::EnumerationReference {
             ty: opt.map(|(ty, _comma)| ty),
             enum_ref,
+            span,
         })
         .parse(input)
 }
 
 /// 243 interval = `{` [interval_low] [interval_op] [interval_item] [interval_op] [interval_high] `}` .
 pub fn interval(input: &str) -> ParseResult<Expression> {
-    tuple((
+    spanned(tuple((
         char('{'),
         interval_low,
         interval_op,
@@ -119,14 +125,15 @@ pub fn interval(input: &str) -> ParseResult<Expression> {
         interval_op,
         interval_high,
         char('}'),
-    ))
+    )))
     .map(
-        |(_open, low, op_low, item, op_high, high, _close)| Expression::Interval {
+        |((_open, low, op_low, item, op_high, high, _close), span)| Expression::Interval {
             op_low,
             op_high,
             low: Box::new(low),
             item: Box::new(item),
             high: Box::new(high),
+            span,
         },
     )
     .parse(input)
@@ -159,7 +166,7 @@ pub fn logical_expression(input: &str) -> ParseResult<Expression> {
 
 /// 277 query_expression = QUERY `(` [variable_id] `<*` [aggregate_source] `|` [logical_expression] `)` .
 pub fn query_expression(input: &str) -> ParseResult<Expression> {
-    tuple((
+    spanned(tuple((
         tag("QUERY"),
         char('('),
         variable_id,
@@ -168,12 +175,13 @@ pub fn query_expression(input: &str) -> ParseResult<Expression> {
         char('|'),
         logical_expression,
         char(')'),
-    ))
+    )))
     .map(
-        |(_start, _open, variable, _star, source, _bar, expr, _close)| Expression::Query {
+        |((_start, _open, variable, _star, source, _bar, expr, _close), span)| Expression::Query {
             variable,
             source: Box::new(source),
             expr: Box::new(expr),
+            span,
         },
     )
     .parse(input)
@@ -181,16 +189,17 @@ pub fn query_expression(input: &str) -> ParseResult<Expression> {
 
 /// 205 entity_constructor = entity_ref `(` [ [expression] { `,` [expression] } ] `)` .
 pub fn entity_constructor(input: &str) -> ParseResult<Expression> {
-    tuple((
+    spanned(tuple((
         entity_ref,
         char('('),
         opt(comma_separated(expression)),
         char(')'),
-    ))
+    )))
     .map(
-        |(name, _open, values, _close)| Expression::EntityConstructor {
+        |((name, _open, values, _close), span)| Expression::EntityConstructor {
             name,
             values: values.unwrap_or_default(),
+            span,
         },
     )
     .parse(input)
@@ -209,7 +218,8 @@ mod tests {
             expr,
             Expression::Unary {
                 op: UnaryOperator::Minus,
-                arg: Box::new(Expression::Literal(Literal::Real(1.0))),
+                arg: Box::new(Expression::real(1.0).with_span(Span::new(3, 0))),
+                span: Span::new(4, 0),
             }
         );
     }
@@ -231,8 +241,9 @@ mod tests {
             expr,
             Expression::Binary {
                 op: BinaryOperator::Add,
-                arg1: Box::new(Expression::Literal(Literal::Real(1.0))),
-                arg2: Box::new(Expression::Literal(Literal::Real(2.0))),
+                arg1: Box::new(Expression::real(1.0).with_span(Span::new(9, 6))),
+                arg2: Box::new(Expression::real(2.0).with_span(Span::new(3, 0))),
+                span: Span::new(9, 0),
             }
         );
     }
@@ -241,32 +252,26 @@ mod tests {
     fn binary3() {
         let (res, (expr, _remarks)) = super::expression("1.0 + 2.0 - 3.0").finish().unwrap();
         assert_eq!(res, "");
-        assert_eq!(
-            expr,
-            Expression::Binary {
-                op: BinaryOperator::Sub,
-                arg1: Box::new(Expression::Binary {
-                    op: BinaryOperator::Add,
-                    arg1: Box::new(Expression::Literal(Literal::Real(1.0))),
-                    arg2: Box::new(Expression::Literal(Literal::Real(2.0))),
-                }),
-                arg2: Box::new(Expression::Literal(Literal::Real(3.0)))
-            }
-        );
+        Span::ignored(|| {
+            assert_eq!(
+                expr,
+                (Expression::real(1.0) + Expression::real(2.0)) - Expression::real(3.0)
+            )
+        });
     }
 
     #[test]
     fn relation() {
         let (res, (expr, _remarks)) = super::expression("1 <= 2").finish().unwrap();
         assert_eq!(res, "");
-        assert_eq!(expr, Expression::real(1.0).leq(Expression::real(2.0)));
+        Span::ignored(|| assert_eq!(expr, Expression::real(1.0).leq(Expression::real(2.0))));
     }
 
     #[test]
     fn relation_self() {
         let (res, (expr, _remarks)) = super::expression("1 <= SELF").finish().unwrap();
         assert_eq!(res, "");
-        assert_eq!(expr, Expression::real(1.0).leq(Expression::self_()));
+        Span::ignored(|| assert_eq!(expr, Expression::real(1.0).leq(Expression::self_())));
     }
 
     #[test]
@@ -275,19 +280,21 @@ mod tests {
             .finish()
             .unwrap();
         assert_eq!(res, "");
-        assert_eq!(
-            expr,
-            Expression::real(1.0)
-                .leq(Expression::self_())
-                .and(Expression::self_().leq(Expression::real(12.0)))
-        );
+        Span::ignored(|| {
+            assert_eq!(
+                expr,
+                Expression::real(1.0)
+                    .leq(Expression::self_())
+                    .and(Expression::self_().leq(Expression::real(12.0)))
+            )
+        });
     }
 
     #[test]
     fn literal() {
         let (res, (expr, _remarks)) = super::expression("1.0").finish().unwrap();
         assert_eq!(res, "");
-        assert_eq!(expr, Expression::real(1.0));
+        assert_eq!(expr, Expression::real(1.0).with_span(Span::new(3, 0)));
     }
 
     #[test]
@@ -301,7 +308,8 @@ mod tests {
                 qualifiers: vec![
                     Qualifier::Group("group".to_string()),
                     Qualifier::Attribute("attr".to_string())
-                ]
+                ],
+                span: Span::new(12, 0),
             }
         );
     }
@@ -312,7 +320,7 @@ mod tests {
             .finish()
             .unwrap();
         assert_eq!(residual, "");
-        if let super::Expression::EntityConstructor { name, values } = ctor {
+        if let super::Expression::EntityConstructor { name, values, .. } = ctor {
             assert_eq!(name, "point");
             assert_eq!(values.len(), 3);
         } else {
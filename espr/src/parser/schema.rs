@@ -8,8 +8,14 @@ pub fn schema_decl(input: &str) -> ParseResult<Schema> {
     // FIXME schema_version_id
     let schema_head =
         tuple((tag("SCHEMA "), schema_id, char(';'))).map(|(_start, id, _semicolon)| id);
-    tuple((schema_head, schema_body, tag("END_SCHEMA"), char(';')))
-        .map(|(name, (interfaces, constants, decls), _end, _semicolon)| {
+    spanned(tuple((
+        schema_head,
+        schema_body,
+        tag("END_SCHEMA"),
+        char(';'),
+    )))
+    .map(
+        |((name, (interfaces, constants, decls), _end, _semicolon), span)| {
             let mut entities = Vec::new();
             let mut types = Vec::new();
             let mut functions = Vec::new();
@@ -38,9 +44,11 @@ pub fn schema_decl(input: &str) -> ParseResult<Schema> {
                 constants,
                 interfaces,
                 subtype_constraints,
+                span,
             }
-        })
-        .parse(input)
+        },
+    )
+    .parse(input)
 }
 
 /// 295 schema_body = { [interface_specification] } \[ [constant_decl] \] { [declaration] | [rule_decl] } .
@@ -69,20 +77,23 @@ pub fn declaration(input: &str) -> ParseResult<Declaration> {
 
 /// 271 procedure_decl = [procedure_head] [algorithm_head] { [stmt] } END_PROCEDURE `;` .
 pub fn procedure_decl(input: &str) -> ParseResult<Procedure> {
-    tuple((
+    spanned(tuple((
         procedure_head,
         algorithm_head,
         many0(stmt),
         tag("END_PROCEDURE"),
         char(';'),
-    ))
+    )))
     .map(
         |(
-            (name, parameters),
-            (declarations, constants, variables),
-            statements,
-            _end,
-            _semicolon,
+            (
+                (name, parameters),
+                (declarations, constants, variables),
+                statements,
+                _end,
+                _semicolon,
+            ),
+            span,
         )| Procedure {
             name,
             parameters,
@@ -90,6 +101,7 @@ pub fn procedure_decl(input: &str) -> ParseResult<Procedure> {
             constants,
             variables,
             statements,
+            span,
         },
     )
     .parse(input)
@@ -127,20 +139,23 @@ pub fn procedure_head(input: &str) -> ParseResult<(String, Vec<FormalParameter>)
 
 /// 220 function_decl = [function_head] [algorithm_head] [stmt] { [stmt] } END_FUNCTION `;` .
 pub fn function_decl(input: &str) -> ParseResult<Function> {
-    tuple((
+    spanned(tuple((
         function_head,
         algorithm_head,
         many1(stmt),
         tag("END_FUNCTION"),
         char(';'),
-    ))
+    )))
     .map(
         |(
-            (name, parameters, return_type),
-            (declarations, constants, variables),
-            statements,
-            _end,
-            _semicolon,
+            (
+                (name, parameters, return_type),
+                (declarations, constants, variables),
+                statements,
+                _end,
+                _semicolon,
+            ),
+            span,
         )| Function {
             name,
             parameters,
@@ -149,6 +164,7 @@ pub fn function_decl(input: &str) -> ParseResult<Function> {
             variables,
             statements,
             return_type,
+            span,
         },
     )
     .parse(input)
@@ -207,36 +223,46 @@ pub fn constant_decl(input: &str) -> ParseResult<Vec<Constant>> {
 
 /// 194 constant_body = [constant_id] `:` [instantiable_type] `:=` [expression] `;` .
 pub fn constant_body(input: &str) -> ParseResult<Constant> {
-    tuple((
+    spanned(tuple((
         constant_id,
         char(':'),
         instantiable_type,
         tag(":="),
         expression,
         char(';'),
-    ))
-    .map(|(name, _colon, ty, _def, expr, _semicolon)| Constant { name, ty, expr })
+    )))
+    .map(
+        |((name, _colon, ty, _def, expr, _semicolon), span)| Constant {
+            name,
+            ty,
+            expr,
+            span,
+        },
+    )
     .parse(input)
 }
 
 /// 291 rule_decl = [rule_head] [algorithm_head] { [stmt] } [where_clause] END_RULE `;` .
 pub fn rule_decl(input: &str) -> ParseResult<Rule> {
-    tuple((
+    spanned(tuple((
         rule_head,
         algorithm_head,
         many0(stmt),
         where_clause,
         tag("END_RULE"),
         char(';'),
-    ))
+    )))
     .map(
         |(
-            (name, references),
-            (declarations, constants, variables),
-            statements,
-            where_clause,
-            _end,
-            _semicolon,
+            (
+                (name, references),
+                (declarations, constants, variables),
+                statements,
+                where_clause,
+                _end,
+                _semicolon,
+            ),
+            span,
         )| Rule {
             name,
             references,
@@ -245,6 +271,7 @@ pub fn rule_decl(input: &str) -> ParseResult<Rule> {
             variables,
             statements,
             where_clause,
+            span,
         },
     )
     .parse(input)
@@ -396,37 +423,40 @@ mod tests {
         let (residual, (schema, _remark)) = super::schema_decl(exp_str).finish().unwrap();
         assert_eq!(schema.name, "my_first_schema");
         assert_eq!(schema.entities.len(), 2);
-        assert_eq!(
-            schema.entities[0],
-            entity_decl(
-                r#"
-                ENTITY first;
-                  m_ref : second;
-                  fattr : STRING;
-                END_ENTITY;
-                "#
-                .trim()
-            )
-            .finish()
-            .unwrap()
-            .1
-             .0
-        );
-        assert_eq!(
-            schema.entities[1],
-            entity_decl(
-                r#"
-                ENTITY second;
-                  sattr : STRING;
-                END_ENTITY;
-                "#
-                .trim()
-            )
-            .finish()
-            .unwrap()
-            .1
-             .0
-        );
+        // Entities parsed alone have different spans from those in the schema
+        Span::ignored(|| {
+            assert_eq!(
+                schema.entities[0],
+                entity_decl(
+                    r#"
+                    ENTITY first;
+                      m_ref : second;
+                      fattr : STRING;
+                    END_ENTITY;
+                    "#
+                    .trim()
+                )
+                .finish()
+                .unwrap()
+                .1
+                 .0
+            );
+            assert_eq!(
+                schema.entities[1],
+                entity_decl(
+                    r#"
+                    ENTITY second;
+                      sattr : STRING;
+                    END_ENTITY;
+                    "#
+                    .trim()
+                )
+                .finish()
+                .unwrap()
+                .1
+                 .0
+            );
+        });
         assert_eq!(residual, "");
     }
 
@@ -667,4 +697,48 @@ mod tests {
         dbg!(&rule);
         assert_eq!(residual, "");
     }
+
+    #[test]
+    fn spans() {
+        let exp_str = r#"
+        SCHEMA s;
+          CONSTANT
+            origin : REAL := 0.0;
+          END_CONSTANT;
+          TYPE shape = SELECT (circle, square); END_TYPE;
+          FUNCTION half(x : REAL) : REAL; RETURN (x / 2.0); END_FUNCTION;
+          RULE r FOR (circle); WHERE wr1: SIZEOF(circle) > 0; END_RULE;
+        END_SCHEMA;
+        "#
+        .trim();
+        let (residual, (schema, _remark)) = super::schema_decl(exp_str).finish().unwrap();
+        assert_eq!(residual, "");
+        let text = |span: Span| &exp_str[span.range(exp_str)];
+
+        let constant = &schema.constants[0];
+        assert_eq!(text(constant.span), "origin : REAL := 0.0;");
+        assert_eq!(text(constant.expr.span()), "0.0");
+
+        if let Type::Select { spans, .. } = &schema.types[0].underlying_type {
+            assert_eq!(text(spans[0]), "circle");
+            assert_eq!(text(spans[1]), "square");
+        } else {
+            panic!("Must be select")
+        }
+
+        let function = &schema.functions[0];
+        assert_eq!(
+            text(function.span),
+            "FUNCTION half(x : REAL) : REAL; RETURN (x / 2.0); END_FUNCTION;"
+        );
+
+        let rule = &schema.rules[0];
+        assert_eq!(
+            text(rule.span),
+            "RULE r FOR (circle); WHERE wr1: SIZEOF(circle) > 0; END_RULE;"
+        );
+        let wr1 = &rule.where_clause.rules[0];
+        assert_eq!(text(wr1.span), "wr1: SIZEOF(circle) > 0");
+        assert_eq!(text(wr1.expr.span()), "SIZEOF(circle) > 0");
+    }
 }
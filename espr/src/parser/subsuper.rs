@@ -163,7 +163,7 @@ pub fn total_over(input: &str) -> ParseResult<Vec<String>> {
     tuple((
         tag("TOTAL_OVER"),
         char('('),
-        many1(entity_ref),
+        comma_separated(entity_ref),
         char(')'),
         char(';'),
     ))
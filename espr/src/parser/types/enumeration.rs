@@ -8,22 +8,34 @@ pub fn enumeration_items(input: &str) -> ParseResult<Vec<String>> {
         .parse(input)
 }
 
+/// 212 enumeration_extension = BASED_ON [type_ref] \[ WITH [enumeration_items] \] .
+pub fn enumeration_extension(input: &str) -> ParseResult<(String, Vec<String>)> {
+    let with = tuple((tag("WITH"), enumeration_items)).map(|(_with, items)| items);
+    tuple((tag("BASED_ON"), type_ref, opt(with)))
+        .map(|(_based_on, id, opt)| (id, opt.unwrap_or_default()))
+        .parse(input)
+}
+
 /// 213 enumeration_type = \[ EXTENSIBLE \] ENUMERATION \[ ( OF [enumeration_items] ) | enumeration_extension \] .
 pub fn enumeration_type(input: &str) -> ParseResult<Type> {
-    // FIXME enumeration_extension
+    let items = tuple((tag("OF"), enumeration_items)).map(|(_of, items)| (None, items));
+    let extension = enumeration_extension.map(|(based_on, items)| (Some(based_on), items));
     tuple((
         opt(tag("EXTENSIBLE")),
         tag("ENUMERATION"),
-        tag("OF"),
-        enumeration_items,
+        opt(alt((items, extension))),
     ))
-    .map(|(extensiblility, _start, _of, items)| Type::Enumeration {
-        extensibility: if extensiblility.is_some() {
-            Extensibility::Extensible
-        } else {
-            Extensibility::None
-        },
-        items,
+    .map(|(extensiblility, _start, items)| {
+        let (based_on, items) = items.unwrap_or_default();
+        Type::Enumeration {
+            extensibility: if extensiblility.is_some() {
+                Extensibility::Extensible
+            } else {
+                Extensibility::None
+            },
+            based_on,
+            items,
+        }
     })
     .parse(input)
 }
@@ -43,6 +55,7 @@ mod tests {
             e,
             super::Type::Enumeration {
                 extensibility: super::Extensibility::None,
+                based_on: None,
                 items: vec![
                     "up".to_string(),
                     "down".to_string(),
@@ -64,6 +77,7 @@ mod tests {
             e,
             super::Type::Enumeration {
                 extensibility: super::Extensibility::Extensible,
+                based_on: None,
                 items: vec![
                     "up".to_string(),
                     "down".to_string(),
@@ -80,4 +94,34 @@ mod tests {
         .finish()
         .is_err());
     }
+
+    #[test]
+    fn enumeration_extension() {
+        let (residual, (e, _remark)) = super::enumeration_type("EXTENSIBLE ENUMERATION")
+            .finish()
+            .unwrap();
+        assert_eq!(residual, "");
+        assert_eq!(
+            e,
+            super::Type::Enumeration {
+                extensibility: super::Extensibility::Extensible,
+                based_on: None,
+                items: Vec::new(),
+            }
+        );
+
+        let (residual, (e, _remark)) =
+            super::enumeration_type("ENUMERATION BASED_ON direction WITH (front, back)")
+                .finish()
+                .unwrap();
+        assert_eq!(residual, "");
+        assert_eq!(
+            e,
+            super::Type::Enumeration {
+                extensibility: super::Extensibility::None,
+                based_on: Some("direction".to_string()),
+                items: vec!["front".to_string(), "back".to_string()],
+            }
+        );
+    }
 }
@@ -25,7 +25,7 @@ pub fn underlying_type(input: &str) -> ParseResult<Type> {
 
 /// 327 type_decl = TYPE [type_id] `=` [underlying_type] `;` \[ [where_clause] \] END_TYPE `;` .
 pub fn type_decl(input: &str) -> ParseResult<TypeDecl> {
-    tuple((
+    spanned(tuple((
         tag("TYPE"),
         type_id,
         char('='),
@@ -34,22 +34,26 @@ pub fn type_decl(input: &str) -> ParseResult<TypeDecl> {
         opt(where_clause),
         tag("END_TYPE"),
         char(';'),
-    ))
+    )))
     .map(
         |(
-            _start,
-            type_id,
-            _equal,
-            underlying_type,
-            _semicolon1,
-            where_clause,
-            _end,
-            _semicolon2,
+            (
+                _start,
+                type_id,
+                _equal,
+                underlying_type,
+                _semicolon1,
+                where_clause,
+                _end,
+                _semicolon2,
+            ),
+            span,
         )| {
             TypeDecl {
                 type_id,
                 underlying_type,
                 where_clause,
+                span,
             }
         },
     )
@@ -66,16 +70,19 @@ mod tests {
             .finish()
             .unwrap();
         assert_eq!(residual, "");
-        assert_eq!(
-            ty,
-            super::TypeDecl {
-                type_id: "my_type".to_string(),
-                underlying_type: super::Type::Simple(super::SimpleType::String_ {
-                    width_spec: None
-                }),
-                where_clause: None,
-            }
-        );
+        super::Span::ignored(|| {
+            assert_eq!(
+                ty,
+                super::TypeDecl {
+                    type_id: "my_type".to_string(),
+                    underlying_type: super::Type::Simple(super::SimpleType::String_ {
+                        width_spec: None
+                    }),
+                    where_clause: None,
+                    span: Default::default(),
+                }
+            )
+        });
     }
 
     #[test]
@@ -2,14 +2,14 @@ use super::{super::combinator::*, *};
 use crate::ast::*;
 
 /// 301 select_list = `(` [named_types] { `,` [named_types] } `)` .
-pub fn select_list(input: &str) -> ParseResult<Vec<String>> {
-    tuple((char('('), comma_separated(named_types), char(')')))
+pub fn select_list(input: &str) -> ParseResult<Vec<(String, Span)>> {
+    tuple((char('('), comma_separated(spanned(named_types)), char(')')))
         .map(|(_start, ids, _end)| ids)
         .parse(input)
 }
 
 /// 300 select_extension = BASED_ON [type_ref] \[ WITH [select_list] \] .
-pub fn select_extension(input: &str) -> ParseResult<(String, Vec<String>)> {
+pub fn select_extension(input: &str) -> ParseResult<(String, Vec<(String, Span)>)> {
     let with = tuple((tag("WITH"), select_list)).map(|(_with, list)| list);
     tuple((tag("BASED_ON"), type_ref, opt(with)))
         .map(|(_based_on, id, opt)| (id, opt.unwrap_or_default()))
@@ -18,8 +18,6 @@ pub fn select_extension(input: &str) -> ParseResult<(String, Vec<String>)> {
 
 /// 302 select_type = \[ EXTENSIBLE \[ GENERIC_ENTITY \] \] SELECT \[ [select_list] | [select_extension] \] .
 pub fn select_type(input: &str) -> ParseResult<Type> {
-    // FIXME support select_extension
-
     // `GENERIC_ENTITY` only appears in `select_type` declaration.
     let extensibility = tuple((
         tag("EXTENSIBLE"),
@@ -33,22 +31,27 @@ pub fn select_type(input: &str) -> ParseResult<Type> {
         }
     });
 
+    let list = select_list.map(|types| (None, types));
+    let extension = select_extension.map(|(based_on, types)| (Some(based_on), types));
+
     tuple((
         opt(tuple((extensibility, spaces))),
         tag("SELECT"),
-        select_list,
+        opt(alt((list, extension))),
     ))
-    .map(|(opt, _select, types)| {
-        if let Some((extensibility, _spaces)) = opt {
-            Type::Select {
-                extensibility,
-                types,
-            }
+    .map(|(opt, _select, list)| {
+        let extensibility = if let Some((extensibility, _spaces)) = opt {
+            extensibility
         } else {
-            Type::Select {
-                extensibility: Extensibility::None,
-                types,
-            }
+            Extensibility::None
+        };
+        let (based_on, items) = list.unwrap_or_default();
+        let (types, spans) = items.into_iter().unzip();
+        Type::Select {
+            extensibility,
+            based_on,
+            types,
+            spans,
         }
     })
     .parse(input)
@@ -65,14 +68,50 @@ mod tests {
         assert_eq!(res, "");
         if let Type::Select {
             extensibility,
+            based_on,
             types,
+            ..
         } = s
         {
             assert_eq!(extensibility, Extensibility::None);
+            assert_eq!(based_on, None);
             assert_eq!(types[0], "a");
             assert_eq!(types[1], "b");
         } else {
             panic!()
         }
     }
+
+    #[test]
+    fn select_extension() {
+        let (res, (s, _remarks)) = super::select_type("EXTENSIBLE GENERIC_ENTITY SELECT")
+            .finish()
+            .unwrap();
+        assert_eq!(res, "");
+        assert_eq!(
+            s,
+            Type::Select {
+                extensibility: Extensibility::GenericEntity,
+                based_on: None,
+                types: Vec::new(),
+                spans: Vec::new(),
+            }
+        );
+
+        let (res, (s, _remarks)) = super::select_type("SELECT BASED_ON base WITH (c, d)")
+            .finish()
+            .unwrap();
+        assert_eq!(res, "");
+        Span::ignored(|| {
+            assert_eq!(
+                s,
+                Type::Select {
+                    extensibility: Extensibility::None,
+                    based_on: Some("base".to_string()),
+                    types: vec!["c".to_string(), "d".to_string()],
+                    spans: vec![Span::default(); 2],
+                }
+            )
+        });
+    }
 }
@@ -45,6 +45,11 @@ fn any() {
                 &self.sub2
             }
         }
+        #[doc = " ```text"]
+        #[doc = " ENTITY base SUPERTYPE OF (ONEOF (sub1, sub2));"]
+        #[doc = "   x: REAL;"]
+        #[doc = " END_ENTITY;"]
+        #[doc = " ```"]
         #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
         # [holder (table = Tables)]
         # [holder (field = base)]
@@ -87,6 +92,11 @@ fn any() {
                 }
             }
         }
+        #[doc = " ```text"]
+        #[doc = " ENTITY sub1 SUBTYPE OF (base);"]
+        #[doc = "   y1: REAL;"]
+        #[doc = " END_ENTITY;"]
+        #[doc = " ```"]
         #[derive(
             Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
         )]
@@ -102,6 +112,11 @@ fn any() {
             pub base: Base,
             pub y1: f64,
         }
+        #[doc = " ```text"]
+        #[doc = " ENTITY sub2 SUBTYPE OF (base);"]
+        #[doc = "   y2: REAL;"]
+        #[doc = " END_ENTITY;"]
+        #[doc = " ```"]
         #[derive(
             Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
         )]
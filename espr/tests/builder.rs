@@ -0,0 +1,186 @@
+use espr::{BuildError, Builder};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+fn path(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/load")
+        .join(name)
+}
+
+/// Empty directory for each test to write generated code
+fn out_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("espr-builder-{}", name));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+#[test]
+fn generate() {
+    let out_dir = out_dir("generate");
+    let generated = Builder::new()
+        .source(path("shape.exp"))
+        .include_dir(path("include"))
+        .schema("shape")
+        .out_dir(&out_dir)
+        .generate()
+        .unwrap();
+    assert_eq!(generated, out_dir.join("express.rs"));
+
+    let code = fs::read_to_string(generated).unwrap();
+    assert!(code.starts_with("pub mod shape {\n"));
+    assert!(code.contains("use ruststep::{"));
+    // Schemas interfaced from the selected one are needed
+    assert!(code.contains("pub mod topology {"));
+    assert!(code.contains("pub mod geometry {"));
+
+    let generated = Builder::new()
+        .source(path("shape.exp"))
+        .include_dir(path("include"))
+        .schema("topology")
+        .out_dir(&out_dir)
+        .generate()
+        .unwrap();
+    let code = fs::read_to_string(generated).unwrap();
+    assert!(!code.contains("pub mod shape {"));
+    assert!(code.contains("pub mod topology {"));
+    assert!(code.contains("pub mod geometry {"));
+}
+
+#[test]
+fn source_dir() {
+    let out_dir = out_dir("source_dir");
+    let generated = Builder::new()
+        .source(path("include"))
+        .out_dir(&out_dir)
+        .file_name("include.rs")
+        .generate()
+        .unwrap();
+    let code = fs::read_to_string(generated).unwrap();
+    assert!(code.contains("pub mod topology {"));
+    assert!(code.contains("pub mod geometry {"));
+}
+
+#[test]
+fn errors() {
+    let out_dir = out_dir("errors");
+    let err = Builder::new()
+        .source(path("syntax_error.exp"))
+        .out_dir(&out_dir)
+        .generate()
+        .unwrap_err();
+    match &err {
+        BuildError::Load { diagnostics } => {
+            assert!(
+                diagnostics.contains("syntax_error.exp:2:3"),
+                "{}",
+                diagnostics
+            )
+        }
+        _ => panic!("Unexpected error: {}", err),
+    }
+
+    let err = Builder::new()
+        .source(path("shape.exp"))
+        .include_dir(path("include"))
+        .schema("unknown")
+        .out_dir(&out_dir)
+        .generate()
+        .unwrap_err();
+    assert!(matches!(err, BuildError::SchemaNotFound { name } if name == "unknown"));
+}
+
+#[test]
+fn roots() {
+    let out_dir = out_dir("roots");
+    let generated = Builder::new()
+        .source(path("shape.exp"))
+        .include_dir(path("include"))
+        .root("edge")
+        .out_dir(&out_dir)
+        .generate()
+        .unwrap();
+    let code = fs::read_to_string(generated).unwrap();
+    assert!(code.contains("pub struct Edge {"));
+    // `point` in Geometry is used as `vertex` in topology
+    assert!(code.contains("pub struct Point {"));
+    assert!(!code.contains("pub mod shape"));
+
+    let err = Builder::new()
+        .source(path("include"))
+        .root("face")
+        .out_dir(&out_dir)
+        .generate()
+        .unwrap_err();
+    assert!(matches!(err, BuildError::Subset(_)));
+}
+
+/// Build a crate generating its code by [Builder] in `build.rs`
+#[test]
+fn build_crate() {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("builder-crate");
+    fs::create_dir_all(dir.join("src")).unwrap();
+    let manifest = format!(
+        r#"
+[package]
+name = "builder-crate"
+version = "0.1.0"
+edition = "2018"
+
+[dependencies]
+ruststep = {{ path = "{0}/../ruststep" }}
+ruststep-derive = {{ path = "{0}/../ruststep-derive" }}
+derive-new = "0.5.9"
+serde = {{ version = "1.0.158", features = ["derive"] }}
+
+[build-dependencies]
+espr = {{ path = "{0}" }}
+
+[workspace]
+"#,
+        manifest_dir.display()
+    );
+    fs::write(dir.join("Cargo.toml"), manifest).unwrap();
+    // Use the versions of dependencies resolved for this workspace
+    let _ = fs::copy(manifest_dir.join("../Cargo.lock"), dir.join("Cargo.lock"));
+    let build = format!(
+        r#"
+fn main() {{
+    espr::Builder::new()
+        .source({:?})
+        .include_dir({:?})
+        .generate()
+        .unwrap();
+}}
+"#,
+        path("shape.exp"),
+        path("include")
+    );
+    fs::write(dir.join("build.rs"), build).unwrap();
+    // `SCHEMA Geometry;` is generated as `geometry` module
+    let lib = r#"
+include!(concat!(env!("OUT_DIR"), "/express.rs"));
+
+pub fn edge() -> topology::Edge {
+    let point = geometry::Point { x: 0.0, y: 0.0 };
+    topology::Edge {
+        head: point.clone(),
+        tail: point,
+    }
+}
+"#;
+    fs::write(dir.join("src/lib.rs"), lib).unwrap();
+
+    let status = Command::new(env!("CARGO"))
+        .args(["check", "--quiet"])
+        .current_dir(&dir)
+        .env("CARGO_TARGET_DIR", dir.join("target"))
+        .status()
+        .unwrap();
+    assert!(status.success(), "Failed to build the generated code");
+}
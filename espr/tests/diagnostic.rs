@@ -0,0 +1,131 @@
+use espr::{
+    ast::{LoadError, Source, SyntaxTree},
+    diagnostic::*,
+    ir::IR,
+};
+use std::path::PathBuf;
+
+const EXPRESS: &str = r#"
+SCHEMA test_schema;
+  ENTITY a;
+    x: undefined_a;
+    y: REAL;
+  END_ENTITY;
+
+  ENTITY b SUBTYPE OF (a);
+    z: undefined_b;
+  END_ENTITY;
+
+  TYPE c = undefined_c;
+  END_TYPE;
+END_SCHEMA;
+"#;
+
+#[test]
+fn multiple_semantic_errors() {
+    let st = SyntaxTree::parse(EXPRESS).unwrap();
+    let err = IR::from_syntax_tree(&st).unwrap_err();
+    let files = Files::new(&st);
+    let diagnostics = semantic_error(&files, err);
+    assert_eq!(diagnostics.len(), 3);
+    assert_eq!(
+        render(&files, &diagnostics),
+        r#"error: Not found the Type undefined_a referred in scope test_schema
+  ┌─ <input>:4:5
+  │
+4 │     x: undefined_a;
+  │     ^^^^^^^^^^^^^^^
+
+error: Not found the Type undefined_b referred in scope test_schema
+  ┌─ <input>:9:5
+  │
+9 │     z: undefined_b;
+  │     ^^^^^^^^^^^^^^^
+
+error: Not found the Type undefined_c referred in scope test_schema
+   ┌─ <input>:12:3
+   │  
+12 │ ╭   TYPE c = undefined_c;
+13 │ │   END_TYPE;
+   │ ╰───────────^
+
+"#
+    );
+}
+
+#[test]
+fn empty_generic_entity_select() {
+    let st = SyntaxTree::parse(
+        r#"
+SCHEMA test_schema;
+  TYPE item = EXTENSIBLE GENERIC_ENTITY SELECT;
+  END_TYPE;
+END_SCHEMA;
+"#,
+    )
+    .unwrap();
+    let err = IR::from_syntax_tree(&st).unwrap_err();
+    let files = Files::new(&st);
+    let diagnostics = semantic_error(&files, err);
+    assert_eq!(
+        render(&files, &diagnostics),
+        r#"error: EXTENSIBLE GENERIC_ENTITY SELECT test_schema.item is not extended by any type
+  ┌─ <input>:3:3
+  │  
+3 │ ╭   TYPE item = EXTENSIBLE GENERIC_ENTITY SELECT;
+4 │ │   END_TYPE;
+  │ ╰───────────^
+
+"#
+    );
+}
+
+#[test]
+fn undefined_select_item() {
+    let st = SyntaxTree::parse(
+        r#"
+SCHEMA test_schema;
+  ENTITY circle; END_ENTITY;
+  TYPE shape = SELECT (circle, square);
+  END_TYPE;
+END_SCHEMA;
+"#,
+    )
+    .unwrap();
+    let err = IR::from_syntax_tree(&st).unwrap_err();
+    let files = Files::new(&st);
+    let diagnostics = semantic_error(&files, err);
+    assert_eq!(
+        render(&files, &diagnostics),
+        r#"error: Not found the Type square referred in scope test_schema
+  ┌─ <input>:4:32
+  │
+4 │   TYPE shape = SELECT (circle, square);
+  │                                ^^^^^^
+
+"#
+    );
+}
+
+#[test]
+fn syntax_error() {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/load/syntax_error.exp");
+    let err = SyntaxTree::load(&[path], &[]).unwrap_err();
+    let (files, diagnostic) = load_error(&err);
+    let rendered = render(&files, &[diagnostic]);
+    assert!(rendered.starts_with("error: Syntax error\n"));
+    assert!(rendered.contains("syntax_error.exp:2:3"));
+}
+
+#[test]
+fn syntax_error_without_path() {
+    let err = LoadError::Syntax {
+        input: Source {
+            path: None,
+            text: "SCHEMA s; END_SCHEMA".to_string(),
+            schemas: vec!["s".to_string()],
+        },
+        errors: Vec::new(),
+    };
+    assert_eq!(err.to_string(), "Syntax error in <input>");
+}
@@ -0,0 +1,98 @@
+use espr::{ast::SyntaxTree, diff::*, ir::IR};
+
+const OLD: &str = r#"
+SCHEMA shapes;
+  TYPE side = ENUMERATION OF (left, right); END_TYPE;
+  TYPE shape_select = SELECT (circle, square); END_TYPE;
+  TYPE distance = REAL; END_TYPE;
+  ENTITY shape SUPERTYPE OF (ONEOF (circle, square)); END_ENTITY;
+  ENTITY circle SUBTYPE OF (shape);
+    radius : distance;
+    label : OPTIONAL STRING;
+    side : side;
+  END_ENTITY;
+  ENTITY square SUBTYPE OF (shape); END_ENTITY;
+  ENTITY obsolete; END_ENTITY;
+END_SCHEMA;
+"#;
+
+const NEW: &str = r#"
+SCHEMA shapes_e2;
+  TYPE side = ENUMERATION OF (left, top); END_TYPE;
+  TYPE shape_select = SELECT (circle, square, triangle); END_TYPE;
+  TYPE distance = INTEGER; END_TYPE;
+  ENTITY shape SUPERTYPE OF (ONEOF (circle, square, triangle)); END_ENTITY;
+  ENTITY circle SUBTYPE OF (shape);
+    label : STRING;
+    radius : LIST [1:?] OF distance;
+    centre : OPTIONAL distance;
+  END_ENTITY;
+  ENTITY square; END_ENTITY;
+  ENTITY triangle SUBTYPE OF (shape); END_ENTITY;
+END_SCHEMA;
+"#;
+
+fn legalize(input: &str) -> IR {
+    IR::from_syntax_tree(&SyntaxTree::parse(input).unwrap()).unwrap()
+}
+
+#[test]
+fn diff_editions() {
+    let changes = diff(&legalize(OLD), &legalize(NEW));
+    let report = changes
+        .iter()
+        .map(|change| format!("{:>12}: {}\n", change.severity(), change))
+        .collect::<String>();
+    insta::assert_snapshot!(report, @r###"
+non-breaking: enumeration item top added to shapes_e2.side
+    breaking: enumeration item right removed from shapes_e2.side
+non-breaking: select item triangle added to shapes_e2.shape_select
+    breaking: type shapes_e2.distance changed from REAL to INTEGER
+non-breaking: instantiable subtypes of entity shapes_e2.shape changed from (circle, square) to (circle, square, triangle)
+    breaking: attribute shapes_e2.circle.label made mandatory
+    breaking: attribute shapes_e2.circle.radius changed from distance to LIST [1:?] OF distance
+    breaking: attribute shapes_e2.circle.centre added as OPTIONAL distance
+    breaking: attribute shapes_e2.circle.side removed
+    breaking: attributes of entity shapes_e2.circle reordered from (radius, label) to (label, radius)
+    breaking: supertypes of entity shapes_e2.square changed from (shape) to ()
+non-breaking: entity shapes_e2.triangle added
+    breaking: entity shapes_e2.obsolete removed
+    "###);
+}
+
+#[test]
+fn diff_same() {
+    assert!(diff(&legalize(OLD), &legalize(OLD)).is_empty());
+}
+
+#[test]
+fn diff_schemas() {
+    let old = legalize("SCHEMA a; END_SCHEMA; SCHEMA b; END_SCHEMA;");
+    let new = legalize("SCHEMA b; END_SCHEMA; SCHEMA c; END_SCHEMA;");
+    assert_eq!(
+        diff(&old, &new),
+        vec![
+            Change::SchemaAdded {
+                schema: "c".to_string()
+            },
+            Change::SchemaRemoved {
+                schema: "a".to_string()
+            },
+        ]
+    );
+}
+
+#[test]
+fn json() {
+    let changes = diff(&legalize(OLD), &legalize(NEW));
+    let value = serde_json::to_value(&changes[0]).unwrap();
+    assert_eq!(
+        value,
+        serde_json::json!({
+            "change": "enumeration_item_added",
+            "schema": "shapes_e2",
+            "ty": "side",
+            "item": "top",
+        })
+    );
+}
@@ -0,0 +1,220 @@
+use espr::{ast::SyntaxTree, doc::*, ir::IR};
+use std::path::Path;
+
+const EXPRESS: &str = r#"
+SCHEMA shapes;
+  TYPE side = ENUMERATION OF (left, right); END_TYPE;
+  TYPE shape_select = SELECT (circle, square); END_TYPE;
+  TYPE distance = REAL;
+  WHERE
+    positive : SELF > 0;
+  END_TYPE;
+  ENTITY shape SUPERTYPE OF (ONEOF (circle, square));
+    name : STRING;
+  END_ENTITY;
+  ENTITY circle SUBTYPE OF (shape);
+    radius : distance;
+    sides : OPTIONAL LIST [1:?] OF side;
+  DERIVE
+    diameter : REAL := 2.0 * radius;
+  WHERE
+    wr1 : radius < 100;
+  END_ENTITY;
+  ENTITY square SUBTYPE OF (shape); END_ENTITY;
+  ENTITY drawing;
+    items : SET [1:?] OF shape_select;
+  END_ENTITY;
+END_SCHEMA;
+
+(*"shapes" Shapes in a plane *)
+(*"shapes.circle"
+A circle with its centre at the origin.
+
+Circles are drawn as `shape_select`.
+*)
+(*"circle.radius" Radius of the circle *)
+(*"circle.wr1" Circles must be smaller than the sheet *)
+(*"side.left" Left side of <sheet> *)
+"#;
+
+fn document_of(format: DocFormat) -> Vec<Page> {
+    let st = SyntaxTree::parse(EXPRESS).unwrap();
+    let ir = IR::from_syntax_tree(&st).unwrap();
+    document(&st, &ir, format)
+}
+
+fn page<'a>(pages: &'a [Page], path: &str) -> &'a str {
+    &pages
+        .iter()
+        .find(|page| page.path == Path::new(path))
+        .unwrap()
+        .content
+}
+
+#[test]
+fn pages() {
+    let pages = document_of(DocFormat::Markdown);
+    let paths: Vec<_> = pages
+        .iter()
+        .map(|page| page.path.to_str().unwrap())
+        .collect();
+    assert_eq!(
+        paths,
+        vec![
+            "index.md",
+            "shapes/index.md",
+            "shapes/side.md",
+            "shapes/shape_select.md",
+            "shapes/distance.md",
+            "shapes/shape.md",
+            "shapes/circle.md",
+            "shapes/square.md",
+            "shapes/drawing.md",
+        ]
+    );
+}
+
+#[test]
+fn entity_page() {
+    let pages = document_of(DocFormat::Markdown);
+    insta::assert_snapshot!(page(&pages, "shapes/circle.md"), @r###"
+# ENTITY circle
+
+Schema: [shapes](index.md)
+
+A circle with its centre at the origin.
+
+Circles are drawn as `shape_select`.
+
+## Supertypes
+
+- [shape](../shapes/shape.md)
+
+## Attributes
+
+| Name | Type | Description |
+| --- | --- | --- |
+| radius | [distance](../shapes/distance.md) | Radius of the circle |
+| sides | OPTIONAL LIST [1:?] OF [side](../shapes/side.md) |  |
+
+## Inherited attributes
+
+| Name | Type | From |
+| --- | --- | --- |
+| name | STRING | [shape](../shapes/shape.md) |
+
+## Derived attributes
+
+| Name | Type | Description |
+| --- | --- | --- |
+| diameter | REAL |  |
+
+## WHERE rules
+
+| Label | Expression | Description |
+| --- | --- | --- |
+| wr1 | `radius < 100` | Circles must be smaller than the sheet |
+
+## Referenced by
+
+- [shape_select](../shapes/shape_select.md)
+
+## EXPRESS
+
+```express
+ENTITY circle
+  SUBTYPE OF (shape);
+  radius : distance;
+  sides : OPTIONAL LIST [1:?] OF side;
+DERIVE
+  diameter : REAL := 2 * radius;
+WHERE
+  wr1 : radius < 100;
+END_ENTITY;
+```
+    "###);
+}
+
+#[test]
+fn type_page() {
+    let pages = document_of(DocFormat::Markdown);
+    insta::assert_snapshot!(page(&pages, "shapes/side.md"), @r###"
+# TYPE side
+
+Schema: [shapes](index.md)
+
+## Enumeration items
+
+| Item | Description |
+| --- | --- |
+| left | Left side of &lt;sheet> |
+| right |  |
+
+## Referenced by
+
+- [circle](../shapes/circle.md).sides
+
+## EXPRESS
+
+```express
+TYPE side = ENUMERATION OF (left, right);
+END_TYPE;
+```
+    "###);
+    insta::assert_snapshot!(page(&pages, "shapes/index.md"), @r###"
+# SCHEMA shapes
+
+[Schemas](../index.md)
+
+Shapes in a plane
+
+## Entities
+
+| Name | Description |
+| --- | --- |
+| [circle](../shapes/circle.md) | A circle with its centre at the origin. |
+| [drawing](../shapes/drawing.md) |  |
+| [shape](../shapes/shape.md) |  |
+| [square](../shapes/square.md) |  |
+
+## Types
+
+| Name | Kind | Description |
+| --- | --- | --- |
+| [distance](../shapes/distance.md) | defined |  |
+| [shape_select](../shapes/shape_select.md) | select |  |
+| [side](../shapes/side.md) | enumeration |  |
+    "###);
+}
+
+#[test]
+fn html() {
+    let pages = document_of(DocFormat::Html);
+    let side = page(&pages, "shapes/side.html");
+    assert!(side.starts_with("<!DOCTYPE html>"));
+    assert!(side.contains("<td>left</td><td>Left side of &lt;sheet&gt;</td>"));
+    let circle = page(&pages, "shapes/circle.html");
+    assert!(circle
+        .contains(r#"<td>radius</td><td><a href="../shapes/distance.html">distance</a></td>"#));
+}
+
+#[test]
+fn backticks() {
+    let st = SyntaxTree::parse(
+        r#"
+        SCHEMA quotes;
+          ENTITY label;
+            text : STRING;
+          WHERE
+            wr1 : text <> '```';
+          END_ENTITY;
+        END_SCHEMA;
+        "#,
+    )
+    .unwrap();
+    let ir = IR::from_syntax_tree(&st).unwrap();
+    let pages = document(&st, &ir, DocFormat::Markdown);
+    let label = page(&pages, "quotes/label.md");
+    assert!(label.contains("````express\n"));
+    assert!(label.contains("| wr1 | ````text <> '```'```` |"));
+}
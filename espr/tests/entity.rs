@@ -39,6 +39,12 @@ fn entity() {
                 &self.b
             }
         }
+        #[doc = " ```text"]
+        #[doc = " ENTITY a;"]
+        #[doc = "   x: REAL;"]
+        #[doc = "   y: REAL;"]
+        #[doc = " END_ENTITY;"]
+        #[doc = " ```"]
         #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
         # [holder (table = Tables)]
         # [holder (field = a)]
@@ -47,6 +53,12 @@ fn entity() {
             pub x: f64,
             pub y: f64,
         }
+        #[doc = " ```text"]
+        #[doc = " ENTITY b;"]
+        #[doc = "   z: REAL;"]
+        #[doc = "   a: a;"]
+        #[doc = " END_ENTITY;"]
+        #[doc = " ```"]
         #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
         # [holder (table = Tables)]
         # [holder (field = b)]
@@ -59,3 +71,29 @@ fn entity() {
     }
     "###);
 }
+
+#[test]
+fn generators_disabled() {
+    let st = SyntaxTree::parse(EXPRESS).unwrap();
+    let ir = IR::from_syntax_tree(&st).unwrap();
+    let tt = rustfmt(ir.to_token_stream(CratePrefix::External).to_string());
+    assert!(tt.contains("pub struct B"));
+    assert!(!tt.contains("pub fn builder"));
+    assert!(!tt.contains("pub mod visit"));
+    assert!(!tt.contains("SCHEMA_INFO"));
+
+    let st = SyntaxTree::parse(
+        r#"
+        SCHEMA test_schema;
+          ENTITY base; END_ENTITY;
+          ENTITY sub SUBTYPE OF (base); END_ENTITY;
+        END_SCHEMA;
+        "#,
+    )
+    .unwrap();
+    let ir = IR::from_syntax_tree(&st).unwrap();
+    let tt = rustfmt(ir.to_token_stream(CratePrefix::External).to_string());
+    assert!(tt.contains("pub enum BaseAny"));
+    assert!(!tt.contains("downcast_ref"));
+    assert!(!tt.contains("base_any_iter"));
+}
@@ -0,0 +1,271 @@
+use espr::ast::{Span, SyntaxTree};
+
+const EXPRESS: &str = r#"
+SCHEMA geometry;
+  USE FROM support_resource_schema (label, text AS description);
+  REFERENCE FROM measure_schema;
+  CONSTANT
+    origin_index : INTEGER := 1;
+  END_CONSTANT;
+
+  TYPE positive_length = REAL; WHERE wr1: SELF > 0; END_TYPE;
+  TYPE name = STRING(32) FIXED; END_TYPE;
+  TYPE side = ENUMERATION OF (left, right); END_TYPE;
+  TYPE more_side = EXTENSIBLE ENUMERATION BASED_ON side WITH (top, bottom); END_TYPE;
+  TYPE shape_select = EXTENSIBLE GENERIC_ENTITY SELECT (cartesian_point, circle, polyline_with_a_long_name, trimmed_curve_with_a_long_name); END_TYPE;
+  TYPE coordinates = ARRAY [1:3] OF OPTIONAL UNIQUE REAL; END_TYPE;
+
+  ENTITY point ABSTRACT SUPERTYPE OF (ONEOF (cartesian_point, polar_point) ANDOR (named AND (a ANDOR b)));
+  END_ENTITY;
+
+  ENTITY cartesian_point SUBTYPE OF (point, named);
+    x, y : REAL;
+    z : OPTIONAL REAL;
+    tags : LIST [0:?] OF UNIQUE label;
+  DERIVE
+    norm : REAL := SQRT(x ** 2 + y ** 2 + NVL(z, 0.0) ** 2);
+    SELF\named.id RENAMED code : STRING := 'P' + FORMAT(x, '5.2F');
+  INVERSE
+    used_in : SET [1:?] OF polyline FOR points;
+    owner : BAG OF model FOR model.items;
+  UNIQUE
+    ur1 : x, y;
+    SELF\named.id;
+  WHERE
+    wr1 : -(x - y) * 2 / (x + 1) >= -x ** -2;
+    NOT (x < 0) OR (y <> 0) XOR (z = ?);
+    wr3 : {0 <= x < 10} AND ('a' LIKE 'b') AND (SIZEOF(QUERY(t <* tags | t IN ['a', 'b' : 2])) = 0);
+  END_ENTITY;
+
+  ENTITY named ABSTRACT;
+    id : name;
+  END_ENTITY;
+
+  SUBTYPE_CONSTRAINT separate FOR point;
+    ABSTRACT SUPERTYPE;
+    TOTAL_OVER (cartesian_point, polar_point);
+    ONEOF (cartesian_point, polar_point);
+  END_SUBTYPE_CONSTRAINT;
+
+  FUNCTION distance(p : cartesian_point; q : AGGREGATE:t OF GENERIC:u) : REAL;
+    TYPE local_type = INTEGER; END_TYPE;
+    CONSTANT
+      eps : REAL := 1.0e-6;
+    END_CONSTANT;
+    LOCAL
+      d : REAL := 0.0;
+      i : INTEGER;
+    END_LOCAL;
+    REPEAT i := 1 TO HIINDEX(q) BY 2 WHILE d < 1.0 UNTIL d > eps;
+      d := d + q[i] - q[i:i + 1][1];
+      IF (d > 10) THEN ESCAPE; ELSE SKIP; END_IF;
+    END_REPEAT;
+    CASE i OF
+      1, 2 : d := PI * CONST_E;
+      3 : BEGIN ; RETURN; END;
+      OTHERWISE : d := p.x;
+    END_CASE;
+    ALIAS px FOR p.x;
+      px := p\named.id;
+    END_ALIAS;
+    RETURN (d);
+  END_FUNCTION;
+
+  PROCEDURE normalize(VAR p : cartesian_point; s : GENERIC_ENTITY);
+    INSERT(p, s, 1);
+    normalize(p, s);
+  END_PROCEDURE;
+
+  RULE unique_names FOR (named, cartesian_point);
+    LOCAL
+      n : INTEGER := SIZEOF(named);
+    END_LOCAL;
+  WHERE
+    wr1 : n = SIZEOF(cartesian_point) * 2 - 1;
+  END_RULE;
+END_SCHEMA;
+
+(* multi-line
+   remark *)
+-- "geometry.point" tail remark
+"#;
+
+/// Printed EXPRESS is parsed into the same AST except for spans, and printed again to the same string
+fn round_trip(input: &str) -> String {
+    let st = SyntaxTree::parse(input).unwrap();
+    let printed = st.to_string();
+    let reparsed = SyntaxTree::parse(&printed).unwrap();
+    Span::ignored(|| {
+        assert_eq!(st.schemas, reparsed.schemas);
+        assert_eq!(st.remarks, reparsed.remarks);
+    });
+    assert_eq!(printed, reparsed.to_string());
+    printed
+}
+
+#[test]
+fn format() {
+    let printed = round_trip(EXPRESS);
+    insta::assert_snapshot!(printed, @r###"
+SCHEMA geometry;
+
+  USE FROM support_resource_schema (label, text AS description);
+  REFERENCE FROM measure_schema;
+
+  CONSTANT
+    origin_index : INTEGER := 1;
+  END_CONSTANT;
+
+  TYPE positive_length = REAL;
+  WHERE
+    wr1 : SELF > 0;
+  END_TYPE;
+
+  TYPE name = STRING(32) FIXED;
+  END_TYPE;
+
+  TYPE side = ENUMERATION OF (left, right);
+  END_TYPE;
+
+  TYPE more_side = EXTENSIBLE ENUMERATION BASED_ON side WITH (top, bottom);
+  END_TYPE;
+
+  TYPE shape_select = EXTENSIBLE GENERIC_ENTITY SELECT
+    (cartesian_point,
+     circle,
+     polyline_with_a_long_name,
+     trimmed_curve_with_a_long_name);
+  END_TYPE;
+
+  TYPE coordinates = ARRAY [1:3] OF OPTIONAL UNIQUE REAL;
+  END_TYPE;
+
+  ENTITY point
+    ABSTRACT SUPERTYPE OF (ONEOF (cartesian_point, polar_point) ANDOR named AND (a ANDOR b));
+  END_ENTITY;
+
+  ENTITY cartesian_point
+    SUBTYPE OF (point, named);
+    x : REAL;
+    y : REAL;
+    z : OPTIONAL REAL;
+    tags : LIST [0:?] OF UNIQUE label;
+  DERIVE
+    norm : REAL := SQRT(x ** 2 + y ** 2 + NVL(z, 0) ** 2);
+    SELF\named.id RENAMED code : STRING := 'P' + FORMAT(x, '5.2F');
+  INVERSE
+    used_in : SET [1:?] OF polyline FOR points;
+    owner : BAG OF model FOR model.items;
+  UNIQUE
+    ur1 : x, y;
+    SELF\named.id;
+  WHERE
+    wr1 : -(x - y) * 2 / (x + 1) >= -x ** -2;
+    NOT (x < 0) OR (y <> 0) XOR (z = ?);
+    wr3 : {0 <= x < 10} AND ('a' LIKE 'b') AND (SIZEOF(QUERY(t <* tags | t IN ['a', 'b' : 2])) = 0);
+  END_ENTITY;
+
+  ENTITY named
+    ABSTRACT;
+    id : name;
+  END_ENTITY;
+
+  SUBTYPE_CONSTRAINT separate FOR point;
+    ABSTRACT SUPERTYPE;
+    TOTAL_OVER (cartesian_point, polar_point);
+    ONEOF (cartesian_point, polar_point);
+  END_SUBTYPE_CONSTRAINT;
+
+  FUNCTION distance(p : cartesian_point; q : AGGREGATE:t OF GENERIC:u) : REAL;
+    TYPE local_type = INTEGER;
+    END_TYPE;
+    CONSTANT
+      eps : REAL := 1.0e-6;
+    END_CONSTANT;
+    LOCAL
+      d : REAL := 0;
+      i : INTEGER;
+    END_LOCAL;
+    REPEAT i := 1 TO HIINDEX(q) BY 2 WHILE d < 1 UNTIL d > eps;
+      d := d + q[i] - q[i:i + 1][1];
+      IF d > 10 THEN
+        ESCAPE;
+      ELSE
+        SKIP;
+      END_IF;
+    END_REPEAT;
+    CASE i OF
+      1, 2 :
+        d := PI * CONST_E;
+      3 :
+        BEGIN
+          ;
+          RETURN;
+        END;
+      OTHERWISE :
+        d := p.x;
+    END_CASE;
+    ALIAS px FOR p.x;
+      px := p\named.id;
+    END_ALIAS;
+    RETURN (d);
+  END_FUNCTION;
+
+  PROCEDURE normalize(VAR p : cartesian_point; s : GENERIC_ENTITY);
+    INSERT(p, s, 1);
+    normalize(p, s);
+  END_PROCEDURE;
+
+  RULE unique_names FOR (named, cartesian_point);
+    LOCAL
+      n : INTEGER := SIZEOF(named);
+    END_LOCAL;
+  WHERE
+    wr1 : n = SIZEOF(cartesian_point) * 2 - 1;
+  END_RULE;
+
+END_SCHEMA;
+
+(* multi-line
+   remark *)
+-- "geometry.point" tail remark
+    "###);
+}
+
+#[test]
+fn format_ap201() {
+    let input = std::fs::read_to_string(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../schemas/APs/10303-201-aim-long.exp"
+    ))
+    .unwrap();
+    round_trip(&input);
+}
+
+#[test]
+fn format_literals() {
+    let printed = round_trip(
+        r#"
+        SCHEMA literals;
+          CONSTANT
+            quoted : STRING := 'it''s';
+            large : REAL := 1.5e20;
+            small : REAL := 2.0e-7;
+            len : INTEGER := LENGTH('''') + HIINDEX([1, 2]);
+          END_CONSTANT;
+        END_SCHEMA;
+        "#,
+    );
+    insta::assert_snapshot!(printed, @r###"
+SCHEMA literals;
+
+  CONSTANT
+    quoted : STRING := 'it''s';
+    large : REAL := 1.5e20;
+    small : REAL := 2.0e-7;
+    len : INTEGER := LENGTH('''') + HIINDEX([1, 2]);
+  END_CONSTANT;
+
+END_SCHEMA;
+    "###);
+}
@@ -0,0 +1,269 @@
+use espr::{ast::SyntaxTree, codegen::rust::*, ir::IR};
+
+const EXPRESS: &str = r#"
+SCHEMA test_schema;
+  ENTITY base SUPERTYPE OF (ONEOF (sub));
+    name: STRING;
+  END_ENTITY;
+  ENTITY sub SUBTYPE OF (base);
+    x: REAL;
+  END_ENTITY;
+END_SCHEMA;
+"#;
+
+/// Items generated only by `generators`, i.e. the lines not in the default output
+fn generated_by(generators: Generators) -> String {
+    let st = SyntaxTree::parse(EXPRESS).unwrap();
+    let ir = IR::from_syntax_tree(&st).unwrap();
+    let default = rustfmt(ir.to_token_stream(CratePrefix::External).to_string());
+    let tt = rustfmt(
+        ir.to_token_stream_with(CratePrefix::External, generators)
+            .to_string(),
+    );
+    let mut default = default.lines().peekable();
+    let mut lines = Vec::new();
+    for line in tt.lines() {
+        if default.peek() == Some(&line) {
+            default.next();
+        } else {
+            lines.push(line);
+        }
+    }
+    lines.join("\n")
+}
+
+#[test]
+fn builder() {
+    let tt = generated_by(Generators {
+        builder: true,
+        ..Default::default()
+    });
+    insta::assert_snapshot!(tt, @r###"
+    #[doc = " Builder of [Base] created by [Base::builder]"]
+    #[derive(Debug, Clone, Default)]
+    pub struct BaseBuilder {
+        name: Option<String>,
+    }
+    impl Base {
+        #[doc = " Start to build [Base] with flattened attributes of supertypes"]
+        pub fn builder() -> BaseBuilder {
+            BaseBuilder::default()
+        }
+    }
+    impl BaseBuilder {
+        #[doc = " Set `name` attribute of `base`"]
+        pub fn name(mut self, value: impl Into<String>) -> Self {
+            self.name = Some(value.into());
+            self
+        }
+        #[doc = r" Build the entity, or returns an error if a required attribute is not set"]
+        pub fn build(self) -> ::ruststep::error::Result<Base> {
+            let BaseBuilder { name } = self;
+            let name = name.ok_or_else(|| ::ruststep::error::Error::MissingAttribute {
+                entity: "base".to_string(),
+                attribute: "name".to_string(),
+            })?;
+            Ok(Base { name })
+        }
+    }
+    #[doc = " Builder of [Sub] created by [Sub::builder]"]
+    #[derive(Debug, Clone, Default)]
+    pub struct SubBuilder {
+        name: Option<String>,
+        x: Option<f64>,
+    }
+    impl Sub {
+        #[doc = " Start to build [Sub] with flattened attributes of supertypes"]
+        pub fn builder() -> SubBuilder {
+            SubBuilder::default()
+        }
+    }
+    impl SubBuilder {
+        #[doc = " Set `name` attribute of `base`"]
+        pub fn name(mut self, value: impl Into<String>) -> Self {
+            self.name = Some(value.into());
+            self
+        }
+        #[doc = " Set `x` attribute of `sub`"]
+        pub fn x(mut self, value: impl Into<f64>) -> Self {
+            self.x = Some(value.into());
+            self
+        }
+        #[doc = r" Build the entity, or returns an error if a required attribute is not set"]
+        pub fn build(self) -> ::ruststep::error::Result<Sub> {
+            let SubBuilder { name, x } = self;
+            let name = name.ok_or_else(|| ::ruststep::error::Error::MissingAttribute {
+                entity: "sub".to_string(),
+                attribute: "name".to_string(),
+            })?;
+            let x = x.ok_or_else(|| ::ruststep::error::Error::MissingAttribute {
+                entity: "sub".to_string(),
+                attribute: "x".to_string(),
+            })?;
+            Ok(Sub {
+                base: Base { name },
+                x,
+            })
+        }
+    }
+    "###);
+}
+
+#[test]
+fn visit() {
+    let tt = generated_by(Generators {
+        visit: true,
+        ..Default::default()
+    });
+    insta::assert_snapshot!(tt, @r###"
+    pub mod visit {
+        use super::*;
+        #[doc = " Visitor borrowing the values of entities and types in this schema"]
+        pub trait Visit<'ast> {
+            fn visit_base(&mut self, node: &'ast Base) {
+                visit_base(self, node)
+            }
+            fn visit_base_any(&mut self, node: &'ast BaseAny) {
+                visit_base_any(self, node)
+            }
+            fn visit_sub(&mut self, node: &'ast Sub) {
+                visit_sub(self, node)
+            }
+        }
+        pub fn visit_base<'ast, V: Visit<'ast> + ?Sized>(_v: &mut V, _node: &'ast Base) {}
+        pub fn visit_base_any<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast BaseAny) {
+            match node {
+                BaseAny::Base(x) => v.visit_base(x),
+                BaseAny::Sub(x) => v.visit_sub(x),
+            }
+        }
+        pub fn visit_sub<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Sub) {
+            v.visit_base(&node.base);
+        }
+    }
+    pub mod visit_mut {
+        use super::*;
+        #[doc = " Visitor mutating the values of entities and types in this schema"]
+        pub trait VisitMut {
+            fn visit_base_mut(&mut self, node: &mut Base) {
+                visit_base_mut(self, node)
+            }
+            fn visit_base_any_mut(&mut self, node: &mut BaseAny) {
+                visit_base_any_mut(self, node)
+            }
+            fn visit_sub_mut(&mut self, node: &mut Sub) {
+                visit_sub_mut(self, node)
+            }
+        }
+        pub fn visit_base_mut<V: VisitMut + ?Sized>(_v: &mut V, _node: &mut Base) {}
+        pub fn visit_base_any_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut BaseAny) {
+            match node {
+                BaseAny::Base(x) => v.visit_base_mut(x),
+                BaseAny::Sub(x) => v.visit_sub_mut(x),
+            }
+        }
+        pub fn visit_sub_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Sub) {
+            v.visit_base_mut(&mut node.base);
+        }
+    }
+    "###);
+}
+
+#[test]
+fn reflection() {
+    let tt = generated_by(Generators {
+        reflection: true,
+        ..Default::default()
+    });
+    insta::assert_snapshot!(tt, @r###"
+    #[doc = " Entities and types declared in `test_schema` schema"]
+    pub static SCHEMA_INFO: ::ruststep::reflection::SchemaInfo =
+        ::ruststep::reflection::SchemaInfo {
+            name: "test_schema",
+            entities: &[
+                ::ruststep::reflection::EntityInfo {
+                    name: "base",
+                    attributes: &[::ruststep::reflection::AttributeInfo {
+                        name: "name",
+                        ty: ::ruststep::reflection::AttributeType::Simple("STRING"),
+                        optional: false,
+                        redeclared_as_derived: false,
+                    }],
+                    supertypes: &[],
+                    subtypes: &["sub"],
+                    subtype_constraint: Some(::ruststep::reflection::SubtypeConstraint::OneOf(&[
+                        ::ruststep::reflection::SubtypeConstraint::Reference("sub"),
+                    ])),
+                },
+                ::ruststep::reflection::EntityInfo {
+                    name: "sub",
+                    attributes: &[::ruststep::reflection::AttributeInfo {
+                        name: "x",
+                        ty: ::ruststep::reflection::AttributeType::Simple("REAL"),
+                        optional: false,
+                        redeclared_as_derived: false,
+                    }],
+                    supertypes: &["base"],
+                    subtypes: &[],
+                    subtype_constraint: None,
+                },
+            ],
+            types: &[],
+        };
+    #[doc = r" Look up an entity in [SCHEMA_INFO] by name case-insensitively"]
+    pub fn entity_info(name: &str) -> Option<&'static ::ruststep::reflection::EntityInfo> {
+        SCHEMA_INFO.entity(name)
+    }
+    "###);
+}
+
+#[test]
+fn downcast() {
+    let tt = generated_by(Generators {
+        downcast: true,
+        ..Default::default()
+    });
+    insta::assert_snapshot!(tt, @r###"
+    impl BaseAny {
+        #[doc = r" Reference to the concrete entity if it is `T`, following nested `Any` enums of subtypes"]
+        pub fn downcast_ref<T: 'static>(&self) -> Option<&T> {
+            match self {
+                BaseAny::Base(x) => (x.as_ref() as &dyn::std::any::Any).downcast_ref(),
+                BaseAny::Sub(x) => (x.as_ref() as &dyn::std::any::Any).downcast_ref(),
+            }
+        }
+        #[doc = r" Check if the concrete entity is `T`"]
+        pub fn is<T: 'static>(&self) -> bool {
+            self.downcast_ref::<T>().is_some()
+        }
+    }
+    impl ::std::convert::TryFrom<BaseAny> for Base {
+        type Error = BaseAny;
+        fn try_from(any: BaseAny) -> ::std::result::Result<Self, BaseAny> {
+            match any {
+                BaseAny::Base(x) => Ok(*x),
+                _ => Err(any),
+            }
+        }
+    }
+    impl ::std::convert::TryFrom<BaseAny> for Sub {
+        type Error = BaseAny;
+        fn try_from(any: BaseAny) -> ::std::result::Result<Self, BaseAny> {
+            match any {
+                BaseAny::Sub(x) => Ok(*x),
+                _ => Err(any),
+            }
+        }
+    }
+    impl Tables {
+        #[doc = " Every instance of `base` and its subtypes as the concrete entity wrapped in [BaseAny]"]
+        #[doc = ""]
+        #[doc = " Shorthand of `EntityTable::<BaseAnyHolder>::owned_iter`, which yields the instances table by table, i.e. not in the order of their ids."]
+        pub fn base_any_iter(
+            &self,
+        ) -> impl Iterator<Item = ::ruststep::error::Result<BaseAny>> + '_ {
+            ::ruststep::tables::EntityTable::<::ruststep::as_holder!(BaseAny)>::owned_iter(self)
+        }
+    }
+    "###);
+}
@@ -0,0 +1,132 @@
+use espr::{ast::SyntaxTree, codegen::dot::*, ir::IR};
+
+const EXPRESS: &str = r#"
+SCHEMA shapes;
+  TYPE side = ENUMERATION OF (left, right); END_TYPE;
+  TYPE shape_select = SELECT (circle, square); END_TYPE;
+  TYPE distance = REAL; END_TYPE;
+  ENTITY shape SUPERTYPE OF (ONEOF (circle, square)); END_ENTITY;
+  ENTITY circle SUBTYPE OF (shape);
+    radius : distance;
+    label : OPTIONAL STRING;
+    sides : LIST [1:?] OF side;
+  DERIVE
+    diameter : REAL := 2.0 * radius;
+  END_ENTITY;
+  ENTITY square SUBTYPE OF (shape); END_ENTITY;
+  ENTITY drawing;
+    items : SET [1:?] OF shape_select;
+  END_ENTITY;
+END_SCHEMA;
+"#;
+
+fn legalize(input: &str) -> IR {
+    IR::from_syntax_tree(&SyntaxTree::parse(input).unwrap()).unwrap()
+}
+
+#[test]
+fn express_g_all() {
+    let pages = express_g(&legalize(EXPRESS), &GraphOptions::default()).unwrap();
+    assert_eq!(pages.len(), 1);
+    insta::assert_snapshot!(pages[0], @r###"
+digraph page_1 {
+  node [shape=box];
+  edge [arrowhead=odot];
+  "shapes.side" [label="side|", shape=record, style=dashed];
+  "shapes.shape_select" [label="|shape_select", shape=record, style=dashed];
+  "shapes.distance" [label="distance", style=dashed];
+  "shapes.shape" [label="shape"];
+  "shapes.circle" [label="circle"];
+  "shapes.square" [label="square"];
+  "shapes.drawing" [label="drawing"];
+  "REAL" [label="REAL|", shape=record];
+  "STRING" [label="STRING|", shape=record];
+  "shapes.shape_select" -> "shapes.circle";
+  "shapes.shape_select" -> "shapes.square";
+  "shapes.distance" -> "REAL";
+  "shapes.shape" -> "shapes.circle" [penwidth=3];
+  "shapes.circle" -> "shapes.distance" [label="radius"];
+  "shapes.circle" -> "STRING" [label="label", style=dashed];
+  "shapes.circle" -> "shapes.side" [label="sides L[1:?]"];
+  "shapes.circle" -> "REAL" [label="(DER) diameter"];
+  "shapes.shape" -> "shapes.square" [penwidth=3];
+  "shapes.drawing" -> "shapes.shape_select" [label="items S[1:?]"];
+}
+    "###);
+}
+
+#[test]
+fn express_g_focus() {
+    let options = GraphOptions {
+        focus: Some("square".to_string()),
+        ..Default::default()
+    };
+    let pages = express_g(&legalize(EXPRESS), &options).unwrap();
+    insta::assert_snapshot!(pages[0], @r###"
+digraph page_1 {
+  node [shape=box];
+  edge [arrowhead=odot];
+  "shapes.square" [label="square"];
+  "shapes.shape_select" [label="|shape_select", shape=record, style=dashed];
+  "shapes.shape" [label="shape"];
+  "shapes.shape_select" -> "shapes.square";
+  "shapes.shape" -> "shapes.square" [penwidth=3];
+}
+    "###);
+
+    let options = GraphOptions {
+        focus: Some("shapes.triangle".to_string()),
+        ..Default::default()
+    };
+    match express_g(&legalize(EXPRESS), &options) {
+        Err(GraphError::FocusNotFound { name }) => assert_eq!(name, "shapes.triangle"),
+        result => panic!("Unexpected result: {:?}", result),
+    }
+}
+
+#[test]
+fn express_g_pages() {
+    let options = GraphOptions {
+        page_size: Some(4),
+        ..Default::default()
+    };
+    let pages = express_g(&legalize(EXPRESS), &options).unwrap();
+    assert_eq!(pages.len(), 2);
+    insta::assert_snapshot!(pages[1], @r###"
+digraph page_2 {
+  node [shape=box];
+  edge [arrowhead=odot];
+  "shapes.circle" [label="circle"];
+  "shapes.square" [label="square"];
+  "shapes.drawing" [label="drawing"];
+  "shapes.distance@1" [label="1, distance", style=rounded];
+  "STRING" [label="STRING|", shape=record];
+  "shapes.side@1" [label="1, side", style=rounded];
+  "REAL" [label="REAL|", shape=record];
+  "shapes.shape_select@1" [label="1, shape_select", style=rounded];
+  "shapes.circle" -> "shapes.distance@1" [label="radius"];
+  "shapes.circle" -> "STRING" [label="label", style=dashed];
+  "shapes.circle" -> "shapes.side@1" [label="sides L[1:?]"];
+  "shapes.circle" -> "REAL" [label="(DER) diameter"];
+  "shapes.drawing" -> "shapes.shape_select@1" [label="items S[1:?]"];
+}
+    "###);
+}
+
+#[test]
+fn express_g_missing() {
+    // Lines to declarations not in IR are skipped
+    let mut ir = legalize(EXPRESS);
+    ir.schemas[0].types.retain(|ty| ty.id() != "distance");
+    for options in [
+        GraphOptions::default(),
+        GraphOptions {
+            focus: Some("circle".to_string()),
+            ..Default::default()
+        },
+    ] {
+        let pages = express_g(&ir, &options).unwrap();
+        assert!(!pages[0].contains("distance"));
+        assert!(pages[0].contains(r#""shapes.circle" -> "shapes.side""#));
+    }
+}
@@ -0,0 +1,169 @@
+use espr::{ast::SyntaxTree, codegen::json_schema::*, ir::IR};
+use serde_json::{json, Value};
+
+const EXPRESS: &str = r#"
+SCHEMA shapes;
+  TYPE color = ENUMERATION OF (red, green); END_TYPE;
+  TYPE label = STRING(8) FIXED; END_TYPE;
+  TYPE item = SELECT (point, label); END_TYPE;
+
+  (*"shapes.point" A point in 2D *)
+  ENTITY point;
+    x : REAL;
+    tag : OPTIONAL label;
+  END_ENTITY;
+
+  ENTITY shape;
+    fill : color;
+    corners : ARRAY [1:4] OF OPTIONAL point;
+    items : SET [0:?] OF item;
+    path : LIST [2:?] OF UNIQUE point;
+  END_ENTITY;
+
+  ENTITY circle SUBTYPE OF (shape);
+    visible : LOGICAL;
+  END_ENTITY;
+END_SCHEMA;
+"#;
+
+fn schema() -> Value {
+    json_schema(&IR::from_syntax_tree(&SyntaxTree::parse(EXPRESS).unwrap()).unwrap())
+}
+
+#[test]
+fn document() {
+    let schema = schema();
+    assert_eq!(schema["$schema"], DIALECT);
+    assert_eq!(schema["title"], "shapes");
+    let defs: Vec<_> = schema["$defs"].as_object().unwrap().keys().collect();
+    assert_eq!(
+        defs,
+        [
+            "shapes.circle",
+            "shapes.color",
+            "shapes.item",
+            "shapes.label",
+            "shapes.point",
+            "shapes.shape"
+        ]
+    );
+}
+
+#[test]
+fn types() {
+    let schema = schema();
+    assert_eq!(
+        schema["$defs"]["shapes.color"],
+        json!({ "type": "string", "enum": ["red", "green"] })
+    );
+    assert_eq!(
+        schema["$defs"]["shapes.label"],
+        json!({ "type": "string", "maxLength": 8, "minLength": 8 })
+    );
+    assert_eq!(
+        schema["$defs"]["shapes.item"],
+        json!({ "anyOf": [{ "$ref": "#/$defs/shapes.point" }, { "$ref": "#/$defs/shapes.label" }] })
+    );
+}
+
+#[test]
+fn entities() {
+    let schema = schema();
+    assert_eq!(
+        schema["$defs"]["shapes.point"],
+        json!({
+            "type": "object",
+            "description": "A point in 2D",
+            "properties": {
+                "x": { "type": "number" },
+                "tag": { "$ref": "#/$defs/shapes.label" },
+            },
+            "required": ["x"],
+        })
+    );
+    assert_eq!(
+        schema["$defs"]["shapes.shape"]["properties"],
+        json!({
+            "fill": { "$ref": "#/$defs/shapes.color" },
+            "corners": {
+                "type": "array",
+                "items": { "anyOf": [{ "$ref": "#/$defs/shapes.point" }, { "type": "null" }] },
+                "minItems": 4,
+                "maxItems": 4,
+            },
+            "items": {
+                "type": "array",
+                "items": { "$ref": "#/$defs/shapes.item" },
+                "uniqueItems": true,
+            },
+            "path": {
+                "type": "array",
+                "items": { "$ref": "#/$defs/shapes.point" },
+                "minItems": 2,
+                "uniqueItems": true,
+            },
+        })
+    );
+    assert_eq!(
+        schema["$defs"]["shapes.circle"],
+        json!({
+            "allOf": [
+                { "$ref": "#/$defs/shapes.shape" },
+                {
+                    "type": "object",
+                    "properties": { "visible": { "type": ["boolean", "null"] } },
+                    "required": ["visible"],
+                },
+            ]
+        })
+    );
+}
+
+#[test]
+fn schemas() {
+    let st = SyntaxTree::parse(
+        r#"
+        SCHEMA metric;
+          ENTITY point;
+            x : REAL;
+          END_ENTITY;
+        END_SCHEMA;
+
+        SCHEMA grid;
+          ENTITY point;
+            x : INTEGER;
+          END_ENTITY;
+          ENTITY segment;
+            start : point;
+            ratio : REAL;
+          END_ENTITY;
+          ENTITY unit_segment SUBTYPE OF (segment);
+          DERIVE
+            SELF\segment.ratio : REAL := 1.0;
+          END_ENTITY;
+        END_SCHEMA;
+        "#,
+    )
+    .unwrap();
+    let schema = json_schema(&IR::from_syntax_tree(&st).unwrap());
+    assert_eq!(
+        schema["$defs"]["metric.point"]["properties"]["x"],
+        json!({ "type": "number" })
+    );
+    assert_eq!(
+        schema["$defs"]["grid.point"]["properties"]["x"],
+        json!({ "type": "integer" })
+    );
+    // `ratio` is not given in `unit_segment`
+    assert_eq!(
+        schema["$defs"]["grid.segment"],
+        json!({
+            "type": "object",
+            "properties": {
+                "start": { "$ref": "#/$defs/grid.point" },
+                "ratio": { "type": "number" },
+            },
+            "required": ["start"],
+        })
+    );
+}
@@ -51,6 +51,10 @@ fn list() {
                 &self.d
             }
         }
+        #[doc = " ```text"]
+        #[doc = " TYPE c = LIST [0:?] OF REAL;"]
+        #[doc = " END_TYPE;"]
+        #[doc = " ```"]
         #[derive(
             Clone, Debug, PartialEq, AsRef, Deref, DerefMut, Into, From, :: ruststep_derive :: Holder,
         )]
@@ -58,6 +62,10 @@ fn list() {
         # [holder (field = c)]
         #[holder(generate_deserialize)]
         pub struct C(#[holder(use_place_holder)] pub Vec<f64>);
+        #[doc = " ```text"]
+        #[doc = " TYPE d = LIST [0:?] OF a;"]
+        #[doc = " END_TYPE;"]
+        #[doc = " ```"]
         #[derive(
             Clone, Debug, PartialEq, AsRef, Deref, DerefMut, Into, From, :: ruststep_derive :: Holder,
         )]
@@ -65,6 +73,11 @@ fn list() {
         # [holder (field = d)]
         #[holder(generate_deserialize)]
         pub struct D(#[holder(use_place_holder)] pub Vec<A>);
+        #[doc = " ```text"]
+        #[doc = " ENTITY a;"]
+        #[doc = "   x: LIST [0:?] OF REAL;"]
+        #[doc = " END_ENTITY;"]
+        #[doc = " ```"]
         #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
         # [holder (table = Tables)]
         # [holder (field = a)]
@@ -72,6 +85,11 @@ fn list() {
         pub struct A {
             pub x: Vec<f64>,
         }
+        #[doc = " ```text"]
+        #[doc = " ENTITY b;"]
+        #[doc = "   a: LIST [0:?] OF a;"]
+        #[doc = " END_ENTITY;"]
+        #[doc = " ```"]
         #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
         # [holder (table = Tables)]
         # [holder (field = b)]
@@ -0,0 +1,73 @@
+use espr::{
+    ast::{LoadError, SyntaxTree},
+    codegen::rust::CratePrefix,
+    ir::IR,
+};
+use std::{fs, path::PathBuf};
+
+fn path(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/load")
+        .join(name)
+}
+
+#[test]
+fn load_interfaced_schemas() {
+    let st = SyntaxTree::load(&[path("shape.exp")], &[path("include")]).unwrap();
+    let names: Vec<_> = st
+        .schemas
+        .iter()
+        .map(|schema| schema.name.as_str())
+        .collect();
+    assert_eq!(names, vec!["shape", "topology", "Geometry"]);
+
+    let ir = IR::from_syntax_tree(&st).unwrap();
+    assert_eq!(ir.schemas.len(), 3);
+
+    // Interfaced entities are imported from the modules of the schemas declaring them,
+    // see ruststep/tests/interface.rs for the compiled code
+    let code = ir.to_token_stream(CratePrefix::External).to_string();
+    assert!(code.contains("use super :: topology :: { self , Edge , EdgeHolder } ;"));
+    assert!(code.contains("use super :: geometry :: { self , Point , PointHolder } ;"));
+    assert!(code.contains("_topology : topology :: Tables"));
+}
+
+#[test]
+fn schema_not_found() {
+    let err = SyntaxTree::load(&[path("missing.exp")], &[path("include")]).unwrap_err();
+    match err {
+        LoadError::SchemaNotFound { name, referrer } => {
+            assert_eq!(name, "unknown_schema");
+            assert_eq!(referrer, "missing");
+        }
+        _ => panic!("Unexpected error: {}", err),
+    }
+}
+
+/// Include directories are scanned only until the interfaced schema is found
+#[test]
+fn scan_include_dirs_lazily() {
+    let dir = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join("load-lazily");
+    let include = dir.join("include");
+    fs::create_dir_all(&include).unwrap();
+    fs::write(
+        dir.join("main.exp"),
+        "SCHEMA main; USE FROM Base; END_SCHEMA;",
+    )
+    .unwrap();
+    fs::write(
+        include.join("a.exp"),
+        "SCHEMA base; ENTITY p; x: REAL; END_ENTITY; END_SCHEMA;",
+    )
+    .unwrap();
+    // Not a UTF-8 file, and fails to be read if scanned
+    fs::write(include.join("b.exp"), [0xff, 0xfe]).unwrap();
+
+    let st = SyntaxTree::load(&[dir.join("main.exp")], &[include]).unwrap();
+    let names: Vec<_> = st
+        .schemas
+        .iter()
+        .map(|schema| schema.name.as_str())
+        .collect();
+    assert_eq!(names, vec!["main", "base"]);
+}
@@ -20,7 +20,7 @@ fn logical_literal() {
     let tt = rustfmt(tt);
 
     insta::assert_snapshot!(tt, @r###"
-    pub mod IFC4X3_DEV_6a23ae8 {
+    pub mod ifc4x3_dev_6a_2_3ae_8 {
         use ruststep::{as_holder, derive_more::*, primitive::*, Holder, TableInit};
         use std::collections::HashMap;
         #[derive(Debug, Clone, PartialEq, Default, TableInit)]
@@ -35,6 +35,13 @@ fn logical_literal() {
                 &self.IfcGeometricRepresentationContext
             }
         }
+        #[doc = " ```text"]
+        #[doc = " ENTITY IfcGeometricRepresentationContext;"]
+        #[doc = " \tTrueNorth : OPTIONAL BOOLEAN;"]
+        #[doc = "  WHERE"]
+        #[doc = " \tNorth2D : NOT(EXISTS(TrueNorth)) OR (HIINDEX(TrueNorth.DirectionRatios) = 2);"]
+        #[doc = " END_ENTITY;"]
+        #[doc = " ```"]
         #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
         # [holder (table = Tables)]
         # [holder (field = IfcGeometricRepresentationContext)]
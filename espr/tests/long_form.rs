@@ -0,0 +1,277 @@
+use espr::{
+    ast::{LongFormError, SyntaxTree},
+    ir::IR,
+};
+use std::path::PathBuf;
+
+const EXPRESS: &str = r#"
+SCHEMA drawing;
+  USE FROM shapes (circle AS round, square);
+  REFERENCE FROM support (positive AS is_positive);
+  ENTITY sheet;
+    items : SET [1:?] OF round;
+  WHERE
+    wr1 : is_positive(SIZEOF(items));
+  END_ENTITY;
+END_SCHEMA;
+
+SCHEMA shapes;
+  REFERENCE FROM support;
+  CONSTANT
+    unit_size : REAL := 1.0;
+  END_CONSTANT;
+  TYPE size = REAL;
+  WHERE
+    wr1 : positive(SELF);
+  END_TYPE;
+  ENTITY shape SUPERTYPE OF (ONEOF (circle, square)); END_ENTITY;
+  ENTITY circle SUBTYPE OF (shape);
+    radius : size;
+  DERIVE
+    area : REAL := PI * radius ** 2;
+  END_ENTITY;
+  ENTITY square SUBTYPE OF (shape);
+    side : size;
+  END_ENTITY;
+  ENTITY triangle SUBTYPE OF (shape); END_ENTITY;
+  SUBTYPE_CONSTRAINT exclusive_shapes FOR shape;
+    ONEOF (circle, square, triangle);
+  END_SUBTYPE_CONSTRAINT;
+  RULE unique_circles FOR (circle);
+  WHERE
+    wr1 : SIZEOF(circle) >= 0;
+  END_RULE;
+  RULE unique_triangles FOR (triangle, sheet);
+  WHERE
+    wr1 : SIZEOF(triangle) >= 0;
+  END_RULE;
+  FUNCTION unused(radius : size) : REAL;
+    RETURN (radius * unit_size);
+  END_FUNCTION;
+END_SCHEMA;
+
+SCHEMA support;
+  FUNCTION positive(size : REAL) : BOOLEAN;
+    RETURN (size > 0);
+  END_FUNCTION;
+  FUNCTION negative(size : REAL) : BOOLEAN;
+    RETURN (size < 0);
+  END_FUNCTION;
+END_SCHEMA;
+"#;
+
+#[test]
+fn long_form() {
+    let st = SyntaxTree::parse(EXPRESS).unwrap();
+    let long_form = st.long_form("drawing").unwrap();
+    let printed = long_form.to_string();
+    insta::assert_snapshot!(printed, @r###"
+SCHEMA drawing;
+
+  TYPE size = REAL;
+  WHERE
+    wr1 : is_positive(SELF);
+  END_TYPE;
+
+  ENTITY sheet;
+    items : SET [1:?] OF round;
+  WHERE
+    wr1 : is_positive(SIZEOF(items));
+  END_ENTITY;
+
+  ENTITY shape
+    SUPERTYPE OF (ONEOF (round, square));
+  END_ENTITY;
+
+  ENTITY round
+    SUBTYPE OF (shape);
+    radius : size;
+  DERIVE
+    area : REAL := PI * radius ** 2;
+  END_ENTITY;
+
+  ENTITY square
+    SUBTYPE OF (shape);
+    side : size;
+  END_ENTITY;
+
+  ENTITY triangle
+    SUBTYPE OF (shape);
+  END_ENTITY;
+
+  SUBTYPE_CONSTRAINT exclusive_shapes FOR shape;
+    ONEOF (round, square, triangle);
+  END_SUBTYPE_CONSTRAINT;
+
+  FUNCTION is_positive(size : REAL) : BOOLEAN;
+    RETURN (size > 0);
+  END_FUNCTION;
+
+  RULE unique_circles FOR (round);
+  WHERE
+    wr1 : SIZEOF(round) >= 0;
+  END_RULE;
+
+END_SCHEMA;
+    "###);
+
+    // The long form is a valid schema by itself
+    let st = SyntaxTree::parse(&printed).unwrap();
+    IR::from_syntax_tree(&st).unwrap();
+}
+
+#[test]
+fn long_form_files() {
+    let dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/load");
+    let st = SyntaxTree::load(&[dir.join("shape.exp")], &[dir.join("include")]).unwrap();
+    let long_form = st.long_form("shape").unwrap();
+    let entities: Vec<_> = long_form
+        .entities
+        .iter()
+        .map(|entity| entity.name.as_str())
+        .collect();
+    assert_eq!(entities, vec!["face", "edge", "vertex"]);
+}
+
+#[test]
+fn name_conflict() {
+    let st = SyntaxTree::parse(
+        r#"
+        SCHEMA a;
+          USE FROM b (point AS location);
+          ENTITY location; END_ENTITY;
+        END_SCHEMA;
+        SCHEMA b;
+          ENTITY point; END_ENTITY;
+        END_SCHEMA;
+        "#,
+    )
+    .unwrap();
+    // Declaration in the schema shadows the interfaced one
+    let long_form = st.long_form("a").unwrap();
+    assert_eq!(long_form.entities.len(), 1);
+
+    let st = SyntaxTree::parse(
+        r#"
+        SCHEMA a;
+          USE FROM b (point);
+          USE FROM c (point AS vertex);
+        END_SCHEMA;
+        SCHEMA b;
+          ENTITY point; END_ENTITY;
+        END_SCHEMA;
+        SCHEMA c;
+          ENTITY point; END_ENTITY;
+          ENTITY line; p : point; END_ENTITY;
+        END_SCHEMA;
+        "#,
+    )
+    .unwrap();
+    assert!(st.long_form("a").is_ok());
+
+    let st = SyntaxTree::parse(
+        r#"
+        SCHEMA a;
+          USE FROM b (line);
+          USE FROM c (point);
+        END_SCHEMA;
+        SCHEMA b;
+          ENTITY point; END_ENTITY;
+          ENTITY line; p : point; END_ENTITY;
+        END_SCHEMA;
+        SCHEMA c;
+          ENTITY point; x : REAL; END_ENTITY;
+        END_SCHEMA;
+        "#,
+    )
+    .unwrap();
+    match st.long_form("a") {
+        Err(LongFormError::NameConflict { name, .. }) => assert_eq!(name, "point"),
+        result => panic!("Unexpected result: {:?}", result),
+    }
+}
+
+#[test]
+fn redeclared() {
+    let st = SyntaxTree::parse(
+        r#"
+        SCHEMA a;
+          USE FROM b (line);
+          USE FROM c (circle);
+          CONSTANT
+            origin : point := point(0.0);
+          END_CONSTANT;
+        END_SCHEMA;
+        SCHEMA b;
+          CONSTANT
+            origin : point := point(0.0);
+          END_CONSTANT;
+          ENTITY point; x : REAL; END_ENTITY;
+          ENTITY line; p : point; WHERE wr1 : p.x <> origin.x; END_ENTITY;
+        END_SCHEMA;
+        SCHEMA c;
+          REFERENCE FROM b (point);
+          ENTITY circle; WHERE wr1 : is_point(SELF); END_ENTITY;
+          FUNCTION is_point(x : GENERIC) : BOOLEAN;
+            RETURN ('C.POINT' IN TYPEOF(x));
+          END_FUNCTION;
+        END_SCHEMA;
+        "#,
+    )
+    .unwrap();
+    // The same constant redeclared in the root schema is kept only in the root schema,
+    // and the type name qualified by schema `C` is qualified by the root schema
+    let printed = st.long_form("a").unwrap().to_string();
+    assert_eq!(printed.matches("origin :").count(), 1);
+    assert!(printed.contains("RETURN ('A.POINT' IN TYPEOF(x));"));
+}
+
+/// Expand the short form of AP203 with integrated resources and application interpreted constructs,
+/// and compare with the long form shipped in the `schemas` directory
+#[test]
+fn long_form_ap203() {
+    let schemas = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../schemas");
+    let st = SyntaxTree::load(
+        &[schemas.join("APs/10303-203-aim-short.exp")],
+        &[schemas.join("IRs"), schemas.join("AICs")],
+    )
+    .unwrap();
+    let long_form = st.long_form("config_control_design").unwrap();
+    let printed = long_form.to_string();
+    let expanded = SyntaxTree::parse(&printed).unwrap();
+    IR::from_syntax_tree(&expanded).unwrap();
+
+    let shipped = SyntaxTree::load(&[schemas.join("APs/10303-203-aim-long.exp")], &[]).unwrap();
+    let shipped = &shipped.schemas[0];
+    let names = |names: &mut dyn Iterator<Item = &String>| {
+        let mut names: Vec<_> = names.map(|name| name.to_lowercase()).collect();
+        names.sort();
+        names
+    };
+    assert_eq!(
+        names(&mut long_form.constants.iter().map(|c| &c.name)),
+        names(&mut shipped.constants.iter().map(|c| &c.name))
+    );
+    assert_eq!(
+        names(&mut long_form.rules.iter().map(|r| &r.name)),
+        names(&mut shipped.rules.iter().map(|r| &r.name))
+    );
+    // Integrated resources in `schemas/IRs` are of later editions than the shipped long form,
+    // and thus they may contain more entities and functions
+    let entities = names(&mut long_form.entities.iter().map(|e| &e.name));
+    for entity in &shipped.entities {
+        assert!(
+            entities.contains(&entity.name.to_lowercase()),
+            "{} is not expanded",
+            entity.name
+        );
+    }
+    let functions = names(&mut long_form.functions.iter().map(|f| &f.name));
+    for function in &shipped.functions {
+        assert!(
+            functions.contains(&function.name.to_lowercase()),
+            "{} is not expanded",
+            function.name
+        );
+    }
+}
@@ -0,0 +1,148 @@
+use espr::{ast::SyntaxTree, codegen::rust::*, ir::IR};
+
+const EXPRESS: &str = r#"
+SCHEMA test_schema;
+  TYPE label = STRING;
+  END_TYPE;
+
+  ENTITY a;
+    x: REAL;
+    name: label;
+  END_ENTITY;
+END_SCHEMA;
+
+(*"test_schema.label" Human readable name *)
+
+(*"a"
+An entity with a coordinate.
+
+See also `label`.
+*)
+
+(*"a.x" Coordinate in millimetres *)
+"#;
+
+#[test]
+fn remarks() {
+    let st = SyntaxTree::parse(EXPRESS).unwrap();
+    let ir = IR::from_syntax_tree(&st).unwrap();
+    let tt = ir.to_token_stream(CratePrefix::External).to_string();
+
+    let tt = rustfmt(tt);
+
+    insta::assert_snapshot!(tt, @r###"
+    pub mod test_schema {
+        use ruststep::{as_holder, derive_more::*, primitive::*, Holder, TableInit};
+        use std::collections::HashMap;
+        #[derive(Debug, Clone, PartialEq, Default, TableInit)]
+        pub struct Tables {
+            a: HashMap<u64, as_holder!(A)>,
+            label: HashMap<u64, as_holder!(Label)>,
+        }
+        impl Tables {
+            pub fn a_holders(&self) -> &HashMap<u64, as_holder!(A)> {
+                &self.a
+            }
+            pub fn label_holders(&self) -> &HashMap<u64, as_holder!(Label)> {
+                &self.label
+            }
+        }
+        #[doc = " Human readable name"]
+        #[doc = ""]
+        #[doc = " ```text"]
+        #[doc = " TYPE label = STRING;"]
+        #[doc = " END_TYPE;"]
+        #[doc = " ```"]
+        #[derive(
+            Clone, Debug, PartialEq, AsRef, Deref, DerefMut, Into, From, :: ruststep_derive :: Holder,
+        )]
+        # [holder (table = Tables)]
+        # [holder (field = label)]
+        #[holder(generate_deserialize)]
+        pub struct Label(pub String);
+        #[doc = " An entity with a coordinate."]
+        #[doc = ""]
+        #[doc = " See also `label`."]
+        #[doc = ""]
+        #[doc = " ```text"]
+        #[doc = " ENTITY a;"]
+        #[doc = "   x: REAL;"]
+        #[doc = "   name: label;"]
+        #[doc = " END_ENTITY;"]
+        #[doc = " ```"]
+        #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
+        # [holder (table = Tables)]
+        # [holder (field = a)]
+        #[holder(generate_deserialize)]
+        pub struct A {
+            #[doc = " Coordinate in millimetres"]
+            pub x: f64,
+            #[holder(use_place_holder)]
+            pub name: Label,
+        }
+    }
+    "###);
+}
+
+#[test]
+fn remarks_markdown() {
+    let st = SyntaxTree::parse(
+        r#"
+        SCHEMA test_schema;
+          ENTITY a; END_ENTITY;
+        END_SCHEMA;
+
+        (*"a"
+            Indented paragraph with [a link],
+              and a nested line.
+            ```
+            code
+            ```
+        *)
+        "#,
+    )
+    .unwrap();
+    let ir = IR::from_syntax_tree(&st).unwrap();
+    let tt = rustfmt(ir.to_token_stream(CratePrefix::External).to_string());
+    let docs: Vec<&str> = tt
+        .lines()
+        .map(str::trim)
+        .filter(|line| line.starts_with("#[doc"))
+        .take(5)
+        .collect();
+    assert_eq!(
+        docs,
+        [
+            r#"#[doc = " Indented paragraph with \\[a link\\],"]"#,
+            r#"#[doc = "   and a nested line."]"#,
+            r#"#[doc = " \\`\\`\\`"]"#,
+            r#"#[doc = " code"]"#,
+            r#"#[doc = " \\`\\`\\`"]"#,
+        ]
+    );
+}
+
+#[test]
+fn remarks_ideographic_space() {
+    // U+3000 is kept as a part of text since only ASCII spaces and tabs are indent
+    let st = SyntaxTree::parse(
+        "SCHEMA test_schema;\n  ENTITY a; END_ENTITY;\nEND_SCHEMA;\n(*\"a\" 概要\n\u{3000}全角字下げ\n  次の行\n*)\n",
+    )
+    .unwrap();
+    let ir = IR::from_syntax_tree(&st).unwrap();
+    let tt = rustfmt(ir.to_token_stream(CratePrefix::External).to_string());
+    let docs: Vec<&str> = tt
+        .lines()
+        .map(str::trim)
+        .filter(|line| line.starts_with("#[doc"))
+        .take(3)
+        .collect();
+    assert_eq!(
+        docs,
+        [
+            r#"#[doc = " 概要"]"#,
+            r#"#[doc = " \u{3000}全角字下げ"]"#,
+            r#"#[doc = "   次の行"]"#,
+        ]
+    );
+}
@@ -53,6 +53,10 @@ fn reserved_keyword() {
                 &self.b
             }
         }
+        #[doc = " ```text"]
+        #[doc = " TYPE b = loop;"]
+        #[doc = " END_TYPE;"]
+        #[doc = " ```"]
         #[derive(
             Clone, Debug, PartialEq, AsRef, Deref, DerefMut, Into, From, :: ruststep_derive :: Holder,
         )]
@@ -60,6 +64,11 @@ fn reserved_keyword() {
         # [holder (field = b)]
         #[holder(generate_deserialize)]
         pub struct B(#[holder(use_place_holder)] pub Loop);
+        #[doc = " ```text"]
+        #[doc = " ENTITY loop;"]
+        #[doc = "   a: REAL;"]
+        #[doc = " END_ENTITY;"]
+        #[doc = " ```"]
         #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
         # [holder (table = Tables)]
         # [holder (field = r#loop)]
@@ -67,6 +76,12 @@ fn reserved_keyword() {
         pub struct Loop {
             pub a: f64,
         }
+        #[doc = " ```text"]
+        #[doc = " ENTITY a;"]
+        #[doc = "   z: REAL;"]
+        #[doc = "   a_loop: loop;"]
+        #[doc = " END_ENTITY;"]
+        #[doc = " ```"]
         #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
         # [holder (table = Tables)]
         # [holder (field = a)]
@@ -76,6 +91,11 @@ fn reserved_keyword() {
             #[holder(use_place_holder)]
             pub a_loop: Loop,
         }
+        #[doc = " ```text"]
+        #[doc = " ENTITY c;"]
+        #[doc = "   loop: b;"]
+        #[doc = " END_ENTITY;"]
+        #[doc = " ```"]
         #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
         # [holder (table = Tables)]
         # [holder (field = c)]
@@ -0,0 +1,483 @@
+use espr::{ast::SyntaxTree, codegen::rust::*, ir::IR};
+use std::{fs, path::Path, process::Command};
+
+const EXPRESS: &str = r#"
+SCHEMA shapes;
+  TYPE color = ENUMERATION OF (red, green); END_TYPE;
+  TYPE distance = REAL; END_TYPE;
+  TYPE item = SELECT (point, shape); END_TYPE;
+  ENTITY point;
+    x : distance;
+  INVERSE
+    lines : SET [0:?] OF line FOR start;
+  END_ENTITY;
+  ENTITY line;
+    start : point;
+    finish : point;
+  END_ENTITY;
+  ENTITY shape SUPERTYPE OF (ONEOF (circle, square));
+    fill : color;
+  END_ENTITY;
+  ENTITY circle SUBTYPE OF (shape);
+    center : point;
+    radius : distance;
+  END_ENTITY;
+  ENTITY square SUBTYPE OF (shape); END_ENTITY;
+  ENTITY label;
+    target : item;
+  END_ENTITY;
+END_SCHEMA;
+"#;
+
+fn legalize(input: &str) -> IR {
+    IR::from_syntax_tree(&SyntaxTree::parse(input).unwrap()).unwrap()
+}
+
+fn files(options: SplitOptions) -> Vec<(String, String)> {
+    legalize(EXPRESS)
+        .to_split_modules(options)
+        .into_iter()
+        .map(|file| {
+            (
+                file.path.display().to_string(),
+                rustfmt(file.tokens.to_string()),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn clusters() {
+    let ir = legalize(EXPRESS);
+    let clusters: Vec<_> = ir.schemas[0]
+        .clusters()
+        .into_iter()
+        .map(|c| (c.name, c.entities, c.types, c.dependencies))
+        .collect();
+    insta::assert_debug_snapshot!(clusters, @r###"
+    [
+        (
+            "distance",
+            [],
+            [
+                "distance",
+            ],
+            [],
+        ),
+        (
+            "point",
+            [
+                "point",
+                "line",
+            ],
+            [],
+            [
+                "distance",
+            ],
+        ),
+        (
+            "color",
+            [],
+            [
+                "color",
+            ],
+            [],
+        ),
+        (
+            "shape",
+            [
+                "shape",
+                "circle",
+                "square",
+            ],
+            [],
+            [
+                "color",
+                "distance",
+                "point",
+            ],
+        ),
+        (
+            "item",
+            [],
+            [
+                "item",
+            ],
+            [
+                "point",
+                "shape",
+            ],
+        ),
+        (
+            "label",
+            [
+                "label",
+            ],
+            [],
+            [
+                "item",
+            ],
+        ),
+    ]
+    "###);
+}
+
+#[test]
+fn cargo_features() {
+    insta::assert_snapshot!(legalize(EXPRESS).cargo_features(), @r###"
+    [features]
+    shapes = ["shapes-color", "shapes-distance", "shapes-item", "shapes-label", "shapes-point", "shapes-shape"]
+    shapes-color = []
+    shapes-distance = []
+    shapes-item = ["shapes-point", "shapes-shape"]
+    shapes-label = ["shapes-item"]
+    shapes-point = ["shapes-distance"]
+    shapes-shape = ["shapes-color", "shapes-distance", "shapes-point"]
+    "###);
+}
+
+#[test]
+fn split_modules() {
+    let files = files(SplitOptions {
+        prefix: CratePrefix::External,
+        features: true,
+        generators: Generators::all(),
+    });
+    let paths: Vec<_> = files.iter().map(|(path, _)| path.as_str()).collect();
+    insta::assert_debug_snapshot!(paths, @r###"
+    [
+        "mod.rs",
+        "shapes/mod.rs",
+        "shapes/tables.rs",
+        "shapes/visit.rs",
+        "shapes/visit_mut.rs",
+        "shapes/color.rs",
+        "shapes/distance.rs",
+        "shapes/item.rs",
+        "shapes/label.rs",
+        "shapes/point.rs",
+        "shapes/shape.rs",
+    ]
+    "###);
+
+    let (_, schema) = &files[1];
+    insta::assert_snapshot!(schema, @r###"
+    mod tables;
+    pub use tables::Tables;
+    #[cfg(feature = "shapes-color")]
+    mod color;
+    pub mod visit;
+    pub mod visit_mut;
+    #[cfg(feature = "shapes-color")]
+    pub use color::*;
+    #[cfg(feature = "shapes-distance")]
+    mod distance;
+    #[cfg(feature = "shapes-distance")]
+    pub use distance::*;
+    #[cfg(feature = "shapes-item")]
+    mod item;
+    #[cfg(feature = "shapes-item")]
+    pub use item::*;
+    #[cfg(feature = "shapes-label")]
+    mod label;
+    #[cfg(feature = "shapes-label")]
+    pub use label::*;
+    #[cfg(feature = "shapes-point")]
+    mod point;
+    #[cfg(feature = "shapes-point")]
+    pub use point::*;
+    #[cfg(feature = "shapes-shape")]
+    mod shape;
+    #[cfg(feature = "shapes-shape")]
+    pub use shape::*;
+    #[doc = " Entities and types declared in `shapes` schema"]
+    pub static SCHEMA_INFO: ::ruststep::reflection::SchemaInfo = ::ruststep::reflection::SchemaInfo {
+        name: "shapes",
+        entities: &[
+            ::ruststep::reflection::EntityInfo {
+                name: "point",
+                attributes: &[::ruststep::reflection::AttributeInfo {
+                    name: "x",
+                    ty: ::ruststep::reflection::AttributeType::Named("distance"),
+                    optional: false,
+                    redeclared_as_derived: false,
+                }],
+                supertypes: &[],
+                subtypes: &[],
+                subtype_constraint: None,
+            },
+            ::ruststep::reflection::EntityInfo {
+                name: "line",
+                attributes: &[
+                    ::ruststep::reflection::AttributeInfo {
+                        name: "start",
+                        ty: ::ruststep::reflection::AttributeType::Entity("point"),
+                        optional: false,
+                        redeclared_as_derived: false,
+                    },
+                    ::ruststep::reflection::AttributeInfo {
+                        name: "finish",
+                        ty: ::ruststep::reflection::AttributeType::Entity("point"),
+                        optional: false,
+                        redeclared_as_derived: false,
+                    },
+                ],
+                supertypes: &[],
+                subtypes: &[],
+                subtype_constraint: None,
+            },
+            ::ruststep::reflection::EntityInfo {
+                name: "shape",
+                attributes: &[::ruststep::reflection::AttributeInfo {
+                    name: "fill",
+                    ty: ::ruststep::reflection::AttributeType::Named("color"),
+                    optional: false,
+                    redeclared_as_derived: false,
+                }],
+                supertypes: &[],
+                subtypes: &["circle", "square"],
+                subtype_constraint: Some(::ruststep::reflection::SubtypeConstraint::OneOf(&[
+                    ::ruststep::reflection::SubtypeConstraint::Reference("circle"),
+                    ::ruststep::reflection::SubtypeConstraint::Reference("square"),
+                ])),
+            },
+            ::ruststep::reflection::EntityInfo {
+                name: "circle",
+                attributes: &[
+                    ::ruststep::reflection::AttributeInfo {
+                        name: "center",
+                        ty: ::ruststep::reflection::AttributeType::Entity("point"),
+                        optional: false,
+                        redeclared_as_derived: false,
+                    },
+                    ::ruststep::reflection::AttributeInfo {
+                        name: "radius",
+                        ty: ::ruststep::reflection::AttributeType::Named("distance"),
+                        optional: false,
+                        redeclared_as_derived: false,
+                    },
+                ],
+                supertypes: &["shape"],
+                subtypes: &[],
+                subtype_constraint: None,
+            },
+            ::ruststep::reflection::EntityInfo {
+                name: "square",
+                attributes: &[],
+                supertypes: &["shape"],
+                subtypes: &[],
+                subtype_constraint: None,
+            },
+            ::ruststep::reflection::EntityInfo {
+                name: "label",
+                attributes: &[::ruststep::reflection::AttributeInfo {
+                    name: "target",
+                    ty: ::ruststep::reflection::AttributeType::Named("item"),
+                    optional: false,
+                    redeclared_as_derived: false,
+                }],
+                supertypes: &[],
+                subtypes: &[],
+                subtype_constraint: None,
+            },
+        ],
+        types: &[
+            ::ruststep::reflection::TypeInfo {
+                name: "color",
+                kind: ::ruststep::reflection::TypeKind::Enumeration(&["red", "green"]),
+            },
+            ::ruststep::reflection::TypeInfo {
+                name: "distance",
+                kind: ::ruststep::reflection::TypeKind::Defined(
+                    ::ruststep::reflection::AttributeType::Simple("REAL"),
+                ),
+            },
+            ::ruststep::reflection::TypeInfo {
+                name: "item",
+                kind: ::ruststep::reflection::TypeKind::Select(&["point", "shape"]),
+            },
+        ],
+    };
+    #[doc = r" Look up an entity in [SCHEMA_INFO] by name case-insensitively"]
+    pub fn entity_info(name: &str) -> Option<&'static ::ruststep::reflection::EntityInfo> {
+        SCHEMA_INFO.entity(name)
+    }
+    "###);
+}
+
+#[test]
+fn without_features() {
+    let files = files(SplitOptions {
+        prefix: CratePrefix::External,
+        features: false,
+        generators: Generators::all(),
+    });
+    assert_eq!(files[0].1, "pub mod shapes;\n");
+    assert!(files.iter().all(|(_, code)| !code.contains("cfg")));
+
+    // Tables of the entities and types are in the same struct as the single module output
+    let (_, tables) = files
+        .iter()
+        .find(|(path, _)| path.ends_with("tables.rs"))
+        .unwrap();
+    assert!(tables.contains("pub(super) point: HashMap<u64, as_holder!(Point)>,"));
+    let (_, point) = files
+        .iter()
+        .find(|(path, _)| path.ends_with("point.rs"))
+        .unwrap();
+    assert!(point.contains("pub struct Point {"));
+    assert!(point.contains("pub struct Line {"));
+    assert!(point.contains("pub fn lines(&self, id: u64, tables: &Tables) -> Vec<u64> {"));
+}
+
+/// Entities named as the other modules in the schema directory
+const RESERVED: &str = r#"
+SCHEMA shapes;
+  TYPE distance = REAL; END_TYPE;
+  ENTITY tables;
+    size : distance;
+  END_ENTITY;
+  ENTITY visit;
+    target : tables;
+  END_ENTITY;
+  ENTITY visit_mut;
+    depth : distance;
+  END_ENTITY;
+END_SCHEMA;
+"#;
+
+#[test]
+fn reserved_cluster_names() {
+    let clusters = legalize(RESERVED).schemas[0].clusters();
+    let names: Vec<_> = clusters.iter().map(|c| c.name.as_str()).collect();
+    assert_eq!(
+        names,
+        [
+            "distance",
+            "tables_cluster",
+            "visit_cluster",
+            "visit_mut_cluster"
+        ]
+    );
+}
+
+/// Write split modules as a crate
+fn write_crate(dir: &Path, express: &str, options: SplitOptions) {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let ir = legalize(express);
+    let _ = fs::remove_dir_all(dir.join("src"));
+    for file in ir.to_split_modules(options) {
+        // Root `mod.rs` is the crate root
+        let path = if file.path == Path::new("mod.rs") {
+            dir.join("src/lib.rs")
+        } else {
+            dir.join("src").join(&file.path)
+        };
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, file.tokens.to_string()).unwrap();
+    }
+    let manifest = format!(
+        r#"
+[package]
+name = "split-modules"
+version = "0.1.0"
+edition = "2018"
+
+[dependencies]
+ruststep = {{ path = "{0}/../ruststep" }}
+ruststep-derive = {{ path = "{0}/../ruststep-derive" }}
+derive-new = "0.5.9"
+serde = {{ version = "1.0.158", features = ["derive"] }}
+
+[workspace]
+
+{1}
+"#,
+        manifest_dir.display(),
+        ir.cargo_features()
+    );
+    fs::write(dir.join("Cargo.toml"), manifest).unwrap();
+    // Use the versions of dependencies resolved for this workspace
+    let _ = fs::copy(manifest_dir.join("../Cargo.lock"), dir.join("Cargo.lock"));
+}
+
+fn check(dir: &Path, features: &str) {
+    let status = Command::new(env!("CARGO"))
+        .args(["check", "--quiet", "--features", features])
+        .current_dir(dir)
+        .env("CARGO_TARGET_DIR", dir.join("target"))
+        .status()
+        .unwrap();
+    assert!(status.success(), "Failed to build with `{}`", features);
+}
+
+/// Build the split modules as a crate with and without cluster features
+#[test]
+fn build_split_modules() {
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("split-modules");
+    fs::create_dir_all(&dir).unwrap();
+
+    write_crate(
+        &dir,
+        EXPRESS,
+        SplitOptions {
+            prefix: CratePrefix::External,
+            features: true,
+            generators: Generators::all(),
+        },
+    );
+    for features in ["", "shapes-point", "shapes-label", "shapes"] {
+        check(&dir, features);
+    }
+
+    write_crate(
+        &dir,
+        EXPRESS,
+        SplitOptions {
+            prefix: CratePrefix::External,
+            features: false,
+            generators: Generators::all(),
+        },
+    );
+    check(&dir, "");
+}
+
+const INTERFACED: &str = r#"
+SCHEMA shape;
+  USE FROM topology;
+  ENTITY face;
+    bound: LIST [1:?] OF edge;
+  END_ENTITY;
+END_SCHEMA;
+
+SCHEMA geometry;
+  ENTITY point;
+    x, y: REAL;
+  END_ENTITY;
+END_SCHEMA;
+
+SCHEMA topology;
+  USE FROM geometry (point AS vertex);
+  ENTITY edge;
+    head, tail: vertex;
+  END_ENTITY;
+END_SCHEMA;
+"#;
+
+/// Build the split modules of schemas interfacing each other
+#[test]
+fn build_interfaced_split_modules() {
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("split-modules-interfaced");
+    fs::create_dir_all(&dir).unwrap();
+
+    write_crate(
+        &dir,
+        INTERFACED,
+        SplitOptions {
+            prefix: CratePrefix::External,
+            features: false,
+            generators: Generators::all(),
+        },
+    );
+    check(&dir, "");
+}
@@ -0,0 +1,135 @@
+use espr::{ast::SyntaxTree, codegen::sql::*, ir::IR};
+
+const EXPRESS: &str = r#"
+SCHEMA shapes;
+  TYPE color = ENUMERATION OF (red, green); END_TYPE;
+  TYPE label = STRING; END_TYPE;
+  TYPE points = LIST [1:?] OF point; END_TYPE;
+  TYPE target = SELECT (point, shape); END_TYPE;
+  TYPE item = SELECT (point, label); END_TYPE;
+
+  ENTITY point;
+    x : REAL;
+    tag : OPTIONAL label;
+  END_ENTITY;
+
+  ENTITY shape;
+    fill : color;
+    vertices : points;
+  END_ENTITY;
+
+  ENTITY circle SUBTYPE OF (shape);
+    visible : LOGICAL;
+    target : target;
+    items : ARRAY [1:2] OF OPTIONAL item;
+  END_ENTITY;
+END_SCHEMA;
+"#;
+
+fn tables() -> Vec<Table> {
+    espr::codegen::sql::tables(&IR::from_syntax_tree(&SyntaxTree::parse(EXPRESS).unwrap()).unwrap())
+}
+
+#[test]
+fn table_names() {
+    let names: Vec<_> = tables().into_iter().map(|t| t.name).collect();
+    assert_eq!(
+        names,
+        [
+            FILE_TABLE,
+            "point",
+            "shape",
+            "shape.vertices",
+            "circle",
+            "circle.items"
+        ]
+    );
+}
+
+#[test]
+fn file_table() {
+    let tables = tables();
+    assert_eq!(
+        tables[0].to_string(),
+        r#"CREATE TABLE IF NOT EXISTS "_file" (
+  "_id" INTEGER NOT NULL,
+  "name" TEXT,
+  PRIMARY KEY ("_id")
+);
+"#
+    );
+}
+
+#[test]
+fn entity_table() {
+    let tables = tables();
+    assert_eq!(
+        tables[2].to_string(),
+        r#"CREATE TABLE IF NOT EXISTS "shape" (
+  "_file" INTEGER NOT NULL,
+  "_id" INTEGER NOT NULL,
+  "fill" TEXT NOT NULL CHECK ("fill" IN ('red', 'green')),
+  PRIMARY KEY ("_file", "_id"),
+  FOREIGN KEY ("_file") REFERENCES "_file" ("_id") DEFERRABLE INITIALLY DEFERRED
+);
+"#
+    );
+    assert_eq!(
+        tables[4].to_string(),
+        r#"CREATE TABLE IF NOT EXISTS "circle" (
+  "_file" INTEGER NOT NULL,
+  "_id" INTEGER NOT NULL,
+  "visible" TEXT NOT NULL CHECK ("visible" IN ('T', 'F', 'U')),
+  "target" INTEGER NOT NULL,
+  PRIMARY KEY ("_file", "_id"),
+  FOREIGN KEY ("_file") REFERENCES "_file" ("_id") DEFERRABLE INITIALLY DEFERRED,
+  FOREIGN KEY ("_file", "_id") REFERENCES "shape" ("_file", "_id") DEFERRABLE INITIALLY DEFERRED
+);
+"#
+    );
+}
+
+#[test]
+fn link_table() {
+    let tables = tables();
+    // Aggregate through defined type
+    assert_eq!(
+        tables[3].to_string(),
+        r#"CREATE TABLE IF NOT EXISTS "shape.vertices" (
+  "_file" INTEGER NOT NULL,
+  "owner" INTEGER NOT NULL,
+  "position" INTEGER NOT NULL,
+  "value" INTEGER NOT NULL,
+  PRIMARY KEY ("_file", "owner", "position"),
+  FOREIGN KEY ("_file", "owner") REFERENCES "shape" ("_file", "_id") DEFERRABLE INITIALLY DEFERRED,
+  FOREIGN KEY ("_file", "value") REFERENCES "point" ("_file", "_id") DEFERRABLE INITIALLY DEFERRED
+);
+"#
+    );
+    // Select of non-entity types are stored as text
+    let value = tables[5].column("value").unwrap();
+    assert_eq!(value.ty, ColumnType::Text);
+    assert!(value.nullable);
+}
+
+#[test]
+fn no_collisions() {
+    // `id` attribute, and `a_b` entity against `b` attribute of `a`
+    let ir = IR::from_syntax_tree(
+        &SyntaxTree::parse(
+            r#"
+            SCHEMA s;
+              ENTITY a; id: INTEGER; b: LIST [0:?] OF INTEGER; END_ENTITY;
+              ENTITY a_b; x: REAL; END_ENTITY;
+            END_SCHEMA;
+            "#,
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    let tables = espr::codegen::sql::tables(&ir);
+    let names: Vec<_> = tables.iter().map(|t| t.name.as_str()).collect();
+    assert_eq!(names, [FILE_TABLE, "a", "a.b", "a_b"]);
+    let columns: Vec<_> = tables[1].columns.iter().map(|c| c.name.as_str()).collect();
+    assert_eq!(columns, ["_file", "_id", "id"]);
+}
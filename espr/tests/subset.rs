@@ -0,0 +1,73 @@
+use espr::{ast::SyntaxTree, codegen::rust::*, ir::IR};
+
+const EXPRESS: &str = r#"
+SCHEMA test_schema;
+  ENTITY shape;
+    origin : point;
+  END_ENTITY;
+  ENTITY point;
+    x : REAL;
+  END_ENTITY;
+  ENTITY label;
+    text : STRING;
+  END_ENTITY;
+END_SCHEMA;
+"#;
+
+#[test]
+fn subset() {
+    let st = SyntaxTree::parse(EXPRESS).unwrap();
+    let mut ir = IR::from_syntax_tree(&st).unwrap();
+    ir.subset(&["shape"]).unwrap();
+    let tt = rustfmt(ir.to_token_stream(CratePrefix::External).to_string());
+    insta::assert_snapshot!(tt, @r###"
+    pub mod test_schema {
+        use ruststep::{as_holder, derive_more::*, primitive::*, Holder, TableInit};
+        use std::collections::HashMap;
+        #[derive(Debug, Clone, PartialEq, Default, TableInit)]
+        pub struct Tables {
+            shape: HashMap<u64, as_holder!(Shape)>,
+            point: HashMap<u64, as_holder!(Point)>,
+            #[table_init(untyped)]
+            untyped: HashMap<u64, ::ruststep::ast::Record>,
+        }
+        impl Tables {
+            pub fn shape_holders(&self) -> &HashMap<u64, as_holder!(Shape)> {
+                &self.shape
+            }
+            pub fn point_holders(&self) -> &HashMap<u64, as_holder!(Point)> {
+                &self.point
+            }
+            #[doc = r" Records of entities not generated in this subset of the schema"]
+            pub fn untyped_records(&self) -> &HashMap<u64, ::ruststep::ast::Record> {
+                &self.untyped
+            }
+        }
+        #[doc = " ```text"]
+        #[doc = " ENTITY shape;"]
+        #[doc = "   origin : point;"]
+        #[doc = " END_ENTITY;"]
+        #[doc = " ```"]
+        #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
+        # [holder (table = Tables)]
+        # [holder (field = shape)]
+        #[holder(generate_deserialize)]
+        pub struct Shape {
+            #[holder(use_place_holder)]
+            pub origin: Point,
+        }
+        #[doc = " ```text"]
+        #[doc = " ENTITY point;"]
+        #[doc = "   x : REAL;"]
+        #[doc = " END_ENTITY;"]
+        #[doc = " ```"]
+        #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
+        # [holder (table = Tables)]
+        # [holder (field = point)]
+        #[holder(generate_deserialize)]
+        pub struct Point {
+            pub x: f64,
+        }
+    }
+    "###);
+}
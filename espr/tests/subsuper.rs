@@ -47,6 +47,11 @@ fn subsuper() {
                 &self.subsub
             }
         }
+        #[doc = " ```text"]
+        #[doc = " ENTITY base SUPERTYPE OF (ONEOF (sub));"]
+        #[doc = "   x: REAL;"]
+        #[doc = " END_ENTITY;"]
+        #[doc = " ```"]
         #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
         # [holder (table = Tables)]
         # [holder (field = base)]
@@ -81,6 +86,13 @@ fn subsuper() {
                 }
             }
         }
+        #[doc = " ```text"]
+        #[doc = " ENTITY sub"]
+        #[doc = "    SUPERTYPE OF (subsub)"]
+        #[doc = "    SUBTYPE OF (base);"]
+        #[doc = "   y: REAL;"]
+        #[doc = " END_ENTITY;"]
+        #[doc = " ```"]
         #[derive(
             Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
         )]
@@ -131,6 +143,11 @@ fn subsuper() {
                 }
             }
         }
+        #[doc = " ```text"]
+        #[doc = " ENTITY subsub SUBTYPE OF (sub);"]
+        #[doc = "   z: REAL;"]
+        #[doc = " END_ENTITY;"]
+        #[doc = " ```"]
         #[derive(
             Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
         )]
@@ -60,6 +60,10 @@ fn type_decl() {
                 &self.d
             }
         }
+        #[doc = " ```text"]
+        #[doc = " TYPE a = STRING;"]
+        #[doc = " END_TYPE;"]
+        #[doc = " ```"]
         #[derive(
             Clone, Debug, PartialEq, AsRef, Deref, DerefMut, Into, From, :: ruststep_derive :: Holder,
         )]
@@ -67,12 +71,24 @@ fn type_decl() {
         # [holder (field = a)]
         #[holder(generate_deserialize)]
         pub struct A(pub String);
+        #[doc = " ```text"]
+        #[doc = " TYPE b = ENUMERATION OF ("]
+        #[doc = "     are,"]
+        #[doc = "     sore,"]
+        #[doc = "     dore"]
+        #[doc = "   );"]
+        #[doc = " END_TYPE;"]
+        #[doc = " ```"]
         #[derive(Debug, Clone, PartialEq, :: serde :: Deserialize)]
         pub enum B {
             Are,
             Sore,
             Dore,
         }
+        #[doc = " ```text"]
+        #[doc = " TYPE c = a;"]
+        #[doc = " END_TYPE;"]
+        #[doc = " ```"]
         #[derive(
             Clone, Debug, PartialEq, AsRef, Deref, DerefMut, Into, From, :: ruststep_derive :: Holder,
         )]
@@ -80,6 +96,10 @@ fn type_decl() {
         # [holder (field = c)]
         #[holder(generate_deserialize)]
         pub struct C(#[holder(use_place_holder)] pub A);
+        #[doc = " ```text"]
+        #[doc = " TYPE d = b;"]
+        #[doc = " END_TYPE;"]
+        #[doc = " ```"]
         #[derive(
             Clone, Debug, PartialEq, AsRef, Deref, DerefMut, Into, From, :: ruststep_derive :: Holder,
         )]
@@ -87,6 +107,14 @@ fn type_decl() {
         # [holder (field = d)]
         #[holder(generate_deserialize)]
         pub struct D(pub B);
+        #[doc = " ```text"]
+        #[doc = " ENTITY e;"]
+        #[doc = "   a: a;"]
+        #[doc = "   b: b;"]
+        #[doc = "   c: c;"]
+        #[doc = "   d: d;"]
+        #[doc = " END_ENTITY;"]
+        #[doc = " ```"]
         #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
         # [holder (table = Tables)]
         # [holder (field = e)]
@@ -0,0 +1,85 @@
+use espr::{ast::SyntaxTree, codegen::typescript::*, ir::IR};
+
+const EXPRESS: &str = r#"
+SCHEMA shapes;
+  TYPE color = ENUMERATION OF (red, green); END_TYPE;
+  TYPE label = STRING(8) FIXED; END_TYPE;
+  TYPE item = SELECT (point, label); END_TYPE;
+
+  (*"shapes.point" A point in 2D *)
+  ENTITY point;
+    x : REAL;
+    tag : OPTIONAL label;
+  END_ENTITY;
+
+  ENTITY shape;
+    fill : color;
+    corners : ARRAY [1:4] OF OPTIONAL point;
+    items : SET [0:?] OF item;
+    path : LIST [2:?] OF UNIQUE point;
+  END_ENTITY;
+
+  ENTITY circle SUBTYPE OF (shape);
+    visible : LOGICAL;
+    flags : LIST [0:?] OF LOGICAL;
+  END_ENTITY;
+END_SCHEMA;
+
+SCHEMA grid;
+  REFERENCE FROM shapes (point);
+
+  ENTITY segment;
+    start : point;
+    ratio : REAL;
+  END_ENTITY;
+
+  ENTITY unit_segment SUBTYPE OF (segment);
+  DERIVE
+    SELF\segment.ratio : REAL := 1.0;
+  END_ENTITY;
+END_SCHEMA;
+"#;
+
+#[test]
+fn typescript_declarations() {
+    let ir = IR::from_syntax_tree(&SyntaxTree::parse(EXPRESS).unwrap()).unwrap();
+    insta::assert_snapshot!(typescript(&ir), @r###"
+export namespace shapes {
+  export type Color = "red" | "green";
+
+  export type Label = string;
+
+  export type Item = shapes.Point | shapes.Label;
+
+  /**
+   * A point in 2D
+   */
+  export interface Point {
+    x: number;
+    tag?: shapes.Label;
+  }
+
+  export interface Shape {
+    fill: shapes.Color;
+    corners: (shapes.Point | null)[];
+    items: shapes.Item[];
+    path: shapes.Point[];
+  }
+
+  export interface Circle extends shapes.Shape {
+    visible: boolean | null;
+    flags: (boolean | null)[];
+  }
+}
+
+export namespace grid {
+  export interface Segment {
+    start: shapes.Point;
+    ratio?: number;
+  }
+
+  export interface UnitSegment extends grid.Segment {
+  }
+}
+    "###);
+}
@@ -22,6 +22,7 @@ pub fn derive_holder(ident: &syn::Ident, st: &syn::DataStruct, attr: &HolderAttr
     let def_holder_tt = def_holder(ident, st);
     let impl_holder_tt = impl_holder(ident, attr, st);
     let impl_entity_table_tt = impl_entity_table(ident, attr);
+    let impl_refer_tt = impl_refer(ident);
     if attr.generate_deserialize {
         let def_visitor_tt = def_visitor(&holder_ident, &name, st);
         let impl_deserialize_tt = impl_deserialize(&holder_ident, &name, st);
@@ -30,6 +31,7 @@ pub fn derive_holder(ident: &syn::Ident, st: &syn::DataStruct, attr: &HolderAttr
             #def_holder_tt
             #impl_holder_tt
             #impl_entity_table_tt
+            #impl_refer_tt
             #def_visitor_tt
             #impl_deserialize_tt
             #impl_with_visitor_tt
@@ -39,6 +41,7 @@ pub fn derive_holder(ident: &syn::Ident, st: &syn::DataStruct, attr: &HolderAttr
             #def_holder_tt
             #impl_holder_tt
             #impl_entity_table_tt
+            #impl_refer_tt
         }
     }
 }
@@ -52,6 +55,7 @@ struct FieldEntries {
     attributes: Vec<syn::Ident>,
     holder_types: Vec<syn::Type>,
     into_owned: Vec<TokenStream2>,
+    size_checks: Vec<TokenStream2>,
 }
 
 impl FieldEntries {
@@ -61,6 +65,8 @@ impl FieldEntries {
         let mut attributes = Vec::new();
         let mut holder_types = Vec::new();
         let mut into_owned = Vec::new();
+        let mut size_checks = Vec::new();
+        let ruststep = ruststep_crate();
 
         for field in &st.fields {
             let ident = field.ident.as_ref().expect_or_abort("st is not struct");
@@ -68,19 +74,28 @@ impl FieldEntries {
 
             let ft: FieldType = field.ty.clone().try_into().unwrap();
 
-            let HolderAttr { place_holder, .. } = HolderAttr::parse(&field.attrs);
+            let HolderAttr {
+                place_holder, size, ..
+            } = HolderAttr::parse(&field.attrs);
+            if let Some(size) = size {
+                let name = ident.to_string();
+                size_checks.push(quote! {
+                    #ruststep::tables::check_size(#name, &#ident, #size)?;
+                });
+            }
             if place_holder {
+                let table = quote! { #ruststep::tables::ForeignTable::foreign_table(#table_arg) };
                 match &ft {
                     FieldType::Path(_) => {
-                        into_owned.push(quote! { #ident.into_owned(#table_arg)? });
+                        into_owned.push(quote! { #ident.into_owned(#table)? });
                     }
                     FieldType::Optional(_) => {
-                        into_owned.push(quote! { #ident.map(|holder| holder.into_owned(#table_arg)).transpose()? });
+                        into_owned.push(quote! { #ident.map(|holder| holder.into_owned(#table)).transpose()? });
                     }
                     FieldType::List(_) => into_owned.push(quote! {
                         #ident
                             .into_iter()
-                            .map(|v| v.into_owned(#table_arg))
+                            .map(|v| v.into_owned(#table))
                             .collect::<::std::result::Result<Vec<_>, _>>()?
                     }),
                     FieldType::Boxed(_) => abort_call_site!("Unexpected Box<T>"),
@@ -95,6 +110,7 @@ impl FieldEntries {
             attributes,
             holder_types,
             into_owned,
+            size_checks,
         }
     }
 }
@@ -121,6 +137,7 @@ pub fn impl_holder(ident: &syn::Ident, table: &HolderAttr, st: &syn::DataStruct)
     let FieldEntries {
         attributes,
         into_owned,
+        size_checks,
         ..
     } = FieldEntries::parse(st);
     let attr_len = attributes.len();
@@ -135,6 +152,7 @@ pub fn impl_holder(ident: &syn::Ident, table: &HolderAttr, st: &syn::DataStruct)
             type Owned = #ident;
             fn into_owned(self, #table_arg: &Self::Table) -> #ruststep::error::Result<Self::Owned> {
                 let #holder_ident { #(#attributes),* } = self;
+                #(#size_checks)*
                 Ok(#ident { #(#attributes: #into_owned),* })
             }
         }
@@ -168,6 +186,19 @@ pub fn impl_entity_table(ident: &syn::Ident, table: &HolderAttr) -> TokenStream2
     }
 }
 
+// Inline entity instance has no id to be referred,
+// and the references in its attributes are not the ones of the attribute holding it.
+fn impl_refer(ident: &syn::Ident) -> TokenStream2 {
+    let holder_ident = as_holder_ident(ident);
+    let ruststep = ruststep_crate();
+    quote! {
+        #[automatically_derived]
+        impl #ruststep::tables::Refer for #holder_ident {
+            fn referred_ids(&self, _ids: &mut Vec<u64>) {}
+        }
+    }
+}
+
 // `name` may be different from `ident`
 // because this will be used for both Entity struct and its `*Holder` struct.
 fn def_visitor(ident: &syn::Ident, name: &str, st: &syn::DataStruct) -> TokenStream2 {
@@ -1,11 +1,12 @@
 //! Parse the associated attribute `#[holder(...)]` with `#[derive(Holder)]`
 //!
-//! There are three options:
+//! There are following options:
 //!
 //! - `#[holder(table = {path::to::table::struct})]`
 //! - `#[holder(field = {field_ident})]`
 //! - `#[holder(use_place_holder)]`
 //! - `#[holder(generate_deserialize)]`
+//! - `#[holder(size = {range})]`, e.g. `#[holder(size = 1..=3)]` for `ARRAY [1:3] OF REAL`
 //!
 
 #[derive(Debug, Clone, PartialEq)]
@@ -14,6 +15,7 @@ pub struct HolderAttr {
     pub field: Option<syn::Ident>,
     pub place_holder: bool,
     pub generate_deserialize: bool,
+    pub size: Option<syn::ExprRange>,
 }
 
 impl HolderAttr {
@@ -22,6 +24,7 @@ impl HolderAttr {
         let mut field = None;
         let mut place_holder = false;
         let mut generate_deserialize = false;
+        let mut size = None;
 
         for attr in attrs {
             // Only read `#[holder(...)]`
@@ -46,6 +49,9 @@ impl HolderAttr {
                 Attr::GenerateDeserialize => {
                     generate_deserialize = true;
                 }
+                Attr::Size(range) => {
+                    size = Some(range);
+                }
             }
         }
         HolderAttr {
@@ -53,6 +59,7 @@ impl HolderAttr {
             field,
             place_holder,
             generate_deserialize,
+            size,
         }
     }
 }
@@ -63,6 +70,7 @@ enum Attr {
     Field(syn::Ident),
     PlaceHolder,
     GenerateDeserialize,
+    Size(syn::ExprRange),
 }
 
 impl syn::parse::Parse for Attr {
@@ -81,9 +89,14 @@ impl syn::parse::Parse for Attr {
             }
             "use_place_holder" => Ok(Attr::PlaceHolder),
             "generate_deserialize" => Ok(Attr::GenerateDeserialize),
+            "size" => {
+                let _eq: syn::Token![=] = input.parse()?;
+                let range = input.parse()?;
+                Ok(Attr::Size(range))
+            }
             _ => Err(syn::parse::Error::new(
                 ident.span(),
-                "expected `table`, `field`, or `use_place_holder`",
+                "expected `table`, `field`, `use_place_holder`, `generate_deserialize`, or `size`",
             )),
         }
     }
@@ -132,4 +145,17 @@ mod tests {
         // typo
         assert!(syn::parse_str::<Attr>("use_place_helder").is_err());
     }
+
+    #[test]
+    fn parse_attr_size() {
+        let attr: Attr = syn::parse_str("size = 1..=3").unwrap();
+        assert_eq!(attr, Attr::Size(syn::parse_str("1..=3").unwrap()));
+
+        let attr: Attr = syn::parse_str("size = 1..").unwrap();
+        assert_eq!(attr, Attr::Size(syn::parse_str("1..").unwrap()));
+
+        // size must be a range
+        assert!(syn::parse_str::<Attr>("size = 3").is_err());
+        assert!(syn::parse_str::<Attr>("size").is_err());
+    }
 }
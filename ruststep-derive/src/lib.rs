@@ -71,8 +71,78 @@ use table_init::*;
 ///     pub a: A,
 /// }
 /// ```
+///
+/// Records of entities not in the tables are rejected with `Error::UnknownEntityName`,
+/// unless a field of `HashMap<u64, ruststep::ast::Record>` has `#[table_init(untyped)]` attribute
+/// to keep them untyped:
+///
+/// ```
+/// use ruststep::ast::Record;
+/// use ruststep_derive::{as_holder, Holder, TableInit};
+/// use std::collections::HashMap;
+///
+/// #[derive(TableInit, Default)]
+/// pub struct Table {
+///     a: HashMap<u64, as_holder!(A)>,
+///     #[table_init(untyped)]
+///     others: HashMap<u64, Record>,
+/// }
+///
+/// #[derive(Debug, Clone, PartialEq, Holder)]
+/// #[holder(table = Table)]
+/// #[holder(field = a)]
+/// #[holder(generate_deserialize)]
+/// pub struct A {
+///     pub x: f64,
+/// }
+/// ```
+///
+/// Tables of another schema are nested in a field with `#[table_init(foreign)]` attribute,
+/// and records of the entities declared in the schema are inserted into the nested tables.
+/// Place holders of such entities are resolved in the nested tables through
+/// `ruststep::tables::ForeignTable` implemented for the field:
+///
+/// ```
+/// use ruststep_derive::{as_holder, Holder, TableInit};
+/// use std::collections::HashMap;
+///
+/// mod geometry {
+///     use ruststep_derive::{as_holder, Holder, TableInit};
+///     use std::collections::HashMap;
+///
+///     #[derive(TableInit, Default)]
+///     pub struct Table {
+///         point: HashMap<u64, as_holder!(Point)>,
+///     }
+///
+///     #[derive(Debug, Clone, PartialEq, Holder)]
+///     #[holder(table = Table)]
+///     #[holder(field = point)]
+///     #[holder(generate_deserialize)]
+///     pub struct Point {
+///         pub x: f64,
+///     }
+/// }
+/// use geometry::{Point, PointHolder};
+///
+/// #[derive(TableInit, Default)]
+/// pub struct Table {
+///     edge: HashMap<u64, as_holder!(Edge)>,
+///     #[table_init(foreign)]
+///     _geometry: geometry::Table,
+/// }
+///
+/// #[derive(Debug, Clone, PartialEq, Holder)]
+/// #[holder(table = Table)]
+/// #[holder(field = edge)]
+/// #[holder(generate_deserialize)]
+/// pub struct Edge {
+///     #[holder(use_place_holder)]
+///     pub head: Point,
+/// }
+/// ```
 #[proc_macro_error]
-#[proc_macro_derive(TableInit)]
+#[proc_macro_derive(TableInit, attributes(table_init))]
 pub fn derive_table_init_entry(input: TokenStream) -> TokenStream {
     derive_table_init(&syn::parse(input).unwrap()).into()
 }
@@ -131,7 +201,7 @@ fn derive_deserialize(ast: &syn::DeriveInput) -> TokenStream2 {
 /// - `#[holder(use_place_holder)]`
 ///   - This can be both in field or variant attribute
 ///   - Specify the field is not a simple type
-///
+
 #[proc_macro_error]
 #[proc_macro_derive(Holder, attributes(holder))]
 pub fn derive_holder_entry(input: TokenStream) -> TokenStream {
@@ -198,8 +268,12 @@ mod snapshot_tests {
             type Table = Table;
             fn into_owned(self, table: &Self::Table) -> ::ruststep::error::Result<Self::Owned> {
                 Ok(match self {
-                    S1Holder::A(sub) => S1::A(Box::new(sub.into_owned(table)?)),
-                    S1Holder::B(sub) => S1::B(Box::new(sub.into_owned(table)?)),
+                    S1Holder::A(sub) => S1::A(Box::new(
+                        sub.into_owned(::ruststep::tables::ForeignTable::foreign_table(table))?,
+                    )),
+                    S1Holder::B(sub) => S1::B(Box::new(
+                        sub.into_owned(::ruststep::tables::ForeignTable::foreign_table(table))?,
+                    )),
                 })
             }
         }
@@ -211,6 +285,17 @@ mod snapshot_tests {
                 0
             }
         }
+        impl ::ruststep::tables::Refer for S1Holder {
+            #[allow(unused_variables)]
+            fn referred_ids(&self, ids: &mut Vec<u64>) {
+                match self {
+                    S1Holder::A(sub) => ::ruststep::tables::Refer::referred_ids(sub, ids),
+                    S1Holder::B(sub) => ::ruststep::tables::Refer::referred_ids(sub, ids),
+                    #[allow(unreachable_patterns)]
+                    _ => {}
+                }
+            }
+        }
         impl<'de> ::ruststep::serde::de::Deserialize<'de> for S1Holder {
             fn deserialize<D>(deserializer: D) -> ::std::result::Result<Self, D::Error>
             where
@@ -310,8 +395,12 @@ mod snapshot_tests {
             type Table = Tables;
             fn into_owned(self, table: &Self::Table) -> ::ruststep::error::Result<Self::Owned> {
                 Ok(match self {
-                    BaseAnyHolder::Base(sub) => BaseAny::Base(Box::new(sub.into_owned(table)?)),
-                    BaseAnyHolder::Sub(sub) => BaseAny::Sub(Box::new(sub.into_owned(table)?)),
+                    BaseAnyHolder::Base(sub) => BaseAny::Base(Box::new(
+                        sub.into_owned(::ruststep::tables::ForeignTable::foreign_table(table))?,
+                    )),
+                    BaseAnyHolder::Sub(sub) => BaseAny::Sub(Box::new(
+                        sub.into_owned(::ruststep::tables::ForeignTable::foreign_table(table))?,
+                    )),
                 })
             }
         }
@@ -323,6 +412,17 @@ mod snapshot_tests {
                 0
             }
         }
+        impl ::ruststep::tables::Refer for BaseAnyHolder {
+            #[allow(unused_variables)]
+            fn referred_ids(&self, ids: &mut Vec<u64>) {
+                match self {
+                    BaseAnyHolder::Base(sub) => ::ruststep::tables::Refer::referred_ids(sub, ids),
+                    BaseAnyHolder::Sub(sub) => ::ruststep::tables::Refer::referred_ids(sub, ids),
+                    #[allow(unreachable_patterns)]
+                    _ => {}
+                }
+            }
+        }
         impl<'de> ::ruststep::serde::de::Deserialize<'de> for BaseAnyHolder {
             fn deserialize<D>(deserializer: D) -> ::std::result::Result<Self, D::Error>
             where
@@ -433,7 +533,7 @@ mod snapshot_tests {
             fn into_owned(self, table: &Self::Table) -> ::ruststep::error::Result<Self::Owned> {
                 let Sub1Holder { base, y1 } = self;
                 Ok(Sub1 {
-                    base: base.into_owned(table)?,
+                    base: base.into_owned(::ruststep::tables::ForeignTable::foreign_table(table))?,
                     y1: y1,
                 })
             }
@@ -458,6 +558,10 @@ mod snapshot_tests {
                 ::ruststep::tables::owned_iter(self, &self.sub1)
             }
         }
+        #[automatically_derived]
+        impl ::ruststep::tables::Refer for Sub1Holder {
+            fn referred_ids(&self, _ids: &mut Vec<u64>) {}
+        }
         #[doc(hidden)]
         pub struct Sub1HolderVisitor;
         #[automatically_derived]
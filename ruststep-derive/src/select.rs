@@ -39,6 +39,8 @@ impl Input {
         let mut variant_exprs = Vec::new();
         let mut variant_into_exprs = Vec::new();
         let mut place_holders = Vec::new();
+        let ruststep = ruststep_crate();
+        let foreign_table = quote! { #ruststep::tables::ForeignTable::foreign_table(table) };
         for var in &e.variants {
             let HolderAttr { place_holder, .. } = HolderAttr::parse(&var.attrs);
             place_holders.push(place_holder);
@@ -50,7 +52,7 @@ impl Input {
                     if place_holder {
                         // ENTITY case
                         holder_types.push(as_holder_path(&f.ty));
-                        holder_exprs.push(quote! { Box::new(sub.into_owned(table)?) });
+                        holder_exprs.push(quote! { Box::new(sub.into_owned(#foreign_table)?) });
                         variant_exprs.push(quote! { Box::new(owned) });
                         variant_into_exprs.push(quote! { Box::new(owned.into()) });
                     } else {
@@ -62,7 +64,7 @@ impl Input {
                     if place_holder {
                         // *Any case
                         holder_types.push(as_holder_path(&f.ty));
-                        holder_exprs.push(quote! { sub.into_owned(table)? });
+                        holder_exprs.push(quote! { sub.into_owned(#foreign_table)? });
                     } else {
                         // SimpleType case
                         holder_types.push(f.ty.clone());
@@ -136,6 +138,33 @@ impl Input {
         } // quote!
     }
 
+    /// Refers the entities through the variant using place holder
+    fn impl_refer(&self) -> TokenStream2 {
+        let Input {
+            holder_ident,
+            variants,
+            place_holders,
+            ..
+        } = self;
+        let ruststep = ruststep_crate();
+        let variants = variants
+            .iter()
+            .zip(place_holders)
+            .filter_map(|(var, place_holder)| if *place_holder { Some(var) } else { None });
+        quote! {
+            impl #ruststep::tables::Refer for #holder_ident {
+                #[allow(unused_variables)]
+                fn referred_ids(&self, ids: &mut Vec<u64>) {
+                    match self {
+                        #(#holder_ident::#variants(sub) => #ruststep::tables::Refer::referred_ids(sub, ids),)*
+                        #[allow(unreachable_patterns)]
+                        _ => {}
+                    }
+                }
+            }
+        } // quote!
+    }
+
     fn impl_deserialize(&self) -> TokenStream2 {
         let Input {
             name,
@@ -270,6 +299,7 @@ pub fn derive_holder(ident: &syn::Ident, e: &syn::DataEnum, attr: &HolderAttr) -
     let input = Input::parse(ident, e, attr);
     let def_holder_tt = input.def_holder();
     let impl_holder_tt = input.impl_holder();
+    let impl_refer_tt = input.impl_refer();
 
     if attr.generate_deserialize {
         let impl_deserialize_tt = input.impl_deserialize();
@@ -278,6 +308,7 @@ pub fn derive_holder(ident: &syn::Ident, e: &syn::DataEnum, attr: &HolderAttr) -
         quote! {
             #def_holder_tt
             #impl_holder_tt
+            #impl_refer_tt
             #impl_deserialize_tt
             #def_visitor_tt
             #impl_entity_table_tt
@@ -286,6 +317,7 @@ pub fn derive_holder(ident: &syn::Ident, e: &syn::DataEnum, attr: &HolderAttr) -
         quote! {
             #def_holder_tt
             #impl_holder_tt
+            #impl_refer_tt
         } // quote!
     }
 }
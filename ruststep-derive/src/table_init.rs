@@ -1,6 +1,6 @@
 use inflector::Inflector;
 use proc_macro2::TokenStream as TokenStream2;
-use proc_macro_error::{abort_call_site, OptionExt};
+use proc_macro_error::{abort, abort_call_site, OptionExt};
 use quote::quote;
 
 use crate::common::ruststep_crate;
@@ -17,11 +17,63 @@ pub fn derive_table_init(ast: &syn::DeriveInput) -> TokenStream2 {
     }
 }
 
+/// Field of tables struct other than entity tables
+#[derive(Clone, Copy, PartialEq)]
+enum FieldKind {
+    /// Table of an entity
+    Table,
+    /// `#[table_init(untyped)]` keeping records of unknown entities
+    Untyped,
+    /// `#[table_init(index)]` for cached indices cleared when records are appended
+    Index,
+    /// `#[table_init(foreign)]` for nested tables of another schema
+    Foreign,
+}
+
+/// Check `#[table_init(...)]` attribute of a field
+fn field_kind(field: &syn::Field) -> FieldKind {
+    for attr in &field.attrs {
+        if !attr.path().is_ident("table_init") {
+            continue;
+        }
+        match attr.parse_args::<syn::Ident>() {
+            Ok(ident) if ident == "untyped" => return FieldKind::Untyped,
+            Ok(ident) if ident == "index" => return FieldKind::Index,
+            Ok(ident) if ident == "foreign" => return FieldKind::Foreign,
+            _ => abort!(
+                attr,
+                "expected `#[table_init(untyped)]`, `#[table_init(index)]` or `#[table_init(foreign)]`"
+            ),
+        }
+    }
+    FieldKind::Table
+}
+
 fn entity_impl_table_init(ident: &syn::Ident, st: &syn::DataStruct) -> TokenStream2 {
     let mut table_names = Vec::new();
     let mut entity_names = Vec::new();
+    let mut untyped = None;
+    let mut indices = Vec::new();
+    let mut foreigns = Vec::new();
+    let mut foreign_types = Vec::new();
     for field in &st.fields {
         let ident = field.ident.as_ref().expect_or_abort("unreachable!");
+        match field_kind(field) {
+            FieldKind::Table => {}
+            FieldKind::Untyped => {
+                untyped = Some(ident);
+                continue;
+            }
+            FieldKind::Index => {
+                indices.push(ident);
+                continue;
+            }
+            FieldKind::Foreign => {
+                foreigns.push(ident);
+                foreign_types.push(&field.ty);
+                continue;
+            }
+        }
         let name = ident.to_string().to_screaming_snake_case();
         table_names.push(ident);
         entity_names.push(name);
@@ -30,29 +82,61 @@ fn entity_impl_table_init(ident: &syn::Ident, st: &syn::DataStruct) -> TokenStre
 
     let ruststep = ruststep_crate();
 
+    // Records of unknown entities are kept as is in the untyped field if exists
+    let unknown = match untyped {
+        Some(untyped) => quote! {
+            #ruststep::tables::insert_untyped(&mut self.#untyped, *id, record)?
+        },
+        None => quote! {
+            return Err(Error::UnknownEntityName {
+                entity_name: record.name.clone(),
+                schema: "".to_string(),
+            })
+        },
+    };
+
     quote! {
         #[automatically_derived]
         impl #ruststep::tables::TableInit for #ident {
+            fn insert(
+                &mut self,
+                id: u64,
+                record: &#ruststep::ast::Record
+            ) -> #ruststep::error::Result<bool> {
+                use #ruststep::tables::{insert_record, TableInit};
+                match record.name.as_str() {
+                    #(
+                    #entity_names => insert_record(&mut self.#table_names, id, record)?,
+                    )*
+                    // Non-short-circuit `|` inserts the record into every foreign table holding it
+                    _ => return Ok(false #(| self.#foreigns.insert(id, record)?)*),
+                }
+                #(self.#indices.clear();)*
+                Ok(true)
+            }
+
             fn append_data_section(
                 &mut self,
                 data_sec: &#ruststep::ast::DataSection
             ) -> #ruststep::error::Result<()> {
-                use #ruststep::{error::Error, tables::insert_record, ast::EntityInstance};
+                use #ruststep::{error::Error, ast::EntityInstance};
                 for entity in &data_sec.entities {
                     match entity {
-                        EntityInstance::Simple { id, record } => match record.name.as_str() {
-                            #(
-                            #entity_names => insert_record(&mut self.#table_names, *id, record)?,
-                            )*
-                            _ => {
-                                return Err(Error::UnknownEntityName {
-                                    entity_name: record.name.clone(),
-                                    schema: "".to_string(),
-                                });
+                        EntityInstance::Simple { id, record } => {
+                            if !self.insert(*id, record)? {
+                                #unknown;
                             }
-                        },
-                        EntityInstance::Complex { .. } => {
-                            unimplemented!("Complex entity is not supported")
+                        }
+                        EntityInstance::Complex { id, subsuper } => {
+                            return Err(Error::UnsupportedComplexEntity {
+                                id: *id,
+                                entities: subsuper
+                                    .0
+                                    .iter()
+                                    .map(|record| record.name.as_str())
+                                    .collect::<Vec<_>>()
+                                    .join(", "),
+                            });
                         }
                     }
                 }
@@ -60,6 +144,15 @@ fn entity_impl_table_init(ident: &syn::Ident, st: &syn::DataStruct) -> TokenStre
             }
         }
 
+        #(
+        #[automatically_derived]
+        impl #ruststep::tables::ForeignTable<#foreign_types> for #ident {
+            fn foreign_table(&self) -> &#foreign_types {
+                &self.#foreigns
+            }
+        }
+        )*
+
         #[automatically_derived]
         impl ::std::str::FromStr for #ident {
             type Err = #ruststep::error::Error;
@@ -106,8 +199,16 @@ fn tuple_impl_table_init(ident: &syn::Ident, st: &syn::DataStruct) -> TokenStrea
                                 });
                             }
                         },
-                        EntityInstance::Complex { .. } => {
-                            unimplemented!("Complex entity is not supported")
+                        EntityInstance::Complex { id, subsuper } => {
+                            return Err(Error::UnsupportedComplexEntity {
+                                id: *id,
+                                entities: subsuper
+                                    .0
+                                    .iter()
+                                    .map(|record| record.name.as_str())
+                                    .collect::<Vec<_>>()
+                                    .join(", "),
+                            });
                         }
                     }
                 }
@@ -11,6 +11,7 @@ pub fn derive_holder(ident: &syn::Ident, st: &syn::DataStruct, attr: &HolderAttr
     let def_holder_tt = def_holder(ident, st);
     let impl_holder_tt = impl_holder(ident, attr, st);
     let impl_entity_table_tt = impl_entity_table(ident, attr);
+    let impl_refer_tt = impl_refer(ident, st);
     if attr.generate_deserialize {
         let def_visitor_tt = def_visitor(&holder_ident, &name, st);
         let impl_deserialize_tt = impl_deserialize(&holder_ident, &name, st);
@@ -19,6 +20,7 @@ pub fn derive_holder(ident: &syn::Ident, st: &syn::DataStruct, attr: &HolderAttr
             #def_holder_tt
             #impl_holder_tt
             #impl_entity_table_tt
+            #impl_refer_tt
             #def_visitor_tt
             #impl_deserialize_tt
             #impl_with_visitor_tt
@@ -28,6 +30,7 @@ pub fn derive_holder(ident: &syn::Ident, st: &syn::DataStruct, attr: &HolderAttr
             #def_holder_tt
             #impl_holder_tt
             #impl_entity_table_tt
+            #impl_refer_tt
         }
     }
 }
@@ -48,6 +51,7 @@ pub fn impl_holder(ident: &syn::Ident, table: &HolderAttr, st: &syn::DataStruct)
     let FieldEntries {
         holder_types,
         into_owned,
+        size_checks,
     } = FieldEntries::parse(st);
     let HolderAttr { table, .. } = table;
     let tuple_len = holder_types.len();
@@ -60,6 +64,7 @@ pub fn impl_holder(ident: &syn::Ident, table: &HolderAttr, st: &syn::DataStruct)
             type Table = #table;
             type Owned = #ident;
             fn into_owned(self, #table_arg: &Self::Table) -> #ruststep::error::Result<Self::Owned> {
+                #(#size_checks)*
                 Ok(#ident ( #(#into_owned),* ))
             }
         }
@@ -93,6 +98,28 @@ pub fn impl_entity_table(ident: &syn::Ident, table: &HolderAttr) -> TokenStream2
     }
 }
 
+// Refers the entities through the fields using place holder,
+// e.g. `#6` in `KNOB_REF(#6)` for `TYPE knob_ref = knob; END_TYPE;`
+fn impl_refer(ident: &syn::Ident, st: &syn::DataStruct) -> TokenStream2 {
+    let holder_ident = as_holder_ident(ident);
+    let ruststep = ruststep_crate();
+    let indices = st
+        .fields
+        .iter()
+        .enumerate()
+        .filter(|(_, field)| HolderAttr::parse(&field.attrs).place_holder)
+        .map(|(i, _)| syn::Index::from(i));
+    quote! {
+        #[automatically_derived]
+        impl #ruststep::tables::Refer for #holder_ident {
+            #[allow(unused_variables)]
+            fn referred_ids(&self, ids: &mut Vec<u64>) {
+                #( #ruststep::tables::Refer::referred_ids(&self.#indices, ids); )*
+            }
+        }
+    }
+}
+
 // `name` may be different from `ident`
 // because this will be used for both Entity struct and its `*Holder` struct.
 fn def_visitor(ident: &syn::Ident, name: &str, st: &syn::DataStruct) -> TokenStream2 {
@@ -187,6 +214,7 @@ fn impl_with_visitor(ident: &syn::Ident) -> TokenStream2 {
 struct FieldEntries {
     holder_types: Vec<syn::Type>,
     into_owned: Vec<TokenStream2>,
+    size_checks: Vec<TokenStream2>,
 }
 
 impl FieldEntries {
@@ -195,24 +223,35 @@ impl FieldEntries {
 
         let mut holder_types = Vec::new();
         let mut into_owned = Vec::new();
+        let mut size_checks = Vec::new();
+        let ruststep = ruststep_crate();
 
         for (i, field) in st.fields.iter().enumerate() {
             let ft: FieldType = field.ty.clone().try_into().unwrap();
             let index = syn::Index::from(i);
 
-            let HolderAttr { place_holder, .. } = HolderAttr::parse(&field.attrs);
+            let HolderAttr {
+                place_holder, size, ..
+            } = HolderAttr::parse(&field.attrs);
+            if let Some(size) = size {
+                let name = i.to_string();
+                size_checks.push(quote! {
+                    #ruststep::tables::check_size(#name, &self.#index, #size)?;
+                });
+            }
             if place_holder {
+                let table = quote! { #ruststep::tables::ForeignTable::foreign_table(#table_arg) };
                 match &ft {
                     FieldType::Path(_) => {
-                        into_owned.push(quote! { self.#index.into_owned(#table_arg)? });
+                        into_owned.push(quote! { self.#index.into_owned(#table)? });
                     }
                     FieldType::Optional(_) => {
-                        into_owned.push(quote! { self.#index.map(|holder| holder.into_owned(#table_arg)).transpose()? },);
+                        into_owned.push(quote! { self.#index.map(|holder| holder.into_owned(#table)).transpose()? },);
                     }
                     FieldType::List(_) => into_owned.push(quote! {
                         self.#index
                             .into_iter()
-                            .map(|v| v.into_owned(#table_arg))
+                            .map(|v| v.into_owned(#table))
                             .collect::<::std::result::Result<Vec<_>, _>>()?
                     }),
                     FieldType::Boxed(_) => abort_call_site!("Unexpected Box<T>"),
@@ -226,6 +265,7 @@ impl FieldEntries {
         FieldEntries {
             holder_types,
             into_owned,
+            size_checks,
         }
     }
 }
@@ -2,7 +2,7 @@
 pub mod explicit_draughting {
     use crate::{as_holder, derive_more::*, primitive::*, Holder, TableInit};
     use std::collections::HashMap;
-    #[derive(Debug, Clone, PartialEq, Default, TableInit)]
+    #[derive(Debug, Clone, Default, TableInit)]
     pub struct Tables {
         address: HashMap<u64, as_holder!(Address)>,
         angular_dimension: HashMap<u64, as_holder!(AngularDimension)>,
@@ -308,6 +308,402 @@ pub mod explicit_draughting {
         unit: HashMap<u64, as_holder!(Unit)>,
         vector_or_direction: HashMap<u64, as_holder!(VectorOrDirection)>,
         year_number: HashMap<u64, as_holder!(YearNumber)>,
+        #[table_init(index)]
+        _inverse_index: crate::tables::InverseIndex,
+    }
+    impl PartialEq for Tables {
+        fn eq(&self, other: &Self) -> bool {
+            let equal = true;
+            let equal = equal && self.address == other.address;
+            let equal = equal && self.angular_dimension == other.angular_dimension;
+            let equal =
+                equal && self.annotation_curve_occurrence == other.annotation_curve_occurrence;
+            let equal = equal && self.annotation_fill_area == other.annotation_fill_area;
+            let equal = equal
+                && self.annotation_fill_area_occurrence == other.annotation_fill_area_occurrence;
+            let equal = equal && self.annotation_occurrence == other.annotation_occurrence;
+            let equal = equal
+                && self.annotation_subfigure_occurrence == other.annotation_subfigure_occurrence;
+            let equal = equal && self.annotation_symbol == other.annotation_symbol;
+            let equal =
+                equal && self.annotation_symbol_occurrence == other.annotation_symbol_occurrence;
+            let equal = equal && self.annotation_text == other.annotation_text;
+            let equal =
+                equal && self.annotation_text_occurrence == other.annotation_text_occurrence;
+            let equal = equal && self.application_context == other.application_context;
+            let equal =
+                equal && self.application_context_element == other.application_context_element;
+            let equal = equal
+                && self.application_protocol_definition == other.application_protocol_definition;
+            let equal = equal && self.approval == other.approval;
+            let equal = equal && self.approval_assignment == other.approval_assignment;
+            let equal = equal && self.approval_date_time == other.approval_date_time;
+            let equal =
+                equal && self.approval_person_organization == other.approval_person_organization;
+            let equal = equal && self.approval_role == other.approval_role;
+            let equal = equal && self.approval_status == other.approval_status;
+            let equal = equal && self.area_in_set == other.area_in_set;
+            let equal = equal && self.axis2_placement_2d == other.axis2_placement_2d;
+            let equal = equal && self.b_spline_curve == other.b_spline_curve;
+            let equal = equal && self.b_spline_curve_with_knots == other.b_spline_curve_with_knots;
+            let equal = equal && self.bezier_curve == other.bezier_curve;
+            let equal = equal && self.bounded_curve == other.bounded_curve;
+            let equal = equal && self.calendar_date == other.calendar_date;
+            let equal = equal && self.camera_image == other.camera_image;
+            let equal =
+                equal && self.camera_image_2d_with_scale == other.camera_image_2d_with_scale;
+            let equal = equal && self.camera_model == other.camera_model;
+            let equal = equal && self.camera_model_d2 == other.camera_model_d2;
+            let equal = equal && self.camera_usage == other.camera_usage;
+            let equal = equal && self.cartesian_point == other.cartesian_point;
+            let equal = equal && self.circle == other.circle;
+            let equal = equal && self.colour == other.colour;
+            let equal = equal && self.colour_rgb == other.colour_rgb;
+            let equal = equal && self.colour_specification == other.colour_specification;
+            let equal = equal && self.composite_curve == other.composite_curve;
+            let equal = equal && self.composite_curve_segment == other.composite_curve_segment;
+            let equal = equal && self.composite_text == other.composite_text;
+            let equal = equal
+                && self.composite_text_with_associated_curves
+                    == other.composite_text_with_associated_curves;
+            let equal = equal
+                && self.composite_text_with_blanking_box == other.composite_text_with_blanking_box;
+            let equal =
+                equal && self.composite_text_with_extent == other.composite_text_with_extent;
+            let equal = equal && self.conic == other.conic;
+            let equal = equal
+                && self.context_dependent_invisibility == other.context_dependent_invisibility;
+            let equal = equal && self.contract == other.contract;
+            let equal = equal && self.contract_assignment == other.contract_assignment;
+            let equal = equal && self.contract_type == other.contract_type;
+            let equal = equal && self.conversion_based_unit == other.conversion_based_unit;
+            let equal = equal && self.curve == other.curve;
+            let equal = equal && self.curve_dimension == other.curve_dimension;
+            let equal = equal && self.curve_style == other.curve_style;
+            let equal = equal && self.curve_style_font == other.curve_style_font;
+            let equal = equal && self.curve_style_font_pattern == other.curve_style_font_pattern;
+            let equal = equal && self.date == other.date;
+            let equal = equal && self.datum_feature_callout == other.datum_feature_callout;
+            let equal = equal && self.datum_target_callout == other.datum_target_callout;
+            let equal = equal && self.defined_symbol == other.defined_symbol;
+            let equal = equal && self.diameter_dimension == other.diameter_dimension;
+            let equal = equal
+                && self.dimension_callout_component_relationship
+                    == other.dimension_callout_component_relationship;
+            let equal = equal
+                && self.dimension_callout_relationship == other.dimension_callout_relationship;
+            let equal = equal && self.dimension_curve == other.dimension_curve;
+            let equal = equal
+                && self.dimension_curve_directed_callout == other.dimension_curve_directed_callout;
+            let equal =
+                equal && self.dimension_curve_terminator == other.dimension_curve_terminator;
+            let equal = equal && self.dimension_pair == other.dimension_pair;
+            let equal = equal && self.dimensional_exponents == other.dimensional_exponents;
+            let equal = equal && self.direction == other.direction;
+            let equal = equal && self.document == other.document;
+            let equal = equal && self.document_reference == other.document_reference;
+            let equal = equal && self.document_type == other.document_type;
+            let equal = equal
+                && self.draughting_annotation_occurrence == other.draughting_annotation_occurrence;
+            let equal = equal
+                && self.draughting_approval_assignment == other.draughting_approval_assignment;
+            let equal = equal && self.draughting_callout == other.draughting_callout;
+            let equal = equal
+                && self.draughting_callout_relationship == other.draughting_callout_relationship;
+            let equal = equal
+                && self.draughting_contract_assignment == other.draughting_contract_assignment;
+            let equal =
+                equal && self.draughting_drawing_revision == other.draughting_drawing_revision;
+            let equal = equal && self.draughting_elements == other.draughting_elements;
+            let equal =
+                equal && self.draughting_group_assignment == other.draughting_group_assignment;
+            let equal = equal && self.draughting_model == other.draughting_model;
+            let equal = equal
+                && self.draughting_organization_assignment
+                    == other.draughting_organization_assignment;
+            let equal = equal
+                && self.draughting_person_and_organization_assignment
+                    == other.draughting_person_and_organization_assignment;
+            let equal =
+                equal && self.draughting_person_assignment == other.draughting_person_assignment;
+            let equal =
+                equal && self.draughting_pre_defined_colour == other.draughting_pre_defined_colour;
+            let equal = equal
+                && self.draughting_pre_defined_curve_font
+                    == other.draughting_pre_defined_curve_font;
+            let equal = equal
+                && self.draughting_pre_defined_text_font == other.draughting_pre_defined_text_font;
+            let equal = equal && self.draughting_presented_item == other.draughting_presented_item;
+            let equal = equal
+                && self.draughting_security_classification_assignment
+                    == other.draughting_security_classification_assignment;
+            let equal = equal
+                && self.draughting_specification_reference
+                    == other.draughting_specification_reference;
+            let equal = equal
+                && self.draughting_subfigure_representation
+                    == other.draughting_subfigure_representation;
+            let equal = equal
+                && self.draughting_symbol_representation == other.draughting_symbol_representation;
+            let equal = equal
+                && self.draughting_text_literal_with_delineation
+                    == other.draughting_text_literal_with_delineation;
+            let equal = equal && self.draughting_title == other.draughting_title;
+            let equal = equal && self.drawing_definition == other.drawing_definition;
+            let equal = equal && self.drawing_revision == other.drawing_revision;
+            let equal = equal && self.drawing_sheet_layout == other.drawing_sheet_layout;
+            let equal = equal && self.drawing_sheet_revision == other.drawing_sheet_revision;
+            let equal =
+                equal && self.drawing_sheet_revision_usage == other.drawing_sheet_revision_usage;
+            let equal = equal && self.ellipse == other.ellipse;
+            let equal = equal && self.external_source == other.external_source;
+            let equal =
+                equal && self.externally_defined_curve_font == other.externally_defined_curve_font;
+            let equal = equal
+                && self.externally_defined_hatch_style == other.externally_defined_hatch_style;
+            let equal = equal && self.externally_defined_item == other.externally_defined_item;
+            let equal = equal && self.externally_defined_symbol == other.externally_defined_symbol;
+            let equal =
+                equal && self.externally_defined_text_font == other.externally_defined_text_font;
+            let equal =
+                equal && self.externally_defined_tile_style == other.externally_defined_tile_style;
+            let equal = equal && self.fill_area_style == other.fill_area_style;
+            let equal = equal && self.fill_area_style_colour == other.fill_area_style_colour;
+            let equal = equal && self.fill_area_style_hatching == other.fill_area_style_hatching;
+            let equal = equal
+                && self.fill_area_style_tile_symbol_with_style
+                    == other.fill_area_style_tile_symbol_with_style;
+            let equal = equal && self.fill_area_style_tiles == other.fill_area_style_tiles;
+            let equal = equal && self.geometric_curve_set == other.geometric_curve_set;
+            let equal = equal
+                && self.geometric_representation_context == other.geometric_representation_context;
+            let equal =
+                equal && self.geometric_representation_item == other.geometric_representation_item;
+            let equal = equal && self.geometric_set == other.geometric_set;
+            let equal =
+                equal && self.geometrical_tolerance_callout == other.geometrical_tolerance_callout;
+            let equal = equal
+                && self.geometrically_bounded_2d_wireframe_representation
+                    == other.geometrically_bounded_2d_wireframe_representation;
+            let equal =
+                equal && self.global_unit_assigned_context == other.global_unit_assigned_context;
+            let equal = equal && self.group == other.group;
+            let equal = equal && self.group_assignment == other.group_assignment;
+            let equal = equal && self.group_relationship == other.group_relationship;
+            let equal = equal && self.hyperbola == other.hyperbola;
+            let equal = equal && self.invisibility == other.invisibility;
+            let equal = equal && self.leader_curve == other.leader_curve;
+            let equal = equal && self.leader_directed_callout == other.leader_directed_callout;
+            let equal = equal && self.leader_directed_dimension == other.leader_directed_dimension;
+            let equal = equal && self.leader_terminator == other.leader_terminator;
+            let equal = equal && self.length_measure_with_unit == other.length_measure_with_unit;
+            let equal = equal && self.length_unit == other.length_unit;
+            let equal = equal && self.line == other.line;
+            let equal = equal && self.linear_dimension == other.linear_dimension;
+            let equal = equal && self.mapped_item == other.mapped_item;
+            let equal = equal && self.measure_with_unit == other.measure_with_unit;
+            let equal = equal && self.named_unit == other.named_unit;
+            let equal = equal && self.offset_curve_2d == other.offset_curve_2d;
+            let equal =
+                equal && self.one_direction_repeat_factor == other.one_direction_repeat_factor;
+            let equal = equal && self.ordinate_dimension == other.ordinate_dimension;
+            let equal = equal && self.organization == other.organization;
+            let equal = equal && self.organization_assignment == other.organization_assignment;
+            let equal = equal && self.organization_role == other.organization_role;
+            let equal = equal && self.organizational_address == other.organizational_address;
+            let equal = equal && self.parabola == other.parabola;
+            let equal = equal && self.person == other.person;
+            let equal = equal && self.person_and_organization == other.person_and_organization;
+            let equal = equal
+                && self.person_and_organization_assignment
+                    == other.person_and_organization_assignment;
+            let equal =
+                equal && self.person_and_organization_role == other.person_and_organization_role;
+            let equal = equal && self.person_assignment == other.person_assignment;
+            let equal = equal && self.person_role == other.person_role;
+            let equal = equal && self.personal_address == other.personal_address;
+            let equal = equal && self.placement == other.placement;
+            let equal = equal && self.planar_box == other.planar_box;
+            let equal = equal && self.planar_extent == other.planar_extent;
+            let equal =
+                equal && self.plane_angle_measure_with_unit == other.plane_angle_measure_with_unit;
+            let equal = equal && self.plane_angle_unit == other.plane_angle_unit;
+            let equal = equal && self.point == other.point;
+            let equal = equal && self.point_on_curve == other.point_on_curve;
+            let equal = equal && self.polyline == other.polyline;
+            let equal = equal && self.pre_defined_colour == other.pre_defined_colour;
+            let equal = equal && self.pre_defined_curve_font == other.pre_defined_curve_font;
+            let equal =
+                equal && self.pre_defined_dimension_symbol == other.pre_defined_dimension_symbol;
+            let equal = equal
+                && self.pre_defined_geometrical_tolerance_symbol
+                    == other.pre_defined_geometrical_tolerance_symbol;
+            let equal = equal && self.pre_defined_item == other.pre_defined_item;
+            let equal = equal
+                && self.pre_defined_point_marker_symbol == other.pre_defined_point_marker_symbol;
+            let equal = equal && self.pre_defined_symbol == other.pre_defined_symbol;
+            let equal =
+                equal && self.pre_defined_terminator_symbol == other.pre_defined_terminator_symbol;
+            let equal = equal && self.pre_defined_text_font == other.pre_defined_text_font;
+            let equal = equal && self.presentation_area == other.presentation_area;
+            let equal =
+                equal && self.presentation_layer_assignment == other.presentation_layer_assignment;
+            let equal = equal && self.presentation_layer_usage == other.presentation_layer_usage;
+            let equal =
+                equal && self.presentation_representation == other.presentation_representation;
+            let equal = equal && self.presentation_set == other.presentation_set;
+            let equal = equal && self.presentation_size == other.presentation_size;
+            let equal =
+                equal && self.presentation_style_assignment == other.presentation_style_assignment;
+            let equal =
+                equal && self.presentation_style_by_context == other.presentation_style_by_context;
+            let equal = equal && self.presentation_view == other.presentation_view;
+            let equal = equal && self.presented_item == other.presented_item;
+            let equal =
+                equal && self.presented_item_representation == other.presented_item_representation;
+            let equal = equal && self.product == other.product;
+            let equal = equal && self.product_context == other.product_context;
+            let equal = equal && self.product_definition == other.product_definition;
+            let equal =
+                equal && self.product_definition_context == other.product_definition_context;
+            let equal =
+                equal && self.product_definition_formation == other.product_definition_formation;
+            let equal = equal && self.product_definition_shape == other.product_definition_shape;
+            let equal = equal && self.projection_curve == other.projection_curve;
+            let equal =
+                equal && self.projection_directed_callout == other.projection_directed_callout;
+            let equal = equal && self.property_definition == other.property_definition;
+            let equal = equal
+                && self.property_definition_representation
+                    == other.property_definition_representation;
+            let equal = equal && self.quasi_uniform_curve == other.quasi_uniform_curve;
+            let equal = equal && self.radius_dimension == other.radius_dimension;
+            let equal = equal && self.rational_b_spline_curve == other.rational_b_spline_curve;
+            let equal = equal && self.representation == other.representation;
+            let equal = equal && self.representation_context == other.representation_context;
+            let equal = equal && self.representation_item == other.representation_item;
+            let equal = equal && self.representation_map == other.representation_map;
+            let equal = equal && self.security_classification == other.security_classification;
+            let equal = equal
+                && self.security_classification_assignment
+                    == other.security_classification_assignment;
+            let equal =
+                equal && self.security_classification_level == other.security_classification_level;
+            let equal = equal
+                && self.shape_definition_representation == other.shape_definition_representation;
+            let equal = equal && self.shape_representation == other.shape_representation;
+            let equal = equal && self.si_unit == other.si_unit;
+            let equal =
+                equal && self.structured_dimension_callout == other.structured_dimension_callout;
+            let equal = equal && self.styled_item == other.styled_item;
+            let equal = equal && self.symbol_colour == other.symbol_colour;
+            let equal = equal && self.symbol_representation == other.symbol_representation;
+            let equal = equal && self.symbol_representation_map == other.symbol_representation_map;
+            let equal = equal && self.symbol_style == other.symbol_style;
+            let equal = equal && self.symbol_target == other.symbol_target;
+            let equal = equal && self.terminator_symbol == other.terminator_symbol;
+            let equal = equal && self.text_literal == other.text_literal;
+            let equal = equal
+                && self.text_literal_with_associated_curves
+                    == other.text_literal_with_associated_curves;
+            let equal = equal
+                && self.text_literal_with_blanking_box == other.text_literal_with_blanking_box;
+            let equal =
+                equal && self.text_literal_with_delineation == other.text_literal_with_delineation;
+            let equal = equal && self.text_literal_with_extent == other.text_literal_with_extent;
+            let equal = equal && self.text_style == other.text_style;
+            let equal =
+                equal && self.text_style_for_defined_font == other.text_style_for_defined_font;
+            let equal = equal
+                && self.text_style_with_box_characteristics
+                    == other.text_style_with_box_characteristics;
+            let equal = equal && self.text_style_with_mirror == other.text_style_with_mirror;
+            let equal = equal && self.trimmed_curve == other.trimmed_curve;
+            let equal =
+                equal && self.two_direction_repeat_factor == other.two_direction_repeat_factor;
+            let equal = equal && self.uniform_curve == other.uniform_curve;
+            let equal = equal && self.vector == other.vector;
+            let equal = equal && self.approved_item == other.approved_item;
+            let equal = equal && self.area_or_view == other.area_or_view;
+            let equal = equal && self.axis2_placement == other.axis2_placement;
+            let equal = equal && self.box_characteristic_select == other.box_characteristic_select;
+            let equal = equal && self.box_height == other.box_height;
+            let equal = equal && self.box_rotate_angle == other.box_rotate_angle;
+            let equal = equal && self.box_slant_angle == other.box_slant_angle;
+            let equal = equal && self.box_width == other.box_width;
+            let equal = equal && self.character_spacing_select == other.character_spacing_select;
+            let equal = equal && self.character_style_select == other.character_style_select;
+            let equal = equal && self.characterized_definition == other.characterized_definition;
+            let equal = equal
+                && self.characterized_product_definition == other.characterized_product_definition;
+            let equal = equal && self.classified_item == other.classified_item;
+            let equal = equal && self.contracted_item == other.contracted_item;
+            let equal = equal
+                && self.curve_font_or_scaled_curve_font_select
+                    == other.curve_font_or_scaled_curve_font_select;
+            let equal = equal
+                && self.curve_or_annotation_curve_occurrence
+                    == other.curve_or_annotation_curve_occurrence;
+            let equal = equal && self.curve_or_render == other.curve_or_render;
+            let equal = equal && self.curve_style_font_select == other.curve_style_font_select;
+            let equal = equal && self.date_time_select == other.date_time_select;
+            let equal = equal && self.day_in_month_number == other.day_in_month_number;
+            let equal = equal && self.defined_symbol_select == other.defined_symbol_select;
+            let equal = equal && self.dimension_count == other.dimension_count;
+            let equal =
+                equal && self.draughting_callout_element == other.draughting_callout_element;
+            let equal = equal && self.draughting_grouped_item == other.draughting_grouped_item;
+            let equal =
+                equal && self.draughting_organization_item == other.draughting_organization_item;
+            let equal = equal
+                && self.draughting_presented_item_select == other.draughting_presented_item_select;
+            let equal = equal && self.draughting_titled_item == other.draughting_titled_item;
+            let equal = equal
+                && self.fill_area_style_tile_shape_select
+                    == other.fill_area_style_tile_shape_select;
+            let equal = equal && self.fill_style_select == other.fill_style_select;
+            let equal = equal && self.font_select == other.font_select;
+            let equal = equal && self.geometric_set_select == other.geometric_set_select;
+            let equal = equal && self.hiding_or_blanking_select == other.hiding_or_blanking_select;
+            let equal = equal && self.identifier == other.identifier;
+            let equal = equal && self.invisibility_context == other.invisibility_context;
+            let equal = equal && self.invisible_item == other.invisible_item;
+            let equal = equal && self.label == other.label;
+            let equal = equal && self.layered_item == other.layered_item;
+            let equal = equal && self.length_measure == other.length_measure;
+            let equal = equal && self.measure_value == other.measure_value;
+            let equal = equal && self.month_in_year_number == other.month_in_year_number;
+            let equal = equal && self.parameter_value == other.parameter_value;
+            let equal =
+                equal && self.person_organization_select == other.person_organization_select;
+            let equal = equal && self.plane_angle_measure == other.plane_angle_measure;
+            let equal = equal && self.positive_length_measure == other.positive_length_measure;
+            let equal = equal && self.positive_ratio_measure == other.positive_ratio_measure;
+            let equal = equal && self.presentable_text == other.presentable_text;
+            let equal = equal
+                && self.presentation_representation_select
+                    == other.presentation_representation_select;
+            let equal = equal
+                && self.presentation_size_assignment_select
+                    == other.presentation_size_assignment_select;
+            let equal = equal && self.presentation_style_select == other.presentation_style_select;
+            let equal = equal && self.ratio_measure == other.ratio_measure;
+            let equal = equal && self.shape_definition == other.shape_definition;
+            let equal = equal && self.size_select == other.size_select;
+            let equal = equal && self.source_item == other.source_item;
+            let equal = equal && self.specified_item == other.specified_item;
+            let equal = equal && self.style_context_select == other.style_context_select;
+            let equal = equal && self.symbol_style_select == other.symbol_style_select;
+            let equal = equal && self.text == other.text;
+            let equal = equal && self.text_alignment == other.text_alignment;
+            let equal = equal && self.text_delineation == other.text_delineation;
+            let equal = equal && self.text_or_character == other.text_or_character;
+            let equal = equal && self.trimming_select == other.trimming_select;
+            let equal = equal && self.unit == other.unit;
+            let equal = equal && self.vector_or_direction == other.vector_or_direction;
+            let equal = equal && self.year_number == other.year_number;
+            equal
+        }
     }
     impl Tables {
         pub fn address_holders(&self) -> &HashMap<u64, as_holder!(Address)> {
@@ -1429,6 +1825,12 @@ pub mod explicit_draughting {
             &self.year_number
         }
     }
+    #[doc = " ```text"]
+    #[doc = " TYPE approved_item = SELECT"]
+    #[doc = "   (drawing_revision,"]
+    #[doc = "    drawing_sheet_revision);"]
+    #[doc = " END_TYPE; -- approved_item"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
     #[holder(generate_deserialize)]
@@ -1438,6 +1840,22 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         DrawingSheetRevision(Box<DrawingSheetRevision>),
     }
+    impl From<DrawingRevisionAny> for ApprovedItem {
+        fn from(value: DrawingRevisionAny) -> Self {
+            ApprovedItem::DrawingRevision(value)
+        }
+    }
+    impl From<DrawingSheetRevision> for ApprovedItem {
+        fn from(value: DrawingSheetRevision) -> Self {
+            ApprovedItem::DrawingSheetRevision(Box::new(value))
+        }
+    }
+    #[doc = " ```text"]
+    #[doc = " TYPE area_or_view = SELECT"]
+    #[doc = "   (presentation_area,"]
+    #[doc = "    presentation_view);"]
+    #[doc = " END_TYPE; -- area_or_view"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
     #[holder(generate_deserialize)]
@@ -1447,6 +1865,21 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         PresentationView(Box<PresentationView>),
     }
+    impl From<PresentationAreaAny> for AreaOrView {
+        fn from(value: PresentationAreaAny) -> Self {
+            AreaOrView::PresentationArea(value)
+        }
+    }
+    impl From<PresentationView> for AreaOrView {
+        fn from(value: PresentationView) -> Self {
+            AreaOrView::PresentationView(Box::new(value))
+        }
+    }
+    #[doc = " ```text"]
+    #[doc = " TYPE axis2_placement = SELECT"]
+    #[doc = "   (axis2_placement_2d);"]
+    #[doc = " END_TYPE; -- axis2_placement"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
     #[holder(generate_deserialize)]
@@ -1454,6 +1887,21 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         Axis2Placement2D(Box<Axis2Placement2D>),
     }
+    impl From<Axis2Placement2D> for Axis2Placement {
+        fn from(value: Axis2Placement2D) -> Self {
+            Axis2Placement::Axis2Placement2D(Box::new(value))
+        }
+    }
+    #[doc = " ```text"]
+    #[doc = " TYPE b_spline_curve_form = ENUMERATION OF"]
+    #[doc = "   (elliptic_arc,"]
+    #[doc = "    polyline_form,"]
+    #[doc = "    parabolic_arc,"]
+    #[doc = "    circular_arc,"]
+    #[doc = "    unspecified,"]
+    #[doc = "    hyperbolic_arc);"]
+    #[doc = " END_TYPE; -- b_spline_curve_form"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: serde :: Deserialize)]
     pub enum BSplineCurveForm {
         EllipticArc,
@@ -1463,6 +1911,14 @@ pub mod explicit_draughting {
         Unspecified,
         HyperbolicArc,
     }
+    #[doc = " ```text"]
+    #[doc = " TYPE box_characteristic_select = SELECT"]
+    #[doc = "   (box_height,"]
+    #[doc = "    box_width,"]
+    #[doc = "    box_slant_angle,"]
+    #[doc = "    box_rotate_angle);"]
+    #[doc = " END_TYPE; -- box_characteristic_select"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
     #[holder(generate_deserialize)]
@@ -1476,6 +1932,30 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         BoxRotateAngle(Box<BoxRotateAngle>),
     }
+    impl From<BoxHeight> for BoxCharacteristicSelect {
+        fn from(value: BoxHeight) -> Self {
+            BoxCharacteristicSelect::BoxHeight(Box::new(value))
+        }
+    }
+    impl From<BoxWidth> for BoxCharacteristicSelect {
+        fn from(value: BoxWidth) -> Self {
+            BoxCharacteristicSelect::BoxWidth(Box::new(value))
+        }
+    }
+    impl From<BoxSlantAngle> for BoxCharacteristicSelect {
+        fn from(value: BoxSlantAngle) -> Self {
+            BoxCharacteristicSelect::BoxSlantAngle(Box::new(value))
+        }
+    }
+    impl From<BoxRotateAngle> for BoxCharacteristicSelect {
+        fn from(value: BoxRotateAngle) -> Self {
+            BoxCharacteristicSelect::BoxRotateAngle(Box::new(value))
+        }
+    }
+    #[doc = " ```text"]
+    #[doc = " TYPE box_height = positive_ratio_measure;"]
+    #[doc = " END_TYPE; -- box_height"]
+    #[doc = " ```"]
     #[derive(
         Clone, Debug, PartialEq, AsRef, Deref, DerefMut, Into, From, :: ruststep_derive :: Holder,
     )]
@@ -1483,6 +1963,10 @@ pub mod explicit_draughting {
     # [holder (field = box_height)]
     #[holder(generate_deserialize)]
     pub struct BoxHeight(#[holder(use_place_holder)] pub PositiveRatioMeasure);
+    #[doc = " ```text"]
+    #[doc = " TYPE box_rotate_angle = plane_angle_measure;"]
+    #[doc = " END_TYPE; -- box_rotate_angle"]
+    #[doc = " ```"]
     #[derive(
         Clone, Debug, PartialEq, AsRef, Deref, DerefMut, Into, From, :: ruststep_derive :: Holder,
     )]
@@ -1490,6 +1974,10 @@ pub mod explicit_draughting {
     # [holder (field = box_rotate_angle)]
     #[holder(generate_deserialize)]
     pub struct BoxRotateAngle(#[holder(use_place_holder)] pub PlaneAngleMeasure);
+    #[doc = " ```text"]
+    #[doc = " TYPE box_slant_angle = plane_angle_measure;"]
+    #[doc = " END_TYPE; -- box_slant_angle"]
+    #[doc = " ```"]
     #[derive(
         Clone, Debug, PartialEq, AsRef, Deref, DerefMut, Into, From, :: ruststep_derive :: Holder,
     )]
@@ -1497,6 +1985,10 @@ pub mod explicit_draughting {
     # [holder (field = box_slant_angle)]
     #[holder(generate_deserialize)]
     pub struct BoxSlantAngle(#[holder(use_place_holder)] pub PlaneAngleMeasure);
+    #[doc = " ```text"]
+    #[doc = " TYPE box_width = positive_ratio_measure;"]
+    #[doc = " END_TYPE; -- box_width"]
+    #[doc = " ```"]
     #[derive(
         Clone, Debug, PartialEq, AsRef, Deref, DerefMut, Into, From, :: ruststep_derive :: Holder,
     )]
@@ -1504,6 +1996,13 @@ pub mod explicit_draughting {
     # [holder (field = box_width)]
     #[holder(generate_deserialize)]
     pub struct BoxWidth(#[holder(use_place_holder)] pub PositiveRatioMeasure);
+    #[doc = " ```text"]
+    #[doc = " TYPE character_spacing_select = SELECT"]
+    #[doc = "   (length_measure,"]
+    #[doc = "    ratio_measure,"]
+    #[doc = "    measure_with_unit);"]
+    #[doc = " END_TYPE; -- character_spacing_select"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
     #[holder(generate_deserialize)]
@@ -1515,6 +2014,26 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         MeasureWithUnit(MeasureWithUnitAny),
     }
+    impl From<LengthMeasure> for CharacterSpacingSelect {
+        fn from(value: LengthMeasure) -> Self {
+            CharacterSpacingSelect::LengthMeasure(Box::new(value))
+        }
+    }
+    impl From<RatioMeasure> for CharacterSpacingSelect {
+        fn from(value: RatioMeasure) -> Self {
+            CharacterSpacingSelect::RatioMeasure(Box::new(value))
+        }
+    }
+    impl From<MeasureWithUnitAny> for CharacterSpacingSelect {
+        fn from(value: MeasureWithUnitAny) -> Self {
+            CharacterSpacingSelect::MeasureWithUnit(value)
+        }
+    }
+    #[doc = " ```text"]
+    #[doc = " TYPE character_style_select = SELECT"]
+    #[doc = "   (text_style_for_defined_font);"]
+    #[doc = " END_TYPE; -- character_style_select"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
     #[holder(generate_deserialize)]
@@ -1522,6 +2041,17 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         TextStyleForDefinedFont(Box<TextStyleForDefinedFont>),
     }
+    impl From<TextStyleForDefinedFont> for CharacterStyleSelect {
+        fn from(value: TextStyleForDefinedFont) -> Self {
+            CharacterStyleSelect::TextStyleForDefinedFont(Box::new(value))
+        }
+    }
+    #[doc = " ```text"]
+    #[doc = " TYPE characterized_definition = SELECT"]
+    #[doc = "   (characterized_product_definition,"]
+    #[doc = "    shape_definition);"]
+    #[doc = " END_TYPE; -- characterized_definition"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
     #[holder(generate_deserialize)]
@@ -1531,6 +2061,21 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         ShapeDefinition(Box<ShapeDefinition>),
     }
+    impl From<CharacterizedProductDefinition> for CharacterizedDefinition {
+        fn from(value: CharacterizedProductDefinition) -> Self {
+            CharacterizedDefinition::CharacterizedProductDefinition(Box::new(value))
+        }
+    }
+    impl From<ShapeDefinition> for CharacterizedDefinition {
+        fn from(value: ShapeDefinition) -> Self {
+            CharacterizedDefinition::ShapeDefinition(Box::new(value))
+        }
+    }
+    #[doc = " ```text"]
+    #[doc = " TYPE characterized_product_definition = SELECT"]
+    #[doc = "   (product_definition);"]
+    #[doc = " END_TYPE; -- characterized_product_definition"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
     #[holder(generate_deserialize)]
@@ -1538,6 +2083,17 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         ProductDefinition(Box<ProductDefinition>),
     }
+    impl From<ProductDefinition> for CharacterizedProductDefinition {
+        fn from(value: ProductDefinition) -> Self {
+            CharacterizedProductDefinition::ProductDefinition(Box::new(value))
+        }
+    }
+    #[doc = " ```text"]
+    #[doc = " TYPE classified_item = SELECT"]
+    #[doc = "   (drawing_revision,"]
+    #[doc = "    drawing_sheet_revision);"]
+    #[doc = " END_TYPE; -- classified_item"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
     #[holder(generate_deserialize)]
@@ -1547,6 +2103,21 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         DrawingSheetRevision(Box<DrawingSheetRevision>),
     }
+    impl From<DrawingRevisionAny> for ClassifiedItem {
+        fn from(value: DrawingRevisionAny) -> Self {
+            ClassifiedItem::DrawingRevision(value)
+        }
+    }
+    impl From<DrawingSheetRevision> for ClassifiedItem {
+        fn from(value: DrawingSheetRevision) -> Self {
+            ClassifiedItem::DrawingSheetRevision(Box::new(value))
+        }
+    }
+    #[doc = " ```text"]
+    #[doc = " TYPE contracted_item = SELECT"]
+    #[doc = "   (drawing_revision);"]
+    #[doc = " END_TYPE; -- contracted_item"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
     #[holder(generate_deserialize)]
@@ -1554,6 +2125,16 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         DrawingRevision(DrawingRevisionAny),
     }
+    impl From<DrawingRevisionAny> for ContractedItem {
+        fn from(value: DrawingRevisionAny) -> Self {
+            ContractedItem::DrawingRevision(value)
+        }
+    }
+    #[doc = " ```text"]
+    #[doc = " TYPE curve_font_or_scaled_curve_font_select = SELECT"]
+    #[doc = "   (curve_style_font_select);"]
+    #[doc = " END_TYPE; -- curve_font_or_scaled_curve_font_select"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
     #[holder(generate_deserialize)]
@@ -1561,6 +2142,17 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         CurveStyleFontSelect(Box<CurveStyleFontSelect>),
     }
+    impl From<CurveStyleFontSelect> for CurveFontOrScaledCurveFontSelect {
+        fn from(value: CurveStyleFontSelect) -> Self {
+            CurveFontOrScaledCurveFontSelect::CurveStyleFontSelect(Box::new(value))
+        }
+    }
+    #[doc = " ```text"]
+    #[doc = " TYPE curve_or_annotation_curve_occurrence = SELECT"]
+    #[doc = "   (curve,"]
+    #[doc = "    annotation_curve_occurrence);"]
+    #[doc = " END_TYPE; -- curve_or_annotation_curve_occurrence"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
     #[holder(generate_deserialize)]
@@ -1570,6 +2162,21 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         AnnotationCurveOccurrence(AnnotationCurveOccurrenceAny),
     }
+    impl From<CurveAny> for CurveOrAnnotationCurveOccurrence {
+        fn from(value: CurveAny) -> Self {
+            CurveOrAnnotationCurveOccurrence::Curve(value)
+        }
+    }
+    impl From<AnnotationCurveOccurrenceAny> for CurveOrAnnotationCurveOccurrence {
+        fn from(value: AnnotationCurveOccurrenceAny) -> Self {
+            CurveOrAnnotationCurveOccurrence::AnnotationCurveOccurrence(value)
+        }
+    }
+    #[doc = " ```text"]
+    #[doc = " TYPE curve_or_render = SELECT"]
+    #[doc = "   (curve_style);"]
+    #[doc = " END_TYPE; -- curve_or_render"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
     #[holder(generate_deserialize)]
@@ -1577,6 +2184,18 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         CurveStyle(Box<CurveStyle>),
     }
+    impl From<CurveStyle> for CurveOrRender {
+        fn from(value: CurveStyle) -> Self {
+            CurveOrRender::CurveStyle(Box::new(value))
+        }
+    }
+    #[doc = " ```text"]
+    #[doc = " TYPE curve_style_font_select = SELECT"]
+    #[doc = "   (curve_style_font,"]
+    #[doc = "    pre_defined_curve_font,"]
+    #[doc = "    externally_defined_curve_font);"]
+    #[doc = " END_TYPE; -- curve_style_font_select"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
     #[holder(generate_deserialize)]
@@ -1588,6 +2207,26 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         ExternallyDefinedCurveFont(Box<ExternallyDefinedCurveFont>),
     }
+    impl From<CurveStyleFont> for CurveStyleFontSelect {
+        fn from(value: CurveStyleFont) -> Self {
+            CurveStyleFontSelect::CurveStyleFont(Box::new(value))
+        }
+    }
+    impl From<PreDefinedCurveFontAny> for CurveStyleFontSelect {
+        fn from(value: PreDefinedCurveFontAny) -> Self {
+            CurveStyleFontSelect::PreDefinedCurveFont(value)
+        }
+    }
+    impl From<ExternallyDefinedCurveFont> for CurveStyleFontSelect {
+        fn from(value: ExternallyDefinedCurveFont) -> Self {
+            CurveStyleFontSelect::ExternallyDefinedCurveFont(Box::new(value))
+        }
+    }
+    #[doc = " ```text"]
+    #[doc = " TYPE date_time_select = SELECT"]
+    #[doc = "   (date);"]
+    #[doc = " END_TYPE; -- date_time_select"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
     #[holder(generate_deserialize)]
@@ -1595,6 +2234,15 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         Date(DateAny),
     }
+    impl From<DateAny> for DateTimeSelect {
+        fn from(value: DateAny) -> Self {
+            DateTimeSelect::Date(value)
+        }
+    }
+    #[doc = " ```text"]
+    #[doc = " TYPE day_in_month_number = INTEGER;"]
+    #[doc = " END_TYPE; -- day_in_month_number"]
+    #[doc = " ```"]
     #[derive(
         Clone, Debug, PartialEq, AsRef, Deref, DerefMut, Into, From, :: ruststep_derive :: Holder,
     )]
@@ -1602,6 +2250,12 @@ pub mod explicit_draughting {
     # [holder (field = day_in_month_number)]
     #[holder(generate_deserialize)]
     pub struct DayInMonthNumber(pub i64);
+    #[doc = " ```text"]
+    #[doc = " TYPE defined_symbol_select = SELECT"]
+    #[doc = "   (pre_defined_symbol,"]
+    #[doc = "    externally_defined_symbol);"]
+    #[doc = " END_TYPE; -- defined_symbol_select"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
     #[holder(generate_deserialize)]
@@ -1611,6 +2265,22 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         ExternallyDefinedSymbol(Box<ExternallyDefinedSymbol>),
     }
+    impl From<PreDefinedSymbolAny> for DefinedSymbolSelect {
+        fn from(value: PreDefinedSymbolAny) -> Self {
+            DefinedSymbolSelect::PreDefinedSymbol(value)
+        }
+    }
+    impl From<ExternallyDefinedSymbol> for DefinedSymbolSelect {
+        fn from(value: ExternallyDefinedSymbol) -> Self {
+            DefinedSymbolSelect::ExternallyDefinedSymbol(Box::new(value))
+        }
+    }
+    #[doc = " ```text"]
+    #[doc = " TYPE dimension_count = INTEGER;"]
+    #[doc = " WHERE"]
+    #[doc = "   wr1: SELF > 0;"]
+    #[doc = " END_TYPE; -- dimension_count"]
+    #[doc = " ```"]
     #[derive(
         Clone, Debug, PartialEq, AsRef, Deref, DerefMut, Into, From, :: ruststep_derive :: Holder,
     )]
@@ -1618,11 +2288,24 @@ pub mod explicit_draughting {
     # [holder (field = dimension_count)]
     #[holder(generate_deserialize)]
     pub struct DimensionCount(pub i64);
+    #[doc = " ```text"]
+    #[doc = " TYPE dimension_extent_usage = ENUMERATION OF"]
+    #[doc = "   (origin,"]
+    #[doc = "    target);"]
+    #[doc = " END_TYPE; -- dimension_extent_usage"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: serde :: Deserialize)]
     pub enum DimensionExtentUsage {
         Origin,
         Target,
     }
+    #[doc = " ```text"]
+    #[doc = " TYPE draughting_callout_element = SELECT"]
+    #[doc = "   (annotation_text_occurrence,"]
+    #[doc = "    annotation_symbol_occurrence,"]
+    #[doc = "    annotation_curve_occurrence);"]
+    #[doc = " END_TYPE; -- draughting_callout_element"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
     #[holder(generate_deserialize)]
@@ -1634,6 +2317,27 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         AnnotationCurveOccurrence(AnnotationCurveOccurrenceAny),
     }
+    impl From<AnnotationTextOccurrence> for DraughtingCalloutElement {
+        fn from(value: AnnotationTextOccurrence) -> Self {
+            DraughtingCalloutElement::AnnotationTextOccurrence(Box::new(value))
+        }
+    }
+    impl From<AnnotationSymbolOccurrenceAny> for DraughtingCalloutElement {
+        fn from(value: AnnotationSymbolOccurrenceAny) -> Self {
+            DraughtingCalloutElement::AnnotationSymbolOccurrence(value)
+        }
+    }
+    impl From<AnnotationCurveOccurrenceAny> for DraughtingCalloutElement {
+        fn from(value: AnnotationCurveOccurrenceAny) -> Self {
+            DraughtingCalloutElement::AnnotationCurveOccurrence(value)
+        }
+    }
+    #[doc = " ```text"]
+    #[doc = " TYPE draughting_grouped_item = SELECT"]
+    #[doc = "   (annotation_occurrence,"]
+    #[doc = "    geometric_set_select);"]
+    #[doc = " END_TYPE; -- draughting_grouped_item"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
     #[holder(generate_deserialize)]
@@ -1643,6 +2347,23 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         GeometricSetSelect(Box<GeometricSetSelect>),
     }
+    impl From<AnnotationOccurrenceAny> for DraughtingGroupedItem {
+        fn from(value: AnnotationOccurrenceAny) -> Self {
+            DraughtingGroupedItem::AnnotationOccurrence(value)
+        }
+    }
+    impl From<GeometricSetSelect> for DraughtingGroupedItem {
+        fn from(value: GeometricSetSelect) -> Self {
+            DraughtingGroupedItem::GeometricSetSelect(Box::new(value))
+        }
+    }
+    #[doc = " ```text"]
+    #[doc = " TYPE draughting_organization_item = SELECT"]
+    #[doc = "   (product_definition_formation,"]
+    #[doc = "    drawing_revision,"]
+    #[doc = "    drawing_sheet_revision);"]
+    #[doc = " END_TYPE; -- draughting_organization_item"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
     #[holder(generate_deserialize)]
@@ -1654,6 +2375,26 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         DrawingSheetRevision(Box<DrawingSheetRevision>),
     }
+    impl From<ProductDefinitionFormation> for DraughtingOrganizationItem {
+        fn from(value: ProductDefinitionFormation) -> Self {
+            DraughtingOrganizationItem::ProductDefinitionFormation(Box::new(value))
+        }
+    }
+    impl From<DrawingRevisionAny> for DraughtingOrganizationItem {
+        fn from(value: DrawingRevisionAny) -> Self {
+            DraughtingOrganizationItem::DrawingRevision(value)
+        }
+    }
+    impl From<DrawingSheetRevision> for DraughtingOrganizationItem {
+        fn from(value: DrawingSheetRevision) -> Self {
+            DraughtingOrganizationItem::DrawingSheetRevision(Box::new(value))
+        }
+    }
+    #[doc = " ```text"]
+    #[doc = " TYPE draughting_presented_item_select = SELECT"]
+    #[doc = "   (product_definition_formation);"]
+    #[doc = " END_TYPE; -- draughting_presented_item_select"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
     #[holder(generate_deserialize)]
@@ -1661,6 +2402,17 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         ProductDefinitionFormation(Box<ProductDefinitionFormation>),
     }
+    impl From<ProductDefinitionFormation> for DraughtingPresentedItemSelect {
+        fn from(value: ProductDefinitionFormation) -> Self {
+            DraughtingPresentedItemSelect::ProductDefinitionFormation(Box::new(value))
+        }
+    }
+    #[doc = " ```text"]
+    #[doc = " TYPE draughting_titled_item = SELECT"]
+    #[doc = "   (drawing_revision,"]
+    #[doc = "    drawing_sheet_revision);"]
+    #[doc = " END_TYPE; -- draughting_titled_item"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
     #[holder(generate_deserialize)]
@@ -1670,6 +2422,21 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         DrawingSheetRevision(Box<DrawingSheetRevision>),
     }
+    impl From<DrawingRevisionAny> for DraughtingTitledItem {
+        fn from(value: DrawingRevisionAny) -> Self {
+            DraughtingTitledItem::DrawingRevision(value)
+        }
+    }
+    impl From<DrawingSheetRevision> for DraughtingTitledItem {
+        fn from(value: DrawingSheetRevision) -> Self {
+            DraughtingTitledItem::DrawingSheetRevision(Box::new(value))
+        }
+    }
+    #[doc = " ```text"]
+    #[doc = " TYPE fill_area_style_tile_shape_select = SELECT"]
+    #[doc = "   (fill_area_style_tile_symbol_with_style);"]
+    #[doc = " END_TYPE; -- fill_area_style_tile_shape_select"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
     #[holder(generate_deserialize)]
@@ -1677,6 +2444,20 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         FillAreaStyleTileSymbolWithStyle(Box<FillAreaStyleTileSymbolWithStyle>),
     }
+    impl From<FillAreaStyleTileSymbolWithStyle> for FillAreaStyleTileShapeSelect {
+        fn from(value: FillAreaStyleTileSymbolWithStyle) -> Self {
+            FillAreaStyleTileShapeSelect::FillAreaStyleTileSymbolWithStyle(Box::new(value))
+        }
+    }
+    #[doc = " ```text"]
+    #[doc = " TYPE fill_style_select = SELECT"]
+    #[doc = "   (fill_area_style_colour,"]
+    #[doc = "    externally_defined_tile_style,"]
+    #[doc = "    fill_area_style_tiles,"]
+    #[doc = "    externally_defined_hatch_style,"]
+    #[doc = "    fill_area_style_hatching);"]
+    #[doc = " END_TYPE; -- fill_style_select"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
     #[holder(generate_deserialize)]
@@ -1692,6 +2473,37 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         FillAreaStyleHatching(Box<FillAreaStyleHatching>),
     }
+    impl From<FillAreaStyleColour> for FillStyleSelect {
+        fn from(value: FillAreaStyleColour) -> Self {
+            FillStyleSelect::FillAreaStyleColour(Box::new(value))
+        }
+    }
+    impl From<ExternallyDefinedTileStyle> for FillStyleSelect {
+        fn from(value: ExternallyDefinedTileStyle) -> Self {
+            FillStyleSelect::ExternallyDefinedTileStyle(Box::new(value))
+        }
+    }
+    impl From<FillAreaStyleTiles> for FillStyleSelect {
+        fn from(value: FillAreaStyleTiles) -> Self {
+            FillStyleSelect::FillAreaStyleTiles(Box::new(value))
+        }
+    }
+    impl From<ExternallyDefinedHatchStyle> for FillStyleSelect {
+        fn from(value: ExternallyDefinedHatchStyle) -> Self {
+            FillStyleSelect::ExternallyDefinedHatchStyle(Box::new(value))
+        }
+    }
+    impl From<FillAreaStyleHatching> for FillStyleSelect {
+        fn from(value: FillAreaStyleHatching) -> Self {
+            FillStyleSelect::FillAreaStyleHatching(Box::new(value))
+        }
+    }
+    #[doc = " ```text"]
+    #[doc = " TYPE font_select = SELECT"]
+    #[doc = "   (pre_defined_text_font,"]
+    #[doc = "    externally_defined_text_font);"]
+    #[doc = " END_TYPE; -- font_select"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
     #[holder(generate_deserialize)]
@@ -1701,6 +2513,22 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         ExternallyDefinedTextFont(Box<ExternallyDefinedTextFont>),
     }
+    impl From<PreDefinedTextFontAny> for FontSelect {
+        fn from(value: PreDefinedTextFontAny) -> Self {
+            FontSelect::PreDefinedTextFont(value)
+        }
+    }
+    impl From<ExternallyDefinedTextFont> for FontSelect {
+        fn from(value: ExternallyDefinedTextFont) -> Self {
+            FontSelect::ExternallyDefinedTextFont(Box::new(value))
+        }
+    }
+    #[doc = " ```text"]
+    #[doc = " TYPE geometric_set_select = SELECT"]
+    #[doc = "   (point,"]
+    #[doc = "    curve);"]
+    #[doc = " END_TYPE; -- geometric_set_select"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
     #[holder(generate_deserialize)]
@@ -1710,6 +2538,23 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         Curve(CurveAny),
     }
+    impl From<PointAny> for GeometricSetSelect {
+        fn from(value: PointAny) -> Self {
+            GeometricSetSelect::Point(value)
+        }
+    }
+    impl From<CurveAny> for GeometricSetSelect {
+        fn from(value: CurveAny) -> Self {
+            GeometricSetSelect::Curve(value)
+        }
+    }
+    #[doc = " ```text"]
+    #[doc = " TYPE hiding_or_blanking_select = SELECT"]
+    #[doc = "   (presentation_area,"]
+    #[doc = "    presentation_view,"]
+    #[doc = "    annotation_fill_area);"]
+    #[doc = " END_TYPE; -- hiding_or_blanking_select"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
     #[holder(generate_deserialize)]
@@ -1721,6 +2566,25 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         AnnotationFillArea(Box<AnnotationFillArea>),
     }
+    impl From<PresentationAreaAny> for HidingOrBlankingSelect {
+        fn from(value: PresentationAreaAny) -> Self {
+            HidingOrBlankingSelect::PresentationArea(value)
+        }
+    }
+    impl From<PresentationView> for HidingOrBlankingSelect {
+        fn from(value: PresentationView) -> Self {
+            HidingOrBlankingSelect::PresentationView(Box::new(value))
+        }
+    }
+    impl From<AnnotationFillArea> for HidingOrBlankingSelect {
+        fn from(value: AnnotationFillArea) -> Self {
+            HidingOrBlankingSelect::AnnotationFillArea(Box::new(value))
+        }
+    }
+    #[doc = " ```text"]
+    #[doc = " TYPE identifier = STRING;"]
+    #[doc = " END_TYPE; -- identifier"]
+    #[doc = " ```"]
     #[derive(
         Clone, Debug, PartialEq, AsRef, Deref, DerefMut, Into, From, :: ruststep_derive :: Holder,
     )]
@@ -1728,6 +2592,13 @@ pub mod explicit_draughting {
     # [holder (field = identifier)]
     #[holder(generate_deserialize)]
     pub struct Identifier(pub String);
+    #[doc = " ```text"]
+    #[doc = " TYPE invisibility_context = SELECT"]
+    #[doc = "   (presentation_layer_usage,"]
+    #[doc = "    presentation_representation,"]
+    #[doc = "    presentation_set);"]
+    #[doc = " END_TYPE; -- invisibility_context"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
     #[holder(generate_deserialize)]
@@ -1739,6 +2610,28 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         PresentationSet(PresentationSetAny),
     }
+    impl From<PresentationLayerUsage> for InvisibilityContext {
+        fn from(value: PresentationLayerUsage) -> Self {
+            InvisibilityContext::PresentationLayerUsage(Box::new(value))
+        }
+    }
+    impl From<PresentationRepresentationAny> for InvisibilityContext {
+        fn from(value: PresentationRepresentationAny) -> Self {
+            InvisibilityContext::PresentationRepresentation(value)
+        }
+    }
+    impl From<PresentationSetAny> for InvisibilityContext {
+        fn from(value: PresentationSetAny) -> Self {
+            InvisibilityContext::PresentationSet(value)
+        }
+    }
+    #[doc = " ```text"]
+    #[doc = " TYPE invisible_item = SELECT"]
+    #[doc = "   (styled_item,"]
+    #[doc = "    presentation_layer_assignment,"]
+    #[doc = "    presentation_representation);"]
+    #[doc = " END_TYPE; -- invisible_item"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
     #[holder(generate_deserialize)]
@@ -1750,6 +2643,29 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         PresentationRepresentation(PresentationRepresentationAny),
     }
+    impl From<StyledItemAny> for InvisibleItem {
+        fn from(value: StyledItemAny) -> Self {
+            InvisibleItem::StyledItem(value)
+        }
+    }
+    impl From<PresentationLayerAssignment> for InvisibleItem {
+        fn from(value: PresentationLayerAssignment) -> Self {
+            InvisibleItem::PresentationLayerAssignment(Box::new(value))
+        }
+    }
+    impl From<PresentationRepresentationAny> for InvisibleItem {
+        fn from(value: PresentationRepresentationAny) -> Self {
+            InvisibleItem::PresentationRepresentation(value)
+        }
+    }
+    #[doc = " ```text"]
+    #[doc = " TYPE knot_type = ENUMERATION OF"]
+    #[doc = "   (uniform_knots,"]
+    #[doc = "    quasi_uniform_knots,"]
+    #[doc = "    piecewise_bezier_knots,"]
+    #[doc = "    unspecified);"]
+    #[doc = " END_TYPE; -- knot_type"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: serde :: Deserialize)]
     pub enum KnotType {
         UniformKnots,
@@ -1757,6 +2673,10 @@ pub mod explicit_draughting {
         PiecewiseBezierKnots,
         Unspecified,
     }
+    #[doc = " ```text"]
+    #[doc = " TYPE label = STRING;"]
+    #[doc = " END_TYPE; -- label"]
+    #[doc = " ```"]
     #[derive(
         Clone, Debug, PartialEq, AsRef, Deref, DerefMut, Into, From, :: ruststep_derive :: Holder,
     )]
@@ -1764,6 +2684,12 @@ pub mod explicit_draughting {
     # [holder (field = label)]
     #[holder(generate_deserialize)]
     pub struct Label(pub String);
+    #[doc = " ```text"]
+    #[doc = " TYPE layered_item = SELECT"]
+    #[doc = "   (presentation_representation,"]
+    #[doc = "    representation_item);"]
+    #[doc = " END_TYPE; -- layered_item"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
     #[holder(generate_deserialize)]
@@ -1773,6 +2699,20 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         RepresentationItem(RepresentationItemAny),
     }
+    impl From<PresentationRepresentationAny> for LayeredItem {
+        fn from(value: PresentationRepresentationAny) -> Self {
+            LayeredItem::PresentationRepresentation(value)
+        }
+    }
+    impl From<RepresentationItemAny> for LayeredItem {
+        fn from(value: RepresentationItemAny) -> Self {
+            LayeredItem::RepresentationItem(value)
+        }
+    }
+    #[doc = " ```text"]
+    #[doc = " TYPE length_measure = REAL;"]
+    #[doc = " END_TYPE; -- length_measure"]
+    #[doc = " ```"]
     #[derive(
         Clone, Debug, PartialEq, AsRef, Deref, DerefMut, Into, From, :: ruststep_derive :: Holder,
     )]
@@ -1780,6 +2720,16 @@ pub mod explicit_draughting {
     # [holder (field = length_measure)]
     #[holder(generate_deserialize)]
     pub struct LengthMeasure(pub f64);
+    #[doc = " ```text"]
+    #[doc = " TYPE measure_value = SELECT"]
+    #[doc = "   (length_measure,"]
+    #[doc = "    plane_angle_measure,"]
+    #[doc = "    ratio_measure,"]
+    #[doc = "    parameter_value,"]
+    #[doc = "    positive_length_measure,"]
+    #[doc = "    positive_ratio_measure);"]
+    #[doc = " END_TYPE; -- measure_value"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
     #[holder(generate_deserialize)]
@@ -1797,6 +2747,42 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         PositiveRatioMeasure(Box<PositiveRatioMeasure>),
     }
+    impl From<LengthMeasure> for MeasureValue {
+        fn from(value: LengthMeasure) -> Self {
+            MeasureValue::LengthMeasure(Box::new(value))
+        }
+    }
+    impl From<PlaneAngleMeasure> for MeasureValue {
+        fn from(value: PlaneAngleMeasure) -> Self {
+            MeasureValue::PlaneAngleMeasure(Box::new(value))
+        }
+    }
+    impl From<RatioMeasure> for MeasureValue {
+        fn from(value: RatioMeasure) -> Self {
+            MeasureValue::RatioMeasure(Box::new(value))
+        }
+    }
+    impl From<ParameterValue> for MeasureValue {
+        fn from(value: ParameterValue) -> Self {
+            MeasureValue::ParameterValue(Box::new(value))
+        }
+    }
+    impl From<PositiveLengthMeasure> for MeasureValue {
+        fn from(value: PositiveLengthMeasure) -> Self {
+            MeasureValue::PositiveLengthMeasure(Box::new(value))
+        }
+    }
+    impl From<PositiveRatioMeasure> for MeasureValue {
+        fn from(value: PositiveRatioMeasure) -> Self {
+            MeasureValue::PositiveRatioMeasure(Box::new(value))
+        }
+    }
+    #[doc = " ```text"]
+    #[doc = " TYPE month_in_year_number = INTEGER;"]
+    #[doc = " WHERE"]
+    #[doc = "   wr1: (1 <= SELF) AND (SELF <= 12);"]
+    #[doc = " END_TYPE; -- month_in_year_number"]
+    #[doc = " ```"]
     #[derive(
         Clone, Debug, PartialEq, AsRef, Deref, DerefMut, Into, From, :: ruststep_derive :: Holder,
     )]
@@ -1804,10 +2790,19 @@ pub mod explicit_draughting {
     # [holder (field = month_in_year_number)]
     #[holder(generate_deserialize)]
     pub struct MonthInYearNumber(pub i64);
+    #[doc = " ```text"]
+    #[doc = " TYPE null_style = ENUMERATION OF"]
+    #[doc = "   (null);"]
+    #[doc = " END_TYPE; -- null_style"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: serde :: Deserialize)]
     pub enum NullStyle {
         Null,
     }
+    #[doc = " ```text"]
+    #[doc = " TYPE parameter_value = REAL;"]
+    #[doc = " END_TYPE; -- parameter_value"]
+    #[doc = " ```"]
     #[derive(
         Clone, Debug, PartialEq, AsRef, Deref, DerefMut, Into, From, :: ruststep_derive :: Holder,
     )]
@@ -1815,6 +2810,13 @@ pub mod explicit_draughting {
     # [holder (field = parameter_value)]
     #[holder(generate_deserialize)]
     pub struct ParameterValue(pub f64);
+    #[doc = " ```text"]
+    #[doc = " TYPE person_organization_select = SELECT"]
+    #[doc = "   (person,"]
+    #[doc = "    organization,"]
+    #[doc = "    person_and_organization);"]
+    #[doc = " END_TYPE; -- person_organization_select"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
     #[holder(generate_deserialize)]
@@ -1826,6 +2828,25 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         PersonAndOrganization(Box<PersonAndOrganization>),
     }
+    impl From<Person> for PersonOrganizationSelect {
+        fn from(value: Person) -> Self {
+            PersonOrganizationSelect::Person(Box::new(value))
+        }
+    }
+    impl From<Organization> for PersonOrganizationSelect {
+        fn from(value: Organization) -> Self {
+            PersonOrganizationSelect::Organization(Box::new(value))
+        }
+    }
+    impl From<PersonAndOrganization> for PersonOrganizationSelect {
+        fn from(value: PersonAndOrganization) -> Self {
+            PersonOrganizationSelect::PersonAndOrganization(Box::new(value))
+        }
+    }
+    #[doc = " ```text"]
+    #[doc = " TYPE plane_angle_measure = REAL;"]
+    #[doc = " END_TYPE; -- plane_angle_measure"]
+    #[doc = " ```"]
     #[derive(
         Clone, Debug, PartialEq, AsRef, Deref, DerefMut, Into, From, :: ruststep_derive :: Holder,
     )]
@@ -1833,6 +2854,12 @@ pub mod explicit_draughting {
     # [holder (field = plane_angle_measure)]
     #[holder(generate_deserialize)]
     pub struct PlaneAngleMeasure(pub f64);
+    #[doc = " ```text"]
+    #[doc = " TYPE positive_length_measure = length_measure;"]
+    #[doc = " WHERE"]
+    #[doc = "   wr1: SELF > 0;"]
+    #[doc = " END_TYPE; -- positive_length_measure"]
+    #[doc = " ```"]
     #[derive(
         Clone, Debug, PartialEq, AsRef, Deref, DerefMut, Into, From, :: ruststep_derive :: Holder,
     )]
@@ -1840,6 +2867,12 @@ pub mod explicit_draughting {
     # [holder (field = positive_length_measure)]
     #[holder(generate_deserialize)]
     pub struct PositiveLengthMeasure(#[holder(use_place_holder)] pub LengthMeasure);
+    #[doc = " ```text"]
+    #[doc = " TYPE positive_ratio_measure = ratio_measure;"]
+    #[doc = " WHERE"]
+    #[doc = "   wr1: SELF > 0;"]
+    #[doc = " END_TYPE; -- positive_ratio_measure"]
+    #[doc = " ```"]
     #[derive(
         Clone, Debug, PartialEq, AsRef, Deref, DerefMut, Into, From, :: ruststep_derive :: Holder,
     )]
@@ -1847,6 +2880,10 @@ pub mod explicit_draughting {
     # [holder (field = positive_ratio_measure)]
     #[holder(generate_deserialize)]
     pub struct PositiveRatioMeasure(#[holder(use_place_holder)] pub RatioMeasure);
+    #[doc = " ```text"]
+    #[doc = " TYPE presentable_text = STRING;"]
+    #[doc = " END_TYPE; -- presentable_text"]
+    #[doc = " ```"]
     #[derive(
         Clone, Debug, PartialEq, AsRef, Deref, DerefMut, Into, From, :: ruststep_derive :: Holder,
     )]
@@ -1854,6 +2891,12 @@ pub mod explicit_draughting {
     # [holder (field = presentable_text)]
     #[holder(generate_deserialize)]
     pub struct PresentableText(pub String);
+    #[doc = " ```text"]
+    #[doc = " TYPE presentation_representation_select = SELECT"]
+    #[doc = "   (presentation_representation,"]
+    #[doc = "    presentation_set);"]
+    #[doc = " END_TYPE; -- presentation_representation_select"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
     #[holder(generate_deserialize)]
@@ -1863,6 +2906,23 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         PresentationSet(PresentationSetAny),
     }
+    impl From<PresentationRepresentationAny> for PresentationRepresentationSelect {
+        fn from(value: PresentationRepresentationAny) -> Self {
+            PresentationRepresentationSelect::PresentationRepresentation(value)
+        }
+    }
+    impl From<PresentationSetAny> for PresentationRepresentationSelect {
+        fn from(value: PresentationSetAny) -> Self {
+            PresentationRepresentationSelect::PresentationSet(value)
+        }
+    }
+    #[doc = " ```text"]
+    #[doc = " TYPE presentation_size_assignment_select = SELECT"]
+    #[doc = "   (presentation_view,"]
+    #[doc = "    presentation_area,"]
+    #[doc = "    area_in_set);"]
+    #[doc = " END_TYPE; -- presentation_size_assignment_select"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
     #[holder(generate_deserialize)]
@@ -1874,6 +2934,30 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         AreaInSet(AreaInSetAny),
     }
+    impl From<PresentationView> for PresentationSizeAssignmentSelect {
+        fn from(value: PresentationView) -> Self {
+            PresentationSizeAssignmentSelect::PresentationView(Box::new(value))
+        }
+    }
+    impl From<PresentationAreaAny> for PresentationSizeAssignmentSelect {
+        fn from(value: PresentationAreaAny) -> Self {
+            PresentationSizeAssignmentSelect::PresentationArea(value)
+        }
+    }
+    impl From<AreaInSetAny> for PresentationSizeAssignmentSelect {
+        fn from(value: AreaInSetAny) -> Self {
+            PresentationSizeAssignmentSelect::AreaInSet(value)
+        }
+    }
+    #[doc = " ```text"]
+    #[doc = " TYPE presentation_style_select = SELECT"]
+    #[doc = "   (curve_style,"]
+    #[doc = "    symbol_style,"]
+    #[doc = "    fill_area_style,"]
+    #[doc = "    text_style,"]
+    #[doc = "    null_style);"]
+    #[doc = " END_TYPE; -- presentation_style_select"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
     #[holder(generate_deserialize)]
@@ -1888,6 +2972,35 @@ pub mod explicit_draughting {
         TextStyle(TextStyleAny),
         NullStyle(NullStyle),
     }
+    impl From<CurveStyle> for PresentationStyleSelect {
+        fn from(value: CurveStyle) -> Self {
+            PresentationStyleSelect::CurveStyle(Box::new(value))
+        }
+    }
+    impl From<SymbolStyle> for PresentationStyleSelect {
+        fn from(value: SymbolStyle) -> Self {
+            PresentationStyleSelect::SymbolStyle(Box::new(value))
+        }
+    }
+    impl From<FillAreaStyle> for PresentationStyleSelect {
+        fn from(value: FillAreaStyle) -> Self {
+            PresentationStyleSelect::FillAreaStyle(Box::new(value))
+        }
+    }
+    impl From<TextStyleAny> for PresentationStyleSelect {
+        fn from(value: TextStyleAny) -> Self {
+            PresentationStyleSelect::TextStyle(value)
+        }
+    }
+    impl From<NullStyle> for PresentationStyleSelect {
+        fn from(value: NullStyle) -> Self {
+            PresentationStyleSelect::NullStyle(value)
+        }
+    }
+    #[doc = " ```text"]
+    #[doc = " TYPE ratio_measure = REAL;"]
+    #[doc = " END_TYPE; -- ratio_measure"]
+    #[doc = " ```"]
     #[derive(
         Clone, Debug, PartialEq, AsRef, Deref, DerefMut, Into, From, :: ruststep_derive :: Holder,
     )]
@@ -1895,6 +3008,11 @@ pub mod explicit_draughting {
     # [holder (field = ratio_measure)]
     #[holder(generate_deserialize)]
     pub struct RatioMeasure(pub f64);
+    #[doc = " ```text"]
+    #[doc = " TYPE shape_definition = SELECT"]
+    #[doc = "   (product_definition_shape);"]
+    #[doc = " END_TYPE; -- shape_definition"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
     #[holder(generate_deserialize)]
@@ -1902,6 +3020,31 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         ProductDefinitionShape(Box<ProductDefinitionShape>),
     }
+    impl From<ProductDefinitionShape> for ShapeDefinition {
+        fn from(value: ProductDefinitionShape) -> Self {
+            ShapeDefinition::ProductDefinitionShape(Box::new(value))
+        }
+    }
+    #[doc = " ```text"]
+    #[doc = " TYPE si_prefix = ENUMERATION OF"]
+    #[doc = "   (exa,"]
+    #[doc = "    pico,"]
+    #[doc = "    mega,"]
+    #[doc = "    femto,"]
+    #[doc = "    atto,"]
+    #[doc = "    centi,"]
+    #[doc = "    nano,"]
+    #[doc = "    hecto,"]
+    #[doc = "    micro,"]
+    #[doc = "    tera,"]
+    #[doc = "    giga,"]
+    #[doc = "    milli,"]
+    #[doc = "    peta,"]
+    #[doc = "    deci,"]
+    #[doc = "    kilo,"]
+    #[doc = "    deca);"]
+    #[doc = " END_TYPE; -- si_prefix"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: serde :: Deserialize)]
     pub enum SiPrefix {
         Exa,
@@ -1921,6 +3064,38 @@ pub mod explicit_draughting {
         Kilo,
         Deca,
     }
+    #[doc = " ```text"]
+    #[doc = " TYPE si_unit_name = ENUMERATION OF"]
+    #[doc = "   (hertz,"]
+    #[doc = "    degree_celsius,"]
+    #[doc = "    siemens,"]
+    #[doc = "    sievert,"]
+    #[doc = "    lux,"]
+    #[doc = "    watt,"]
+    #[doc = "    ohm,"]
+    #[doc = "    second,"]
+    #[doc = "    becquerel,"]
+    #[doc = "    pascal,"]
+    #[doc = "    henry,"]
+    #[doc = "    tesla,"]
+    #[doc = "    volt,"]
+    #[doc = "    joule,"]
+    #[doc = "    kelvin,"]
+    #[doc = "    ampere,"]
+    #[doc = "    gram,"]
+    #[doc = "    steradian,"]
+    #[doc = "    mole,"]
+    #[doc = "    lumen,"]
+    #[doc = "    gray,"]
+    #[doc = "    candela,"]
+    #[doc = "    farad,"]
+    #[doc = "    radian,"]
+    #[doc = "    newton,"]
+    #[doc = "    metre,"]
+    #[doc = "    weber,"]
+    #[doc = "    coulomb);"]
+    #[doc = " END_TYPE; -- si_unit_name"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: serde :: Deserialize)]
     pub enum SiUnitName {
         Hertz,
@@ -1952,6 +3127,12 @@ pub mod explicit_draughting {
         Weber,
         Coulomb,
     }
+    #[doc = " ```text"]
+    #[doc = " TYPE size_select = SELECT"]
+    #[doc = "   (positive_length_measure,"]
+    #[doc = "    measure_with_unit);"]
+    #[doc = " END_TYPE; -- size_select"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
     #[holder(generate_deserialize)]
@@ -1961,6 +3142,21 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         MeasureWithUnit(MeasureWithUnitAny),
     }
+    impl From<PositiveLengthMeasure> for SizeSelect {
+        fn from(value: PositiveLengthMeasure) -> Self {
+            SizeSelect::PositiveLengthMeasure(Box::new(value))
+        }
+    }
+    impl From<MeasureWithUnitAny> for SizeSelect {
+        fn from(value: MeasureWithUnitAny) -> Self {
+            SizeSelect::MeasureWithUnit(value)
+        }
+    }
+    #[doc = " ```text"]
+    #[doc = " TYPE source_item = SELECT"]
+    #[doc = "   (identifier);"]
+    #[doc = " END_TYPE; -- source_item"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
     #[holder(generate_deserialize)]
@@ -1968,6 +3164,16 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         Identifier(Box<Identifier>),
     }
+    impl From<Identifier> for SourceItem {
+        fn from(value: Identifier) -> Self {
+            SourceItem::Identifier(Box::new(value))
+        }
+    }
+    #[doc = " ```text"]
+    #[doc = " TYPE specified_item = SELECT"]
+    #[doc = "   (drawing_revision);"]
+    #[doc = " END_TYPE; -- specified_item"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
     #[holder(generate_deserialize)]
@@ -1975,6 +3181,18 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         DrawingRevision(DrawingRevisionAny),
     }
+    impl From<DrawingRevisionAny> for SpecifiedItem {
+        fn from(value: DrawingRevisionAny) -> Self {
+            SpecifiedItem::DrawingRevision(value)
+        }
+    }
+    #[doc = " ```text"]
+    #[doc = " TYPE style_context_select = SELECT"]
+    #[doc = "   (representation,"]
+    #[doc = "    representation_item,"]
+    #[doc = "    presentation_set);"]
+    #[doc = " END_TYPE; -- style_context_select"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
     #[holder(generate_deserialize)]
@@ -1986,6 +3204,26 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         PresentationSet(PresentationSetAny),
     }
+    impl From<RepresentationAny> for StyleContextSelect {
+        fn from(value: RepresentationAny) -> Self {
+            StyleContextSelect::Representation(value)
+        }
+    }
+    impl From<RepresentationItemAny> for StyleContextSelect {
+        fn from(value: RepresentationItemAny) -> Self {
+            StyleContextSelect::RepresentationItem(value)
+        }
+    }
+    impl From<PresentationSetAny> for StyleContextSelect {
+        fn from(value: PresentationSetAny) -> Self {
+            StyleContextSelect::PresentationSet(value)
+        }
+    }
+    #[doc = " ```text"]
+    #[doc = " TYPE symbol_style_select = SELECT"]
+    #[doc = "   (symbol_colour);"]
+    #[doc = " END_TYPE; -- symbol_style_select"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
     #[holder(generate_deserialize)]
@@ -1993,6 +3231,15 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         SymbolColour(Box<SymbolColour>),
     }
+    impl From<SymbolColour> for SymbolStyleSelect {
+        fn from(value: SymbolColour) -> Self {
+            SymbolStyleSelect::SymbolColour(Box::new(value))
+        }
+    }
+    #[doc = " ```text"]
+    #[doc = " TYPE text = STRING;"]
+    #[doc = " END_TYPE; -- text"]
+    #[doc = " ```"]
     #[derive(
         Clone, Debug, PartialEq, AsRef, Deref, DerefMut, Into, From, :: ruststep_derive :: Holder,
     )]
@@ -2000,6 +3247,10 @@ pub mod explicit_draughting {
     # [holder (field = text)]
     #[holder(generate_deserialize)]
     pub struct Text(pub String);
+    #[doc = " ```text"]
+    #[doc = " TYPE text_alignment = label;"]
+    #[doc = " END_TYPE; -- text_alignment"]
+    #[doc = " ```"]
     #[derive(
         Clone, Debug, PartialEq, AsRef, Deref, DerefMut, Into, From, :: ruststep_derive :: Holder,
     )]
@@ -2007,6 +3258,10 @@ pub mod explicit_draughting {
     # [holder (field = text_alignment)]
     #[holder(generate_deserialize)]
     pub struct TextAlignment(#[holder(use_place_holder)] pub Label);
+    #[doc = " ```text"]
+    #[doc = " TYPE text_delineation = label;"]
+    #[doc = " END_TYPE; -- text_delineation"]
+    #[doc = " ```"]
     #[derive(
         Clone, Debug, PartialEq, AsRef, Deref, DerefMut, Into, From, :: ruststep_derive :: Holder,
     )]
@@ -2014,6 +3269,13 @@ pub mod explicit_draughting {
     # [holder (field = text_delineation)]
     #[holder(generate_deserialize)]
     pub struct TextDelineation(#[holder(use_place_holder)] pub Label);
+    #[doc = " ```text"]
+    #[doc = " TYPE text_or_character = SELECT"]
+    #[doc = "   (annotation_text,"]
+    #[doc = "    composite_text,"]
+    #[doc = "    text_literal);"]
+    #[doc = " END_TYPE; -- text_or_character"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
     #[holder(generate_deserialize)]
@@ -2025,6 +3287,29 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         TextLiteral(TextLiteralAny),
     }
+    impl From<AnnotationText> for TextOrCharacter {
+        fn from(value: AnnotationText) -> Self {
+            TextOrCharacter::AnnotationText(Box::new(value))
+        }
+    }
+    impl From<CompositeTextAny> for TextOrCharacter {
+        fn from(value: CompositeTextAny) -> Self {
+            TextOrCharacter::CompositeText(value)
+        }
+    }
+    impl From<TextLiteralAny> for TextOrCharacter {
+        fn from(value: TextLiteralAny) -> Self {
+            TextOrCharacter::TextLiteral(value)
+        }
+    }
+    #[doc = " ```text"]
+    #[doc = " TYPE text_path = ENUMERATION OF"]
+    #[doc = "   (up,"]
+    #[doc = "    right,"]
+    #[doc = "    down,"]
+    #[doc = "    left);"]
+    #[doc = " END_TYPE; -- text_path"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: serde :: Deserialize)]
     pub enum TextPath {
         Up,
@@ -2032,6 +3317,14 @@ pub mod explicit_draughting {
         Down,
         Left,
     }
+    #[doc = " ```text"]
+    #[doc = " TYPE transition_code = ENUMERATION OF"]
+    #[doc = "   (discontinuous,"]
+    #[doc = "    cont_same_gradient_same_curvature,"]
+    #[doc = "    cont_same_gradient,"]
+    #[doc = "    continuous);"]
+    #[doc = " END_TYPE; -- transition_code"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: serde :: Deserialize)]
     pub enum TransitionCode {
         Discontinuous,
@@ -2039,12 +3332,25 @@ pub mod explicit_draughting {
         ContSameGradient,
         Continuous,
     }
+    #[doc = " ```text"]
+    #[doc = " TYPE trimming_preference = ENUMERATION OF"]
+    #[doc = "   (parameter,"]
+    #[doc = "    unspecified,"]
+    #[doc = "    cartesian);"]
+    #[doc = " END_TYPE; -- trimming_preference"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: serde :: Deserialize)]
     pub enum TrimmingPreference {
         Parameter,
         Unspecified,
         Cartesian,
     }
+    #[doc = " ```text"]
+    #[doc = " TYPE trimming_select = SELECT"]
+    #[doc = "   (cartesian_point,"]
+    #[doc = "    parameter_value);"]
+    #[doc = " END_TYPE; -- trimming_select"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
     #[holder(generate_deserialize)]
@@ -2054,6 +3360,21 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         ParameterValue(Box<ParameterValue>),
     }
+    impl From<CartesianPoint> for TrimmingSelect {
+        fn from(value: CartesianPoint) -> Self {
+            TrimmingSelect::CartesianPoint(Box::new(value))
+        }
+    }
+    impl From<ParameterValue> for TrimmingSelect {
+        fn from(value: ParameterValue) -> Self {
+            TrimmingSelect::ParameterValue(Box::new(value))
+        }
+    }
+    #[doc = " ```text"]
+    #[doc = " TYPE unit = SELECT"]
+    #[doc = "   (named_unit);"]
+    #[doc = " END_TYPE; -- unit"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
     #[holder(generate_deserialize)]
@@ -2061,6 +3382,17 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         NamedUnit(NamedUnitAny),
     }
+    impl From<NamedUnitAny> for Unit {
+        fn from(value: NamedUnitAny) -> Self {
+            Unit::NamedUnit(value)
+        }
+    }
+    #[doc = " ```text"]
+    #[doc = " TYPE vector_or_direction = SELECT"]
+    #[doc = "   (vector,"]
+    #[doc = "    direction);"]
+    #[doc = " END_TYPE; -- vector_or_direction"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
     #[holder(generate_deserialize)]
@@ -2070,6 +3402,20 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         Direction(Box<Direction>),
     }
+    impl From<Vector> for VectorOrDirection {
+        fn from(value: Vector) -> Self {
+            VectorOrDirection::Vector(Box::new(value))
+        }
+    }
+    impl From<Direction> for VectorOrDirection {
+        fn from(value: Direction) -> Self {
+            VectorOrDirection::Direction(Box::new(value))
+        }
+    }
+    #[doc = " ```text"]
+    #[doc = " TYPE year_number = INTEGER;"]
+    #[doc = " END_TYPE; -- year_number"]
+    #[doc = " ```"]
     #[derive(
         Clone, Debug, PartialEq, AsRef, Deref, DerefMut, Into, From, :: ruststep_derive :: Holder,
     )]
@@ -2077,6 +3423,28 @@ pub mod explicit_draughting {
     # [holder (field = year_number)]
     #[holder(generate_deserialize)]
     pub struct YearNumber(pub i64);
+    #[doc = " ```text"]
+    #[doc = " ENTITY address;"]
+    #[doc = "     internal_location       : OPTIONAL label;"]
+    #[doc = "     street_number           : OPTIONAL label;"]
+    #[doc = "     street                  : OPTIONAL label;"]
+    #[doc = "     postal_box              : OPTIONAL label;"]
+    #[doc = "     town                    : OPTIONAL label;"]
+    #[doc = "     region                  : OPTIONAL label;"]
+    #[doc = "     postal_code             : OPTIONAL label;"]
+    #[doc = "     country                 : OPTIONAL label;"]
+    #[doc = "     facsimile_number        : OPTIONAL label;"]
+    #[doc = "     telephone_number        : OPTIONAL label;"]
+    #[doc = "     electronic_mail_address : OPTIONAL label;"]
+    #[doc = "     telex_number            : OPTIONAL label;"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: EXISTS(internal_location) OR EXISTS(street_number) OR EXISTS("]
+    #[doc = "              street) OR EXISTS(postal_box) OR EXISTS(town) OR EXISTS("]
+    #[doc = "              region) OR EXISTS(postal_code) OR EXISTS(country) OR EXISTS("]
+    #[doc = "              facsimile_number) OR EXISTS(telephone_number) OR EXISTS("]
+    #[doc = "              electronic_mail_address) OR EXISTS(telex_number);"]
+    #[doc = " END_ENTITY; -- address"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = address)]
@@ -2142,6 +3510,11 @@ pub mod explicit_draughting {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY angular_dimension"]
+    #[doc = "   SUBTYPE OF (dimension_curve_directed_callout);"]
+    #[doc = " END_ENTITY; -- angular_dimension"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -2156,6 +3529,13 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub dimension_curve_directed_callout: DimensionCurveDirectedCallout,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY annotation_curve_occurrence"]
+    #[doc = "   SUBTYPE OF (annotation_occurrence);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: 'EXPLICIT_DRAUGHTING.CURVE' IN TYPEOF(SELF\\styled_item.item);"]
+    #[doc = " END_ENTITY; -- annotation_curve_occurrence"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -2231,6 +3611,12 @@ pub mod explicit_draughting {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY annotation_fill_area"]
+    #[doc = "   SUBTYPE OF (geometric_representation_item);"]
+    #[doc = "     boundaries : SET [1:?] OF curve;"]
+    #[doc = " END_ENTITY; -- annotation_fill_area"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -2245,8 +3631,17 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub geometric_representation_item: GeometricRepresentationItem,
         #[holder(use_place_holder)]
+        # [holder (size = 1 ..)]
         pub boundaries: Vec<CurveAny>,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY annotation_fill_area_occurrence"]
+    #[doc = "   SUBTYPE OF (annotation_occurrence);"]
+    #[doc = "     fill_style_target : point;"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: 'EXPLICIT_DRAUGHTING.ANNOTATION_FILL_AREA' IN TYPEOF(SELF.item);"]
+    #[doc = " END_ENTITY; -- annotation_fill_area_occurrence"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -2263,6 +3658,17 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub fill_style_target: PointAny,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY annotation_occurrence"]
+    #[doc = "   SUPERTYPE OF (ONEOF (annotation_curve_occurrence,"]
+    #[doc = "       annotation_fill_area_occurrence,annotation_text_occurrence,"]
+    #[doc = "       annotation_symbol_occurrence))"]
+    #[doc = "   SUBTYPE OF (styled_item);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: 'EXPLICIT_DRAUGHTING.GEOMETRIC_REPRESENTATION_ITEM' IN TYPEOF("]
+    #[doc = "              SELF);"]
+    #[doc = " END_ENTITY; -- annotation_occurrence"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -2360,6 +3766,22 @@ pub mod explicit_draughting {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY annotation_subfigure_occurrence"]
+    #[doc = "   SUBTYPE OF (annotation_symbol_occurrence);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: SIZEOF(QUERY ( sty <* SELF\\styled_item.styles | (NOT (SIZEOF("]
+    #[doc = "              sty.styles) = 1)) )) = 0;"]
+    #[doc = "     wr2: SIZEOF(QUERY ( sty <* SELF\\styled_item.styles | (NOT ("]
+    #[doc = "              'EXPLICIT_DRAUGHTING.NULL_STYLE' IN TYPEOF(sty.styles[1]))) )) "]
+    #[doc = "              = 0;"]
+    #[doc = "     wr3: 'EXPLICIT_DRAUGHTING.ANNOTATION_SYMBOL' IN TYPEOF(SELF\\"]
+    #[doc = "              styled_item.item);"]
+    #[doc = "     wr4: 'EXPLICIT_DRAUGHTING.DRAUGHTING_SUBFIGURE_REPRESENTATION' IN "]
+    #[doc = "              TYPEOF(SELF\\styled_item.item\\mapped_item.mapping_source."]
+    #[doc = "              mapped_representation);"]
+    #[doc = " END_ENTITY; -- annotation_subfigure_occurrence"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -2374,6 +3796,18 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub annotation_symbol_occurrence: AnnotationSymbolOccurrence,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY annotation_symbol"]
+    #[doc = "   SUBTYPE OF (mapped_item);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: 'EXPLICIT_DRAUGHTING.SYMBOL_REPRESENTATION_MAP' IN TYPEOF(SELF\\"]
+    #[doc = "              mapped_item.mapping_source);"]
+    #[doc = "     wr2: 'EXPLICIT_DRAUGHTING.SYMBOL_TARGET' IN TYPEOF(SELF\\mapped_item."]
+    #[doc = "              mapping_target);"]
+    #[doc = "     wr3: 'EXPLICIT_DRAUGHTING.GEOMETRIC_REPRESENTATION_ITEM' IN TYPEOF("]
+    #[doc = "              SELF);"]
+    #[doc = " END_ENTITY; -- annotation_symbol"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -2388,6 +3822,15 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub mapped_item: MappedItem,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY annotation_symbol_occurrence"]
+    #[doc = "   SUBTYPE OF (annotation_occurrence);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: SIZEOF(['EXPLICIT_DRAUGHTING.ANNOTATION_SYMBOL',"]
+    #[doc = "              'EXPLICIT_DRAUGHTING.DEFINED_SYMBOL'] * TYPEOF(SELF\\"]
+    #[doc = "              styled_item.item)) > 0;"]
+    #[doc = " END_ENTITY; -- annotation_symbol_occurrence"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -2452,6 +3895,18 @@ pub mod explicit_draughting {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY annotation_text"]
+    #[doc = "   SUBTYPE OF (mapped_item);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: 'EXPLICIT_DRAUGHTING.AXIS2_PLACEMENT' IN TYPEOF(SELF\\"]
+    #[doc = "              mapped_item.mapping_target);"]
+    #[doc = "     wr2: 'EXPLICIT_DRAUGHTING.TEXT_STRING_REPRESENTATION' IN TYPEOF(SELF"]
+    #[doc = "              \\mapped_item.mapping_source.mapped_representation);"]
+    #[doc = "     wr3: 'EXPLICIT_DRAUGHTING.GEOMETRIC_REPRESENTATION_ITEM' IN TYPEOF("]
+    #[doc = "              SELF);"]
+    #[doc = " END_ENTITY; -- annotation_text"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -2466,6 +3921,18 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub mapped_item: MappedItem,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY annotation_text_occurrence"]
+    #[doc = "   SUBTYPE OF (annotation_occurrence);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: SIZEOF(['EXPLICIT_DRAUGHTING.TEXT_LITERAL',"]
+    #[doc = "              'EXPLICIT_DRAUGHTING.ANNOTATION_TEXT',"]
+    #[doc = "              'EXPLICIT_DRAUGHTING.ANNOTATION_TEXT_CHARACTER',"]
+    #[doc = "              'EXPLICIT_DRAUGHTING.DEFINED_CHARACTER_GLYPH',"]
+    #[doc = "              'EXPLICIT_DRAUGHTING.COMPOSITE_TEXT'] * TYPEOF(SELF\\"]
+    #[doc = "              styled_item.item)) > 0;"]
+    #[doc = " END_ENTITY; -- annotation_text_occurrence"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -2480,6 +3947,14 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub annotation_occurrence: AnnotationOccurrence,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY application_context;"]
+    #[doc = "     application : text;"]
+    #[doc = "   INVERSE"]
+    #[doc = "     context_elements : SET [1:?] OF application_context_element FOR "]
+    #[doc = "                           frame_of_reference;"]
+    #[doc = " END_ENTITY; -- application_context"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = application_context)]
@@ -2488,6 +3963,13 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub application: Text,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY application_context_element"]
+    #[doc = "   SUPERTYPE OF (ONEOF (product_context,product_definition_context));"]
+    #[doc = "     name               : label;"]
+    #[doc = "     frame_of_reference : application_context;"]
+    #[doc = " END_ENTITY; -- application_context_element"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = application_context_element)]
@@ -2533,6 +4015,14 @@ pub mod explicit_draughting {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY application_protocol_definition;"]
+    #[doc = "     status                                    : label;"]
+    #[doc = "     application_interpreted_model_schema_name : label;"]
+    #[doc = "     application_protocol_year                 : year_number;"]
+    #[doc = "     application                               : application_context;"]
+    #[doc = " END_ENTITY; -- application_protocol_definition"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = application_protocol_definition)]
@@ -2547,6 +4037,12 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub application: ApplicationContext,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY approval;"]
+    #[doc = "     status : approval_status;"]
+    #[doc = "     level  : label;"]
+    #[doc = " END_ENTITY; -- approval"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = approval)]
@@ -2557,6 +4053,12 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub level: Label,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY approval_assignment"]
+    #[doc = "   ABSTRACT SUPERTYPE;"]
+    #[doc = "     assigned_approval : approval;"]
+    #[doc = " END_ENTITY; -- approval_assignment"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = approval_assignment)]
@@ -2592,6 +4094,12 @@ pub mod explicit_draughting {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY approval_date_time;"]
+    #[doc = "     date_time      : date_time_select;"]
+    #[doc = "     dated_approval : approval;"]
+    #[doc = " END_ENTITY; -- approval_date_time"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = approval_date_time)]
@@ -2602,6 +4110,13 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub dated_approval: Approval,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY approval_person_organization;"]
+    #[doc = "     person_organization : person_organization_select;"]
+    #[doc = "     authorized_approval : approval;"]
+    #[doc = "     role                : approval_role;"]
+    #[doc = " END_ENTITY; -- approval_person_organization"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = approval_person_organization)]
@@ -2614,6 +4129,11 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub role: ApprovalRole,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY approval_role;"]
+    #[doc = "     role : label;"]
+    #[doc = " END_ENTITY; -- approval_role"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = approval_role)]
@@ -2622,6 +4142,11 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub role: Label,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY approval_status;"]
+    #[doc = "     name : label;"]
+    #[doc = " END_ENTITY; -- approval_status"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = approval_status)]
@@ -2630,6 +4155,12 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub name: Label,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY area_in_set;"]
+    #[doc = "     area   : presentation_area;"]
+    #[doc = "     in_set : presentation_set;"]
+    #[doc = " END_ENTITY; -- area_in_set"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = area_in_set)]
@@ -2667,6 +4198,16 @@ pub mod explicit_draughting {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY axis2_placement_2d"]
+    #[doc = "   SUBTYPE OF (placement);"]
+    #[doc = "     ref_direction : OPTIONAL direction;"]
+    #[doc = "   DERIVE"]
+    #[doc = "     p : LIST [2:2] OF direction := build_2axes(ref_direction);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: SELF\\geometric_representation_item.dim = 2;"]
+    #[doc = " END_ENTITY; -- axis2_placement_2d"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -2683,6 +4224,32 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub ref_direction: Option<Direction>,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY b_spline_curve"]
+    #[doc = "   SUPERTYPE OF (ONEOF (uniform_curve,b_spline_curve_with_knots,"]
+    #[doc = "       quasi_uniform_curve,bezier_curve) ANDOR rational_b_spline_curve)"]
+    #[doc = "   SUBTYPE OF (bounded_curve);"]
+    #[doc = "     degree              : INTEGER;"]
+    #[doc = "     control_points_list : LIST [2:?] OF cartesian_point;"]
+    #[doc = "     curve_form          : b_spline_curve_form;"]
+    #[doc = "     closed_curve        : LOGICAL;"]
+    #[doc = "     self_intersect      : LOGICAL;"]
+    #[doc = "   DERIVE"]
+    #[doc = "     upper_index_on_control_points : INTEGER := SIZEOF("]
+    #[doc = "                                        control_points_list) - 1;"]
+    #[doc = "     control_points                : ARRAY [0:"]
+    #[doc = "                                        upper_index_on_control_points] OF"]
+    #[doc = "                                         cartesian_point := list_to_array("]
+    #[doc = "                                        control_points_list,0,"]
+    #[doc = "                                        upper_index_on_control_points);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: ('EXPLICIT_DRAUGHTING.UNIFORM_CURVE' IN TYPEOF(SELF)) OR ("]
+    #[doc = "              'EXPLICIT_DRAUGHTING.QUASI_UNIFORM_CURVE' IN TYPEOF(SELF)) "]
+    #[doc = "              OR ('EXPLICIT_DRAUGHTING.BEZIER_CURVE' IN TYPEOF(SELF)) OR ("]
+    #[doc = "              'EXPLICIT_DRAUGHTING.B_SPLINE_CURVE_WITH_KNOTS' IN TYPEOF("]
+    #[doc = "              SELF));"]
+    #[doc = " END_ENTITY; -- b_spline_curve"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -2698,6 +4265,7 @@ pub mod explicit_draughting {
         pub bounded_curve: BoundedCurve,
         pub degree: i64,
         #[holder(use_place_holder)]
+        # [holder (size = 2 ..)]
         pub control_points_list: Vec<CartesianPoint>,
         pub curve_form: BSplineCurveForm,
         pub closed_curve: Logical,
@@ -2784,6 +4352,20 @@ pub mod explicit_draughting {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY b_spline_curve_with_knots"]
+    #[doc = "   SUBTYPE OF (b_spline_curve);"]
+    #[doc = "     knot_multiplicities : LIST [2:?] OF INTEGER;"]
+    #[doc = "     knots               : LIST [2:?] OF parameter_value;"]
+    #[doc = "     knot_spec           : knot_type;"]
+    #[doc = "   DERIVE"]
+    #[doc = "     upper_index_on_knots : INTEGER := SIZEOF(knots);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: constraints_param_b_spline(degree,upper_index_on_knots,"]
+    #[doc = "              upper_index_on_control_points,knot_multiplicities,knots);"]
+    #[doc = "     wr2: SIZEOF(knot_multiplicities) = upper_index_on_knots;"]
+    #[doc = " END_ENTITY; -- b_spline_curve_with_knots"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -2797,11 +4379,18 @@ pub mod explicit_draughting {
         #[deref_mut]
         #[holder(use_place_holder)]
         pub b_spline_curve: BSplineCurve,
+        # [holder (size = 2 ..)]
         pub knot_multiplicities: Vec<i64>,
         #[holder(use_place_holder)]
+        # [holder (size = 2 ..)]
         pub knots: Vec<ParameterValue>,
         pub knot_spec: KnotType,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY bezier_curve"]
+    #[doc = "   SUBTYPE OF (b_spline_curve);"]
+    #[doc = " END_ENTITY; -- bezier_curve"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -2816,6 +4405,13 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub b_spline_curve: BSplineCurve,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY bounded_curve"]
+    #[doc = "   SUPERTYPE OF (ONEOF (polyline,b_spline_curve,trimmed_curve,"]
+    #[doc = "       composite_curve))"]
+    #[doc = "   SUBTYPE OF (curve);"]
+    #[doc = " END_ENTITY; -- bounded_curve"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -2898,6 +4494,15 @@ pub mod explicit_draughting {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY calendar_date"]
+    #[doc = "   SUBTYPE OF (date);"]
+    #[doc = "     day_component   : day_in_month_number;"]
+    #[doc = "     month_component : month_in_year_number;"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: valid_calendar_date(SELF);"]
+    #[doc = " END_ENTITY; -- calendar_date"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -2916,6 +4521,18 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub month_component: MonthInYearNumber,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY camera_image"]
+    #[doc = "   SUBTYPE OF (mapped_item);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: 'EXPLICIT_DRAUGHTING.CAMERA_USAGE' IN TYPEOF(SELF\\mapped_item."]
+    #[doc = "              mapping_source);"]
+    #[doc = "     wr2: 'EXPLICIT_DRAUGHTING.PLANAR_BOX' IN TYPEOF(SELF\\mapped_item."]
+    #[doc = "              mapping_target);"]
+    #[doc = "     wr3: 'EXPLICIT_DRAUGHTING.GEOMETRIC_REPRESENTATION_ITEM' IN TYPEOF("]
+    #[doc = "              SELF);"]
+    #[doc = " END_ENTITY; -- camera_image"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -2967,6 +4584,23 @@ pub mod explicit_draughting {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY camera_image_2d_with_scale"]
+    #[doc = "   SUBTYPE OF (camera_image);"]
+    #[doc = "   DERIVE"]
+    #[doc = "     scale : positive_ratio_measure := SELF\\mapped_item.mapping_target\\"]
+    #[doc = "                planar_extent.size_in_x / SELF\\mapped_item.mapping_source."]
+    #[doc = "                mapping_origin\\camera_model_d2.view_window.size_in_x;"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: 'EXPLICIT_DRAUGHTING.CAMERA_MODEL_D2' IN TYPEOF(SELF\\"]
+    #[doc = "              mapped_item.mapping_source.mapping_origin);"]
+    #[doc = "     wr2: aspect_ratio(SELF\\mapped_item.mapping_target) = aspect_ratio("]
+    #[doc = "              SELF\\mapped_item.mapping_source.mapping_origin\\"]
+    #[doc = "              camera_model_d2.view_window);"]
+    #[doc = "     wr3: SELF\\mapped_item.mapping_source.mapping_origin\\camera_model_d2."]
+    #[doc = "              view_window_clipping;"]
+    #[doc = " END_ENTITY; -- camera_image_2d_with_scale"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -2981,6 +4615,19 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub camera_image: CameraImage,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY camera_model"]
+    #[doc = "   SUPERTYPE OF (camera_model_d2)"]
+    #[doc = "   SUBTYPE OF (geometric_representation_item);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: (SIZEOF(USEDIN(SELF,'EXPLICIT_DRAUGHTING.' + "]
+    #[doc = "              'ITEM_DEFINED_TRANSFORMATION.' + 'TRANSFORM_ITEM_1')) + "]
+    #[doc = "              SIZEOF(USEDIN(SELF,'EXPLICIT_DRAUGHTING.' + "]
+    #[doc = "              'REPRESENTATION_MAP.MAPPING_ORIGIN'))) > 0;"]
+    #[doc = "     wr2: SIZEOF(USEDIN(SELF,'EXPLICIT_DRAUGHTING.' + 'STYLED_ITEM.ITEM')) "]
+    #[doc = "              = 0;"]
+    #[doc = " END_ENTITY; -- camera_model"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -3032,6 +4679,15 @@ pub mod explicit_draughting {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY camera_model_d2"]
+    #[doc = "   SUBTYPE OF (camera_model);"]
+    #[doc = "     view_window          : planar_box;"]
+    #[doc = "     view_window_clipping : BOOLEAN;"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: SELF\\geometric_representation_item.dim = 2;"]
+    #[doc = " END_ENTITY; -- camera_model_d2"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -3049,6 +4705,16 @@ pub mod explicit_draughting {
         pub view_window: PlanarBox,
         pub view_window_clipping: bool,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY camera_usage"]
+    #[doc = "   SUBTYPE OF (representation_map);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: NOT ('EXPLICIT_DRAUGHTING.PRESENTATION_REPRESENTATION' IN "]
+    #[doc = "              TYPEOF(SELF\\representation_map.mapped_representation));"]
+    #[doc = "     wr2: 'EXPLICIT_DRAUGHTING.CAMERA_MODEL' IN TYPEOF(SELF\\"]
+    #[doc = "              representation_map.mapping_origin);"]
+    #[doc = " END_ENTITY; -- camera_usage"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -3063,6 +4729,12 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub representation_map: RepresentationMap,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY cartesian_point"]
+    #[doc = "   SUBTYPE OF (point);"]
+    #[doc = "     coordinates : LIST [1:3] OF length_measure;"]
+    #[doc = " END_ENTITY; -- cartesian_point"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -3077,8 +4749,15 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub point: Point,
         #[holder(use_place_holder)]
+        # [holder (size = 1 ..= 3)]
         pub coordinates: Vec<LengthMeasure>,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY circle"]
+    #[doc = "   SUBTYPE OF (conic);"]
+    #[doc = "     radius : positive_length_measure;"]
+    #[doc = " END_ENTITY; -- circle"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -3095,6 +4774,10 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub radius: PositiveLengthMeasure,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY colour;"]
+    #[doc = " END_ENTITY; -- colour"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = colour)]
@@ -3135,6 +4818,18 @@ pub mod explicit_draughting {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY colour_rgb"]
+    #[doc = "   SUBTYPE OF (colour_specification);"]
+    #[doc = "     red   : REAL;"]
+    #[doc = "     green : REAL;"]
+    #[doc = "     blue  : REAL;"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: (0 <= red) AND (red <= 1);"]
+    #[doc = "     wr2: (0 <= green) AND (green <= 1);"]
+    #[doc = "     wr3: (0 <= blue) AND (blue <= 1);"]
+    #[doc = " END_ENTITY; -- colour_rgb"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -3152,6 +4847,12 @@ pub mod explicit_draughting {
         pub green: f64,
         pub blue: f64,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY colour_specification"]
+    #[doc = "   SUBTYPE OF (colour);"]
+    #[doc = "     name : colour;"]
+    #[doc = " END_ENTITY; -- colour_specification"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -3207,6 +4908,22 @@ pub mod explicit_draughting {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY composite_curve"]
+    #[doc = "   SUBTYPE OF (bounded_curve);"]
+    #[doc = "     segments       : LIST [1:?] OF composite_curve_segment;"]
+    #[doc = "     self_intersect : LOGICAL;"]
+    #[doc = "   DERIVE"]
+    #[doc = "     n_segments   : INTEGER := SIZEOF(segments);"]
+    #[doc = "     closed_curve : LOGICAL := segments[n_segments].transition <> "]
+    #[doc = "                       discontinuous;"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: ((NOT closed_curve) AND (SIZEOF(QUERY ( temp <* segments | ("]
+    #[doc = "              temp.transition = discontinuous) )) = 1)) OR (closed_curve "]
+    #[doc = "              AND (SIZEOF(QUERY ( temp <* segments | (temp.transition = "]
+    #[doc = "              discontinuous) )) = 0));"]
+    #[doc = " END_ENTITY; -- composite_curve"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -3221,9 +4938,21 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub bounded_curve: BoundedCurve,
         #[holder(use_place_holder)]
+        # [holder (size = 1 ..)]
         pub segments: Vec<CompositeCurveSegment>,
         pub self_intersect: Logical,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY composite_curve_segment;"]
+    #[doc = "     transition   : transition_code;"]
+    #[doc = "     same_sense   : BOOLEAN;"]
+    #[doc = "     parent_curve : curve;"]
+    #[doc = "   INVERSE"]
+    #[doc = "     using_curves : BAG [1:?] OF composite_curve FOR segments;"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: 'EXPLICIT_DRAUGHTING.BOUNDED_CURVE' IN TYPEOF(parent_curve);"]
+    #[doc = " END_ENTITY; -- composite_curve_segment"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = composite_curve_segment)]
@@ -3234,6 +4963,14 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub parent_curve: CurveAny,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY composite_text"]
+    #[doc = "   SUBTYPE OF (geometric_representation_item);"]
+    #[doc = "     collected_text : SET [2:?] OF text_or_character;"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: acyclic_composite_text(SELF,SELF.collected_text);"]
+    #[doc = " END_ENTITY; -- composite_text"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -3248,6 +4985,7 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub geometric_representation_item: GeometricRepresentationItem,
         #[holder(use_place_holder)]
+        # [holder (size = 2 ..)]
         pub collected_text: Vec<TextOrCharacter>,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
@@ -3309,6 +5047,12 @@ pub mod explicit_draughting {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY composite_text_with_associated_curves"]
+    #[doc = "   SUBTYPE OF (composite_text);"]
+    #[doc = "     associated_curves : SET [1:?] OF curve;"]
+    #[doc = " END_ENTITY; -- composite_text_with_associated_curves"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -3323,8 +5067,15 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub composite_text: CompositeText,
         #[holder(use_place_holder)]
+        # [holder (size = 1 ..)]
         pub associated_curves: Vec<CurveAny>,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY composite_text_with_blanking_box"]
+    #[doc = "   SUBTYPE OF (composite_text);"]
+    #[doc = "     blanking : planar_box;"]
+    #[doc = " END_ENTITY; -- composite_text_with_blanking_box"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -3341,6 +5092,12 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub blanking: PlanarBox,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY composite_text_with_extent"]
+    #[doc = "   SUBTYPE OF (composite_text);"]
+    #[doc = "     extent : planar_extent;"]
+    #[doc = " END_ENTITY; -- composite_text_with_extent"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -3357,6 +5114,13 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub extent: PlanarExtentAny,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY conic"]
+    #[doc = "   SUPERTYPE OF (ONEOF (circle,ellipse,hyperbola,parabola))"]
+    #[doc = "   SUBTYPE OF (curve);"]
+    #[doc = "     position : axis2_placement;"]
+    #[doc = " END_ENTITY; -- conic"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -3435,6 +5199,12 @@ pub mod explicit_draughting {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY context_dependent_invisibility"]
+    #[doc = "   SUBTYPE OF (invisibility);"]
+    #[doc = "     presentation_context : invisibility_context;"]
+    #[doc = " END_ENTITY; -- context_dependent_invisibility"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -3451,6 +5221,13 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub presentation_context: InvisibilityContext,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY contract;"]
+    #[doc = "     name    : label;"]
+    #[doc = "     purpose : text;"]
+    #[doc = "     kind    : contract_type;"]
+    #[doc = " END_ENTITY; -- contract"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = contract)]
@@ -3463,6 +5240,12 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub kind: ContractType,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY contract_assignment"]
+    #[doc = "   ABSTRACT SUPERTYPE;"]
+    #[doc = "     assigned_contract : contract;"]
+    #[doc = " END_ENTITY; -- contract_assignment"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = contract_assignment)]
@@ -3498,6 +5281,11 @@ pub mod explicit_draughting {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY contract_type;"]
+    #[doc = "     description : label;"]
+    #[doc = " END_ENTITY; -- contract_type"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = contract_type)]
@@ -3506,6 +5294,13 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub description: Label,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY conversion_based_unit"]
+    #[doc = "   SUBTYPE OF (named_unit);"]
+    #[doc = "     name              : label;"]
+    #[doc = "     conversion_factor : measure_with_unit;"]
+    #[doc = " END_ENTITY; -- conversion_based_unit"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -3524,6 +5319,12 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub conversion_factor: MeasureWithUnitAny,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY curve"]
+    #[doc = "   SUPERTYPE OF (ONEOF (line,conic,offset_curve_2d))"]
+    #[doc = "   SUBTYPE OF (geometric_representation_item);"]
+    #[doc = " END_ENTITY; -- curve"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -3600,6 +5401,11 @@ pub mod explicit_draughting {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY curve_dimension"]
+    #[doc = "   SUBTYPE OF (dimension_curve_directed_callout);"]
+    #[doc = " END_ENTITY; -- curve_dimension"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -3614,6 +5420,14 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub dimension_curve_directed_callout: DimensionCurveDirectedCallout,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY curve_style;"]
+    #[doc = "     name         : label;"]
+    #[doc = "     curve_font   : curve_font_or_scaled_curve_font_select;"]
+    #[doc = "     curve_width  : size_select;"]
+    #[doc = "     curve_colour : colour;"]
+    #[doc = " END_ENTITY; -- curve_style"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = curve_style)]
@@ -3628,6 +5442,12 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub curve_colour: ColourAny,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY curve_style_font;"]
+    #[doc = "     name         : label;"]
+    #[doc = "     pattern_list : LIST [1:?] OF curve_style_font_pattern;"]
+    #[doc = " END_ENTITY; -- curve_style_font"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = curve_style_font)]
@@ -3636,8 +5456,15 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub name: Label,
         #[holder(use_place_holder)]
+        # [holder (size = 1 ..)]
         pub pattern_list: Vec<CurveStyleFontPattern>,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY curve_style_font_pattern;"]
+    #[doc = "     visible_segment_length   : positive_length_measure;"]
+    #[doc = "     invisible_segment_length : positive_length_measure;"]
+    #[doc = " END_ENTITY; -- curve_style_font_pattern"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = curve_style_font_pattern)]
@@ -3648,6 +5475,12 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub invisible_segment_length: PositiveLengthMeasure,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY date"]
+    #[doc = "   SUPERTYPE OF (calendar_date);"]
+    #[doc = "     year_component : year_number;"]
+    #[doc = " END_ENTITY; -- date"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = date)]
@@ -3683,6 +5516,11 @@ pub mod explicit_draughting {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY datum_feature_callout"]
+    #[doc = "   SUBTYPE OF (draughting_callout);"]
+    #[doc = " END_ENTITY; -- datum_feature_callout"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -3697,6 +5535,11 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub draughting_callout: DraughtingCallout,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY datum_target_callout"]
+    #[doc = "   SUBTYPE OF (draughting_callout);"]
+    #[doc = " END_ENTITY; -- datum_target_callout"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -3711,6 +5554,13 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub draughting_callout: DraughtingCallout,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY defined_symbol"]
+    #[doc = "   SUBTYPE OF (geometric_representation_item);"]
+    #[doc = "     definition : defined_symbol_select;"]
+    #[doc = "     target     : symbol_target;"]
+    #[doc = " END_ENTITY; -- defined_symbol"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -3729,6 +5579,11 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub target: SymbolTarget,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY diameter_dimension"]
+    #[doc = "   SUBTYPE OF (dimension_curve_directed_callout);"]
+    #[doc = " END_ENTITY; -- diameter_dimension"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -3743,6 +5598,23 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub dimension_curve_directed_callout: DimensionCurveDirectedCallout,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY dimension_callout_component_relationship"]
+    #[doc = "   SUBTYPE OF (draughting_callout_relationship);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: SELF.name IN ['prefix','suffix'];"]
+    #[doc = "     wr2: 'EXPLICIT_DRAUGHTING.STRUCTURED_DIMENSION_CALLOUT' IN TYPEOF("]
+    #[doc = "              SELF.relating_draughting_callout);"]
+    #[doc = "     wr3: SIZEOF(TYPEOF(SELF.related_draughting_callout) * ["]
+    #[doc = "              'EXPLICIT_DRAUGHTING.LEADER_DIRECTED_CALLOUT',"]
+    #[doc = "              'EXPLICIT_DRAUGHTING.PROJECTION_DIRECTED_CALLOUT',"]
+    #[doc = "              'EXPLICIT_DRAUGHTING.DIMENSION_CURVE_DIRECTED_CALLOUT',"]
+    #[doc = "              'EXPLICIT_DRAUGHTING.STRUCTURED_DIMENSION_CALLOUT']) = 0;"]
+    #[doc = "     wr4: (SELF.related_draughting_callout.contents * SELF."]
+    #[doc = "              relating_draughting_callout.contents) = SELF."]
+    #[doc = "              related_draughting_callout.contents;"]
+    #[doc = " END_ENTITY; -- dimension_callout_component_relationship"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -3757,6 +5629,28 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub draughting_callout_relationship: DraughtingCalloutRelationship,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY dimension_callout_relationship"]
+    #[doc = "   SUBTYPE OF (draughting_callout_relationship);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: SELF.name IN ['primary','secondary'];"]
+    #[doc = "     wr2: SIZEOF(TYPEOF(SELF.relating_draughting_callout) * ["]
+    #[doc = "              'EXPLICIT_DRAUGHTING.ANGULAR_DIMENSION',"]
+    #[doc = "              'EXPLICIT_DRAUGHTING.CURVE_DIMENSION',"]
+    #[doc = "              'EXPLICIT_DRAUGHTING.DIAMETER_DIMENSION',"]
+    #[doc = "              'EXPLICIT_DRAUGHTING.LEADER_DIRECTED_DIMENSION',"]
+    #[doc = "              'EXPLICIT_DRAUGHTING.LINEAR_DIMENSION',"]
+    #[doc = "              'EXPLICIT_DRAUGHTING.ORDINATE_DIMENSION',"]
+    #[doc = "              'EXPLICIT_DRAUGHTING.RADIUS_DIMENSION']) = 1;"]
+    #[doc = "     wr3: SIZEOF(TYPEOF(SELF.related_draughting_callout) * ["]
+    #[doc = "              'EXPLICIT_DRAUGHTING.DIMENSION_CURVE_DIRECTED_CALLOUT',"]
+    #[doc = "              'EXPLICIT_DRAUGHTING.PROJECTION_DIRECTED_CALLOUT',"]
+    #[doc = "              'EXPLICIT_DRAUGHTING.LEADER_DIRECTED_CALLOUT']) = 0;"]
+    #[doc = "     wr4: (SELF.related_draughting_callout.contents * SELF."]
+    #[doc = "              relating_draughting_callout.contents) = SELF."]
+    #[doc = "              related_draughting_callout.contents;"]
+    #[doc = " END_ENTITY; -- dimension_callout_relationship"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -3771,6 +5665,23 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub draughting_callout_relationship: DraughtingCalloutRelationship,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY dimension_curve"]
+    #[doc = "   SUBTYPE OF (annotation_curve_occurrence);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: SIZEOF(USEDIN(SELF,'EXPLICIT_DRAUGHTING.' + "]
+    #[doc = "              'DIMENSION_CURVE_TERMINATOR.ANNOTATED_DIMENSION_' + 'CURVE')) "]
+    #[doc = "              <= 2;"]
+    #[doc = "     wr2: SIZEOF(USEDIN(SELF,'EXPLICIT_DRAUGHTING.' + "]
+    #[doc = "              'DIMENSION_CURVE_DIRECTED_CALLOUT.CONTENTS')) >= 1;"]
+    #[doc = "     wr3: (SIZEOF(QUERY ( dct_1 <* USEDIN(SELF,'DRAUGHTING_ELEMENT_' + "]
+    #[doc = "              'SCHEMA.DIMENSION_CURVE_TERMINATOR.ANNOTATED_DIMENSION_' + "]
+    #[doc = "              'CURVE') | (dct_1.role = origin) )) <= 1) AND (SIZEOF("]
+    #[doc = "              QUERY ( dct_2 <* USEDIN(SELF,'DRAUGHTING_ELEMENT_' + "]
+    #[doc = "              'SCHEMA.DIMENSION_CURVE_TERMINATOR.ANNOTATED_DIMENSION_' + "]
+    #[doc = "              'CURVE') | (dct_2.role = target) )) <= 1);"]
+    #[doc = " END_ENTITY; -- dimension_curve"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -3785,6 +5696,15 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub annotation_curve_occurrence: AnnotationCurveOccurrence,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY dimension_curve_directed_callout"]
+    #[doc = "   SUBTYPE OF (draughting_callout);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: SIZEOF(QUERY ( d_c <* SELF\\draughting_callout.contents | ("]
+    #[doc = "              'EXPLICIT_DRAUGHTING.DIMENSION_CURVE' IN TYPEOF(d_c)) )) = 1;"]
+    #[doc = "     wr2: SIZEOF(SELF\\draughting_callout.contents) >= 2;"]
+    #[doc = " END_ENTITY; -- dimension_curve_directed_callout"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -3882,6 +5802,15 @@ pub mod explicit_draughting {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY dimension_curve_terminator"]
+    #[doc = "   SUBTYPE OF (terminator_symbol);"]
+    #[doc = "     role : dimension_extent_usage;"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: 'EXPLICIT_DRAUGHTING.DIMENSION_CURVE' IN TYPEOF(SELF\\"]
+    #[doc = "              terminator_symbol.annotated_curve);"]
+    #[doc = " END_ENTITY; -- dimension_curve_terminator"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -3897,6 +5826,27 @@ pub mod explicit_draughting {
         pub terminator_symbol: TerminatorSymbol,
         pub role: DimensionExtentUsage,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY dimension_pair"]
+    #[doc = "   SUBTYPE OF (draughting_callout_relationship);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: SELF.name IN ['chained','parallel'];"]
+    #[doc = "     wr2: SIZEOF(TYPEOF(SELF.relating_draughting_callout) * ["]
+    #[doc = "              'EXPLICIT_DRAUGHTING.ANGULAR_DIMENSION',"]
+    #[doc = "              'EXPLICIT_DRAUGHTING.CURVE_DIMENSION',"]
+    #[doc = "              'EXPLICIT_DRAUGHTING.DIAMETER_DIMENSION',"]
+    #[doc = "              'EXPLICIT_DRAUGHTING.LINEAR_DIMENSION',"]
+    #[doc = "              'EXPLICIT_DRAUGHTING.ORDINATE_DIMENSION',"]
+    #[doc = "              'EXPLICIT_DRAUGHTING.RADIUS_DIMENSION']) = 1;"]
+    #[doc = "     wr3: SIZEOF(TYPEOF(SELF.related_draughting_callout) * ["]
+    #[doc = "              'EXPLICIT_DRAUGHTING.ANGULAR_DIMENSION',"]
+    #[doc = "              'EXPLICIT_DRAUGHTING.CURVE_DIMENSION',"]
+    #[doc = "              'EXPLICIT_DRAUGHTING.DIAMETER_DIMENSION',"]
+    #[doc = "              'EXPLICIT_DRAUGHTING.LINEAR_DIMENSION',"]
+    #[doc = "              'EXPLICIT_DRAUGHTING.ORDINATE_DIMENSION',"]
+    #[doc = "              'EXPLICIT_DRAUGHTING.RADIUS_DIMENSION']) = 1;"]
+    #[doc = " END_ENTITY; -- dimension_pair"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -3911,6 +5861,17 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub draughting_callout_relationship: DraughtingCalloutRelationship,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY dimensional_exponents;"]
+    #[doc = "     length_exponent                    : REAL;"]
+    #[doc = "     mass_exponent                      : REAL;"]
+    #[doc = "     time_exponent                      : REAL;"]
+    #[doc = "     electric_current_exponent          : REAL;"]
+    #[doc = "     thermodynamic_temperature_exponent : REAL;"]
+    #[doc = "     amount_of_substance_exponent       : REAL;"]
+    #[doc = "     luminous_intensity_exponent        : REAL;"]
+    #[doc = " END_ENTITY; -- dimensional_exponents"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = dimensional_exponents)]
@@ -3924,6 +5885,14 @@ pub mod explicit_draughting {
         pub amount_of_substance_exponent: f64,
         pub luminous_intensity_exponent: f64,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY direction"]
+    #[doc = "   SUBTYPE OF (geometric_representation_item);"]
+    #[doc = "     direction_ratios : LIST [2:3] OF REAL;"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: SIZEOF(QUERY ( tmp <* direction_ratios | (tmp <> 0) )) > 0;"]
+    #[doc = " END_ENTITY; -- direction"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -3937,8 +5906,19 @@ pub mod explicit_draughting {
         #[deref_mut]
         #[holder(use_place_holder)]
         pub geometric_representation_item: GeometricRepresentationItem,
+        # [holder (size = 2 ..= 3)]
         pub direction_ratios: Vec<f64>,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY document;"]
+    #[doc = "     id          : identifier;"]
+    #[doc = "     name        : label;"]
+    #[doc = "     description : text;"]
+    #[doc = "     kind        : document_type;"]
+    #[doc = "   UNIQUE"]
+    #[doc = "     ur1 : id;"]
+    #[doc = " END_ENTITY; -- document"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = document)]
@@ -3953,6 +5933,13 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub kind: DocumentType,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY document_reference"]
+    #[doc = "   ABSTRACT SUPERTYPE;"]
+    #[doc = "     assigned_document : document;"]
+    #[doc = "     source            : label;"]
+    #[doc = " END_ENTITY; -- document_reference"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = document_reference)]
@@ -3990,6 +5977,11 @@ pub mod explicit_draughting {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY document_type;"]
+    #[doc = "     product_data_type : label;"]
+    #[doc = " END_ENTITY; -- document_type"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = document_type)]
@@ -3998,6 +5990,220 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub product_data_type: Label,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY draughting_annotation_occurrence"]
+    #[doc = "   SUBTYPE OF (annotation_occurrence);"]
+    #[doc = "   WHERE"]
+    #[doc = "     curve_has_curve_style             : (NOT ('EXPLICIT_DRAUGHTING.ANNOTATION_CURVE_OCCURRENCE' "]
+    #[doc = "                                             IN TYPEOF(SELF))) OR (SIZEOF("]
+    #[doc = "                                             QUERY ( sty <* SELF\\"]
+    #[doc = "                                             styled_item.styles | (NOT (("]
+    #[doc = "                                             SIZEOF(sty.styles) = 1) AND ('EXPLICIT_DRAUGHTING.CURVE_STYLE' "]
+    #[doc = "                                             IN TYPEOF(sty.styles[1])))) )) "]
+    #[doc = "                                             = 0);"]
+    #[doc = "     fill_area_has_fill_style          : (NOT ('EXPLICIT_DRAUGHTING.ANNOTATION_FILL_AREA_OCCURRENCE' "]
+    #[doc = "                                             IN TYPEOF(SELF))) OR (SIZEOF("]
+    #[doc = "                                             QUERY ( sty <* SELF\\"]
+    #[doc = "                                             styled_item.styles | (NOT (("]
+    #[doc = "                                             SIZEOF(sty.styles) = 1) AND ('EXPLICIT_DRAUGHTING.FILL_AREA_STYLE' "]
+    #[doc = "                                             IN TYPEOF(sty.styles[1])))) )) "]
+    #[doc = "                                             = 0);"]
+    #[doc = "     styled_fill_boundaries            : (NOT ('EXPLICIT_DRAUGHTING.ANNOTATION_FILL_AREA_OCCURRENCE' "]
+    #[doc = "                                             IN TYPEOF(SELF))) OR (SIZEOF("]
+    #[doc = "                                             QUERY ( bound <* SELF\\"]
+    #[doc = "                                             styled_item.item\\"]
+    #[doc = "                                             annotation_fill_area."]
+    #[doc = "                                             boundaries | (NOT (SIZEOF("]
+    #[doc = "                                             QUERY ( si <* USEDIN(bound,"]
+    #[doc = "                                             'EXPLICIT_DRAUGHTING.' + "]
+    #[doc = "                                             'STYLED_ITEM.ITEM') | ('EXPLICIT_DRAUGHTING.ANNOTATION_CURVE_OCCURRENCE' "]
+    #[doc = "                                             IN TYPEOF(si)) )) > 0)) )) = "]
+    #[doc = "                                             0);"]
+    #[doc = "     symbol_has_symbol_style           : (NOT ('EXPLICIT_DRAUGHTING.ANNOTATION_SYMBOL_OCCURRENCE' "]
+    #[doc = "                                             IN TYPEOF(SELF))) OR (SIZEOF("]
+    #[doc = "                                             QUERY ( sty <* SELF\\"]
+    #[doc = "                                             styled_item.styles | (NOT (("]
+    #[doc = "                                             SIZEOF(sty.styles) = 1) AND ("]
+    #[doc = "                                             SIZEOF(TYPEOF(sty.styles[1]) "]
+    #[doc = "                                             * ['EXPLICIT_DRAUGHTING.SYMBOL_STYLE','EXPLICIT_DRAUGHTING.NULL_STYLE']) "]
+    #[doc = "                                             = 1))) )) = 0);"]
+    #[doc = "     allowable_symbol_representations  : (NOT (('EXPLICIT_DRAUGHTING.ANNOTATION_SYMBOL_OCCURRENCE' "]
+    #[doc = "                                             IN TYPEOF(SELF)) AND ('EXPLICIT_DRAUGHTING.ANNOTATION_SYMBOL' "]
+    #[doc = "                                             IN TYPEOF(SELF\\styled_item."]
+    #[doc = "                                             item)))) OR (SIZEOF(["]
+    #[doc = "                                             'EXPLICIT_DRAUGHTING.' + 'DRAUGHTING_SYMBOL_REPRESENTATION',"]
+    #[doc = "                                             'EXPLICIT_DRAUGHTING.' + 'DRAUGHTING_SUBFIGURE_REPRESENTATION'] "]
+    #[doc = "                                             * TYPEOF(SELF\\styled_item."]
+    #[doc = "                                             item\\mapped_item."]
+    #[doc = "                                             mapping_source."]
+    #[doc = "                                             mapped_representation)) = 1);"]
+    #[doc = "     text_has_text_style               : (NOT ('EXPLICIT_DRAUGHTING.ANNOTATION_TEXT_OCCURRENCE' "]
+    #[doc = "                                             IN TYPEOF(SELF))) OR (SIZEOF("]
+    #[doc = "                                             QUERY ( sty <* SELF\\"]
+    #[doc = "                                             styled_item.styles | (NOT (("]
+    #[doc = "                                             SIZEOF(sty.styles) = 1) AND ('EXPLICIT_DRAUGHTING.TEXT_STYLE' "]
+    #[doc = "                                             IN TYPEOF(sty.styles[1])))) )) "]
+    #[doc = "                                             = 0);"]
+    #[doc = "     text_not_nested                   : (NOT (('EXPLICIT_DRAUGHTING.ANNOTATION_TEXT_OCCURRENCE' "]
+    #[doc = "                                             IN TYPEOF(SELF)) AND ('EXPLICIT_DRAUGHTING.COMPOSITE_TEXT' "]
+    #[doc = "                                             IN TYPEOF(SELF\\styled_item."]
+    #[doc = "                                             item)))) OR (SIZEOF("]
+    #[doc = "                                             QUERY ( text <* SELF\\"]
+    #[doc = "                                             styled_item.item\\"]
+    #[doc = "                                             composite_text.collected_text"]
+    #[doc = "                                              | (NOT ('EXPLICIT_DRAUGHTING.TEXT_LITERAL' "]
+    #[doc = "                                             IN TYPEOF(text))) )) = 0);"]
+    #[doc = "     allowable_text                    : NOT (('EXPLICIT_DRAUGHTING.ANNOTATION_TEXT_OCCURRENCE' "]
+    #[doc = "                                             IN TYPEOF(SELF)) AND (SIZEOF("]
+    #[doc = "                                             TYPEOF(SELF\\styled_item.item) "]
+    #[doc = "                                             * ['EXPLICIT_DRAUGHTING.COMPOSITE_TEXT','EXPLICIT_DRAUGHTING.TEXT_LITERAL']) "]
+    #[doc = "                                             = 1));"]
+    #[doc = "     text_alignment_literals           : (NOT (('EXPLICIT_DRAUGHTING.ANNOTATION_TEXT_OCCURRENCE' "]
+    #[doc = "                                             IN TYPEOF(SELF)) AND ('EXPLICIT_DRAUGHTING.TEXT_LITERAL' "]
+    #[doc = "                                             IN TYPEOF(SELF\\styled_item."]
+    #[doc = "                                             item)))) OR (SELF\\styled_item"]
+    #[doc = "                                             .item\\text_literal.alignment "]
+    #[doc = "                                             IN ['baseline left',"]
+    #[doc = "                                             'baseline centre',"]
+    #[doc = "                                             'baseline right']);"]
+    #[doc = "     text_alignment_composites         : (NOT (('EXPLICIT_DRAUGHTING.ANNOTATION_TEXT_OCCURRENCE' "]
+    #[doc = "                                             IN TYPEOF(SELF)) AND ('EXPLICIT_DRAUGHTING.COMPOSITE_TEXT' "]
+    #[doc = "                                             IN TYPEOF(SELF\\styled_item."]
+    #[doc = "                                             item)))) OR (SIZEOF("]
+    #[doc = "                                             QUERY ( literal <* "]
+    #[doc = "                                             QUERY ( text <* SELF\\"]
+    #[doc = "                                             styled_item.item\\"]
+    #[doc = "                                             composite_text.collected_text"]
+    #[doc = "                                              | ('EXPLICIT_DRAUGHTING.TEXT_LITERAL' "]
+    #[doc = "                                             IN TYPEOF(text)) ) | (NOT ("]
+    #[doc = "                                             literal.alignment IN ["]
+    #[doc = "                                             'baseline left',"]
+    #[doc = "                                             'baseline centre',"]
+    #[doc = "                                             'baseline right'])) )) = 0);"]
+    #[doc = "     text_literal_not_rotated          : NOT (('EXPLICIT_DRAUGHTING.ANNOTATION_TEXT_OCCURRENCE' "]
+    #[doc = "                                             IN TYPEOF(SELF)) AND ('EXPLICIT_DRAUGHTING.TEXT_LITERAL' "]
+    #[doc = "                                             IN TYPEOF(SELF\\styled_item."]
+    #[doc = "                                             item)) AND EXISTS(SELF\\"]
+    #[doc = "                                             styled_item.item\\text_literal"]
+    #[doc = "                                             .placement\\axis2_placement_2d"]
+    #[doc = "                                             .ref_direction));"]
+    #[doc = "     literals_in_composites_not_rotated: (NOT (('EXPLICIT_DRAUGHTING.ANNOTATION_TEXT_OCCURRENCE' "]
+    #[doc = "                                             IN TYPEOF(SELF)) AND ('EXPLICIT_DRAUGHTING.COMPOSITE_TEXT' "]
+    #[doc = "                                             IN TYPEOF(SELF\\styled_item."]
+    #[doc = "                                             item)))) OR (SIZEOF("]
+    #[doc = "                                             QUERY ( literal <* "]
+    #[doc = "                                             QUERY ( text <* SELF\\"]
+    #[doc = "                                             styled_item.item\\"]
+    #[doc = "                                             composite_text.collected_text"]
+    #[doc = "                                              | ('EXPLICIT_DRAUGHTING.TEXT_LITERAL' "]
+    #[doc = "                                             IN TYPEOF(text)) ) | EXISTS("]
+    #[doc = "                                             literal.placement\\"]
+    #[doc = "                                             axis2_placement_2d."]
+    #[doc = "                                             ref_direction) )) = 0);"]
+    #[doc = "     single_text_alignment             : (NOT (('EXPLICIT_DRAUGHTING.ANNOTATION_TEXT_OCCURRENCE' "]
+    #[doc = "                                             IN TYPEOF(SELF)) AND ('EXPLICIT_DRAUGHTING.COMPOSITE_TEXT' "]
+    #[doc = "                                             IN TYPEOF(SELF\\styled_item."]
+    #[doc = "                                             item)))) OR "]
+    #[doc = "                                             check_text_alignment(SELF\\"]
+    #[doc = "                                             styled_item.item);"]
+    #[doc = "     single_text_font                  : (NOT (('EXPLICIT_DRAUGHTING.ANNOTATION_TEXT_OCCURRENCE' "]
+    #[doc = "                                             IN TYPEOF(SELF)) AND ('EXPLICIT_DRAUGHTING.COMPOSITE_TEXT' "]
+    #[doc = "                                             IN TYPEOF(SELF\\styled_item."]
+    #[doc = "                                             item)))) OR check_text_font("]
+    #[doc = "                                             SELF\\styled_item.item);"]
+    #[doc = "     allowable_text_literals           : (NOT (('EXPLICIT_DRAUGHTING.ANNOTATION_TEXT_OCCURRENCE' "]
+    #[doc = "                                             IN TYPEOF(SELF)) AND ('EXPLICIT_DRAUGHTING.COMPOSITE_TEXT' "]
+    #[doc = "                                             IN TYPEOF(SELF\\styled_item."]
+    #[doc = "                                             item)))) OR (SIZEOF("]
+    #[doc = "                                             QUERY ( literal <* "]
+    #[doc = "                                             QUERY ( text <* SELF\\"]
+    #[doc = "                                             styled_item.item\\"]
+    #[doc = "                                             composite_text.collected_text"]
+    #[doc = "                                              | ('EXPLICIT_DRAUGHTING.TEXT_LITERAL' "]
+    #[doc = "                                             IN TYPEOF(text)) ) | (NOT ("]
+    #[doc = "                                             SIZEOF(TYPEOF(literal) * ["]
+    #[doc = "                                             'EXPLICIT_DRAUGHTING.' + 'TEXT_LITERAL_WITH_BLANKING_BOX',"]
+    #[doc = "                                             'EXPLICIT_DRAUGHTING.' + 'TEXT_LITERAL_WITH_ASSOCIATED_CURVES']) "]
+    #[doc = "                                             = 0)) )) = 0);"]
+    #[doc = "     styled_text_literal_curves        : (NOT (('EXPLICIT_DRAUGHTING.ANNOTATION_TEXT_OCCURRENCE' "]
+    #[doc = "                                             IN TYPEOF(SELF)) AND ('EXPLICIT_DRAUGHTING.TEXT_LITERAL_WITH_ASSOCIATED_CURVES' "]
+    #[doc = "                                             IN TYPEOF(SELF\\styled_item."]
+    #[doc = "                                             item)))) OR (SIZEOF("]
+    #[doc = "                                             QUERY ( crv <* SELF\\"]
+    #[doc = "                                             styled_item.item\\text_literal_with_associated_curves"]
+    #[doc = "                                             .associated_curves | (NOT ("]
+    #[doc = "                                             SIZEOF(QUERY ( si <* USEDIN("]
+    #[doc = "                                             crv,'EXPLICIT_DRAUGHTING.' + "]
+    #[doc = "                                             'STYLED_ITEM.ITEM') | ('EXPLICIT_DRAUGHTING.ANNOTATION_CURVE_OCCURRENCE' "]
+    #[doc = "                                             IN TYPEOF(si)) )) > 0)) )) = "]
+    #[doc = "                                             0);"]
+    #[doc = "     styled_composite_text_curves      : (NOT (('EXPLICIT_DRAUGHTING.ANNOTATION_TEXT_OCCURRENCE' "]
+    #[doc = "                                             IN TYPEOF(SELF)) AND ('EXPLICIT_DRAUGHTING.COMPOSITE_TEXT_WITH_ASSOCIATED_CURVES' "]
+    #[doc = "                                             IN TYPEOF(SELF\\styled_item."]
+    #[doc = "                                             item)))) OR (SIZEOF("]
+    #[doc = "                                             QUERY ( crv <* SELF\\"]
+    #[doc = "                                             styled_item.item\\composite_text_with_associated_curves"]
+    #[doc = "                                             .associated_curves | (NOT ("]
+    #[doc = "                                             SIZEOF(QUERY ( si <* USEDIN("]
+    #[doc = "                                             crv,'EXPLICIT_DRAUGHTING.' + "]
+    #[doc = "                                             'STYLED_ITEM.ITEM') | ('EXPLICIT_DRAUGHTING.ANNOTATION_CURVE_OCCURRENCE' "]
+    #[doc = "                                             IN TYPEOF(si)) )) > 0)) )) = "]
+    #[doc = "                                             0);"]
+    #[doc = "     curve_style_has_width             : SIZEOF(QUERY ( cs <* "]
+    #[doc = "                                             QUERY ( sty <* SELF\\"]
+    #[doc = "                                             styled_item.styles | ('EXPLICIT_DRAUGHTING.CURVE_STYLE' "]
+    #[doc = "                                             IN TYPEOF(sty.styles[1])) )"]
+    #[doc = "                                              | (NOT (('EXPLICIT_DRAUGHTING.LENGTH_MEASURE_WITH_UNIT' "]
+    #[doc = "                                             IN TYPEOF(cs.styles[1]\\"]
+    #[doc = "                                             curve_style.curve_width)) AND"]
+    #[doc = "                                              ('EXPLICIT_DRAUGHTING.POSITIVE_LENGTH_MEASURE' "]
+    #[doc = "                                             IN TYPEOF(cs.styles[1]\\"]
+    #[doc = "                                             curve_style.curve_width."]
+    #[doc = "                                             value_component)))) )) = 0;"]
+    #[doc = "     tiling_constraints                : SIZEOF(QUERY ( fas <* "]
+    #[doc = "                                             QUERY ( sty <* SELF\\"]
+    #[doc = "                                             styled_item.styles | ('EXPLICIT_DRAUGHTING.FILL_AREA_STYLE' "]
+    #[doc = "                                             IN TYPEOF(sty.styles[1])) )"]
+    #[doc = "                                              | (NOT ((SIZEOF("]
+    #[doc = "                                             QUERY ( fs <* fas.styles[1]\\"]
+    #[doc = "                                             fill_area_style.fill_styles"]
+    #[doc = "                                              | ('EXPLICIT_DRAUGHTING.FILL_AREA_STYLE_TILES' "]
+    #[doc = "                                             IN TYPEOF(fs)) )) <= 1) AND ("]
+    #[doc = "                                             SIZEOF(QUERY ( fst <* "]
+    #[doc = "                                             QUERY ( fs <* fas.styles[1]\\"]
+    #[doc = "                                             fill_area_style.fill_styles"]
+    #[doc = "                                              | ('EXPLICIT_DRAUGHTING.FILL_AREA_STYLE_TILES' "]
+    #[doc = "                                             IN TYPEOF(fs)) ) | (NOT ("]
+    #[doc = "                                             SIZEOF(fst.tiles) = 1)) )) = "]
+    #[doc = "                                             0))) )) = 0;"]
+    #[doc = "     hatching_constraints              : SIZEOF(QUERY ( fas <* "]
+    #[doc = "                                             QUERY ( sty <* SELF\\"]
+    #[doc = "                                             styled_item.styles | ('EXPLICIT_DRAUGHTING.FILL_AREA_STYLE' "]
+    #[doc = "                                             IN TYPEOF(sty.styles[1])) )"]
+    #[doc = "                                              | (NOT (SIZEOF("]
+    #[doc = "                                             QUERY ( fst <* QUERY ( fs <* "]
+    #[doc = "                                             fas.styles[1]\\fill_area_style"]
+    #[doc = "                                             .fill_styles | ('EXPLICIT_DRAUGHTING.FILL_AREA_STYLE_HATCHING' "]
+    #[doc = "                                             IN TYPEOF(fs)) ) | (NOT (fst.point_of_reference_hatch_line "]
+    #[doc = "                                             :=: fst.pattern_start)) )) = "]
+    #[doc = "                                             0)) )) = 0;"]
+    #[doc = "     text_style_constraint             : SIZEOF(QUERY ( ts <* "]
+    #[doc = "                                             QUERY ( sty <* SELF\\"]
+    #[doc = "                                             styled_item.styles | ('EXPLICIT_DRAUGHTING.TEXT_STYLE' "]
+    #[doc = "                                             IN TYPEOF(sty.styles[1])) )"]
+    #[doc = "                                              | (NOT ('EXPLICIT_DRAUGHTING.TEXT_STYLE_WITH_BOX_CHARACTERISTICS' "]
+    #[doc = "                                             IN TYPEOF(ts.styles[1]))) )) "]
+    #[doc = "                                             = 0;"]
+    #[doc = "     text_style_characteristics        : SIZEOF(QUERY ( ts <* "]
+    #[doc = "                                             QUERY ( sty <* SELF\\"]
+    #[doc = "                                             styled_item.styles | (("]
+    #[doc = "                                             'EXPLICIT_DRAUGHTING.' + 'TEXT_STYLE_WITH_BOX_CHARACTERISTICS') "]
+    #[doc = "                                             IN TYPEOF(sty.styles[1])) )"]
+    #[doc = "                                              | (NOT (SIZEOF(ts.styles[1]\\text_style_with_box_characteristics"]
+    #[doc = "                                             .characteristics) = 4)) )) = "]
+    #[doc = "                                             0;"]
+    #[doc = " END_ENTITY; -- draughting_annotation_occurrence"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -4012,6 +6218,16 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub annotation_occurrence: AnnotationOccurrence,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY draughting_approval_assignment"]
+    #[doc = "   SUBTYPE OF (approval_assignment);"]
+    #[doc = "     approved_items : SET [1:?] OF approved_item;"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: SIZEOF(QUERY ( item <* approved_items | ("]
+    #[doc = "              'EXPLICIT_DRAUGHTING.DRAWING_REVISION' IN TYPEOF(item)) )) "]
+    #[doc = "              <= 1;"]
+    #[doc = " END_ENTITY; -- draughting_approval_assignment"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -4026,8 +6242,15 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub approval_assignment: ApprovalAssignment,
         #[holder(use_place_holder)]
+        # [holder (size = 1 ..)]
         pub approved_items: Vec<ApprovedItem>,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY draughting_callout"]
+    #[doc = "   SUBTYPE OF (geometric_representation_item);"]
+    #[doc = "     contents : SET [1:?] OF draughting_callout_element;"]
+    #[doc = " END_ENTITY; -- draughting_callout"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -4042,6 +6265,7 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub geometric_representation_item: GeometricRepresentationItem,
         #[holder(use_place_holder)]
+        # [holder (size = 1 ..)]
         pub contents: Vec<DraughtingCalloutElement>,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
@@ -4160,6 +6384,14 @@ pub mod explicit_draughting {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY draughting_callout_relationship;"]
+    #[doc = "     name                        : label;"]
+    #[doc = "     description                 : text;"]
+    #[doc = "     relating_draughting_callout : draughting_callout;"]
+    #[doc = "     related_draughting_callout  : draughting_callout;"]
+    #[doc = " END_ENTITY; -- draughting_callout_relationship"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = draughting_callout_relationship)]
@@ -4221,6 +6453,12 @@ pub mod explicit_draughting {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY draughting_contract_assignment"]
+    #[doc = "   SUBTYPE OF (contract_assignment);"]
+    #[doc = "     items : SET [1:?] OF contracted_item;"]
+    #[doc = " END_ENTITY; -- draughting_contract_assignment"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -4235,8 +6473,187 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub contract_assignment: ContractAssignment,
         #[holder(use_place_holder)]
+        # [holder (size = 1 ..)]
         pub items: Vec<ContractedItem>,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY draughting_drawing_revision"]
+    #[doc = "   SUBTYPE OF (drawing_revision);"]
+    #[doc = "   WHERE"]
+    #[doc = "     drawing_requires_sheets           : (SIZEOF(USEDIN(SELF,'EXPLICIT_DRAUGHTING.AREA_IN_SET.IN_SET')) "]
+    #[doc = "                                             >= 1) AND (SIZEOF("]
+    #[doc = "                                             QUERY ( ais <* USEDIN(SELF,"]
+    #[doc = "                                             'EXPLICIT_DRAUGHTING.' + "]
+    #[doc = "                                             'AREA_IN_SET.IN_SET') | (NOT "]
+    #[doc = "                                             ('EXPLICIT_DRAUGHTING.DRAWING_SHEET_REVISION' "]
+    #[doc = "                                             IN TYPEOF(ais.area))) )) = 0);"]
+    #[doc = "     drawing_approval_has_date         : SIZEOF(QUERY ( app_ass <* "]
+    #[doc = "                                             USEDIN(SELF,"]
+    #[doc = "                                             'EXPLICIT_DRAUGHTING.' + 'DRAUGHTING_APPROVAL_ASSIGNMENT.APPROVED_ITEMS')"]
+    #[doc = "                                              | (NOT (SIZEOF(USEDIN("]
+    #[doc = "                                             app_ass.assigned_approval,'EXPLICIT_DRAUGHTING.APPROVAL_DATE_TIME.DATED_APPROVAL')) "]
+    #[doc = "                                             = 1)) )) = 0;"]
+    #[doc = "     sheet_approval_has_date           : SIZEOF(QUERY ( ais <* USEDIN("]
+    #[doc = "                                             SELF,'EXPLICIT_DRAUGHTING.' +"]
+    #[doc = "                                              'AREA_IN_SET.IN_SET') | ("]
+    #[doc = "                                             NOT (SIZEOF("]
+    #[doc = "                                             QUERY ( app_ass <* USEDIN(ais"]
+    #[doc = "                                             .area,'EXPLICIT_DRAUGHTING.' "]
+    #[doc = "                                             + 'DRAUGHTING_APPROVAL_ASSIGNMENT.APPROVED_ITEMS')"]
+    #[doc = "                                              | (NOT (SIZEOF(USEDIN("]
+    #[doc = "                                             app_ass.assigned_approval,'EXPLICIT_DRAUGHTING.APPROVAL_DATE_TIME.DATED_APPROVAL')) "]
+    #[doc = "                                             = 1)) )) = 0)) )) = 0;"]
+    #[doc = "     drawing_approval_has_authorization: SIZEOF(QUERY ( app_ass <* "]
+    #[doc = "                                             USEDIN(SELF,"]
+    #[doc = "                                             'EXPLICIT_DRAUGHTING.' + 'DRAUGHTING_APPROVAL_ASSIGNMENT.APPROVED_ITEMS')"]
+    #[doc = "                                              | (NOT (SIZEOF(USEDIN("]
+    #[doc = "                                             app_ass.assigned_approval,"]
+    #[doc = "                                             'EXPLICIT_DRAUGHTING.' + 'APPROVAL_PERSON_ORGANIZATION.AUTHORIZED_APPROVAL')) "]
+    #[doc = "                                             >= 1)) )) = 0;"]
+    #[doc = "     sheet_approval_has_authorization  : SIZEOF(QUERY ( ais <* USEDIN("]
+    #[doc = "                                             SELF,'EXPLICIT_DRAUGHTING.' +"]
+    #[doc = "                                              'AREA_IN_SET.IN_SET') | ("]
+    #[doc = "                                             NOT (SIZEOF("]
+    #[doc = "                                             QUERY ( app_ass <* USEDIN(ais"]
+    #[doc = "                                             .area,'EXPLICIT_DRAUGHTING.' "]
+    #[doc = "                                             + 'DRAUGHTING_APPROVAL_ASSIGNMENT.APPROVED_ITEMS')"]
+    #[doc = "                                              | (NOT (SIZEOF(USEDIN("]
+    #[doc = "                                             app_ass.assigned_approval,"]
+    #[doc = "                                             'EXPLICIT_DRAUGHTING.' + 'APPROVAL_PERSON_ORGANIZATION.AUTHORIZED_APPROVAL')) "]
+    #[doc = "                                             >= 1)) )) = 0)) )) = 0;"]
+    #[doc = "     drawing_title                     : SIZEOF(USEDIN(SELF,'EXPLICIT_DRAUGHTING.DRAUGHTING_TITLE.ITEMS')) "]
+    #[doc = "                                             <= 1;"]
+    #[doc = "     sheet_title                       : SIZEOF(QUERY ( ais <* USEDIN("]
+    #[doc = "                                             SELF,'EXPLICIT_DRAUGHTING.' +"]
+    #[doc = "                                              'AREA_IN_SET.IN_SET') | ("]
+    #[doc = "                                             NOT (SIZEOF(USEDIN(ais.area,"]
+    #[doc = "                                             'EXPLICIT_DRAUGHTING.' + "]
+    #[doc = "                                             'DRAUGHTING_TITLE.ITEMS')) <="]
+    #[doc = "                                              1)) )) = 0;"]
+    #[doc = "     allowable_items_of_sheet          : SIZEOF(QUERY ( ais <* USEDIN("]
+    #[doc = "                                             SELF,'EXPLICIT_DRAUGHTING.' +"]
+    #[doc = "                                              'AREA_IN_SET.IN_SET') | ("]
+    #[doc = "                                             NOT (SIZEOF(QUERY ( item <* "]
+    #[doc = "                                             ais.area\\representation.items"]
+    #[doc = "                                              | (NOT (SIZEOF(['EXPLICIT_DRAUGHTING.ANNOTATION_OCCURRENCE','EXPLICIT_DRAUGHTING.MAPPED_ITEM','EXPLICIT_DRAUGHTING.AXIS2_PLACEMENT','EXPLICIT_DRAUGHTING.PLANAR_BOX'] "]
+    #[doc = "                                             * TYPEOF(item)) = 1)) )) = 0)) )) "]
+    #[doc = "                                             = 0;"]
+    #[doc = "     sheet_contains_view_or_annotation : SIZEOF(QUERY ( ais <* USEDIN("]
+    #[doc = "                                             SELF,'EXPLICIT_DRAUGHTING.' +"]
+    #[doc = "                                              'AREA_IN_SET.IN_SET') | ("]
+    #[doc = "                                             NOT (SIZEOF(QUERY ( item <* "]
+    #[doc = "                                             ais.area\\representation.items"]
+    #[doc = "                                              | (SIZEOF(['EXPLICIT_DRAUGHTING.ANNOTATION_OCCURRENCE','EXPLICIT_DRAUGHTING.MAPPED_ITEM'] "]
+    #[doc = "                                             * TYPEOF(item)) = 1) )) > 0)) )) "]
+    #[doc = "                                             = 0;"]
+    #[doc = "     planar_box_in_sheet               : SIZEOF(QUERY ( ais <* USEDIN("]
+    #[doc = "                                             SELF,'EXPLICIT_DRAUGHTING.' +"]
+    #[doc = "                                              'AREA_IN_SET.IN_SET') | ("]
+    #[doc = "                                             NOT (SIZEOF(QUERY ( p_b <* "]
+    #[doc = "                                             QUERY ( item <* ais.area\\"]
+    #[doc = "                                             representation.items | ('EXPLICIT_DRAUGHTING.PLANAR_BOX' "]
+    #[doc = "                                             IN TYPEOF(item)) ) | (NOT ("]
+    #[doc = "                                             SIZEOF(USEDIN(p_b,"]
+    #[doc = "                                             'EXPLICIT_DRAUGHTING.' + "]
+    #[doc = "                                             'PRESENTATION_SIZE.SIZE')) = "]
+    #[doc = "                                             1)) )) = 0)) )) = 0;"]
+    #[doc = "     sheets_contain_views              : SIZEOF(QUERY ( ais <* USEDIN("]
+    #[doc = "                                             SELF,'EXPLICIT_DRAUGHTING.' +"]
+    #[doc = "                                              'AREA_IN_SET.IN_SET') | ("]
+    #[doc = "                                             NOT (SIZEOF(QUERY ( mi <* "]
+    #[doc = "                                             QUERY ( item <* ais.area\\"]
+    #[doc = "                                             representation.items | ('EXPLICIT_DRAUGHTING.MAPPED_ITEM' "]
+    #[doc = "                                             IN TYPEOF(item)) ) | (NOT ('EXPLICIT_DRAUGHTING.PRESENTATION_VIEW' "]
+    #[doc = "                                             IN TYPEOF(mi.mapping_source."]
+    #[doc = "                                             mapped_representation))) )) ="]
+    #[doc = "                                              0)) )) = 0;"]
+    #[doc = "     sheets_contain_placements         : SIZEOF(QUERY ( ais <* USEDIN("]
+    #[doc = "                                             SELF,'EXPLICIT_DRAUGHTING.' +"]
+    #[doc = "                                              'AREA_IN_SET.IN_SET') | ("]
+    #[doc = "                                             NOT (SIZEOF(QUERY ( a2p <* "]
+    #[doc = "                                             QUERY ( item <* ais.area\\"]
+    #[doc = "                                             representation.items | ('EXPLICIT_DRAUGHTING.AXIS2_PLACEMENT' "]
+    #[doc = "                                             IN TYPEOF(item)) ) | (NOT ("]
+    #[doc = "                                             SIZEOF(USEDIN(a2p,"]
+    #[doc = "                                             'EXPLICIT_DRAUGHTING.' + "]
+    #[doc = "                                             'MAPPED_ITEM.MAPPING_TARGET')) "]
+    #[doc = "                                             > 0)) )) = 0)) )) = 0;"]
+    #[doc = "     sheets_have_unique_context        : SIZEOF(QUERY ( ais <* USEDIN("]
+    #[doc = "                                             SELF,'EXPLICIT_DRAUGHTING.AREA_IN_SET.IN_SET')"]
+    #[doc = "                                              | (NOT (SIZEOF(ais.area\\"]
+    #[doc = "                                             representation."]
+    #[doc = "                                             context_of_items."]
+    #[doc = "                                             representations_in_context) ="]
+    #[doc = "                                              1)) )) = 0;"]
+    #[doc = "     views_presented_once              : SIZEOF(QUERY ( ais <* USEDIN("]
+    #[doc = "                                             SELF,'EXPLICIT_DRAUGHTING.' +"]
+    #[doc = "                                              'AREA_IN_SET.IN_SET') | ("]
+    #[doc = "                                             NOT (SIZEOF(QUERY ( mi <* "]
+    #[doc = "                                             QUERY ( item <* ais.area\\"]
+    #[doc = "                                             representation.items | ('EXPLICIT_DRAUGHTING.MAPPED_ITEM' "]
+    #[doc = "                                             IN TYPEOF(item)) ) | (NOT ("]
+    #[doc = "                                             SIZEOF(USEDIN(mi."]
+    #[doc = "                                             mapping_source."]
+    #[doc = "                                             mapped_representation,'EXPLICIT_DRAUGHTING.REPRESENTATION_MAP.' "]
+    #[doc = "                                             + 'MAPPED_REPRESENTATION')) ="]
+    #[doc = "                                              1)) )) = 0)) )) = 0;"]
+    #[doc = "     allowable_items_of_view           : SIZEOF(QUERY ( ais <* USEDIN("]
+    #[doc = "                                             SELF,'EXPLICIT_DRAUGHTING.' +"]
+    #[doc = "                                              'AREA_IN_SET.IN_SET') | ("]
+    #[doc = "                                             NOT (SIZEOF(QUERY ( mi <* "]
+    #[doc = "                                             QUERY ( item <* ais.area\\"]
+    #[doc = "                                             representation.items | ('EXPLICIT_DRAUGHTING.MAPPED_ITEM' "]
+    #[doc = "                                             IN TYPEOF(item)) ) | (NOT ("]
+    #[doc = "                                             SIZEOF(QUERY ( pv_item <* mi."]
+    #[doc = "                                             mapping_source."]
+    #[doc = "                                             mapped_representation\\"]
+    #[doc = "                                             representation.items | (NOT ("]
+    #[doc = "                                             SIZEOF(['EXPLICIT_DRAUGHTING.ANNOTATION_OCCURRENCE','EXPLICIT_DRAUGHTING.CAMERA_IMAGE','EXPLICIT_DRAUGHTING.AXIS2_PLACEMENT'] "]
+    #[doc = "                                             * TYPEOF(pv_item)) = 1)) )) ="]
+    #[doc = "                                              0)) )) = 0)) )) = 0;"]
+    #[doc = "     views_contain_one_projection      : SIZEOF(QUERY ( ais <* USEDIN("]
+    #[doc = "                                             SELF,'EXPLICIT_DRAUGHTING.' +"]
+    #[doc = "                                              'AREA_IN_SET.IN_SET') | ("]
+    #[doc = "                                             NOT (SIZEOF(QUERY ( mi <* "]
+    #[doc = "                                             QUERY ( item <* ais.area\\"]
+    #[doc = "                                             representation.items | ('EXPLICIT_DRAUGHTING.MAPPED_ITEM' "]
+    #[doc = "                                             IN TYPEOF(item)) ) | (NOT ("]
+    #[doc = "                                             SIZEOF(QUERY ( pv_item <* mi."]
+    #[doc = "                                             mapping_source."]
+    #[doc = "                                             mapped_representation\\"]
+    #[doc = "                                             representation.items | ('EXPLICIT_DRAUGHTING.CAMERA_IMAGE' "]
+    #[doc = "                                             IN TYPEOF(pv_item)) )) = 1)) )) "]
+    #[doc = "                                             = 0)) )) = 0;"]
+    #[doc = "     views_contain_one_placement       : SIZEOF(QUERY ( ais <* USEDIN("]
+    #[doc = "                                             SELF,'EXPLICIT_DRAUGHTING.' +"]
+    #[doc = "                                              'AREA_IN_SET.IN_SET') | ("]
+    #[doc = "                                             NOT (SIZEOF(QUERY ( mi <* "]
+    #[doc = "                                             QUERY ( item <* ais.area\\"]
+    #[doc = "                                             representation.items | ('EXPLICIT_DRAUGHTING.MAPPED_ITEM' "]
+    #[doc = "                                             IN TYPEOF(item)) ) | (NOT ("]
+    #[doc = "                                             SIZEOF(QUERY ( a2p <* "]
+    #[doc = "                                             QUERY ( pv_item <* mi."]
+    #[doc = "                                             mapping_source."]
+    #[doc = "                                             mapped_representation\\"]
+    #[doc = "                                             representation.items | ('EXPLICIT_DRAUGHTING.AXIS2_PLACEMENT' "]
+    #[doc = "                                             IN TYPEOF(pv_item)) ) | ('EXPLICIT_DRAUGHTING.MAPPING_SOURCE.MAPPING_ORIGIN' "]
+    #[doc = "                                             IN ROLESOF(a2p)) )) = 1)) )) "]
+    #[doc = "                                             = 0)) )) = 0;"]
+    #[doc = "     views_have_unique_context         : SIZEOF(QUERY ( ais <* USEDIN("]
+    #[doc = "                                             SELF,'EXPLICIT_DRAUGHTING.AREA_IN_SET.IN_SET')"]
+    #[doc = "                                              | (NOT (SIZEOF("]
+    #[doc = "                                             QUERY ( mi <* "]
+    #[doc = "                                             QUERY ( item <* ais.area\\"]
+    #[doc = "                                             representation.items | ('EXPLICIT_DRAUGHTING.MAPPED_ITEM' "]
+    #[doc = "                                             IN TYPEOF(item)) ) | (NOT ("]
+    #[doc = "                                             SIZEOF(mi.mapping_source."]
+    #[doc = "                                             mapped_representation\\"]
+    #[doc = "                                             representation."]
+    #[doc = "                                             context_of_items."]
+    #[doc = "                                             representations_in_context) ="]
+    #[doc = "                                              1)) )) = 0)) )) = 0;"]
+    #[doc = " END_ENTITY; -- draughting_drawing_revision"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -4251,6 +6668,37 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub drawing_revision: DrawingRevision,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY draughting_elements"]
+    #[doc = "   SUBTYPE OF (draughting_callout);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: SIZEOF(QUERY ( l_c <* QUERY ( con <* SELF.contents | ("]
+    #[doc = "              'EXPLICIT_DRAUGHTING.LEADER_CURVE' IN TYPEOF(con)) ) | (NOT "]
+    #[doc = "              (SIZEOF(QUERY ( ldc <* USEDIN(l_c,'EXPLICIT_DRAUGHTING.' + "]
+    #[doc = "              'DRAUGHTING_CALLOUT.CONTENTS') | ("]
+    #[doc = "              'EXPLICIT_DRAUGHTING.LEADER_DIRECTED_CALLOUT' IN TYPEOF(ldc)) )) "]
+    #[doc = "              <= 1)) )) = 0;"]
+    #[doc = "     wr2: (NOT ('EXPLICIT_DRAUGHTING.DIMENSION_CURVE_DIRECTED_CALLOUT' IN"]
+    #[doc = "               TYPEOF(SELF))) OR (SIZEOF(QUERY ( con <* SELF.contents | ("]
+    #[doc = "              'EXPLICIT_DRAUGHTING.PROJECTION_CURVE' IN TYPEOF(con)) )) <="]
+    #[doc = "               2);"]
+    #[doc = "     wr3: SIZEOF(QUERY ( rc <* USEDIN(SELF,"]
+    #[doc = "              'EXPLICIT_DRAUGHTING.DIMENSION_CALLOUT_' + "]
+    #[doc = "              'RELATIONSHIP.RELATING_DRAUGHTING_CALLOUT') | (rc.name = "]
+    #[doc = "              'primary') )) <= 1;"]
+    #[doc = "     wr4: SIZEOF(QUERY ( rc <* USEDIN(SELF,"]
+    #[doc = "              'EXPLICIT_DRAUGHTING.DIMENSION_CALLOUT_' + "]
+    #[doc = "              'RELATIONSHIP.RELATING_DRAUGHTING_CALLOUT') | (rc.name = "]
+    #[doc = "              'secondary') )) <= 1;"]
+    #[doc = "     wr5: SIZEOF(QUERY ( sec <* QUERY ( rc <* USEDIN(SELF,"]
+    #[doc = "              'EXPLICIT_DRAUGHTING.DIMENSION_CALLOUT_' + "]
+    #[doc = "              'RELATIONSHIP.RELATING_DRAUGHTING_CALLOUT') | (rc.name = "]
+    #[doc = "              'secondary') ) | (NOT (SIZEOF(QUERY ( prim <* USEDIN(SELF,"]
+    #[doc = "              'EXPLICIT_DRAUGHTING.DIMENSION_CALLOUT_' + "]
+    #[doc = "              'RELATIONSHIP.RELATING_DRAUGHTING_CALLOUT') | (prim.name = "]
+    #[doc = "              'primary') )) = 1)) )) = 0;"]
+    #[doc = " END_ENTITY; -- draughting_elements"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -4265,6 +6713,12 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub draughting_callout: DraughtingCallout,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY draughting_group_assignment"]
+    #[doc = "   SUBTYPE OF (group_assignment);"]
+    #[doc = "     items : SET [1:?] OF draughting_grouped_item;"]
+    #[doc = " END_ENTITY; -- draughting_group_assignment"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -4279,8 +6733,37 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub group_assignment: GroupAssignment,
         #[holder(use_place_holder)]
+        # [holder (size = 1 ..)]
         pub items: Vec<DraughtingGroupedItem>,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY draughting_model"]
+    #[doc = "   SUBTYPE OF (representation);"]
+    #[doc = "   UNIQUE"]
+    #[doc = "     ur1 : name;"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: SIZEOF(QUERY ( it <* SELF.items | (NOT (SIZEOF(["]
+    #[doc = "              'EXPLICIT_DRAUGHTING.MAPPED_ITEM',"]
+    #[doc = "              'EXPLICIT_DRAUGHTING.STYLED_ITEM',"]
+    #[doc = "              'EXPLICIT_DRAUGHTING.AXIS2_PLACEMENT'] * TYPEOF(it)) = 1)) )) "]
+    #[doc = "              = 0;"]
+    #[doc = "     wr2: SIZEOF(QUERY ( mi <* QUERY ( it <* SELF.items | ("]
+    #[doc = "              'EXPLICIT_DRAUGHTING.MAPPED_ITEM' IN TYPEOF(it)) ) | (NOT ("]
+    #[doc = "              SIZEOF(['EXPLICIT_DRAUGHTING.SHAPE_REPRESENTATION',"]
+    #[doc = "              'EXPLICIT_DRAUGHTING.DRAUGHTING_MODEL'] * TYPEOF(mi\\"]
+    #[doc = "              mapped_item.mapping_source.mapped_representation)) = 1)) )) "]
+    #[doc = "              = 0;"]
+    #[doc = "     wr3: SIZEOF(QUERY ( smi <* QUERY ( si <* QUERY ( it <* SELF.items | "]
+    #[doc = "              ('EXPLICIT_DRAUGHTING.STYLED_ITEM' IN TYPEOF(it)) ) | ("]
+    #[doc = "              'EXPLICIT_DRAUGHTING.MAPPED_ITEM' IN TYPEOF(si\\styled_item."]
+    #[doc = "              item)) ) | (NOT (('EXPLICIT_DRAUGHTING.SHAPE_REPRESENTATION' "]
+    #[doc = "              IN TYPEOF(smi\\styled_item.item\\mapped_item.mapping_source."]
+    #[doc = "              mapped_representation)) AND (SIZEOF(QUERY ( sty <* smi\\"]
+    #[doc = "              styled_item.styles | (NOT (SIZEOF(QUERY ( psa <* sty.styles"]
+    #[doc = "               | (NOT ('EXPLICIT_DRAUGHTING.CURVE_STYLE' IN TYPEOF(psa))) )) "]
+    #[doc = "              = 1)) )) = 1))) )) = 0;"]
+    #[doc = " END_ENTITY; -- draughting_model"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -4295,6 +6778,12 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub representation: Representation,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY draughting_organization_assignment"]
+    #[doc = "   SUBTYPE OF (organization_assignment);"]
+    #[doc = "     assigned_items : SET [1:?] OF draughting_organization_item;"]
+    #[doc = " END_ENTITY; -- draughting_organization_assignment"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -4309,8 +6798,15 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub organization_assignment: OrganizationAssignment,
         #[holder(use_place_holder)]
+        # [holder (size = 1 ..)]
         pub assigned_items: Vec<DraughtingOrganizationItem>,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY draughting_person_and_organization_assignment"]
+    #[doc = "   SUBTYPE OF (person_and_organization_assignment);"]
+    #[doc = "     assigned_items : SET [1:?] OF draughting_organization_item;"]
+    #[doc = " END_ENTITY; -- draughting_person_and_organization_assignment"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -4325,8 +6821,15 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub person_and_organization_assignment: PersonAndOrganizationAssignment,
         #[holder(use_place_holder)]
+        # [holder (size = 1 ..)]
         pub assigned_items: Vec<DraughtingOrganizationItem>,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY draughting_person_assignment"]
+    #[doc = "   SUBTYPE OF (person_assignment);"]
+    #[doc = "     assigned_items : SET [1:?] OF draughting_organization_item;"]
+    #[doc = " END_ENTITY; -- draughting_person_assignment"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -4341,8 +6844,17 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub person_assignment: PersonAssignment,
         #[holder(use_place_holder)]
+        # [holder (size = 1 ..)]
         pub assigned_items: Vec<DraughtingOrganizationItem>,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY draughting_pre_defined_colour"]
+    #[doc = "   SUBTYPE OF (pre_defined_colour);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: SELF.name IN ['red','green','blue','yellow','magenta','cyan',"]
+    #[doc = "              'black','white'];"]
+    #[doc = " END_ENTITY; -- draughting_pre_defined_colour"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -4357,6 +6869,14 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub pre_defined_colour: PreDefinedColour,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY draughting_pre_defined_curve_font"]
+    #[doc = "   SUBTYPE OF (pre_defined_curve_font);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: SELF.name IN ['continuous','chain','chain double dash','dashed',"]
+    #[doc = "              'dotted'];"]
+    #[doc = " END_ENTITY; -- draughting_pre_defined_curve_font"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -4371,6 +6891,13 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub pre_defined_curve_font: PreDefinedCurveFont,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY draughting_pre_defined_text_font"]
+    #[doc = "   SUBTYPE OF (pre_defined_text_font);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: SELF.name IN ['ISO 3098-1 font A','ISO 3098-1 font B'];"]
+    #[doc = " END_ENTITY; -- draughting_pre_defined_text_font"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -4385,6 +6912,17 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub pre_defined_text_font: PreDefinedTextFont,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY draughting_presented_item"]
+    #[doc = "   SUBTYPE OF (presented_item);"]
+    #[doc = "     items : SET [1:?] OF draughting_presented_item_select;"]
+    #[doc = "   WHERE"]
+    #[doc = "     presented_item_presentation: SIZEOF(QUERY ( pir <* USEDIN(SELF,"]
+    #[doc = "                                      'EXPLICIT_DRAUGHTING.' + 'PRESENTED_ITEM_REPRESENTATION.ITEM')"]
+    #[doc = "                                       | (NOT ('EXPLICIT_DRAUGHTING.DRAWING_REVISION' "]
+    #[doc = "                                      IN TYPEOF(pir.presentation))) )) = 0;"]
+    #[doc = " END_ENTITY; -- draughting_presented_item"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -4399,8 +6937,15 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub presented_item: PresentedItem,
         #[holder(use_place_holder)]
+        # [holder (size = 1 ..)]
         pub items: Vec<DraughtingPresentedItemSelect>,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY draughting_security_classification_assignment"]
+    #[doc = "   SUBTYPE OF (security_classification_assignment);"]
+    #[doc = "     assigned_items : SET [1:?] OF classified_item;"]
+    #[doc = " END_ENTITY; -- draughting_security_classification_assignment"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -4415,8 +6960,18 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub security_classification_assignment: SecurityClassificationAssignment,
         #[holder(use_place_holder)]
+        # [holder (size = 1 ..)]
         pub assigned_items: Vec<ClassifiedItem>,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY draughting_specification_reference"]
+    #[doc = "   SUBTYPE OF (document_reference);"]
+    #[doc = "     specified_items : SET [1:?] OF specified_item;"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: SELF.assigned_document.kind.product_data_type = "]
+    #[doc = "              'draughting specification';"]
+    #[doc = " END_ENTITY; -- draughting_specification_reference"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -4431,8 +6986,36 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub document_reference: DocumentReference,
         #[holder(use_place_holder)]
+        # [holder (size = 1 ..)]
         pub specified_items: Vec<SpecifiedItem>,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY draughting_subfigure_representation"]
+    #[doc = "   SUBTYPE OF (symbol_representation);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: SIZEOF(QUERY ( item <* SELF\\representation.items | (NOT ("]
+    #[doc = "              SIZEOF(['EXPLICIT_DRAUGHTING.ANNOTATION_OCCURRENCE',"]
+    #[doc = "              'EXPLICIT_DRAUGHTING.DRAUGHTING_CALLOUT',"]
+    #[doc = "              'EXPLICIT_DRAUGHTING.AXIS2_PLACEMENT'] * TYPEOF(item)) = 1)) )) "]
+    #[doc = "              = 0;"]
+    #[doc = "     wr2: SIZEOF(QUERY ( item <* SELF\\representation.items | (SIZEOF(["]
+    #[doc = "              'EXPLICIT_DRAUGHTING.ANNOTATION_OCCURRENCE',"]
+    #[doc = "              'EXPLICIT_DRAUGHTING.DRAUGHTING_CALLOUT'] * TYPEOF(item)) = "]
+    #[doc = "              1) )) >= 1;"]
+    #[doc = "     wr3: SIZEOF(QUERY ( srm <* QUERY ( rm <* USEDIN(SELF,"]
+    #[doc = "              'EXPLICIT_DRAUGHTING.' + 'REPRESENTATION_MAP.' + "]
+    #[doc = "              'MAPPED_REPRESENTATION') | ("]
+    #[doc = "              'EXPLICIT_DRAUGHTING.SYMBOL_REPRESENTATION_MAP' IN TYPEOF(rm)) )"]
+    #[doc = "               | (NOT (SIZEOF(QUERY ( a_s <* QUERY ( mi <* srm.map_usage"]
+    #[doc = "               | ('EXPLICIT_DRAUGHTING.ANNOTATION_SYMBOL' IN TYPEOF(mi)) )"]
+    #[doc = "               | (NOT (SIZEOF(QUERY ( aso <* USEDIN(a_s,"]
+    #[doc = "              'EXPLICIT_DRAUGHTING.' + 'STYLED_ITEM.ITEM') | (NOT (("]
+    #[doc = "              'EXPLICIT_DRAUGHTING.' + 'ANNOTATION_SUBFIGURE_OCCURRENCE') "]
+    #[doc = "              IN TYPEOF(aso))) )) = 0)) )) = 0)) )) > 0;"]
+    #[doc = "     wr4: NOT acyclic_mapped_item_usage(SELF);"]
+    #[doc = "     wr5: SIZEOF(SELF.context_of_items.representations_in_context) = 1;"]
+    #[doc = " END_ENTITY; -- draughting_subfigure_representation"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -4447,6 +7030,42 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub symbol_representation: SymbolRepresentation,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY draughting_symbol_representation"]
+    #[doc = "   SUBTYPE OF (symbol_representation);"]
+    #[doc = "   UNIQUE"]
+    #[doc = "     ur1 : name;"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: SIZEOF(QUERY ( item <* SELF\\representation.items | (NOT ("]
+    #[doc = "              SIZEOF(['EXPLICIT_DRAUGHTING.' + "]
+    #[doc = "              'ANNOTATION_CURVE_OCCURRENCE','EXPLICIT_DRAUGHTING.' + "]
+    #[doc = "              'ANNOTATION_SYMBOL_OCCURRENCE','EXPLICIT_DRAUGHTING.' + "]
+    #[doc = "              'ANNOTATION_FILL_AREA_OCCURRENCE','EXPLICIT_DRAUGHTING.' + "]
+    #[doc = "              'ANNOTATION_TEXT_OCCURRENCE','EXPLICIT_DRAUGHTING.' + "]
+    #[doc = "              'AXIS2_PLACEMENT'] * TYPEOF(item)) = 1)) )) = 0;"]
+    #[doc = "     wr2: SIZEOF(QUERY ( item <* SELF\\representation.items | (SIZEOF(["]
+    #[doc = "              'EXPLICIT_DRAUGHTING.' + 'ANNOTATION_CURVE_OCCURRENCE',"]
+    #[doc = "              'EXPLICIT_DRAUGHTING.' + 'ANNOTATION_SYMBOL_OCCURRENCE',"]
+    #[doc = "              'EXPLICIT_DRAUGHTING.' + 'ANNOTATION_FILL_AREA_OCCURRENCE',"]
+    #[doc = "              'EXPLICIT_DRAUGHTING.' + 'ANNOTATION_TEXT_OCCURRENCE'] * "]
+    #[doc = "              TYPEOF(item)) = 1) )) >= 1;"]
+    #[doc = "     wr3: SIZEOF(QUERY ( item <* SELF\\representation.items | ("]
+    #[doc = "              'EXPLICIT_DRAUGHTING.ANNOTATION_SUBFIGURE_OCCURRENCE' IN "]
+    #[doc = "              TYPEOF(item)) )) = 0;"]
+    #[doc = "     wr4: SIZEOF(QUERY ( srm <* QUERY ( rm <* USEDIN(SELF,"]
+    #[doc = "              'EXPLICIT_DRAUGHTING.' + 'REPRESENTATION_MAP.' + "]
+    #[doc = "              'MAPPED_REPRESENTATION') | ("]
+    #[doc = "              'EXPLICIT_DRAUGHTING.SYMBOL_REPRESENTATION_MAP' IN TYPEOF(rm)) )"]
+    #[doc = "               | (NOT (SIZEOF(QUERY ( a_s <* QUERY ( mi <* srm.map_usage"]
+    #[doc = "               | ('EXPLICIT_DRAUGHTING.ANNOTATION_SYMBOL' IN TYPEOF(mi)) )"]
+    #[doc = "               | (NOT (SIZEOF(QUERY ( aso <* USEDIN(a_s,"]
+    #[doc = "              'EXPLICIT_DRAUGHTING.' + 'STYLED_ITEM.ITEM') | (NOT (("]
+    #[doc = "              'EXPLICIT_DRAUGHTING.' + 'ANNOTATION_SYMBOL_OCCURRENCE') IN "]
+    #[doc = "              TYPEOF(aso))) )) = 0)) )) = 0)) )) > 0;"]
+    #[doc = "     wr5: NOT acyclic_mapped_item_usage(SELF);"]
+    #[doc = "     wr6: SIZEOF(SELF.context_of_items.representations_in_context) = 1;"]
+    #[doc = " END_ENTITY; -- draughting_symbol_representation"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -4500,6 +7119,14 @@ pub mod explicit_draughting {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY draughting_text_literal_with_delineation"]
+    #[doc = "   SUBTYPE OF (text_literal_with_delineation);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: SELF\\text_literal_with_delineation.delineation IN ['underline',"]
+    #[doc = "              'overline'];"]
+    #[doc = " END_ENTITY; -- draughting_text_literal_with_delineation"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -4514,18 +7141,32 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub text_literal_with_delineation: TextLiteralWithDelineation,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY draughting_title;"]
+    #[doc = "     items    : SET [1:?] OF draughting_titled_item;"]
+    #[doc = "     language : label;"]
+    #[doc = "     contents : text;"]
+    #[doc = " END_ENTITY; -- draughting_title"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = draughting_title)]
     #[holder(generate_deserialize)]
     pub struct DraughtingTitle {
         #[holder(use_place_holder)]
+        # [holder (size = 1 ..)]
         pub items: Vec<DraughtingTitledItem>,
         #[holder(use_place_holder)]
         pub language: Label,
         #[holder(use_place_holder)]
         pub contents: Text,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY drawing_definition;"]
+    #[doc = "     drawing_number : identifier;"]
+    #[doc = "     drawing_type   : OPTIONAL label;"]
+    #[doc = " END_ENTITY; -- drawing_definition"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = drawing_definition)]
@@ -4536,6 +7177,16 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub drawing_type: Option<Label>,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY drawing_revision"]
+    #[doc = "   SUBTYPE OF (presentation_set);"]
+    #[doc = "     revision_identifier : identifier;"]
+    #[doc = "     drawing_identifier  : drawing_definition;"]
+    #[doc = "     intended_scale      : OPTIONAL text;"]
+    #[doc = "   UNIQUE"]
+    #[doc = "     ur1 : revision_identifier, drawing_identifier;"]
+    #[doc = " END_ENTITY; -- drawing_revision"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -4595,6 +7246,11 @@ pub mod explicit_draughting {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY drawing_sheet_layout"]
+    #[doc = "   SUBTYPE OF (draughting_symbol_representation);"]
+    #[doc = " END_ENTITY; -- drawing_sheet_layout"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -4609,6 +7265,17 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub draughting_symbol_representation: DraughtingSymbolRepresentation,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY drawing_sheet_revision"]
+    #[doc = "   SUBTYPE OF (presentation_area);"]
+    #[doc = "     revision_identifier : identifier;"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: SIZEOF(QUERY ( item <* SELF\\representation.items | (("]
+    #[doc = "              'EXPLICIT_DRAUGHTING.MAPPED_ITEM' IN TYPEOF(item)) AND ("]
+    #[doc = "              'EXPLICIT_DRAUGHTING.DRAWING_SHEET_REVISION' IN TYPEOF(item\\"]
+    #[doc = "              mapped_item.mapping_source.mapped_representation))) )) = 0;"]
+    #[doc = " END_ENTITY; -- drawing_sheet_revision"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -4625,6 +7292,19 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub revision_identifier: Identifier,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY drawing_sheet_revision_usage"]
+    #[doc = "   SUBTYPE OF (area_in_set);"]
+    #[doc = "     sheet_number : identifier;"]
+    #[doc = "   UNIQUE"]
+    #[doc = "     ur1 : sheet_number, in_set;"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: ('EXPLICIT_DRAUGHTING.DRAWING_SHEET_REVISION' IN TYPEOF(SELF\\"]
+    #[doc = "              area_in_set.area)) AND ("]
+    #[doc = "              'EXPLICIT_DRAUGHTING.DRAWING_REVISION' IN TYPEOF(SELF\\"]
+    #[doc = "              area_in_set.in_set));"]
+    #[doc = " END_ENTITY; -- drawing_sheet_revision_usage"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -4641,6 +7321,13 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub sheet_number: Identifier,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY ellipse"]
+    #[doc = "   SUBTYPE OF (conic);"]
+    #[doc = "     semi_axis_1 : positive_length_measure;"]
+    #[doc = "     semi_axis_2 : positive_length_measure;"]
+    #[doc = " END_ENTITY; -- ellipse"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -4659,6 +7346,11 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub semi_axis_2: PositiveLengthMeasure,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY external_source;"]
+    #[doc = "     source_id : source_item;"]
+    #[doc = " END_ENTITY; -- external_source"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = external_source)]
@@ -4667,6 +7359,11 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub source_id: SourceItem,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY externally_defined_curve_font"]
+    #[doc = "   SUBTYPE OF (externally_defined_item);"]
+    #[doc = " END_ENTITY; -- externally_defined_curve_font"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -4681,6 +7378,11 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub externally_defined_item: ExternallyDefinedItem,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY externally_defined_hatch_style"]
+    #[doc = "   SUBTYPE OF (externally_defined_item, geometric_representation_item);"]
+    #[doc = " END_ENTITY; -- externally_defined_hatch_style"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut)]
     # [holder (table = Tables)]
     # [holder (field = externally_defined_hatch_style)]
@@ -4695,6 +7397,12 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub geometric_representation_item: GeometricRepresentationItem,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY externally_defined_item;"]
+    #[doc = "     item_id : source_item;"]
+    #[doc = "     source  : external_source;"]
+    #[doc = " END_ENTITY; -- externally_defined_item"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = externally_defined_item)]
@@ -4764,6 +7472,11 @@ pub mod explicit_draughting {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY externally_defined_symbol"]
+    #[doc = "   SUBTYPE OF (externally_defined_item);"]
+    #[doc = " END_ENTITY; -- externally_defined_symbol"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -4778,6 +7491,11 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub externally_defined_item: ExternallyDefinedItem,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY externally_defined_text_font"]
+    #[doc = "   SUBTYPE OF (externally_defined_item);"]
+    #[doc = " END_ENTITY; -- externally_defined_text_font"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -4792,6 +7510,11 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub externally_defined_item: ExternallyDefinedItem,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY externally_defined_tile_style"]
+    #[doc = "   SUBTYPE OF (externally_defined_item, geometric_representation_item);"]
+    #[doc = " END_ENTITY; -- externally_defined_tile_style"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut)]
     # [holder (table = Tables)]
     # [holder (field = externally_defined_tile_style)]
@@ -4806,6 +7529,16 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub geometric_representation_item: GeometricRepresentationItem,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY fill_area_style;"]
+    #[doc = "     name        : label;"]
+    #[doc = "     fill_styles : SET [1:?] OF fill_style_select;"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: SIZEOF(QUERY ( fill_style <* SELF.fill_styles | (("]
+    #[doc = "              'EXPLICIT_DRAUGHTING.' + 'FILL_AREA_STYLE_COLOUR') IN "]
+    #[doc = "              TYPEOF(fill_style)) )) <= 1;"]
+    #[doc = " END_ENTITY; -- fill_area_style"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = fill_area_style)]
@@ -4814,8 +7547,15 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub name: Label,
         #[holder(use_place_holder)]
+        # [holder (size = 1 ..)]
         pub fill_styles: Vec<FillStyleSelect>,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY fill_area_style_colour;"]
+    #[doc = "     name        : label;"]
+    #[doc = "     fill_colour : colour;"]
+    #[doc = " END_ENTITY; -- fill_area_style_colour"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = fill_area_style_colour)]
@@ -4826,6 +7566,16 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub fill_colour: ColourAny,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY fill_area_style_hatching"]
+    #[doc = "   SUBTYPE OF (geometric_representation_item);"]
+    #[doc = "     hatch_line_appearance         : curve_style;"]
+    #[doc = "     start_of_next_hatch_line      : one_direction_repeat_factor;"]
+    #[doc = "     point_of_reference_hatch_line : cartesian_point;"]
+    #[doc = "     pattern_start                 : cartesian_point;"]
+    #[doc = "     hatch_line_angle              : plane_angle_measure;"]
+    #[doc = " END_ENTITY; -- fill_area_style_hatching"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -4850,6 +7600,12 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub hatch_line_angle: PlaneAngleMeasure,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY fill_area_style_tile_symbol_with_style"]
+    #[doc = "   SUBTYPE OF (geometric_representation_item);"]
+    #[doc = "     symbol : annotation_symbol_occurrence;"]
+    #[doc = " END_ENTITY; -- fill_area_style_tile_symbol_with_style"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -4866,6 +7622,14 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub symbol: AnnotationSymbolOccurrenceAny,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY fill_area_style_tiles"]
+    #[doc = "   SUBTYPE OF (geometric_representation_item);"]
+    #[doc = "     tiling_pattern : two_direction_repeat_factor;"]
+    #[doc = "     tiles          : SET [1:?] OF fill_area_style_tile_shape_select;"]
+    #[doc = "     tiling_scale   : positive_ratio_measure;"]
+    #[doc = " END_ENTITY; -- fill_area_style_tiles"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -4882,10 +7646,19 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub tiling_pattern: TwoDirectionRepeatFactor,
         #[holder(use_place_holder)]
+        # [holder (size = 1 ..)]
         pub tiles: Vec<FillAreaStyleTileShapeSelect>,
         #[holder(use_place_holder)]
         pub tiling_scale: PositiveRatioMeasure,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY geometric_curve_set"]
+    #[doc = "   SUBTYPE OF (geometric_set);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: SIZEOF(QUERY ( temp <* SELF\\geometric_set.elements | ("]
+    #[doc = "              'EXPLICIT_DRAUGHTING.SURFACE' IN TYPEOF(temp)) )) = 0;"]
+    #[doc = " END_ENTITY; -- geometric_curve_set"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -4900,6 +7673,12 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub geometric_set: GeometricSet,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY geometric_representation_context"]
+    #[doc = "   SUBTYPE OF (representation_context);"]
+    #[doc = "     coordinate_space_dimension : dimension_count;"]
+    #[doc = " END_ENTITY; -- geometric_representation_context"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -4916,6 +7695,19 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub coordinate_space_dimension: DimensionCount,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY geometric_representation_item"]
+    #[doc = "   SUPERTYPE OF (ONEOF (point,direction,vector,placement,curve,"]
+    #[doc = "       geometric_set))"]
+    #[doc = "   SUBTYPE OF (representation_item);"]
+    #[doc = "   DERIVE"]
+    #[doc = "     dim : dimension_count := dimension_of(SELF);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: SIZEOF(QUERY ( using_rep <* using_representations(SELF) | (NOT "]
+    #[doc = "              ('EXPLICIT_DRAUGHTING.GEOMETRIC_REPRESENTATION_CONTEXT' IN "]
+    #[doc = "              TYPEOF(using_rep.context_of_items))) )) = 0;"]
+    #[doc = " END_ENTITY; -- geometric_representation_item"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -5180,6 +7972,13 @@ pub mod explicit_draughting {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY geometric_set"]
+    #[doc = "   SUPERTYPE OF (geometric_curve_set)"]
+    #[doc = "   SUBTYPE OF (geometric_representation_item);"]
+    #[doc = "     elements : SET [1:?] OF geometric_set_select;"]
+    #[doc = " END_ENTITY; -- geometric_set"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -5194,6 +7993,7 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub geometric_representation_item: GeometricRepresentationItem,
         #[holder(use_place_holder)]
+        # [holder (size = 1 ..)]
         pub elements: Vec<GeometricSetSelect>,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
@@ -5233,6 +8033,11 @@ pub mod explicit_draughting {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY geometrical_tolerance_callout"]
+    #[doc = "   SUBTYPE OF (draughting_callout);"]
+    #[doc = " END_ENTITY; -- geometrical_tolerance_callout"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -5247,6 +8052,46 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub draughting_callout: DraughtingCallout,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY geometrically_bounded_2d_wireframe_representation"]
+    #[doc = "   SUBTYPE OF (shape_representation);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: SELF.context_of_items\\geometric_representation_context."]
+    #[doc = "              coordinate_space_dimension = 2;"]
+    #[doc = "     wr2: SIZEOF(QUERY ( item <* SELF\\representation.items | (NOT ("]
+    #[doc = "              SIZEOF(TYPEOF(item) * ["]
+    #[doc = "              'EXPLICIT_DRAUGHTING.GEOMETRIC_CURVE_SET',"]
+    #[doc = "              'EXPLICIT_DRAUGHTING.AXIS2_PLACEMENT_2D',"]
+    #[doc = "              'EXPLICIT_DRAUGHTING.MAPPED_ITEM']) = 1)) )) = 0;"]
+    #[doc = "     wr3: SIZEOF(QUERY ( gcs <* QUERY ( item <* SELF\\representation.items"]
+    #[doc = "               | ('EXPLICIT_DRAUGHTING.GEOMETRIC_CURVE_SET' IN TYPEOF(item)) )"]
+    #[doc = "               | (NOT (SIZEOF(QUERY ( elem <* gcs\\geometric_set.elements"]
+    #[doc = "               | (NOT (SIZEOF(TYPEOF(elem) * ['EXPLICIT_DRAUGHTING.LINE',"]
+    #[doc = "              'EXPLICIT_DRAUGHTING.HYPERBOLA',"]
+    #[doc = "              'EXPLICIT_DRAUGHTING.PARABOLA']) = 0)) )) = 0)) )) = 0;"]
+    #[doc = "     wr4: SIZEOF(QUERY ( mi <* QUERY ( item <* SELF\\representation.items"]
+    #[doc = "               | ('EXPLICIT_DRAUGHTING.MAPPED_ITEM' IN TYPEOF(item)) ) | ("]
+    #[doc = "              NOT ('EXPLICIT_DRAUGHTING.GEOMETRICALLY_BOUNDED_2D_WIREFRAME_REPRESENTATION' "]
+    #[doc = "              IN TYPEOF(mi\\mapped_item.mapping_source."]
+    #[doc = "              mapped_representation))) )) = 0;"]
+    #[doc = "     wr5: SIZEOF(QUERY ( gcs <* QUERY ( item <* SELF\\representation.items"]
+    #[doc = "               | ('EXPLICIT_DRAUGHTING.GEOMETRIC_CURVE_SET' IN TYPEOF(item)) )"]
+    #[doc = "               | (NOT (SIZEOF(QUERY ( pl <* QUERY ( elem <* gcs\\"]
+    #[doc = "              geometric_set.elements | ('EXPLICIT_DRAUGHTING.POLYLINE' IN "]
+    #[doc = "              TYPEOF(elem)) ) | (NOT (SIZEOF(pl\\polyline.points) > 2)) )) "]
+    #[doc = "              = 0)) )) = 0;"]
+    #[doc = "     wr6: SIZEOF(QUERY ( item <* SELF\\representation.items | (SIZEOF("]
+    #[doc = "              TYPEOF(item) * ['EXPLICIT_DRAUGHTING.GEOMETRIC_CURVE_SET',"]
+    #[doc = "              'EXPLICIT_DRAUGHTING.MAPPED_ITEM']) = 1) )) >= 1;"]
+    #[doc = "     wr7: SIZEOF(QUERY ( gcs <* QUERY ( item <* SELF.items | ("]
+    #[doc = "              'EXPLICIT_DRAUGHTING.GEOMETRIC_CURVE_SET' IN TYPEOF(item)) )"]
+    #[doc = "               | (NOT (SIZEOF(QUERY ( pnt <* QUERY ( elem <* gcs\\"]
+    #[doc = "              geometric_set.elements | ('EXPLICIT_DRAUGHTING.POINT' IN "]
+    #[doc = "              TYPEOF(elem)) ) | (NOT (SIZEOF(TYPEOF(pnt) * ["]
+    #[doc = "              'EXPLICIT_DRAUGHTING.CARTESIAN_POINT',"]
+    #[doc = "              'EXPLICIT_DRAUGHTING.POINT_ON_CURVE']) = 1)) )) = 0)) )) = 0;"]
+    #[doc = " END_ENTITY; -- geometrically_bounded_2d_wireframe_representation"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -5261,6 +8106,12 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub shape_representation: ShapeRepresentation,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY global_unit_assigned_context"]
+    #[doc = "   SUBTYPE OF (representation_context);"]
+    #[doc = "     units : SET [1:?] OF unit;"]
+    #[doc = " END_ENTITY; -- global_unit_assigned_context"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -5275,8 +8126,15 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub representation_context: RepresentationContext,
         #[holder(use_place_holder)]
+        # [holder (size = 1 ..)]
         pub units: Vec<Unit>,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY group;"]
+    #[doc = "     name        : label;"]
+    #[doc = "     description : text;"]
+    #[doc = " END_ENTITY; -- group"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = group)]
@@ -5287,6 +8145,12 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub description: Text,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY group_assignment"]
+    #[doc = "   ABSTRACT SUPERTYPE;"]
+    #[doc = "     assigned_group : group;"]
+    #[doc = " END_ENTITY; -- group_assignment"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = group_assignment)]
@@ -5322,6 +8186,14 @@ pub mod explicit_draughting {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY group_relationship;"]
+    #[doc = "     name           : label;"]
+    #[doc = "     description    : text;"]
+    #[doc = "     relating_group : group;"]
+    #[doc = "     related_group  : group;"]
+    #[doc = " END_ENTITY; -- group_relationship"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = group_relationship)]
@@ -5336,6 +8208,13 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub related_group: Group,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY hyperbola"]
+    #[doc = "   SUBTYPE OF (conic);"]
+    #[doc = "     semi_axis      : positive_length_measure;"]
+    #[doc = "     semi_imag_axis : positive_length_measure;"]
+    #[doc = " END_ENTITY; -- hyperbola"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -5354,12 +8233,18 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub semi_imag_axis: PositiveLengthMeasure,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY invisibility;"]
+    #[doc = "     invisible_items : SET [1:?] OF invisible_item;"]
+    #[doc = " END_ENTITY; -- invisibility"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = invisibility)]
     #[holder(generate_deserialize)]
     pub struct Invisibility {
         #[holder(use_place_holder)]
+        # [holder (size = 1 ..)]
         pub invisible_items: Vec<InvisibleItem>,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
@@ -5389,6 +8274,14 @@ pub mod explicit_draughting {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY leader_curve"]
+    #[doc = "   SUBTYPE OF (annotation_curve_occurrence);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: SIZEOF(USEDIN(SELF,'EXPLICIT_DRAUGHTING.' + "]
+    #[doc = "              'LEADER_DIRECTED_CALLOUT.CONTENTS')) >= 1;"]
+    #[doc = " END_ENTITY; -- leader_curve"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -5403,6 +8296,15 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub annotation_curve_occurrence: AnnotationCurveOccurrence,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY leader_directed_callout"]
+    #[doc = "   SUBTYPE OF (draughting_callout);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: SIZEOF(QUERY ( l_1 <* SELF\\draughting_callout.contents | ("]
+    #[doc = "              'EXPLICIT_DRAUGHTING.LEADER_CURVE' IN TYPEOF(l_1)) )) >= 1;"]
+    #[doc = "     wr2: SIZEOF(SELF\\draughting_callout.contents) >= 2;"]
+    #[doc = " END_ENTITY; -- leader_directed_callout"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -5456,6 +8358,14 @@ pub mod explicit_draughting {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY leader_directed_dimension"]
+    #[doc = "   SUBTYPE OF (leader_directed_callout);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: SIZEOF(QUERY ( con <* SELF.contents | ("]
+    #[doc = "              'EXPLICIT_DRAUGHTING.LEADER_CURVE' IN TYPEOF(con)) )) = 1;"]
+    #[doc = " END_ENTITY; -- leader_directed_dimension"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -5470,6 +8380,14 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub leader_directed_callout: LeaderDirectedCallout,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY leader_terminator"]
+    #[doc = "   SUBTYPE OF (terminator_symbol);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: 'EXPLICIT_DRAUGHTING.LEADER_CURVE' IN TYPEOF(SELF\\"]
+    #[doc = "              terminator_symbol.annotated_curve);"]
+    #[doc = " END_ENTITY; -- leader_terminator"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -5484,6 +8402,14 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub terminator_symbol: TerminatorSymbol,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY length_measure_with_unit"]
+    #[doc = "   SUBTYPE OF (measure_with_unit);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: 'EXPLICIT_DRAUGHTING.LENGTH_UNIT' IN TYPEOF(SELF\\"]
+    #[doc = "              measure_with_unit.unit_component);"]
+    #[doc = " END_ENTITY; -- length_measure_with_unit"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -5498,6 +8424,20 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub measure_with_unit: MeasureWithUnit,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY length_unit"]
+    #[doc = "   SUBTYPE OF (named_unit);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: (SELF\\named_unit.dimensions.length_exponent = 1) AND (SELF\\"]
+    #[doc = "              named_unit.dimensions.mass_exponent = 0) AND (SELF\\"]
+    #[doc = "              named_unit.dimensions.time_exponent = 0) AND (SELF\\"]
+    #[doc = "              named_unit.dimensions.electric_current_exponent = 0) AND ("]
+    #[doc = "              SELF\\named_unit.dimensions."]
+    #[doc = "              thermodynamic_temperature_exponent = 0) AND (SELF\\named_unit"]
+    #[doc = "              .dimensions.amount_of_substance_exponent = 0) AND (SELF\\"]
+    #[doc = "              named_unit.dimensions.luminous_intensity_exponent = 0);"]
+    #[doc = " END_ENTITY; -- length_unit"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -5512,6 +8452,15 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub named_unit: NamedUnit,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY line"]
+    #[doc = "   SUBTYPE OF (curve);"]
+    #[doc = "     pnt : cartesian_point;"]
+    #[doc = "     dir : vector;"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: dir.dim = pnt.dim;"]
+    #[doc = " END_ENTITY; -- line"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -5530,6 +8479,11 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub dir: Vector,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY linear_dimension"]
+    #[doc = "   SUBTYPE OF (dimension_curve_directed_callout);"]
+    #[doc = " END_ENTITY; -- linear_dimension"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -5544,6 +8498,15 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub dimension_curve_directed_callout: DimensionCurveDirectedCallout,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY mapped_item"]
+    #[doc = "   SUBTYPE OF (representation_item);"]
+    #[doc = "     mapping_source : representation_map;"]
+    #[doc = "     mapping_target : representation_item;"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: acyclic_mapped_representation(using_representations(SELF),[SELF]);"]
+    #[doc = " END_ENTITY; -- mapped_item"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -5619,6 +8582,16 @@ pub mod explicit_draughting {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY measure_with_unit"]
+    #[doc = "   SUPERTYPE OF (ONEOF (length_measure_with_unit,"]
+    #[doc = "       plane_angle_measure_with_unit));"]
+    #[doc = "     value_component : measure_value;"]
+    #[doc = "     unit_component  : unit;"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: valid_units(SELF);"]
+    #[doc = " END_ENTITY; -- measure_with_unit"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = measure_with_unit)]
@@ -5664,13 +8637,21 @@ pub mod explicit_draughting {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY named_unit"]
+    #[doc = "   SUPERTYPE OF (ONEOF (si_unit,conversion_based_unit) ANDOR ONEOF ("]
+    #[doc = "       length_unit,plane_angle_unit));"]
+    #[doc = "     dimensions : dimensional_exponents;"]
+    #[doc = " END_ENTITY; -- named_unit"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = named_unit)]
     #[holder(generate_deserialize)]
     pub struct NamedUnit {
+        #[doc = " `None` in instances of a subtype redeclaring this attribute as derived"]
         #[holder(use_place_holder)]
-        pub dimensions: DimensionalExponents,
+        pub dimensions: Option<DimensionalExponents>,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
@@ -5723,6 +8704,16 @@ pub mod explicit_draughting {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY offset_curve_2d"]
+    #[doc = "   SUBTYPE OF (curve);"]
+    #[doc = "     basis_curve    : curve;"]
+    #[doc = "     distance       : length_measure;"]
+    #[doc = "     self_intersect : LOGICAL;"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: basis_curve.dim = 2;"]
+    #[doc = " END_ENTITY; -- offset_curve_2d"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -5742,6 +8733,12 @@ pub mod explicit_draughting {
         pub distance: LengthMeasure,
         pub self_intersect: Logical,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY one_direction_repeat_factor"]
+    #[doc = "   SUBTYPE OF (geometric_representation_item);"]
+    #[doc = "     repeat_factor : vector;"]
+    #[doc = " END_ENTITY; -- one_direction_repeat_factor"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -5797,6 +8794,11 @@ pub mod explicit_draughting {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY ordinate_dimension"]
+    #[doc = "   SUBTYPE OF (projection_directed_callout);"]
+    #[doc = " END_ENTITY; -- ordinate_dimension"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -5811,6 +8813,13 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub projection_directed_callout: ProjectionDirectedCallout,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY organization;"]
+    #[doc = "     id          : OPTIONAL identifier;"]
+    #[doc = "     name        : label;"]
+    #[doc = "     description : text;"]
+    #[doc = " END_ENTITY; -- organization"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = organization)]
@@ -5823,6 +8832,13 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub description: Text,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY organization_assignment"]
+    #[doc = "   ABSTRACT SUPERTYPE;"]
+    #[doc = "     assigned_organization : organization;"]
+    #[doc = "     role                  : organization_role;"]
+    #[doc = " END_ENTITY; -- organization_assignment"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = organization_assignment)]
@@ -5860,6 +8876,11 @@ pub mod explicit_draughting {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY organization_role;"]
+    #[doc = "     name : label;"]
+    #[doc = " END_ENTITY; -- organization_role"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = organization_role)]
@@ -5868,6 +8889,13 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub name: Label,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY organizational_address"]
+    #[doc = "   SUBTYPE OF (address);"]
+    #[doc = "     organizations : SET [1:?] OF organization;"]
+    #[doc = "     description   : text;"]
+    #[doc = " END_ENTITY; -- organizational_address"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -5882,10 +8910,19 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub address: Address,
         #[holder(use_place_holder)]
+        # [holder (size = 1 ..)]
         pub organizations: Vec<Organization>,
         #[holder(use_place_holder)]
         pub description: Text,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY parabola"]
+    #[doc = "   SUBTYPE OF (conic);"]
+    #[doc = "     focal_dist : length_measure;"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: focal_dist <> 0;"]
+    #[doc = " END_ENTITY; -- parabola"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -5902,6 +8939,20 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub focal_dist: LengthMeasure,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY person;"]
+    #[doc = "     id            : identifier;"]
+    #[doc = "     last_name     : OPTIONAL label;"]
+    #[doc = "     first_name    : OPTIONAL label;"]
+    #[doc = "     middle_names  : OPTIONAL LIST [1:?] OF label;"]
+    #[doc = "     prefix_titles : OPTIONAL LIST [1:?] OF label;"]
+    #[doc = "     suffix_titles : OPTIONAL LIST [1:?] OF label;"]
+    #[doc = "   UNIQUE"]
+    #[doc = "     ur1 : id;"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: EXISTS(last_name) OR EXISTS(first_name);"]
+    #[doc = " END_ENTITY; -- person"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = person)]
@@ -5914,12 +8965,21 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub first_name: Option<Label>,
         #[holder(use_place_holder)]
+        # [holder (size = 1 ..)]
         pub middle_names: Option<Vec<Label>>,
         #[holder(use_place_holder)]
+        # [holder (size = 1 ..)]
         pub prefix_titles: Option<Vec<Label>>,
         #[holder(use_place_holder)]
+        # [holder (size = 1 ..)]
         pub suffix_titles: Option<Vec<Label>>,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY person_and_organization;"]
+    #[doc = "     the_person       : person;"]
+    #[doc = "     the_organization : organization;"]
+    #[doc = " END_ENTITY; -- person_and_organization"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = person_and_organization)]
@@ -5930,6 +8990,13 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub the_organization: Organization,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY person_and_organization_assignment"]
+    #[doc = "   ABSTRACT SUPERTYPE;"]
+    #[doc = "     assigned_person_and_organization : person_and_organization;"]
+    #[doc = "     role                             : person_and_organization_role;"]
+    #[doc = " END_ENTITY; -- person_and_organization_assignment"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = person_and_organization_assignment)]
@@ -5973,6 +9040,11 @@ pub mod explicit_draughting {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY person_and_organization_role;"]
+    #[doc = "     name : label;"]
+    #[doc = " END_ENTITY; -- person_and_organization_role"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = person_and_organization_role)]
@@ -5981,6 +9053,13 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub name: Label,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY person_assignment"]
+    #[doc = "   ABSTRACT SUPERTYPE;"]
+    #[doc = "     assigned_person : person;"]
+    #[doc = "     role            : person_role;"]
+    #[doc = " END_ENTITY; -- person_assignment"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = person_assignment)]
@@ -6018,6 +9097,11 @@ pub mod explicit_draughting {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY person_role;"]
+    #[doc = "     name : label;"]
+    #[doc = " END_ENTITY; -- person_role"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = person_role)]
@@ -6026,6 +9110,13 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub name: Label,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY personal_address"]
+    #[doc = "   SUBTYPE OF (address);"]
+    #[doc = "     people      : SET [1:?] OF person;"]
+    #[doc = "     description : text;"]
+    #[doc = " END_ENTITY; -- personal_address"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -6040,10 +9131,18 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub address: Address,
         #[holder(use_place_holder)]
+        # [holder (size = 1 ..)]
         pub people: Vec<Person>,
         #[holder(use_place_holder)]
         pub description: Text,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY placement"]
+    #[doc = "   SUPERTYPE OF (axis2_placement_2d)"]
+    #[doc = "   SUBTYPE OF (geometric_representation_item);"]
+    #[doc = "     location : cartesian_point;"]
+    #[doc = " END_ENTITY; -- placement"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -6097,6 +9196,12 @@ pub mod explicit_draughting {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY planar_box"]
+    #[doc = "   SUBTYPE OF (planar_extent);"]
+    #[doc = "     placement : axis2_placement;"]
+    #[doc = " END_ENTITY; -- planar_box"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -6113,6 +9218,13 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub placement: Axis2Placement,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY planar_extent"]
+    #[doc = "   SUBTYPE OF (geometric_representation_item);"]
+    #[doc = "     size_in_x : length_measure;"]
+    #[doc = "     size_in_y : length_measure;"]
+    #[doc = " END_ENTITY; -- planar_extent"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -6166,6 +9278,14 @@ pub mod explicit_draughting {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY plane_angle_measure_with_unit"]
+    #[doc = "   SUBTYPE OF (measure_with_unit);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: 'EXPLICIT_DRAUGHTING.PLANE_ANGLE_UNIT' IN TYPEOF(SELF\\"]
+    #[doc = "              measure_with_unit.unit_component);"]
+    #[doc = " END_ENTITY; -- plane_angle_measure_with_unit"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -6180,6 +9300,20 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub measure_with_unit: MeasureWithUnit,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY plane_angle_unit"]
+    #[doc = "   SUBTYPE OF (named_unit);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: (SELF\\named_unit.dimensions.length_exponent = 0) AND (SELF\\"]
+    #[doc = "              named_unit.dimensions.mass_exponent = 0) AND (SELF\\"]
+    #[doc = "              named_unit.dimensions.time_exponent = 0) AND (SELF\\"]
+    #[doc = "              named_unit.dimensions.electric_current_exponent = 0) AND ("]
+    #[doc = "              SELF\\named_unit.dimensions."]
+    #[doc = "              thermodynamic_temperature_exponent = 0) AND (SELF\\named_unit"]
+    #[doc = "              .dimensions.amount_of_substance_exponent = 0) AND (SELF\\"]
+    #[doc = "              named_unit.dimensions.luminous_intensity_exponent = 0);"]
+    #[doc = " END_ENTITY; -- plane_angle_unit"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -6194,6 +9328,12 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub named_unit: NamedUnit,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY point"]
+    #[doc = "   SUPERTYPE OF (ONEOF (cartesian_point,point_on_curve))"]
+    #[doc = "   SUBTYPE OF (geometric_representation_item);"]
+    #[doc = " END_ENTITY; -- point"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -6252,6 +9392,13 @@ pub mod explicit_draughting {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY point_on_curve"]
+    #[doc = "   SUBTYPE OF (point);"]
+    #[doc = "     basis_curve     : curve;"]
+    #[doc = "     point_parameter : parameter_value;"]
+    #[doc = " END_ENTITY; -- point_on_curve"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -6270,6 +9417,12 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub point_parameter: ParameterValue,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY polyline"]
+    #[doc = "   SUBTYPE OF (bounded_curve);"]
+    #[doc = "     points : LIST [2:?] OF cartesian_point;"]
+    #[doc = " END_ENTITY; -- polyline"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -6284,8 +9437,14 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub bounded_curve: BoundedCurve,
         #[holder(use_place_holder)]
+        # [holder (size = 2 ..)]
         pub points: Vec<CartesianPoint>,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY pre_defined_colour"]
+    #[doc = "   SUBTYPE OF (pre_defined_item, colour);"]
+    #[doc = " END_ENTITY; -- pre_defined_colour"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut)]
     # [holder (table = Tables)]
     # [holder (field = pre_defined_colour)]
@@ -6351,6 +9510,11 @@ pub mod explicit_draughting {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY pre_defined_curve_font"]
+    #[doc = "   SUBTYPE OF (pre_defined_item);"]
+    #[doc = " END_ENTITY; -- pre_defined_curve_font"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -6404,6 +9568,15 @@ pub mod explicit_draughting {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY pre_defined_dimension_symbol"]
+    #[doc = "   SUBTYPE OF (pre_defined_symbol);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: SELF.name IN ['arc length','conical taper','counterbore',"]
+    #[doc = "              'countersink','depth','diameter','plus minus','radius',"]
+    #[doc = "              'slope','spherical diameter','spherical radius','square'];"]
+    #[doc = " END_ENTITY; -- pre_defined_dimension_symbol"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -6418,6 +9591,19 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub pre_defined_symbol: PreDefinedSymbol,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY pre_defined_geometrical_tolerance_symbol"]
+    #[doc = "   SUBTYPE OF (pre_defined_symbol);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: SELF.name IN ['angularity','circular runout','circularity',"]
+    #[doc = "              'concentricity','cylindricity','diameter','flatness',"]
+    #[doc = "              'least material condition','maximum material condition',"]
+    #[doc = "              'parallelism','perpendicularity','position',"]
+    #[doc = "              'profile of a line','profile of a surface',"]
+    #[doc = "              'projected tolerance zone','regardless of feature size',"]
+    #[doc = "              'straightness','symmetry','target point','total runout'];"]
+    #[doc = " END_ENTITY; -- pre_defined_geometrical_tolerance_symbol"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -6432,6 +9618,11 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub pre_defined_symbol: PreDefinedSymbol,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY pre_defined_item;"]
+    #[doc = "     name : label;"]
+    #[doc = " END_ENTITY; -- pre_defined_item"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = pre_defined_item)]
@@ -6491,6 +9682,14 @@ pub mod explicit_draughting {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY pre_defined_point_marker_symbol"]
+    #[doc = "   SUBTYPE OF (pre_defined_symbol);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: SELF.name IN ['asterisk','circle','dot','plus','square',"]
+    #[doc = "              'triangle','x'];"]
+    #[doc = " END_ENTITY; -- pre_defined_point_marker_symbol"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -6505,6 +9704,11 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub pre_defined_symbol: PreDefinedSymbol,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY pre_defined_symbol"]
+    #[doc = "   SUBTYPE OF (pre_defined_item);"]
+    #[doc = " END_ENTITY; -- pre_defined_symbol"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -6591,6 +9795,15 @@ pub mod explicit_draughting {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY pre_defined_terminator_symbol"]
+    #[doc = "   SUBTYPE OF (pre_defined_symbol);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: SELF.name IN ['blanked arrow','blanked box','blanked dot',"]
+    #[doc = "              'dimension origin','filled arrow','filled box','filled dot',"]
+    #[doc = "              'integral symbol','open arrow','slash','unfilled arrow'];"]
+    #[doc = " END_ENTITY; -- pre_defined_terminator_symbol"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -6605,6 +9818,11 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub pre_defined_symbol: PreDefinedSymbol,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY pre_defined_text_font"]
+    #[doc = "   SUBTYPE OF (pre_defined_item);"]
+    #[doc = " END_ENTITY; -- pre_defined_text_font"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -6658,6 +9876,17 @@ pub mod explicit_draughting {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY presentation_area"]
+    #[doc = "   SUBTYPE OF (presentation_representation);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: (SIZEOF(QUERY ( ais <* USEDIN(SELF,'EXPLICIT_DRAUGHTING.' + "]
+    #[doc = "              'AREA_IN_SET.AREA') | (SIZEOF(USEDIN(ais,"]
+    #[doc = "              'EXPLICIT_DRAUGHTING.' + 'PRESENTATION_SIZE.UNIT')) = 1) )) "]
+    #[doc = "              > 0) OR (SIZEOF(USEDIN(SELF,'EXPLICIT_DRAUGHTING.' + "]
+    #[doc = "              'PRESENTATION_SIZE.UNIT')) = 1);"]
+    #[doc = " END_ENTITY; -- presentation_area"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -6711,6 +9940,13 @@ pub mod explicit_draughting {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY presentation_layer_assignment;"]
+    #[doc = "     name           : label;"]
+    #[doc = "     description    : text;"]
+    #[doc = "     assigned_items : SET [1:?] OF layered_item;"]
+    #[doc = " END_ENTITY; -- presentation_layer_assignment"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = presentation_layer_assignment)]
@@ -6721,8 +9957,17 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub description: Text,
         #[holder(use_place_holder)]
+        # [holder (size = 1 ..)]
         pub assigned_items: Vec<LayeredItem>,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY presentation_layer_usage;"]
+    #[doc = "     assignment   : presentation_layer_assignment;"]
+    #[doc = "     presentation : presentation_representation;"]
+    #[doc = "   UNIQUE"]
+    #[doc = "     ur1 : assignment, presentation;"]
+    #[doc = " END_ENTITY; -- presentation_layer_usage"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = presentation_layer_usage)]
@@ -6733,6 +9978,17 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub presentation: PresentationRepresentationAny,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY presentation_representation"]
+    #[doc = "   SUBTYPE OF (representation);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: SELF\\representation.context_of_items\\"]
+    #[doc = "              geometric_representation_context.coordinate_space_dimension "]
+    #[doc = "              = 2;"]
+    #[doc = "     wr2: 'EXPLICIT_DRAUGHTING.GEOMETRIC_REPRESENTATION_CONTEXT' IN "]
+    #[doc = "              TYPEOF(SELF\\representation.context_of_items);"]
+    #[doc = " END_ENTITY; -- presentation_representation"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -6797,6 +10053,12 @@ pub mod explicit_draughting {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY presentation_set;"]
+    #[doc = "   INVERSE"]
+    #[doc = "     areas : SET [1:?] OF area_in_set FOR in_set;"]
+    #[doc = " END_ENTITY; -- presentation_set"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = presentation_set)]
@@ -6829,6 +10091,20 @@ pub mod explicit_draughting {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY presentation_size;"]
+    #[doc = "     unit : presentation_size_assignment_select;"]
+    #[doc = "     size : planar_box;"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: (('EXPLICIT_DRAUGHTING.PRESENTATION_REPRESENTATION' IN TYPEOF("]
+    #[doc = "              SELF.unit)) AND item_in_context(SELF.size,SELF.unit\\"]
+    #[doc = "              representation.context_of_items)) OR (("]
+    #[doc = "              'EXPLICIT_DRAUGHTING.AREA_IN_SET' IN TYPEOF(SELF.unit)) AND "]
+    #[doc = "              (SIZEOF(QUERY ( ais <* SELF.unit\\area_in_set.in_set.areas | "]
+    #[doc = "              (NOT item_in_context(SELF.size,ais.area\\representation."]
+    #[doc = "              context_of_items)) )) = 0));"]
+    #[doc = " END_ENTITY; -- presentation_size"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = presentation_size)]
@@ -6839,12 +10115,28 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub size: PlanarBox,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY presentation_style_assignment;"]
+    #[doc = "     styles : SET [1:?] OF presentation_style_select;"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: SIZEOF(QUERY ( style1 <* SELF.styles | (NOT (SIZEOF("]
+    #[doc = "              QUERY ( style2 <* (SELF.styles - style1) | (NOT ((TYPEOF("]
+    #[doc = "              style1) <> TYPEOF(style2)) OR (SIZEOF(["]
+    #[doc = "              'EXPLICIT_DRAUGHTING.' + 'SURFACE_STYLE_USAGE',"]
+    #[doc = "              'EXPLICIT_DRAUGHTING.' + 'EXTERNALLY_DEFINED_STYLE'] * "]
+    #[doc = "              TYPEOF(style1)) = 1))) )) = 0)) )) = 0;"]
+    #[doc = "     wr2: SIZEOF(QUERY ( style1 <* SELF.styles | ("]
+    #[doc = "              'EXPLICIT_DRAUGHTING.SURFACE_STYLE_USAGE' IN TYPEOF(style1)) )) "]
+    #[doc = "              <= 2;"]
+    #[doc = " END_ENTITY; -- presentation_style_assignment"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = presentation_style_assignment)]
     #[holder(generate_deserialize)]
     pub struct PresentationStyleAssignment {
         #[holder(use_place_holder)]
+        # [holder (size = 1 ..)]
         pub styles: Vec<PresentationStyleSelect>,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
@@ -6874,6 +10166,12 @@ pub mod explicit_draughting {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY presentation_style_by_context"]
+    #[doc = "   SUBTYPE OF (presentation_style_assignment);"]
+    #[doc = "     style_context : style_context_select;"]
+    #[doc = " END_ENTITY; -- presentation_style_by_context"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -6890,6 +10188,11 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub style_context: StyleContextSelect,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY presentation_view"]
+    #[doc = "   SUBTYPE OF (presentation_representation);"]
+    #[doc = " END_ENTITY; -- presentation_view"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -6904,6 +10207,11 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub presentation_representation: PresentationRepresentation,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY presented_item"]
+    #[doc = "   ABSTRACT SUPERTYPE;"]
+    #[doc = " END_ENTITY; -- presented_item"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = presented_item)]
@@ -6936,6 +10244,12 @@ pub mod explicit_draughting {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY presented_item_representation;"]
+    #[doc = "     presentation : presentation_representation_select;"]
+    #[doc = "     item         : presented_item;"]
+    #[doc = " END_ENTITY; -- presented_item_representation"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = presented_item_representation)]
@@ -6946,6 +10260,16 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub item: PresentedItemAny,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY product;"]
+    #[doc = "     id                 : identifier;"]
+    #[doc = "     name               : label;"]
+    #[doc = "     description        : text;"]
+    #[doc = "     frame_of_reference : SET [1:?] OF product_context;"]
+    #[doc = "   UNIQUE"]
+    #[doc = "     ur1 : id;"]
+    #[doc = " END_ENTITY; -- product"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = product)]
@@ -6958,8 +10282,15 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub description: Text,
         #[holder(use_place_holder)]
+        # [holder (size = 1 ..)]
         pub frame_of_reference: Vec<ProductContext>,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY product_context"]
+    #[doc = "   SUBTYPE OF (application_context_element);"]
+    #[doc = "     discipline_type : label;"]
+    #[doc = " END_ENTITY; -- product_context"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -6976,6 +10307,14 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub discipline_type: Label,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY product_definition;"]
+    #[doc = "     id                 : identifier;"]
+    #[doc = "     description        : text;"]
+    #[doc = "     formation          : product_definition_formation;"]
+    #[doc = "     frame_of_reference : product_definition_context;"]
+    #[doc = " END_ENTITY; -- product_definition"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = product_definition)]
@@ -6990,6 +10329,12 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub frame_of_reference: ProductDefinitionContext,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY product_definition_context"]
+    #[doc = "   SUBTYPE OF (application_context_element);"]
+    #[doc = "     life_cycle_stage : label;"]
+    #[doc = " END_ENTITY; -- product_definition_context"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -7006,6 +10351,15 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub life_cycle_stage: Label,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY product_definition_formation;"]
+    #[doc = "     id          : identifier;"]
+    #[doc = "     description : text;"]
+    #[doc = "     of_product  : product;"]
+    #[doc = "   UNIQUE"]
+    #[doc = "     ur1 : id, of_product;"]
+    #[doc = " END_ENTITY; -- product_definition_formation"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = product_definition_formation)]
@@ -7018,6 +10372,16 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub of_product: Product,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY product_definition_shape"]
+    #[doc = "   SUBTYPE OF (property_definition);"]
+    #[doc = "   UNIQUE"]
+    #[doc = "     ur1 : definition;"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: 'EXPLICIT_DRAUGHTING.CHARACTERIZED_PRODUCT_DEFINITION' IN "]
+    #[doc = "              TYPEOF(SELF\\property_definition.definition);"]
+    #[doc = " END_ENTITY; -- product_definition_shape"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -7032,6 +10396,11 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub property_definition: PropertyDefinition,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY projection_curve"]
+    #[doc = "   SUBTYPE OF (annotation_curve_occurrence);"]
+    #[doc = " END_ENTITY; -- projection_curve"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -7046,6 +10415,16 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub annotation_curve_occurrence: AnnotationCurveOccurrence,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY projection_directed_callout"]
+    #[doc = "   SUBTYPE OF (draughting_callout);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: SIZEOF(QUERY ( p_1 <* SELF\\draughting_callout.contents | ("]
+    #[doc = "              'EXPLICIT_DRAUGHTING.PROJECTION_CURVE' IN TYPEOF(p_1)) )) = "]
+    #[doc = "              1;"]
+    #[doc = "     wr2: SIZEOF(SELF\\draughting_callout.contents) >= 2;"]
+    #[doc = " END_ENTITY; -- projection_directed_callout"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -7099,6 +10478,13 @@ pub mod explicit_draughting {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY property_definition;"]
+    #[doc = "     name        : label;"]
+    #[doc = "     description : text;"]
+    #[doc = "     definition  : characterized_definition;"]
+    #[doc = " END_ENTITY; -- property_definition"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = property_definition)]
@@ -7138,6 +10524,12 @@ pub mod explicit_draughting {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY property_definition_representation;"]
+    #[doc = "     definition          : property_definition;"]
+    #[doc = "     used_representation : representation;"]
+    #[doc = " END_ENTITY; -- property_definition_representation"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = property_definition_representation)]
@@ -7181,6 +10573,11 @@ pub mod explicit_draughting {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY quasi_uniform_curve"]
+    #[doc = "   SUBTYPE OF (b_spline_curve);"]
+    #[doc = " END_ENTITY; -- quasi_uniform_curve"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -7195,6 +10592,15 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub b_spline_curve: BSplineCurve,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY radius_dimension"]
+    #[doc = "   SUBTYPE OF (dimension_curve_directed_callout);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: SIZEOF(QUERY ( con <* SELF.contents | ("]
+    #[doc = "              'EXPLICIT_DRAUGHTING.PROJECTION_CURVE' IN TYPEOF(con)) )) <="]
+    #[doc = "               1;"]
+    #[doc = " END_ENTITY; -- radius_dimension"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -7209,6 +10615,20 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub dimension_curve_directed_callout: DimensionCurveDirectedCallout,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY rational_b_spline_curve"]
+    #[doc = "   SUBTYPE OF (b_spline_curve);"]
+    #[doc = "     weights_data : LIST [2:?] OF REAL;"]
+    #[doc = "   DERIVE"]
+    #[doc = "     weights : ARRAY [0:upper_index_on_control_points] OF REAL := "]
+    #[doc = "                  list_to_array(weights_data,0,"]
+    #[doc = "                  upper_index_on_control_points);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: SIZEOF(weights_data) = SIZEOF(SELF\\b_spline_curve."]
+    #[doc = "              control_points_list);"]
+    #[doc = "     wr2: curve_weights_positive(SELF);"]
+    #[doc = " END_ENTITY; -- rational_b_spline_curve"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -7222,8 +10642,16 @@ pub mod explicit_draughting {
         #[deref_mut]
         #[holder(use_place_holder)]
         pub b_spline_curve: BSplineCurve,
+        # [holder (size = 2 ..)]
         pub weights_data: Vec<f64>,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY representation;"]
+    #[doc = "     name             : label;"]
+    #[doc = "     items            : SET [1:?] OF representation_item;"]
+    #[doc = "     context_of_items : representation_context;"]
+    #[doc = " END_ENTITY; -- representation"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = representation)]
@@ -7232,6 +10660,7 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub name: Label,
         #[holder(use_place_holder)]
+        # [holder (size = 1 ..)]
         pub items: Vec<RepresentationItemAny>,
         #[holder(use_place_holder)]
         pub context_of_items: RepresentationContextAny,
@@ -7287,6 +10716,15 @@ pub mod explicit_draughting {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY representation_context;"]
+    #[doc = "     context_identifier : identifier;"]
+    #[doc = "     context_type       : text;"]
+    #[doc = "   INVERSE"]
+    #[doc = "     representations_in_context : SET [1:?] OF representation FOR "]
+    #[doc = "                                     context_of_items;"]
+    #[doc = " END_ENTITY; -- representation_context"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = representation_context)]
@@ -7332,6 +10770,13 @@ pub mod explicit_draughting {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY representation_item;"]
+    #[doc = "     name : label;"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: SIZEOF(using_representations(SELF)) > 0;"]
+    #[doc = " END_ENTITY; -- representation_item"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = representation_item)]
@@ -7383,6 +10828,17 @@ pub mod explicit_draughting {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY representation_map;"]
+    #[doc = "     mapping_origin        : representation_item;"]
+    #[doc = "     mapped_representation : representation;"]
+    #[doc = "   INVERSE"]
+    #[doc = "     map_usage : SET [1:?] OF mapped_item FOR mapping_source;"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: item_in_context(SELF.mapping_origin,SELF.mapped_representation."]
+    #[doc = "              context_of_items);"]
+    #[doc = " END_ENTITY; -- representation_map"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = representation_map)]
@@ -7428,6 +10884,13 @@ pub mod explicit_draughting {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY security_classification;"]
+    #[doc = "     name           : label;"]
+    #[doc = "     purpose        : text;"]
+    #[doc = "     security_level : security_classification_level;"]
+    #[doc = " END_ENTITY; -- security_classification"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = security_classification)]
@@ -7440,6 +10903,12 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub security_level: SecurityClassificationLevel,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY security_classification_assignment"]
+    #[doc = "   ABSTRACT SUPERTYPE;"]
+    #[doc = "     assigned_security_classification : security_classification;"]
+    #[doc = " END_ENTITY; -- security_classification_assignment"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = security_classification_assignment)]
@@ -7481,6 +10950,11 @@ pub mod explicit_draughting {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY security_classification_level;"]
+    #[doc = "     name : label;"]
+    #[doc = " END_ENTITY; -- security_classification_level"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = security_classification_level)]
@@ -7489,6 +10963,18 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub name: Label,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY shape_definition_representation"]
+    #[doc = "   SUBTYPE OF (property_definition_representation);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: ('EXPLICIT_DRAUGHTING.SHAPE_DEFINITION' IN TYPEOF(SELF."]
+    #[doc = "              definition.definition)) OR ("]
+    #[doc = "              'EXPLICIT_DRAUGHTING.PRODUCT_DEFINITION_SHAPE' IN TYPEOF("]
+    #[doc = "              SELF.definition));"]
+    #[doc = "     wr2: 'EXPLICIT_DRAUGHTING.SHAPE_REPRESENTATION' IN TYPEOF(SELF."]
+    #[doc = "              used_representation);"]
+    #[doc = " END_ENTITY; -- shape_definition_representation"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -7503,6 +10989,11 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub property_definition_representation: PropertyDefinitionRepresentation,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY shape_representation"]
+    #[doc = "   SUBTYPE OF (representation);"]
+    #[doc = " END_ENTITY; -- shape_representation"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -7562,6 +11053,16 @@ pub mod explicit_draughting {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY si_unit"]
+    #[doc = "   SUBTYPE OF (named_unit);"]
+    #[doc = "     prefix : OPTIONAL si_prefix;"]
+    #[doc = "     name   : si_unit_name;"]
+    #[doc = "   DERIVE"]
+    #[doc = "     SELF\\named_unit.dimensions : dimensional_exponents := "]
+    #[doc = "                                     dimensions_for_si_unit(SELF.name);"]
+    #[doc = " END_ENTITY; -- si_unit"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -7578,6 +11079,35 @@ pub mod explicit_draughting {
         pub prefix: Option<SiPrefix>,
         pub name: SiUnitName,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY structured_dimension_callout"]
+    #[doc = "   SUBTYPE OF (draughting_callout);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: SIZEOF(TYPEOF(SELF) * ["]
+    #[doc = "              'EXPLICIT_DRAUGHTING.DATUM_FEATURE_CALLOUT',"]
+    #[doc = "              'EXPLICIT_DRAUGHTING.DATUM_TARGET_CALLOUT',"]
+    #[doc = "              'EXPLICIT_DRAUGHTING.GEOMETRICAL_TOLERANCE_CALLOUT',"]
+    #[doc = "              'EXPLICIT_DRAUGHTING.LEADER_DIRECTED_CALLOUT',"]
+    #[doc = "              'EXPLICIT_DRAUGHTING.PROJECTION_DIRECTED_CALLOUT',"]
+    #[doc = "              'EXPLICIT_DRAUGHTING.DIMENSION_CURVE_DIRECTED_CALLOUT']) = 0;"]
+    #[doc = "     wr2: SIZEOF(QUERY ( ato <* QUERY ( con <* SELF.contents | ("]
+    #[doc = "              'EXPLICIT_DRAUGHTING.ANNOTATION_TEXT_OCCURRENCE' IN TYPEOF("]
+    #[doc = "              con)) ) | (NOT (ato\\representation_item.name IN ["]
+    #[doc = "              'dimension value','tolerance value','unit text'])) )) = 0;"]
+    #[doc = "     wr3: SIZEOF(QUERY ( ato <* QUERY ( con <* SELF.contents | ("]
+    #[doc = "              'EXPLICIT_DRAUGHTING.ANNOTATION_TEXT_OCCURRENCE' IN TYPEOF("]
+    #[doc = "              con)) ) | (ato\\representation_item.name = 'dimension value') )) "]
+    #[doc = "              >= 1;"]
+    #[doc = "     wr4: SIZEOF(QUERY ( dcr <* USEDIN(SELF,'EXPLICIT_DRAUGHTING.' + "]
+    #[doc = "              'DIMENSION_CALLOUT_COMPONENT_RELATIONSHIP.' + "]
+    #[doc = "              'RELATING_DRAUGHTING_CALLOUT') | (dcr.name = 'prefix') )) <="]
+    #[doc = "               1;"]
+    #[doc = "     wr5: SIZEOF(QUERY ( dcr <* USEDIN(SELF,'EXPLICIT_DRAUGHTING.' + "]
+    #[doc = "              'DIMENSION_CALLOUT_COMPONENT_RELATIONSHIP.' + "]
+    #[doc = "              'RELATING_DRAUGHTING_CALLOUT') | (dcr.name = 'suffix') )) <="]
+    #[doc = "               1;"]
+    #[doc = " END_ENTITY; -- structured_dimension_callout"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -7592,6 +11122,18 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub draughting_callout: DraughtingCallout,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY styled_item"]
+    #[doc = "   SUBTYPE OF (representation_item);"]
+    #[doc = "     styles : SET [1:?] OF presentation_style_assignment;"]
+    #[doc = "     item   : representation_item;"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: (SIZEOF(SELF.styles) = 1) XOR (SIZEOF(QUERY ( pres_style <* "]
+    #[doc = "              SELF.styles | (NOT (('EXPLICIT_DRAUGHTING.' + "]
+    #[doc = "              'PRESENTATION_STYLE_BY_CONTEXT') IN TYPEOF(pres_style))) )) "]
+    #[doc = "              = 0);"]
+    #[doc = " END_ENTITY; -- styled_item"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -7606,6 +11148,7 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub representation_item: RepresentationItem,
         #[holder(use_place_holder)]
+        # [holder (size = 1 ..)]
         pub styles: Vec<PresentationStyleAssignmentAny>,
         #[holder(use_place_holder)]
         pub item: RepresentationItemAny,
@@ -7647,6 +11190,11 @@ pub mod explicit_draughting {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY symbol_colour;"]
+    #[doc = "     colour_of_symbol : colour;"]
+    #[doc = " END_ENTITY; -- symbol_colour"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = symbol_colour)]
@@ -7655,6 +11203,11 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub colour_of_symbol: ColourAny,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY symbol_representation"]
+    #[doc = "   SUBTYPE OF (representation);"]
+    #[doc = " END_ENTITY; -- symbol_representation"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -7719,6 +11272,16 @@ pub mod explicit_draughting {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY symbol_representation_map"]
+    #[doc = "   SUBTYPE OF (representation_map);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: 'EXPLICIT_DRAUGHTING.SYMBOL_REPRESENTATION' IN TYPEOF(SELF\\"]
+    #[doc = "              representation_map.mapped_representation);"]
+    #[doc = "     wr2: 'EXPLICIT_DRAUGHTING.AXIS2_PLACEMENT' IN TYPEOF(SELF\\"]
+    #[doc = "              representation_map.mapping_origin);"]
+    #[doc = " END_ENTITY; -- symbol_representation_map"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -7733,6 +11296,12 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub representation_map: RepresentationMap,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY symbol_style;"]
+    #[doc = "     name            : label;"]
+    #[doc = "     style_of_symbol : symbol_style_select;"]
+    #[doc = " END_ENTITY; -- symbol_style"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = symbol_style)]
@@ -7743,6 +11312,14 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub style_of_symbol: SymbolStyleSelect,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY symbol_target"]
+    #[doc = "   SUBTYPE OF (geometric_representation_item);"]
+    #[doc = "     placement : axis2_placement;"]
+    #[doc = "     x_scale   : positive_ratio_measure;"]
+    #[doc = "     y_scale   : positive_ratio_measure;"]
+    #[doc = " END_ENTITY; -- symbol_target"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -7763,6 +11340,12 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub y_scale: PositiveRatioMeasure,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY terminator_symbol"]
+    #[doc = "   SUBTYPE OF (annotation_symbol_occurrence);"]
+    #[doc = "     annotated_curve : annotation_curve_occurrence;"]
+    #[doc = " END_ENTITY; -- terminator_symbol"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -7829,6 +11412,16 @@ pub mod explicit_draughting {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY text_literal"]
+    #[doc = "   SUBTYPE OF (geometric_representation_item);"]
+    #[doc = "     literal   : presentable_text;"]
+    #[doc = "     placement : axis2_placement;"]
+    #[doc = "     alignment : text_alignment;"]
+    #[doc = "     path      : text_path;"]
+    #[doc = "     font      : font_select;"]
+    #[doc = " END_ENTITY; -- text_literal"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -7922,6 +11515,12 @@ pub mod explicit_draughting {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY text_literal_with_associated_curves"]
+    #[doc = "   SUBTYPE OF (text_literal);"]
+    #[doc = "     associated_curves : SET [1:?] OF curve;"]
+    #[doc = " END_ENTITY; -- text_literal_with_associated_curves"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -7936,8 +11535,15 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub text_literal: TextLiteral,
         #[holder(use_place_holder)]
+        # [holder (size = 1 ..)]
         pub associated_curves: Vec<CurveAny>,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY text_literal_with_blanking_box"]
+    #[doc = "   SUBTYPE OF (text_literal);"]
+    #[doc = "     blanking : planar_box;"]
+    #[doc = " END_ENTITY; -- text_literal_with_blanking_box"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -7954,6 +11560,12 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub blanking: PlanarBox,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY text_literal_with_delineation"]
+    #[doc = "   SUBTYPE OF (text_literal);"]
+    #[doc = "     delineation : text_delineation;"]
+    #[doc = " END_ENTITY; -- text_literal_with_delineation"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -8013,6 +11625,12 @@ pub mod explicit_draughting {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY text_literal_with_extent"]
+    #[doc = "   SUBTYPE OF (text_literal);"]
+    #[doc = "     extent : planar_extent;"]
+    #[doc = " END_ENTITY; -- text_literal_with_extent"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -8029,6 +11647,12 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub extent: PlanarExtentAny,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY text_style;"]
+    #[doc = "     name                 : label;"]
+    #[doc = "     character_appearance : character_style_select;"]
+    #[doc = " END_ENTITY; -- text_style"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = text_style)]
@@ -8074,6 +11698,11 @@ pub mod explicit_draughting {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY text_style_for_defined_font;"]
+    #[doc = "     text_colour : colour;"]
+    #[doc = " END_ENTITY; -- text_style_for_defined_font"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = text_style_for_defined_font)]
@@ -8082,6 +11711,16 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub text_colour: ColourAny,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY text_style_with_box_characteristics"]
+    #[doc = "   SUBTYPE OF (text_style);"]
+    #[doc = "     characteristics : SET [1:4] OF box_characteristic_select;"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: SIZEOF(QUERY ( c1 <* SELF.characteristics | (SIZEOF("]
+    #[doc = "              QUERY ( c2 <* (SELF.characteristics - c1) | (TYPEOF(c1) = "]
+    #[doc = "              TYPEOF(c2)) )) > 0) )) = 0;"]
+    #[doc = " END_ENTITY; -- text_style_with_box_characteristics"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -8096,8 +11735,15 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub text_style: TextStyle,
         #[holder(use_place_holder)]
+        # [holder (size = 1 ..= 4)]
         pub characteristics: Vec<BoxCharacteristicSelect>,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY text_style_with_mirror"]
+    #[doc = "   SUBTYPE OF (text_style);"]
+    #[doc = "     mirror_placement : axis2_placement;"]
+    #[doc = " END_ENTITY; -- text_style_with_mirror"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -8114,6 +11760,19 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub mirror_placement: Axis2Placement,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY trimmed_curve"]
+    #[doc = "   SUBTYPE OF (bounded_curve);"]
+    #[doc = "     basis_curve           : curve;"]
+    #[doc = "     trim_1                : SET [1:2] OF trimming_select;"]
+    #[doc = "     trim_2                : SET [1:2] OF trimming_select;"]
+    #[doc = "     sense_agreement       : BOOLEAN;"]
+    #[doc = "     master_representation : trimming_preference;"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: (HIINDEX(trim_1) = 1) XOR (TYPEOF(trim_1[1]) <> TYPEOF(trim_1[2]));"]
+    #[doc = "     wr2: (HIINDEX(trim_2) = 1) XOR (TYPEOF(trim_2[1]) <> TYPEOF(trim_2[2]));"]
+    #[doc = " END_ENTITY; -- trimmed_curve"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -8130,12 +11789,20 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub basis_curve: CurveAny,
         #[holder(use_place_holder)]
+        # [holder (size = 1 ..= 2)]
         pub trim_1: Vec<TrimmingSelect>,
         #[holder(use_place_holder)]
+        # [holder (size = 1 ..= 2)]
         pub trim_2: Vec<TrimmingSelect>,
         pub sense_agreement: bool,
         pub master_representation: TrimmingPreference,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY two_direction_repeat_factor"]
+    #[doc = "   SUBTYPE OF (one_direction_repeat_factor);"]
+    #[doc = "     second_repeat_factor : vector;"]
+    #[doc = " END_ENTITY; -- two_direction_repeat_factor"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -8152,6 +11819,11 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub second_repeat_factor: Vector,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY uniform_curve"]
+    #[doc = "   SUBTYPE OF (b_spline_curve);"]
+    #[doc = " END_ENTITY; -- uniform_curve"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -8166,6 +11838,15 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub b_spline_curve: BSplineCurve,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY vector"]
+    #[doc = "   SUBTYPE OF (geometric_representation_item);"]
+    #[doc = "     orientation : direction;"]
+    #[doc = "     magnitude   : length_measure;"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: magnitude >= 0;"]
+    #[doc = " END_ENTITY; -- vector"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -8184,4 +11865,321 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub magnitude: LengthMeasure,
     }
+    impl Axis2Placement2D {
+        #[doc = " Derived attribute `p`, whose expression is not supported yet and always returns an error"]
+        pub fn p(&self) -> crate::error::Result<Vec<Direction>> {
+            Err(crate::error::Error::UnsupportedDerivedAttribute {
+                entity: "axis2_placement_2d".to_string(),
+                attribute: "p".to_string(),
+            })
+        }
+    }
+    impl BSplineCurve {
+        pub fn upper_index_on_control_points(&self) -> crate::error::Result<i64> {
+            Ok(((self.control_points_list.len() as f64) - 1f64) as i64)
+        }
+        #[doc = " Derived attribute `control_points`, whose expression is not supported yet and always returns an error"]
+        pub fn control_points(&self) -> crate::error::Result<Vec<CartesianPoint>> {
+            Err(crate::error::Error::UnsupportedDerivedAttribute {
+                entity: "b_spline_curve".to_string(),
+                attribute: "control_points".to_string(),
+            })
+        }
+    }
+    impl BSplineCurveWithKnots {
+        pub fn upper_index_on_knots(&self) -> crate::error::Result<i64> {
+            Ok((self.knots.len() as f64) as i64)
+        }
+    }
+    impl CameraImage2DWithScale {
+        #[doc = " Derived attribute `scale`, whose expression is not supported yet and always returns an error"]
+        pub fn scale(&self) -> crate::error::Result<PositiveRatioMeasure> {
+            Err(crate::error::Error::UnsupportedDerivedAttribute {
+                entity: "camera_image_2d_with_scale".to_string(),
+                attribute: "scale".to_string(),
+            })
+        }
+    }
+    impl CompositeCurve {
+        pub fn n_segments(&self) -> crate::error::Result<i64> {
+            Ok((self.segments.len() as f64) as i64)
+        }
+        #[doc = " Derived attribute `closed_curve`, whose expression is not supported yet and always returns an error"]
+        pub fn closed_curve(&self) -> crate::error::Result<Logical> {
+            Err(crate::error::Error::UnsupportedDerivedAttribute {
+                entity: "composite_curve".to_string(),
+                attribute: "closed_curve".to_string(),
+            })
+        }
+    }
+    impl GeometricRepresentationItem {
+        #[doc = " Derived attribute `dim`, whose expression is not supported yet and always returns an error"]
+        pub fn dim(&self) -> crate::error::Result<DimensionCount> {
+            Err(crate::error::Error::UnsupportedDerivedAttribute {
+                entity: "geometric_representation_item".to_string(),
+                attribute: "dim".to_string(),
+            })
+        }
+    }
+    impl RationalBSplineCurve {
+        #[doc = " Derived attribute `weights`, whose expression is not supported yet and always returns an error"]
+        pub fn weights(&self) -> crate::error::Result<Vec<f64>> {
+            Err(crate::error::Error::UnsupportedDerivedAttribute {
+                entity: "rational_b_spline_curve".to_string(),
+                attribute: "weights".to_string(),
+            })
+        }
+    }
+    impl SiUnit {
+        #[doc = " Derived attribute `dimensions`, whose expression is not supported yet and always returns an error"]
+        pub fn dimensions(&self) -> crate::error::Result<DimensionalExponents> {
+            Err(crate::error::Error::UnsupportedDerivedAttribute {
+                entity: "si_unit".to_string(),
+                attribute: "dimensions".to_string(),
+            })
+        }
+    }
+    impl ApplicationContext {
+        pub fn context_elements(&self, id: u64, tables: &Tables) -> Vec<u64> {
+            tables
+                ._inverse_index
+                .lookup("application_context.context_elements", id, || {
+                    let mut index = ::std::collections::HashMap::new();
+                    crate::tables::index_referrers(
+                        &mut index,
+                        &tables.application_context_element,
+                        |holder, ids| {
+                            crate::tables::Refer::referred_ids(&holder.frame_of_reference, ids)
+                        },
+                    );
+                    crate::tables::index_referrers(
+                        &mut index,
+                        &tables.product_context,
+                        |holder, ids| {
+                            if let Some(holder) = Some(holder)
+                                .and_then(|holder| holder.application_context_element.as_owned())
+                            {
+                                crate::tables::Refer::referred_ids(&holder.frame_of_reference, ids)
+                            }
+                        },
+                    );
+                    crate::tables::index_referrers(
+                        &mut index,
+                        &tables.product_definition_context,
+                        |holder, ids| {
+                            if let Some(holder) = Some(holder)
+                                .and_then(|holder| holder.application_context_element.as_owned())
+                            {
+                                crate::tables::Refer::referred_ids(&holder.frame_of_reference, ids)
+                            }
+                        },
+                    );
+                    index
+                })
+        }
+        pub fn check_inverse(&self, id: u64, tables: &Tables) -> crate::error::Result<()> {
+            crate::tables::check_cardinality(
+                "context_elements",
+                id,
+                self.context_elements(id, tables).len(),
+                1u64,
+                None,
+            )?;
+            Ok(())
+        }
+    }
+    impl CompositeCurveSegment {
+        pub fn using_curves(&self, id: u64, tables: &Tables) -> Vec<u64> {
+            tables
+                ._inverse_index
+                .lookup("composite_curve_segment.using_curves", id, || {
+                    let mut index = ::std::collections::HashMap::new();
+                    crate::tables::index_referrers(
+                        &mut index,
+                        &tables.composite_curve,
+                        |holder, ids| crate::tables::Refer::referred_ids(&holder.segments, ids),
+                    );
+                    index
+                })
+        }
+        pub fn check_inverse(&self, id: u64, tables: &Tables) -> crate::error::Result<()> {
+            crate::tables::check_cardinality(
+                "using_curves",
+                id,
+                self.using_curves(id, tables).len(),
+                1u64,
+                None,
+            )?;
+            Ok(())
+        }
+    }
+    impl PresentationSet {
+        pub fn areas(&self, id: u64, tables: &Tables) -> Vec<u64> {
+            tables
+                ._inverse_index
+                .lookup("presentation_set.areas", id, || {
+                    let mut index = ::std::collections::HashMap::new();
+                    crate::tables::index_referrers(
+                        &mut index,
+                        &tables.area_in_set,
+                        |holder, ids| crate::tables::Refer::referred_ids(&holder.in_set, ids),
+                    );
+                    crate::tables::index_referrers(
+                        &mut index,
+                        &tables.drawing_sheet_revision_usage,
+                        |holder, ids| {
+                            if let Some(holder) =
+                                Some(holder).and_then(|holder| holder.area_in_set.as_owned())
+                            {
+                                crate::tables::Refer::referred_ids(&holder.in_set, ids)
+                            }
+                        },
+                    );
+                    index
+                })
+        }
+        pub fn check_inverse(&self, id: u64, tables: &Tables) -> crate::error::Result<()> {
+            crate::tables::check_cardinality(
+                "areas",
+                id,
+                self.areas(id, tables).len(),
+                1u64,
+                None,
+            )?;
+            Ok(())
+        }
+    }
+    impl RepresentationContext {
+        pub fn representations_in_context(&self, id: u64, tables: &Tables) -> Vec<u64> {
+            tables._inverse_index.lookup(
+                "representation_context.representations_in_context",
+                id,
+                || {
+                    let mut index = ::std::collections::HashMap::new();
+                    crate::tables::index_referrers(
+                        &mut index,
+                        &tables.representation,
+                        |holder, ids| {
+                            crate::tables::Refer::referred_ids(&holder.context_of_items, ids)
+                        },
+                    );
+                    crate::tables::index_referrers(
+                        &mut index,
+                        &tables.draughting_model,
+                        |holder, ids| {
+                            if let Some(holder) =
+                                Some(holder).and_then(|holder| holder.representation.as_owned())
+                            {
+                                crate::tables::Refer::referred_ids(&holder.context_of_items, ids)
+                            }
+                        },
+                    );
+                    crate::tables::index_referrers(
+                        &mut index,
+                        &tables.presentation_representation,
+                        |holder, ids| {
+                            if let Some(holder) =
+                                Some(holder).and_then(|holder| holder.representation.as_owned())
+                            {
+                                crate::tables::Refer::referred_ids(&holder.context_of_items, ids)
+                            }
+                        },
+                    );
+                    crate::tables::index_referrers(
+                        &mut index,
+                        &tables.shape_representation,
+                        |holder, ids| {
+                            if let Some(holder) =
+                                Some(holder).and_then(|holder| holder.representation.as_owned())
+                            {
+                                crate::tables::Refer::referred_ids(&holder.context_of_items, ids)
+                            }
+                        },
+                    );
+                    crate::tables::index_referrers(
+                        &mut index,
+                        &tables.symbol_representation,
+                        |holder, ids| {
+                            if let Some(holder) =
+                                Some(holder).and_then(|holder| holder.representation.as_owned())
+                            {
+                                crate::tables::Refer::referred_ids(&holder.context_of_items, ids)
+                            }
+                        },
+                    );
+                    index
+                },
+            )
+        }
+        pub fn check_inverse(&self, id: u64, tables: &Tables) -> crate::error::Result<()> {
+            crate::tables::check_cardinality(
+                "representations_in_context",
+                id,
+                self.representations_in_context(id, tables).len(),
+                1u64,
+                None,
+            )?;
+            Ok(())
+        }
+    }
+    impl RepresentationMap {
+        pub fn map_usage(&self, id: u64, tables: &Tables) -> Vec<u64> {
+            tables
+                ._inverse_index
+                .lookup("representation_map.map_usage", id, || {
+                    let mut index = ::std::collections::HashMap::new();
+                    crate::tables::index_referrers(
+                        &mut index,
+                        &tables.mapped_item,
+                        |holder, ids| {
+                            crate::tables::Refer::referred_ids(&holder.mapping_source, ids)
+                        },
+                    );
+                    crate::tables::index_referrers(
+                        &mut index,
+                        &tables.annotation_symbol,
+                        |holder, ids| {
+                            if let Some(holder) =
+                                Some(holder).and_then(|holder| holder.mapped_item.as_owned())
+                            {
+                                crate::tables::Refer::referred_ids(&holder.mapping_source, ids)
+                            }
+                        },
+                    );
+                    crate::tables::index_referrers(
+                        &mut index,
+                        &tables.annotation_text,
+                        |holder, ids| {
+                            if let Some(holder) =
+                                Some(holder).and_then(|holder| holder.mapped_item.as_owned())
+                            {
+                                crate::tables::Refer::referred_ids(&holder.mapping_source, ids)
+                            }
+                        },
+                    );
+                    crate::tables::index_referrers(
+                        &mut index,
+                        &tables.camera_image,
+                        |holder, ids| {
+                            if let Some(holder) =
+                                Some(holder).and_then(|holder| holder.mapped_item.as_owned())
+                            {
+                                crate::tables::Refer::referred_ids(&holder.mapping_source, ids)
+                            }
+                        },
+                    );
+                    index
+                })
+        }
+        pub fn check_inverse(&self, id: u64, tables: &Tables) -> crate::error::Result<()> {
+            crate::tables::check_cardinality(
+                "map_usage",
+                id,
+                self.map_usage(id, tables).len(),
+                1u64,
+                None,
+            )?;
+            Ok(())
+        }
+    }
 }
@@ -2,7 +2,7 @@
 pub mod config_control_design {
     use crate::{as_holder, derive_more::*, primitive::*, Holder, TableInit};
     use std::collections::HashMap;
-    #[derive(Debug, Clone, PartialEq, Default, TableInit)]
+    #[derive(Debug, Clone, Default, TableInit)]
     pub struct Tables {
         action: HashMap<u64, as_holder!(Action)>,
         action_assignment: HashMap<u64, as_holder!(ActionAssignment)>,
@@ -340,6 +340,427 @@ pub mod config_control_design {
         wireframe_model: HashMap<u64, as_holder!(WireframeModel)>,
         work_item: HashMap<u64, as_holder!(WorkItem)>,
         year_number: HashMap<u64, as_holder!(YearNumber)>,
+        #[table_init(index)]
+        _inverse_index: crate::tables::InverseIndex,
+    }
+    impl PartialEq for Tables {
+        fn eq(&self, other: &Self) -> bool {
+            let equal = true;
+            let equal = equal && self.action == other.action;
+            let equal = equal && self.action_assignment == other.action_assignment;
+            let equal = equal && self.action_directive == other.action_directive;
+            let equal = equal && self.action_method == other.action_method;
+            let equal = equal && self.action_request_assignment == other.action_request_assignment;
+            let equal = equal && self.action_request_solution == other.action_request_solution;
+            let equal = equal && self.action_request_status == other.action_request_status;
+            let equal = equal && self.action_status == other.action_status;
+            let equal = equal && self.address == other.address;
+            let equal = equal
+                && self.advanced_brep_shape_representation
+                    == other.advanced_brep_shape_representation;
+            let equal = equal && self.advanced_face == other.advanced_face;
+            let equal = equal
+                && self.alternate_product_relationship == other.alternate_product_relationship;
+            let equal = equal && self.application_context == other.application_context;
+            let equal =
+                equal && self.application_context_element == other.application_context_element;
+            let equal = equal
+                && self.application_protocol_definition == other.application_protocol_definition;
+            let equal = equal && self.approval == other.approval;
+            let equal = equal && self.approval_assignment == other.approval_assignment;
+            let equal = equal && self.approval_date_time == other.approval_date_time;
+            let equal =
+                equal && self.approval_person_organization == other.approval_person_organization;
+            let equal = equal && self.approval_relationship == other.approval_relationship;
+            let equal = equal && self.approval_role == other.approval_role;
+            let equal = equal && self.approval_status == other.approval_status;
+            let equal = equal && self.area_measure_with_unit == other.area_measure_with_unit;
+            let equal = equal && self.area_unit == other.area_unit;
+            let equal = equal && self.assembly_component_usage == other.assembly_component_usage;
+            let equal = equal
+                && self.assembly_component_usage_substitute
+                    == other.assembly_component_usage_substitute;
+            let equal = equal && self.axis1_placement == other.axis1_placement;
+            let equal = equal && self.axis2_placement_2d == other.axis2_placement_2d;
+            let equal = equal && self.axis2_placement_3d == other.axis2_placement_3d;
+            let equal = equal && self.b_spline_curve == other.b_spline_curve;
+            let equal = equal && self.b_spline_curve_with_knots == other.b_spline_curve_with_knots;
+            let equal = equal && self.b_spline_surface == other.b_spline_surface;
+            let equal =
+                equal && self.b_spline_surface_with_knots == other.b_spline_surface_with_knots;
+            let equal = equal && self.bezier_curve == other.bezier_curve;
+            let equal = equal && self.bezier_surface == other.bezier_surface;
+            let equal = equal && self.boundary_curve == other.boundary_curve;
+            let equal = equal && self.bounded_curve == other.bounded_curve;
+            let equal = equal && self.bounded_pcurve == other.bounded_pcurve;
+            let equal = equal && self.bounded_surface == other.bounded_surface;
+            let equal = equal && self.bounded_surface_curve == other.bounded_surface_curve;
+            let equal = equal && self.brep_with_voids == other.brep_with_voids;
+            let equal = equal && self.calendar_date == other.calendar_date;
+            let equal = equal && self.cartesian_point == other.cartesian_point;
+            let equal = equal
+                && self.cartesian_transformation_operator
+                    == other.cartesian_transformation_operator;
+            let equal = equal
+                && self.cartesian_transformation_operator_3d
+                    == other.cartesian_transformation_operator_3d;
+            let equal = equal && self.cc_design_approval == other.cc_design_approval;
+            let equal = equal && self.cc_design_certification == other.cc_design_certification;
+            let equal = equal && self.cc_design_contract == other.cc_design_contract;
+            let equal = equal
+                && self.cc_design_date_and_time_assignment
+                    == other.cc_design_date_and_time_assignment;
+            let equal = equal
+                && self.cc_design_person_and_organization_assignment
+                    == other.cc_design_person_and_organization_assignment;
+            let equal = equal
+                && self.cc_design_security_classification
+                    == other.cc_design_security_classification;
+            let equal = equal
+                && self.cc_design_specification_reference
+                    == other.cc_design_specification_reference;
+            let equal = equal && self.certification == other.certification;
+            let equal = equal && self.certification_assignment == other.certification_assignment;
+            let equal = equal && self.certification_type == other.certification_type;
+            let equal = equal && self.change == other.change;
+            let equal = equal && self.change_request == other.change_request;
+            let equal = equal && self.circle == other.circle;
+            let equal = equal && self.closed_shell == other.closed_shell;
+            let equal = equal && self.composite_curve == other.composite_curve;
+            let equal =
+                equal && self.composite_curve_on_surface == other.composite_curve_on_surface;
+            let equal = equal && self.composite_curve_segment == other.composite_curve_segment;
+            let equal = equal && self.configuration_design == other.configuration_design;
+            let equal = equal && self.configuration_effectivity == other.configuration_effectivity;
+            let equal = equal && self.configuration_item == other.configuration_item;
+            let equal = equal && self.conic == other.conic;
+            let equal = equal && self.conical_surface == other.conical_surface;
+            let equal = equal && self.connected_edge_set == other.connected_edge_set;
+            let equal = equal && self.connected_face_set == other.connected_face_set;
+            let equal = equal
+                && self.context_dependent_shape_representation
+                    == other.context_dependent_shape_representation;
+            let equal = equal && self.context_dependent_unit == other.context_dependent_unit;
+            let equal = equal && self.contract == other.contract;
+            let equal = equal && self.contract_assignment == other.contract_assignment;
+            let equal = equal && self.contract_type == other.contract_type;
+            let equal = equal && self.conversion_based_unit == other.conversion_based_unit;
+            let equal = equal
+                && self.coordinated_universal_time_offset
+                    == other.coordinated_universal_time_offset;
+            let equal = equal && self.curve == other.curve;
+            let equal = equal && self.curve_bounded_surface == other.curve_bounded_surface;
+            let equal = equal && self.curve_replica == other.curve_replica;
+            let equal = equal && self.cylindrical_surface == other.cylindrical_surface;
+            let equal = equal && self.date == other.date;
+            let equal = equal && self.date_and_time == other.date_and_time;
+            let equal = equal && self.date_and_time_assignment == other.date_and_time_assignment;
+            let equal = equal && self.date_time_role == other.date_time_role;
+            let equal = equal && self.dated_effectivity == other.dated_effectivity;
+            let equal =
+                equal && self.definitional_representation == other.definitional_representation;
+            let equal = equal && self.degenerate_pcurve == other.degenerate_pcurve;
+            let equal =
+                equal && self.degenerate_toroidal_surface == other.degenerate_toroidal_surface;
+            let equal = equal && self.design_context == other.design_context;
+            let equal =
+                equal && self.design_make_from_relationship == other.design_make_from_relationship;
+            let equal = equal && self.dimensional_exponents == other.dimensional_exponents;
+            let equal = equal && self.directed_action == other.directed_action;
+            let equal = equal && self.direction == other.direction;
+            let equal = equal && self.document == other.document;
+            let equal = equal && self.document_reference == other.document_reference;
+            let equal = equal && self.document_relationship == other.document_relationship;
+            let equal = equal && self.document_type == other.document_type;
+            let equal = equal && self.document_usage_constraint == other.document_usage_constraint;
+            let equal = equal && self.document_with_class == other.document_with_class;
+            let equal = equal && self.edge == other.edge;
+            let equal =
+                equal && self.edge_based_wireframe_model == other.edge_based_wireframe_model;
+            let equal = equal
+                && self.edge_based_wireframe_shape_representation
+                    == other.edge_based_wireframe_shape_representation;
+            let equal = equal && self.edge_curve == other.edge_curve;
+            let equal = equal && self.edge_loop == other.edge_loop;
+            let equal = equal && self.effectivity == other.effectivity;
+            let equal = equal && self.elementary_surface == other.elementary_surface;
+            let equal = equal && self.ellipse == other.ellipse;
+            let equal =
+                equal && self.evaluated_degenerate_pcurve == other.evaluated_degenerate_pcurve;
+            let equal = equal && self.executed_action == other.executed_action;
+            let equal = equal && self.face == other.face;
+            let equal = equal && self.face_bound == other.face_bound;
+            let equal = equal && self.face_outer_bound == other.face_outer_bound;
+            let equal = equal && self.face_surface == other.face_surface;
+            let equal = equal && self.faceted_brep == other.faceted_brep;
+            let equal = equal
+                && self.faceted_brep_shape_representation
+                    == other.faceted_brep_shape_representation;
+            let equal = equal && self.founded_item == other.founded_item;
+            let equal = equal
+                && self.functionally_defined_transformation
+                    == other.functionally_defined_transformation;
+            let equal = equal && self.geometric_curve_set == other.geometric_curve_set;
+            let equal = equal
+                && self.geometric_representation_context == other.geometric_representation_context;
+            let equal =
+                equal && self.geometric_representation_item == other.geometric_representation_item;
+            let equal = equal && self.geometric_set == other.geometric_set;
+            let equal = equal
+                && self.geometrically_bounded_surface_shape_representation
+                    == other.geometrically_bounded_surface_shape_representation;
+            let equal = equal
+                && self.geometrically_bounded_wireframe_shape_representation
+                    == other.geometrically_bounded_wireframe_shape_representation;
+            let equal = equal
+                && self.global_uncertainty_assigned_context
+                    == other.global_uncertainty_assigned_context;
+            let equal =
+                equal && self.global_unit_assigned_context == other.global_unit_assigned_context;
+            let equal = equal && self.hyperbola == other.hyperbola;
+            let equal = equal && self.intersection_curve == other.intersection_curve;
+            let equal =
+                equal && self.item_defined_transformation == other.item_defined_transformation;
+            let equal = equal && self.length_measure_with_unit == other.length_measure_with_unit;
+            let equal = equal && self.length_unit == other.length_unit;
+            let equal = equal && self.line == other.line;
+            let equal = equal && self.local_time == other.local_time;
+            let equal = equal && self.r#loop == other.r#loop;
+            let equal = equal && self.lot_effectivity == other.lot_effectivity;
+            let equal = equal && self.manifold_solid_brep == other.manifold_solid_brep;
+            let equal = equal
+                && self.manifold_surface_shape_representation
+                    == other.manifold_surface_shape_representation;
+            let equal = equal && self.mapped_item == other.mapped_item;
+            let equal = equal && self.mass_measure_with_unit == other.mass_measure_with_unit;
+            let equal = equal && self.mass_unit == other.mass_unit;
+            let equal = equal && self.measure_with_unit == other.measure_with_unit;
+            let equal = equal && self.mechanical_context == other.mechanical_context;
+            let equal = equal && self.named_unit == other.named_unit;
+            let equal = equal
+                && self.next_assembly_usage_occurrence == other.next_assembly_usage_occurrence;
+            let equal = equal && self.offset_curve_3d == other.offset_curve_3d;
+            let equal = equal && self.offset_surface == other.offset_surface;
+            let equal = equal && self.open_shell == other.open_shell;
+            let equal = equal && self.ordinal_date == other.ordinal_date;
+            let equal = equal && self.organization == other.organization;
+            let equal = equal && self.organization_relationship == other.organization_relationship;
+            let equal = equal && self.organizational_address == other.organizational_address;
+            let equal = equal && self.organizational_project == other.organizational_project;
+            let equal = equal && self.oriented_closed_shell == other.oriented_closed_shell;
+            let equal = equal && self.oriented_edge == other.oriented_edge;
+            let equal = equal && self.oriented_face == other.oriented_face;
+            let equal = equal && self.oriented_open_shell == other.oriented_open_shell;
+            let equal = equal && self.oriented_path == other.oriented_path;
+            let equal = equal && self.outer_boundary_curve == other.outer_boundary_curve;
+            let equal = equal && self.parabola == other.parabola;
+            let equal = equal
+                && self.parametric_representation_context
+                    == other.parametric_representation_context;
+            let equal = equal && self.path == other.path;
+            let equal = equal && self.pcurve == other.pcurve;
+            let equal = equal && self.person == other.person;
+            let equal = equal && self.person_and_organization == other.person_and_organization;
+            let equal = equal
+                && self.person_and_organization_assignment
+                    == other.person_and_organization_assignment;
+            let equal =
+                equal && self.person_and_organization_role == other.person_and_organization_role;
+            let equal = equal && self.personal_address == other.personal_address;
+            let equal = equal && self.placement == other.placement;
+            let equal = equal && self.plane == other.plane;
+            let equal =
+                equal && self.plane_angle_measure_with_unit == other.plane_angle_measure_with_unit;
+            let equal = equal && self.plane_angle_unit == other.plane_angle_unit;
+            let equal = equal && self.point == other.point;
+            let equal = equal && self.point_on_curve == other.point_on_curve;
+            let equal = equal && self.point_on_surface == other.point_on_surface;
+            let equal = equal && self.point_replica == other.point_replica;
+            let equal = equal && self.poly_loop == other.poly_loop;
+            let equal = equal && self.polyline == other.polyline;
+            let equal = equal && self.product == other.product;
+            let equal = equal && self.product_category == other.product_category;
+            let equal =
+                equal && self.product_category_relationship == other.product_category_relationship;
+            let equal = equal && self.product_concept == other.product_concept;
+            let equal = equal && self.product_concept_context == other.product_concept_context;
+            let equal = equal && self.product_context == other.product_context;
+            let equal = equal && self.product_definition == other.product_definition;
+            let equal =
+                equal && self.product_definition_context == other.product_definition_context;
+            let equal = equal
+                && self.product_definition_effectivity == other.product_definition_effectivity;
+            let equal =
+                equal && self.product_definition_formation == other.product_definition_formation;
+            let equal = equal
+                && self.product_definition_formation_with_specified_source
+                    == other.product_definition_formation_with_specified_source;
+            let equal = equal
+                && self.product_definition_relationship == other.product_definition_relationship;
+            let equal = equal && self.product_definition_shape == other.product_definition_shape;
+            let equal = equal && self.product_definition_usage == other.product_definition_usage;
+            let equal = equal
+                && self.product_definition_with_associated_documents
+                    == other.product_definition_with_associated_documents;
+            let equal = equal
+                && self.product_related_product_category == other.product_related_product_category;
+            let equal =
+                equal && self.promissory_usage_occurrence == other.promissory_usage_occurrence;
+            let equal = equal && self.property_definition == other.property_definition;
+            let equal = equal
+                && self.property_definition_representation
+                    == other.property_definition_representation;
+            let equal = equal
+                && self.quantified_assembly_component_usage
+                    == other.quantified_assembly_component_usage;
+            let equal = equal && self.quasi_uniform_curve == other.quasi_uniform_curve;
+            let equal = equal && self.quasi_uniform_surface == other.quasi_uniform_surface;
+            let equal = equal && self.rational_b_spline_curve == other.rational_b_spline_curve;
+            let equal = equal && self.rational_b_spline_surface == other.rational_b_spline_surface;
+            let equal =
+                equal && self.rectangular_composite_surface == other.rectangular_composite_surface;
+            let equal =
+                equal && self.rectangular_trimmed_surface == other.rectangular_trimmed_surface;
+            let equal = equal
+                && self.reparametrised_composite_curve_segment
+                    == other.reparametrised_composite_curve_segment;
+            let equal = equal && self.representation == other.representation;
+            let equal = equal && self.representation_context == other.representation_context;
+            let equal = equal && self.representation_item == other.representation_item;
+            let equal = equal && self.representation_map == other.representation_map;
+            let equal =
+                equal && self.representation_relationship == other.representation_relationship;
+            let equal = equal
+                && self.representation_relationship_with_transformation
+                    == other.representation_relationship_with_transformation;
+            let equal = equal && self.seam_curve == other.seam_curve;
+            let equal = equal && self.security_classification == other.security_classification;
+            let equal = equal
+                && self.security_classification_assignment
+                    == other.security_classification_assignment;
+            let equal =
+                equal && self.security_classification_level == other.security_classification_level;
+            let equal =
+                equal && self.serial_numbered_effectivity == other.serial_numbered_effectivity;
+            let equal = equal && self.shape_aspect == other.shape_aspect;
+            let equal = equal && self.shape_aspect_relationship == other.shape_aspect_relationship;
+            let equal = equal
+                && self.shape_definition_representation == other.shape_definition_representation;
+            let equal = equal && self.shape_representation == other.shape_representation;
+            let equal = equal
+                && self.shape_representation_relationship
+                    == other.shape_representation_relationship;
+            let equal = equal && self.shell_based_surface_model == other.shell_based_surface_model;
+            let equal =
+                equal && self.shell_based_wireframe_model == other.shell_based_wireframe_model;
+            let equal = equal
+                && self.shell_based_wireframe_shape_representation
+                    == other.shell_based_wireframe_shape_representation;
+            let equal = equal && self.si_unit == other.si_unit;
+            let equal =
+                equal && self.solid_angle_measure_with_unit == other.solid_angle_measure_with_unit;
+            let equal = equal && self.solid_angle_unit == other.solid_angle_unit;
+            let equal = equal && self.solid_model == other.solid_model;
+            let equal = equal
+                && self.specified_higher_usage_occurrence
+                    == other.specified_higher_usage_occurrence;
+            let equal = equal && self.spherical_surface == other.spherical_surface;
+            let equal = equal && self.start_request == other.start_request;
+            let equal = equal && self.start_work == other.start_work;
+            let equal =
+                equal && self.supplied_part_relationship == other.supplied_part_relationship;
+            let equal = equal && self.surface == other.surface;
+            let equal = equal && self.surface_curve == other.surface_curve;
+            let equal =
+                equal && self.surface_of_linear_extrusion == other.surface_of_linear_extrusion;
+            let equal = equal && self.surface_of_revolution == other.surface_of_revolution;
+            let equal = equal && self.surface_patch == other.surface_patch;
+            let equal = equal && self.surface_replica == other.surface_replica;
+            let equal = equal && self.swept_surface == other.swept_surface;
+            let equal = equal
+                && self.topological_representation_item == other.topological_representation_item;
+            let equal = equal && self.toroidal_surface == other.toroidal_surface;
+            let equal = equal && self.trimmed_curve == other.trimmed_curve;
+            let equal =
+                equal && self.uncertainty_measure_with_unit == other.uncertainty_measure_with_unit;
+            let equal = equal && self.uniform_curve == other.uniform_curve;
+            let equal = equal && self.uniform_surface == other.uniform_surface;
+            let equal = equal && self.vector == other.vector;
+            let equal = equal && self.versioned_action_request == other.versioned_action_request;
+            let equal = equal && self.vertex == other.vertex;
+            let equal = equal && self.vertex_loop == other.vertex_loop;
+            let equal = equal && self.vertex_point == other.vertex_point;
+            let equal = equal && self.vertex_shell == other.vertex_shell;
+            let equal = equal && self.volume_measure_with_unit == other.volume_measure_with_unit;
+            let equal = equal && self.volume_unit == other.volume_unit;
+            let equal = equal && self.week_of_year_and_day_date == other.week_of_year_and_day_date;
+            let equal = equal && self.wire_shell == other.wire_shell;
+            let equal = equal && self.approved_item == other.approved_item;
+            let equal = equal && self.area_measure == other.area_measure;
+            let equal = equal && self.axis2_placement == other.axis2_placement;
+            let equal = equal && self.boolean_operand == other.boolean_operand;
+            let equal = equal && self.certified_item == other.certified_item;
+            let equal = equal && self.change_request_item == other.change_request_item;
+            let equal = equal && self.characterized_definition == other.characterized_definition;
+            let equal = equal
+                && self.characterized_product_definition == other.characterized_product_definition;
+            let equal = equal && self.classified_item == other.classified_item;
+            let equal = equal && self.context_dependent_measure == other.context_dependent_measure;
+            let equal = equal && self.contracted_item == other.contracted_item;
+            let equal = equal && self.count_measure == other.count_measure;
+            let equal = equal && self.curve_on_surface == other.curve_on_surface;
+            let equal = equal && self.date_time_item == other.date_time_item;
+            let equal = equal && self.date_time_select == other.date_time_select;
+            let equal = equal && self.day_in_month_number == other.day_in_month_number;
+            let equal = equal && self.day_in_week_number == other.day_in_week_number;
+            let equal = equal && self.day_in_year_number == other.day_in_year_number;
+            let equal = equal && self.descriptive_measure == other.descriptive_measure;
+            let equal = equal && self.dimension_count == other.dimension_count;
+            let equal = equal && self.founded_item_select == other.founded_item_select;
+            let equal = equal && self.geometric_set_select == other.geometric_set_select;
+            let equal = equal && self.hour_in_day == other.hour_in_day;
+            let equal = equal && self.identifier == other.identifier;
+            let equal = equal && self.label == other.label;
+            let equal = equal && self.length_measure == other.length_measure;
+            let equal = equal
+                && self.list_of_reversible_topology_item == other.list_of_reversible_topology_item;
+            let equal = equal && self.mass_measure == other.mass_measure;
+            let equal = equal && self.measure_value == other.measure_value;
+            let equal = equal && self.minute_in_hour == other.minute_in_hour;
+            let equal = equal && self.month_in_year_number == other.month_in_year_number;
+            let equal = equal && self.parameter_value == other.parameter_value;
+            let equal = equal && self.pcurve_or_surface == other.pcurve_or_surface;
+            let equal = equal && self.person_organization_item == other.person_organization_item;
+            let equal =
+                equal && self.person_organization_select == other.person_organization_select;
+            let equal = equal && self.plane_angle_measure == other.plane_angle_measure;
+            let equal = equal && self.positive_length_measure == other.positive_length_measure;
+            let equal =
+                equal && self.positive_plane_angle_measure == other.positive_plane_angle_measure;
+            let equal = equal && self.reversible_topology == other.reversible_topology;
+            let equal = equal && self.reversible_topology_item == other.reversible_topology_item;
+            let equal = equal && self.second_in_minute == other.second_in_minute;
+            let equal = equal
+                && self.set_of_reversible_topology_item == other.set_of_reversible_topology_item;
+            let equal = equal && self.shape_definition == other.shape_definition;
+            let equal = equal && self.shell == other.shell;
+            let equal = equal && self.solid_angle_measure == other.solid_angle_measure;
+            let equal = equal && self.specified_item == other.specified_item;
+            let equal = equal && self.start_request_item == other.start_request_item;
+            let equal = equal && self.supported_item == other.supported_item;
+            let equal = equal && self.surface_model == other.surface_model;
+            let equal = equal && self.text == other.text;
+            let equal = equal && self.transformation == other.transformation;
+            let equal = equal && self.trimming_select == other.trimming_select;
+            let equal = equal && self.unit == other.unit;
+            let equal = equal && self.vector_or_direction == other.vector_or_direction;
+            let equal = equal && self.volume_measure == other.volume_measure;
+            let equal = equal && self.week_in_year_number == other.week_in_year_number;
+            let equal = equal && self.wireframe_model == other.wireframe_model;
+            let equal = equal && self.work_item == other.work_item;
+            let equal = equal && self.year_number == other.year_number;
+            equal
+        }
     }
     impl Tables {
         pub fn action_holders(&self) -> &HashMap<u64, as_holder!(Action)> {
@@ -1522,11 +1943,32 @@ pub mod config_control_design {
             &self.year_number
         }
     }
+    #[doc = " ```text"]
+    #[doc = " TYPE ahead_or_behind = ENUMERATION OF"]
+    #[doc = "   (ahead,"]
+    #[doc = "    behind);"]
+    #[doc = " END_TYPE; -- ahead_or_behind"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: serde :: Deserialize)]
     pub enum AheadOrBehind {
         Ahead,
         Behind,
     }
+    #[doc = " ```text"]
+    #[doc = " TYPE approved_item = SELECT"]
+    #[doc = "   (product_definition_formation,"]
+    #[doc = "    product_definition,"]
+    #[doc = "    configuration_effectivity,"]
+    #[doc = "    configuration_item,"]
+    #[doc = "    security_classification,"]
+    #[doc = "    change_request,"]
+    #[doc = "    change,"]
+    #[doc = "    start_request,"]
+    #[doc = "    start_work,"]
+    #[doc = "    certification,"]
+    #[doc = "    contract);"]
+    #[doc = " END_TYPE; -- approved_item"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
     #[holder(generate_deserialize)]
@@ -1554,6 +1996,65 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         Contract(Box<Contract>),
     }
+    impl From<ProductDefinitionFormationAny> for ApprovedItem {
+        fn from(value: ProductDefinitionFormationAny) -> Self {
+            ApprovedItem::ProductDefinitionFormation(value)
+        }
+    }
+    impl From<ProductDefinitionAny> for ApprovedItem {
+        fn from(value: ProductDefinitionAny) -> Self {
+            ApprovedItem::ProductDefinition(value)
+        }
+    }
+    impl From<ConfigurationEffectivity> for ApprovedItem {
+        fn from(value: ConfigurationEffectivity) -> Self {
+            ApprovedItem::ConfigurationEffectivity(Box::new(value))
+        }
+    }
+    impl From<ConfigurationItem> for ApprovedItem {
+        fn from(value: ConfigurationItem) -> Self {
+            ApprovedItem::ConfigurationItem(Box::new(value))
+        }
+    }
+    impl From<SecurityClassification> for ApprovedItem {
+        fn from(value: SecurityClassification) -> Self {
+            ApprovedItem::SecurityClassification(Box::new(value))
+        }
+    }
+    impl From<ChangeRequest> for ApprovedItem {
+        fn from(value: ChangeRequest) -> Self {
+            ApprovedItem::ChangeRequest(Box::new(value))
+        }
+    }
+    impl From<Change> for ApprovedItem {
+        fn from(value: Change) -> Self {
+            ApprovedItem::Change(Box::new(value))
+        }
+    }
+    impl From<StartRequest> for ApprovedItem {
+        fn from(value: StartRequest) -> Self {
+            ApprovedItem::StartRequest(Box::new(value))
+        }
+    }
+    impl From<StartWork> for ApprovedItem {
+        fn from(value: StartWork) -> Self {
+            ApprovedItem::StartWork(Box::new(value))
+        }
+    }
+    impl From<Certification> for ApprovedItem {
+        fn from(value: Certification) -> Self {
+            ApprovedItem::Certification(Box::new(value))
+        }
+    }
+    impl From<Contract> for ApprovedItem {
+        fn from(value: Contract) -> Self {
+            ApprovedItem::Contract(Box::new(value))
+        }
+    }
+    #[doc = " ```text"]
+    #[doc = " TYPE area_measure = REAL;"]
+    #[doc = " END_TYPE; -- area_measure"]
+    #[doc = " ```"]
     #[derive(
         Clone, Debug, PartialEq, AsRef, Deref, DerefMut, Into, From, :: ruststep_derive :: Holder,
     )]
@@ -1561,6 +2062,12 @@ pub mod config_control_design {
     # [holder (field = area_measure)]
     #[holder(generate_deserialize)]
     pub struct AreaMeasure(pub f64);
+    #[doc = " ```text"]
+    #[doc = " TYPE axis2_placement = SELECT"]
+    #[doc = "   (axis2_placement_2d,"]
+    #[doc = "    axis2_placement_3d);"]
+    #[doc = " END_TYPE; -- axis2_placement"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
     #[holder(generate_deserialize)]
@@ -1570,6 +2077,26 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         Axis2Placement3D(Box<Axis2Placement3D>),
     }
+    impl From<Axis2Placement2D> for Axis2Placement {
+        fn from(value: Axis2Placement2D) -> Self {
+            Axis2Placement::Axis2Placement2D(Box::new(value))
+        }
+    }
+    impl From<Axis2Placement3D> for Axis2Placement {
+        fn from(value: Axis2Placement3D) -> Self {
+            Axis2Placement::Axis2Placement3D(Box::new(value))
+        }
+    }
+    #[doc = " ```text"]
+    #[doc = " TYPE b_spline_curve_form = ENUMERATION OF"]
+    #[doc = "   (polyline_form,"]
+    #[doc = "    circular_arc,"]
+    #[doc = "    elliptic_arc,"]
+    #[doc = "    parabolic_arc,"]
+    #[doc = "    hyperbolic_arc,"]
+    #[doc = "    unspecified);"]
+    #[doc = " END_TYPE; -- b_spline_curve_form"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: serde :: Deserialize)]
     pub enum BSplineCurveForm {
         PolylineForm,
@@ -1579,6 +2106,21 @@ pub mod config_control_design {
         HyperbolicArc,
         Unspecified,
     }
+    #[doc = " ```text"]
+    #[doc = " TYPE b_spline_surface_form = ENUMERATION OF"]
+    #[doc = "   (plane_surf,"]
+    #[doc = "    cylindrical_surf,"]
+    #[doc = "    conical_surf,"]
+    #[doc = "    spherical_surf,"]
+    #[doc = "    toroidal_surf,"]
+    #[doc = "    surf_of_revolution,"]
+    #[doc = "    ruled_surf,"]
+    #[doc = "    generalised_cone,"]
+    #[doc = "    quadric_surf,"]
+    #[doc = "    surf_of_linear_extrusion,"]
+    #[doc = "    unspecified);"]
+    #[doc = " END_TYPE; -- b_spline_surface_form"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: serde :: Deserialize)]
     pub enum BSplineSurfaceForm {
         PlaneSurf,
@@ -1593,6 +2135,11 @@ pub mod config_control_design {
         SurfOfLinearExtrusion,
         Unspecified,
     }
+    #[doc = " ```text"]
+    #[doc = " TYPE boolean_operand = SELECT"]
+    #[doc = "   (solid_model);"]
+    #[doc = " END_TYPE; -- boolean_operand"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
     #[holder(generate_deserialize)]
@@ -1600,6 +2147,16 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         SolidModel(SolidModelAny),
     }
+    impl From<SolidModelAny> for BooleanOperand {
+        fn from(value: SolidModelAny) -> Self {
+            BooleanOperand::SolidModel(value)
+        }
+    }
+    #[doc = " ```text"]
+    #[doc = " TYPE certified_item = SELECT"]
+    #[doc = "   (supplied_part_relationship);"]
+    #[doc = " END_TYPE; -- certified_item"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
     #[holder(generate_deserialize)]
@@ -1607,6 +2164,16 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         SuppliedPartRelationship(Box<SuppliedPartRelationship>),
     }
+    impl From<SuppliedPartRelationship> for CertifiedItem {
+        fn from(value: SuppliedPartRelationship) -> Self {
+            CertifiedItem::SuppliedPartRelationship(Box::new(value))
+        }
+    }
+    #[doc = " ```text"]
+    #[doc = " TYPE change_request_item = SELECT"]
+    #[doc = "   (product_definition_formation);"]
+    #[doc = " END_TYPE; -- change_request_item"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
     #[holder(generate_deserialize)]
@@ -1614,6 +2181,17 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         ProductDefinitionFormation(ProductDefinitionFormationAny),
     }
+    impl From<ProductDefinitionFormationAny> for ChangeRequestItem {
+        fn from(value: ProductDefinitionFormationAny) -> Self {
+            ChangeRequestItem::ProductDefinitionFormation(value)
+        }
+    }
+    #[doc = " ```text"]
+    #[doc = " TYPE characterized_definition = SELECT"]
+    #[doc = "   (characterized_product_definition,"]
+    #[doc = "    shape_definition);"]
+    #[doc = " END_TYPE; -- characterized_definition"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
     #[holder(generate_deserialize)]
@@ -1623,6 +2201,22 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         ShapeDefinition(Box<ShapeDefinition>),
     }
+    impl From<CharacterizedProductDefinition> for CharacterizedDefinition {
+        fn from(value: CharacterizedProductDefinition) -> Self {
+            CharacterizedDefinition::CharacterizedProductDefinition(Box::new(value))
+        }
+    }
+    impl From<ShapeDefinition> for CharacterizedDefinition {
+        fn from(value: ShapeDefinition) -> Self {
+            CharacterizedDefinition::ShapeDefinition(Box::new(value))
+        }
+    }
+    #[doc = " ```text"]
+    #[doc = " TYPE characterized_product_definition = SELECT"]
+    #[doc = "   (product_definition,"]
+    #[doc = "    product_definition_relationship);"]
+    #[doc = " END_TYPE; -- characterized_product_definition"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
     #[holder(generate_deserialize)]
@@ -1632,6 +2226,22 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         ProductDefinitionRelationship(ProductDefinitionRelationshipAny),
     }
+    impl From<ProductDefinitionAny> for CharacterizedProductDefinition {
+        fn from(value: ProductDefinitionAny) -> Self {
+            CharacterizedProductDefinition::ProductDefinition(value)
+        }
+    }
+    impl From<ProductDefinitionRelationshipAny> for CharacterizedProductDefinition {
+        fn from(value: ProductDefinitionRelationshipAny) -> Self {
+            CharacterizedProductDefinition::ProductDefinitionRelationship(value)
+        }
+    }
+    #[doc = " ```text"]
+    #[doc = " TYPE classified_item = SELECT"]
+    #[doc = "   (product_definition_formation,"]
+    #[doc = "    assembly_component_usage);"]
+    #[doc = " END_TYPE; -- classified_item"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
     #[holder(generate_deserialize)]
@@ -1641,6 +2251,20 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         AssemblyComponentUsage(AssemblyComponentUsageAny),
     }
+    impl From<ProductDefinitionFormationAny> for ClassifiedItem {
+        fn from(value: ProductDefinitionFormationAny) -> Self {
+            ClassifiedItem::ProductDefinitionFormation(value)
+        }
+    }
+    impl From<AssemblyComponentUsageAny> for ClassifiedItem {
+        fn from(value: AssemblyComponentUsageAny) -> Self {
+            ClassifiedItem::AssemblyComponentUsage(value)
+        }
+    }
+    #[doc = " ```text"]
+    #[doc = " TYPE context_dependent_measure = REAL;"]
+    #[doc = " END_TYPE; -- context_dependent_measure"]
+    #[doc = " ```"]
     #[derive(
         Clone, Debug, PartialEq, AsRef, Deref, DerefMut, Into, From, :: ruststep_derive :: Holder,
     )]
@@ -1648,6 +2272,11 @@ pub mod config_control_design {
     # [holder (field = context_dependent_measure)]
     #[holder(generate_deserialize)]
     pub struct ContextDependentMeasure(pub f64);
+    #[doc = " ```text"]
+    #[doc = " TYPE contracted_item = SELECT"]
+    #[doc = "   (product_definition_formation);"]
+    #[doc = " END_TYPE; -- contracted_item"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
     #[holder(generate_deserialize)]
@@ -1655,6 +2284,15 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         ProductDefinitionFormation(ProductDefinitionFormationAny),
     }
+    impl From<ProductDefinitionFormationAny> for ContractedItem {
+        fn from(value: ProductDefinitionFormationAny) -> Self {
+            ContractedItem::ProductDefinitionFormation(value)
+        }
+    }
+    #[doc = " ```text"]
+    #[doc = " TYPE count_measure = NUMBER;"]
+    #[doc = " END_TYPE; -- count_measure"]
+    #[doc = " ```"]
     #[derive(
         Clone, Debug, PartialEq, AsRef, Deref, DerefMut, Into, From, :: ruststep_derive :: Holder,
     )]
@@ -1662,6 +2300,13 @@ pub mod config_control_design {
     # [holder (field = count_measure)]
     #[holder(generate_deserialize)]
     pub struct CountMeasure(pub f64);
+    #[doc = " ```text"]
+    #[doc = " TYPE curve_on_surface = SELECT"]
+    #[doc = "   (pcurve,"]
+    #[doc = "    surface_curve,"]
+    #[doc = "    composite_curve_on_surface);"]
+    #[doc = " END_TYPE; -- curve_on_surface"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
     #[holder(generate_deserialize)]
@@ -1673,6 +2318,34 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         CompositeCurveOnSurface(CompositeCurveOnSurfaceAny),
     }
+    impl From<PcurveAny> for CurveOnSurface {
+        fn from(value: PcurveAny) -> Self {
+            CurveOnSurface::Pcurve(value)
+        }
+    }
+    impl From<SurfaceCurveAny> for CurveOnSurface {
+        fn from(value: SurfaceCurveAny) -> Self {
+            CurveOnSurface::SurfaceCurve(value)
+        }
+    }
+    impl From<CompositeCurveOnSurfaceAny> for CurveOnSurface {
+        fn from(value: CompositeCurveOnSurfaceAny) -> Self {
+            CurveOnSurface::CompositeCurveOnSurface(value)
+        }
+    }
+    #[doc = " ```text"]
+    #[doc = " TYPE date_time_item = SELECT"]
+    #[doc = "   (product_definition,"]
+    #[doc = "    change_request,"]
+    #[doc = "    start_request,"]
+    #[doc = "    change,"]
+    #[doc = "    start_work,"]
+    #[doc = "    approval_person_organization,"]
+    #[doc = "    contract,"]
+    #[doc = "    security_classification,"]
+    #[doc = "    certification);"]
+    #[doc = " END_TYPE; -- date_time_item"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
     #[holder(generate_deserialize)]
@@ -1696,6 +2369,58 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         Certification(Box<Certification>),
     }
+    impl From<ProductDefinitionAny> for DateTimeItem {
+        fn from(value: ProductDefinitionAny) -> Self {
+            DateTimeItem::ProductDefinition(value)
+        }
+    }
+    impl From<ChangeRequest> for DateTimeItem {
+        fn from(value: ChangeRequest) -> Self {
+            DateTimeItem::ChangeRequest(Box::new(value))
+        }
+    }
+    impl From<StartRequest> for DateTimeItem {
+        fn from(value: StartRequest) -> Self {
+            DateTimeItem::StartRequest(Box::new(value))
+        }
+    }
+    impl From<Change> for DateTimeItem {
+        fn from(value: Change) -> Self {
+            DateTimeItem::Change(Box::new(value))
+        }
+    }
+    impl From<StartWork> for DateTimeItem {
+        fn from(value: StartWork) -> Self {
+            DateTimeItem::StartWork(Box::new(value))
+        }
+    }
+    impl From<ApprovalPersonOrganization> for DateTimeItem {
+        fn from(value: ApprovalPersonOrganization) -> Self {
+            DateTimeItem::ApprovalPersonOrganization(Box::new(value))
+        }
+    }
+    impl From<Contract> for DateTimeItem {
+        fn from(value: Contract) -> Self {
+            DateTimeItem::Contract(Box::new(value))
+        }
+    }
+    impl From<SecurityClassification> for DateTimeItem {
+        fn from(value: SecurityClassification) -> Self {
+            DateTimeItem::SecurityClassification(Box::new(value))
+        }
+    }
+    impl From<Certification> for DateTimeItem {
+        fn from(value: Certification) -> Self {
+            DateTimeItem::Certification(Box::new(value))
+        }
+    }
+    #[doc = " ```text"]
+    #[doc = " TYPE date_time_select = SELECT"]
+    #[doc = "   (date,"]
+    #[doc = "    local_time,"]
+    #[doc = "    date_and_time);"]
+    #[doc = " END_TYPE; -- date_time_select"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
     #[holder(generate_deserialize)]
@@ -1707,6 +2432,25 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         DateAndTime(Box<DateAndTime>),
     }
+    impl From<DateAny> for DateTimeSelect {
+        fn from(value: DateAny) -> Self {
+            DateTimeSelect::Date(value)
+        }
+    }
+    impl From<LocalTime> for DateTimeSelect {
+        fn from(value: LocalTime) -> Self {
+            DateTimeSelect::LocalTime(Box::new(value))
+        }
+    }
+    impl From<DateAndTime> for DateTimeSelect {
+        fn from(value: DateAndTime) -> Self {
+            DateTimeSelect::DateAndTime(Box::new(value))
+        }
+    }
+    #[doc = " ```text"]
+    #[doc = " TYPE day_in_month_number = INTEGER;"]
+    #[doc = " END_TYPE; -- day_in_month_number"]
+    #[doc = " ```"]
     #[derive(
         Clone, Debug, PartialEq, AsRef, Deref, DerefMut, Into, From, :: ruststep_derive :: Holder,
     )]
@@ -1714,6 +2458,12 @@ pub mod config_control_design {
     # [holder (field = day_in_month_number)]
     #[holder(generate_deserialize)]
     pub struct DayInMonthNumber(pub i64);
+    #[doc = " ```text"]
+    #[doc = " TYPE day_in_week_number = INTEGER;"]
+    #[doc = " WHERE"]
+    #[doc = "   wr1: ((1 <= SELF) AND (SELF <= 7));"]
+    #[doc = " END_TYPE; -- day_in_week_number"]
+    #[doc = " ```"]
     #[derive(
         Clone, Debug, PartialEq, AsRef, Deref, DerefMut, Into, From, :: ruststep_derive :: Holder,
     )]
@@ -1721,6 +2471,10 @@ pub mod config_control_design {
     # [holder (field = day_in_week_number)]
     #[holder(generate_deserialize)]
     pub struct DayInWeekNumber(pub i64);
+    #[doc = " ```text"]
+    #[doc = " TYPE day_in_year_number = INTEGER;"]
+    #[doc = " END_TYPE; -- day_in_year_number"]
+    #[doc = " ```"]
     #[derive(
         Clone, Debug, PartialEq, AsRef, Deref, DerefMut, Into, From, :: ruststep_derive :: Holder,
     )]
@@ -1728,6 +2482,10 @@ pub mod config_control_design {
     # [holder (field = day_in_year_number)]
     #[holder(generate_deserialize)]
     pub struct DayInYearNumber(pub i64);
+    #[doc = " ```text"]
+    #[doc = " TYPE descriptive_measure = STRING;"]
+    #[doc = " END_TYPE; -- descriptive_measure"]
+    #[doc = " ```"]
     #[derive(
         Clone, Debug, PartialEq, AsRef, Deref, DerefMut, Into, From, :: ruststep_derive :: Holder,
     )]
@@ -1735,6 +2493,12 @@ pub mod config_control_design {
     # [holder (field = descriptive_measure)]
     #[holder(generate_deserialize)]
     pub struct DescriptiveMeasure(pub String);
+    #[doc = " ```text"]
+    #[doc = " TYPE dimension_count = INTEGER;"]
+    #[doc = " WHERE"]
+    #[doc = "   wr1: (SELF > 0);"]
+    #[doc = " END_TYPE; -- dimension_count"]
+    #[doc = " ```"]
     #[derive(
         Clone, Debug, PartialEq, AsRef, Deref, DerefMut, Into, From, :: ruststep_derive :: Holder,
     )]
@@ -1742,6 +2506,12 @@ pub mod config_control_design {
     # [holder (field = dimension_count)]
     #[holder(generate_deserialize)]
     pub struct DimensionCount(pub i64);
+    #[doc = " ```text"]
+    #[doc = " TYPE founded_item_select = SELECT"]
+    #[doc = "   (founded_item,"]
+    #[doc = "    representation_item);"]
+    #[doc = " END_TYPE; -- founded_item_select"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
     #[holder(generate_deserialize)]
@@ -1751,6 +2521,23 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         RepresentationItem(RepresentationItemAny),
     }
+    impl From<FoundedItemAny> for FoundedItemSelect {
+        fn from(value: FoundedItemAny) -> Self {
+            FoundedItemSelect::FoundedItem(value)
+        }
+    }
+    impl From<RepresentationItemAny> for FoundedItemSelect {
+        fn from(value: RepresentationItemAny) -> Self {
+            FoundedItemSelect::RepresentationItem(value)
+        }
+    }
+    #[doc = " ```text"]
+    #[doc = " TYPE geometric_set_select = SELECT"]
+    #[doc = "   (point,"]
+    #[doc = "    curve,"]
+    #[doc = "    surface);"]
+    #[doc = " END_TYPE; -- geometric_set_select"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
     #[holder(generate_deserialize)]
@@ -1762,6 +2549,27 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         Surface(SurfaceAny),
     }
+    impl From<PointAny> for GeometricSetSelect {
+        fn from(value: PointAny) -> Self {
+            GeometricSetSelect::Point(value)
+        }
+    }
+    impl From<CurveAny> for GeometricSetSelect {
+        fn from(value: CurveAny) -> Self {
+            GeometricSetSelect::Curve(value)
+        }
+    }
+    impl From<SurfaceAny> for GeometricSetSelect {
+        fn from(value: SurfaceAny) -> Self {
+            GeometricSetSelect::Surface(value)
+        }
+    }
+    #[doc = " ```text"]
+    #[doc = " TYPE hour_in_day = INTEGER;"]
+    #[doc = " WHERE"]
+    #[doc = "   wr1: ((0 <= SELF) AND (SELF < 24));"]
+    #[doc = " END_TYPE; -- hour_in_day"]
+    #[doc = " ```"]
     #[derive(
         Clone, Debug, PartialEq, AsRef, Deref, DerefMut, Into, From, :: ruststep_derive :: Holder,
     )]
@@ -1769,6 +2577,10 @@ pub mod config_control_design {
     # [holder (field = hour_in_day)]
     #[holder(generate_deserialize)]
     pub struct HourInDay(pub i64);
+    #[doc = " ```text"]
+    #[doc = " TYPE identifier = STRING;"]
+    #[doc = " END_TYPE; -- identifier"]
+    #[doc = " ```"]
     #[derive(
         Clone, Debug, PartialEq, AsRef, Deref, DerefMut, Into, From, :: ruststep_derive :: Holder,
     )]
@@ -1776,6 +2588,14 @@ pub mod config_control_design {
     # [holder (field = identifier)]
     #[holder(generate_deserialize)]
     pub struct Identifier(pub String);
+    #[doc = " ```text"]
+    #[doc = " TYPE knot_type = ENUMERATION OF"]
+    #[doc = "   (uniform_knots,"]
+    #[doc = "    unspecified,"]
+    #[doc = "    quasi_uniform_knots,"]
+    #[doc = "    piecewise_bezier_knots);"]
+    #[doc = " END_TYPE; -- knot_type"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: serde :: Deserialize)]
     pub enum KnotType {
         UniformKnots,
@@ -1783,6 +2603,10 @@ pub mod config_control_design {
         QuasiUniformKnots,
         PiecewiseBezierKnots,
     }
+    #[doc = " ```text"]
+    #[doc = " TYPE label = STRING;"]
+    #[doc = " END_TYPE; -- label"]
+    #[doc = " ```"]
     #[derive(
         Clone, Debug, PartialEq, AsRef, Deref, DerefMut, Into, From, :: ruststep_derive :: Holder,
     )]
@@ -1790,6 +2614,10 @@ pub mod config_control_design {
     # [holder (field = label)]
     #[holder(generate_deserialize)]
     pub struct Label(pub String);
+    #[doc = " ```text"]
+    #[doc = " TYPE length_measure = REAL;"]
+    #[doc = " END_TYPE; -- length_measure"]
+    #[doc = " ```"]
     #[derive(
         Clone, Debug, PartialEq, AsRef, Deref, DerefMut, Into, From, :: ruststep_derive :: Holder,
     )]
@@ -1797,6 +2625,11 @@ pub mod config_control_design {
     # [holder (field = length_measure)]
     #[holder(generate_deserialize)]
     pub struct LengthMeasure(pub f64);
+    #[doc = " ```text"]
+    #[doc = " TYPE list_of_reversible_topology_item = LIST [0:?] OF"]
+    #[doc = "             reversible_topology_item;"]
+    #[doc = " END_TYPE; -- list_of_reversible_topology_item"]
+    #[doc = " ```"]
     #[derive(
         Clone, Debug, PartialEq, AsRef, Deref, DerefMut, Into, From, :: ruststep_derive :: Holder,
     )]
@@ -1806,6 +2639,10 @@ pub mod config_control_design {
     pub struct ListOfReversibleTopologyItem(
         #[holder(use_place_holder)] pub Vec<ReversibleTopologyItem>,
     );
+    #[doc = " ```text"]
+    #[doc = " TYPE mass_measure = REAL;"]
+    #[doc = " END_TYPE; -- mass_measure"]
+    #[doc = " ```"]
     #[derive(
         Clone, Debug, PartialEq, AsRef, Deref, DerefMut, Into, From, :: ruststep_derive :: Holder,
     )]
@@ -1813,6 +2650,22 @@ pub mod config_control_design {
     # [holder (field = mass_measure)]
     #[holder(generate_deserialize)]
     pub struct MassMeasure(pub f64);
+    #[doc = " ```text"]
+    #[doc = " TYPE measure_value = SELECT"]
+    #[doc = "   (length_measure,"]
+    #[doc = "    mass_measure,"]
+    #[doc = "    plane_angle_measure,"]
+    #[doc = "    solid_angle_measure,"]
+    #[doc = "    area_measure,"]
+    #[doc = "    volume_measure,"]
+    #[doc = "    parameter_value,"]
+    #[doc = "    context_dependent_measure,"]
+    #[doc = "    descriptive_measure,"]
+    #[doc = "    positive_length_measure,"]
+    #[doc = "    positive_plane_angle_measure,"]
+    #[doc = "    count_measure);"]
+    #[doc = " END_TYPE; -- measure_value"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
     #[holder(generate_deserialize)]
@@ -1842,6 +2695,72 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         CountMeasure(Box<CountMeasure>),
     }
+    impl From<LengthMeasure> for MeasureValue {
+        fn from(value: LengthMeasure) -> Self {
+            MeasureValue::LengthMeasure(Box::new(value))
+        }
+    }
+    impl From<MassMeasure> for MeasureValue {
+        fn from(value: MassMeasure) -> Self {
+            MeasureValue::MassMeasure(Box::new(value))
+        }
+    }
+    impl From<PlaneAngleMeasure> for MeasureValue {
+        fn from(value: PlaneAngleMeasure) -> Self {
+            MeasureValue::PlaneAngleMeasure(Box::new(value))
+        }
+    }
+    impl From<SolidAngleMeasure> for MeasureValue {
+        fn from(value: SolidAngleMeasure) -> Self {
+            MeasureValue::SolidAngleMeasure(Box::new(value))
+        }
+    }
+    impl From<AreaMeasure> for MeasureValue {
+        fn from(value: AreaMeasure) -> Self {
+            MeasureValue::AreaMeasure(Box::new(value))
+        }
+    }
+    impl From<VolumeMeasure> for MeasureValue {
+        fn from(value: VolumeMeasure) -> Self {
+            MeasureValue::VolumeMeasure(Box::new(value))
+        }
+    }
+    impl From<ParameterValue> for MeasureValue {
+        fn from(value: ParameterValue) -> Self {
+            MeasureValue::ParameterValue(Box::new(value))
+        }
+    }
+    impl From<ContextDependentMeasure> for MeasureValue {
+        fn from(value: ContextDependentMeasure) -> Self {
+            MeasureValue::ContextDependentMeasure(Box::new(value))
+        }
+    }
+    impl From<DescriptiveMeasure> for MeasureValue {
+        fn from(value: DescriptiveMeasure) -> Self {
+            MeasureValue::DescriptiveMeasure(Box::new(value))
+        }
+    }
+    impl From<PositiveLengthMeasure> for MeasureValue {
+        fn from(value: PositiveLengthMeasure) -> Self {
+            MeasureValue::PositiveLengthMeasure(Box::new(value))
+        }
+    }
+    impl From<PositivePlaneAngleMeasure> for MeasureValue {
+        fn from(value: PositivePlaneAngleMeasure) -> Self {
+            MeasureValue::PositivePlaneAngleMeasure(Box::new(value))
+        }
+    }
+    impl From<CountMeasure> for MeasureValue {
+        fn from(value: CountMeasure) -> Self {
+            MeasureValue::CountMeasure(Box::new(value))
+        }
+    }
+    #[doc = " ```text"]
+    #[doc = " TYPE minute_in_hour = INTEGER;"]
+    #[doc = " WHERE"]
+    #[doc = "   wr1: ((0 <= SELF) AND (SELF <= 59));"]
+    #[doc = " END_TYPE; -- minute_in_hour"]
+    #[doc = " ```"]
     #[derive(
         Clone, Debug, PartialEq, AsRef, Deref, DerefMut, Into, From, :: ruststep_derive :: Holder,
     )]
@@ -1849,6 +2768,12 @@ pub mod config_control_design {
     # [holder (field = minute_in_hour)]
     #[holder(generate_deserialize)]
     pub struct MinuteInHour(pub i64);
+    #[doc = " ```text"]
+    #[doc = " TYPE month_in_year_number = INTEGER;"]
+    #[doc = " WHERE"]
+    #[doc = "   wr1: ((1 <= SELF) AND (SELF <= 12));"]
+    #[doc = " END_TYPE; -- month_in_year_number"]
+    #[doc = " ```"]
     #[derive(
         Clone, Debug, PartialEq, AsRef, Deref, DerefMut, Into, From, :: ruststep_derive :: Holder,
     )]
@@ -1856,6 +2781,10 @@ pub mod config_control_design {
     # [holder (field = month_in_year_number)]
     #[holder(generate_deserialize)]
     pub struct MonthInYearNumber(pub i64);
+    #[doc = " ```text"]
+    #[doc = " TYPE parameter_value = REAL;"]
+    #[doc = " END_TYPE; -- parameter_value"]
+    #[doc = " ```"]
     #[derive(
         Clone, Debug, PartialEq, AsRef, Deref, DerefMut, Into, From, :: ruststep_derive :: Holder,
     )]
@@ -1863,6 +2792,12 @@ pub mod config_control_design {
     # [holder (field = parameter_value)]
     #[holder(generate_deserialize)]
     pub struct ParameterValue(pub f64);
+    #[doc = " ```text"]
+    #[doc = " TYPE pcurve_or_surface = SELECT"]
+    #[doc = "   (pcurve,"]
+    #[doc = "    surface);"]
+    #[doc = " END_TYPE; -- pcurve_or_surface"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
     #[holder(generate_deserialize)]
@@ -1872,6 +2807,30 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         Surface(SurfaceAny),
     }
+    impl From<PcurveAny> for PcurveOrSurface {
+        fn from(value: PcurveAny) -> Self {
+            PcurveOrSurface::Pcurve(value)
+        }
+    }
+    impl From<SurfaceAny> for PcurveOrSurface {
+        fn from(value: SurfaceAny) -> Self {
+            PcurveOrSurface::Surface(value)
+        }
+    }
+    #[doc = " ```text"]
+    #[doc = " TYPE person_organization_item = SELECT"]
+    #[doc = "   (change,"]
+    #[doc = "    start_work,"]
+    #[doc = "    change_request,"]
+    #[doc = "    start_request,"]
+    #[doc = "    configuration_item,"]
+    #[doc = "    product,"]
+    #[doc = "    product_definition_formation,"]
+    #[doc = "    product_definition,"]
+    #[doc = "    contract,"]
+    #[doc = "    security_classification);"]
+    #[doc = " END_TYPE; -- person_organization_item"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
     #[holder(generate_deserialize)]
@@ -1897,6 +2856,63 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         SecurityClassification(Box<SecurityClassification>),
     }
+    impl From<Change> for PersonOrganizationItem {
+        fn from(value: Change) -> Self {
+            PersonOrganizationItem::Change(Box::new(value))
+        }
+    }
+    impl From<StartWork> for PersonOrganizationItem {
+        fn from(value: StartWork) -> Self {
+            PersonOrganizationItem::StartWork(Box::new(value))
+        }
+    }
+    impl From<ChangeRequest> for PersonOrganizationItem {
+        fn from(value: ChangeRequest) -> Self {
+            PersonOrganizationItem::ChangeRequest(Box::new(value))
+        }
+    }
+    impl From<StartRequest> for PersonOrganizationItem {
+        fn from(value: StartRequest) -> Self {
+            PersonOrganizationItem::StartRequest(Box::new(value))
+        }
+    }
+    impl From<ConfigurationItem> for PersonOrganizationItem {
+        fn from(value: ConfigurationItem) -> Self {
+            PersonOrganizationItem::ConfigurationItem(Box::new(value))
+        }
+    }
+    impl From<Product> for PersonOrganizationItem {
+        fn from(value: Product) -> Self {
+            PersonOrganizationItem::Product(Box::new(value))
+        }
+    }
+    impl From<ProductDefinitionFormationAny> for PersonOrganizationItem {
+        fn from(value: ProductDefinitionFormationAny) -> Self {
+            PersonOrganizationItem::ProductDefinitionFormation(value)
+        }
+    }
+    impl From<ProductDefinitionAny> for PersonOrganizationItem {
+        fn from(value: ProductDefinitionAny) -> Self {
+            PersonOrganizationItem::ProductDefinition(value)
+        }
+    }
+    impl From<Contract> for PersonOrganizationItem {
+        fn from(value: Contract) -> Self {
+            PersonOrganizationItem::Contract(Box::new(value))
+        }
+    }
+    impl From<SecurityClassification> for PersonOrganizationItem {
+        fn from(value: SecurityClassification) -> Self {
+            PersonOrganizationItem::SecurityClassification(Box::new(value))
+        }
+    }
+    #[doc = " ```text"]
+    #[doc = " TYPE person_organization_select = SELECT"]
+    #[doc = "   (person,"]
+    #[doc = "    organization,"]
+    #[doc = "    person_and_organization);"]
+    #[doc = " END_TYPE; -- person_organization_select"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
     #[holder(generate_deserialize)]
@@ -1908,6 +2924,25 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         PersonAndOrganization(Box<PersonAndOrganization>),
     }
+    impl From<Person> for PersonOrganizationSelect {
+        fn from(value: Person) -> Self {
+            PersonOrganizationSelect::Person(Box::new(value))
+        }
+    }
+    impl From<Organization> for PersonOrganizationSelect {
+        fn from(value: Organization) -> Self {
+            PersonOrganizationSelect::Organization(Box::new(value))
+        }
+    }
+    impl From<PersonAndOrganization> for PersonOrganizationSelect {
+        fn from(value: PersonAndOrganization) -> Self {
+            PersonOrganizationSelect::PersonAndOrganization(Box::new(value))
+        }
+    }
+    #[doc = " ```text"]
+    #[doc = " TYPE plane_angle_measure = REAL;"]
+    #[doc = " END_TYPE; -- plane_angle_measure"]
+    #[doc = " ```"]
     #[derive(
         Clone, Debug, PartialEq, AsRef, Deref, DerefMut, Into, From, :: ruststep_derive :: Holder,
     )]
@@ -1915,6 +2950,12 @@ pub mod config_control_design {
     # [holder (field = plane_angle_measure)]
     #[holder(generate_deserialize)]
     pub struct PlaneAngleMeasure(pub f64);
+    #[doc = " ```text"]
+    #[doc = " TYPE positive_length_measure = length_measure;"]
+    #[doc = " WHERE"]
+    #[doc = "   wr1: (SELF > 0);"]
+    #[doc = " END_TYPE; -- positive_length_measure"]
+    #[doc = " ```"]
     #[derive(
         Clone, Debug, PartialEq, AsRef, Deref, DerefMut, Into, From, :: ruststep_derive :: Holder,
     )]
@@ -1922,6 +2963,12 @@ pub mod config_control_design {
     # [holder (field = positive_length_measure)]
     #[holder(generate_deserialize)]
     pub struct PositiveLengthMeasure(#[holder(use_place_holder)] pub LengthMeasure);
+    #[doc = " ```text"]
+    #[doc = " TYPE positive_plane_angle_measure = plane_angle_measure;"]
+    #[doc = " WHERE"]
+    #[doc = "   wr1: (SELF > 0);"]
+    #[doc = " END_TYPE; -- positive_plane_angle_measure"]
+    #[doc = " ```"]
     #[derive(
         Clone, Debug, PartialEq, AsRef, Deref, DerefMut, Into, From, :: ruststep_derive :: Holder,
     )]
@@ -1929,12 +2976,26 @@ pub mod config_control_design {
     # [holder (field = positive_plane_angle_measure)]
     #[holder(generate_deserialize)]
     pub struct PositivePlaneAngleMeasure(#[holder(use_place_holder)] pub PlaneAngleMeasure);
+    #[doc = " ```text"]
+    #[doc = " TYPE preferred_surface_curve_representation = ENUMERATION OF"]
+    #[doc = "   (curve_3d,"]
+    #[doc = "    pcurve_s1,"]
+    #[doc = "    pcurve_s2);"]
+    #[doc = " END_TYPE; -- preferred_surface_curve_representation"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: serde :: Deserialize)]
     pub enum PreferredSurfaceCurveRepresentation {
         Curve3D,
         PcurveS1,
         PcurveS2,
     }
+    #[doc = " ```text"]
+    #[doc = " TYPE reversible_topology = SELECT"]
+    #[doc = "   (reversible_topology_item,"]
+    #[doc = "    list_of_reversible_topology_item,"]
+    #[doc = "    set_of_reversible_topology_item);"]
+    #[doc = " END_TYPE; -- reversible_topology"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
     #[holder(generate_deserialize)]
@@ -1946,6 +3007,31 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         SetOfReversibleTopologyItem(Box<SetOfReversibleTopologyItem>),
     }
+    impl From<ReversibleTopologyItem> for ReversibleTopology {
+        fn from(value: ReversibleTopologyItem) -> Self {
+            ReversibleTopology::ReversibleTopologyItem(Box::new(value))
+        }
+    }
+    impl From<ListOfReversibleTopologyItem> for ReversibleTopology {
+        fn from(value: ListOfReversibleTopologyItem) -> Self {
+            ReversibleTopology::ListOfReversibleTopologyItem(Box::new(value))
+        }
+    }
+    impl From<SetOfReversibleTopologyItem> for ReversibleTopology {
+        fn from(value: SetOfReversibleTopologyItem) -> Self {
+            ReversibleTopology::SetOfReversibleTopologyItem(Box::new(value))
+        }
+    }
+    #[doc = " ```text"]
+    #[doc = " TYPE reversible_topology_item = SELECT"]
+    #[doc = "   (edge,"]
+    #[doc = "    path,"]
+    #[doc = "    face,"]
+    #[doc = "    face_bound,"]
+    #[doc = "    closed_shell,"]
+    #[doc = "    open_shell);"]
+    #[doc = " END_TYPE; -- reversible_topology_item"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
     #[holder(generate_deserialize)]
@@ -1963,6 +3049,42 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         OpenShell(OpenShellAny),
     }
+    impl From<EdgeAny> for ReversibleTopologyItem {
+        fn from(value: EdgeAny) -> Self {
+            ReversibleTopologyItem::Edge(value)
+        }
+    }
+    impl From<PathAny> for ReversibleTopologyItem {
+        fn from(value: PathAny) -> Self {
+            ReversibleTopologyItem::Path(value)
+        }
+    }
+    impl From<FaceAny> for ReversibleTopologyItem {
+        fn from(value: FaceAny) -> Self {
+            ReversibleTopologyItem::Face(value)
+        }
+    }
+    impl From<FaceBoundAny> for ReversibleTopologyItem {
+        fn from(value: FaceBoundAny) -> Self {
+            ReversibleTopologyItem::FaceBound(value)
+        }
+    }
+    impl From<ClosedShellAny> for ReversibleTopologyItem {
+        fn from(value: ClosedShellAny) -> Self {
+            ReversibleTopologyItem::ClosedShell(value)
+        }
+    }
+    impl From<OpenShellAny> for ReversibleTopologyItem {
+        fn from(value: OpenShellAny) -> Self {
+            ReversibleTopologyItem::OpenShell(value)
+        }
+    }
+    #[doc = " ```text"]
+    #[doc = " TYPE second_in_minute = REAL;"]
+    #[doc = " WHERE"]
+    #[doc = "   wr1: ((0 <= SELF) AND (SELF < 60));"]
+    #[doc = " END_TYPE; -- second_in_minute"]
+    #[doc = " ```"]
     #[derive(
         Clone, Debug, PartialEq, AsRef, Deref, DerefMut, Into, From, :: ruststep_derive :: Holder,
     )]
@@ -1970,6 +3092,11 @@ pub mod config_control_design {
     # [holder (field = second_in_minute)]
     #[holder(generate_deserialize)]
     pub struct SecondInMinute(pub f64);
+    #[doc = " ```text"]
+    #[doc = " TYPE set_of_reversible_topology_item = SET [0:?] OF"]
+    #[doc = "             reversible_topology_item;"]
+    #[doc = " END_TYPE; -- set_of_reversible_topology_item"]
+    #[doc = " ```"]
     #[derive(
         Clone, Debug, PartialEq, AsRef, Deref, DerefMut, Into, From, :: ruststep_derive :: Holder,
     )]
@@ -1979,6 +3106,13 @@ pub mod config_control_design {
     pub struct SetOfReversibleTopologyItem(
         #[holder(use_place_holder)] pub Vec<ReversibleTopologyItem>,
     );
+    #[doc = " ```text"]
+    #[doc = " TYPE shape_definition = SELECT"]
+    #[doc = "   (product_definition_shape,"]
+    #[doc = "    shape_aspect,"]
+    #[doc = "    shape_aspect_relationship);"]
+    #[doc = " END_TYPE; -- shape_definition"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
     #[holder(generate_deserialize)]
@@ -1990,6 +3124,29 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         ShapeAspectRelationship(Box<ShapeAspectRelationship>),
     }
+    impl From<ProductDefinitionShape> for ShapeDefinition {
+        fn from(value: ProductDefinitionShape) -> Self {
+            ShapeDefinition::ProductDefinitionShape(Box::new(value))
+        }
+    }
+    impl From<ShapeAspect> for ShapeDefinition {
+        fn from(value: ShapeAspect) -> Self {
+            ShapeDefinition::ShapeAspect(Box::new(value))
+        }
+    }
+    impl From<ShapeAspectRelationship> for ShapeDefinition {
+        fn from(value: ShapeAspectRelationship) -> Self {
+            ShapeDefinition::ShapeAspectRelationship(Box::new(value))
+        }
+    }
+    #[doc = " ```text"]
+    #[doc = " TYPE shell = SELECT"]
+    #[doc = "   (vertex_shell,"]
+    #[doc = "    wire_shell,"]
+    #[doc = "    open_shell,"]
+    #[doc = "    closed_shell);"]
+    #[doc = " END_TYPE; -- shell"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
     #[holder(generate_deserialize)]
@@ -2003,6 +3160,46 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         ClosedShell(ClosedShellAny),
     }
+    impl From<VertexShell> for Shell {
+        fn from(value: VertexShell) -> Self {
+            Shell::VertexShell(Box::new(value))
+        }
+    }
+    impl From<WireShell> for Shell {
+        fn from(value: WireShell) -> Self {
+            Shell::WireShell(Box::new(value))
+        }
+    }
+    impl From<OpenShellAny> for Shell {
+        fn from(value: OpenShellAny) -> Self {
+            Shell::OpenShell(value)
+        }
+    }
+    impl From<ClosedShellAny> for Shell {
+        fn from(value: ClosedShellAny) -> Self {
+            Shell::ClosedShell(value)
+        }
+    }
+    #[doc = " ```text"]
+    #[doc = " TYPE si_prefix = ENUMERATION OF"]
+    #[doc = "   (exa,"]
+    #[doc = "    peta,"]
+    #[doc = "    tera,"]
+    #[doc = "    giga,"]
+    #[doc = "    mega,"]
+    #[doc = "    kilo,"]
+    #[doc = "    hecto,"]
+    #[doc = "    deca,"]
+    #[doc = "    deci,"]
+    #[doc = "    centi,"]
+    #[doc = "    milli,"]
+    #[doc = "    micro,"]
+    #[doc = "    nano,"]
+    #[doc = "    pico,"]
+    #[doc = "    femto,"]
+    #[doc = "    atto);"]
+    #[doc = " END_TYPE; -- si_prefix"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: serde :: Deserialize)]
     pub enum SiPrefix {
         Exa,
@@ -2022,6 +3219,38 @@ pub mod config_control_design {
         Femto,
         Atto,
     }
+    #[doc = " ```text"]
+    #[doc = " TYPE si_unit_name = ENUMERATION OF"]
+    #[doc = "   (metre,"]
+    #[doc = "    gram,"]
+    #[doc = "    second,"]
+    #[doc = "    ampere,"]
+    #[doc = "    kelvin,"]
+    #[doc = "    mole,"]
+    #[doc = "    candela,"]
+    #[doc = "    radian,"]
+    #[doc = "    steradian,"]
+    #[doc = "    hertz,"]
+    #[doc = "    newton,"]
+    #[doc = "    pascal,"]
+    #[doc = "    joule,"]
+    #[doc = "    watt,"]
+    #[doc = "    coulomb,"]
+    #[doc = "    volt,"]
+    #[doc = "    farad,"]
+    #[doc = "    ohm,"]
+    #[doc = "    siemens,"]
+    #[doc = "    weber,"]
+    #[doc = "    tesla,"]
+    #[doc = "    henry,"]
+    #[doc = "    degree_celsius,"]
+    #[doc = "    lumen,"]
+    #[doc = "    lux,"]
+    #[doc = "    becquerel,"]
+    #[doc = "    gray,"]
+    #[doc = "    sievert);"]
+    #[doc = " END_TYPE; -- si_unit_name"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: serde :: Deserialize)]
     pub enum SiUnitName {
         Metre,
@@ -2053,6 +3282,10 @@ pub mod config_control_design {
         Gray,
         Sievert,
     }
+    #[doc = " ```text"]
+    #[doc = " TYPE solid_angle_measure = REAL;"]
+    #[doc = " END_TYPE; -- solid_angle_measure"]
+    #[doc = " ```"]
     #[derive(
         Clone, Debug, PartialEq, AsRef, Deref, DerefMut, Into, From, :: ruststep_derive :: Holder,
     )]
@@ -2060,12 +3293,25 @@ pub mod config_control_design {
     # [holder (field = solid_angle_measure)]
     #[holder(generate_deserialize)]
     pub struct SolidAngleMeasure(pub f64);
+    #[doc = " ```text"]
+    #[doc = " TYPE source = ENUMERATION OF"]
+    #[doc = "   (made,"]
+    #[doc = "    bought,"]
+    #[doc = "    not_known);"]
+    #[doc = " END_TYPE; -- source"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: serde :: Deserialize)]
     pub enum Source {
         Made,
         Bought,
         NotKnown,
     }
+    #[doc = " ```text"]
+    #[doc = " TYPE specified_item = SELECT"]
+    #[doc = "   (product_definition,"]
+    #[doc = "    shape_aspect);"]
+    #[doc = " END_TYPE; -- specified_item"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
     #[holder(generate_deserialize)]
@@ -2075,6 +3321,21 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         ShapeAspect(Box<ShapeAspect>),
     }
+    impl From<ProductDefinitionAny> for SpecifiedItem {
+        fn from(value: ProductDefinitionAny) -> Self {
+            SpecifiedItem::ProductDefinition(value)
+        }
+    }
+    impl From<ShapeAspect> for SpecifiedItem {
+        fn from(value: ShapeAspect) -> Self {
+            SpecifiedItem::ShapeAspect(Box::new(value))
+        }
+    }
+    #[doc = " ```text"]
+    #[doc = " TYPE start_request_item = SELECT"]
+    #[doc = "   (product_definition_formation);"]
+    #[doc = " END_TYPE; -- start_request_item"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
     #[holder(generate_deserialize)]
@@ -2082,6 +3343,18 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         ProductDefinitionFormation(ProductDefinitionFormationAny),
     }
+    impl From<ProductDefinitionFormationAny> for StartRequestItem {
+        fn from(value: ProductDefinitionFormationAny) -> Self {
+            StartRequestItem::ProductDefinitionFormation(value)
+        }
+    }
+    #[doc = " ```text"]
+    #[doc = " TYPE supported_item = SELECT"]
+    #[doc = "   (action_directive,"]
+    #[doc = "    action,"]
+    #[doc = "    action_method);"]
+    #[doc = " END_TYPE; -- supported_item"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
     #[holder(generate_deserialize)]
@@ -2093,6 +3366,26 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         ActionMethod(Box<ActionMethod>),
     }
+    impl From<ActionDirective> for SupportedItem {
+        fn from(value: ActionDirective) -> Self {
+            SupportedItem::ActionDirective(Box::new(value))
+        }
+    }
+    impl From<ActionAny> for SupportedItem {
+        fn from(value: ActionAny) -> Self {
+            SupportedItem::Action(value)
+        }
+    }
+    impl From<ActionMethod> for SupportedItem {
+        fn from(value: ActionMethod) -> Self {
+            SupportedItem::ActionMethod(Box::new(value))
+        }
+    }
+    #[doc = " ```text"]
+    #[doc = " TYPE surface_model = SELECT"]
+    #[doc = "   (shell_based_surface_model);"]
+    #[doc = " END_TYPE; -- surface_model"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
     #[holder(generate_deserialize)]
@@ -2100,6 +3393,15 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         ShellBasedSurfaceModel(Box<ShellBasedSurfaceModel>),
     }
+    impl From<ShellBasedSurfaceModel> for SurfaceModel {
+        fn from(value: ShellBasedSurfaceModel) -> Self {
+            SurfaceModel::ShellBasedSurfaceModel(Box::new(value))
+        }
+    }
+    #[doc = " ```text"]
+    #[doc = " TYPE text = STRING;"]
+    #[doc = " END_TYPE; -- text"]
+    #[doc = " ```"]
     #[derive(
         Clone, Debug, PartialEq, AsRef, Deref, DerefMut, Into, From, :: ruststep_derive :: Holder,
     )]
@@ -2107,6 +3409,12 @@ pub mod config_control_design {
     # [holder (field = text)]
     #[holder(generate_deserialize)]
     pub struct Text(pub String);
+    #[doc = " ```text"]
+    #[doc = " TYPE transformation = SELECT"]
+    #[doc = "   (item_defined_transformation,"]
+    #[doc = "    functionally_defined_transformation);"]
+    #[doc = " END_TYPE; -- transformation"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
     #[holder(generate_deserialize)]
@@ -2116,6 +3424,24 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         FunctionallyDefinedTransformation(FunctionallyDefinedTransformationAny),
     }
+    impl From<ItemDefinedTransformation> for Transformation {
+        fn from(value: ItemDefinedTransformation) -> Self {
+            Transformation::ItemDefinedTransformation(Box::new(value))
+        }
+    }
+    impl From<FunctionallyDefinedTransformationAny> for Transformation {
+        fn from(value: FunctionallyDefinedTransformationAny) -> Self {
+            Transformation::FunctionallyDefinedTransformation(value)
+        }
+    }
+    #[doc = " ```text"]
+    #[doc = " TYPE transition_code = ENUMERATION OF"]
+    #[doc = "   (discontinuous,"]
+    #[doc = "    continuous,"]
+    #[doc = "    cont_same_gradient,"]
+    #[doc = "    cont_same_gradient_same_curvature);"]
+    #[doc = " END_TYPE; -- transition_code"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: serde :: Deserialize)]
     pub enum TransitionCode {
         Discontinuous,
@@ -2123,12 +3449,25 @@ pub mod config_control_design {
         ContSameGradient,
         ContSameGradientSameCurvature,
     }
+    #[doc = " ```text"]
+    #[doc = " TYPE trimming_preference = ENUMERATION OF"]
+    #[doc = "   (cartesian,"]
+    #[doc = "    parameter,"]
+    #[doc = "    unspecified);"]
+    #[doc = " END_TYPE; -- trimming_preference"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: serde :: Deserialize)]
     pub enum TrimmingPreference {
         Cartesian,
         Parameter,
         Unspecified,
     }
+    #[doc = " ```text"]
+    #[doc = " TYPE trimming_select = SELECT"]
+    #[doc = "   (cartesian_point,"]
+    #[doc = "    parameter_value);"]
+    #[doc = " END_TYPE; -- trimming_select"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
     #[holder(generate_deserialize)]
@@ -2138,6 +3477,21 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         ParameterValue(Box<ParameterValue>),
     }
+    impl From<CartesianPoint> for TrimmingSelect {
+        fn from(value: CartesianPoint) -> Self {
+            TrimmingSelect::CartesianPoint(Box::new(value))
+        }
+    }
+    impl From<ParameterValue> for TrimmingSelect {
+        fn from(value: ParameterValue) -> Self {
+            TrimmingSelect::ParameterValue(Box::new(value))
+        }
+    }
+    #[doc = " ```text"]
+    #[doc = " TYPE unit = SELECT"]
+    #[doc = "   (named_unit);"]
+    #[doc = " END_TYPE; -- unit"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
     #[holder(generate_deserialize)]
@@ -2145,6 +3499,17 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         NamedUnit(NamedUnitAny),
     }
+    impl From<NamedUnitAny> for Unit {
+        fn from(value: NamedUnitAny) -> Self {
+            Unit::NamedUnit(value)
+        }
+    }
+    #[doc = " ```text"]
+    #[doc = " TYPE vector_or_direction = SELECT"]
+    #[doc = "   (vector,"]
+    #[doc = "    direction);"]
+    #[doc = " END_TYPE; -- vector_or_direction"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
     #[holder(generate_deserialize)]
@@ -2154,6 +3519,20 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         Direction(Box<Direction>),
     }
+    impl From<Vector> for VectorOrDirection {
+        fn from(value: Vector) -> Self {
+            VectorOrDirection::Vector(Box::new(value))
+        }
+    }
+    impl From<Direction> for VectorOrDirection {
+        fn from(value: Direction) -> Self {
+            VectorOrDirection::Direction(Box::new(value))
+        }
+    }
+    #[doc = " ```text"]
+    #[doc = " TYPE volume_measure = REAL;"]
+    #[doc = " END_TYPE; -- volume_measure"]
+    #[doc = " ```"]
     #[derive(
         Clone, Debug, PartialEq, AsRef, Deref, DerefMut, Into, From, :: ruststep_derive :: Holder,
     )]
@@ -2161,6 +3540,12 @@ pub mod config_control_design {
     # [holder (field = volume_measure)]
     #[holder(generate_deserialize)]
     pub struct VolumeMeasure(pub f64);
+    #[doc = " ```text"]
+    #[doc = " TYPE week_in_year_number = INTEGER;"]
+    #[doc = " WHERE"]
+    #[doc = "   wr1: ((1 <= SELF) AND (SELF <= 53));"]
+    #[doc = " END_TYPE; -- week_in_year_number"]
+    #[doc = " ```"]
     #[derive(
         Clone, Debug, PartialEq, AsRef, Deref, DerefMut, Into, From, :: ruststep_derive :: Holder,
     )]
@@ -2168,6 +3553,12 @@ pub mod config_control_design {
     # [holder (field = week_in_year_number)]
     #[holder(generate_deserialize)]
     pub struct WeekInYearNumber(pub i64);
+    #[doc = " ```text"]
+    #[doc = " TYPE wireframe_model = SELECT"]
+    #[doc = "   (shell_based_wireframe_model,"]
+    #[doc = "    edge_based_wireframe_model);"]
+    #[doc = " END_TYPE; -- wireframe_model"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
     #[holder(generate_deserialize)]
@@ -2177,6 +3568,21 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         EdgeBasedWireframeModel(Box<EdgeBasedWireframeModel>),
     }
+    impl From<ShellBasedWireframeModel> for WireframeModel {
+        fn from(value: ShellBasedWireframeModel) -> Self {
+            WireframeModel::ShellBasedWireframeModel(Box::new(value))
+        }
+    }
+    impl From<EdgeBasedWireframeModel> for WireframeModel {
+        fn from(value: EdgeBasedWireframeModel) -> Self {
+            WireframeModel::EdgeBasedWireframeModel(Box::new(value))
+        }
+    }
+    #[doc = " ```text"]
+    #[doc = " TYPE work_item = SELECT"]
+    #[doc = "   (product_definition_formation);"]
+    #[doc = " END_TYPE; -- work_item"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
     #[holder(generate_deserialize)]
@@ -2184,6 +3590,15 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         ProductDefinitionFormation(ProductDefinitionFormationAny),
     }
+    impl From<ProductDefinitionFormationAny> for WorkItem {
+        fn from(value: ProductDefinitionFormationAny) -> Self {
+            WorkItem::ProductDefinitionFormation(value)
+        }
+    }
+    #[doc = " ```text"]
+    #[doc = " TYPE year_number = INTEGER;"]
+    #[doc = " END_TYPE; -- year_number"]
+    #[doc = " ```"]
     #[derive(
         Clone, Debug, PartialEq, AsRef, Deref, DerefMut, Into, From, :: ruststep_derive :: Holder,
     )]
@@ -2191,6 +3606,13 @@ pub mod config_control_design {
     # [holder (field = year_number)]
     #[holder(generate_deserialize)]
     pub struct YearNumber(pub i64);
+    #[doc = " ```text"]
+    #[doc = " ENTITY action;"]
+    #[doc = "     name          : label;"]
+    #[doc = "     description   : text;"]
+    #[doc = "     chosen_method : action_method;"]
+    #[doc = " END_ENTITY; -- action"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = action)]
@@ -2230,6 +3652,12 @@ pub mod config_control_design {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY action_assignment"]
+    #[doc = "   ABSTRACT SUPERTYPE;"]
+    #[doc = "     assigned_action : action;"]
+    #[doc = " END_ENTITY; -- action_assignment"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = action_assignment)]
@@ -2273,6 +3701,15 @@ pub mod config_control_design {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY action_directive;"]
+    #[doc = "     name        : label;"]
+    #[doc = "     description : text;"]
+    #[doc = "     analysis    : text;"]
+    #[doc = "     comment     : text;"]
+    #[doc = "     requests    : SET [1:?] OF versioned_action_request;"]
+    #[doc = " END_ENTITY; -- action_directive"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = action_directive)]
@@ -2287,8 +3724,17 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub comment: Text,
         #[holder(use_place_holder)]
+        # [holder (size = 1 ..)]
         pub requests: Vec<VersionedActionRequest>,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY action_method;"]
+    #[doc = "     name        : label;"]
+    #[doc = "     description : text;"]
+    #[doc = "     consequence : text;"]
+    #[doc = "     purpose     : text;"]
+    #[doc = " END_ENTITY; -- action_method"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = action_method)]
@@ -2303,6 +3749,12 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub purpose: Text,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY action_request_assignment"]
+    #[doc = "   ABSTRACT SUPERTYPE;"]
+    #[doc = "     assigned_action_request : versioned_action_request;"]
+    #[doc = " END_ENTITY; -- action_request_assignment"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = action_request_assignment)]
@@ -2346,6 +3798,12 @@ pub mod config_control_design {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY action_request_solution;"]
+    #[doc = "     method  : action_method;"]
+    #[doc = "     request : versioned_action_request;"]
+    #[doc = " END_ENTITY; -- action_request_solution"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = action_request_solution)]
@@ -2356,6 +3814,12 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub request: VersionedActionRequest,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY action_request_status;"]
+    #[doc = "     status           : label;"]
+    #[doc = "     assigned_request : versioned_action_request;"]
+    #[doc = " END_ENTITY; -- action_request_status"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = action_request_status)]
@@ -2366,6 +3830,12 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub assigned_request: VersionedActionRequest,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY action_status;"]
+    #[doc = "     status          : label;"]
+    #[doc = "     assigned_action : executed_action;"]
+    #[doc = " END_ENTITY; -- action_status"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = action_status)]
@@ -2376,6 +3846,28 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub assigned_action: ExecutedActionAny,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY address;"]
+    #[doc = "     internal_location       : OPTIONAL label;"]
+    #[doc = "     street_number           : OPTIONAL label;"]
+    #[doc = "     street                  : OPTIONAL label;"]
+    #[doc = "     postal_box              : OPTIONAL label;"]
+    #[doc = "     town                    : OPTIONAL label;"]
+    #[doc = "     region                  : OPTIONAL label;"]
+    #[doc = "     postal_code             : OPTIONAL label;"]
+    #[doc = "     country                 : OPTIONAL label;"]
+    #[doc = "     facsimile_number        : OPTIONAL label;"]
+    #[doc = "     telephone_number        : OPTIONAL label;"]
+    #[doc = "     electronic_mail_address : OPTIONAL label;"]
+    #[doc = "     telex_number            : OPTIONAL label;"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: (EXISTS(internal_location) OR EXISTS(street_number) OR EXISTS("]
+    #[doc = "              street) OR EXISTS(postal_box) OR EXISTS(town) OR EXISTS("]
+    #[doc = "              region) OR EXISTS(postal_code) OR EXISTS(country) OR EXISTS("]
+    #[doc = "              facsimile_number) OR EXISTS(telephone_number) OR EXISTS("]
+    #[doc = "              electronic_mail_address) OR EXISTS(telex_number));"]
+    #[doc = " END_ENTITY; -- address"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = address)]
@@ -2441,6 +3933,41 @@ pub mod config_control_design {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY advanced_brep_shape_representation"]
+    #[doc = "   SUBTYPE OF (shape_representation);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: (SIZEOF(QUERY ( it <* SELF.items | (NOT (SIZEOF(["]
+    #[doc = "              'CONFIG_CONTROL_DESIGN.MANIFOLD_SOLID_BREP',"]
+    #[doc = "              'CONFIG_CONTROL_DESIGN.FACETED_BREP',"]
+    #[doc = "              'CONFIG_CONTROL_DESIGN.MAPPED_ITEM',"]
+    #[doc = "              'CONFIG_CONTROL_DESIGN.AXIS2_PLACEMENT_3D'] * TYPEOF(it)) ="]
+    #[doc = "              1)) )) = 0);"]
+    #[doc = "     wr2: (SIZEOF(QUERY ( it <* SELF.items | (SIZEOF(["]
+    #[doc = "              'CONFIG_CONTROL_DESIGN.MANIFOLD_SOLID_BREP',"]
+    #[doc = "              'CONFIG_CONTROL_DESIGN.MAPPED_ITEM'] * TYPEOF(it)) = 1) )) >"]
+    #[doc = "               0);"]
+    #[doc = "     wr3: (SIZEOF(QUERY ( msb <* QUERY ( it <* SELF.items | ("]
+    #[doc = "              'CONFIG_CONTROL_DESIGN.MANIFOLD_SOLID_BREP' IN TYPEOF(it)) )"]
+    #[doc = "               | (NOT (SIZEOF(QUERY ( csh <* msb_shells(msb) | (NOT ("]
+    #[doc = "              SIZEOF(QUERY ( fcs <* csh\\connected_face_set.cfs_faces | ("]
+    #[doc = "              NOT ('CONFIG_CONTROL_DESIGN.ADVANCED_FACE'"]
+    #[doc = "              IN TYPEOF(fcs))) )) = 0)) )) = 0)) )) = 0);"]
+    #[doc = "     wr4: (SIZEOF(QUERY ( msb <* QUERY ( it <* items | ("]
+    #[doc = "              'CONFIG_CONTROL_DESIGN.MANIFOLD_SOLID_BREP' IN TYPEOF(it)) )"]
+    #[doc = "               | ('CONFIG_CONTROL_DESIGN.ORIENTED_CLOSED_SHELL' IN TYPEOF("]
+    #[doc = "              msb\\manifold_solid_brep.outer)) )) = 0);"]
+    #[doc = "     wr5: (SIZEOF(QUERY ( brv <* QUERY ( it <* items | ("]
+    #[doc = "              'CONFIG_CONTROL_DESIGN.BREP_WITH_VOIDS' IN TYPEOF(it)) ) | ("]
+    #[doc = "              NOT (SIZEOF(QUERY ( csh <* brv\\brep_with_voids.voids | csh\\"]
+    #[doc = "              oriented_closed_shell.orientation )) = 0)) )) = 0);"]
+    #[doc = "     wr6: (SIZEOF(QUERY ( mi <* QUERY ( it <* items | ("]
+    #[doc = "              'CONFIG_CONTROL_DESIGN.MAPPED_ITEM' IN TYPEOF(it)) ) | (NOT"]
+    #[doc = "              ('CONFIG_CONTROL_DESIGN.ADVANCED_BREP_SHAPE_REPRESENTATION'"]
+    #[doc = "              IN TYPEOF(mi\\mapped_item.mapping_source."]
+    #[doc = "              mapped_representation))) )) = 0);"]
+    #[doc = " END_ENTITY; -- advanced_brep_shape_representation"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -2455,6 +3982,85 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub shape_representation: ShapeRepresentation,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY advanced_face"]
+    #[doc = "   SUBTYPE OF (face_surface);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1 : (SIZEOF(['CONFIG_CONTROL_DESIGN.ELEMENTARY_SURFACE',"]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.B_SPLINE_SURFACE',"]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.SWEPT_SURFACE'] * TYPEOF("]
+    #[doc = "               face_geometry)) = 1);"]
+    #[doc = "     wr2 : (SIZEOF(QUERY ( elp_fbnds <* QUERY ( bnds <* bounds | ("]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.EDGE_LOOP' IN TYPEOF(bnds.bound)) )"]
+    #[doc = "                | (NOT (SIZEOF(QUERY ( oe <* elp_fbnds.bound\\path."]
+    #[doc = "               edge_list | (NOT ('CONFIG_CONTROL_DESIGN.EDGE_CURVE' IN"]
+    #[doc = "               TYPEOF(oe\\oriented_edge.edge_element))) )) = 0)) )) = 0);"]
+    #[doc = "     wr3 : (SIZEOF(QUERY ( elp_fbnds <* QUERY ( bnds <* bounds | ("]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.EDGE_LOOP' IN TYPEOF(bnds.bound)) )"]
+    #[doc = "                | (NOT (SIZEOF(QUERY ( oe <* elp_fbnds.bound\\path."]
+    #[doc = "               edge_list | (NOT (SIZEOF(['CONFIG_CONTROL_DESIGN.LINE',"]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.CONIC',"]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.POLYLINE',"]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.SURFACE_CURVE',"]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.B_SPLINE_CURVE'] * TYPEOF(oe."]
+    #[doc = "               edge_element\\edge_curve.edge_geometry)) = 1)) )) = 0)) )) ="]
+    #[doc = "                0);"]
+    #[doc = "     wr4 : (SIZEOF(QUERY ( elp_fbnds <* QUERY ( bnds <* bounds | ("]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.EDGE_LOOP' IN TYPEOF(bnds.bound)) )"]
+    #[doc = "                | (NOT (SIZEOF(QUERY ( oe <* elp_fbnds.bound\\path."]
+    #[doc = "               edge_list | (NOT (('CONFIG_CONTROL_DESIGN.VERTEX_POINT' IN"]
+    #[doc = "               TYPEOF(oe\\edge.edge_start)) AND ("]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.CARTESIAN_POINT' IN TYPEOF(oe\\edge."]
+    #[doc = "               edge_start\\vertex_point.vertex_geometry)) AND ("]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.VERTEX_POINT' IN TYPEOF(oe\\edge."]
+    #[doc = "               edge_end)) AND ('CONFIG_CONTROL_DESIGN.CARTESIAN_POINT' IN"]
+    #[doc = "               TYPEOF(oe\\edge.edge_end\\vertex_point.vertex_geometry)))) ))"]
+    #[doc = "               = 0)) )) = 0);"]
+    #[doc = "     wr5 : (SIZEOF(QUERY ( elp_fbnds <* QUERY ( bnds <* bounds | ("]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.EDGE_LOOP' IN TYPEOF(bnds.bound)) )"]
+    #[doc = "                | ('CONFIG_CONTROL_DESIGN.ORIENTED_PATH' IN TYPEOF("]
+    #[doc = "               elp_fbnds.bound)) )) = 0);"]
+    #[doc = "     wr6 : ((NOT ('CONFIG_CONTROL_DESIGN.SWEPT_SURFACE' IN TYPEOF("]
+    #[doc = "               face_geometry))) OR (SIZEOF(['CONFIG_CONTROL_DESIGN.LINE',"]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.CONIC',"]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.POLYLINE',"]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.B_SPLINE_CURVE'] * TYPEOF("]
+    #[doc = "               face_geometry\\swept_surface.swept_curve)) = 1));"]
+    #[doc = "     wr7 : (SIZEOF(QUERY ( vlp_fbnds <* QUERY ( bnds <* bounds | ("]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.VERTEX_LOOP'"]
+    #[doc = "               IN TYPEOF(bnds.bound)) )"]
+    #[doc = "                | (NOT (('CONFIG_CONTROL_DESIGN.VERTEX_POINT' IN TYPEOF("]
+    #[doc = "               vlp_fbnds\\face_bound.bound\\vertex_loop.loop_vertex)) AND ("]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.CARTESIAN_POINT' IN TYPEOF(vlp_fbnds"]
+    #[doc = "               \\face_bound.bound\\vertex_loop.loop_vertex\\vertex_point."]
+    #[doc = "               vertex_geometry)))) )) = 0);"]
+    #[doc = "     wr8 : (SIZEOF(QUERY ( bnd <* bounds | (NOT (SIZEOF(["]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.EDGE_LOOP',"]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.VERTEX_LOOP'] * TYPEOF(bnd.bound)) ="]
+    #[doc = "                1)) )) = 0);"]
+    #[doc = "     wr9 : (SIZEOF(QUERY ( elp_fbnds <* QUERY ( bnds <* bounds | ("]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.EDGE_LOOP' IN TYPEOF(bnds.bound)) )"]
+    #[doc = "                | (NOT (SIZEOF(QUERY ( oe <* elp_fbnds.bound\\path."]
+    #[doc = "               edge_list | (('CONFIG_CONTROL_DESIGN.SURFACE_CURVE' IN"]
+    #[doc = "               TYPEOF(oe\\oriented_edge.edge_element\\edge_curve."]
+    #[doc = "               edge_geometry)) AND (NOT (SIZEOF(QUERY ( sc_ag <* oe."]
+    #[doc = "               edge_element\\edge_curve.edge_geometry\\surface_curve."]
+    #[doc = "               associated_geometry | (NOT ('CONFIG_CONTROL_DESIGN.PCURVE'"]
+    #[doc = "               IN TYPEOF(sc_ag))) )) = 0))) )) = 0)) )) = 0);"]
+    #[doc = "     wr10: (((NOT ('CONFIG_CONTROL_DESIGN.SWEPT_SURFACE' IN TYPEOF("]
+    #[doc = "               face_geometry))) OR (NOT ('CONFIG_CONTROL_DESIGN.POLYLINE'"]
+    #[doc = "               IN TYPEOF(face_geometry\\swept_surface.swept_curve))) OR ("]
+    #[doc = "               SIZEOF(face_geometry\\swept_surface.swept_curve\\polyline."]
+    #[doc = "               points) >= 3)) AND (SIZEOF(QUERY ( elp_fbnds <*"]
+    #[doc = "               QUERY ( bnds <* bounds | ('CONFIG_CONTROL_DESIGN.EDGE_LOOP'"]
+    #[doc = "               IN TYPEOF(bnds.bound)) ) | (NOT (SIZEOF(QUERY ( oe <*"]
+    #[doc = "               elp_fbnds.bound\\path.edge_list | (("]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.POLYLINE' IN TYPEOF(oe\\oriented_edge"]
+    #[doc = "               .edge_element\\edge_curve.edge_geometry)) AND (NOT (SIZEOF("]
+    #[doc = "               oe\\oriented_edge.edge_element\\edge_curve.edge_geometry\\"]
+    #[doc = "               polyline.points) >= 3))) )) = 0)) )) = 0));"]
+    #[doc = " END_ENTITY; -- advanced_face"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -2469,6 +4075,19 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub face_surface: FaceSurface,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY alternate_product_relationship;"]
+    #[doc = "     name       : label;"]
+    #[doc = "     definition : text;"]
+    #[doc = "     alternate  : product;"]
+    #[doc = "     base       : product;"]
+    #[doc = "     basis      : text;"]
+    #[doc = "   UNIQUE"]
+    #[doc = "     ur1 : alternate, base;"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: (alternate :<>: base);"]
+    #[doc = " END_ENTITY; -- alternate_product_relationship"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = alternate_product_relationship)]
@@ -2485,6 +4104,14 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub basis: Text,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY application_context;"]
+    #[doc = "     application : text;"]
+    #[doc = "   INVERSE"]
+    #[doc = "     context_elements : SET [1:?] OF application_context_element FOR"]
+    #[doc = "                           frame_of_reference;"]
+    #[doc = " END_ENTITY; -- application_context"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = application_context)]
@@ -2493,6 +4120,14 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub application: Text,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY application_context_element"]
+    #[doc = "   SUPERTYPE OF (ONEOF (product_context,product_definition_context,"]
+    #[doc = "       product_concept_context));"]
+    #[doc = "     name               : label;"]
+    #[doc = "     frame_of_reference : application_context;"]
+    #[doc = " END_ENTITY; -- application_context_element"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = application_context_element)]
@@ -2546,6 +4181,14 @@ pub mod config_control_design {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY application_protocol_definition;"]
+    #[doc = "     status                                    : label;"]
+    #[doc = "     application_interpreted_model_schema_name : label;"]
+    #[doc = "     application_protocol_year                 : year_number;"]
+    #[doc = "     application                               : application_context;"]
+    #[doc = " END_ENTITY; -- application_protocol_definition"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = application_protocol_definition)]
@@ -2560,6 +4203,12 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub application: ApplicationContext,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY approval;"]
+    #[doc = "     status : approval_status;"]
+    #[doc = "     level  : label;"]
+    #[doc = " END_ENTITY; -- approval"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = approval)]
@@ -2570,6 +4219,12 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub level: Label,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY approval_assignment"]
+    #[doc = "   ABSTRACT SUPERTYPE;"]
+    #[doc = "     assigned_approval : approval;"]
+    #[doc = " END_ENTITY; -- approval_assignment"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = approval_assignment)]
@@ -2605,6 +4260,12 @@ pub mod config_control_design {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY approval_date_time;"]
+    #[doc = "     date_time      : date_time_select;"]
+    #[doc = "     dated_approval : approval;"]
+    #[doc = " END_ENTITY; -- approval_date_time"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = approval_date_time)]
@@ -2615,6 +4276,13 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub dated_approval: Approval,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY approval_person_organization;"]
+    #[doc = "     person_organization : person_organization_select;"]
+    #[doc = "     authorized_approval : approval;"]
+    #[doc = "     role                : approval_role;"]
+    #[doc = " END_ENTITY; -- approval_person_organization"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = approval_person_organization)]
@@ -2627,6 +4295,14 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub role: ApprovalRole,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY approval_relationship;"]
+    #[doc = "     name              : label;"]
+    #[doc = "     description       : text;"]
+    #[doc = "     relating_approval : approval;"]
+    #[doc = "     related_approval  : approval;"]
+    #[doc = " END_ENTITY; -- approval_relationship"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = approval_relationship)]
@@ -2641,6 +4317,11 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub related_approval: Approval,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY approval_role;"]
+    #[doc = "     role : label;"]
+    #[doc = " END_ENTITY; -- approval_role"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = approval_role)]
@@ -2649,6 +4330,11 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub role: Label,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY approval_status;"]
+    #[doc = "     name : label;"]
+    #[doc = " END_ENTITY; -- approval_status"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = approval_status)]
@@ -2657,6 +4343,14 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub name: Label,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY area_measure_with_unit"]
+    #[doc = "   SUBTYPE OF (measure_with_unit);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: ('CONFIG_CONTROL_DESIGN.AREA_UNIT' IN TYPEOF(SELF\\"]
+    #[doc = "              measure_with_unit.unit_component));"]
+    #[doc = " END_ENTITY; -- area_measure_with_unit"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -2671,6 +4365,20 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub measure_with_unit: MeasureWithUnit,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY area_unit"]
+    #[doc = "   SUBTYPE OF (named_unit);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: ((SELF\\named_unit.dimensions.length_exponent = 2) AND (SELF\\"]
+    #[doc = "              named_unit.dimensions.mass_exponent = 0) AND (SELF\\"]
+    #[doc = "              named_unit.dimensions.time_exponent = 0) AND (SELF\\"]
+    #[doc = "              named_unit.dimensions.electric_current_exponent = 0) AND ("]
+    #[doc = "              SELF\\named_unit.dimensions."]
+    #[doc = "              thermodynamic_temperature_exponent = 0) AND (SELF\\named_unit"]
+    #[doc = "              .dimensions.amount_of_substance_exponent = 0) AND (SELF\\"]
+    #[doc = "              named_unit.dimensions.luminous_intensity_exponent = 0));"]
+    #[doc = " END_ENTITY; -- area_unit"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -2685,6 +4393,14 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub named_unit: NamedUnit,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY assembly_component_usage"]
+    #[doc = "   SUPERTYPE OF (ONEOF (next_assembly_usage_occurrence,"]
+    #[doc = "       specified_higher_usage_occurrence,promissory_usage_occurrence))"]
+    #[doc = "   SUBTYPE OF (product_definition_usage);"]
+    #[doc = "     reference_designator : OPTIONAL identifier;"]
+    #[doc = " END_ENTITY; -- assembly_component_usage"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -2773,6 +4489,20 @@ pub mod config_control_design {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY assembly_component_usage_substitute;"]
+    #[doc = "     name       : label;"]
+    #[doc = "     definition : text;"]
+    #[doc = "     base       : assembly_component_usage;"]
+    #[doc = "     substitute : assembly_component_usage;"]
+    #[doc = "   UNIQUE"]
+    #[doc = "     ur1 : base, substitute;"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: (base.relating_product_definition :=: substitute."]
+    #[doc = "              relating_product_definition);"]
+    #[doc = "     wr2: (base :<>: substitute);"]
+    #[doc = " END_ENTITY; -- assembly_component_usage_substitute"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = assembly_component_usage_substitute)]
@@ -2787,6 +4517,17 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub substitute: AssemblyComponentUsageAny,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY axis1_placement"]
+    #[doc = "   SUBTYPE OF (placement);"]
+    #[doc = "     axis : OPTIONAL direction;"]
+    #[doc = "   DERIVE"]
+    #[doc = "     z : direction := NVL(normalise(axis),dummy_gri ||"]
+    #[doc = "            direction([0,0,1]));"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: (SELF\\geometric_representation_item.dim = 3);"]
+    #[doc = " END_ENTITY; -- axis1_placement"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -2803,6 +4544,16 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub axis: Option<Direction>,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY axis2_placement_2d"]
+    #[doc = "   SUBTYPE OF (placement);"]
+    #[doc = "     ref_direction : OPTIONAL direction;"]
+    #[doc = "   DERIVE"]
+    #[doc = "     p : LIST [2:2] OF direction := build_2axes(ref_direction);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: (SELF\\geometric_representation_item.dim = 2);"]
+    #[doc = " END_ENTITY; -- axis2_placement_2d"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -2819,6 +4570,21 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub ref_direction: Option<Direction>,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY axis2_placement_3d"]
+    #[doc = "   SUBTYPE OF (placement);"]
+    #[doc = "     axis          : OPTIONAL direction;"]
+    #[doc = "     ref_direction : OPTIONAL direction;"]
+    #[doc = "   DERIVE"]
+    #[doc = "     p : LIST [3:3] OF direction := build_axes(axis,ref_direction);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: (SELF\\placement.location.dim = 3);"]
+    #[doc = "     wr2: ((NOT EXISTS(axis)) OR (axis.dim = 3));"]
+    #[doc = "     wr3: ((NOT EXISTS(ref_direction)) OR (ref_direction.dim = 3));"]
+    #[doc = "     wr4: ((NOT EXISTS(axis)) OR (NOT EXISTS(ref_direction)) OR ("]
+    #[doc = "              cross_product(axis,ref_direction).magnitude > 0));"]
+    #[doc = " END_ENTITY; -- axis2_placement_3d"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -2837,6 +4603,32 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub ref_direction: Option<Direction>,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY b_spline_curve"]
+    #[doc = "   SUPERTYPE OF (ONEOF (uniform_curve,b_spline_curve_with_knots,"]
+    #[doc = "       quasi_uniform_curve,bezier_curve) ANDOR rational_b_spline_curve)"]
+    #[doc = "   SUBTYPE OF (bounded_curve);"]
+    #[doc = "     degree              : INTEGER;"]
+    #[doc = "     control_points_list : LIST [2:?] OF cartesian_point;"]
+    #[doc = "     curve_form          : b_spline_curve_form;"]
+    #[doc = "     closed_curve        : LOGICAL;"]
+    #[doc = "     self_intersect      : LOGICAL;"]
+    #[doc = "   DERIVE"]
+    #[doc = "     upper_index_on_control_points : INTEGER := SIZEOF("]
+    #[doc = "                                        control_points_list) - 1;"]
+    #[doc = "     control_points                : ARRAY [0:"]
+    #[doc = "                                        upper_index_on_control_points] OF"]
+    #[doc = "                                         cartesian_point := list_to_array("]
+    #[doc = "                                        control_points_list,0,"]
+    #[doc = "                                        upper_index_on_control_points);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: (('CONFIG_CONTROL_DESIGN.UNIFORM_CURVE' IN TYPEOF(SELF)) OR ("]
+    #[doc = "              'CONFIG_CONTROL_DESIGN.QUASI_UNIFORM_CURVE' IN TYPEOF(SELF))"]
+    #[doc = "              OR ('CONFIG_CONTROL_DESIGN.BEZIER_CURVE' IN TYPEOF(SELF)) OR"]
+    #[doc = "               ('CONFIG_CONTROL_DESIGN.B_SPLINE_CURVE_WITH_KNOTS' IN"]
+    #[doc = "              TYPEOF(SELF)));"]
+    #[doc = " END_ENTITY; -- b_spline_curve"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -2852,6 +4644,7 @@ pub mod config_control_design {
         pub bounded_curve: BoundedCurve,
         pub degree: i64,
         #[holder(use_place_holder)]
+        # [holder (size = 2 ..)]
         pub control_points_list: Vec<CartesianPoint>,
         pub curve_form: BSplineCurveForm,
         pub closed_curve: Logical,
@@ -2938,6 +4731,20 @@ pub mod config_control_design {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY b_spline_curve_with_knots"]
+    #[doc = "   SUBTYPE OF (b_spline_curve);"]
+    #[doc = "     knot_multiplicities : LIST [2:?] OF INTEGER;"]
+    #[doc = "     knots               : LIST [2:?] OF parameter_value;"]
+    #[doc = "     knot_spec           : knot_type;"]
+    #[doc = "   DERIVE"]
+    #[doc = "     upper_index_on_knots : INTEGER := SIZEOF(knots);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: constraints_param_b_spline(degree,upper_index_on_knots,"]
+    #[doc = "              upper_index_on_control_points,knot_multiplicities,knots);"]
+    #[doc = "     wr2: (SIZEOF(knot_multiplicities) = upper_index_on_knots);"]
+    #[doc = " END_ENTITY; -- b_spline_curve_with_knots"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -2951,11 +4758,41 @@ pub mod config_control_design {
         #[deref_mut]
         #[holder(use_place_holder)]
         pub b_spline_curve: BSplineCurve,
+        # [holder (size = 2 ..)]
         pub knot_multiplicities: Vec<i64>,
         #[holder(use_place_holder)]
+        # [holder (size = 2 ..)]
         pub knots: Vec<ParameterValue>,
         pub knot_spec: KnotType,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY b_spline_surface"]
+    #[doc = "   SUPERTYPE OF (ONEOF (b_spline_surface_with_knots,uniform_surface,"]
+    #[doc = "       quasi_uniform_surface,bezier_surface) ANDOR"]
+    #[doc = "       rational_b_spline_surface)"]
+    #[doc = "   SUBTYPE OF (bounded_surface);"]
+    #[doc = "     u_degree            : INTEGER;"]
+    #[doc = "     v_degree            : INTEGER;"]
+    #[doc = "     control_points_list : LIST [2:?] OF LIST [2:?] OF cartesian_point;"]
+    #[doc = "     surface_form        : b_spline_surface_form;"]
+    #[doc = "     u_closed            : LOGICAL;"]
+    #[doc = "     v_closed            : LOGICAL;"]
+    #[doc = "     self_intersect      : LOGICAL;"]
+    #[doc = "   DERIVE"]
+    #[doc = "     u_upper        : INTEGER := SIZEOF(control_points_list) - 1;"]
+    #[doc = "     v_upper        : INTEGER := SIZEOF(control_points_list[1]) - 1;"]
+    #[doc = "     control_points : ARRAY [0:u_upper] OF ARRAY [0:v_upper] OF"]
+    #[doc = "                          cartesian_point := make_array_of_array("]
+    #[doc = "                         control_points_list,0,u_upper,0,v_upper);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: (('CONFIG_CONTROL_DESIGN.UNIFORM_SURFACE' IN TYPEOF(SELF)) OR ("]
+    #[doc = "              'CONFIG_CONTROL_DESIGN.QUASI_UNIFORM_SURFACE' IN"]
+    #[doc = "              TYPEOF(SELF))"]
+    #[doc = "              OR ('CONFIG_CONTROL_DESIGN.BEZIER_SURFACE' IN TYPEOF(SELF))"]
+    #[doc = "              OR ('CONFIG_CONTROL_DESIGN.B_SPLINE_SURFACE_WITH_KNOTS' IN"]
+    #[doc = "              TYPEOF(SELF)));"]
+    #[doc = " END_ENTITY; -- b_spline_surface"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -2972,6 +4809,7 @@ pub mod config_control_design {
         pub u_degree: i64,
         pub v_degree: i64,
         #[holder(use_place_holder)]
+        # [holder (size = 2 ..)]
         pub control_points_list: Vec<Vec<CartesianPoint>>,
         pub surface_form: BSplineSurfaceForm,
         pub u_closed: Logical,
@@ -3059,6 +4897,28 @@ pub mod config_control_design {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY b_spline_surface_with_knots"]
+    #[doc = "   SUBTYPE OF (b_spline_surface);"]
+    #[doc = "     u_multiplicities : LIST [2:?] OF INTEGER;"]
+    #[doc = "     v_multiplicities : LIST [2:?] OF INTEGER;"]
+    #[doc = "     u_knots          : LIST [2:?] OF parameter_value;"]
+    #[doc = "     v_knots          : LIST [2:?] OF parameter_value;"]
+    #[doc = "     knot_spec        : knot_type;"]
+    #[doc = "   DERIVE"]
+    #[doc = "     knot_u_upper : INTEGER := SIZEOF(u_knots);"]
+    #[doc = "     knot_v_upper : INTEGER := SIZEOF(v_knots);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: constraints_param_b_spline(SELF\\b_spline_surface.u_degree,"]
+    #[doc = "              knot_u_upper,SELF\\b_spline_surface.u_upper,u_multiplicities,"]
+    #[doc = "              u_knots);"]
+    #[doc = "     wr2: constraints_param_b_spline(SELF\\b_spline_surface.v_degree,"]
+    #[doc = "              knot_v_upper,SELF\\b_spline_surface.v_upper,v_multiplicities,"]
+    #[doc = "              v_knots);"]
+    #[doc = "     wr3: (SIZEOF(u_multiplicities) = knot_u_upper);"]
+    #[doc = "     wr4: (SIZEOF(v_multiplicities) = knot_v_upper);"]
+    #[doc = " END_ENTITY; -- b_spline_surface_with_knots"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -3072,14 +4932,23 @@ pub mod config_control_design {
         #[deref_mut]
         #[holder(use_place_holder)]
         pub b_spline_surface: BSplineSurface,
+        # [holder (size = 2 ..)]
         pub u_multiplicities: Vec<i64>,
+        # [holder (size = 2 ..)]
         pub v_multiplicities: Vec<i64>,
         #[holder(use_place_holder)]
+        # [holder (size = 2 ..)]
         pub u_knots: Vec<ParameterValue>,
         #[holder(use_place_holder)]
+        # [holder (size = 2 ..)]
         pub v_knots: Vec<ParameterValue>,
         pub knot_spec: KnotType,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY bezier_curve"]
+    #[doc = "   SUBTYPE OF (b_spline_curve);"]
+    #[doc = " END_ENTITY; -- bezier_curve"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -3094,6 +4963,11 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub b_spline_curve: BSplineCurve,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY bezier_surface"]
+    #[doc = "   SUBTYPE OF (b_spline_surface);"]
+    #[doc = " END_ENTITY; -- bezier_surface"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -3108,6 +4982,13 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub b_spline_surface: BSplineSurface,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY boundary_curve"]
+    #[doc = "   SUBTYPE OF (composite_curve_on_surface);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: SELF\\composite_curve.closed_curve;"]
+    #[doc = " END_ENTITY; -- boundary_curve"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -3159,6 +5040,13 @@ pub mod config_control_design {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY bounded_curve"]
+    #[doc = "   SUPERTYPE OF (ONEOF (polyline,b_spline_curve,trimmed_curve,"]
+    #[doc = "       bounded_pcurve,bounded_surface_curve,composite_curve))"]
+    #[doc = "   SUBTYPE OF (curve);"]
+    #[doc = " END_ENTITY; -- bounded_curve"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -3263,6 +5151,14 @@ pub mod config_control_design {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY bounded_pcurve"]
+    #[doc = "   SUBTYPE OF (pcurve, bounded_curve);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: ('CONFIG_CONTROL_DESIGN.BOUNDED_CURVE' IN TYPEOF(SELF\\pcurve."]
+    #[doc = "              reference_to_curve.items[1]));"]
+    #[doc = " END_ENTITY; -- bounded_pcurve"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut)]
     # [holder (table = Tables)]
     # [holder (field = bounded_pcurve)]
@@ -3277,6 +5173,13 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub bounded_curve: BoundedCurve,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY bounded_surface"]
+    #[doc = "   SUPERTYPE OF (ONEOF (b_spline_surface,rectangular_trimmed_surface,"]
+    #[doc = "       curve_bounded_surface,rectangular_composite_surface))"]
+    #[doc = "   SUBTYPE OF (surface);"]
+    #[doc = " END_ENTITY; -- bounded_surface"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -3361,6 +5264,14 @@ pub mod config_control_design {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY bounded_surface_curve"]
+    #[doc = "   SUBTYPE OF (surface_curve, bounded_curve);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: ('CONFIG_CONTROL_DESIGN.BOUNDED_CURVE' IN TYPEOF(SELF\\"]
+    #[doc = "              surface_curve.curve_3d));"]
+    #[doc = " END_ENTITY; -- bounded_surface_curve"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut)]
     # [holder (table = Tables)]
     # [holder (field = bounded_surface_curve)]
@@ -3375,6 +5286,12 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub bounded_curve: BoundedCurve,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY brep_with_voids"]
+    #[doc = "   SUBTYPE OF (manifold_solid_brep);"]
+    #[doc = "     voids : SET [1:?] OF oriented_closed_shell;"]
+    #[doc = " END_ENTITY; -- brep_with_voids"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -3389,8 +5306,18 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub manifold_solid_brep: ManifoldSolidBrep,
         #[holder(use_place_holder)]
+        # [holder (size = 1 ..)]
         pub voids: Vec<OrientedClosedShell>,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY calendar_date"]
+    #[doc = "   SUBTYPE OF (date);"]
+    #[doc = "     day_component   : day_in_month_number;"]
+    #[doc = "     month_component : month_in_year_number;"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: valid_calendar_date(SELF);"]
+    #[doc = " END_ENTITY; -- calendar_date"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -3409,6 +5336,12 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub month_component: MonthInYearNumber,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY cartesian_point"]
+    #[doc = "   SUBTYPE OF (point);"]
+    #[doc = "     coordinates : LIST [1:3] OF length_measure;"]
+    #[doc = " END_ENTITY; -- cartesian_point"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -3423,8 +5356,24 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub point: Point,
         #[holder(use_place_holder)]
+        # [holder (size = 1 ..= 3)]
         pub coordinates: Vec<LengthMeasure>,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY cartesian_transformation_operator"]
+    #[doc = "   SUPERTYPE OF (cartesian_transformation_operator_3d)"]
+    #[doc = "   SUBTYPE OF (geometric_representation_item,"]
+    #[doc = "       functionally_defined_transformation);"]
+    #[doc = "     axis1        : OPTIONAL direction;"]
+    #[doc = "     axis2        : OPTIONAL direction;"]
+    #[doc = "     local_origin : cartesian_point;"]
+    #[doc = "     scale        : OPTIONAL REAL;"]
+    #[doc = "   DERIVE"]
+    #[doc = "     scl : REAL := NVL(scale,1);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: (scl > 0);"]
+    #[doc = " END_ENTITY; -- cartesian_transformation_operator"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut)]
     # [holder (table = Tables)]
     # [holder (field = cartesian_transformation_operator)]
@@ -3503,6 +5452,18 @@ pub mod config_control_design {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY cartesian_transformation_operator_3d"]
+    #[doc = "   SUBTYPE OF (cartesian_transformation_operator);"]
+    #[doc = "     axis3 : OPTIONAL direction;"]
+    #[doc = "   DERIVE"]
+    #[doc = "     u : LIST [3:3] OF direction := base_axis(3,SELF\\"]
+    #[doc = "            cartesian_transformation_operator.axis1,SELF\\"]
+    #[doc = "            cartesian_transformation_operator.axis2,axis3);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: (SELF\\geometric_representation_item.dim = 3);"]
+    #[doc = " END_ENTITY; -- cartesian_transformation_operator_3d"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -3519,6 +5480,12 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub axis3: Option<Direction>,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY cc_design_approval"]
+    #[doc = "   SUBTYPE OF (approval_assignment);"]
+    #[doc = "     items : SET [1:?] OF approved_item;"]
+    #[doc = " END_ENTITY; -- cc_design_approval"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -3533,8 +5500,15 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub approval_assignment: ApprovalAssignment,
         #[holder(use_place_holder)]
+        # [holder (size = 1 ..)]
         pub items: Vec<ApprovedItem>,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY cc_design_certification"]
+    #[doc = "   SUBTYPE OF (certification_assignment);"]
+    #[doc = "     items : SET [1:?] OF certified_item;"]
+    #[doc = " END_ENTITY; -- cc_design_certification"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -3549,8 +5523,15 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub certification_assignment: CertificationAssignment,
         #[holder(use_place_holder)]
+        # [holder (size = 1 ..)]
         pub items: Vec<CertifiedItem>,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY cc_design_contract"]
+    #[doc = "   SUBTYPE OF (contract_assignment);"]
+    #[doc = "     items : SET [1:?] OF contracted_item;"]
+    #[doc = " END_ENTITY; -- cc_design_contract"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -3565,8 +5546,17 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub contract_assignment: ContractAssignment,
         #[holder(use_place_holder)]
+        # [holder (size = 1 ..)]
         pub items: Vec<ContractedItem>,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY cc_design_date_and_time_assignment"]
+    #[doc = "   SUBTYPE OF (date_and_time_assignment);"]
+    #[doc = "     items : SET [1:?] OF date_time_item;"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: cc_design_date_time_correlation(SELF);"]
+    #[doc = " END_ENTITY; -- cc_design_date_and_time_assignment"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -3581,8 +5571,17 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub date_and_time_assignment: DateAndTimeAssignment,
         #[holder(use_place_holder)]
+        # [holder (size = 1 ..)]
         pub items: Vec<DateTimeItem>,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY cc_design_person_and_organization_assignment"]
+    #[doc = "   SUBTYPE OF (person_and_organization_assignment);"]
+    #[doc = "     items : SET [1:?] OF person_organization_item;"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: cc_design_person_and_organization_correlation(SELF);"]
+    #[doc = " END_ENTITY; -- cc_design_person_and_organization_assignment"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -3597,8 +5596,15 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub person_and_organization_assignment: PersonAndOrganizationAssignment,
         #[holder(use_place_holder)]
+        # [holder (size = 1 ..)]
         pub items: Vec<PersonOrganizationItem>,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY cc_design_security_classification"]
+    #[doc = "   SUBTYPE OF (security_classification_assignment);"]
+    #[doc = "     items : SET [1:?] OF classified_item;"]
+    #[doc = " END_ENTITY; -- cc_design_security_classification"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -3613,8 +5619,15 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub security_classification_assignment: SecurityClassificationAssignment,
         #[holder(use_place_holder)]
+        # [holder (size = 1 ..)]
         pub items: Vec<ClassifiedItem>,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY cc_design_specification_reference"]
+    #[doc = "   SUBTYPE OF (document_reference);"]
+    #[doc = "     items : SET [1:?] OF specified_item;"]
+    #[doc = " END_ENTITY; -- cc_design_specification_reference"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -3629,8 +5642,16 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub document_reference: DocumentReference,
         #[holder(use_place_holder)]
+        # [holder (size = 1 ..)]
         pub items: Vec<SpecifiedItem>,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY certification;"]
+    #[doc = "     name    : label;"]
+    #[doc = "     purpose : text;"]
+    #[doc = "     kind    : certification_type;"]
+    #[doc = " END_ENTITY; -- certification"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = certification)]
@@ -3643,6 +5664,12 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub kind: CertificationType,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY certification_assignment"]
+    #[doc = "   ABSTRACT SUPERTYPE;"]
+    #[doc = "     assigned_certification : certification;"]
+    #[doc = " END_ENTITY; -- certification_assignment"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = certification_assignment)]
@@ -3678,6 +5705,11 @@ pub mod config_control_design {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY certification_type;"]
+    #[doc = "     description : label;"]
+    #[doc = " END_ENTITY; -- certification_type"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = certification_type)]
@@ -3686,6 +5718,12 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub description: Label,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY change"]
+    #[doc = "   SUBTYPE OF (action_assignment);"]
+    #[doc = "     items : SET [1:?] OF work_item;"]
+    #[doc = " END_ENTITY; -- change"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -3700,8 +5738,15 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub action_assignment: ActionAssignment,
         #[holder(use_place_holder)]
+        # [holder (size = 1 ..)]
         pub items: Vec<WorkItem>,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY change_request"]
+    #[doc = "   SUBTYPE OF (action_request_assignment);"]
+    #[doc = "     items : SET [1:?] OF change_request_item;"]
+    #[doc = " END_ENTITY; -- change_request"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -3716,8 +5761,15 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub action_request_assignment: ActionRequestAssignment,
         #[holder(use_place_holder)]
+        # [holder (size = 1 ..)]
         pub items: Vec<ChangeRequestItem>,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY circle"]
+    #[doc = "   SUBTYPE OF (conic);"]
+    #[doc = "     radius : positive_length_measure;"]
+    #[doc = " END_ENTITY; -- circle"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -3734,6 +5786,11 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub radius: PositiveLengthMeasure,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY closed_shell"]
+    #[doc = "   SUBTYPE OF (connected_face_set);"]
+    #[doc = " END_ENTITY; -- closed_shell"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -3785,6 +5842,22 @@ pub mod config_control_design {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY composite_curve"]
+    #[doc = "   SUBTYPE OF (bounded_curve);"]
+    #[doc = "     segments       : LIST [1:?] OF composite_curve_segment;"]
+    #[doc = "     self_intersect : LOGICAL;"]
+    #[doc = "   DERIVE"]
+    #[doc = "     n_segments   : INTEGER := SIZEOF(segments);"]
+    #[doc = "     closed_curve : LOGICAL := segments[n_segments].transition <>"]
+    #[doc = "                       discontinuous;"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: (((NOT closed_curve) AND (SIZEOF(QUERY ( temp <* segments | ("]
+    #[doc = "              temp.transition = discontinuous) )) = 1)) OR (closed_curve"]
+    #[doc = "              AND (SIZEOF(QUERY ( temp <* segments | (temp.transition ="]
+    #[doc = "              discontinuous) )) = 0)));"]
+    #[doc = " END_ENTITY; -- composite_curve"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -3799,6 +5872,7 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub bounded_curve: BoundedCurve,
         #[holder(use_place_holder)]
+        # [holder (size = 1 ..)]
         pub segments: Vec<CompositeCurveSegmentAny>,
         pub self_intersect: Logical,
     }
@@ -3839,6 +5913,17 @@ pub mod config_control_design {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY composite_curve_on_surface"]
+    #[doc = "   SUPERTYPE OF (boundary_curve)"]
+    #[doc = "   SUBTYPE OF (composite_curve);"]
+    #[doc = "   DERIVE"]
+    #[doc = "     basis_surface : SET [0:2] OF surface := get_basis_surface(SELF);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: (SIZEOF(basis_surface) > 0);"]
+    #[doc = "     wr2: constraints_composite_curve_on_surface(SELF);"]
+    #[doc = " END_ENTITY; -- composite_curve_on_surface"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -3892,6 +5977,18 @@ pub mod config_control_design {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY composite_curve_segment"]
+    #[doc = "   SUBTYPE OF (founded_item);"]
+    #[doc = "     transition   : transition_code;"]
+    #[doc = "     same_sense   : BOOLEAN;"]
+    #[doc = "     parent_curve : curve;"]
+    #[doc = "   INVERSE"]
+    #[doc = "     using_curves : BAG [1:?] OF composite_curve FOR segments;"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: ('CONFIG_CONTROL_DESIGN.BOUNDED_CURVE' IN TYPEOF(parent_curve));"]
+    #[doc = " END_ENTITY; -- composite_curve_segment"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -3949,6 +6046,14 @@ pub mod config_control_design {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY configuration_design;"]
+    #[doc = "     configuration : configuration_item;"]
+    #[doc = "     design        : product_definition_formation;"]
+    #[doc = "   UNIQUE"]
+    #[doc = "     ur1 : configuration, design;"]
+    #[doc = " END_ENTITY; -- configuration_design"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = configuration_design)]
@@ -3959,6 +6064,17 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub design: ProductDefinitionFormationAny,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY configuration_effectivity"]
+    #[doc = "   SUBTYPE OF (product_definition_effectivity);"]
+    #[doc = "     configuration : configuration_design;"]
+    #[doc = "   UNIQUE"]
+    #[doc = "     ur1 : configuration, usage, id;"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: ('CONFIG_CONTROL_DESIGN.PRODUCT_DEFINITION_USAGE' IN TYPEOF("]
+    #[doc = "              SELF\\product_definition_effectivity.usage));"]
+    #[doc = " END_ENTITY; -- configuration_effectivity"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -3975,6 +6091,17 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub configuration: ConfigurationDesign,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY configuration_item;"]
+    #[doc = "     id           : identifier;"]
+    #[doc = "     name         : label;"]
+    #[doc = "     description  : OPTIONAL text;"]
+    #[doc = "     item_concept : product_concept;"]
+    #[doc = "     purpose      : OPTIONAL label;"]
+    #[doc = "   UNIQUE"]
+    #[doc = "     ur1 : id;"]
+    #[doc = " END_ENTITY; -- configuration_item"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = configuration_item)]
@@ -3991,6 +6118,13 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub purpose: Option<Label>,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY conic"]
+    #[doc = "   SUPERTYPE OF (ONEOF (circle,ellipse,hyperbola,parabola))"]
+    #[doc = "   SUBTYPE OF (curve);"]
+    #[doc = "     position : axis2_placement;"]
+    #[doc = " END_ENTITY; -- conic"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -4069,6 +6203,15 @@ pub mod config_control_design {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY conical_surface"]
+    #[doc = "   SUBTYPE OF (elementary_surface);"]
+    #[doc = "     radius     : length_measure;"]
+    #[doc = "     semi_angle : plane_angle_measure;"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: (radius >= 0);"]
+    #[doc = " END_ENTITY; -- conical_surface"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -4087,6 +6230,12 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub semi_angle: PlaneAngleMeasure,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY connected_edge_set"]
+    #[doc = "   SUBTYPE OF (topological_representation_item);"]
+    #[doc = "     ces_edges : SET [1:?] OF edge;"]
+    #[doc = " END_ENTITY; -- connected_edge_set"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -4101,8 +6250,16 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub topological_representation_item: TopologicalRepresentationItem,
         #[holder(use_place_holder)]
+        # [holder (size = 1 ..)]
         pub ces_edges: Vec<EdgeAny>,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY connected_face_set"]
+    #[doc = "   SUPERTYPE OF (ONEOF (closed_shell,open_shell))"]
+    #[doc = "   SUBTYPE OF (topological_representation_item);"]
+    #[doc = "     cfs_faces : SET [1:?] OF face;"]
+    #[doc = " END_ENTITY; -- connected_face_set"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -4116,8 +6273,10 @@ pub mod config_control_design {
         #[deref_mut]
         #[holder(use_place_holder)]
         pub topological_representation_item: TopologicalRepresentationItem,
+        #[doc = " `None` in instances of a subtype redeclaring this attribute as derived"]
         #[holder(use_place_holder)]
-        pub cfs_faces: Vec<FaceAny>,
+        # [holder (size = 1 ..)]
+        pub cfs_faces: Option<Vec<FaceAny>>,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
@@ -4169,6 +6328,15 @@ pub mod config_control_design {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY context_dependent_shape_representation;"]
+    #[doc = "     representation_relation      : shape_representation_relationship;"]
+    #[doc = "     represented_product_relation : product_definition_shape;"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: ('CONFIG_CONTROL_DESIGN.PRODUCT_DEFINITION_RELATIONSHIP' IN"]
+    #[doc = "              TYPEOF(SELF.represented_product_relation.definition));"]
+    #[doc = " END_ENTITY; -- context_dependent_shape_representation"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = context_dependent_shape_representation)]
@@ -4179,6 +6347,12 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub represented_product_relation: ProductDefinitionShape,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY context_dependent_unit"]
+    #[doc = "   SUBTYPE OF (named_unit);"]
+    #[doc = "     name : label;"]
+    #[doc = " END_ENTITY; -- context_dependent_unit"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -4195,6 +6369,13 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub name: Label,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY contract;"]
+    #[doc = "     name    : label;"]
+    #[doc = "     purpose : text;"]
+    #[doc = "     kind    : contract_type;"]
+    #[doc = " END_ENTITY; -- contract"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = contract)]
@@ -4207,6 +6388,12 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub kind: ContractType,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY contract_assignment"]
+    #[doc = "   ABSTRACT SUPERTYPE;"]
+    #[doc = "     assigned_contract : contract;"]
+    #[doc = " END_ENTITY; -- contract_assignment"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = contract_assignment)]
@@ -4242,6 +6429,11 @@ pub mod config_control_design {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY contract_type;"]
+    #[doc = "     description : label;"]
+    #[doc = " END_ENTITY; -- contract_type"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = contract_type)]
@@ -4250,6 +6442,13 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub description: Label,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY conversion_based_unit"]
+    #[doc = "   SUBTYPE OF (named_unit);"]
+    #[doc = "     name              : label;"]
+    #[doc = "     conversion_factor : measure_with_unit;"]
+    #[doc = " END_ENTITY; -- conversion_based_unit"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -4268,6 +6467,13 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub conversion_factor: MeasureWithUnitAny,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY coordinated_universal_time_offset;"]
+    #[doc = "     hour_offset   : hour_in_day;"]
+    #[doc = "     minute_offset : OPTIONAL minute_in_hour;"]
+    #[doc = "     sense         : ahead_or_behind;"]
+    #[doc = " END_ENTITY; -- coordinated_universal_time_offset"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = coordinated_universal_time_offset)]
@@ -4279,6 +6485,13 @@ pub mod config_control_design {
         pub minute_offset: Option<MinuteInHour>,
         pub sense: AheadOrBehind,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY curve"]
+    #[doc = "   SUPERTYPE OF (ONEOF (line,conic,pcurve,surface_curve,offset_curve_3d,"]
+    #[doc = "       curve_replica))"]
+    #[doc = "   SUBTYPE OF (geometric_representation_item);"]
+    #[doc = " END_ENTITY; -- curve"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -4382,6 +6595,27 @@ pub mod config_control_design {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY curve_bounded_surface"]
+    #[doc = "   SUBTYPE OF (bounded_surface);"]
+    #[doc = "     basis_surface  : surface;"]
+    #[doc = "     boundaries     : SET [1:?] OF boundary_curve;"]
+    #[doc = "     implicit_outer : BOOLEAN;"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: (NOT (implicit_outer AND ("]
+    #[doc = "              'CONFIG_CONTROL_DESIGN.OUTER_BOUNDARY_CURVE' IN TYPEOF("]
+    #[doc = "              boundaries))));"]
+    #[doc = "     wr2: ((NOT implicit_outer) OR ("]
+    #[doc = "              'CONFIG_CONTROL_DESIGN.BOUNDED_SURFACE' IN TYPEOF("]
+    #[doc = "              basis_surface)));"]
+    #[doc = "     wr3: (SIZEOF(QUERY ( temp <* boundaries | ("]
+    #[doc = "              'CONFIG_CONTROL_DESIGN.OUTER_BOUNDARY_CURVE'"]
+    #[doc = "              IN TYPEOF(temp)) )) <= 1);"]
+    #[doc = "     wr4: (SIZEOF(QUERY ( temp <* boundaries | (temp\\"]
+    #[doc = "              composite_curve_on_surface.basis_surface[1] <> SELF."]
+    #[doc = "              basis_surface) )) = 0);"]
+    #[doc = " END_ENTITY; -- curve_bounded_surface"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -4398,9 +6632,20 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub basis_surface: SurfaceAny,
         #[holder(use_place_holder)]
+        # [holder (size = 1 ..)]
         pub boundaries: Vec<BoundaryCurveAny>,
         pub implicit_outer: bool,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY curve_replica"]
+    #[doc = "   SUBTYPE OF (curve);"]
+    #[doc = "     parent_curve   : curve;"]
+    #[doc = "     transformation : cartesian_transformation_operator;"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: (transformation.dim = parent_curve.dim);"]
+    #[doc = "     wr2: acyclic_curve_replica(SELF,parent_curve);"]
+    #[doc = " END_ENTITY; -- curve_replica"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -4419,6 +6664,12 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub transformation: CartesianTransformationOperatorAny,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY cylindrical_surface"]
+    #[doc = "   SUBTYPE OF (elementary_surface);"]
+    #[doc = "     radius : positive_length_measure;"]
+    #[doc = " END_ENTITY; -- cylindrical_surface"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -4435,6 +6686,13 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub radius: PositiveLengthMeasure,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY date"]
+    #[doc = "   SUPERTYPE OF (ONEOF (calendar_date,ordinal_date,"]
+    #[doc = "       week_of_year_and_day_date));"]
+    #[doc = "     year_component : year_number;"]
+    #[doc = " END_ENTITY; -- date"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = date)]
@@ -4486,6 +6744,12 @@ pub mod config_control_design {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY date_and_time;"]
+    #[doc = "     date_component : date;"]
+    #[doc = "     time_component : local_time;"]
+    #[doc = " END_ENTITY; -- date_and_time"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = date_and_time)]
@@ -4496,6 +6760,13 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub time_component: LocalTime,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY date_and_time_assignment"]
+    #[doc = "   ABSTRACT SUPERTYPE;"]
+    #[doc = "     assigned_date_and_time : date_and_time;"]
+    #[doc = "     role                   : date_time_role;"]
+    #[doc = " END_ENTITY; -- date_and_time_assignment"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = date_and_time_assignment)]
@@ -4533,6 +6804,11 @@ pub mod config_control_design {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY date_time_role;"]
+    #[doc = "     name : label;"]
+    #[doc = " END_ENTITY; -- date_time_role"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = date_time_role)]
@@ -4541,6 +6817,13 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub name: Label,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY dated_effectivity"]
+    #[doc = "   SUBTYPE OF (effectivity);"]
+    #[doc = "     effectivity_start_date : date_and_time;"]
+    #[doc = "     effectivity_end_date   : OPTIONAL date_and_time;"]
+    #[doc = " END_ENTITY; -- dated_effectivity"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -4559,6 +6842,14 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub effectivity_end_date: Option<DateAndTime>,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY definitional_representation"]
+    #[doc = "   SUBTYPE OF (representation);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: ('CONFIG_CONTROL_DESIGN.PARAMETRIC_REPRESENTATION_CONTEXT' IN"]
+    #[doc = "              TYPEOF(SELF\\representation.context_of_items));"]
+    #[doc = " END_ENTITY; -- definitional_representation"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -4573,6 +6864,19 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub representation: Representation,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY degenerate_pcurve"]
+    #[doc = "   SUBTYPE OF (point);"]
+    #[doc = "     basis_surface      : surface;"]
+    #[doc = "     reference_to_curve : definitional_representation;"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: (SIZEOF(reference_to_curve\\representation.items) = 1);"]
+    #[doc = "     wr2: ('CONFIG_CONTROL_DESIGN.CURVE' IN TYPEOF(reference_to_curve\\"]
+    #[doc = "              representation.items[1]));"]
+    #[doc = "     wr3: (reference_to_curve\\representation.items[1]\\"]
+    #[doc = "              geometric_representation_item.dim = 2);"]
+    #[doc = " END_ENTITY; -- degenerate_pcurve"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -4630,6 +6934,14 @@ pub mod config_control_design {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY degenerate_toroidal_surface"]
+    #[doc = "   SUBTYPE OF (toroidal_surface);"]
+    #[doc = "     select_outer : BOOLEAN;"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: (major_radius < minor_radius);"]
+    #[doc = " END_ENTITY; -- degenerate_toroidal_surface"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -4645,6 +6957,13 @@ pub mod config_control_design {
         pub toroidal_surface: ToroidalSurface,
         pub select_outer: bool,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY design_context"]
+    #[doc = "   SUBTYPE OF (product_definition_context);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: (SELF.life_cycle_stage = 'design');"]
+    #[doc = " END_ENTITY; -- design_context"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -4659,6 +6978,11 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub product_definition_context: ProductDefinitionContext,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY design_make_from_relationship"]
+    #[doc = "   SUBTYPE OF (product_definition_relationship);"]
+    #[doc = " END_ENTITY; -- design_make_from_relationship"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -4673,6 +6997,17 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub product_definition_relationship: ProductDefinitionRelationship,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY dimensional_exponents;"]
+    #[doc = "     length_exponent                    : REAL;"]
+    #[doc = "     mass_exponent                      : REAL;"]
+    #[doc = "     time_exponent                      : REAL;"]
+    #[doc = "     electric_current_exponent          : REAL;"]
+    #[doc = "     thermodynamic_temperature_exponent : REAL;"]
+    #[doc = "     amount_of_substance_exponent       : REAL;"]
+    #[doc = "     luminous_intensity_exponent        : REAL;"]
+    #[doc = " END_ENTITY; -- dimensional_exponents"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = dimensional_exponents)]
@@ -4686,6 +7021,12 @@ pub mod config_control_design {
         pub amount_of_substance_exponent: f64,
         pub luminous_intensity_exponent: f64,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY directed_action"]
+    #[doc = "   SUBTYPE OF (executed_action);"]
+    #[doc = "     directive : action_directive;"]
+    #[doc = " END_ENTITY; -- directed_action"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -4702,6 +7043,14 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub directive: ActionDirective,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY direction"]
+    #[doc = "   SUBTYPE OF (geometric_representation_item);"]
+    #[doc = "     direction_ratios : LIST [2:3] OF REAL;"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: (SIZEOF(QUERY ( tmp <* direction_ratios | (tmp <> 0) )) > 0);"]
+    #[doc = " END_ENTITY; -- direction"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -4715,8 +7064,19 @@ pub mod config_control_design {
         #[deref_mut]
         #[holder(use_place_holder)]
         pub geometric_representation_item: GeometricRepresentationItem,
+        # [holder (size = 2 ..= 3)]
         pub direction_ratios: Vec<f64>,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY document;"]
+    #[doc = "     id          : identifier;"]
+    #[doc = "     name        : label;"]
+    #[doc = "     description : text;"]
+    #[doc = "     kind        : document_type;"]
+    #[doc = "   UNIQUE"]
+    #[doc = "     ur1 : id;"]
+    #[doc = " END_ENTITY; -- document"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = document)]
@@ -4758,6 +7118,13 @@ pub mod config_control_design {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY document_reference"]
+    #[doc = "   ABSTRACT SUPERTYPE;"]
+    #[doc = "     assigned_document : document;"]
+    #[doc = "     source            : label;"]
+    #[doc = " END_ENTITY; -- document_reference"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = document_reference)]
@@ -4795,6 +7162,14 @@ pub mod config_control_design {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY document_relationship;"]
+    #[doc = "     name              : label;"]
+    #[doc = "     description       : text;"]
+    #[doc = "     relating_document : document;"]
+    #[doc = "     related_document  : document;"]
+    #[doc = " END_ENTITY; -- document_relationship"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = document_relationship)]
@@ -4809,6 +7184,11 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub related_document: DocumentAny,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY document_type;"]
+    #[doc = "     product_data_type : label;"]
+    #[doc = " END_ENTITY; -- document_type"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = document_type)]
@@ -4817,6 +7197,13 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub product_data_type: Label,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY document_usage_constraint;"]
+    #[doc = "     source                : document;"]
+    #[doc = "     subject_element       : label;"]
+    #[doc = "     subject_element_value : text;"]
+    #[doc = " END_ENTITY; -- document_usage_constraint"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = document_usage_constraint)]
@@ -4829,6 +7216,12 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub subject_element_value: Text,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY document_with_class"]
+    #[doc = "   SUBTYPE OF (document);"]
+    #[doc = "     class : identifier;"]
+    #[doc = " END_ENTITY; -- document_with_class"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -4845,6 +7238,14 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub class: Identifier,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY edge"]
+    #[doc = "   SUPERTYPE OF (ONEOF (edge_curve,oriented_edge))"]
+    #[doc = "   SUBTYPE OF (topological_representation_item);"]
+    #[doc = "     edge_start : vertex;"]
+    #[doc = "     edge_end   : vertex;"]
+    #[doc = " END_ENTITY; -- edge"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -4858,10 +7259,12 @@ pub mod config_control_design {
         #[deref_mut]
         #[holder(use_place_holder)]
         pub topological_representation_item: TopologicalRepresentationItem,
+        #[doc = " `None` in instances of a subtype redeclaring this attribute as derived"]
         #[holder(use_place_holder)]
-        pub edge_start: VertexAny,
+        pub edge_start: Option<VertexAny>,
+        #[doc = " `None` in instances of a subtype redeclaring this attribute as derived"]
         #[holder(use_place_holder)]
-        pub edge_end: VertexAny,
+        pub edge_end: Option<VertexAny>,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
@@ -4907,6 +7310,12 @@ pub mod config_control_design {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY edge_based_wireframe_model"]
+    #[doc = "   SUBTYPE OF (geometric_representation_item);"]
+    #[doc = "     ebwm_boundary : SET [1:?] OF connected_edge_set;"]
+    #[doc = " END_ENTITY; -- edge_based_wireframe_model"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -4921,8 +7330,71 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub geometric_representation_item: GeometricRepresentationItem,
         #[holder(use_place_holder)]
+        # [holder (size = 1 ..)]
         pub ebwm_boundary: Vec<ConnectedEdgeSet>,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY edge_based_wireframe_shape_representation"]
+    #[doc = "   SUBTYPE OF (shape_representation);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: (SIZEOF(QUERY ( it <* SELF.items | (NOT (SIZEOF(["]
+    #[doc = "              'CONFIG_CONTROL_DESIGN.EDGE_BASED_WIREFRAME_MODEL',"]
+    #[doc = "              'CONFIG_CONTROL_DESIGN.MAPPED_ITEM',"]
+    #[doc = "              'CONFIG_CONTROL_DESIGN.AXIS2_PLACEMENT_3D'] * TYPEOF(it)) ="]
+    #[doc = "              1)) )) = 0);"]
+    #[doc = "     wr2: (SIZEOF(QUERY ( it <* SELF.items | (SIZEOF(["]
+    #[doc = "              'CONFIG_CONTROL_DESIGN.EDGE_BASED_WIREFRAME_MODEL',"]
+    #[doc = "              'CONFIG_CONTROL_DESIGN.MAPPED_ITEM'] * TYPEOF(it)) = 1) ))"]
+    #[doc = "              >= 1);"]
+    #[doc = "     wr3: (SIZEOF(QUERY ( ebwm <* QUERY ( it <* SELF.items | ("]
+    #[doc = "              'CONFIG_CONTROL_DESIGN.EDGE_BASED_WIREFRAME_MODEL' IN"]
+    #[doc = "              TYPEOF(it)) ) | (NOT (SIZEOF(QUERY ( eb <* ebwm\\"]
+    #[doc = "              edge_based_wireframe_model.ebwm_boundary | (NOT (SIZEOF("]
+    #[doc = "              QUERY ( edges <* eb.ces_edges | (NOT ("]
+    #[doc = "              'CONFIG_CONTROL_DESIGN.EDGE_CURVE' IN TYPEOF(edges))) ))"]
+    #[doc = "              = 0)) )) = 0)) )) = 0);"]
+    #[doc = "     wr4: (SIZEOF(QUERY ( ebwm <* QUERY ( it <* SELF.items | ("]
+    #[doc = "              'CONFIG_CONTROL_DESIGN.EDGE_BASED_WIREFRAME_MODEL' IN"]
+    #[doc = "              TYPEOF(it)) ) | (NOT (SIZEOF(QUERY ( eb <* ebwm\\"]
+    #[doc = "              edge_based_wireframe_model.ebwm_boundary | (NOT (SIZEOF("]
+    #[doc = "              QUERY ( pline_edges <* QUERY ( edges <* eb.ces_edges | ("]
+    #[doc = "              'CONFIG_CONTROL_DESIGN.POLYLINE' IN TYPEOF(edges\\edge_curve."]
+    #[doc = "              edge_geometry)) ) | (NOT (SIZEOF(pline_edges\\edge_curve."]
+    #[doc = "              edge_geometry\\polyline.points) > 2)) )) = 0)) )) = 0)) )) ="]
+    #[doc = "              0);"]
+    #[doc = "     wr5: (SIZEOF(QUERY ( ebwm <* QUERY ( it <* SELF.items | ("]
+    #[doc = "              'CONFIG_CONTROL_DESIGN.EDGE_BASED_WIREFRAME_MODEL' IN"]
+    #[doc = "              TYPEOF(it)) ) | (NOT (SIZEOF(QUERY ( eb <* ebwm\\"]
+    #[doc = "              edge_based_wireframe_model.ebwm_boundary | (NOT (SIZEOF("]
+    #[doc = "              QUERY ( edges <* eb.ces_edges | (NOT (("]
+    #[doc = "              'CONFIG_CONTROL_DESIGN.VERTEX_POINT' IN TYPEOF(edges."]
+    #[doc = "              edge_start)) AND ('CONFIG_CONTROL_DESIGN.VERTEX_POINT' IN"]
+    #[doc = "              TYPEOF(edges.edge_end)))) )) = 0)) )) = 0)) )) = 0);"]
+    #[doc = "     wr6: (SIZEOF(QUERY ( ebwm <* QUERY ( it <* SELF.items | ("]
+    #[doc = "              'CONFIG_CONTROL_DESIGN.EDGE_BASED_WIREFRAME_MODEL' IN"]
+    #[doc = "              TYPEOF(it)) ) | (NOT (SIZEOF(QUERY ( eb <* ebwm\\"]
+    #[doc = "              edge_based_wireframe_model.ebwm_boundary | (NOT (SIZEOF("]
+    #[doc = "              QUERY ( edges <* eb.ces_edges | (NOT"]
+    #[doc = "              valid_wireframe_edge_curve(edges\\edge_curve.edge_geometry))"]
+    #[doc = "              )) = 0)) )) = 0)) )) = 0);"]
+    #[doc = "     wr7: (SIZEOF(QUERY ( ebwm <* QUERY ( it <* SELF.items | ("]
+    #[doc = "              'CONFIG_CONTROL_DESIGN.EDGE_BASED_WIREFRAME_MODEL' IN"]
+    #[doc = "              TYPEOF(it)) ) | (NOT (SIZEOF(QUERY ( eb <* ebwm\\"]
+    #[doc = "              edge_based_wireframe_model.ebwm_boundary | (NOT (SIZEOF("]
+    #[doc = "              QUERY ( edges <* eb.ces_edges | (NOT ("]
+    #[doc = "              valid_wireframe_vertex_point(edges.edge_start\\vertex_point."]
+    #[doc = "              vertex_geometry) AND valid_wireframe_vertex_point(edges."]
+    #[doc = "              edge_end\\vertex_point.vertex_geometry))) )) = 0)) )) = 0))"]
+    #[doc = "              )) = 0);"]
+    #[doc = "     wr8: (SIZEOF(QUERY ( mi <* QUERY ( it <* SELF.items | ("]
+    #[doc = "              'CONFIG_CONTROL_DESIGN.MAPPED_ITEM' IN TYPEOF(it)) ) | (NOT"]
+    #[doc = "              (('CONFIG_CONTROL_DESIGN.' +"]
+    #[doc = "              'EDGE_BASED_WIREFRAME_SHAPE_REPRESENTATION') IN TYPEOF(mi\\"]
+    #[doc = "              mapped_item.mapping_source.mapped_representation))) )) = 0);"]
+    #[doc = "     wr9: (SELF.context_of_items\\geometric_representation_context."]
+    #[doc = "              coordinate_space_dimension = 3);"]
+    #[doc = " END_ENTITY; -- edge_based_wireframe_shape_representation"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -4937,6 +7409,13 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub shape_representation: ShapeRepresentation,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY edge_curve"]
+    #[doc = "   SUBTYPE OF (edge, geometric_representation_item);"]
+    #[doc = "     edge_geometry : curve;"]
+    #[doc = "     same_sense    : BOOLEAN;"]
+    #[doc = " END_ENTITY; -- edge_curve"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut)]
     # [holder (table = Tables)]
     # [holder (field = edge_curve)]
@@ -4954,6 +7433,16 @@ pub mod config_control_design {
         pub edge_geometry: CurveAny,
         pub same_sense: bool,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY edge_loop"]
+    #[doc = "   SUBTYPE OF (loop, path);"]
+    #[doc = "   DERIVE"]
+    #[doc = "     ne : INTEGER := SIZEOF(SELF\\path.edge_list);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: (SELF\\path.edge_list[1].edge_start :=: SELF\\path.edge_list[ne]."]
+    #[doc = "              edge_end);"]
+    #[doc = " END_ENTITY; -- edge_loop"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut)]
     # [holder (table = Tables)]
     # [holder (field = edge_loop)]
@@ -4968,6 +7457,13 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub path: Path,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY effectivity"]
+    #[doc = "   SUPERTYPE OF (ONEOF (serial_numbered_effectivity,dated_effectivity,"]
+    #[doc = "       lot_effectivity));"]
+    #[doc = "     id : identifier;"]
+    #[doc = " END_ENTITY; -- effectivity"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = effectivity)]
@@ -5027,6 +7523,14 @@ pub mod config_control_design {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY elementary_surface"]
+    #[doc = "   SUPERTYPE OF (ONEOF (plane,cylindrical_surface,conical_surface,"]
+    #[doc = "       spherical_surface,toroidal_surface))"]
+    #[doc = "   SUBTYPE OF (surface);"]
+    #[doc = "     position : axis2_placement_3d;"]
+    #[doc = " END_ENTITY; -- elementary_surface"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -5126,6 +7630,13 @@ pub mod config_control_design {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY ellipse"]
+    #[doc = "   SUBTYPE OF (conic);"]
+    #[doc = "     semi_axis_1 : positive_length_measure;"]
+    #[doc = "     semi_axis_2 : positive_length_measure;"]
+    #[doc = " END_ENTITY; -- ellipse"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -5144,6 +7655,12 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub semi_axis_2: PositiveLengthMeasure,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY evaluated_degenerate_pcurve"]
+    #[doc = "   SUBTYPE OF (degenerate_pcurve);"]
+    #[doc = "     equivalent_point : cartesian_point;"]
+    #[doc = " END_ENTITY; -- evaluated_degenerate_pcurve"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -5160,6 +7677,11 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub equivalent_point: CartesianPoint,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY executed_action"]
+    #[doc = "   SUBTYPE OF (action);"]
+    #[doc = " END_ENTITY; -- executed_action"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -5211,6 +7733,18 @@ pub mod config_control_design {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY face"]
+    #[doc = "   SUPERTYPE OF (ONEOF (face_surface,oriented_face))"]
+    #[doc = "   SUBTYPE OF (topological_representation_item);"]
+    #[doc = "     bounds : SET [1:?] OF face_bound;"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: (NOT mixed_loop_type_set(list_to_set(list_face_loops(SELF))));"]
+    #[doc = "     wr2: (SIZEOF(QUERY ( temp <* bounds | ("]
+    #[doc = "              'CONFIG_CONTROL_DESIGN.FACE_OUTER_BOUND' IN TYPEOF(temp)) ))"]
+    #[doc = "              <= 1);"]
+    #[doc = " END_ENTITY; -- face"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -5224,8 +7758,10 @@ pub mod config_control_design {
         #[deref_mut]
         #[holder(use_place_holder)]
         pub topological_representation_item: TopologicalRepresentationItem,
+        #[doc = " `None` in instances of a subtype redeclaring this attribute as derived"]
         #[holder(use_place_holder)]
-        pub bounds: Vec<FaceBoundAny>,
+        # [holder (size = 1 ..)]
+        pub bounds: Option<Vec<FaceBoundAny>>,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
@@ -5271,6 +7807,13 @@ pub mod config_control_design {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY face_bound"]
+    #[doc = "   SUBTYPE OF (topological_representation_item);"]
+    #[doc = "     bound       : loop;"]
+    #[doc = "     orientation : BOOLEAN;"]
+    #[doc = " END_ENTITY; -- face_bound"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -5323,6 +7866,11 @@ pub mod config_control_design {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY face_outer_bound"]
+    #[doc = "   SUBTYPE OF (face_bound);"]
+    #[doc = " END_ENTITY; -- face_outer_bound"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -5337,6 +7885,13 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub face_bound: FaceBound,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY face_surface"]
+    #[doc = "   SUBTYPE OF (face, geometric_representation_item);"]
+    #[doc = "     face_geometry : surface;"]
+    #[doc = "     same_sense    : BOOLEAN;"]
+    #[doc = " END_ENTITY; -- face_surface"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut)]
     # [holder (table = Tables)]
     # [holder (field = face_surface)]
@@ -5401,6 +7956,11 @@ pub mod config_control_design {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY faceted_brep"]
+    #[doc = "   SUBTYPE OF (manifold_solid_brep);"]
+    #[doc = " END_ENTITY; -- faceted_brep"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -5415,6 +7975,50 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub manifold_solid_brep: ManifoldSolidBrep,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY faceted_brep_shape_representation"]
+    #[doc = "   SUBTYPE OF (shape_representation);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: (SIZEOF(QUERY ( it <* items | (NOT (SIZEOF(["]
+    #[doc = "              'CONFIG_CONTROL_DESIGN.FACETED_BREP',"]
+    #[doc = "              'CONFIG_CONTROL_DESIGN.MAPPED_ITEM',"]
+    #[doc = "              'CONFIG_CONTROL_DESIGN.AXIS2_PLACEMENT_3D'] * TYPEOF(it)) ="]
+    #[doc = "              1)) )) = 0);"]
+    #[doc = "     wr2: (SIZEOF(QUERY ( it <* items | (SIZEOF(["]
+    #[doc = "              'CONFIG_CONTROL_DESIGN.FACETED_BREP',"]
+    #[doc = "              'CONFIG_CONTROL_DESIGN.MAPPED_ITEM'] * TYPEOF(it)) = 1) )) >"]
+    #[doc = "               0);"]
+    #[doc = "     wr3: (SIZEOF(QUERY ( fbrep <* QUERY ( it <* items | ("]
+    #[doc = "              'CONFIG_CONTROL_DESIGN.FACETED_BREP' IN TYPEOF(it)) ) | ("]
+    #[doc = "              NOT (SIZEOF(QUERY ( csh <* msb_shells(fbrep) | (NOT (SIZEOF("]
+    #[doc = "              QUERY ( fcs <* csh\\connected_face_set.cfs_faces | (NOT (("]
+    #[doc = "              'CONFIG_CONTROL_DESIGN.FACE_SURFACE' IN TYPEOF(fcs)) AND ("]
+    #[doc = "              'CONFIG_CONTROL_DESIGN.PLANE' IN TYPEOF(fcs\\face_surface."]
+    #[doc = "              face_geometry)) AND ('CONFIG_CONTROL_DESIGN.CARTESIAN_POINT'"]
+    #[doc = "              IN TYPEOF(fcs\\face_surface.face_geometry\\elementary_surface."]
+    #[doc = "              position.location)))) )) = 0)) )) = 0)) )) = 0);"]
+    #[doc = "     wr4: (SIZEOF(QUERY ( fbrep <* QUERY ( it <* items | ("]
+    #[doc = "              'CONFIG_CONTROL_DESIGN.FACETED_BREP' IN TYPEOF(it)) ) | ("]
+    #[doc = "              NOT (SIZEOF(QUERY ( csh <* msb_shells(fbrep) | (NOT (SIZEOF("]
+    #[doc = "              QUERY ( fcs <* csh\\connected_face_set.cfs_faces | (NOT ("]
+    #[doc = "              SIZEOF(QUERY ( bnds <* fcs.bounds | ("]
+    #[doc = "              'CONFIG_CONTROL_DESIGN.FACE_OUTER_BOUND' IN TYPEOF(bnds)) ))"]
+    #[doc = "              = 1)) )) = 0)) )) = 0)) )) = 0);"]
+    #[doc = "     wr5: (SIZEOF(QUERY ( msb <* QUERY ( it <* items | ("]
+    #[doc = "              'CONFIG_CONTROL_DESIGN.MANIFOLD_SOLID_BREP' IN TYPEOF(it)) )"]
+    #[doc = "               | ('CONFIG_CONTROL_DESIGN.ORIENTED_CLOSED_SHELL' IN TYPEOF("]
+    #[doc = "              msb\\manifold_solid_brep.outer)) )) = 0);"]
+    #[doc = "     wr6: (SIZEOF(QUERY ( brv <* QUERY ( it <* items | ("]
+    #[doc = "              'CONFIG_CONTROL_DESIGN.BREP_WITH_VOIDS' IN TYPEOF(it)) ) | ("]
+    #[doc = "              NOT (SIZEOF(QUERY ( csh <* brv\\brep_with_voids.voids | csh\\"]
+    #[doc = "              oriented_closed_shell.orientation )) = 0)) )) = 0);"]
+    #[doc = "     wr7: (SIZEOF(QUERY ( mi <* QUERY ( it <* items | ("]
+    #[doc = "              'CONFIG_CONTROL_DESIGN.MAPPED_ITEM' IN TYPEOF(it)) ) | (NOT"]
+    #[doc = "              ('CONFIG_CONTROL_DESIGN.FACETED_BREP_SHAPE_REPRESENTATION'"]
+    #[doc = "              IN TYPEOF(mi\\mapped_item.mapping_source."]
+    #[doc = "              mapped_representation))) )) = 0);"]
+    #[doc = " END_ENTITY; -- faceted_brep_shape_representation"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -5429,6 +8033,10 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub shape_representation: ShapeRepresentation,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY founded_item;"]
+    #[doc = " END_ENTITY; -- founded_item"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = founded_item)]
@@ -5469,6 +8077,12 @@ pub mod config_control_design {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY functionally_defined_transformation;"]
+    #[doc = "     name        : label;"]
+    #[doc = "     description : text;"]
+    #[doc = " END_ENTITY; -- functionally_defined_transformation"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = functionally_defined_transformation)]
@@ -5512,6 +8126,14 @@ pub mod config_control_design {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY geometric_curve_set"]
+    #[doc = "   SUBTYPE OF (geometric_set);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: (SIZEOF(QUERY ( temp <* SELF\\geometric_set.elements | ("]
+    #[doc = "              'CONFIG_CONTROL_DESIGN.SURFACE' IN TYPEOF(temp)) )) = 0);"]
+    #[doc = " END_ENTITY; -- geometric_curve_set"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -5526,6 +8148,12 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub geometric_set: GeometricSet,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY geometric_representation_context"]
+    #[doc = "   SUBTYPE OF (representation_context);"]
+    #[doc = "     coordinate_space_dimension : dimension_count;"]
+    #[doc = " END_ENTITY; -- geometric_representation_context"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -5542,6 +8170,23 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub coordinate_space_dimension: DimensionCount,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY geometric_representation_item"]
+    #[doc = "   SUPERTYPE OF (ONEOF (point,direction,vector,placement,"]
+    #[doc = "       cartesian_transformation_operator,curve,surface,edge_curve,"]
+    #[doc = "       face_surface,poly_loop,vertex_point,solid_model,"]
+    #[doc = "       shell_based_surface_model,shell_based_wireframe_model,"]
+    #[doc = "       edge_based_wireframe_model,geometric_set))"]
+    #[doc = "   SUBTYPE OF (representation_item);"]
+    #[doc = "   DERIVE"]
+    #[doc = "     dim : dimension_count := dimension_of(SELF);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: (SIZEOF(QUERY ( using_rep <* using_representations(SELF) | ("]
+    #[doc = "              NOT ("]
+    #[doc = "              'CONFIG_CONTROL_DESIGN.GEOMETRIC_REPRESENTATION_CONTEXT' IN"]
+    #[doc = "              TYPEOF(using_rep.context_of_items))) )) = 0);"]
+    #[doc = " END_ENTITY; -- geometric_representation_item"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -5762,6 +8407,13 @@ pub mod config_control_design {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY geometric_set"]
+    #[doc = "   SUPERTYPE OF (geometric_curve_set)"]
+    #[doc = "   SUBTYPE OF (geometric_representation_item);"]
+    #[doc = "     elements : SET [1:?] OF geometric_set_select;"]
+    #[doc = " END_ENTITY; -- geometric_set"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -5776,6 +8428,7 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub geometric_representation_item: GeometricRepresentationItem,
         #[holder(use_place_holder)]
+        # [holder (size = 1 ..)]
         pub elements: Vec<GeometricSetSelect>,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
@@ -5815,6 +8468,51 @@ pub mod config_control_design {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY geometrically_bounded_surface_shape_representation"]
+    #[doc = "   SUBTYPE OF (shape_representation);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: (SIZEOF(QUERY ( it <* SELF.items | (NOT (SIZEOF(["]
+    #[doc = "              'CONFIG_CONTROL_DESIGN.GEOMETRIC_SET',"]
+    #[doc = "              'CONFIG_CONTROL_DESIGN.MAPPED_ITEM',"]
+    #[doc = "              'CONFIG_CONTROL_DESIGN.AXIS2_PLACEMENT_3D'] * TYPEOF(it)) ="]
+    #[doc = "              1)) )) = 0);"]
+    #[doc = "     wr2: (SIZEOF(QUERY ( it <* SELF.items | (SIZEOF(["]
+    #[doc = "              'CONFIG_CONTROL_DESIGN.GEOMETRIC_SET',"]
+    #[doc = "              'CONFIG_CONTROL_DESIGN.MAPPED_ITEM'] * TYPEOF(it)) = 1) )) >"]
+    #[doc = "               0);"]
+    #[doc = "     wr3: (SIZEOF(QUERY ( mi <* QUERY ( it <* SELF.items | ("]
+    #[doc = "              'CONFIG_CONTROL_DESIGN.MAPPED_ITEM' IN TYPEOF(it)) ) | (NOT"]
+    #[doc = "              ((('CONFIG_CONTROL_DESIGN.' +"]
+    #[doc = "              'GEOMETRICALLY_BOUNDED_SURFACE_SHAPE_REPRESENTATION') IN"]
+    #[doc = "              TYPEOF(mi\\mapped_item.mapping_source.mapped_representation))"]
+    #[doc = "              AND (SIZEOF(QUERY ( mr_it <* mi\\mapped_item.mapping_source."]
+    #[doc = "              mapped_representation.items | ("]
+    #[doc = "              'CONFIG_CONTROL_DESIGN.GEOMETRIC_SET' IN TYPEOF(mr_it)) )) >"]
+    #[doc = "               0))) )) = 0);"]
+    #[doc = "     wr4: (SIZEOF(QUERY ( gs <* QUERY ( it <* SELF.items | ("]
+    #[doc = "              'CONFIG_CONTROL_DESIGN.GEOMETRIC_SET' IN TYPEOF(it)) ) | ("]
+    #[doc = "              NOT (SIZEOF(QUERY ( pnt <* QUERY ( gsel <* gs\\geometric_set."]
+    #[doc = "              elements | ('CONFIG_CONTROL_DESIGN.POINT' IN TYPEOF(gsel)) )"]
+    #[doc = "               | (NOT gbsf_check_point(pnt)) )) = 0)) )) = 0);"]
+    #[doc = "     wr5: (SIZEOF(QUERY ( gs <* QUERY ( it <* SELF.items | ("]
+    #[doc = "              'CONFIG_CONTROL_DESIGN.GEOMETRIC_SET' IN TYPEOF(it)) ) | ("]
+    #[doc = "              NOT (SIZEOF(QUERY ( cv <* QUERY ( gsel <* gs\\geometric_set."]
+    #[doc = "              elements | ('CONFIG_CONTROL_DESIGN.CURVE' IN TYPEOF(gsel)) )"]
+    #[doc = "               | (NOT gbsf_check_curve(cv)) )) = 0)) )) = 0);"]
+    #[doc = "     wr6: (SIZEOF(QUERY ( gs <* QUERY ( it <* SELF.items | ("]
+    #[doc = "              'CONFIG_CONTROL_DESIGN.GEOMETRIC_SET' IN TYPEOF(it)) ) | ("]
+    #[doc = "              NOT (SIZEOF(QUERY ( sf <* QUERY ( gsel <* gs\\geometric_set."]
+    #[doc = "              elements | ('CONFIG_CONTROL_DESIGN.SURFACE' IN"]
+    #[doc = "              TYPEOF(gsel)) ) | (NOT gbsf_check_surface(sf)) ))"]
+    #[doc = "              = 0)) )) = 0);"]
+    #[doc = "     wr7: (SIZEOF(QUERY ( gs <* QUERY ( it <* SELF.items | ("]
+    #[doc = "              'CONFIG_CONTROL_DESIGN.GEOMETRIC_SET' IN TYPEOF(it)) ) | ("]
+    #[doc = "              SIZEOF(QUERY ( gsel <* gs\\geometric_set.elements | ("]
+    #[doc = "              'CONFIG_CONTROL_DESIGN.SURFACE' IN TYPEOF(gsel)) )) > 0) ))"]
+    #[doc = "              > 0);"]
+    #[doc = " END_ENTITY; -- geometrically_bounded_surface_shape_representation"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -5829,6 +8527,50 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub shape_representation: ShapeRepresentation,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY geometrically_bounded_wireframe_shape_representation"]
+    #[doc = "   SUBTYPE OF (shape_representation);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: (SIZEOF(QUERY ( it <* SELF.items | (NOT (SIZEOF(TYPEOF(it) * ["]
+    #[doc = "              'CONFIG_CONTROL_DESIGN.GEOMETRIC_CURVE_SET',"]
+    #[doc = "              'CONFIG_CONTROL_DESIGN.AXIS2_PLACEMENT_3D',"]
+    #[doc = "              'CONFIG_CONTROL_DESIGN.MAPPED_ITEM']) = 1)) )) = 0);"]
+    #[doc = "     wr2: (SIZEOF(QUERY ( it <* SELF.items | (SIZEOF(TYPEOF(it) * ["]
+    #[doc = "              'CONFIG_CONTROL_DESIGN.GEOMETRIC_CURVE_SET',"]
+    #[doc = "              'CONFIG_CONTROL_DESIGN.MAPPED_ITEM']) = 1) )) >= 1);"]
+    #[doc = "     wr3: (SIZEOF(QUERY ( gcs <* QUERY ( it <* SELF.items | ("]
+    #[doc = "              'CONFIG_CONTROL_DESIGN.GEOMETRIC_CURVE_SET' IN TYPEOF(it)) )"]
+    #[doc = "               | (NOT (SIZEOF(QUERY ( crv <* QUERY ( elem <* gcs\\"]
+    #[doc = "              geometric_set.elements | ('CONFIG_CONTROL_DESIGN.CURVE' IN"]
+    #[doc = "              TYPEOF(elem)) ) | (NOT valid_geometrically_bounded_wf_curve("]
+    #[doc = "              crv)) )) = 0)) )) = 0);"]
+    #[doc = "     wr4: (SIZEOF(QUERY ( gcs <* QUERY ( it <* SELF.items | ("]
+    #[doc = "              'CONFIG_CONTROL_DESIGN.GEOMETRIC_CURVE_SET' IN TYPEOF(it)) )"]
+    #[doc = "               | (NOT (SIZEOF(QUERY ( pnts <* QUERY ( elem <* gcs\\"]
+    #[doc = "              geometric_set.elements | ('CONFIG_CONTROL_DESIGN.POINT' IN"]
+    #[doc = "              TYPEOF(elem)) ) | (NOT valid_geometrically_bounded_wf_point("]
+    #[doc = "              pnts)) )) = 0)) )) = 0);"]
+    #[doc = "     wr5: (SIZEOF(QUERY ( gcs <* QUERY ( it <* SELF.items | ("]
+    #[doc = "              'CONFIG_CONTROL_DESIGN.GEOMETRIC_CURVE_SET' IN TYPEOF(it)) )"]
+    #[doc = "               | (NOT (SIZEOF(QUERY ( cnc <* QUERY ( elem <* gcs\\"]
+    #[doc = "              geometric_set.elements | ('CONFIG_CONTROL_DESIGN.CONIC' IN"]
+    #[doc = "              TYPEOF(elem)) ) | (NOT ("]
+    #[doc = "              'CONFIG_CONTROL_DESIGN.AXIS2_PLACEMENT_3D' IN TYPEOF(cnc\\"]
+    #[doc = "              conic.position))) )) = 0)) )) = 0);"]
+    #[doc = "     wr6: (SIZEOF(QUERY ( gcs <* QUERY ( it <* SELF.items | ("]
+    #[doc = "              'CONFIG_CONTROL_DESIGN.GEOMETRIC_CURVE_SET' IN TYPEOF(it)) )"]
+    #[doc = "               | (NOT (SIZEOF(QUERY ( pline <* QUERY ( elem <* gcs\\"]
+    #[doc = "              geometric_set.elements | ('CONFIG_CONTROL_DESIGN.POLYLINE'"]
+    #[doc = "              IN TYPEOF(elem)) ) | (NOT (SIZEOF(pline\\polyline.points)"]
+    #[doc = "              > 2)) )) = 0)) )) = 0);"]
+    #[doc = "     wr7: (SIZEOF(QUERY ( mi <* QUERY ( it <* SELF.items | ("]
+    #[doc = "              'CONFIG_CONTROL_DESIGN.MAPPED_ITEM' IN TYPEOF(it)) ) | (NOT"]
+    #[doc = "              (('CONFIG_CONTROL_DESIGN.' +"]
+    #[doc = "              'GEOMETRICALLY_BOUNDED_WIREFRAME_SHAPE_REPRESENTATION') IN"]
+    #[doc = "              TYPEOF(mi\\mapped_item.mapping_source.mapped_representation)"]
+    #[doc = "              )) )) = 0);"]
+    #[doc = " END_ENTITY; -- geometrically_bounded_wireframe_shape_representation"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -5843,6 +8585,12 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub shape_representation: ShapeRepresentation,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY global_uncertainty_assigned_context"]
+    #[doc = "   SUBTYPE OF (representation_context);"]
+    #[doc = "     uncertainty : SET [1:?] OF uncertainty_measure_with_unit;"]
+    #[doc = " END_ENTITY; -- global_uncertainty_assigned_context"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -5857,8 +8605,15 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub representation_context: RepresentationContext,
         #[holder(use_place_holder)]
+        # [holder (size = 1 ..)]
         pub uncertainty: Vec<UncertaintyMeasureWithUnit>,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY global_unit_assigned_context"]
+    #[doc = "   SUBTYPE OF (representation_context);"]
+    #[doc = "     units : SET [1:?] OF unit;"]
+    #[doc = " END_ENTITY; -- global_unit_assigned_context"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -5873,8 +8628,16 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub representation_context: RepresentationContext,
         #[holder(use_place_holder)]
+        # [holder (size = 1 ..)]
         pub units: Vec<Unit>,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY hyperbola"]
+    #[doc = "   SUBTYPE OF (conic);"]
+    #[doc = "     semi_axis      : positive_length_measure;"]
+    #[doc = "     semi_imag_axis : positive_length_measure;"]
+    #[doc = " END_ENTITY; -- hyperbola"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -5893,6 +8656,16 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub semi_imag_axis: PositiveLengthMeasure,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY intersection_curve"]
+    #[doc = "   SUBTYPE OF (surface_curve);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: (SIZEOF(SELF\\surface_curve.associated_geometry) = 2);"]
+    #[doc = "     wr2: (associated_surface(SELF\\surface_curve.associated_geometry[1])"]
+    #[doc = "              <> associated_surface(SELF\\surface_curve.associated_geometry"]
+    #[doc = "              [2]));"]
+    #[doc = " END_ENTITY; -- intersection_curve"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -5907,6 +8680,14 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub surface_curve: SurfaceCurve,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY item_defined_transformation;"]
+    #[doc = "     name             : label;"]
+    #[doc = "     description      : text;"]
+    #[doc = "     transform_item_1 : representation_item;"]
+    #[doc = "     transform_item_2 : representation_item;"]
+    #[doc = " END_ENTITY; -- item_defined_transformation"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = item_defined_transformation)]
@@ -5921,6 +8702,14 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub transform_item_2: RepresentationItemAny,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY length_measure_with_unit"]
+    #[doc = "   SUBTYPE OF (measure_with_unit);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: ('CONFIG_CONTROL_DESIGN.LENGTH_UNIT' IN TYPEOF(SELF\\"]
+    #[doc = "              measure_with_unit.unit_component));"]
+    #[doc = " END_ENTITY; -- length_measure_with_unit"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -5935,6 +8724,20 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub measure_with_unit: MeasureWithUnit,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY length_unit"]
+    #[doc = "   SUBTYPE OF (named_unit);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: ((SELF\\named_unit.dimensions.length_exponent = 1) AND (SELF\\"]
+    #[doc = "              named_unit.dimensions.mass_exponent = 0) AND (SELF\\"]
+    #[doc = "              named_unit.dimensions.time_exponent = 0) AND (SELF\\"]
+    #[doc = "              named_unit.dimensions.electric_current_exponent = 0) AND ("]
+    #[doc = "              SELF\\named_unit.dimensions."]
+    #[doc = "              thermodynamic_temperature_exponent = 0) AND (SELF\\named_unit"]
+    #[doc = "              .dimensions.amount_of_substance_exponent = 0) AND (SELF\\"]
+    #[doc = "              named_unit.dimensions.luminous_intensity_exponent = 0));"]
+    #[doc = " END_ENTITY; -- length_unit"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -5949,6 +8752,15 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub named_unit: NamedUnit,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY line"]
+    #[doc = "   SUBTYPE OF (curve);"]
+    #[doc = "     pnt : cartesian_point;"]
+    #[doc = "     dir : vector;"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: (dir.dim = pnt.dim);"]
+    #[doc = " END_ENTITY; -- line"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -5967,6 +8779,16 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub dir: Vector,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY local_time;"]
+    #[doc = "     hour_component   : hour_in_day;"]
+    #[doc = "     minute_component : OPTIONAL minute_in_hour;"]
+    #[doc = "     second_component : OPTIONAL second_in_minute;"]
+    #[doc = "     zone             : coordinated_universal_time_offset;"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: valid_time(SELF);"]
+    #[doc = " END_ENTITY; -- local_time"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = local_time)]
@@ -5981,6 +8803,12 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub zone: CoordinatedUniversalTimeOffset,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY loop"]
+    #[doc = "   SUPERTYPE OF (ONEOF (vertex_loop,edge_loop,poly_loop))"]
+    #[doc = "   SUBTYPE OF (topological_representation_item);"]
+    #[doc = " END_ENTITY; -- loop"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -6048,6 +8876,13 @@ pub mod config_control_design {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY lot_effectivity"]
+    #[doc = "   SUBTYPE OF (effectivity);"]
+    #[doc = "     effectivity_lot_id   : identifier;"]
+    #[doc = "     effectivity_lot_size : measure_with_unit;"]
+    #[doc = " END_ENTITY; -- lot_effectivity"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -6066,6 +8901,12 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub effectivity_lot_size: MeasureWithUnitAny,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY manifold_solid_brep"]
+    #[doc = "   SUBTYPE OF (solid_model);"]
+    #[doc = "     outer : closed_shell;"]
+    #[doc = " END_ENTITY; -- manifold_solid_brep"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -6132,6 +8973,185 @@ pub mod config_control_design {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY manifold_surface_shape_representation"]
+    #[doc = "   SUBTYPE OF (shape_representation);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1 : (SIZEOF(QUERY ( it <* SELF.items | (NOT (SIZEOF(["]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.SHELL_BASED_SURFACE_MODEL',"]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.MAPPED_ITEM',"]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.AXIS2_PLACEMENT_3D'] * TYPEOF(it)) ="]
+    #[doc = "                1)) )) = 0);"]
+    #[doc = "     wr2 : (SIZEOF(QUERY ( it <* SELF.items | (SIZEOF(["]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.SHELL_BASED_SURFACE_MODEL',"]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.MAPPED_ITEM'] * TYPEOF(it)) = 1) ))"]
+    #[doc = "               > 0);"]
+    #[doc = "     wr3 : (SIZEOF(QUERY ( mi <* QUERY ( it <* SELF.items | ("]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.MAPPED_ITEM' IN TYPEOF(it)) ) | ("]
+    #[doc = "               NOT (('CONFIG_CONTROL_DESIGN.'+"]
+    #[doc = "              'MANIFOLD_SURFACE_SHAPE_REPRESENTATION'"]
+    #[doc = "               IN TYPEOF(mi\\mapped_item.mapping_source."]
+    #[doc = "               mapped_representation)) AND (SIZEOF(QUERY ( mr_it <* mi\\"]
+    #[doc = "               mapped_item.mapping_source.mapped_representation.items | ("]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.SHELL_BASED_SURFACE_MODEL' IN"]
+    #[doc = "               TYPEOF(mr_it)) )) > 0))) )) = 0);"]
+    #[doc = "     wr4 : (SIZEOF(QUERY ( sbsm <* QUERY ( it <* SELF.items | ("]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.SHELL_BASED_SURFACE_MODEL' IN"]
+    #[doc = "               TYPEOF(it)) ) | (NOT (SIZEOF(QUERY ( sh <* sbsm\\"]
+    #[doc = "               shell_based_surface_model.sbsm_boundary | (NOT (SIZEOF(["]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.OPEN_SHELL',"]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.ORIENTED_CLOSED_SHELL',"]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.CLOSED_SHELL'] * TYPEOF(sh)) = 1))"]
+    #[doc = "               )) = 0)) )) = 0);"]
+    #[doc = "     wr5 : (SIZEOF(QUERY ( sbsm <* QUERY ( it <* SELF.items | ("]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.SHELL_BASED_SURFACE_MODEL' IN"]
+    #[doc = "               TYPEOF(it)) ) | (NOT (SIZEOF(QUERY ( cfs <* sbsm\\"]
+    #[doc = "               shell_based_surface_model.sbsm_boundary | (NOT (SIZEOF("]
+    #[doc = "               QUERY ( fa <* cfs\\connected_face_set.cfs_faces | (NOT ("]
+    #[doc = "               SIZEOF(['CONFIG_CONTROL_DESIGN.FACE_SURFACE',"]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.ORIENTED_FACE'] * TYPEOF(fa)) = 1))"]
+    #[doc = "               )) = 0)) )) = 0)) )) = 0);"]
+    #[doc = "     wr6 : (SIZEOF(QUERY ( sbsm <* QUERY ( it <* SELF.items | ("]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.SHELL_BASED_SURFACE_MODEL' IN"]
+    #[doc = "               TYPEOF(it)) ) | (NOT (SIZEOF(QUERY ( cfs <* sbsm\\"]
+    #[doc = "               shell_based_surface_model.sbsm_boundary | (NOT (SIZEOF("]
+    #[doc = "               QUERY ( f_sf <* QUERY ( fa <* cfs\\connected_face_set."]
+    #[doc = "               cfs_faces | ('CONFIG_CONTROL_DESIGN.FACE_SURFACE' IN"]
+    #[doc = "               TYPEOF(fa)) ) | (NOT (("]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.ADVANCED_FACE' IN TYPEOF(f_sf)) OR ("]
+    #[doc = "               SIZEOF(['CONFIG_CONTROL_DESIGN.B_SPLINE_SURFACE',"]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.ELEMENTARY_SURFACE',"]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.OFFSET_SURFACE',"]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.SURFACE_REPLICA',"]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.SWEPT_SURFACE'] * TYPEOF(f_sf\\"]
+    #[doc = "               face_surface.face_geometry)) = 1))) )) = 0)) )) = 0)) )) ="]
+    #[doc = "               0);"]
+    #[doc = "     wr7 : (SIZEOF(QUERY ( sbsm <* QUERY ( it <* SELF.items | ("]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.SHELL_BASED_SURFACE_MODEL' IN"]
+    #[doc = "               TYPEOF(it)) ) | (NOT (SIZEOF(QUERY ( cfs <* sbsm\\"]
+    #[doc = "               shell_based_surface_model.sbsm_boundary | (NOT (SIZEOF("]
+    #[doc = "               QUERY ( fa <* cfs\\connected_face_set.cfs_faces | (NOT (("]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.ADVANCED_FACE' IN TYPEOF(fa)) OR"]
+    #[doc = "               msf_surface_check(fa\\face_surface.face_geometry))) ))"]
+    #[doc = "               = 0)) )) = 0)) )) = 0);"]
+    #[doc = "     wr8 : (SIZEOF(QUERY ( sbsm <* QUERY ( it <* SELF.items | ("]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.SHELL_BASED_SURFACE_MODEL' IN"]
+    #[doc = "               TYPEOF(it)) ) | (NOT (SIZEOF(QUERY ( cfs <* sbsm\\"]
+    #[doc = "               shell_based_surface_model.sbsm_boundary | (NOT (SIZEOF("]
+    #[doc = "               QUERY ( fa <* cfs\\connected_face_set.cfs_faces | (NOT (("]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.ADVANCED_FACE' IN TYPEOF(fa)) OR ("]
+    #[doc = "               SIZEOF(QUERY ( bnds <* fa.bounds | (NOT (SIZEOF(["]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.EDGE_LOOP',"]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.VERTEX_LOOP'] * TYPEOF(bnds.bound))"]
+    #[doc = "               = 1)) )) = 0))) )) = 0)) )) = 0)) )) = 0);"]
+    #[doc = "     wr9 : (SIZEOF(QUERY ( sbsm <* QUERY ( it <* SELF.items | ("]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.SHELL_BASED_SURFACE_MODEL' IN"]
+    #[doc = "               TYPEOF(it)) ) | (NOT (SIZEOF(QUERY ( cfs <* sbsm\\"]
+    #[doc = "               shell_based_surface_model.sbsm_boundary | (NOT (SIZEOF("]
+    #[doc = "               QUERY ( fa <* cfs\\connected_face_set.cfs_faces | (NOT (("]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.ADVANCED_FACE' IN TYPEOF(fa)) OR ("]
+    #[doc = "               SIZEOF(QUERY ( elp_fbnds <* QUERY ( bnds <* fa.bounds | ("]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.EDGE_LOOP' IN TYPEOF(bnds.bound)) )"]
+    #[doc = "                | (NOT (SIZEOF(QUERY ( oe <* elp_fbnds\\path.edge_list | ("]
+    #[doc = "               NOT ('CONFIG_CONTROL_DESIGN.EDGE_CURVE' IN TYPEOF(oe."]
+    #[doc = "               edge_element))))) = 0)))) = 0))))) = 0)) )) = 0)) )) = 0);"]
+    #[doc = "     wr10: (SIZEOF(QUERY ( sbsm <* QUERY ( it <* SELF.items | ("]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.SHELL_BASED_SURFACE_MODEL' IN"]
+    #[doc = "               TYPEOF(it)) ) | (NOT (SIZEOF(QUERY ( cfs <* sbsm\\"]
+    #[doc = "               shell_based_surface_model.sbsm_boundary | (NOT (SIZEOF("]
+    #[doc = "               QUERY ( fa <* cfs\\connected_face_set.cfs_faces | (NOT (("]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.ADVANCED_FACE' IN TYPEOF(fa)) OR ("]
+    #[doc = "               SIZEOF(QUERY ( elp_fbnds <* QUERY ( bnds <* fa.bounds | ("]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.EDGE_LOOP' IN TYPEOF(bnds.bound)) )"]
+    #[doc = "                | (NOT (SIZEOF(QUERY ( oe_cv <* QUERY ( oe <* elp_fbnds\\"]
+    #[doc = "               path.edge_list | ('CONFIG_CONTROL_DESIGN.EDGE_CURVE' IN"]
+    #[doc = "               TYPEOF(oe.edge_element)) ) | (NOT (SIZEOF(["]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.B_SPLINE_CURVE',"]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.CONIC',"]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.CURVE_REPLICA',"]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.LINE',"]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.OFFSET_CURVE_3D',"]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.PCURVE',"]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.POLYLINE',"]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.SURFACE_CURVE'] * TYPEOF(oe_cv."]
+    #[doc = "               edge_element\\edge_curve.edge_geometry)) = 1)) )) = 0)) )) ="]
+    #[doc = "                0))) )) = 0)) )) = 0)) )) = 0);"]
+    #[doc = "     wr11: (SIZEOF(QUERY ( sbsm <* QUERY ( it <* SELF.items | ("]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.SHELL_BASED_SURFACE_MODEL' IN"]
+    #[doc = "               TYPEOF(it)) ) | (NOT (SIZEOF(QUERY ( cfs <* sbsm\\"]
+    #[doc = "               shell_based_surface_model.sbsm_boundary | (NOT (SIZEOF("]
+    #[doc = "               QUERY ( fa <* cfs\\connected_face_set.cfs_faces | (NOT (("]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.ADVANCED_FACE' IN TYPEOF(fa)) OR ("]
+    #[doc = "               SIZEOF(QUERY ( elp_fbnds <* QUERY ( bnds <* fa.bounds | ("]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.EDGE_LOOP' IN TYPEOF(bnds.bound)) )"]
+    #[doc = "                | (NOT (SIZEOF(QUERY ( oe <* elp_fbnds\\path.edge_list | ("]
+    #[doc = "               NOT msf_curve_check(oe.edge_element\\edge_curve."]
+    #[doc = "               edge_geometry)) )) = 0)) )) = 0))) ))"]
+    #[doc = "               = 0)) )) = 0)) )) = 0);"]
+    #[doc = "     wr12: (SIZEOF(QUERY ( sbsm <* QUERY ( it <* SELF.items | ("]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.SHELL_BASED_SURFACE_MODEL' IN"]
+    #[doc = "               TYPEOF(it)) ) | (NOT (SIZEOF(QUERY ( cfs <* sbsm\\"]
+    #[doc = "               shell_based_surface_model.sbsm_boundary | (NOT (SIZEOF("]
+    #[doc = "               QUERY ( fa <* cfs\\connected_face_set.cfs_faces | (NOT (("]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.ADVANCED_FACE' IN TYPEOF(fa)) OR ("]
+    #[doc = "               SIZEOF(QUERY ( elp_fbnds <* QUERY ( bnds <* fa.bounds | ("]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.EDGE_LOOP' IN TYPEOF(bnds.bound)) )"]
+    #[doc = "                | (NOT (SIZEOF(QUERY ( oe <* elp_fbnds\\path.edge_list | ("]
+    #[doc = "               NOT (('CONFIG_CONTROL_DESIGN.VERTEX_POINT' IN TYPEOF(oe."]
+    #[doc = "               edge_element.edge_start)) AND ("]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.VERTEX_POINT' IN TYPEOF(oe."]
+    #[doc = "               edge_element.edge_end)))) )) = 0)) )) = 0))) )) = 0)) )) ="]
+    #[doc = "               0)) )) = 0);"]
+    #[doc = "     wr13: (SIZEOF(QUERY ( sbsm <* QUERY ( it <* SELF.items | ("]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.SHELL_BASED_SURFACE_MODEL' IN"]
+    #[doc = "               TYPEOF(it)) ) | (NOT (SIZEOF(QUERY ( cfs <* sbsm\\"]
+    #[doc = "               shell_based_surface_model.sbsm_boundary | (NOT (SIZEOF("]
+    #[doc = "               QUERY ( fa <* cfs\\connected_face_set.cfs_faces | (NOT (("]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.ADVANCED_FACE' IN TYPEOF(fa)) OR ("]
+    #[doc = "               SIZEOF(QUERY ( elp_fbnds <* QUERY ( bnds <* fa.bounds | ("]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.EDGE_LOOP' IN TYPEOF(bnds.bound)) )"]
+    #[doc = "                | (NOT (SIZEOF(QUERY ( oe <* elp_fbnds\\path.edge_list | ("]
+    #[doc = "               NOT ((SIZEOF(['CONFIG_CONTROL_DESIGN.CARTESIAN_POINT',"]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.DEGENERATE_PCURVE',"]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.POINT_ON_CURVE',"]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.POINT_ON_SURFACE'] * TYPEOF(oe."]
+    #[doc = "               edge_element.edge_start\\vertex_point.vertex_geometry)) = 1)"]
+    #[doc = "               AND (SIZEOF(['CONFIG_CONTROL_DESIGN.CARTESIAN_POINT',"]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.DEGENERATE_PCURVE',"]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.POINT_ON_CURVE',"]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.POINT_ON_SURFACE'] * TYPEOF(oe."]
+    #[doc = "               edge_element.edge_end\\vertex_point.vertex_geometry))"]
+    #[doc = "               = 1))) )) = 0)) )) = 0))) )) = 0)) )) = 0)) )) = 0);"]
+    #[doc = "     wr14: (SIZEOF(QUERY ( sbsm <* QUERY ( it <* SELF.items | ("]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.SHELL_BASED_SURFACE_MODEL' IN"]
+    #[doc = "               TYPEOF(it)) ) | (NOT (SIZEOF(QUERY ( cfs <* sbsm\\"]
+    #[doc = "               shell_based_surface_model.sbsm_boundary | (NOT (SIZEOF("]
+    #[doc = "               QUERY ( fa <* cfs\\connected_face_set.cfs_faces | (NOT (("]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.ADVANCED_FACE' IN TYPEOF(fa)) OR ("]
+    #[doc = "               SIZEOF(QUERY ( vlp_fbnds <* QUERY ( bnds <* fa.bounds | ("]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.VERTEX_LOOP'"]
+    #[doc = "               IN TYPEOF(bnds.bound)) )"]
+    #[doc = "                | (NOT ('CONFIG_CONTROL_DESIGN.VERTEX_POINT' IN TYPEOF("]
+    #[doc = "               vlp_fbnds\\vertex_loop.loop_vertex))) )) = 0))) )) = 0)) ))"]
+    #[doc = "               = 0)) )) = 0);"]
+    #[doc = "     wr15: (SIZEOF(QUERY ( sbsm <* QUERY ( it <* SELF.items | ("]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.SHELL_BASED_SURFACE_MODEL' IN"]
+    #[doc = "               TYPEOF(it)) ) | (NOT (SIZEOF(QUERY ( cfs <* sbsm\\"]
+    #[doc = "               shell_based_surface_model.sbsm_boundary | (NOT (SIZEOF("]
+    #[doc = "               QUERY ( fa <* cfs\\connected_face_set.cfs_faces | (NOT (("]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.ADVANCED_FACE' IN TYPEOF(fa)) OR ("]
+    #[doc = "               SIZEOF(QUERY ( vlp_fbnds <* QUERY ( bnds <* fa.bounds | ("]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.VERTEX_LOOP'"]
+    #[doc = "               IN TYPEOF(bnds.bound)) )"]
+    #[doc = "                | (NOT (SIZEOF(['CONFIG_CONTROL_DESIGN.CARTESIAN_POINT',"]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.DEGENERATE_PCURVE',"]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.POINT_ON_CURVE',"]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.POINT_ON_SURFACE'] * TYPEOF("]
+    #[doc = "               vlp_fbnds\\vertex_loop.loop_vertex\\vertex_point."]
+    #[doc = "               vertex_geometry)) = 1)) )) = 0))) ))"]
+    #[doc = "               = 0)) )) = 0)) )) = 0);"]
+    #[doc = " END_ENTITY; -- manifold_surface_shape_representation"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -6146,6 +9166,16 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub shape_representation: ShapeRepresentation,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY mapped_item"]
+    #[doc = "   SUBTYPE OF (representation_item);"]
+    #[doc = "     mapping_source : representation_map;"]
+    #[doc = "     mapping_target : representation_item;"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: acyclic_mapped_representation(using_representations(SELF),"]
+    #[doc = "          [SELF]);"]
+    #[doc = " END_ENTITY; -- mapped_item"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -6164,6 +9194,14 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub mapping_target: RepresentationItemAny,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY mass_measure_with_unit"]
+    #[doc = "   SUBTYPE OF (measure_with_unit);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: ('CONFIG_CONTROL_DESIGN.MASS_UNIT' IN TYPEOF(SELF\\"]
+    #[doc = "              measure_with_unit.unit_component));"]
+    #[doc = " END_ENTITY; -- mass_measure_with_unit"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -6178,6 +9216,20 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub measure_with_unit: MeasureWithUnit,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY mass_unit"]
+    #[doc = "   SUBTYPE OF (named_unit);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: ((SELF\\named_unit.dimensions.length_exponent = 0) AND (SELF\\"]
+    #[doc = "              named_unit.dimensions.mass_exponent = 1) AND (SELF\\"]
+    #[doc = "              named_unit.dimensions.time_exponent = 0) AND (SELF\\"]
+    #[doc = "              named_unit.dimensions.electric_current_exponent = 0) AND ("]
+    #[doc = "              SELF\\named_unit.dimensions."]
+    #[doc = "              thermodynamic_temperature_exponent = 0) AND (SELF\\named_unit"]
+    #[doc = "              .dimensions.amount_of_substance_exponent = 0) AND (SELF\\"]
+    #[doc = "              named_unit.dimensions.luminous_intensity_exponent = 0));"]
+    #[doc = " END_ENTITY; -- mass_unit"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -6192,6 +9244,17 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub named_unit: NamedUnit,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY measure_with_unit"]
+    #[doc = "   SUPERTYPE OF (ONEOF (length_measure_with_unit,mass_measure_with_unit,"]
+    #[doc = "       plane_angle_measure_with_unit,solid_angle_measure_with_unit,"]
+    #[doc = "       area_measure_with_unit,volume_measure_with_unit));"]
+    #[doc = "     value_component : measure_value;"]
+    #[doc = "     unit_component  : unit;"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: valid_units(SELF);"]
+    #[doc = " END_ENTITY; -- measure_with_unit"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = measure_with_unit)]
@@ -6277,6 +9340,13 @@ pub mod config_control_design {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY mechanical_context"]
+    #[doc = "   SUBTYPE OF (product_context);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: (SELF.discipline_type = 'mechanical');"]
+    #[doc = " END_ENTITY; -- mechanical_context"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -6291,13 +9361,22 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub product_context: ProductContext,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY named_unit"]
+    #[doc = "   SUPERTYPE OF (ONEOF (si_unit,conversion_based_unit,"]
+    #[doc = "       context_dependent_unit) ANDOR ONEOF (length_unit,mass_unit,"]
+    #[doc = "       plane_angle_unit,solid_angle_unit,area_unit,volume_unit));"]
+    #[doc = "     dimensions : dimensional_exponents;"]
+    #[doc = " END_ENTITY; -- named_unit"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = named_unit)]
     #[holder(generate_deserialize)]
     pub struct NamedUnit {
+        #[doc = " `None` in instances of a subtype redeclaring this attribute as derived"]
         #[holder(use_place_holder)]
-        pub dimensions: DimensionalExponents,
+        pub dimensions: Option<DimensionalExponents>,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
@@ -6390,6 +9469,11 @@ pub mod config_control_design {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY next_assembly_usage_occurrence"]
+    #[doc = "   SUBTYPE OF (assembly_component_usage);"]
+    #[doc = " END_ENTITY; -- next_assembly_usage_occurrence"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -6404,6 +9488,17 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub assembly_component_usage: AssemblyComponentUsage,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY offset_curve_3d"]
+    #[doc = "   SUBTYPE OF (curve);"]
+    #[doc = "     basis_curve    : curve;"]
+    #[doc = "     distance       : length_measure;"]
+    #[doc = "     self_intersect : LOGICAL;"]
+    #[doc = "     ref_direction  : direction;"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: ((basis_curve.dim = 3) AND (ref_direction.dim = 3));"]
+    #[doc = " END_ENTITY; -- offset_curve_3d"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -6425,6 +9520,14 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub ref_direction: Direction,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY offset_surface"]
+    #[doc = "   SUBTYPE OF (surface);"]
+    #[doc = "     basis_surface  : surface;"]
+    #[doc = "     distance       : length_measure;"]
+    #[doc = "     self_intersect : LOGICAL;"]
+    #[doc = " END_ENTITY; -- offset_surface"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -6444,6 +9547,11 @@ pub mod config_control_design {
         pub distance: LengthMeasure,
         pub self_intersect: Logical,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY open_shell"]
+    #[doc = "   SUBTYPE OF (connected_face_set);"]
+    #[doc = " END_ENTITY; -- open_shell"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -6495,6 +9603,17 @@ pub mod config_control_design {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY ordinal_date"]
+    #[doc = "   SUBTYPE OF (date);"]
+    #[doc = "     day_component : day_in_year_number;"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: (((NOT leap_year(SELF.year_component)) AND (1 <= day_component)"]
+    #[doc = "              AND (day_component <= 365)) OR (leap_year(SELF."]
+    #[doc = "              year_component) AND (1 <= day_component) AND (day_component"]
+    #[doc = "              <= 366)));"]
+    #[doc = " END_ENTITY; -- ordinal_date"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -6511,6 +9630,13 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub day_component: DayInYearNumber,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY organization;"]
+    #[doc = "     id          : OPTIONAL identifier;"]
+    #[doc = "     name        : label;"]
+    #[doc = "     description : text;"]
+    #[doc = " END_ENTITY; -- organization"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = organization)]
@@ -6523,6 +9649,14 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub description: Text,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY organization_relationship;"]
+    #[doc = "     name                  : label;"]
+    #[doc = "     description           : text;"]
+    #[doc = "     relating_organization : organization;"]
+    #[doc = "     related_organization  : organization;"]
+    #[doc = " END_ENTITY; -- organization_relationship"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = organization_relationship)]
@@ -6537,6 +9671,13 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub related_organization: Organization,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY organizational_address"]
+    #[doc = "   SUBTYPE OF (address);"]
+    #[doc = "     organizations : SET [1:?] OF organization;"]
+    #[doc = "     description   : text;"]
+    #[doc = " END_ENTITY; -- organizational_address"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -6551,10 +9692,18 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub address: Address,
         #[holder(use_place_holder)]
+        # [holder (size = 1 ..)]
         pub organizations: Vec<Organization>,
         #[holder(use_place_holder)]
         pub description: Text,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY organizational_project;"]
+    #[doc = "     name                      : label;"]
+    #[doc = "     description               : text;"]
+    #[doc = "     responsible_organizations : SET [1:?] OF organization;"]
+    #[doc = " END_ENTITY; -- organizational_project"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = organizational_project)]
@@ -6565,8 +9714,23 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub description: Text,
         #[holder(use_place_holder)]
+        # [holder (size = 1 ..)]
         pub responsible_organizations: Vec<Organization>,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY oriented_closed_shell"]
+    #[doc = "   SUBTYPE OF (closed_shell);"]
+    #[doc = "     closed_shell_element : closed_shell;"]
+    #[doc = "     orientation          : BOOLEAN;"]
+    #[doc = "   DERIVE"]
+    #[doc = "     SELF\\connected_face_set.cfs_faces : SET [1:?] OF face :="]
+    #[doc = "         conditional_reverse(SELF.orientation,SELF."]
+    #[doc = "         closed_shell_element.cfs_faces);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: (NOT ('CONFIG_CONTROL_DESIGN.ORIENTED_CLOSED_SHELL' IN TYPEOF("]
+    #[doc = "              SELF.closed_shell_element)));"]
+    #[doc = " END_ENTITY; -- oriented_closed_shell"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -6584,6 +9748,23 @@ pub mod config_control_design {
         pub closed_shell_element: ClosedShellAny,
         pub orientation: bool,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY oriented_edge"]
+    #[doc = "   SUBTYPE OF (edge);"]
+    #[doc = "     edge_element : edge;"]
+    #[doc = "     orientation  : BOOLEAN;"]
+    #[doc = "   DERIVE"]
+    #[doc = "     SELF\\edge.edge_start : vertex := boolean_choose(SELF.orientation,"]
+    #[doc = "                               SELF.edge_element.edge_start,SELF."]
+    #[doc = "                               edge_element.edge_end);"]
+    #[doc = "     SELF\\edge.edge_end   : vertex := boolean_choose(SELF.orientation,"]
+    #[doc = "                               SELF.edge_element.edge_end,SELF."]
+    #[doc = "                               edge_element.edge_start);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: (NOT ('CONFIG_CONTROL_DESIGN.ORIENTED_EDGE' IN TYPEOF(SELF."]
+    #[doc = "              edge_element)));"]
+    #[doc = " END_ENTITY; -- oriented_edge"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -6601,6 +9782,19 @@ pub mod config_control_design {
         pub edge_element: EdgeAny,
         pub orientation: bool,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY oriented_face"]
+    #[doc = "   SUBTYPE OF (face);"]
+    #[doc = "     face_element : face;"]
+    #[doc = "     orientation  : BOOLEAN;"]
+    #[doc = "   DERIVE"]
+    #[doc = "     SELF\\face.bounds : SET [1:?] OF face_bound := conditional_reverse("]
+    #[doc = "                           SELF.orientation,SELF.face_element.bounds);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: (NOT ('CONFIG_CONTROL_DESIGN.ORIENTED_FACE' IN TYPEOF(SELF."]
+    #[doc = "              face_element)));"]
+    #[doc = " END_ENTITY; -- oriented_face"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -6618,6 +9812,21 @@ pub mod config_control_design {
         pub face_element: FaceAny,
         pub orientation: bool,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY oriented_open_shell"]
+    #[doc = "   SUBTYPE OF (open_shell);"]
+    #[doc = "     open_shell_element : open_shell;"]
+    #[doc = "     orientation        : BOOLEAN;"]
+    #[doc = "   DERIVE"]
+    #[doc = "     SELF\\connected_face_set.cfs_faces : SET [1:?] OF face :="]
+    #[doc = "                                            conditional_reverse(SELF."]
+    #[doc = "                                            orientation,SELF."]
+    #[doc = "                                            open_shell_element.cfs_faces);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: (NOT ('CONFIG_CONTROL_DESIGN.ORIENTED_OPEN_SHELL' IN TYPEOF("]
+    #[doc = "              SELF.open_shell_element)));"]
+    #[doc = " END_ENTITY; -- oriented_open_shell"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -6635,6 +9844,20 @@ pub mod config_control_design {
         pub open_shell_element: OpenShellAny,
         pub orientation: bool,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY oriented_path"]
+    #[doc = "   SUBTYPE OF (path);"]
+    #[doc = "     path_element : path;"]
+    #[doc = "     orientation  : BOOLEAN;"]
+    #[doc = "   DERIVE"]
+    #[doc = "     SELF\\path.edge_list : LIST [1:?] OF UNIQUE oriented_edge :="]
+    #[doc = "                              conditional_reverse(SELF.orientation,SELF."]
+    #[doc = "                              path_element.edge_list);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: (NOT ('CONFIG_CONTROL_DESIGN.ORIENTED_PATH' IN TYPEOF(SELF."]
+    #[doc = "              path_element)));"]
+    #[doc = " END_ENTITY; -- oriented_path"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -6652,6 +9875,11 @@ pub mod config_control_design {
         pub path_element: PathAny,
         pub orientation: bool,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY outer_boundary_curve"]
+    #[doc = "   SUBTYPE OF (boundary_curve);"]
+    #[doc = " END_ENTITY; -- outer_boundary_curve"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -6666,6 +9894,14 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub boundary_curve: BoundaryCurve,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY parabola"]
+    #[doc = "   SUBTYPE OF (conic);"]
+    #[doc = "     focal_dist : length_measure;"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: (focal_dist <> 0);"]
+    #[doc = " END_ENTITY; -- parabola"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -6682,6 +9918,11 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub focal_dist: LengthMeasure,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY parametric_representation_context"]
+    #[doc = "   SUBTYPE OF (representation_context);"]
+    #[doc = " END_ENTITY; -- parametric_representation_context"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -6696,6 +9937,15 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub representation_context: RepresentationContext,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY path"]
+    #[doc = "   SUPERTYPE OF (ONEOF (edge_loop,oriented_path))"]
+    #[doc = "   SUBTYPE OF (topological_representation_item);"]
+    #[doc = "     edge_list : LIST [1:?] OF UNIQUE oriented_edge;"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: path_head_to_tail(SELF);"]
+    #[doc = " END_ENTITY; -- path"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -6709,8 +9959,10 @@ pub mod config_control_design {
         #[deref_mut]
         #[holder(use_place_holder)]
         pub topological_representation_item: TopologicalRepresentationItem,
+        #[doc = " `None` in instances of a subtype redeclaring this attribute as derived"]
         #[holder(use_place_holder)]
-        pub edge_list: Vec<OrientedEdge>,
+        # [holder (size = 1 ..)]
+        pub edge_list: Option<Vec<OrientedEdge>>,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
@@ -6756,6 +10008,19 @@ pub mod config_control_design {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY pcurve"]
+    #[doc = "   SUBTYPE OF (curve);"]
+    #[doc = "     basis_surface      : surface;"]
+    #[doc = "     reference_to_curve : definitional_representation;"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: (SIZEOF(reference_to_curve\\representation.items) = 1);"]
+    #[doc = "     wr2: ('CONFIG_CONTROL_DESIGN.CURVE' IN TYPEOF(reference_to_curve\\"]
+    #[doc = "              representation.items[1]));"]
+    #[doc = "     wr3: (reference_to_curve\\representation.items[1]\\"]
+    #[doc = "              geometric_representation_item.dim = 2);"]
+    #[doc = " END_ENTITY; -- pcurve"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -6809,6 +10074,20 @@ pub mod config_control_design {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY person;"]
+    #[doc = "     id            : identifier;"]
+    #[doc = "     last_name     : OPTIONAL label;"]
+    #[doc = "     first_name    : OPTIONAL label;"]
+    #[doc = "     middle_names  : OPTIONAL LIST [1:?] OF label;"]
+    #[doc = "     prefix_titles : OPTIONAL LIST [1:?] OF label;"]
+    #[doc = "     suffix_titles : OPTIONAL LIST [1:?] OF label;"]
+    #[doc = "   UNIQUE"]
+    #[doc = "     ur1 : id;"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: (EXISTS(last_name) OR EXISTS(first_name));"]
+    #[doc = " END_ENTITY; -- person"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = person)]
@@ -6821,12 +10100,21 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub first_name: Option<Label>,
         #[holder(use_place_holder)]
+        # [holder (size = 1 ..)]
         pub middle_names: Option<Vec<Label>>,
         #[holder(use_place_holder)]
+        # [holder (size = 1 ..)]
         pub prefix_titles: Option<Vec<Label>>,
         #[holder(use_place_holder)]
+        # [holder (size = 1 ..)]
         pub suffix_titles: Option<Vec<Label>>,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY person_and_organization;"]
+    #[doc = "     the_person       : person;"]
+    #[doc = "     the_organization : organization;"]
+    #[doc = " END_ENTITY; -- person_and_organization"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = person_and_organization)]
@@ -6837,6 +10125,13 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub the_organization: Organization,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY person_and_organization_assignment"]
+    #[doc = "   ABSTRACT SUPERTYPE;"]
+    #[doc = "     assigned_person_and_organization : person_and_organization;"]
+    #[doc = "     role                             : person_and_organization_role;"]
+    #[doc = " END_ENTITY; -- person_and_organization_assignment"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = person_and_organization_assignment)]
@@ -6880,6 +10175,11 @@ pub mod config_control_design {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY person_and_organization_role;"]
+    #[doc = "     name : label;"]
+    #[doc = " END_ENTITY; -- person_and_organization_role"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = person_and_organization_role)]
@@ -6888,6 +10188,13 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub name: Label,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY personal_address"]
+    #[doc = "   SUBTYPE OF (address);"]
+    #[doc = "     people      : SET [1:?] OF person;"]
+    #[doc = "     description : text;"]
+    #[doc = " END_ENTITY; -- personal_address"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -6902,10 +10209,19 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub address: Address,
         #[holder(use_place_holder)]
+        # [holder (size = 1 ..)]
         pub people: Vec<Person>,
         #[holder(use_place_holder)]
         pub description: Text,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY placement"]
+    #[doc = "   SUPERTYPE OF (ONEOF (axis1_placement,axis2_placement_2d,"]
+    #[doc = "       axis2_placement_3d))"]
+    #[doc = "   SUBTYPE OF (geometric_representation_item);"]
+    #[doc = "     location : cartesian_point;"]
+    #[doc = " END_ENTITY; -- placement"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -6979,6 +10295,11 @@ pub mod config_control_design {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY plane"]
+    #[doc = "   SUBTYPE OF (elementary_surface);"]
+    #[doc = " END_ENTITY; -- plane"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -6993,6 +10314,14 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub elementary_surface: ElementarySurface,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY plane_angle_measure_with_unit"]
+    #[doc = "   SUBTYPE OF (measure_with_unit);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: ('CONFIG_CONTROL_DESIGN.PLANE_ANGLE_UNIT' IN TYPEOF(SELF\\"]
+    #[doc = "              measure_with_unit.unit_component));"]
+    #[doc = " END_ENTITY; -- plane_angle_measure_with_unit"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -7007,6 +10336,20 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub measure_with_unit: MeasureWithUnit,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY plane_angle_unit"]
+    #[doc = "   SUBTYPE OF (named_unit);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: ((SELF\\named_unit.dimensions.length_exponent = 0) AND (SELF\\"]
+    #[doc = "              named_unit.dimensions.mass_exponent = 0) AND (SELF\\"]
+    #[doc = "              named_unit.dimensions.time_exponent = 0) AND (SELF\\"]
+    #[doc = "              named_unit.dimensions.electric_current_exponent = 0) AND ("]
+    #[doc = "              SELF\\named_unit.dimensions."]
+    #[doc = "              thermodynamic_temperature_exponent = 0) AND (SELF\\named_unit"]
+    #[doc = "              .dimensions.amount_of_substance_exponent = 0) AND (SELF\\"]
+    #[doc = "              named_unit.dimensions.luminous_intensity_exponent = 0));"]
+    #[doc = " END_ENTITY; -- plane_angle_unit"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -7021,6 +10364,13 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub named_unit: NamedUnit,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY point"]
+    #[doc = "   SUPERTYPE OF (ONEOF (cartesian_point,point_on_curve,point_on_surface,"]
+    #[doc = "       point_replica,degenerate_pcurve))"]
+    #[doc = "   SUBTYPE OF (geometric_representation_item);"]
+    #[doc = " END_ENTITY; -- point"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -7106,6 +10456,13 @@ pub mod config_control_design {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY point_on_curve"]
+    #[doc = "   SUBTYPE OF (point);"]
+    #[doc = "     basis_curve     : curve;"]
+    #[doc = "     point_parameter : parameter_value;"]
+    #[doc = " END_ENTITY; -- point_on_curve"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -7124,6 +10481,14 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub point_parameter: ParameterValue,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY point_on_surface"]
+    #[doc = "   SUBTYPE OF (point);"]
+    #[doc = "     basis_surface     : surface;"]
+    #[doc = "     point_parameter_u : parameter_value;"]
+    #[doc = "     point_parameter_v : parameter_value;"]
+    #[doc = " END_ENTITY; -- point_on_surface"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -7144,6 +10509,16 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub point_parameter_v: ParameterValue,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY point_replica"]
+    #[doc = "   SUBTYPE OF (point);"]
+    #[doc = "     parent_pt      : point;"]
+    #[doc = "     transformation : cartesian_transformation_operator;"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: (transformation.dim = parent_pt.dim);"]
+    #[doc = "     wr2: acyclic_point_replica(SELF,parent_pt);"]
+    #[doc = " END_ENTITY; -- point_replica"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -7162,6 +10537,12 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub transformation: CartesianTransformationOperatorAny,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY poly_loop"]
+    #[doc = "   SUBTYPE OF (loop, geometric_representation_item);"]
+    #[doc = "     polygon : LIST [3:?] OF UNIQUE cartesian_point;"]
+    #[doc = " END_ENTITY; -- poly_loop"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut)]
     # [holder (table = Tables)]
     # [holder (field = poly_loop)]
@@ -7176,8 +10557,15 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub geometric_representation_item: GeometricRepresentationItem,
         #[holder(use_place_holder)]
+        # [holder (size = 3 ..)]
         pub polygon: Vec<CartesianPoint>,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY polyline"]
+    #[doc = "   SUBTYPE OF (bounded_curve);"]
+    #[doc = "     points : LIST [2:?] OF cartesian_point;"]
+    #[doc = " END_ENTITY; -- polyline"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -7192,8 +10580,19 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub bounded_curve: BoundedCurve,
         #[holder(use_place_holder)]
+        # [holder (size = 2 ..)]
         pub points: Vec<CartesianPoint>,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY product;"]
+    #[doc = "     id                 : identifier;"]
+    #[doc = "     name               : label;"]
+    #[doc = "     description        : text;"]
+    #[doc = "     frame_of_reference : SET [1:?] OF product_context;"]
+    #[doc = "   UNIQUE"]
+    #[doc = "     ur1 : id;"]
+    #[doc = " END_ENTITY; -- product"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = product)]
@@ -7206,8 +10605,15 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub description: Text,
         #[holder(use_place_holder)]
+        # [holder (size = 1 ..)]
         pub frame_of_reference: Vec<ProductContextAny>,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY product_category;"]
+    #[doc = "     name        : label;"]
+    #[doc = "     description : OPTIONAL text;"]
+    #[doc = " END_ENTITY; -- product_category"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = product_category)]
@@ -7245,6 +10651,16 @@ pub mod config_control_design {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY product_category_relationship;"]
+    #[doc = "     name         : label;"]
+    #[doc = "     description  : text;"]
+    #[doc = "     category     : product_category;"]
+    #[doc = "     sub_category : product_category;"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: acyclic_product_category_relationship(SELF,[SELF.sub_category]);"]
+    #[doc = " END_ENTITY; -- product_category_relationship"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = product_category_relationship)]
@@ -7259,6 +10675,16 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub sub_category: ProductCategoryAny,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY product_concept;"]
+    #[doc = "     id             : identifier;"]
+    #[doc = "     name           : label;"]
+    #[doc = "     description    : text;"]
+    #[doc = "     market_context : product_concept_context;"]
+    #[doc = "   UNIQUE"]
+    #[doc = "     ur1 : id;"]
+    #[doc = " END_ENTITY; -- product_concept"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = product_concept)]
@@ -7273,6 +10699,12 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub market_context: ProductConceptContext,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY product_concept_context"]
+    #[doc = "   SUBTYPE OF (application_context_element);"]
+    #[doc = "     market_segment_type : label;"]
+    #[doc = " END_ENTITY; -- product_concept_context"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -7289,6 +10721,12 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub market_segment_type: Label,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY product_context"]
+    #[doc = "   SUBTYPE OF (application_context_element);"]
+    #[doc = "     discipline_type : label;"]
+    #[doc = " END_ENTITY; -- product_context"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -7342,6 +10780,14 @@ pub mod config_control_design {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY product_definition;"]
+    #[doc = "     id                 : identifier;"]
+    #[doc = "     description        : text;"]
+    #[doc = "     formation          : product_definition_formation;"]
+    #[doc = "     frame_of_reference : product_definition_context;"]
+    #[doc = " END_ENTITY; -- product_definition"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = product_definition)]
@@ -7383,6 +10829,12 @@ pub mod config_control_design {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY product_definition_context"]
+    #[doc = "   SUBTYPE OF (application_context_element);"]
+    #[doc = "     life_cycle_stage : label;"]
+    #[doc = " END_ENTITY; -- product_definition_context"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -7438,6 +10890,14 @@ pub mod config_control_design {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY product_definition_effectivity"]
+    #[doc = "   SUBTYPE OF (effectivity);"]
+    #[doc = "     usage : product_definition_relationship;"]
+    #[doc = "   UNIQUE"]
+    #[doc = "     ur1 : usage, id;"]
+    #[doc = " END_ENTITY; -- product_definition_effectivity"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -7493,6 +10953,15 @@ pub mod config_control_design {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY product_definition_formation;"]
+    #[doc = "     id          : identifier;"]
+    #[doc = "     description : text;"]
+    #[doc = "     of_product  : product;"]
+    #[doc = "   UNIQUE"]
+    #[doc = "     ur1 : id, of_product;"]
+    #[doc = " END_ENTITY; -- product_definition_formation"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = product_definition_formation)]
@@ -7538,6 +11007,12 @@ pub mod config_control_design {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY product_definition_formation_with_specified_source"]
+    #[doc = "   SUBTYPE OF (product_definition_formation);"]
+    #[doc = "     make_or_buy : source;"]
+    #[doc = " END_ENTITY; -- product_definition_formation_with_specified_source"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -7553,6 +11028,15 @@ pub mod config_control_design {
         pub product_definition_formation: ProductDefinitionFormation,
         pub make_or_buy: Source,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY product_definition_relationship;"]
+    #[doc = "     id                          : identifier;"]
+    #[doc = "     name                        : label;"]
+    #[doc = "     description                 : text;"]
+    #[doc = "     relating_product_definition : product_definition;"]
+    #[doc = "     related_product_definition  : product_definition;"]
+    #[doc = " END_ENTITY; -- product_definition_relationship"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = product_definition_relationship)]
@@ -7612,6 +11096,16 @@ pub mod config_control_design {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY product_definition_shape"]
+    #[doc = "   SUBTYPE OF (property_definition);"]
+    #[doc = "   UNIQUE"]
+    #[doc = "     ur1 : definition;"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: (NOT ('CONFIG_CONTROL_DESIGN.SHAPE_DEFINITION' IN TYPEOF(SELF\\"]
+    #[doc = "              property_definition.definition)));"]
+    #[doc = " END_ENTITY; -- product_definition_shape"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -7626,6 +11120,18 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub property_definition: PropertyDefinition,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY product_definition_usage"]
+    #[doc = "   SUPERTYPE OF (assembly_component_usage)"]
+    #[doc = "   SUBTYPE OF (product_definition_relationship);"]
+    #[doc = "   UNIQUE"]
+    #[doc = "     ur1 : id, relating_product_definition, related_product_definition;"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: acyclic_product_definition_relationship(SELF,[SELF\\"]
+    #[doc = "              product_definition_relationship.related_product_definition],"]
+    #[doc = "              'CONFIG_CONTROL_DESIGN.PRODUCT_DEFINITION_USAGE');"]
+    #[doc = " END_ENTITY; -- product_definition_usage"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -7679,6 +11185,12 @@ pub mod config_control_design {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY product_definition_with_associated_documents"]
+    #[doc = "   SUBTYPE OF (product_definition);"]
+    #[doc = "     documentation_ids : SET [1:?] OF document;"]
+    #[doc = " END_ENTITY; -- product_definition_with_associated_documents"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -7693,8 +11205,15 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub product_definition: ProductDefinition,
         #[holder(use_place_holder)]
+        # [holder (size = 1 ..)]
         pub documentation_ids: Vec<DocumentAny>,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY product_related_product_category"]
+    #[doc = "   SUBTYPE OF (product_category);"]
+    #[doc = "     products : SET [1:?] OF product;"]
+    #[doc = " END_ENTITY; -- product_related_product_category"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -7709,8 +11228,14 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub product_category: ProductCategory,
         #[holder(use_place_holder)]
+        # [holder (size = 1 ..)]
         pub products: Vec<Product>,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY promissory_usage_occurrence"]
+    #[doc = "   SUBTYPE OF (assembly_component_usage);"]
+    #[doc = " END_ENTITY; -- promissory_usage_occurrence"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -7725,6 +11250,13 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub assembly_component_usage: AssemblyComponentUsage,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY property_definition;"]
+    #[doc = "     name        : label;"]
+    #[doc = "     description : text;"]
+    #[doc = "     definition  : characterized_definition;"]
+    #[doc = " END_ENTITY; -- property_definition"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = property_definition)]
@@ -7764,6 +11296,12 @@ pub mod config_control_design {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY property_definition_representation;"]
+    #[doc = "     definition          : property_definition;"]
+    #[doc = "     used_representation : representation;"]
+    #[doc = " END_ENTITY; -- property_definition_representation"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = property_definition_representation)]
@@ -7807,6 +11345,12 @@ pub mod config_control_design {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY quantified_assembly_component_usage"]
+    #[doc = "   SUBTYPE OF (assembly_component_usage);"]
+    #[doc = "     quantity : measure_with_unit;"]
+    #[doc = " END_ENTITY; -- quantified_assembly_component_usage"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -7823,6 +11367,11 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub quantity: MeasureWithUnitAny,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY quasi_uniform_curve"]
+    #[doc = "   SUBTYPE OF (b_spline_curve);"]
+    #[doc = " END_ENTITY; -- quasi_uniform_curve"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -7837,6 +11386,11 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub b_spline_curve: BSplineCurve,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY quasi_uniform_surface"]
+    #[doc = "   SUBTYPE OF (b_spline_surface);"]
+    #[doc = " END_ENTITY; -- quasi_uniform_surface"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -7851,6 +11405,20 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub b_spline_surface: BSplineSurface,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY rational_b_spline_curve"]
+    #[doc = "   SUBTYPE OF (b_spline_curve);"]
+    #[doc = "     weights_data : LIST [2:?] OF REAL;"]
+    #[doc = "   DERIVE"]
+    #[doc = "     weights : ARRAY [0:upper_index_on_control_points] OF REAL :="]
+    #[doc = "                  list_to_array(weights_data,0,"]
+    #[doc = "                  upper_index_on_control_points);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: (SIZEOF(weights_data) = SIZEOF(SELF\\b_spline_curve."]
+    #[doc = "              control_points_list));"]
+    #[doc = "     wr2: curve_weights_positive(SELF);"]
+    #[doc = " END_ENTITY; -- rational_b_spline_curve"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -7864,8 +11432,23 @@ pub mod config_control_design {
         #[deref_mut]
         #[holder(use_place_holder)]
         pub b_spline_curve: BSplineCurve,
+        # [holder (size = 2 ..)]
         pub weights_data: Vec<f64>,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY rational_b_spline_surface"]
+    #[doc = "   SUBTYPE OF (b_spline_surface);"]
+    #[doc = "     weights_data : LIST [2:?] OF LIST [2:?] OF REAL;"]
+    #[doc = "   DERIVE"]
+    #[doc = "     weights : ARRAY [0:u_upper] OF ARRAY [0:v_upper] OF REAL :="]
+    #[doc = "                  make_array_of_array(weights_data,0,u_upper,0,v_upper);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: ((SIZEOF(weights_data) = SIZEOF(SELF\\b_spline_surface."]
+    #[doc = "              control_points_list)) AND (SIZEOF(weights_data[1]) = SIZEOF("]
+    #[doc = "              SELF\\b_spline_surface.control_points_list[1])));"]
+    #[doc = "     wr2: surface_weights_positive(SELF);"]
+    #[doc = " END_ENTITY; -- rational_b_spline_surface"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -7879,8 +11462,21 @@ pub mod config_control_design {
         #[deref_mut]
         #[holder(use_place_holder)]
         pub b_spline_surface: BSplineSurface,
+        # [holder (size = 2 ..)]
         pub weights_data: Vec<Vec<f64>>,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY rectangular_composite_surface"]
+    #[doc = "   SUBTYPE OF (bounded_surface);"]
+    #[doc = "     segments : LIST [1:?] OF LIST [1:?] OF surface_patch;"]
+    #[doc = "   DERIVE"]
+    #[doc = "     n_u : INTEGER := SIZEOF(segments);"]
+    #[doc = "     n_v : INTEGER := SIZEOF(segments[1]);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: ([] = QUERY ( s <* segments | (n_v <> SIZEOF(s)) ));"]
+    #[doc = "     wr2: constraints_rectangular_composite_surface(SELF);"]
+    #[doc = " END_ENTITY; -- rectangular_composite_surface"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -7895,8 +11491,32 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub bounded_surface: BoundedSurface,
         #[holder(use_place_holder)]
+        # [holder (size = 1 ..)]
         pub segments: Vec<Vec<SurfacePatch>>,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY rectangular_trimmed_surface"]
+    #[doc = "   SUBTYPE OF (bounded_surface);"]
+    #[doc = "     basis_surface : surface;"]
+    #[doc = "     u1            : parameter_value;"]
+    #[doc = "     u2            : parameter_value;"]
+    #[doc = "     v1            : parameter_value;"]
+    #[doc = "     v2            : parameter_value;"]
+    #[doc = "     usense        : BOOLEAN;"]
+    #[doc = "     vsense        : BOOLEAN;"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: (u1 <> u2);"]
+    #[doc = "     wr2: (v1 <> v2);"]
+    #[doc = "     wr3: ((('CONFIG_CONTROL_DESIGN.ELEMENTARY_SURFACE' IN TYPEOF("]
+    #[doc = "              basis_surface)) AND (NOT ('CONFIG_CONTROL_DESIGN.PLANE' IN"]
+    #[doc = "              TYPEOF(basis_surface)))) OR ("]
+    #[doc = "              'CONFIG_CONTROL_DESIGN.SURFACE_OF_REVOLUTION' IN TYPEOF("]
+    #[doc = "              basis_surface)) OR (usense = (u2 > u1)));"]
+    #[doc = "     wr4: (('CONFIG_CONTROL_DESIGN.SPHERICAL_SURFACE' IN TYPEOF("]
+    #[doc = "              basis_surface)) OR ('CONFIG_CONTROL_DESIGN.TOROIDAL_SURFACE'"]
+    #[doc = "              IN TYPEOF(basis_surface)) OR (vsense = (v2 > v1)));"]
+    #[doc = " END_ENTITY; -- rectangular_trimmed_surface"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -7923,6 +11543,14 @@ pub mod config_control_design {
         pub usense: bool,
         pub vsense: bool,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY reparametrised_composite_curve_segment"]
+    #[doc = "   SUBTYPE OF (composite_curve_segment);"]
+    #[doc = "     param_length : parameter_value;"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: (param_length > 0);"]
+    #[doc = " END_ENTITY; -- reparametrised_composite_curve_segment"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -7939,6 +11567,13 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub param_length: ParameterValue,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY representation;"]
+    #[doc = "     name             : label;"]
+    #[doc = "     items            : SET [1:?] OF representation_item;"]
+    #[doc = "     context_of_items : representation_context;"]
+    #[doc = " END_ENTITY; -- representation"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = representation)]
@@ -7947,6 +11582,7 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub name: Label,
         #[holder(use_place_holder)]
+        # [holder (size = 1 ..)]
         pub items: Vec<RepresentationItemAny>,
         #[holder(use_place_holder)]
         pub context_of_items: RepresentationContextAny,
@@ -7986,6 +11622,15 @@ pub mod config_control_design {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY representation_context;"]
+    #[doc = "     context_identifier : identifier;"]
+    #[doc = "     context_type       : text;"]
+    #[doc = "   INVERSE"]
+    #[doc = "     representations_in_context : SET [1:?] OF representation FOR"]
+    #[doc = "                                     context_of_items;"]
+    #[doc = " END_ENTITY; -- representation_context"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = representation_context)]
@@ -8047,6 +11692,13 @@ pub mod config_control_design {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY representation_item;"]
+    #[doc = "     name : label;"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: (SIZEOF(using_representations(SELF)) > 0);"]
+    #[doc = " END_ENTITY; -- representation_item"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = representation_item)]
@@ -8098,6 +11750,17 @@ pub mod config_control_design {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY representation_map;"]
+    #[doc = "     mapping_origin        : representation_item;"]
+    #[doc = "     mapped_representation : representation;"]
+    #[doc = "   INVERSE"]
+    #[doc = "     map_usage : SET [1:?] OF mapped_item FOR mapping_source;"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: item_in_context(SELF.mapping_origin,SELF.mapped_representation."]
+    #[doc = "              context_of_items);"]
+    #[doc = " END_ENTITY; -- representation_map"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = representation_map)]
@@ -8108,6 +11771,14 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub mapped_representation: RepresentationAny,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY representation_relationship;"]
+    #[doc = "     name        : label;"]
+    #[doc = "     description : text;"]
+    #[doc = "     rep_1       : representation;"]
+    #[doc = "     rep_2       : representation;"]
+    #[doc = " END_ENTITY; -- representation_relationship"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = representation_relationship)]
@@ -8163,6 +11834,15 @@ pub mod config_control_design {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY representation_relationship_with_transformation"]
+    #[doc = "   SUBTYPE OF (representation_relationship);"]
+    #[doc = "     transformation_operator : transformation;"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: (SELF\\representation_relationship.rep_1.context_of_items :<>:"]
+    #[doc = "              SELF\\representation_relationship.rep_2.context_of_items);"]
+    #[doc = " END_ENTITY; -- representation_relationship_with_transformation"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -8179,6 +11859,20 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub transformation_operator: Transformation,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY seam_curve"]
+    #[doc = "   SUBTYPE OF (surface_curve);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: (SIZEOF(SELF\\surface_curve.associated_geometry) = 2);"]
+    #[doc = "     wr2: (associated_surface(SELF\\surface_curve.associated_geometry[1])"]
+    #[doc = "              = associated_surface(SELF\\surface_curve.associated_geometry["]
+    #[doc = "              2]));"]
+    #[doc = "     wr3: ('CONFIG_CONTROL_DESIGN.PCURVE' IN TYPEOF(SELF\\surface_curve."]
+    #[doc = "              associated_geometry[1]));"]
+    #[doc = "     wr4: ('CONFIG_CONTROL_DESIGN.PCURVE' IN TYPEOF(SELF\\surface_curve."]
+    #[doc = "              associated_geometry[2]));"]
+    #[doc = " END_ENTITY; -- seam_curve"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -8193,6 +11887,13 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub surface_curve: SurfaceCurve,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY security_classification;"]
+    #[doc = "     name           : label;"]
+    #[doc = "     purpose        : text;"]
+    #[doc = "     security_level : security_classification_level;"]
+    #[doc = " END_ENTITY; -- security_classification"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = security_classification)]
@@ -8205,6 +11906,12 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub security_level: SecurityClassificationLevel,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY security_classification_assignment"]
+    #[doc = "   ABSTRACT SUPERTYPE;"]
+    #[doc = "     assigned_security_classification : security_classification;"]
+    #[doc = " END_ENTITY; -- security_classification_assignment"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = security_classification_assignment)]
@@ -8246,6 +11953,11 @@ pub mod config_control_design {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY security_classification_level;"]
+    #[doc = "     name : label;"]
+    #[doc = " END_ENTITY; -- security_classification_level"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = security_classification_level)]
@@ -8254,6 +11966,13 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub name: Label,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY serial_numbered_effectivity"]
+    #[doc = "   SUBTYPE OF (effectivity);"]
+    #[doc = "     effectivity_start_id : identifier;"]
+    #[doc = "     effectivity_end_id   : OPTIONAL identifier;"]
+    #[doc = " END_ENTITY; -- serial_numbered_effectivity"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -8272,6 +11991,14 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub effectivity_end_id: Option<Identifier>,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY shape_aspect;"]
+    #[doc = "     name                 : label;"]
+    #[doc = "     description          : text;"]
+    #[doc = "     of_shape             : product_definition_shape;"]
+    #[doc = "     product_definitional : LOGICAL;"]
+    #[doc = " END_ENTITY; -- shape_aspect"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = shape_aspect)]
@@ -8285,6 +12012,14 @@ pub mod config_control_design {
         pub of_shape: ProductDefinitionShape,
         pub product_definitional: Logical,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY shape_aspect_relationship;"]
+    #[doc = "     name                  : label;"]
+    #[doc = "     description           : text;"]
+    #[doc = "     relating_shape_aspect : shape_aspect;"]
+    #[doc = "     related_shape_aspect  : shape_aspect;"]
+    #[doc = " END_ENTITY; -- shape_aspect_relationship"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = shape_aspect_relationship)]
@@ -8299,6 +12034,18 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub related_shape_aspect: ShapeAspect,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY shape_definition_representation"]
+    #[doc = "   SUBTYPE OF (property_definition_representation);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: (('CONFIG_CONTROL_DESIGN.SHAPE_DEFINITION' IN TYPEOF(SELF."]
+    #[doc = "              definition.definition)) OR ("]
+    #[doc = "              'CONFIG_CONTROL_DESIGN.PRODUCT_DEFINITION_SHAPE' IN TYPEOF("]
+    #[doc = "              SELF.definition)));"]
+    #[doc = "     wr2: ('CONFIG_CONTROL_DESIGN.SHAPE_REPRESENTATION' IN TYPEOF(SELF."]
+    #[doc = "              used_representation));"]
+    #[doc = " END_ENTITY; -- shape_definition_representation"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -8313,6 +12060,11 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub property_definition_representation: PropertyDefinitionRepresentation,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY shape_representation"]
+    #[doc = "   SUBTYPE OF (representation);"]
+    #[doc = " END_ENTITY; -- shape_representation"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -8444,6 +12196,15 @@ pub mod config_control_design {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY shape_representation_relationship"]
+    #[doc = "   SUBTYPE OF (representation_relationship);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: ('CONFIG_CONTROL_DESIGN.SHAPE_REPRESENTATION' IN (TYPEOF(SELF\\"]
+    #[doc = "              representation_relationship.rep_1) + TYPEOF(SELF\\"]
+    #[doc = "              representation_relationship.rep_2)));"]
+    #[doc = " END_ENTITY; -- shape_representation_relationship"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -8458,6 +12219,14 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub representation_relationship: RepresentationRelationship,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY shell_based_surface_model"]
+    #[doc = "   SUBTYPE OF (geometric_representation_item);"]
+    #[doc = "     sbsm_boundary : SET [1:?] OF shell;"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: constraints_geometry_shell_based_surface_model(SELF);"]
+    #[doc = " END_ENTITY; -- shell_based_surface_model"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -8472,8 +12241,17 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub geometric_representation_item: GeometricRepresentationItem,
         #[holder(use_place_holder)]
+        # [holder (size = 1 ..)]
         pub sbsm_boundary: Vec<Shell>,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY shell_based_wireframe_model"]
+    #[doc = "   SUBTYPE OF (geometric_representation_item);"]
+    #[doc = "     sbwm_boundary : SET [1:?] OF shell;"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: constraints_geometry_shell_based_wireframe_model(SELF);"]
+    #[doc = " END_ENTITY; -- shell_based_wireframe_model"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -8488,8 +12266,129 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub geometric_representation_item: GeometricRepresentationItem,
         #[holder(use_place_holder)]
+        # [holder (size = 1 ..)]
         pub sbwm_boundary: Vec<Shell>,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY shell_based_wireframe_shape_representation"]
+    #[doc = "   SUBTYPE OF (shape_representation);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1 : (SIZEOF(QUERY ( it <* SELF.items | (NOT (SIZEOF(["]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.SHELL_BASED_WIREFRAME_MODEL',"]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.MAPPED_ITEM',"]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.AXIS2_PLACEMENT_3D'] * TYPEOF(it)) ="]
+    #[doc = "                1)) )) = 0);"]
+    #[doc = "     wr2 : (SIZEOF(QUERY ( it <* SELF.items | (SIZEOF(["]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.SHELL_BASED_WIREFRAME_MODEL',"]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.MAPPED_ITEM'] * TYPEOF(it)) = 1) ))"]
+    #[doc = "               >= 1);"]
+    #[doc = "     wr3 : (SIZEOF(QUERY ( sbwm <* QUERY ( it <* SELF.items | ("]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.SHELL_BASED_WIREFRAME_MODEL' IN"]
+    #[doc = "               TYPEOF(it)) ) | (NOT (SIZEOF(QUERY ( ws <* QUERY ( sb <*"]
+    #[doc = "               sbwm\\shell_based_wireframe_model.sbwm_boundary | ("]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.WIRE_SHELL' IN TYPEOF(sb)) ) | (NOT"]
+    #[doc = "               (SIZEOF(QUERY ( eloop <* QUERY ( wsb <* ws\\wire_shell."]
+    #[doc = "               wire_shell_extent | ('CONFIG_CONTROL_DESIGN.EDGE_LOOP' IN"]
+    #[doc = "               TYPEOF(wsb)) ) | (NOT (SIZEOF(QUERY ( el <* eloop\\path."]
+    #[doc = "               edge_list | (NOT ('CONFIG_CONTROL_DESIGN.EDGE_CURVE' IN"]
+    #[doc = "               TYPEOF(el.edge_element))) )) = 0)) ))"]
+    #[doc = "               = 0)) )) = 0)) )) = 0);"]
+    #[doc = "     wr4 : (SIZEOF(QUERY ( sbwm <* QUERY ( it <* SELF.items | ("]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.SHELL_BASED_WIREFRAME_MODEL' IN"]
+    #[doc = "               TYPEOF(it)) ) | (NOT (SIZEOF(QUERY ( ws <* QUERY ( sb <*"]
+    #[doc = "               sbwm\\shell_based_wireframe_model.sbwm_boundary | ("]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.WIRE_SHELL' IN TYPEOF(sb)) ) | (NOT"]
+    #[doc = "               (SIZEOF(QUERY ( eloop <* QUERY ( wsb <* ws\\wire_shell."]
+    #[doc = "               wire_shell_extent | ('CONFIG_CONTROL_DESIGN.EDGE_LOOP' IN"]
+    #[doc = "               TYPEOF(wsb)) ) | (NOT (SIZEOF(QUERY ( pline_el <*"]
+    #[doc = "               QUERY ( el <* eloop\\path.edge_list | ("]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.POLYLINE' IN TYPEOF(el.edge_element\\"]
+    #[doc = "               edge_curve.edge_geometry)) ) | (NOT (SIZEOF(pline_el."]
+    #[doc = "               edge_element\\edge_curve.edge_geometry\\polyline.points)"]
+    #[doc = "               > 2)) )) = 0)) )) = 0)) )) = 0)) )) = 0);"]
+    #[doc = "     wr5 : (SIZEOF(QUERY ( sbwm <* QUERY ( it <* SELF.items | ("]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.SHELL_BASED_WIREFRAME_MODEL' IN"]
+    #[doc = "               TYPEOF(it)) ) | (NOT (SIZEOF(QUERY ( ws <* QUERY ( sb <*"]
+    #[doc = "               sbwm\\shell_based_wireframe_model.sbwm_boundary | ("]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.WIRE_SHELL' IN TYPEOF(sb)) ) | (NOT"]
+    #[doc = "               (SIZEOF(QUERY ( eloop <* QUERY ( wsb <* ws\\wire_shell."]
+    #[doc = "               wire_shell_extent | ('CONFIG_CONTROL_DESIGN.EDGE_LOOP' IN"]
+    #[doc = "               TYPEOF(wsb)) ) | (NOT (SIZEOF(QUERY ( el <* eloop\\path."]
+    #[doc = "               edge_list | (NOT valid_wireframe_edge_curve(el.edge_element"]
+    #[doc = "               \\edge_curve.edge_geometry)) )) = 0)) )) = 0)) )) = 0)) )) ="]
+    #[doc = "                0);"]
+    #[doc = "     wr6 : (SIZEOF(QUERY ( sbwm <* QUERY ( it <* SELF.items | ("]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.SHELL_BASED_WIREFRAME_MODEL' IN"]
+    #[doc = "               TYPEOF(it)) ) | (NOT (SIZEOF(QUERY ( ws <* QUERY ( sb <*"]
+    #[doc = "               sbwm\\shell_based_wireframe_model.sbwm_boundary | ("]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.WIRE_SHELL' IN TYPEOF(sb)) ) | (NOT"]
+    #[doc = "               (SIZEOF(QUERY ( eloop <* QUERY ( wsb <* ws\\wire_shell."]
+    #[doc = "               wire_shell_extent | ('CONFIG_CONTROL_DESIGN.EDGE_LOOP' IN"]
+    #[doc = "               TYPEOF(wsb)) ) | (NOT (SIZEOF(QUERY ( el <* eloop\\path."]
+    #[doc = "               edge_list | (NOT (('CONFIG_CONTROL_DESIGN.VERTEX_POINT' IN"]
+    #[doc = "               TYPEOF(el.edge_element.edge_start)) AND ("]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.VERTEX_POINT' IN TYPEOF(el."]
+    #[doc = "               edge_element.edge_end)))) )) = 0)) ))"]
+    #[doc = "               = 0)) )) = 0)) )) = 0);"]
+    #[doc = "     wr7 : (SIZEOF(QUERY ( sbwm <* QUERY ( it <* SELF.items | ("]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.SHELL_BASED_WIREFRAME_MODEL' IN"]
+    #[doc = "               TYPEOF(it)) ) | (NOT (SIZEOF(QUERY ( ws <* QUERY ( sb <*"]
+    #[doc = "               sbwm\\shell_based_wireframe_model.sbwm_boundary | ("]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.WIRE_SHELL' IN TYPEOF(sb)) ) | (NOT"]
+    #[doc = "               (SIZEOF(QUERY ( eloop <* QUERY ( wsb <* ws\\wire_shell."]
+    #[doc = "               wire_shell_extent | ('CONFIG_CONTROL_DESIGN.EDGE_LOOP' IN"]
+    #[doc = "               TYPEOF(wsb)) ) | (NOT (SIZEOF(QUERY ( el <* eloop\\path."]
+    #[doc = "               edge_list | (NOT (valid_wireframe_vertex_point(el."]
+    #[doc = "               edge_element.edge_start\\vertex_point.vertex_geometry) AND"]
+    #[doc = "               valid_wireframe_vertex_point(el.edge_element.edge_end\\"]
+    #[doc = "               vertex_point.vertex_geometry))) )) = 0)) ))"]
+    #[doc = "               = 0)) )) = 0)) )) = 0);"]
+    #[doc = "     wr8 : (SIZEOF(QUERY ( sbwm <* QUERY ( it <* SELF.items | ("]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.SHELL_BASED_WIREFRAME_MODEL' IN"]
+    #[doc = "               TYPEOF(it)) ) | (NOT (SIZEOF(QUERY ( ws <* QUERY ( sb <*"]
+    #[doc = "               sbwm\\shell_based_wireframe_model.sbwm_boundary | ("]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.WIRE_SHELL' IN TYPEOF(sb)) ) | (NOT"]
+    #[doc = "               (SIZEOF(QUERY ( vloop <* QUERY ( wsb <* ws\\wire_shell."]
+    #[doc = "               wire_shell_extent | ('CONFIG_CONTROL_DESIGN.VERTEX_LOOP' IN"]
+    #[doc = "                TYPEOF(wsb)) ) | (NOT ("]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.VERTEX_POINT' IN TYPEOF(vloop\\"]
+    #[doc = "               vertex_loop.loop_vertex))) )) = 0)) )) = 0)) )) = 0);"]
+    #[doc = "     wr9 : (SIZEOF(QUERY ( sbwm <* QUERY ( it <* SELF.items | ("]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.SHELL_BASED_WIREFRAME_MODEL' IN"]
+    #[doc = "               TYPEOF(it)) ) | (NOT (SIZEOF(QUERY ( ws <* QUERY ( sb <*"]
+    #[doc = "               sbwm\\shell_based_wireframe_model.sbwm_boundary | ("]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.WIRE_SHELL' IN TYPEOF(sb)) ) | (NOT"]
+    #[doc = "               (SIZEOF(QUERY ( vloop <* QUERY ( wsb <* ws\\wire_shell."]
+    #[doc = "               wire_shell_extent | ('CONFIG_CONTROL_DESIGN.VERTEX_LOOP' IN"]
+    #[doc = "                TYPEOF(wsb)) ) | (NOT valid_wireframe_vertex_point(vloop\\"]
+    #[doc = "               vertex_loop.loop_vertex\\vertex_point.vertex_geometry)) )) ="]
+    #[doc = "                0)) )) = 0)) )) = 0);"]
+    #[doc = "     wr10: (SIZEOF(QUERY ( sbwm <* QUERY ( it <* SELF.items | ("]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.SHELL_BASED_WIREFRAME_MODEL' IN"]
+    #[doc = "               TYPEOF(it)) ) | (NOT (SIZEOF(QUERY ( vs <* QUERY ( sb <*"]
+    #[doc = "               sbwm\\shell_based_wireframe_model.sbwm_boundary | ("]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.VERTEX_SHELL' IN TYPEOF(sb)) ) | ("]
+    #[doc = "               NOT ('CONFIG_CONTROL_DESIGN.VERTEX_POINT' IN TYPEOF(vs\\"]
+    #[doc = "               vertex_shell.vertex_shell_extent.loop_vertex))) )) = 0)) ))"]
+    #[doc = "               = 0);"]
+    #[doc = "     wr11: (SIZEOF(QUERY ( sbwm <* QUERY ( it <* SELF.items | ("]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.SHELL_BASED_WIREFRAME_MODEL' IN"]
+    #[doc = "               TYPEOF(it)) ) | (NOT (SIZEOF(QUERY ( vs <* QUERY ( sb <*"]
+    #[doc = "               sbwm\\shell_based_wireframe_model.sbwm_boundary | ("]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.VERTEX_SHELL' IN TYPEOF(sb)) ) | ("]
+    #[doc = "               NOT valid_wireframe_vertex_point(vs\\vertex_shell."]
+    #[doc = "               vertex_shell_extent.loop_vertex\\vertex_point."]
+    #[doc = "               vertex_geometry)) )) = 0)) )) = 0);"]
+    #[doc = "     wr12: (SIZEOF(QUERY ( mi <* QUERY ( it <* SELF.items | ("]
+    #[doc = "               'CONFIG_CONTROL_DESIGN.MAPPED_ITEM' IN TYPEOF(it)) ) | ("]
+    #[doc = "               NOT (('CONFIG_CONTROL_DESIGN.' +"]
+    #[doc = "               'SHELL_BASED_WIREFRAME_SHAPE_REPRESENTATION') IN TYPEOF(mi\\"]
+    #[doc = "               mapped_item.mapping_source.mapped_representation))) ))"]
+    #[doc = "               = 0);"]
+    #[doc = "     wr13: (SELF.context_of_items\\geometric_representation_context."]
+    #[doc = "               coordinate_space_dimension = 3);"]
+    #[doc = " END_ENTITY; -- shell_based_wireframe_shape_representation"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -8504,6 +12403,16 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub shape_representation: ShapeRepresentation,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY si_unit"]
+    #[doc = "   SUBTYPE OF (named_unit);"]
+    #[doc = "     prefix : OPTIONAL si_prefix;"]
+    #[doc = "     name   : si_unit_name;"]
+    #[doc = "   DERIVE"]
+    #[doc = "     SELF\\named_unit.dimensions : dimensional_exponents :="]
+    #[doc = "                                     dimensions_for_si_unit(SELF.name);"]
+    #[doc = " END_ENTITY; -- si_unit"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -8520,6 +12429,14 @@ pub mod config_control_design {
         pub prefix: Option<SiPrefix>,
         pub name: SiUnitName,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY solid_angle_measure_with_unit"]
+    #[doc = "   SUBTYPE OF (measure_with_unit);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: ('CONFIG_CONTROL_DESIGN.SOLID_ANGLE_UNIT' IN TYPEOF(SELF\\"]
+    #[doc = "              measure_with_unit.unit_component));"]
+    #[doc = " END_ENTITY; -- solid_angle_measure_with_unit"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -8534,6 +12451,20 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub measure_with_unit: MeasureWithUnit,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY solid_angle_unit"]
+    #[doc = "   SUBTYPE OF (named_unit);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: ((SELF\\named_unit.dimensions.length_exponent = 0) AND (SELF\\"]
+    #[doc = "              named_unit.dimensions.mass_exponent = 0) AND (SELF\\"]
+    #[doc = "              named_unit.dimensions.time_exponent = 0) AND (SELF\\"]
+    #[doc = "              named_unit.dimensions.electric_current_exponent = 0) AND ("]
+    #[doc = "              SELF\\named_unit.dimensions."]
+    #[doc = "              thermodynamic_temperature_exponent = 0) AND (SELF\\named_unit"]
+    #[doc = "              .dimensions.amount_of_substance_exponent = 0) AND (SELF\\"]
+    #[doc = "              named_unit.dimensions.luminous_intensity_exponent = 0));"]
+    #[doc = " END_ENTITY; -- solid_angle_unit"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -8548,6 +12479,12 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub named_unit: NamedUnit,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY solid_model"]
+    #[doc = "   SUPERTYPE OF (manifold_solid_brep)"]
+    #[doc = "   SUBTYPE OF (geometric_representation_item);"]
+    #[doc = " END_ENTITY; -- solid_model"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -8599,6 +12536,27 @@ pub mod config_control_design {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY specified_higher_usage_occurrence"]
+    #[doc = "   SUBTYPE OF (assembly_component_usage);"]
+    #[doc = "     upper_usage : assembly_component_usage;"]
+    #[doc = "     next_usage  : next_assembly_usage_occurrence;"]
+    #[doc = "   UNIQUE"]
+    #[doc = "     ur1 : upper_usage, next_usage;"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: (SELF :<>: upper_usage);"]
+    #[doc = "     wr2: (SELF\\product_definition_relationship."]
+    #[doc = "              relating_product_definition :=: upper_usage."]
+    #[doc = "              relating_product_definition);"]
+    #[doc = "     wr3: (SELF\\product_definition_relationship."]
+    #[doc = "              related_product_definition :=: next_usage."]
+    #[doc = "              related_product_definition);"]
+    #[doc = "     wr4: (upper_usage.related_product_definition :=: next_usage."]
+    #[doc = "              relating_product_definition);"]
+    #[doc = "     wr5: (NOT ('CONFIG_CONTROL_DESIGN.PROMISSORY_USAGE_OCCURRENCE' IN"]
+    #[doc = "              TYPEOF(upper_usage)));"]
+    #[doc = " END_ENTITY; -- specified_higher_usage_occurrence"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -8617,6 +12575,12 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub next_usage: NextAssemblyUsageOccurrence,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY spherical_surface"]
+    #[doc = "   SUBTYPE OF (elementary_surface);"]
+    #[doc = "     radius : positive_length_measure;"]
+    #[doc = " END_ENTITY; -- spherical_surface"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -8633,6 +12597,12 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub radius: PositiveLengthMeasure,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY start_request"]
+    #[doc = "   SUBTYPE OF (action_request_assignment);"]
+    #[doc = "     items : SET [1:?] OF start_request_item;"]
+    #[doc = " END_ENTITY; -- start_request"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -8647,8 +12617,15 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub action_request_assignment: ActionRequestAssignment,
         #[holder(use_place_holder)]
+        # [holder (size = 1 ..)]
         pub items: Vec<StartRequestItem>,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY start_work"]
+    #[doc = "   SUBTYPE OF (action_assignment);"]
+    #[doc = "     items : SET [1:?] OF work_item;"]
+    #[doc = " END_ENTITY; -- start_work"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -8663,8 +12640,14 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub action_assignment: ActionAssignment,
         #[holder(use_place_holder)]
+        # [holder (size = 1 ..)]
         pub items: Vec<WorkItem>,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY supplied_part_relationship"]
+    #[doc = "   SUBTYPE OF (product_definition_relationship);"]
+    #[doc = " END_ENTITY; -- supplied_part_relationship"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -8679,6 +12662,13 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub product_definition_relationship: ProductDefinitionRelationship,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY surface"]
+    #[doc = "   SUPERTYPE OF (ONEOF (elementary_surface,swept_surface,bounded_surface,"]
+    #[doc = "       offset_surface,surface_replica))"]
+    #[doc = "   SUBTYPE OF (geometric_representation_item);"]
+    #[doc = " END_ENTITY; -- surface"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -8764,6 +12754,25 @@ pub mod config_control_design {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY surface_curve"]
+    #[doc = "   SUPERTYPE OF (ONEOF (intersection_curve,seam_curve) ANDOR"]
+    #[doc = "       bounded_surface_curve)"]
+    #[doc = "   SUBTYPE OF (curve);"]
+    #[doc = "     curve_3d              : curve;"]
+    #[doc = "     associated_geometry   : LIST [1:2] OF pcurve_or_surface;"]
+    #[doc = "     master_representation : preferred_surface_curve_representation;"]
+    #[doc = "   DERIVE"]
+    #[doc = "     basis_surface : SET [1:2] OF surface := get_basis_surface(SELF);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: (curve_3d.dim = 3);"]
+    #[doc = "     wr2: (('CONFIG_CONTROL_DESIGN.PCURVE' IN TYPEOF(associated_geometry["]
+    #[doc = "              1])) OR (master_representation <> pcurve_s1));"]
+    #[doc = "     wr3: (('CONFIG_CONTROL_DESIGN.PCURVE' IN TYPEOF(associated_geometry["]
+    #[doc = "              2])) OR (master_representation <> pcurve_s2));"]
+    #[doc = "     wr4: (NOT ('CONFIG_CONTROL_DESIGN.PCURVE' IN TYPEOF(curve_3d)));"]
+    #[doc = " END_ENTITY; -- surface_curve"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -8780,6 +12789,7 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub curve_3d: CurveAny,
         #[holder(use_place_holder)]
+        # [holder (size = 1 ..= 2)]
         pub associated_geometry: Vec<PcurveOrSurface>,
         pub master_representation: PreferredSurfaceCurveRepresentation,
     }
@@ -8840,6 +12850,12 @@ pub mod config_control_design {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY surface_of_linear_extrusion"]
+    #[doc = "   SUBTYPE OF (swept_surface);"]
+    #[doc = "     extrusion_axis : vector;"]
+    #[doc = " END_ENTITY; -- surface_of_linear_extrusion"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -8856,6 +12872,15 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub extrusion_axis: Vector,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY surface_of_revolution"]
+    #[doc = "   SUBTYPE OF (swept_surface);"]
+    #[doc = "     axis_position : axis1_placement;"]
+    #[doc = "   DERIVE"]
+    #[doc = "     axis_line : line := dummy_gri || curve() || line(axis_position."]
+    #[doc = "                    location,dummy_gri || vector(axis_position.z,1));"]
+    #[doc = " END_ENTITY; -- surface_of_revolution"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -8872,6 +12897,22 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub axis_position: Axis1Placement,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY surface_patch"]
+    #[doc = "   SUBTYPE OF (founded_item);"]
+    #[doc = "     parent_surface : bounded_surface;"]
+    #[doc = "     u_transition   : transition_code;"]
+    #[doc = "     v_transition   : transition_code;"]
+    #[doc = "     u_sense        : BOOLEAN;"]
+    #[doc = "     v_sense        : BOOLEAN;"]
+    #[doc = "   INVERSE"]
+    #[doc = "     using_surfaces : BAG [1:?] OF rectangular_composite_surface FOR"]
+    #[doc = "                         segments;"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: (NOT ('CONFIG_CONTROL_DESIGN.CURVE_BOUNDED_SURFACE' IN TYPEOF("]
+    #[doc = "              parent_surface)));"]
+    #[doc = " END_ENTITY; -- surface_patch"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -8892,6 +12933,15 @@ pub mod config_control_design {
         pub u_sense: bool,
         pub v_sense: bool,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY surface_replica"]
+    #[doc = "   SUBTYPE OF (surface);"]
+    #[doc = "     parent_surface : surface;"]
+    #[doc = "     transformation : cartesian_transformation_operator_3d;"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: acyclic_surface_replica(SELF,parent_surface);"]
+    #[doc = " END_ENTITY; -- surface_replica"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -8910,6 +12960,14 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub transformation: CartesianTransformationOperator3D,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY swept_surface"]
+    #[doc = "   SUPERTYPE OF (ONEOF (surface_of_linear_extrusion,"]
+    #[doc = "       surface_of_revolution))"]
+    #[doc = "   SUBTYPE OF (surface);"]
+    #[doc = "     swept_curve : curve;"]
+    #[doc = " END_ENTITY; -- swept_surface"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -8974,6 +13032,13 @@ pub mod config_control_design {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY topological_representation_item"]
+    #[doc = "   SUPERTYPE OF (ONEOF (vertex,edge,face_bound,face,vertex_shell,"]
+    #[doc = "       wire_shell,connected_edge_set,connected_face_set,loop ANDOR path))"]
+    #[doc = "   SUBTYPE OF (representation_item);"]
+    #[doc = " END_ENTITY; -- topological_representation_item"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -9126,6 +13191,13 @@ pub mod config_control_design {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY toroidal_surface"]
+    #[doc = "   SUBTYPE OF (elementary_surface);"]
+    #[doc = "     major_radius : positive_length_measure;"]
+    #[doc = "     minor_radius : positive_length_measure;"]
+    #[doc = " END_ENTITY; -- toroidal_surface"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -9183,6 +13255,21 @@ pub mod config_control_design {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY trimmed_curve"]
+    #[doc = "   SUBTYPE OF (bounded_curve);"]
+    #[doc = "     basis_curve           : curve;"]
+    #[doc = "     trim_1                : SET [1:2] OF trimming_select;"]
+    #[doc = "     trim_2                : SET [1:2] OF trimming_select;"]
+    #[doc = "     sense_agreement       : BOOLEAN;"]
+    #[doc = "     master_representation : trimming_preference;"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: ((HIINDEX(trim_1) = 1) OR (TYPEOF(trim_1[1]) <>"]
+    #[doc = "          TYPEOF(trim_1[2])));"]
+    #[doc = "     wr2: ((HIINDEX(trim_2) = 1) OR (TYPEOF(trim_2[1]) <>"]
+    #[doc = "          TYPEOF(trim_2[2])));"]
+    #[doc = " END_ENTITY; -- trimmed_curve"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -9199,12 +13286,23 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub basis_curve: CurveAny,
         #[holder(use_place_holder)]
+        # [holder (size = 1 ..= 2)]
         pub trim_1: Vec<TrimmingSelect>,
         #[holder(use_place_holder)]
+        # [holder (size = 1 ..= 2)]
         pub trim_2: Vec<TrimmingSelect>,
         pub sense_agreement: bool,
         pub master_representation: TrimmingPreference,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY uncertainty_measure_with_unit"]
+    #[doc = "   SUBTYPE OF (measure_with_unit);"]
+    #[doc = "     name        : label;"]
+    #[doc = "     description : text;"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: valid_measure_value(SELF\\measure_with_unit.value_component);"]
+    #[doc = " END_ENTITY; -- uncertainty_measure_with_unit"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -9223,6 +13321,11 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub description: Text,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY uniform_curve"]
+    #[doc = "   SUBTYPE OF (b_spline_curve);"]
+    #[doc = " END_ENTITY; -- uniform_curve"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -9237,6 +13340,11 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub b_spline_curve: BSplineCurve,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY uniform_surface"]
+    #[doc = "   SUBTYPE OF (b_spline_surface);"]
+    #[doc = " END_ENTITY; -- uniform_surface"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -9251,6 +13359,15 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub b_spline_surface: BSplineSurface,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY vector"]
+    #[doc = "   SUBTYPE OF (geometric_representation_item);"]
+    #[doc = "     orientation : direction;"]
+    #[doc = "     magnitude   : length_measure;"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: (magnitude >= 0);"]
+    #[doc = " END_ENTITY; -- vector"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -9269,6 +13386,14 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub magnitude: LengthMeasure,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY versioned_action_request;"]
+    #[doc = "     id          : identifier;"]
+    #[doc = "     version     : label;"]
+    #[doc = "     purpose     : text;"]
+    #[doc = "     description : text;"]
+    #[doc = " END_ENTITY; -- versioned_action_request"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = versioned_action_request)]
@@ -9283,6 +13408,11 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub description: Text,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY vertex"]
+    #[doc = "   SUBTYPE OF (topological_representation_item);"]
+    #[doc = " END_ENTITY; -- vertex"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -9332,6 +13462,12 @@ pub mod config_control_design {
             }
         }
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY vertex_loop"]
+    #[doc = "   SUBTYPE OF (loop);"]
+    #[doc = "     loop_vertex : vertex;"]
+    #[doc = " END_ENTITY; -- vertex_loop"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -9348,6 +13484,12 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub loop_vertex: VertexAny,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY vertex_point"]
+    #[doc = "   SUBTYPE OF (vertex, geometric_representation_item);"]
+    #[doc = "     vertex_geometry : point;"]
+    #[doc = " END_ENTITY; -- vertex_point"]
+    #[doc = " ```"]
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut)]
     # [holder (table = Tables)]
     # [holder (field = vertex_point)]
@@ -9364,6 +13506,12 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub vertex_geometry: PointAny,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY vertex_shell"]
+    #[doc = "   SUBTYPE OF (topological_representation_item);"]
+    #[doc = "     vertex_shell_extent : vertex_loop;"]
+    #[doc = " END_ENTITY; -- vertex_shell"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -9380,6 +13528,14 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub vertex_shell_extent: VertexLoop,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY volume_measure_with_unit"]
+    #[doc = "   SUBTYPE OF (measure_with_unit);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: ('CONFIG_CONTROL_DESIGN.VOLUME_UNIT' IN TYPEOF(SELF\\"]
+    #[doc = "              measure_with_unit.unit_component));"]
+    #[doc = " END_ENTITY; -- volume_measure_with_unit"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -9394,6 +13550,20 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub measure_with_unit: MeasureWithUnit,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY volume_unit"]
+    #[doc = "   SUBTYPE OF (named_unit);"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: ((SELF\\named_unit.dimensions.length_exponent = 3) AND (SELF\\"]
+    #[doc = "              named_unit.dimensions.mass_exponent = 0) AND (SELF\\"]
+    #[doc = "              named_unit.dimensions.time_exponent = 0) AND (SELF\\"]
+    #[doc = "              named_unit.dimensions.electric_current_exponent = 0) AND ("]
+    #[doc = "              SELF\\named_unit.dimensions."]
+    #[doc = "              thermodynamic_temperature_exponent = 0) AND (SELF\\named_unit"]
+    #[doc = "              .dimensions.amount_of_substance_exponent = 0) AND (SELF\\"]
+    #[doc = "              named_unit.dimensions.luminous_intensity_exponent = 0));"]
+    #[doc = " END_ENTITY; -- volume_unit"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -9408,6 +13578,13 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub named_unit: NamedUnit,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY week_of_year_and_day_date"]
+    #[doc = "   SUBTYPE OF (date);"]
+    #[doc = "     week_component : week_in_year_number;"]
+    #[doc = "     day_component  : OPTIONAL day_in_week_number;"]
+    #[doc = " END_ENTITY; -- week_of_year_and_day_date"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -9426,6 +13603,14 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub day_component: Option<DayInWeekNumber>,
     }
+    #[doc = " ```text"]
+    #[doc = " ENTITY wire_shell"]
+    #[doc = "   SUBTYPE OF (topological_representation_item);"]
+    #[doc = "     wire_shell_extent : SET [1:?] OF loop;"]
+    #[doc = "   WHERE"]
+    #[doc = "     wr1: (NOT mixed_loop_type_set(wire_shell_extent));"]
+    #[doc = " END_ENTITY; -- wire_shell"]
+    #[doc = " ```"]
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
@@ -9440,6 +13625,443 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub topological_representation_item: TopologicalRepresentationItem,
         #[holder(use_place_holder)]
+        # [holder (size = 1 ..)]
         pub wire_shell_extent: Vec<LoopAny>,
     }
+    impl Axis1Placement {
+        #[doc = " Derived attribute `z`, whose expression is not supported yet and always returns an error"]
+        pub fn z(&self) -> crate::error::Result<Direction> {
+            Err(crate::error::Error::UnsupportedDerivedAttribute {
+                entity: "axis1_placement".to_string(),
+                attribute: "z".to_string(),
+            })
+        }
+    }
+    impl Axis2Placement2D {
+        #[doc = " Derived attribute `p`, whose expression is not supported yet and always returns an error"]
+        pub fn p(&self) -> crate::error::Result<Vec<Direction>> {
+            Err(crate::error::Error::UnsupportedDerivedAttribute {
+                entity: "axis2_placement_2d".to_string(),
+                attribute: "p".to_string(),
+            })
+        }
+    }
+    impl Axis2Placement3D {
+        #[doc = " Derived attribute `p`, whose expression is not supported yet and always returns an error"]
+        pub fn p(&self) -> crate::error::Result<Vec<Direction>> {
+            Err(crate::error::Error::UnsupportedDerivedAttribute {
+                entity: "axis2_placement_3d".to_string(),
+                attribute: "p".to_string(),
+            })
+        }
+    }
+    impl BSplineCurve {
+        pub fn upper_index_on_control_points(&self) -> crate::error::Result<i64> {
+            Ok(((self.control_points_list.len() as f64) - 1f64) as i64)
+        }
+        #[doc = " Derived attribute `control_points`, whose expression is not supported yet and always returns an error"]
+        pub fn control_points(&self) -> crate::error::Result<Vec<CartesianPoint>> {
+            Err(crate::error::Error::UnsupportedDerivedAttribute {
+                entity: "b_spline_curve".to_string(),
+                attribute: "control_points".to_string(),
+            })
+        }
+    }
+    impl BSplineCurveWithKnots {
+        pub fn upper_index_on_knots(&self) -> crate::error::Result<i64> {
+            Ok((self.knots.len() as f64) as i64)
+        }
+    }
+    impl BSplineSurface {
+        pub fn u_upper(&self) -> crate::error::Result<i64> {
+            Ok(((self.control_points_list.len() as f64) - 1f64) as i64)
+        }
+        #[doc = " Derived attribute `v_upper`, whose expression is not supported yet and always returns an error"]
+        pub fn v_upper(&self) -> crate::error::Result<i64> {
+            Err(crate::error::Error::UnsupportedDerivedAttribute {
+                entity: "b_spline_surface".to_string(),
+                attribute: "v_upper".to_string(),
+            })
+        }
+        #[doc = " Derived attribute `control_points`, whose expression is not supported yet and always returns an error"]
+        pub fn control_points(&self) -> crate::error::Result<Vec<Vec<CartesianPoint>>> {
+            Err(crate::error::Error::UnsupportedDerivedAttribute {
+                entity: "b_spline_surface".to_string(),
+                attribute: "control_points".to_string(),
+            })
+        }
+    }
+    impl BSplineSurfaceWithKnots {
+        pub fn knot_u_upper(&self) -> crate::error::Result<i64> {
+            Ok((self.u_knots.len() as f64) as i64)
+        }
+        pub fn knot_v_upper(&self) -> crate::error::Result<i64> {
+            Ok((self.v_knots.len() as f64) as i64)
+        }
+    }
+    impl CartesianTransformationOperator {
+        #[doc = " Derived attribute `scl`, whose expression is not supported yet and always returns an error"]
+        pub fn scl(&self) -> crate::error::Result<f64> {
+            Err(crate::error::Error::UnsupportedDerivedAttribute {
+                entity: "cartesian_transformation_operator".to_string(),
+                attribute: "scl".to_string(),
+            })
+        }
+    }
+    impl CartesianTransformationOperator3D {
+        #[doc = " Derived attribute `u`, whose expression is not supported yet and always returns an error"]
+        pub fn u(&self) -> crate::error::Result<Vec<Direction>> {
+            Err(crate::error::Error::UnsupportedDerivedAttribute {
+                entity: "cartesian_transformation_operator_3d".to_string(),
+                attribute: "u".to_string(),
+            })
+        }
+    }
+    impl CompositeCurve {
+        pub fn n_segments(&self) -> crate::error::Result<i64> {
+            Ok((self.segments.len() as f64) as i64)
+        }
+        #[doc = " Derived attribute `closed_curve`, whose expression is not supported yet and always returns an error"]
+        pub fn closed_curve(&self) -> crate::error::Result<Logical> {
+            Err(crate::error::Error::UnsupportedDerivedAttribute {
+                entity: "composite_curve".to_string(),
+                attribute: "closed_curve".to_string(),
+            })
+        }
+    }
+    impl CompositeCurveOnSurface {
+        #[doc = " Derived attribute `basis_surface`, whose expression is not supported yet and always returns an error"]
+        pub fn basis_surface(&self) -> crate::error::Result<Vec<SurfaceAny>> {
+            Err(crate::error::Error::UnsupportedDerivedAttribute {
+                entity: "composite_curve_on_surface".to_string(),
+                attribute: "basis_surface".to_string(),
+            })
+        }
+    }
+    impl EdgeLoop {
+        #[doc = " Derived attribute `ne`, whose expression is not supported yet and always returns an error"]
+        pub fn ne(&self) -> crate::error::Result<i64> {
+            Err(crate::error::Error::UnsupportedDerivedAttribute {
+                entity: "edge_loop".to_string(),
+                attribute: "ne".to_string(),
+            })
+        }
+    }
+    impl GeometricRepresentationItem {
+        #[doc = " Derived attribute `dim`, whose expression is not supported yet and always returns an error"]
+        pub fn dim(&self) -> crate::error::Result<DimensionCount> {
+            Err(crate::error::Error::UnsupportedDerivedAttribute {
+                entity: "geometric_representation_item".to_string(),
+                attribute: "dim".to_string(),
+            })
+        }
+    }
+    impl OrientedClosedShell {
+        #[doc = " Derived attribute `cfs_faces`, whose expression is not supported yet and always returns an error"]
+        pub fn cfs_faces(&self) -> crate::error::Result<Vec<FaceAny>> {
+            Err(crate::error::Error::UnsupportedDerivedAttribute {
+                entity: "oriented_closed_shell".to_string(),
+                attribute: "cfs_faces".to_string(),
+            })
+        }
+    }
+    impl OrientedEdge {
+        #[doc = " Derived attribute `edge_start`, whose expression is not supported yet and always returns an error"]
+        pub fn edge_start(&self) -> crate::error::Result<VertexAny> {
+            Err(crate::error::Error::UnsupportedDerivedAttribute {
+                entity: "oriented_edge".to_string(),
+                attribute: "edge_start".to_string(),
+            })
+        }
+        #[doc = " Derived attribute `edge_end`, whose expression is not supported yet and always returns an error"]
+        pub fn edge_end(&self) -> crate::error::Result<VertexAny> {
+            Err(crate::error::Error::UnsupportedDerivedAttribute {
+                entity: "oriented_edge".to_string(),
+                attribute: "edge_end".to_string(),
+            })
+        }
+    }
+    impl OrientedFace {
+        #[doc = " Derived attribute `bounds`, whose expression is not supported yet and always returns an error"]
+        pub fn bounds(&self) -> crate::error::Result<Vec<FaceBoundAny>> {
+            Err(crate::error::Error::UnsupportedDerivedAttribute {
+                entity: "oriented_face".to_string(),
+                attribute: "bounds".to_string(),
+            })
+        }
+    }
+    impl OrientedOpenShell {
+        #[doc = " Derived attribute `cfs_faces`, whose expression is not supported yet and always returns an error"]
+        pub fn cfs_faces(&self) -> crate::error::Result<Vec<FaceAny>> {
+            Err(crate::error::Error::UnsupportedDerivedAttribute {
+                entity: "oriented_open_shell".to_string(),
+                attribute: "cfs_faces".to_string(),
+            })
+        }
+    }
+    impl OrientedPath {
+        #[doc = " Derived attribute `edge_list`, whose expression is not supported yet and always returns an error"]
+        pub fn edge_list(&self) -> crate::error::Result<Vec<OrientedEdge>> {
+            Err(crate::error::Error::UnsupportedDerivedAttribute {
+                entity: "oriented_path".to_string(),
+                attribute: "edge_list".to_string(),
+            })
+        }
+    }
+    impl RationalBSplineCurve {
+        #[doc = " Derived attribute `weights`, whose expression is not supported yet and always returns an error"]
+        pub fn weights(&self) -> crate::error::Result<Vec<f64>> {
+            Err(crate::error::Error::UnsupportedDerivedAttribute {
+                entity: "rational_b_spline_curve".to_string(),
+                attribute: "weights".to_string(),
+            })
+        }
+    }
+    impl RationalBSplineSurface {
+        #[doc = " Derived attribute `weights`, whose expression is not supported yet and always returns an error"]
+        pub fn weights(&self) -> crate::error::Result<Vec<Vec<f64>>> {
+            Err(crate::error::Error::UnsupportedDerivedAttribute {
+                entity: "rational_b_spline_surface".to_string(),
+                attribute: "weights".to_string(),
+            })
+        }
+    }
+    impl RectangularCompositeSurface {
+        pub fn n_u(&self) -> crate::error::Result<i64> {
+            Ok((self.segments.len() as f64) as i64)
+        }
+        #[doc = " Derived attribute `n_v`, whose expression is not supported yet and always returns an error"]
+        pub fn n_v(&self) -> crate::error::Result<i64> {
+            Err(crate::error::Error::UnsupportedDerivedAttribute {
+                entity: "rectangular_composite_surface".to_string(),
+                attribute: "n_v".to_string(),
+            })
+        }
+    }
+    impl SiUnit {
+        #[doc = " Derived attribute `dimensions`, whose expression is not supported yet and always returns an error"]
+        pub fn dimensions(&self) -> crate::error::Result<DimensionalExponents> {
+            Err(crate::error::Error::UnsupportedDerivedAttribute {
+                entity: "si_unit".to_string(),
+                attribute: "dimensions".to_string(),
+            })
+        }
+    }
+    impl SurfaceCurve {
+        #[doc = " Derived attribute `basis_surface`, whose expression is not supported yet and always returns an error"]
+        pub fn basis_surface(&self) -> crate::error::Result<Vec<SurfaceAny>> {
+            Err(crate::error::Error::UnsupportedDerivedAttribute {
+                entity: "surface_curve".to_string(),
+                attribute: "basis_surface".to_string(),
+            })
+        }
+    }
+    impl SurfaceOfRevolution {
+        #[doc = " Derived attribute `axis_line`, whose expression is not supported yet and always returns an error"]
+        pub fn axis_line(&self) -> crate::error::Result<Line> {
+            Err(crate::error::Error::UnsupportedDerivedAttribute {
+                entity: "surface_of_revolution".to_string(),
+                attribute: "axis_line".to_string(),
+            })
+        }
+    }
+    impl ApplicationContext {
+        pub fn context_elements(&self, id: u64, tables: &Tables) -> Vec<u64> {
+            tables
+                ._inverse_index
+                .lookup("application_context.context_elements", id, || {
+                    let mut index = ::std::collections::HashMap::new();
+                    crate::tables::index_referrers(
+                        &mut index,
+                        &tables.application_context_element,
+                        |holder, ids| {
+                            crate::tables::Refer::referred_ids(&holder.frame_of_reference, ids)
+                        },
+                    );
+                    crate::tables::index_referrers(
+                        &mut index,
+                        &tables.product_concept_context,
+                        |holder, ids| {
+                            if let Some(holder) = Some(holder)
+                                .and_then(|holder| holder.application_context_element.as_owned())
+                            {
+                                crate::tables::Refer::referred_ids(&holder.frame_of_reference, ids)
+                            }
+                        },
+                    );
+                    crate::tables::index_referrers(
+                        &mut index,
+                        &tables.product_context,
+                        |holder, ids| {
+                            if let Some(holder) = Some(holder)
+                                .and_then(|holder| holder.application_context_element.as_owned())
+                            {
+                                crate::tables::Refer::referred_ids(&holder.frame_of_reference, ids)
+                            }
+                        },
+                    );
+                    crate::tables::index_referrers(
+                        &mut index,
+                        &tables.product_definition_context,
+                        |holder, ids| {
+                            if let Some(holder) = Some(holder)
+                                .and_then(|holder| holder.application_context_element.as_owned())
+                            {
+                                crate::tables::Refer::referred_ids(&holder.frame_of_reference, ids)
+                            }
+                        },
+                    );
+                    index
+                })
+        }
+        pub fn check_inverse(&self, id: u64, tables: &Tables) -> crate::error::Result<()> {
+            crate::tables::check_cardinality(
+                "context_elements",
+                id,
+                self.context_elements(id, tables).len(),
+                1u64,
+                None,
+            )?;
+            Ok(())
+        }
+    }
+    impl CompositeCurveSegment {
+        pub fn using_curves(&self, id: u64, tables: &Tables) -> Vec<u64> {
+            tables
+                ._inverse_index
+                .lookup("composite_curve_segment.using_curves", id, || {
+                    let mut index = ::std::collections::HashMap::new();
+                    crate::tables::index_referrers(
+                        &mut index,
+                        &tables.composite_curve,
+                        |holder, ids| crate::tables::Refer::referred_ids(&holder.segments, ids),
+                    );
+                    crate::tables::index_referrers(
+                        &mut index,
+                        &tables.composite_curve_on_surface,
+                        |holder, ids| {
+                            if let Some(holder) =
+                                Some(holder).and_then(|holder| holder.composite_curve.as_owned())
+                            {
+                                crate::tables::Refer::referred_ids(&holder.segments, ids)
+                            }
+                        },
+                    );
+                    index
+                })
+        }
+        pub fn check_inverse(&self, id: u64, tables: &Tables) -> crate::error::Result<()> {
+            crate::tables::check_cardinality(
+                "using_curves",
+                id,
+                self.using_curves(id, tables).len(),
+                1u64,
+                None,
+            )?;
+            Ok(())
+        }
+    }
+    impl RepresentationContext {
+        pub fn representations_in_context(&self, id: u64, tables: &Tables) -> Vec<u64> {
+            tables._inverse_index.lookup(
+                "representation_context.representations_in_context",
+                id,
+                || {
+                    let mut index = ::std::collections::HashMap::new();
+                    crate::tables::index_referrers(
+                        &mut index,
+                        &tables.representation,
+                        |holder, ids| {
+                            crate::tables::Refer::referred_ids(&holder.context_of_items, ids)
+                        },
+                    );
+                    crate::tables::index_referrers(
+                        &mut index,
+                        &tables.definitional_representation,
+                        |holder, ids| {
+                            if let Some(holder) =
+                                Some(holder).and_then(|holder| holder.representation.as_owned())
+                            {
+                                crate::tables::Refer::referred_ids(&holder.context_of_items, ids)
+                            }
+                        },
+                    );
+                    crate::tables::index_referrers(
+                        &mut index,
+                        &tables.shape_representation,
+                        |holder, ids| {
+                            if let Some(holder) =
+                                Some(holder).and_then(|holder| holder.representation.as_owned())
+                            {
+                                crate::tables::Refer::referred_ids(&holder.context_of_items, ids)
+                            }
+                        },
+                    );
+                    index
+                },
+            )
+        }
+        pub fn check_inverse(&self, id: u64, tables: &Tables) -> crate::error::Result<()> {
+            crate::tables::check_cardinality(
+                "representations_in_context",
+                id,
+                self.representations_in_context(id, tables).len(),
+                1u64,
+                None,
+            )?;
+            Ok(())
+        }
+    }
+    impl RepresentationMap {
+        pub fn map_usage(&self, id: u64, tables: &Tables) -> Vec<u64> {
+            tables
+                ._inverse_index
+                .lookup("representation_map.map_usage", id, || {
+                    let mut index = ::std::collections::HashMap::new();
+                    crate::tables::index_referrers(
+                        &mut index,
+                        &tables.mapped_item,
+                        |holder, ids| {
+                            crate::tables::Refer::referred_ids(&holder.mapping_source, ids)
+                        },
+                    );
+                    index
+                })
+        }
+        pub fn check_inverse(&self, id: u64, tables: &Tables) -> crate::error::Result<()> {
+            crate::tables::check_cardinality(
+                "map_usage",
+                id,
+                self.map_usage(id, tables).len(),
+                1u64,
+                None,
+            )?;
+            Ok(())
+        }
+    }
+    impl SurfacePatch {
+        pub fn using_surfaces(&self, id: u64, tables: &Tables) -> Vec<u64> {
+            tables
+                ._inverse_index
+                .lookup("surface_patch.using_surfaces", id, || {
+                    let mut index = ::std::collections::HashMap::new();
+                    crate::tables::index_referrers(
+                        &mut index,
+                        &tables.rectangular_composite_surface,
+                        |holder, ids| crate::tables::Refer::referred_ids(&holder.segments, ids),
+                    );
+                    index
+                })
+        }
+        pub fn check_inverse(&self, id: u64, tables: &Tables) -> crate::error::Result<()> {
+            crate::tables::check_cardinality(
+                "using_surfaces",
+                id,
+                self.using_surfaces(id, tables).len(),
+                1u64,
+                None,
+            )?;
+            Ok(())
+        }
+    }
 }
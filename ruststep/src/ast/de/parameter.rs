@@ -20,6 +20,7 @@ impl<'de, 'param> de::Deserializer<'de> for &'param Parameter {
             Parameter::Integer(val) => visitor.visit_i64(*val),
             Parameter::Real(val) => visitor.visit_f64(*val),
             Parameter::String(val) => visitor.visit_str(val),
+            Parameter::Binary(val) => visitor.visit_str(&val.to_string()),
             Parameter::List(params) => visitor.visit_seq(SeqDeserializer::new(params)),
             Parameter::Ref(name) => visitor.visit_enum(name),
             Parameter::NotProvided | Parameter::Omitted => visitor.visit_none(),
@@ -37,7 +37,7 @@
 pub mod de;
 pub mod ser;
 
-use crate::parser;
+use crate::{parser, primitive::Binary};
 use std::str::FromStr;
 
 /// AST portion
@@ -460,6 +460,7 @@ derive_ast_from_str!(DataSection, parser::exchange::data_section);
 /// | NotProvided | option (always none)|
 /// | Omitted     | option (always none)|
 /// | Enumeration | unit_variant (through [serde::de::value::StringDeserializer])|
+/// | Binary      | string (hexadecimal digits like `0A3F`) |
 /// | Typed       | map (through [de::RecordDeserializer])|
 /// | Ref         | newtype_variant  |
 ///
@@ -633,6 +634,32 @@ pub enum Parameter {
     ///
     Enumeration(String),
 
+    /// Binary, a sequence of bits encoded by hexadecimal digits like `"0A3F"`
+    ///
+    /// FromStr
+    /// --------
+    /// ```
+    /// use std::str::FromStr;
+    /// use ruststep::{ast::Parameter, primitive::Binary};
+    ///
+    /// let p = Parameter::from_str("\"0A3F\"").unwrap();
+    /// assert_eq!(p, Parameter::Binary(Binary::from_str("0A3F").unwrap()));
+    /// ```
+    ///
+    /// Deserialize
+    /// ------------
+    /// ```
+    /// use std::str::FromStr;
+    /// use ruststep::{ast::Parameter, primitive::Binary};
+    /// use serde::Deserialize;
+    ///
+    /// let p = Parameter::from_str("\"21\"").unwrap();
+    /// let b = Binary::deserialize(&p).unwrap();
+    /// assert_eq!(b.iter().collect::<Vec<_>>(), [false, true]);
+    /// ```
+    #[from]
+    Binary(Binary),
+
     /// List of parameters. This can be non-uniform.
     ///
     /// FromStr
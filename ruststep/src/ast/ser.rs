@@ -1,4 +1,8 @@
-use crate::{ast::*, error::*};
+use crate::{
+    ast::*,
+    error::*,
+    primitive::{binary::BINARY_NEWTYPE, Binary},
+};
 use serde::ser;
 use std::convert::TryFrom;
 
@@ -85,8 +89,9 @@ impl<'se> ser::Serializer for &'se mut RecordSerializer {
         self.parameters.push(Parameter::String(v.to_string()));
         Ok(())
     }
-    fn serialize_bytes(self, _v: &[u8]) -> Result<()> {
-        unimplemented!("Bytes is not supported yet")
+    fn serialize_bytes(self, v: &[u8]) -> Result<()> {
+        self.parameters.push(Parameter::Binary(Binary::from(v)));
+        Ok(())
     }
 
     fn serialize_none(self) -> Result<()> {
@@ -118,11 +123,26 @@ impl<'se> ser::Serializer for &'se mut RecordSerializer {
         self.serialize_str(variant)
     }
 
-    fn serialize_newtype_struct<T>(self, _name: &'static str, value: &T) -> Result<()>
+    fn serialize_newtype_struct<T>(self, name: &'static str, value: &T) -> Result<()>
     where
         T: ?Sized + ser::Serialize,
     {
-        value.serialize(self)
+        value.serialize(&mut *self)?;
+        // Binary is serialized as a string of hexadecimal digits wrapped by a newtype struct
+        // named `BINARY_NEWTYPE`, see the document of it
+        if name == BINARY_NEWTYPE {
+            match self.parameters.pop() {
+                Some(Parameter::String(hex)) => {
+                    self.parameters.push(Parameter::Binary(hex.parse()?));
+                }
+                _ => {
+                    return Err(ser::Error::custom(
+                        "Binary must be serialized as hexadecimal digits",
+                    ))
+                }
+            }
+        }
+        Ok(())
     }
 
     fn serialize_newtype_variant<T>(
@@ -0,0 +1,667 @@
+//! Entity instances typed by an EXPRESS schema given at runtime
+//!
+//! While the schema-specific tables generated by espr require compiling Rust code for each schema,
+//! [Model] reads a data section along with [espr::ir::IR] built at runtime,
+//! e.g. from an arbitrary `.exp` file uploaded by a user.
+//! Each record is checked against the schema,
+//! and its attributes are resolved by name including inherited ones:
+//!
+//! ```
+//! use espr::{ast::SyntaxTree, ir::IR};
+//! use ruststep::{ast::DataSection, dynamic::*};
+//! use std::str::FromStr;
+//!
+//! let ir = IR::from_syntax_tree(
+//!     &SyntaxTree::parse(
+//!         r#"
+//!         SCHEMA shapes;
+//!           ENTITY named;
+//!             name: STRING;
+//!           END_ENTITY;
+//!           ENTITY point SUBTYPE OF (named);
+//!             x: REAL;
+//!             y: REAL;
+//!           END_ENTITY;
+//!         END_SCHEMA;
+//!         "#,
+//!     )
+//!     .unwrap(),
+//! )
+//! .unwrap();
+//!
+//! let data = DataSection::from_str(
+//!     r#"
+//!     DATA;
+//!       #1 = POINT('origin', 0.0, 0.0);
+//!     ENDSEC;
+//!     "#,
+//! )
+//! .unwrap();
+//!
+//! let model = Model::from_data_section(&ir, &data).unwrap();
+//! let point = model.get(1).unwrap();
+//! assert!(point.is_a("named"));
+//! assert_eq!(point.get("name"), Some(&Value::String("origin".to_string())));
+//! assert_eq!(model.instances_of("named").count(), 1);
+//! ```
+
+use crate::{
+    ast::{DataSection, EntityInstance, Name, Parameter, Record},
+    error::*,
+    primitive::{Binary, Logical},
+    reflection::{self, ConstraintNode, Node},
+};
+use espr::ir::{
+    ConstraintExpr, Entity, EntityAttribute, Scope, Select, SimpleType, TypeDecl, TypeRef, IR,
+};
+use std::collections::{BTreeMap, HashMap};
+
+/// Value of an attribute checked by the schema
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value<'ir> {
+    Integer(i64),
+    Real(f64),
+    String(String),
+    Boolean(bool),
+    Logical(Logical),
+    Binary(Binary),
+    /// Item of `ENUMERATION` in lower case
+    Enumeration(String),
+    /// Reference to another instance in the [Model], e.g. `#12`
+    Ref(u64),
+    /// Instance written inline, e.g. `POINT((0.0, 0.0))`
+    Entity(Box<Instance<'ir>>),
+    /// Value of a defined type in `SELECT`, e.g. `LABEL('A')`, where `name` is in lower case
+    Typed {
+        name: String,
+        value: Box<Value<'ir>>,
+    },
+    /// Elements of an aggregate
+    List(Vec<Value<'ir>>),
+    /// Not provided optional value, `$`
+    Null,
+    /// Omitted value of an attribute redeclared as derived in subtype, `*`
+    Derived,
+}
+
+/// Attributes of an entity composing an [Instance]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Partial<'ir> {
+    pub entity: &'ir Entity,
+    /// Values of explicit attributes declared in `entity`, in the declaration order
+    pub values: Vec<Value<'ir>>,
+}
+
+/// An entity instance, simple or complex
+#[derive(Debug, Clone, PartialEq)]
+pub struct Instance<'ir> {
+    id: Option<u64>,
+    partials: Vec<Partial<'ir>>,
+}
+
+impl<'ir> Instance<'ir> {
+    /// Entity instance name, `None` if written inline
+    pub fn id(&self) -> Option<u64> {
+        self.id
+    }
+
+    /// Every entity composing this instance, including supertypes
+    pub fn partials(&self) -> &[Partial<'ir>] {
+        &self.partials
+    }
+
+    /// Names of the entities composing this instance
+    pub fn entities(&self) -> impl Iterator<Item = &'ir str> + '_ {
+        self.partials.iter().map(|p| p.entity.name.as_str())
+    }
+
+    /// Check if this instance is of `entity` or its subtype, case-insensitively
+    pub fn is_a(&self, entity: &str) -> bool {
+        self.entities()
+            .any(|name| name.eq_ignore_ascii_case(entity))
+    }
+
+    /// Look up an attribute by name case-insensitively, including inherited ones
+    pub fn get(&self, attribute: &str) -> Option<&Value<'ir>> {
+        self.attributes()
+            .find(|(attr, _)| attr.name.eq_ignore_ascii_case(attribute))
+            .map(|(_, value)| value)
+    }
+
+    /// Every explicit attribute with its value
+    pub fn attributes(&self) -> impl Iterator<Item = (&'ir EntityAttribute, &Value<'ir>)> + '_ {
+        self.partials
+            .iter()
+            .flat_map(|p| p.entity.attributes.iter().zip(&p.values))
+    }
+}
+
+/// Attribute being read
+#[derive(Clone, Copy)]
+struct Context<'a> {
+    entity: &'a Entity,
+    attribute: &'a EntityAttribute,
+}
+
+/// Reference found while reading a record, checked after every record is read
+struct Reference {
+    id: u64,
+    /// The target must be an instance of one of these entities
+    entities: Vec<String>,
+    entity: String,
+    attribute: String,
+    expected: String,
+}
+
+impl Reference {
+    fn new(id: u64, entities: Vec<String>, at: Context) -> Self {
+        Reference {
+            id,
+            entities,
+            entity: at.entity.name.clone(),
+            attribute: at.attribute.name.clone(),
+            expected: at.attribute.ty.to_string(),
+        }
+    }
+}
+
+impl<'a> ConstraintNode<'a> for &'a ConstraintExpr {
+    fn node(self) -> Node<'a, Self> {
+        match self {
+            ConstraintExpr::Reference(path) => Node::Reference(&path.name),
+            ConstraintExpr::AndOr(exprs) => Node::AndOr(exprs.iter().collect()),
+            ConstraintExpr::And(exprs) => Node::And(exprs.iter().collect()),
+            ConstraintExpr::OneOf(exprs) => Node::OneOf(exprs.iter().collect()),
+        }
+    }
+}
+
+/// Declarations of each name with the schema declaring it,
+/// since schemas may declare entities or types of the same name
+type Declarations<'ir, T> = HashMap<&'ir str, Vec<(&'ir str, &'ir T)>>;
+
+/// Declaration of `name` in the schema of `scope`
+fn declared<'ir, T>(
+    declarations: &Declarations<'ir, T>,
+    name: &str,
+    scope: &Scope,
+) -> Option<&'ir T> {
+    let schema = scope.schema_name()?;
+    declarations
+        .get(name)?
+        .iter()
+        .find(|(s, _)| *s == schema)
+        .map(|(_, declaration)| *declaration)
+}
+
+/// Entity instances in data sections typed by [IR]
+#[derive(Debug, Clone)]
+pub struct Model<'ir> {
+    schemas: Vec<&'ir str>,
+    entities: Declarations<'ir, Entity>,
+    types: Declarations<'ir, TypeDecl>,
+    instances: BTreeMap<u64, Instance<'ir>>,
+}
+
+impl<'ir> Model<'ir> {
+    /// Empty model of entities and types in every schema of `ir`
+    ///
+    /// Records are read by the entity of its keyword in any schema,
+    /// and [Error::AmbiguousEntityName] is returned if several schemas declare it.
+    pub fn new(ir: &'ir IR) -> Self {
+        let mut entities: Declarations<Entity> = HashMap::new();
+        let mut types: Declarations<TypeDecl> = HashMap::new();
+        for schema in &ir.schemas {
+            for entity in &schema.entities {
+                entities
+                    .entry(entity.name.as_str())
+                    .or_default()
+                    .push((&schema.name, entity));
+            }
+            for ty in &schema.types {
+                types.entry(ty.id()).or_default().push((&schema.name, ty));
+            }
+        }
+        Model {
+            schemas: ir.schemas.iter().map(|s| s.name.as_str()).collect(),
+            entities,
+            types,
+            instances: BTreeMap::new(),
+        }
+    }
+
+    pub fn from_data_section(ir: &'ir IR, section: &DataSection) -> Result<Self> {
+        let mut model = Self::new(ir);
+        model.append_data_section(section)?;
+        Ok(model)
+    }
+
+    /// Read and check every instance in `section`,
+    /// where references may refer instances appended before
+    pub fn append_data_section(&mut self, section: &DataSection) -> Result<()> {
+        let mut references = Vec::new();
+        let mut instances = BTreeMap::new();
+        for instance in &section.entities {
+            let (id, instance) = match instance {
+                EntityInstance::Simple { id, record } => {
+                    (*id, self.simple(Some(*id), record, &mut references)?)
+                }
+                EntityInstance::Complex { id, subsuper } => {
+                    (*id, self.complex(*id, &subsuper.0, &mut references)?)
+                }
+            };
+            if self.instances.contains_key(&id) || instances.insert(id, instance).is_some() {
+                return Err(Error::DuplicatedEntity(id));
+            }
+        }
+        for reference in references {
+            let target = instances
+                .get(&reference.id)
+                .or_else(|| self.instances.get(&reference.id))
+                .ok_or(Error::UnknownEntity(reference.id))?;
+            if !reference.entities.iter().any(|e| target.is_a(e)) {
+                return Err(Error::InvalidParameter {
+                    entity: reference.entity,
+                    attribute: reference.attribute,
+                    expected: reference.expected,
+                    parameter: format!("#{}", reference.id),
+                });
+            }
+        }
+        self.instances.append(&mut instances);
+        Ok(())
+    }
+
+    pub fn get(&self, id: u64) -> Option<&Instance<'ir>> {
+        self.instances.get(&id)
+    }
+
+    /// Instances sorted by id
+    pub fn instances(&self) -> impl Iterator<Item = &Instance<'ir>> {
+        self.instances.values()
+    }
+
+    /// Instances of `entity` including its subtypes, sorted by id
+    pub fn instances_of<'a>(&'a self, entity: &'a str) -> impl Iterator<Item = &'a Instance<'ir>> {
+        self.instances()
+            .filter(move |instance| instance.is_a(entity))
+    }
+
+    /// Instance referred or written inline as `value`
+    pub fn resolve<'a>(&'a self, value: &'a Value<'ir>) -> Option<&'a Instance<'ir>> {
+        match value {
+            Value::Ref(id) => self.get(*id),
+            Value::Entity(instance) => Some(instance),
+            _ => None,
+        }
+    }
+
+    fn entity(&self, keyword: &str) -> Result<&'ir Entity> {
+        match self.entities.get(keyword.to_lowercase().as_str()) {
+            Some(declarations) if declarations.len() == 1 => Ok(declarations[0].1),
+            Some(declarations) => Err(Error::AmbiguousEntityName {
+                entity_name: keyword.to_string(),
+                schemas: declarations
+                    .iter()
+                    .map(|(schema, _)| *schema)
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            }),
+            None => Err(Error::UnknownEntityName {
+                entity_name: keyword.to_string(),
+                schema: self.schemas.join(", "),
+            }),
+        }
+    }
+
+    /// `entity` and its supertypes, where supertypes come first as in the simple record
+    fn flatten(&self, entity: &'ir Entity, output: &mut Vec<&'ir Entity>) {
+        for supertype in &entity.supertypes {
+            if let TypeRef::Entity { name, scope, .. } = supertype {
+                if let Some(supertype) = declared(&self.entities, name, scope) {
+                    self.flatten(supertype, output);
+                }
+            }
+        }
+        if !output.iter().any(|e| e.name == entity.name) {
+            output.push(entity);
+        }
+    }
+
+    fn simple(
+        &self,
+        id: Option<u64>,
+        record: &Record,
+        references: &mut Vec<Reference>,
+    ) -> Result<Instance<'ir>> {
+        let mut entities = Vec::new();
+        self.flatten(self.entity(&record.name)?, &mut entities);
+        self.check_instantiable(&entities)?;
+
+        let parameters = parameters(&record.parameter);
+        let expected = entities.iter().map(|e| e.attributes.len()).sum();
+        if parameters.len() != expected {
+            return Err(Error::ParameterCount {
+                entity: record.name.clone(),
+                expected,
+                found: parameters.len(),
+            });
+        }
+        let mut parameters = parameters.iter();
+        let partials = entities
+            .into_iter()
+            .map(|entity| {
+                let n = entity.attributes.len();
+                self.partial(entity, parameters.by_ref().take(n), references)
+            })
+            .collect::<Result<_>>()?;
+        Ok(Instance { id, partials })
+    }
+
+    fn complex(
+        &self,
+        id: u64,
+        records: &[Record],
+        references: &mut Vec<Reference>,
+    ) -> Result<Instance<'ir>> {
+        let mut partials = Vec::new();
+        for record in records {
+            let entity = self.entity(&record.name)?;
+            let parameters = parameters(&record.parameter);
+            if parameters.len() != entity.attributes.len() {
+                return Err(Error::ParameterCount {
+                    entity: record.name.clone(),
+                    expected: entity.attributes.len(),
+                    found: parameters.len(),
+                });
+            }
+            partials.push(self.partial(entity, parameters.iter(), references)?);
+        }
+        let entities: Vec<_> = partials.iter().map(|p| p.entity).collect();
+        // Every supertype must be written as a partial record
+        let mut closure = Vec::new();
+        for entity in &entities {
+            self.flatten(entity, &mut closure);
+        }
+        if closure.len() != entities.len() {
+            return Err(not_instantiable(&closure));
+        }
+        self.check_instantiable(&entities)?;
+        Ok(Instance {
+            id: Some(id),
+            partials,
+        })
+    }
+
+    /// Check `entities` closed under supertypes satisfy `SUBTYPE_CONSTRAINT`s of them
+    fn check_instantiable(&self, entities: &[&'ir Entity]) -> Result<()> {
+        for entity in entities {
+            let constraint = match &entity.subtype_constraint {
+                Some(constraint) => constraint,
+                None => continue,
+            };
+            let subtypes: Vec<&str> = reflection::subtypes(constraint)
+                .into_iter()
+                .filter(|name| entities.iter().any(|e| e.name == *name))
+                .collect();
+            if !subtypes.is_empty() && !reflection::is_instantiable(constraint, &subtypes) {
+                return Err(not_instantiable(entities));
+            }
+        }
+        Ok(())
+    }
+
+    fn partial<'p>(
+        &self,
+        entity: &'ir Entity,
+        parameters: impl Iterator<Item = &'p Parameter>,
+        references: &mut Vec<Reference>,
+    ) -> Result<Partial<'ir>> {
+        let values = entity
+            .attributes
+            .iter()
+            .zip(parameters)
+            .map(|(attr, parameter)| {
+                let mismatch = || Error::InvalidParameter {
+                    entity: entity.name.clone(),
+                    attribute: attr.name.clone(),
+                    expected: attr.ty.to_string(),
+                    parameter: format!("{:?}", parameter),
+                };
+                match parameter {
+                    Parameter::NotProvided if attr.optional => return Ok(Value::Null),
+                    Parameter::Omitted if attr.redeclared_as_derived => return Ok(Value::Derived),
+                    _ => {}
+                }
+                let at = Context {
+                    entity,
+                    attribute: attr,
+                };
+                self.value(&attr.ty, parameter, at, references)?
+                    .ok_or_else(mismatch)
+            })
+            .collect::<Result<_>>()?;
+        Ok(Partial { entity, values })
+    }
+
+    /// Check `parameter` is of `ty`, where `Ok(None)` means mismatch
+    ///
+    /// References are pushed into `references` with the entities the target must be,
+    /// which are checked after reading all records.
+    fn value(
+        &self,
+        ty: &TypeRef,
+        parameter: &Parameter,
+        at: Context,
+        references: &mut Vec<Reference>,
+    ) -> Result<Option<Value<'ir>>> {
+        Ok(match ty {
+            TypeRef::SimpleType(ty) => simple(ty, parameter),
+            TypeRef::Named { name, scope, .. } => {
+                return self.named(name, scope, parameter, at, references)
+            }
+            TypeRef::Entity { name, .. } => {
+                return self.reference(vec![name.clone()], parameter, at, references)
+            }
+            TypeRef::Set { base, .. } | TypeRef::Bag { base, .. } | TypeRef::List { base, .. } => {
+                let items = match parameter {
+                    Parameter::List(items) => items,
+                    _ => return Ok(None),
+                };
+                check_size(ty, items.len())?;
+                let values = items
+                    .iter()
+                    .map(|item| self.value(base, item, at, references))
+                    .collect::<Result<Option<_>>>()?;
+                values.map(Value::List)
+            }
+            TypeRef::Array { base, optional, .. } => {
+                let items = match parameter {
+                    Parameter::List(items) => items,
+                    _ => return Ok(None),
+                };
+                check_size(ty, items.len())?;
+                let values = items
+                    .iter()
+                    .map(|item| match item {
+                        Parameter::NotProvided if *optional => Ok(Some(Value::Null)),
+                        _ => self.value(base, item, at, references),
+                    })
+                    .collect::<Result<Option<_>>>()?;
+                values.map(Value::List)
+            }
+        })
+    }
+
+    /// Value of the type declared as `TYPE name`
+    fn named(
+        &self,
+        name: &str,
+        scope: &Scope,
+        parameter: &Parameter,
+        at: Context,
+        references: &mut Vec<Reference>,
+    ) -> Result<Option<Value<'ir>>> {
+        Ok(match declared(&self.types, name, scope) {
+            Some(TypeDecl::Simple(ty)) => simple(&ty.ty, parameter),
+            Some(TypeDecl::Rename(rename)) => {
+                return self.value(&rename.ty, parameter, at, references)
+            }
+            Some(TypeDecl::Enumeration(e)) => match parameter {
+                Parameter::Enumeration(item)
+                    if e.items.iter().any(|i| i.eq_ignore_ascii_case(item)) =>
+                {
+                    Some(Value::Enumeration(item.to_lowercase()))
+                }
+                _ => None,
+            },
+            Some(TypeDecl::Select(select)) => {
+                return self.select(select, parameter, at, references)
+            }
+            None => None,
+        })
+    }
+
+    /// Reference or inline instance which must be one of `entities`
+    fn reference(
+        &self,
+        entities: Vec<String>,
+        parameter: &Parameter,
+        at: Context,
+        references: &mut Vec<Reference>,
+    ) -> Result<Option<Value<'ir>>> {
+        Ok(match parameter {
+            Parameter::Ref(Name::Entity(id)) => {
+                references.push(Reference::new(*id, entities, at));
+                Some(Value::Ref(*id))
+            }
+            Parameter::Typed { keyword, parameter } => {
+                let record = Record {
+                    name: keyword.clone(),
+                    parameter: parameter.as_ref().clone(),
+                };
+                let instance = self.simple(None, &record, references)?;
+                if !entities.iter().any(|e| instance.is_a(e)) {
+                    return Ok(None);
+                }
+                Some(Value::Entity(Box::new(instance)))
+            }
+            _ => None,
+        })
+    }
+
+    fn select(
+        &self,
+        select: &Select,
+        parameter: &Parameter,
+        at: Context,
+        references: &mut Vec<Reference>,
+    ) -> Result<Option<Value<'ir>>> {
+        let mut entities = Vec::new();
+        let mut types = Vec::new();
+        self.select_members(select, &mut entities, &mut types);
+        if let Parameter::Typed { keyword, parameter } = parameter {
+            if let Some((ty, scope)) = types
+                .iter()
+                .find(|(ty, _)| ty.eq_ignore_ascii_case(keyword))
+            {
+                return Ok(self
+                    .named(ty, scope, parameter, at, references)?
+                    .map(|value| Value::Typed {
+                        name: ty.to_string(),
+                        value: Box::new(value),
+                    }));
+            }
+        }
+        if entities.is_empty() {
+            return Ok(None);
+        }
+        self.reference(entities, parameter, at, references)
+    }
+
+    /// Entities and defined types in `select` including nested selects
+    fn select_members(
+        &self,
+        select: &Select,
+        entities: &mut Vec<String>,
+        types: &mut Vec<(String, Scope)>,
+    ) {
+        for ty in &select.types {
+            match ty {
+                TypeRef::Entity { name, .. } => entities.push(name.clone()),
+                TypeRef::Named { name, scope, .. } => match declared(&self.types, name, scope) {
+                    Some(TypeDecl::Select(select)) => self.select_members(select, entities, types),
+                    _ => types.push((name.clone(), scope.clone())),
+                },
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Parameters of a record, `A()` is read as an empty list
+fn parameters(parameter: &Parameter) -> &[Parameter] {
+    match parameter {
+        Parameter::List(parameters) => parameters,
+        _ => std::slice::from_ref(parameter),
+    }
+}
+
+fn not_instantiable(entities: &[&Entity]) -> Error {
+    let mut names: Vec<&str> = entities.iter().map(|e| e.name.as_str()).collect();
+    names.sort_unstable();
+    Error::NotInstantiable {
+        entities: names.join(", "),
+    }
+}
+
+/// Check the number of elements of an aggregate within its bound
+fn check_size(ty: &TypeRef, size: usize) -> Result<()> {
+    let bound = match ty.bound() {
+        Some(bound) => bound,
+        None => return Ok(()),
+    };
+    let size = size as i64;
+    let lower = bound.lower_value();
+    let upper = bound.upper_value();
+    let valid = match ty {
+        // Bound of array is of its index
+        TypeRef::Array { .. } => match (lower, upper) {
+            (Some(lower), Some(upper)) => size == upper - lower + 1,
+            _ => true,
+        },
+        _ => lower.map_or(true, |l| size >= l) && upper.map_or(true, |u| size <= u),
+    };
+    if valid {
+        Ok(())
+    } else {
+        Err(Error::InvalidAggregateSize {
+            attribute: ty.to_string(),
+            size: size as usize,
+            bound: bound.to_string(),
+        })
+    }
+}
+
+fn simple<'ir>(ty: &SimpleType, parameter: &Parameter) -> Option<Value<'ir>> {
+    use espr::ast::SimpleType as S;
+    Some(match (&ty.0, parameter) {
+        (S::Integer | S::Number, Parameter::Integer(i)) => Value::Integer(*i),
+        (S::Real, Parameter::Integer(i)) => Value::Real(*i as f64),
+        (S::Real | S::Number, Parameter::Real(x)) => Value::Real(*x),
+        (S::String_ { .. }, Parameter::String(s)) => Value::String(s.clone()),
+        (S::Binary { .. }, Parameter::Binary(b)) => Value::Binary(b.clone()),
+        (S::Boolen, Parameter::Enumeration(e)) => match e.as_str() {
+            "T" | "TRUE" => Value::Boolean(true),
+            "F" | "FALSE" => Value::Boolean(false),
+            _ => return None,
+        },
+        (S::Logical, Parameter::Enumeration(e)) => match e.as_str() {
+            "T" | "TRUE" => Value::Logical(Logical::True),
+            "F" | "FALSE" => Value::Logical(Logical::False),
+            "U" | "UNKNOWN" => Value::Logical(Logical::Unknown),
+            _ => return None,
+        },
+        _ => return None,
+    })
+}
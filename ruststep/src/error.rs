@@ -22,6 +22,71 @@ pub enum Error {
 
     #[error("Entity '{entity_name}' is not a member of the schema '{schema}'")]
     UnknownEntityName { entity_name: String, schema: String },
+
+    #[error("Entity '{entity_name}' is declared in several schemas '{schemas}'")]
+    AmbiguousEntityName {
+        entity_name: String,
+        schemas: String,
+    },
+
+    #[error("Invalid binary in exchange structure: \"{0}\"")]
+    InvalidBinary(String),
+
+    #[error("Aggregate attribute '{attribute}' has {size} elements, but must be in {bound}")]
+    InvalidAggregateSize {
+        attribute: String,
+        size: usize,
+        bound: String,
+    },
+
+    #[error(
+        "Inverse attribute '{attribute}' of #{id} has {size} entities, but must be in {bound}"
+    )]
+    InverseCardinality {
+        attribute: String,
+        id: u64,
+        size: u64,
+        bound: String,
+    },
+
+    #[error("Derived attribute '{attribute}' of '{entity}' is not supported yet")]
+    UnsupportedDerivedAttribute { entity: String, attribute: String },
+
+    #[error("Required attribute '{attribute}' of '{entity}' is not set in the builder")]
+    MissingAttribute { entity: String, attribute: String },
+
+    #[error("Attribute '{attribute}' of '{entity}' must be {expected}, but got {parameter}")]
+    InvalidParameter {
+        entity: String,
+        attribute: String,
+        expected: String,
+        parameter: String,
+    },
+
+    #[error("Record of '{entity}' must have {expected} parameters, but got {found}")]
+    ParameterCount {
+        entity: String,
+        expected: usize,
+        found: usize,
+    },
+
+    #[error("Complex entity instance #{id} of [{entities}] is not supported")]
+    UnsupportedComplexEntity { id: u64, entities: String },
+
+    #[error("Complex entity instance of [{entities}] is not instantiable")]
+    NotInstantiable { entities: String },
+
+    #[cfg(feature = "sqlite")]
+    #[error("Table '{table}' is not created from the schemas")]
+    MissingTable { table: String },
+
+    #[cfg(feature = "sqlite")]
+    #[error("Column '{column}' is not found in table '{table}'")]
+    MissingColumn { table: String, column: String },
+
+    #[cfg(feature = "sqlite")]
+    #[error(transparent)]
+    Sqlite(#[from] rusqlite::Error),
 }
 
 impl de::Error for Error {
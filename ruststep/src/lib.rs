@@ -109,10 +109,15 @@
 #![deny(rustdoc::broken_intra_doc_links)]
 
 pub mod ast;
+#[cfg(feature = "dynamic")]
+pub mod dynamic;
 pub mod error;
 pub mod header;
 pub mod parser;
 pub mod primitive;
+pub mod reflection;
+#[cfg(feature = "sqlite")]
+pub mod sqlite;
 pub mod tables;
 
 // To work generated code by ruststep-derive only with ruststep
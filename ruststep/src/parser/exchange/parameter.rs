@@ -26,7 +26,7 @@ pub fn typed_parameter(input: &str) -> ParseResult<Parameter> {
         .parse(input)
 }
 
-/// untyped_parameter = `$` | [integer] | [real] | [string] | [rhs_occurrence_name] | [enumeration] | binary | [list] .
+/// untyped_parameter = `$` | [integer] | [real] | [string] | [rhs_occurrence_name] | [enumeration] | [binary] | [list] .
 pub fn untyped_parameter(input: &str) -> ParseResult<Parameter> {
     alt((
         char_('$').map(|_| Parameter::NotProvided),
@@ -35,7 +35,7 @@ pub fn untyped_parameter(input: &str) -> ParseResult<Parameter> {
         string.map(Parameter::String),
         rhs_occurrence_name.map(Parameter::Ref),
         enumeration.map(Parameter::Enumeration),
-        // FIXME binary
+        binary.map(Parameter::Binary),
         list,
     ))
     .parse(input)
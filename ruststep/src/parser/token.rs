@@ -3,6 +3,7 @@
 use crate::{
     ast::*,
     parser::{basic::*, combinator::*},
+    primitive::Binary,
 };
 use nom::{
     branch::alt,
@@ -12,6 +13,7 @@ use nom::{
     sequence::tuple,
     Parser,
 };
+use std::str::FromStr;
 
 /// sign = `+` | `-` .
 pub fn sign(input: &str) -> ParseResult<char> {
@@ -73,6 +75,26 @@ pub fn string(input: &str) -> ParseResult<String> {
         .parse(input)
 }
 
+/// binary = `"` ( `0` | `1` | `2` | `3` ) { [hex] } `"` .
+///
+/// Lowercase `a` to `f` are also accepted as `hex` for the files written by other tools.
+pub fn binary(input: &str) -> ParseResult<Binary> {
+    let (residual, (_start, unused, digits, _end)) = tuple((
+        char('"'),
+        satisfy(|c| matches!(c, '0'..='3')),
+        many0(satisfy(|c| c.is_ascii_hexdigit())),
+        char('"'),
+    ))
+    .parse(input)?;
+    let hex: String = std::iter::once(unused).chain(digits).collect();
+    match Binary::from_str(&hex) {
+        Ok(binary) => Ok((residual, binary)),
+        Err(_) => Err(nom::Err::Failure(nom::error::VerboseError {
+            errors: vec![(input, nom::error::VerboseErrorKind::Context("binary"))],
+        })),
+    }
+}
+
 /// resource = `<` UNIVERSAL_RESOURCE_IDENTIFIER `>` .
 ///
 /// Parse as string, without validating as URI
@@ -259,6 +281,27 @@ mod tests {
         assert_eq!(s, "vim");
     }
 
+    #[test]
+    fn binary() {
+        let (res, b) = super::binary("\"0A3F\"").finish().unwrap();
+        assert_eq!(res, "");
+        assert_eq!(b.len(), 12);
+        assert_eq!(b.to_string(), "0A3F");
+
+        let (res, b) = super::binary("\"0\"").finish().unwrap();
+        assert_eq!(res, "");
+        assert!(b.is_empty());
+
+        let (res, b) = super::binary("\"0a3f\"").finish().unwrap();
+        assert_eq!(res, "");
+        assert_eq!(b.to_string(), "0A3F");
+
+        // Unused bits must be zero
+        assert!(super::binary("\"3F\"").finish().is_err());
+        // Number of unused bits must be 0 to 3
+        assert!(super::binary("\"4F\"").finish().is_err());
+    }
+
     #[test]
     fn instance_name() {
         let (res, s) = super::entity_instance_name("#18446744073709551615" /* u64::MAX */)
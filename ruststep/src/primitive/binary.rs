@@ -0,0 +1,235 @@
+use crate::{
+    error::{Error, Result},
+    tables::Aggregate,
+};
+use serde::{de, ser};
+use std::{fmt, str::FromStr};
+
+/// Name used to pass [Binary] through serde data model as a newtype struct
+///
+/// [Binary] is serialized as a newtype struct of this name wrapping its hexadecimal digits
+/// in exchange structure, e.g. `"0A3F"` without double quotes, since bytes cannot express
+/// the number of bits. `ruststep::ast::ser` recognizes this name and stores the digits
+/// as [crate::ast::Parameter::Binary] instead of [crate::ast::Parameter::String],
+/// while other serializers see the digits as a string wrapped by a newtype struct.
+/// Deserialization accepts the digits as a string, and bytes as full 8 bits each.
+pub(crate) const BINARY_NEWTYPE: &str = "$ruststep::primitive::Binary";
+
+/// `BINARY` type, a sequence of bits
+///
+/// Bits are packed into bytes from the most significant bit of the first byte,
+/// and the unused bits of the last byte are zero.
+///
+/// Encoding in exchange structure
+/// -------------------------------
+/// A binary is encoded as hexadecimal digits in exchange structure like `"0A3F"`.
+/// The first digit (`0` to `3`) is the number of unused bits
+/// at the most significant side of the first hexadecimal digit following it.
+///
+/// ```
+/// use ruststep::primitive::Binary;
+/// use std::str::FromStr;
+///
+/// // `"0"` is an empty binary
+/// let b = Binary::from_str("0").unwrap();
+/// assert!(b.is_empty());
+///
+/// // `A` = `1010`, `3` = `0011`
+/// let b = Binary::from_str("0A3").unwrap();
+/// assert_eq!(b.len(), 8);
+/// assert_eq!(b.as_bytes(), &[0xA3]);
+/// assert_eq!(b.to_string(), "0A3");
+///
+/// // First two bits of `1` = `0001` are unused
+/// let b = Binary::from_str("21").unwrap();
+/// assert_eq!(b.iter().collect::<Vec<_>>(), [false, true]);
+/// assert_eq!(b.get(1), Some(true));
+/// assert_eq!(b.to_string(), "21");
+///
+/// // Lowercase digits are also accepted
+/// assert_eq!(Binary::from_str("0a3").unwrap(), Binary::from(&[0xA3_u8][..]));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Binary {
+    bytes: Vec<u8>,
+    /// Number of bits
+    len: usize,
+}
+
+impl Binary {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of bits
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Bit at `index` from the most significant side, `None` if out of range
+    pub fn get(&self, index: usize) -> Option<bool> {
+        if index < self.len {
+            Some(self.bytes[index / 8] & (0x80 >> (index % 8)) != 0)
+        } else {
+            None
+        }
+    }
+
+    /// Iterate over bits from the most significant side
+    pub fn iter(&self) -> impl Iterator<Item = bool> + '_ {
+        (0..self.len).map(move |index| self.bytes[index / 8] & (0x80 >> (index % 8)) != 0)
+    }
+
+    /// Packed bits, where the unused bits of the last byte are zero
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    pub fn push(&mut self, bit: bool) {
+        if self.len % 8 == 0 {
+            self.bytes.push(0);
+        }
+        if bit {
+            *self.bytes.last_mut().unwrap() |= 0x80 >> (self.len % 8);
+        }
+        self.len += 1;
+    }
+}
+
+impl std::iter::FromIterator<bool> for Binary {
+    fn from_iter<I: IntoIterator<Item = bool>>(iter: I) -> Self {
+        let mut binary = Binary::new();
+        for bit in iter {
+            binary.push(bit);
+        }
+        binary
+    }
+}
+
+impl From<Vec<bool>> for Binary {
+    fn from(bits: Vec<bool>) -> Self {
+        bits.into_iter().collect()
+    }
+}
+
+impl From<&[u8]> for Binary {
+    fn from(bytes: &[u8]) -> Self {
+        Binary {
+            bytes: bytes.to_vec(),
+            len: bytes.len() * 8,
+        }
+    }
+}
+
+impl FromStr for Binary {
+    type Err = Error;
+
+    /// Parse hexadecimal digits in exchange structure without double quotes
+    fn from_str(input: &str) -> Result<Self> {
+        let invalid = || Error::InvalidBinary(input.to_string());
+        let mut chars = input.chars();
+        let unused = match chars.next() {
+            Some(c @ '0'..='3') => c as usize - '0' as usize,
+            _ => return Err(invalid()),
+        };
+        let digits = chars
+            .map(|c| c.to_digit(16).ok_or_else(invalid))
+            .collect::<Result<Vec<u32>>>()?;
+        let first = match digits.first() {
+            Some(first) => *first,
+            None if unused == 0 => return Ok(Binary::new()),
+            None => return Err(invalid()),
+        };
+        // Unused bits must be zero
+        if first >> (4 - unused) != 0 {
+            return Err(invalid());
+        }
+        Ok(digits
+            .iter()
+            .flat_map(|digit| (0..4).rev().map(move |i| digit & (1 << i) != 0))
+            .skip(unused)
+            .collect())
+    }
+}
+
+impl fmt::Display for Binary {
+    /// Hexadecimal digits in exchange structure without double quotes
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let unused = (4 - self.len % 4) % 4;
+        write!(f, "{}", unused)?;
+        let mut digit = 0;
+        for (i, bit) in std::iter::repeat(false)
+            .take(unused)
+            .chain(self.iter())
+            .enumerate()
+        {
+            digit = (digit << 1) | bit as u8;
+            if i % 4 == 3 {
+                write!(f, "{:X}", digit)?;
+                digit = 0;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The size of binary is the number of bits, which is restricted by `BINARY(width)`
+impl Aggregate for Binary {
+    fn size(&self) -> Option<usize> {
+        Some(self.len())
+    }
+}
+
+/// Serialized as a newtype struct named [BINARY_NEWTYPE] wrapping hexadecimal digits
+impl ser::Serialize for Binary {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        serializer.serialize_newtype_struct(BINARY_NEWTYPE, &self.to_string())
+    }
+}
+
+struct BinaryVisitor;
+
+impl<'de> de::Visitor<'de> for BinaryVisitor {
+    type Value = Binary;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "Binary")
+    }
+
+    fn visit_str<E>(self, v: &str) -> std::result::Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Binary::from_str(v).map_err(E::custom)
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> std::result::Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(Binary::from(v))
+    }
+
+    fn visit_newtype_struct<D>(self, deserializer: D) -> std::result::Result<Self::Value, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        deserializer.deserialize_str(self)
+    }
+}
+
+impl<'de> de::Deserialize<'de> for Binary {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        deserializer.deserialize_newtype_struct(BINARY_NEWTYPE, BinaryVisitor)
+    }
+}
@@ -1,4 +1,7 @@
 //! Primitive types appears in STEP and not defined in Rust
 
+pub(crate) mod binary;
 mod logical;
+
+pub use binary::*;
 pub use logical::*;
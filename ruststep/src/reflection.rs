@@ -0,0 +1,336 @@
+//! Runtime metadata of schemas generated by espr
+//!
+//! espr generates a static [SchemaInfo] named `SCHEMA_INFO` and a function `entity_info`
+//! in each schema module when enabled,
+//! e.g. by `esprc --reflection` or `reflection` argument of `espr_derive::inline_express!`.
+//! Generic tools like viewers or validators can inspect the structure of any compiled schema
+//! through them without schema-specific code:
+//!
+//! ```
+//! use ruststep::reflection::*;
+//!
+//! // Usually generated by espr
+//! static SCHEMA_INFO: SchemaInfo = SchemaInfo {
+//!     name: "shapes",
+//!     entities: &[EntityInfo {
+//!         name: "point",
+//!         attributes: &[AttributeInfo {
+//!             name: "coordinates",
+//!             ty: AttributeType::List {
+//!                 base: &AttributeType::Simple("REAL"),
+//!                 lower: Some(1),
+//!                 upper: Some(3),
+//!             },
+//!             optional: false,
+//!             redeclared_as_derived: false,
+//!         }],
+//!         supertypes: &[],
+//!         subtypes: &[],
+//!         subtype_constraint: None,
+//!     }],
+//!     types: &[],
+//! };
+//!
+//! // Names in exchange structure are upper case
+//! let point = SCHEMA_INFO.entity("POINT").unwrap();
+//! assert_eq!(point.attributes[0].name, "coordinates");
+//! assert!(SCHEMA_INFO.entity("line").is_none());
+//! ```
+
+/// Entities and types declared in a schema
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SchemaInfo {
+    pub name: &'static str,
+    /// Entities in declaration order
+    pub entities: &'static [EntityInfo],
+    /// Types in declaration order
+    pub types: &'static [TypeInfo],
+}
+
+impl SchemaInfo {
+    /// Look up an entity by name case-insensitively, e.g. `CARTESIAN_POINT` in exchange structure
+    pub fn entity(&self, name: &str) -> Option<&'static EntityInfo> {
+        self.entities
+            .iter()
+            .find(|e| e.name.eq_ignore_ascii_case(name))
+    }
+
+    /// Look up a type by name case-insensitively
+    pub fn type_info(&self, name: &str) -> Option<&'static TypeInfo> {
+        self.types
+            .iter()
+            .find(|t| t.name.eq_ignore_ascii_case(name))
+    }
+}
+
+/// Declaration of `ENTITY`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EntityInfo {
+    /// Name in snake_case
+    pub name: &'static str,
+    /// Explicit attributes declared in this entity, not including inherited ones
+    pub attributes: &'static [AttributeInfo],
+    /// Names of supertypes declared by `SUBTYPE OF`
+    pub supertypes: &'static [&'static str],
+    /// Names of subtypes instantiable as a single entity, i.e. variants of `XxxAny` enum
+    pub subtypes: &'static [&'static str],
+    /// Combined `SUBTYPE_CONSTRAINT` if this entity is a supertype
+    pub subtype_constraint: Option<SubtypeConstraint>,
+}
+
+impl EntityInfo {
+    /// Instantiable combinations of subtypes, see [SubtypeConstraint::instantiables]
+    pub fn instantiables(&self) -> Vec<Vec<&'static str>> {
+        self.subtype_constraint
+            .as_ref()
+            .map(SubtypeConstraint::instantiables)
+            .unwrap_or_default()
+    }
+
+    /// Look up an attribute declared in this entity by name case-insensitively
+    pub fn attribute(&self, name: &str) -> Option<&'static AttributeInfo> {
+        self.attributes
+            .iter()
+            .find(|a| a.name.eq_ignore_ascii_case(name))
+    }
+}
+
+/// Explicit attribute of an entity
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AttributeInfo {
+    pub name: &'static str,
+    pub ty: AttributeType,
+    /// Declared as `OPTIONAL`
+    pub optional: bool,
+    /// Redeclared as derived in some subtype, and then given as `*` in the exchange structure
+    /// for instances of the subtype. The field of the generated struct is `Option` as `OPTIONAL`.
+    pub redeclared_as_derived: bool,
+}
+
+/// Type of an attribute, or the underlying type of a defined type
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AttributeType {
+    /// Simple type as in EXPRESS, e.g. `REAL` or `STRING`
+    Simple(&'static str),
+    /// Type declared by `TYPE`
+    Named(&'static str),
+    /// Entity declared by `ENTITY`
+    Entity(&'static str),
+    /// `SET [lower:upper] OF base`, where `None` bound is `?` or cannot be evaluated
+    Set {
+        base: &'static AttributeType,
+        lower: Option<i64>,
+        upper: Option<i64>,
+    },
+    /// `BAG [lower:upper] OF base`
+    Bag {
+        base: &'static AttributeType,
+        lower: Option<i64>,
+        upper: Option<i64>,
+    },
+    /// `LIST [lower:upper] OF base`
+    List {
+        base: &'static AttributeType,
+        lower: Option<i64>,
+        upper: Option<i64>,
+    },
+    /// `ARRAY [lower:upper] OF base`, where the bounds are of the index
+    Array {
+        base: &'static AttributeType,
+        lower: Option<i64>,
+        upper: Option<i64>,
+        /// Elements may be indeterminate, i.e. `ARRAY [1:3] OF OPTIONAL base`
+        optional: bool,
+    },
+}
+
+/// Declaration of `TYPE`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TypeInfo {
+    /// Name in snake_case
+    pub name: &'static str,
+    pub kind: TypeKind,
+}
+
+/// Underlying type of `TYPE`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TypeKind {
+    /// Simple, named or aggregate type, e.g. `TYPE label = STRING; END_TYPE;`
+    Defined(AttributeType),
+    /// Items of `ENUMERATION OF`
+    Enumeration(&'static [&'static str]),
+    /// Names of types and entities in `SELECT`
+    Select(&'static [&'static str]),
+}
+
+/// Expression of `SUBTYPE_CONSTRAINT` or `SUPERTYPE OF` on names of subtypes
+///
+/// Instantiable combinations are kept as this expression since they grow exponentially,
+/// e.g. `ANDOR` of 20 subtypes has about a million combinations.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SubtypeConstraint {
+    Reference(&'static str),
+    AndOr(&'static [SubtypeConstraint]),
+    And(&'static [SubtypeConstraint]),
+    OneOf(&'static [SubtypeConstraint]),
+}
+
+/// Operator of a `SUBTYPE_CONSTRAINT` expression node with its operands
+///
+/// This abstracts [SubtypeConstraint] and `espr::ir::ConstraintExpr` used in [crate::dynamic],
+/// so that both share the evaluation below.
+pub(crate) enum Node<'a, T> {
+    Reference(&'a str),
+    AndOr(Vec<T>),
+    And(Vec<T>),
+    OneOf(Vec<T>),
+}
+
+/// Reference to a node of `SUBTYPE_CONSTRAINT` expression tree
+pub(crate) trait ConstraintNode<'a>: Copy {
+    fn node(self) -> Node<'a, Self>;
+}
+
+// Names and operands are `'static` even if the root is not
+impl ConstraintNode<'static> for &SubtypeConstraint {
+    fn node(self) -> Node<'static, Self> {
+        match self {
+            SubtypeConstraint::Reference(name) => Node::Reference(name),
+            SubtypeConstraint::AndOr(exprs) => Node::AndOr(exprs.iter().collect()),
+            SubtypeConstraint::And(exprs) => Node::And(exprs.iter().collect()),
+            SubtypeConstraint::OneOf(exprs) => Node::OneOf(exprs.iter().collect()),
+        }
+    }
+}
+
+/// Sorted names of subtypes composing a complex entity
+type Combination<'a> = Vec<&'a str>;
+
+/// `[A, B] & [C, D] = [A & C, A & D, B & C, B & D]`
+fn and<'a>(lhs: &[Combination<'a>], rhs: &[Combination<'a>]) -> Vec<Combination<'a>> {
+    let mut output = Vec::new();
+    for l in lhs {
+        for r in rhs {
+            let mut c: Combination = l.iter().chain(r).copied().collect();
+            c.sort_unstable();
+            c.dedup();
+            output.push(c);
+        }
+    }
+    output
+}
+
+/// `[A, B] + [B, C] = [A, B, C]`
+fn add<'a>(mut lhs: Vec<Combination<'a>>, rhs: Vec<Combination<'a>>) -> Vec<Combination<'a>> {
+    for c in rhs {
+        if !lhs.contains(&c) {
+            lhs.push(c);
+        }
+    }
+    lhs
+}
+
+/// Names of subtypes appearing in the expression, sorted and deduplicated
+pub(crate) fn subtypes<'a, T: ConstraintNode<'a>>(expr: T) -> Vec<&'a str> {
+    match expr.node() {
+        Node::Reference(name) => vec![name],
+        Node::AndOr(exprs) | Node::And(exprs) | Node::OneOf(exprs) => {
+            let mut names: Vec<_> = exprs.into_iter().flat_map(subtypes).collect();
+            names.sort_unstable();
+            names.dedup();
+            names
+        }
+    }
+}
+
+/// Instantiable combinations of subtypes, see [SubtypeConstraint::instantiables]
+pub(crate) fn instantiables<'a, T: ConstraintNode<'a>>(expr: T) -> Vec<Combination<'a>> {
+    match expr.node() {
+        Node::Reference(name) => vec![vec![name]],
+        Node::OneOf(exprs) => exprs
+            .into_iter()
+            .fold(Vec::new(), |acc, e| add(acc, instantiables(e))),
+        Node::And(exprs) => {
+            let mut exprs = exprs.into_iter();
+            let first = exprs.next().map(instantiables).unwrap_or_default();
+            exprs.fold(first, |acc, e| and(&acc, &instantiables(e)))
+        }
+        Node::AndOr(exprs) => exprs.into_iter().fold(Vec::new(), |acc, e| {
+            let is = instantiables(e);
+            let both = and(&acc, &is);
+            add(add(acc, is), both)
+        }),
+    }
+}
+
+/// Check if the complex entity of `names` is instantiable, see [SubtypeConstraint::is_instantiable]
+pub(crate) fn is_instantiable<'a, T: ConstraintNode<'a>>(expr: T, names: &[&str]) -> bool {
+    let mut names: Vec<String> = names.iter().map(|n| n.to_ascii_lowercase()).collect();
+    names.sort_unstable();
+    names.dedup();
+    let names: Vec<&str> = names.iter().map(String::as_str).collect();
+    produces(expr, &names)
+}
+
+/// Check if `names` sorted and deduplicated is one of [instantiables]
+fn produces<'a, T: ConstraintNode<'a>>(expr: T, names: &[&str]) -> bool {
+    let node = expr.node();
+    let exprs = match &node {
+        Node::Reference(name) => return names == [*name],
+        Node::OneOf(exprs) => return exprs.iter().any(|e| produces(*e, names)),
+        Node::And(exprs) | Node::AndOr(exprs) => exprs,
+    };
+    // Split `names` into the parts of operands,
+    // which is unique if operands have disjoint subtypes as usual
+    let universes: Vec<_> = exprs.iter().map(|e| subtypes(*e)).collect();
+    let total: usize = universes.iter().map(Vec::len).sum();
+    let mut all: Vec<&str> = universes.iter().flatten().copied().collect();
+    all.sort_unstable();
+    all.dedup();
+    if all.len() < total {
+        return instantiables(expr).iter().any(|c| c.as_slice() == names);
+    }
+    if names.iter().any(|n| !all.contains(n)) {
+        return false;
+    }
+    let mut used = 0;
+    for (e, universe) in exprs.iter().zip(&universes) {
+        let part: Vec<&str> = names
+            .iter()
+            .copied()
+            .filter(|n| universe.contains(n))
+            .collect();
+        if part.is_empty() {
+            if matches!(node, Node::And(_)) {
+                return false;
+            }
+            continue;
+        }
+        if !produces(*e, &part) {
+            return false;
+        }
+        used += 1;
+    }
+    used > 0
+}
+
+impl SubtypeConstraint {
+    /// Names of subtypes appearing in this expression
+    pub fn subtypes(&self) -> Vec<&'static str> {
+        subtypes(self)
+    }
+
+    /// Evaluate into instantiable combinations of subtypes as described in ISO-10303-11 Annex B,
+    /// e.g. `[["b"], ["c"], ["b", "c"]]` for `b ANDOR c`
+    ///
+    /// Each combination is sorted by name.
+    /// This can be very large, and [SubtypeConstraint::is_instantiable] does not expand it.
+    pub fn instantiables(&self) -> Vec<Vec<&'static str>> {
+        instantiables(self)
+    }
+
+    /// Check if the complex entity of `names` is instantiable, ignoring the order and case
+    pub fn is_instantiable(&self, names: &[&str]) -> bool {
+        is_instantiable(self, names)
+    }
+}
@@ -0,0 +1,266 @@
+//! Store entity instances into SQLite database
+//!
+//! Tables are created by the DDL generated by [espr::codegen::sql] from the schema,
+//! and each [Instance] of [Model] is inserted as rows of them:
+//!
+//! - Each call of [insert] adds a row to [sql::FILE_TABLE],
+//!   and every row of the data section is keyed by its `_file` id,
+//!   so that several exchange structures can be stored into a database.
+//! - The instance of `#12` has a row of `_id = 12` in the table of each entity composing it,
+//!   i.e. the entity and its supertypes.
+//! - Elements of an aggregate attribute are rows in the link table with their positions.
+//! - Instances written inline, e.g. `POINT((0.0, 0.0))`,
+//!   are inserted with new ids larger than any id in the data section.
+//!
+//! ```
+//! use espr::{ast::SyntaxTree, ir::IR};
+//! use ruststep::{ast::DataSection, sqlite};
+//! use std::str::FromStr;
+//!
+//! let ir = IR::from_syntax_tree(
+//!     &SyntaxTree::parse(
+//!         r#"
+//!         SCHEMA shapes;
+//!           ENTITY point;
+//!             coordinates: LIST [2:3] OF REAL;
+//!           END_ENTITY;
+//!         END_SCHEMA;
+//!         "#,
+//!     )
+//!     .unwrap(),
+//! )
+//! .unwrap();
+//! let data = DataSection::from_str("DATA; #1 = POINT((1.0, 2.0)); ENDSEC;").unwrap();
+//!
+//! // Use a file path instead of `:memory:` to store the database
+//! let conn = sqlite::load(":memory:", &ir, &data).unwrap();
+//! let y: f64 = conn
+//!     .query_row(
+//!         r#"SELECT "value" FROM "point.coordinates" WHERE "owner" = 1 AND "position" = 1"#,
+//!         [],
+//!         |row| row.get(0),
+//!     )
+//!     .unwrap();
+//! assert_eq!(y, 2.0);
+//! ```
+
+use crate::{
+    ast::DataSection,
+    dynamic::{Instance, Model, Value},
+    error::*,
+    primitive::Logical,
+};
+use espr::{
+    codegen::sql::{self, ColumnType, Table},
+    ir::IR,
+};
+use rusqlite::{types::Value as SqlValue, Connection, Transaction};
+use std::{collections::HashMap, path::Path};
+
+/// Open or create the database at `path`, and store `section` typed by `ir`
+pub fn load(path: impl AsRef<Path>, ir: &IR, section: &DataSection) -> Result<Connection> {
+    let model = Model::from_data_section(ir, section)?;
+    let mut conn = Connection::open(path)?;
+    conn.execute_batch("PRAGMA foreign_keys = ON")?;
+    create_tables(&conn, ir)?;
+    insert(&mut conn, ir, None, &model)?;
+    Ok(conn)
+}
+
+/// Create tables for the entities in `ir` unless exist
+///
+/// [Error::AmbiguousEntityName] is returned if several schemas declare an entity,
+/// since their tables have the same name.
+pub fn create_tables(conn: &Connection, ir: &IR) -> Result<()> {
+    let mut schemas: HashMap<&str, Vec<&str>> = HashMap::new();
+    for schema in &ir.schemas {
+        for entity in &schema.entities {
+            schemas.entry(&entity.name).or_default().push(&schema.name);
+        }
+    }
+    if let Some((entity_name, schemas)) = schemas.iter().find(|(_, schemas)| schemas.len() > 1) {
+        return Err(Error::AmbiguousEntityName {
+            entity_name: entity_name.to_string(),
+            schemas: schemas.join(", "),
+        });
+    }
+    conn.execute_batch(&sql::ddl(ir))?;
+    Ok(())
+}
+
+/// Insert every instance in `model` as a new file named `name` in a transaction,
+/// and returns the id of the file
+pub fn insert(conn: &mut Connection, ir: &IR, name: Option<&str>, model: &Model) -> Result<i64> {
+    let tables = sql::tables(ir);
+    let tx = conn.transaction()?;
+    let file: i64 = tx.query_row(
+        &format!(
+            "SELECT COALESCE(MAX(\"{}\"), 0) + 1 FROM \"{}\"",
+            sql::ID_COLUMN,
+            sql::FILE_TABLE
+        ),
+        [],
+        |row| row.get(0),
+    )?;
+    let mut writer = Writer {
+        tx: &tx,
+        file,
+        tables: tables.iter().map(|t| (t.name.as_str(), t)).collect(),
+        next_id: model
+            .instances()
+            .filter_map(Instance::id)
+            .max()
+            .unwrap_or(0)
+            + 1,
+    };
+    for instance in model.instances() {
+        if let Some(id) = instance.id() {
+            writer.instance(id, instance)?;
+        }
+    }
+    writer.insert(
+        writer.table(sql::FILE_TABLE)?,
+        &[sql::ID_COLUMN, "name"],
+        vec![SqlValue::Integer(file), name.map(str::to_string).into()],
+    )?;
+    tx.commit()?;
+    Ok(file)
+}
+
+struct Writer<'a> {
+    tx: &'a Transaction<'a>,
+    /// Id of the file in [sql::FILE_TABLE]
+    file: i64,
+    tables: HashMap<&'a str, &'a Table>,
+    /// Id for the next inline instance
+    next_id: u64,
+}
+
+impl<'a> Writer<'a> {
+    /// Table of `name`, which is missing if the instances are not typed by the same [IR]
+    fn table(&self, name: &str) -> Result<&'a Table> {
+        self.tables
+            .get(name)
+            .copied()
+            .ok_or_else(|| Error::MissingTable {
+                table: name.to_string(),
+            })
+    }
+
+    fn instance(&mut self, id: u64, instance: &Instance) -> Result<()> {
+        for partial in instance.partials() {
+            let entity = &partial.entity.name;
+            let table = self.table(entity)?;
+            let mut columns = vec![sql::FILE_COLUMN, sql::ID_COLUMN];
+            let mut row = vec![SqlValue::Integer(self.file), SqlValue::Integer(id as i64)];
+            for (attr, value) in partial.entity.attributes.iter().zip(&partial.values) {
+                if let Some(column) = table.column(&attr.name) {
+                    columns.push(&column.name);
+                    row.push(self.value(value, column.ty)?);
+                    continue;
+                }
+                // Aggregate attributes are not columns but link tables
+                let link = self.table(&sql::link_table_name(entity, &attr.name))?;
+                let ty = link
+                    .column("value")
+                    .ok_or_else(|| Error::MissingColumn {
+                        table: link.name.clone(),
+                        column: "value".to_string(),
+                    })?
+                    .ty;
+                let elements = match value {
+                    Value::List(elements) => elements.as_slice(),
+                    _ => &[],
+                };
+                for (position, element) in elements.iter().enumerate() {
+                    let element = self.value(element, ty)?;
+                    let row = vec![
+                        SqlValue::Integer(self.file),
+                        SqlValue::Integer(id as i64),
+                        SqlValue::Integer(position as i64),
+                        element,
+                    ];
+                    let columns = [sql::FILE_COLUMN, "owner", "position", "value"];
+                    self.insert(link, &columns, row)?;
+                }
+            }
+            self.insert(table, &columns, row)?;
+        }
+        Ok(())
+    }
+
+    fn insert(&self, table: &Table, columns: &[&str], row: Vec<SqlValue>) -> Result<()> {
+        let columns: Vec<_> = columns.iter().map(|c| format!("\"{}\"", c)).collect();
+        let params: Vec<_> = (1..=columns.len()).map(|i| format!("?{}", i)).collect();
+        let statement = format!(
+            "INSERT INTO \"{}\" ({}) VALUES ({})",
+            table.name,
+            columns.join(", "),
+            params.join(", ")
+        );
+        self.tx
+            .prepare_cached(&statement)?
+            .execute(rusqlite::params_from_iter(row))?;
+        Ok(())
+    }
+
+    /// Insert an inline instance and returns its new id
+    fn inline(&mut self, instance: &Instance) -> Result<u64> {
+        let id = self.next_id;
+        self.next_id += 1;
+        self.instance(id, instance)?;
+        Ok(id)
+    }
+
+    fn value(&mut self, value: &Value, ty: ColumnType) -> Result<SqlValue> {
+        Ok(match (value, ty) {
+            (Value::Integer(i), _) => SqlValue::Integer(*i),
+            (Value::Real(x), _) => SqlValue::Real(*x),
+            (Value::Boolean(b), _) => SqlValue::Integer(*b as i64),
+            (Value::Logical(l), _) => SqlValue::Text(logical(*l).to_string()),
+            (Value::String(s), _) | (Value::Enumeration(s), _) => SqlValue::Text(s.clone()),
+            (Value::Binary(b), _) => SqlValue::Text(b.to_string()),
+            (Value::Ref(id), ColumnType::Integer) => SqlValue::Integer(*id as i64),
+            (Value::Entity(instance), ColumnType::Integer) => {
+                SqlValue::Integer(self.inline(instance)? as i64)
+            }
+            (Value::Null, _) | (Value::Derived, _) => SqlValue::Null,
+            _ => SqlValue::Text(self.encode(value)?),
+        })
+    }
+
+    /// Encode `value` as in exchange structure, e.g. `LABEL('A')`
+    fn encode(&mut self, value: &Value) -> Result<String> {
+        Ok(match value {
+            Value::Integer(i) => i.to_string(),
+            Value::Real(x) => format!("{:?}", x),
+            Value::String(s) => format!("'{}'", s.replace('\'', "''")),
+            Value::Boolean(b) => format!(".{}.", if *b { "T" } else { "F" }),
+            Value::Logical(l) => format!(".{}.", logical(*l)),
+            Value::Binary(b) => format!("\"{}\"", b),
+            Value::Enumeration(e) => format!(".{}.", e.to_uppercase()),
+            Value::Ref(id) => format!("#{}", id),
+            Value::Entity(instance) => format!("#{}", self.inline(instance)?),
+            Value::Typed { name, value } => {
+                format!("{}({})", name.to_uppercase(), self.encode(value)?)
+            }
+            Value::List(elements) => {
+                let elements = elements
+                    .iter()
+                    .map(|e| self.encode(e))
+                    .collect::<Result<Vec<_>>>()?;
+                format!("({})", elements.join(", "))
+            }
+            Value::Null => "$".to_string(),
+            Value::Derived => "*".to_string(),
+        })
+    }
+}
+
+fn logical(l: Logical) -> &'static str {
+    match l {
+        Logical::True => "T",
+        Logical::False => "F",
+        Logical::Unknown => "U",
+    }
+}
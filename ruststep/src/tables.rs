@@ -106,7 +106,13 @@ use serde::{
     de::{self, IntoDeserializer, VariantAccess},
     Deserialize,
 };
-use std::{collections::HashMap, fmt, marker::PhantomData};
+use std::{
+    collections::HashMap,
+    fmt,
+    marker::PhantomData,
+    ops::RangeBounds,
+    sync::{Arc, RwLock},
+};
 
 /// Trait for resolving a reference through entity id
 pub trait IntoOwned: Clone + 'static {
@@ -123,6 +129,29 @@ impl<T: IntoOwned> IntoOwned for Vec<T> {
     }
 }
 
+impl<T: IntoOwned> IntoOwned for Option<T> {
+    type Owned = Option<T::Owned>;
+    type Table = T::Table;
+    fn into_owned(self, table: &Self::Table) -> Result<Self::Owned> {
+        self.map(|x| x.into_owned(table)).transpose()
+    }
+}
+
+/// Tables of another schema, `T`, nested in this table
+///
+/// Place holders of an entity or a type are resolved in the table of the schema declaring it,
+/// and thus the tables of the interfaced schemas are nested in the table of the interfacing schema.
+/// Every table is the table of itself.
+pub trait ForeignTable<T> {
+    fn foreign_table(&self) -> &T;
+}
+
+impl<T> ForeignTable<T> for T {
+    fn foreign_table(&self) -> &T {
+        self
+    }
+}
+
 /// Trait for a field of tables
 pub trait Holder: IntoOwned {
     fn name() -> &'static str;
@@ -147,6 +176,14 @@ pub trait EntityTable<T: Holder<Table = Self>> {
 pub trait TableInit: Default {
     fn append_data_section(&mut self, section: &DataSection) -> Result<()>;
 
+    /// Insert a record of a simple entity instance into the table of its entity
+    ///
+    /// Returns `false` if neither this table nor the foreign tables nested in it
+    /// hold the entity of the record. The default implementation holds no entity.
+    fn insert(&mut self, _id: u64, _record: &Record) -> Result<bool> {
+        Ok(false)
+    }
+
     fn from_data_section(section: &DataSection) -> Result<Self> {
         let mut table = Self::default();
         table.append_data_section(section)?;
@@ -201,6 +238,193 @@ pub fn insert_record<'de, T: de::Deserialize<'de>>(
     }
 }
 
+/// Helper function to implement TableInit trait keeping records of unknown entities as is
+pub fn insert_untyped(
+    table: &mut HashMap<u64, Record>,
+    id: u64,
+    record: &Record,
+) -> crate::error::Result<()> {
+    if table.insert(id, record.clone()).is_some() {
+        Err(Error::DuplicatedEntity(id))
+    } else {
+        Ok(())
+    }
+}
+
+/// Entity id to the ids of the entities referring it
+type Referrers = HashMap<u64, Vec<u64>>;
+
+/// Lazily built reverse indices for INVERSE attributes
+///
+/// Each index is keyed by the inverse attribute, e.g. `"shape.items"`,
+/// and maps an entity id to the sorted ids of the entities referring it.
+/// Indices are built at the first lookup, and cleared when records are appended to the tables.
+/// This is a cache of the records, and generated `Tables` excludes it from the equality.
+#[derive(Debug, Default)]
+pub struct InverseIndex {
+    indices: RwLock<HashMap<&'static str, Arc<Referrers>>>,
+}
+
+impl InverseIndex {
+    /// Referrers of `id` through the inverse attribute `key`
+    ///
+    /// `build` creates the index of `key` if it is not built yet.
+    pub fn lookup(
+        &self,
+        key: &'static str,
+        id: u64,
+        build: impl FnOnce() -> HashMap<u64, Vec<u64>>,
+    ) -> Vec<u64> {
+        let index = self.indices.read().unwrap().get(key).cloned();
+        let index = match index {
+            Some(index) => index,
+            None => {
+                let mut index = build();
+                for ids in index.values_mut() {
+                    ids.sort_unstable();
+                    ids.dedup();
+                }
+                let index = Arc::new(index);
+                self.indices.write().unwrap().insert(key, index.clone());
+                index
+            }
+        };
+        index.get(&id).cloned().unwrap_or_default()
+    }
+
+    /// Drop indices, which are rebuilt at the next lookup
+    pub fn clear(&mut self) {
+        self.indices.get_mut().unwrap().clear();
+    }
+}
+
+impl Clone for InverseIndex {
+    fn clone(&self) -> Self {
+        InverseIndex {
+            indices: RwLock::new(self.indices.read().unwrap().clone()),
+        }
+    }
+}
+
+/// Helper function to implement INVERSE attributes
+///
+/// Adds every entity in the table to `index` under the ids which `referred` pushes,
+/// i.e. the entities it refers.
+pub fn index_referrers<T>(
+    index: &mut HashMap<u64, Vec<u64>>,
+    table: &HashMap<u64, T>,
+    referred: impl Fn(&T, &mut Vec<u64>),
+) {
+    let mut ids = Vec::new();
+    for (id, holder) in table {
+        ids.clear();
+        referred(holder, &mut ids);
+        for referred in &ids {
+            index.entry(*referred).or_default().push(*id);
+        }
+    }
+}
+
+/// Helper function to check the cardinality of INVERSE attributes, e.g. `SET [1:?] OF`
+pub fn check_cardinality(
+    attribute: &str,
+    id: u64,
+    size: usize,
+    lower: u64,
+    upper: Option<u64>,
+) -> Result<()> {
+    let size = size as u64;
+    if size < lower || upper.map_or(false, |upper| size > upper) {
+        let upper = upper.map_or("?".to_string(), |upper| upper.to_string());
+        return Err(Error::InverseCardinality {
+            attribute: attribute.to_string(),
+            id,
+            size,
+            bound: format!("[{}:{}]", lower, upper),
+        });
+    }
+    Ok(())
+}
+
+/// Trait for a field of aggregate type, e.g. `LIST [1:?] OF REAL`
+pub trait Aggregate {
+    /// Number of elements, `None` if the field is omitted
+    fn size(&self) -> Option<usize>;
+}
+
+impl<T> Aggregate for Vec<T> {
+    fn size(&self) -> Option<usize> {
+        Some(self.len())
+    }
+}
+
+impl<T: Aggregate> Aggregate for Option<T> {
+    fn size(&self) -> Option<usize> {
+        self.as_ref().and_then(Aggregate::size)
+    }
+}
+
+/// Helper function to check the size of an aggregate field within its bound
+pub fn check_size<B>(attribute: &str, value: &impl Aggregate, bound: B) -> Result<()>
+where
+    B: RangeBounds<usize> + fmt::Debug,
+{
+    match value.size() {
+        Some(size) if !bound.contains(&size) => Err(Error::InvalidAggregateSize {
+            attribute: attribute.to_string(),
+            size,
+            bound: format!("{:?}", bound),
+        }),
+        _ => Ok(()),
+    }
+}
+
+/// Trait for a field of holder struct which may refer other entities through entity id
+pub trait Refer {
+    /// Push the ids of the entities which `self` refers
+    fn referred_ids(&self, ids: &mut Vec<u64>);
+
+    /// Check if `self` refers the entity of `id`
+    fn refers_to(&self, id: u64) -> bool {
+        let mut ids = Vec::new();
+        self.referred_ids(&mut ids);
+        ids.contains(&id)
+    }
+}
+
+impl<T: Refer> Refer for PlaceHolder<T> {
+    fn referred_ids(&self, ids: &mut Vec<u64>) {
+        match self {
+            PlaceHolder::Ref(Name::Entity(id)) => ids.push(*id),
+            PlaceHolder::Ref(_) => {}
+            // Inline value may refer entities through its fields, e.g. `KNOB_REF(#6)`
+            PlaceHolder::Owned(value) => value.referred_ids(ids),
+        }
+    }
+}
+
+impl<T: Refer> Refer for Option<T> {
+    fn referred_ids(&self, ids: &mut Vec<u64>) {
+        if let Some(value) = self {
+            value.referred_ids(ids);
+        }
+    }
+}
+
+impl<T: Refer> Refer for Vec<T> {
+    fn referred_ids(&self, ids: &mut Vec<u64>) {
+        for value in self {
+            value.referred_ids(ids);
+        }
+    }
+}
+
+impl<T: Refer> Refer for Box<T> {
+    fn referred_ids(&self, ids: &mut Vec<u64>) {
+        self.as_ref().referred_ids(ids)
+    }
+}
+
 /// Owned value or reference through entity/value id
 #[derive(Debug, Clone, PartialEq)]
 pub enum PlaceHolder<T> {
@@ -208,6 +432,16 @@ pub enum PlaceHolder<T> {
     Owned(T),
 }
 
+impl<T> PlaceHolder<T> {
+    /// Get owned value if `self` is not a reference
+    pub fn as_owned(&self) -> Option<&T> {
+        match self {
+            PlaceHolder::Ref(_) => None,
+            PlaceHolder::Owned(value) => Some(value),
+        }
+    }
+}
+
 impl<T: Holder> IntoOwned for PlaceHolder<T>
 where
     T::Table: EntityTable<T>,
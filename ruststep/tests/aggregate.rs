@@ -0,0 +1,64 @@
+// Test for bounded aggregates, BAG and ARRAY
+
+use ruststep::tables::*;
+use std::str::FromStr;
+
+espr_derive::inline_express!(
+    r#"
+    SCHEMA test_schema;
+      ENTITY a;
+        x: ARRAY [1:3] OF REAL;
+        y: BAG [2:?] OF INTEGER;
+        z: ARRAY [1:2] OF OPTIONAL REAL;
+        w: LIST [0:2] OF STRING;
+      END_ENTITY;
+
+      ENTITY b;
+        ends: ARRAY [1:2] OF a;
+      END_ENTITY;
+    END_SCHEMA;
+    "#
+);
+
+use test_schema::*;
+
+const EXAMPLE: &str = r#"
+DATA;
+  #1 = A((1.0, 2.0, 3.0), (1, 2), (1.0, $), ('a'));
+  #2 = A((1.0, 2.0), (1, 2), (2.0, 3.0), ('a'));
+  #3 = A((1.0, 2.0, 3.0), (1), (2.0, 3.0), ('a'));
+  #4 = A((1.0, 2.0, 3.0), (1, 2), (2.0, 3.0), ('a', 'b', 'c'));
+  #5 = B((#1, #1));
+  #6 = B((#1));
+ENDSEC;
+"#;
+
+#[test]
+fn aggregate() {
+    let table = Tables::from_str(EXAMPLE).unwrap();
+    let a = EntityTable::<AHolder>::get_owned(&table, 1).unwrap();
+    assert_eq!(
+        a,
+        A {
+            x: vec![1.0, 2.0, 3.0],
+            y: vec![1, 2],
+            z: vec![Some(1.0), None],
+            w: vec!["a".to_string()],
+        }
+    );
+
+    let b = EntityTable::<BHolder>::get_owned(&table, 5).unwrap();
+    assert_eq!(b.ends, vec![a.clone(), a]);
+}
+
+#[test]
+fn aggregate_size() {
+    let table = Tables::from_str(EXAMPLE).unwrap();
+    // Fixed size array
+    assert!(EntityTable::<AHolder>::get_owned(&table, 2).is_err());
+    // Lower bound
+    assert!(EntityTable::<AHolder>::get_owned(&table, 3).is_err());
+    // Upper bound
+    assert!(EntityTable::<AHolder>::get_owned(&table, 4).is_err());
+    assert!(EntityTable::<BHolder>::get_owned(&table, 6).is_err());
+}
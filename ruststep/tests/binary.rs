@@ -0,0 +1,83 @@
+// Test for BINARY type
+
+use ruststep::{ast::*, primitive::Binary, tables::*};
+use std::str::FromStr;
+
+espr_derive::inline_express!(
+    r#"
+    SCHEMA test_schema;
+      TYPE checksum = BINARY(8) FIXED;
+      END_TYPE;
+
+      ENTITY a;
+        data: BINARY;
+        small: BINARY(4);
+        sum: checksum;
+      END_ENTITY;
+    END_SCHEMA;
+    "#
+);
+
+use test_schema::*;
+
+const EXAMPLE: &str = r#"
+DATA;
+  #1 = A("0A3F", "21", #10);
+  #2 = A("0", "0FF", #10);
+  #3 = A("0", "0F", #11);
+  #10 = CHECKSUM("0FF");
+  #11 = CHECKSUM("0F");
+ENDSEC;
+"#;
+
+#[test]
+fn binary() {
+    let table = Tables::from_str(EXAMPLE).unwrap();
+    let a = EntityTable::<AHolder>::get_owned(&table, 1).unwrap();
+    assert_eq!(a.data, Binary::from_str("0A3F").unwrap());
+    assert_eq!(a.small.iter().collect::<Vec<_>>(), [false, true]);
+    assert_eq!(a.sum.0, Binary::from(&[0xFF_u8][..]));
+}
+
+#[test]
+fn lowercase_digits() {
+    let table = Tables::from_str(
+        r#"
+        DATA;
+          #1 = A("0a3f", "21", #10);
+          #10 = CHECKSUM("0ff");
+        ENDSEC;
+        "#,
+    )
+    .unwrap();
+    let a = EntityTable::<AHolder>::get_owned(&table, 1).unwrap();
+    assert_eq!(a.data, Binary::from_str("0A3F").unwrap());
+    assert_eq!(a.data.as_bytes(), &[0xA3, 0xF0]);
+    assert_eq!(a.sum.0.to_string(), "0FF");
+}
+
+#[test]
+fn binary_width() {
+    let table = Tables::from_str(EXAMPLE).unwrap();
+    // `BINARY(4)` accepts at most 4 bits
+    assert!(EntityTable::<AHolder>::get_owned(&table, 2).is_err());
+    // `BINARY(8) FIXED` accepts just 8 bits
+    assert!(EntityTable::<AHolder>::get_owned(&table, 3).is_err());
+}
+
+#[test]
+fn serialize_binary() {
+    let record = ruststep::ast::ser::to_record(&(
+        Binary::from_str("21").unwrap(),
+        Binary::from(&[0xA3_u8][..]),
+    ))
+    .unwrap();
+    assert_eq!(
+        record.parameter,
+        vec![
+            Parameter::Binary(Binary::from_str("21").unwrap()),
+            Parameter::Binary(Binary::from_str("0A3").unwrap()),
+        ]
+        .into()
+    );
+}
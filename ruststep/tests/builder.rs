@@ -0,0 +1,105 @@
+use ruststep::error::Error;
+
+espr_derive::inline_express!(
+    r#"
+    SCHEMA test_schema;
+      ENTITY named SUPERTYPE OF (ONEOF (point));
+        name: STRING;
+      END_ENTITY;
+
+      ENTITY point SUBTYPE OF (named);
+        coordinates: LIST [1:3] OF REAL;
+        weight: OPTIONAL REAL;
+      END_ENTITY;
+
+      ENTITY direction;
+        ratios: LIST [1:3] OF REAL;
+      END_ENTITY;
+
+      TYPE vector_or_point = SELECT (direction, named);
+      END_TYPE;
+
+      ENTITY arrow;
+        head: vector_or_point;
+        tail: named;
+      END_ENTITY;
+
+      ENTITY job;
+        build: INTEGER;
+      END_ENTITY;
+
+      ENTITY scaffold;
+        size: REAL;
+      DERIVE
+        builder: REAL := 2.0 * size;
+      END_ENTITY;
+    END_SCHEMA;
+    "#,
+    builder
+);
+
+use test_schema::*;
+
+fn point() -> Point {
+    Point::builder()
+        .name("origin")
+        .coordinates([0.0, 0.0, 0.0])
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn flatten_supertype() {
+    assert_eq!(
+        point(),
+        Point {
+            named: Named {
+                name: "origin".to_string()
+            },
+            coordinates: vec![0.0, 0.0, 0.0],
+            weight: None,
+        }
+    );
+
+    let p = Point::builder()
+        .name("p")
+        .coordinates(vec![1.0])
+        .weight(2.0)
+        .build()
+        .unwrap();
+    assert_eq!(p.weight, Some(2.0));
+}
+
+#[test]
+fn into_select_and_any() {
+    let direction = Direction::builder()
+        .ratios([1.0, 0.0, 0.0])
+        .build()
+        .unwrap();
+    let arrow = Arrow::builder()
+        .head(direction.clone())
+        .tail(point())
+        .build()
+        .unwrap();
+    assert_eq!(arrow.head, VectorOrPoint::Direction(Box::new(direction)));
+    assert_eq!(arrow.tail, NamedAny::Point(Box::new(point())));
+}
+
+#[test]
+fn missing_attribute() {
+    let err = Point::builder().name("p").build().unwrap_err();
+    assert!(matches!(
+        err,
+        Error::MissingAttribute { entity, attribute } if entity == "point" && attribute == "coordinates"
+    ));
+}
+
+#[test]
+fn conflicting_names() {
+    // Setter of `build` attribute is prefixed by the entity not to conflict with `build()`
+    let job = Job::builder().job_build(3).build().unwrap();
+    assert_eq!(job, Job { build: 3 });
+
+    // `Scaffold::builder()` is not generated since it conflicts with the derived attribute
+    assert_eq!(Scaffold { size: 1.0 }.builder().unwrap(), 2.0);
+}
@@ -0,0 +1,85 @@
+// Test for derived attributes
+
+use ruststep::tables::*;
+use std::str::FromStr;
+
+espr_derive::inline_express!(
+    r#"
+    SCHEMA test_schema;
+      TYPE label = STRING;
+      END_TYPE;
+
+      ENTITY base;
+        x: REAL;
+        n: INTEGER;
+      END_ENTITY;
+
+      ENTITY sub SUBTYPE OF (base);
+        y: REAL;
+        names: LIST [0:?] OF STRING;
+      DERIVE
+        sum: REAL := n + SELF.y;
+        twice: INTEGER := 2 * n;
+        count: INTEGER := SIZEOF(names);
+        positive: BOOLEAN := (n > 0) AND (y > 0.0);
+        other: REAL := f(x);
+        modulo: INTEGER := (-7) MOD n;
+        tag: label := 'sub';
+        half: REAL := sum / 2.0;
+      END_ENTITY;
+
+      ENTITY one SUBTYPE OF (base);
+      DERIVE
+        SELF\base.x: REAL := 1.0;
+      END_ENTITY;
+    END_SCHEMA;
+    "#
+);
+
+use test_schema::*;
+
+const EXAMPLE: &str = r#"
+DATA;
+  #1 = BASE(1.5, 3);
+  #2 = SUB(#1, 2.0, ('a', 'b', 'c'));
+  #3 = ONE(BASE((*, 4)));
+ENDSEC;
+"#;
+
+#[test]
+fn derived_methods() {
+    let table = Tables::from_str(EXAMPLE).unwrap();
+    let sub = EntityTable::<SubHolder>::get_owned(&table, 2).unwrap();
+    assert_eq!(sub.sum().unwrap(), 5.0);
+    assert_eq!(sub.twice().unwrap(), 6);
+    assert_eq!(sub.count().unwrap(), 3);
+    assert!(sub.positive().unwrap());
+    // Floor semantics, i.e. the sign of the divisor
+    assert_eq!(sub.modulo().unwrap(), 2);
+    // Derived attribute of a named type
+    assert_eq!(sub.tag().unwrap(), Label("sub".to_string()));
+    // Derived attribute referring another one
+    assert_eq!(sub.half().unwrap(), 2.5);
+}
+
+#[test]
+fn unsupported() {
+    let table = Tables::from_str(EXAMPLE).unwrap();
+    let sub = EntityTable::<SubHolder>::get_owned(&table, 2).unwrap();
+    assert!(matches!(
+        sub.other(),
+        Err(ruststep::error::Error::UnsupportedDerivedAttribute { .. })
+    ));
+}
+
+#[test]
+fn redeclared_as_derived() {
+    let table = Tables::from_str(EXAMPLE).unwrap();
+    let base = EntityTable::<BaseHolder>::get_owned(&table, 1).unwrap();
+    assert_eq!(base.x, Some(1.5));
+
+    let one = EntityTable::<OneHolder>::get_owned(&table, 3).unwrap();
+    assert_eq!(one.base.x, None);
+    assert_eq!(one.x().unwrap(), 1.0);
+    assert_eq!(one.n, 4);
+}
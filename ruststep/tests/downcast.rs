@@ -0,0 +1,87 @@
+use std::{convert::TryFrom, str::FromStr};
+
+espr_derive::inline_express!(
+    r#"
+    SCHEMA test_schema;
+      ENTITY base SUPERTYPE OF (ONEOF (sub, leaf));
+        x: REAL;
+      END_ENTITY;
+
+      ENTITY sub
+        SUPERTYPE OF (subsub)
+        SUBTYPE OF (base);
+        y: REAL;
+      END_ENTITY;
+
+      ENTITY subsub SUBTYPE OF (sub);
+        z: REAL;
+      END_ENTITY;
+
+      ENTITY leaf SUBTYPE OF (base);
+        w: REAL;
+      END_ENTITY;
+    END_SCHEMA;
+    "#,
+    downcast
+);
+
+use test_schema::*;
+
+const EXAMPLE: &str = r#"
+DATA;
+  #1 = BASE(1.0);
+  #2 = SUB(BASE((1.0)), 2.0);
+  #3 = SUBSUB(SUB((BASE((1.0)), 2.0)), 3.0);
+  #4 = LEAF(#1, 4.0);
+ENDSEC;
+"#;
+
+fn subsub() -> BaseAny {
+    let subsub = Subsub {
+        sub: Sub {
+            base: Base { x: 1.0 },
+            y: 2.0,
+        },
+        z: 3.0,
+    };
+    BaseAny::Sub(Box::new(subsub.into()))
+}
+
+#[test]
+fn downcast_ref() {
+    let any = subsub();
+    assert_eq!(any.downcast_ref::<Subsub>().unwrap().z, 3.0);
+    assert!(any.downcast_ref::<Sub>().is_none());
+    assert!(any.downcast_ref::<Base>().is_none());
+    assert!(any.is::<Subsub>());
+    assert!(!any.is::<Leaf>());
+}
+
+#[test]
+fn try_from() {
+    let any = subsub();
+    let any = Leaf::try_from(any).unwrap_err();
+    let any = Sub::try_from(any).unwrap_err();
+    let subsub = Subsub::try_from(any).unwrap();
+    assert_eq!(subsub.sub.y, 2.0);
+
+    let any: BaseAny = Base { x: 1.0 }.into();
+    assert_eq!(Base::try_from(any).unwrap(), Base { x: 1.0 });
+}
+
+#[test]
+fn any_iter() {
+    let table = Tables::from_str(EXAMPLE).unwrap();
+    let leaves: Vec<BaseAny> = table.base_any_iter().map(|any| any.unwrap()).collect();
+    assert_eq!(leaves.len(), 4);
+    assert_eq!(leaves.iter().filter(|any| any.is::<Base>()).count(), 1);
+    assert_eq!(leaves.iter().filter(|any| any.is::<Sub>()).count(), 1);
+    assert_eq!(leaves.iter().filter(|any| any.is::<Subsub>()).count(), 1);
+    assert_eq!(leaves.iter().filter(|any| any.is::<Leaf>()).count(), 1);
+
+    let subs: Vec<Sub> = table
+        .sub_any_iter()
+        .filter_map(|any| Sub::try_from(any.unwrap()).ok())
+        .collect();
+    assert_eq!(subs.len(), 1);
+}
@@ -0,0 +1,232 @@
+#![cfg(feature = "dynamic")]
+
+use espr::{ast::SyntaxTree, ir::IR};
+use ruststep::{ast::DataSection, dynamic::*, error::Error};
+use std::str::FromStr;
+
+const EXPRESS: &str = r#"
+SCHEMA test_schema;
+  TYPE label = STRING;
+  END_TYPE;
+
+  TYPE color = ENUMERATION OF (red, green);
+  END_TYPE;
+
+  TYPE tag = SELECT (label, point);
+  END_TYPE;
+
+  ENTITY named;
+    name: OPTIONAL label;
+  END_ENTITY;
+
+  ENTITY point SUBTYPE OF (named);
+    coordinates: LIST [2:3] OF REAL;
+  END_ENTITY;
+
+  ENTITY shape SUPERTYPE OF (circle ANDOR filled) SUBTYPE OF (named);
+    tags: OPTIONAL SET [1:?] OF tag;
+  END_ENTITY;
+
+  ENTITY circle SUBTYPE OF (shape);
+    center: point;
+    radius: REAL;
+  END_ENTITY;
+
+  ENTITY filled SUBTYPE OF (shape);
+    fill: color;
+  END_ENTITY;
+END_SCHEMA;
+"#;
+
+fn ir() -> IR {
+    IR::from_syntax_tree(&SyntaxTree::parse(EXPRESS).unwrap()).unwrap()
+}
+
+fn load<'ir>(ir: &'ir IR, data: &str) -> ruststep::error::Result<Model<'ir>> {
+    Model::from_data_section(ir, &DataSection::from_str(data).unwrap())
+}
+
+#[test]
+fn simple() {
+    let ir = ir();
+    let model = load(
+        &ir,
+        r#"
+        DATA;
+          #1 = POINT('origin', (0.0, 0));
+          #2 = CIRCLE($, (LABEL('round'), #1), #1, 2.0);
+        ENDSEC;
+        "#,
+    )
+    .unwrap();
+
+    let point = model.get(1).unwrap();
+    assert_eq!(point.entities().collect::<Vec<_>>(), ["named", "point"]);
+    assert_eq!(
+        point.get("NAME"),
+        Some(&Value::String("origin".to_string()))
+    );
+    assert_eq!(
+        point.get("coordinates"),
+        Some(&Value::List(vec![Value::Real(0.0), Value::Real(0.0)]))
+    );
+
+    let circle = model.get(2).unwrap();
+    assert!(circle.is_a("shape") && circle.is_a("NAMED"));
+    assert_eq!(circle.get("name"), Some(&Value::Null));
+    assert_eq!(
+        circle.get("tags"),
+        Some(&Value::List(vec![
+            Value::Typed {
+                name: "label".to_string(),
+                value: Box::new(Value::String("round".to_string())),
+            },
+            Value::Ref(1),
+        ]))
+    );
+    let center = model.resolve(circle.get("center").unwrap()).unwrap();
+    assert_eq!(center.id(), Some(1));
+
+    let named: Vec<_> = model.instances_of("named").map(|i| i.id()).collect();
+    assert_eq!(named, [Some(1), Some(2)]);
+    assert_eq!(model.instances_of("shape").count(), 1);
+}
+
+#[test]
+fn complex() {
+    let ir = ir();
+    let model = load(
+        &ir,
+        r#"
+        DATA;
+          #1 = POINT($, (0.0, 0.0));
+          #2 = (CIRCLE(#1, 1.0) FILLED(.RED.) NAMED('disk') SHAPE($));
+        ENDSEC;
+        "#,
+    )
+    .unwrap();
+    let disk = model.get(2).unwrap();
+    assert!(disk.is_a("circle") && disk.is_a("filled"));
+    assert_eq!(
+        disk.get("fill"),
+        Some(&Value::Enumeration("red".to_string()))
+    );
+    assert_eq!(disk.get("radius"), Some(&Value::Real(1.0)));
+    assert_eq!(model.instances_of("shape").count(), 1);
+
+    // Supertype `shape` is missing
+    let err = load(
+        &ir,
+        "DATA; #1 = (CIRCLE(#2, 1.0) NAMED($)); #2 = POINT($, (0.0, 0.0)); ENDSEC;",
+    )
+    .unwrap_err();
+    assert!(matches!(err, Error::NotInstantiable { .. }));
+}
+
+#[test]
+fn not_instantiable() {
+    const AND: &str = r#"
+    SCHEMA test_schema;
+      ENTITY base SUPERTYPE OF (a AND b);
+      END_ENTITY;
+      ENTITY a SUBTYPE OF (base);
+      END_ENTITY;
+      ENTITY b SUBTYPE OF (base);
+      END_ENTITY;
+    END_SCHEMA;
+    "#;
+    let ir = IR::from_syntax_tree(&SyntaxTree::parse(AND).unwrap()).unwrap();
+    assert!(load(&ir, "DATA; #1 = (A() B() BASE()); ENDSEC;").is_ok());
+    let err = load(&ir, "DATA; #1 = A(); ENDSEC;").unwrap_err();
+    match err {
+        Error::NotInstantiable { entities } => assert_eq!(entities, "a, base"),
+        _ => panic!("{:?}", err),
+    }
+}
+
+#[test]
+fn invalid() {
+    let ir = ir();
+    let err = |data: &str| load(&ir, data).unwrap_err();
+
+    assert!(matches!(
+        err("DATA; #1 = POINT('a', ('x', 0.0)); ENDSEC;"),
+        Error::InvalidParameter { attribute, .. } if attribute == "coordinates"
+    ));
+    assert!(matches!(
+        err("DATA; #1 = POINT('a', (0.0)); ENDSEC;"),
+        Error::InvalidAggregateSize { size: 1, .. }
+    ));
+    assert!(matches!(
+        err("DATA; #1 = POINT('a'); ENDSEC;"),
+        Error::ParameterCount {
+            expected: 2,
+            found: 1,
+            ..
+        }
+    ));
+    assert!(matches!(
+        err("DATA; #1 = LINE(); ENDSEC;"),
+        Error::UnknownEntityName { .. }
+    ));
+    assert!(matches!(
+        err("DATA; #1 = FILLED('a', $, .BLUE.); ENDSEC;"),
+        Error::InvalidParameter { attribute, .. } if attribute == "fill"
+    ));
+    assert!(matches!(
+        err("DATA; #1 = CIRCLE('a', $, #2, 1.0); ENDSEC;"),
+        Error::UnknownEntity(2)
+    ));
+    assert!(matches!(
+        err("DATA; #1 = CIRCLE('a', $, #2, 1.0); #2 = FILLED('b', $, .RED.); ENDSEC;"),
+        Error::InvalidParameter { attribute, parameter, .. }
+            if attribute == "center" && parameter == "#2"
+    ));
+    assert!(matches!(
+        err("DATA; #1 = POINT('a', (0.0, 0.0)); #1 = POINT('b', (0.0, 0.0)); ENDSEC;"),
+        Error::DuplicatedEntity(1)
+    ));
+}
+
+#[test]
+fn same_names_in_schemas() {
+    let ir = IR::from_syntax_tree(
+        &SyntaxTree::parse(
+            r#"
+            SCHEMA metric;
+              TYPE span = REAL;
+              END_TYPE;
+              ENTITY point;
+                x: span;
+              END_ENTITY;
+            END_SCHEMA;
+
+            SCHEMA grid;
+              TYPE span = INTEGER;
+              END_TYPE;
+              ENTITY point;
+                x: span;
+              END_ENTITY;
+              ENTITY rod;
+                l: span;
+              END_ENTITY;
+            END_SCHEMA;
+            "#,
+        )
+        .unwrap(),
+    )
+    .unwrap();
+
+    // `span` is resolved in `grid` schema declaring `rod`
+    assert!(load(&ir, "DATA; #1 = ROD(3); ENDSEC;").is_ok());
+    assert!(matches!(
+        load(&ir, "DATA; #1 = ROD(3.0); ENDSEC;").unwrap_err(),
+        Error::InvalidParameter { attribute, .. } if attribute == "l"
+    ));
+
+    assert!(matches!(
+        load(&ir, "DATA; #1 = POINT(1.0); ENDSEC;").unwrap_err(),
+        Error::AmbiguousEntityName { entity_name, schemas }
+            if entity_name == "POINT" && schemas == "metric, grid"
+    ));
+}
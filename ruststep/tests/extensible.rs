@@ -0,0 +1,106 @@
+// Test for extensible ENUMERATION and SELECT types
+
+use ruststep::tables::*;
+use std::str::FromStr;
+
+espr_derive::inline_express!(
+    r#"
+    SCHEMA test_schema;
+      TYPE direction = EXTENSIBLE ENUMERATION OF (up, down);
+      END_TYPE;
+
+      TYPE direction_3d = ENUMERATION BASED_ON direction WITH (front, back);
+      END_TYPE;
+
+      ENTITY a;
+        x: REAL;
+      END_ENTITY;
+
+      ENTITY b;
+        y: INTEGER;
+      END_ENTITY;
+
+      ENTITY c;
+        dir: direction;
+      END_ENTITY;
+
+      TYPE item = EXTENSIBLE GENERIC_ENTITY SELECT;
+      END_TYPE;
+
+      TYPE item_a = EXTENSIBLE SELECT BASED_ON item WITH (a);
+      END_TYPE;
+
+      TYPE item_ab = SELECT BASED_ON item_a WITH (b);
+      END_TYPE;
+
+      TYPE empty = EXTENSIBLE SELECT;
+      END_TYPE;
+    END_SCHEMA;
+    "#,
+    visit
+);
+
+use test_schema::*;
+
+const EXAMPLE: &str = r#"
+DATA;
+  #1 = A(3.34);
+  #2 = B(57);
+  #3 = C(.BACK.);
+ENDSEC;
+"#;
+
+#[test]
+fn extended_enumeration() {
+    // Base type contains items of its extension
+    let _ = [
+        Direction::Up,
+        Direction::Down,
+        Direction::Front,
+        Direction::Back,
+    ];
+    let _ = [
+        Direction3D::Up,
+        Direction3D::Down,
+        Direction3D::Front,
+        Direction3D::Back,
+    ];
+
+    let table = Tables::from_str(EXAMPLE).unwrap();
+    let c = EntityTable::<CHolder>::get_owned(&table, 3).unwrap();
+    assert_eq!(c.dir, Direction::Back);
+}
+
+#[test]
+fn extended_select() {
+    let table = Tables::from_str(EXAMPLE).unwrap();
+
+    // Base type contains types of its extensions
+    let item = EntityTable::<ItemHolder>::get_owned(&table, 1).unwrap();
+    assert_eq!(item, Item::A(Box::new(A { x: 3.34 })));
+    let item = EntityTable::<ItemHolder>::get_owned(&table, 2).unwrap();
+    assert_eq!(item, Item::B(Box::new(B { y: 57 })));
+
+    // Extension contains types of its base and its extensions
+    let item_a = EntityTable::<ItemAHolder>::get_owned(&table, 2).unwrap();
+    assert_eq!(item_a, ItemA::B(Box::new(B { y: 57 })));
+    let item_ab = EntityTable::<ItemAbHolder>::get_owned(&table, 1).unwrap();
+    assert_eq!(item_ab, ItemAb::A(Box::new(A { x: 3.34 })));
+}
+
+#[test]
+fn visit_empty_select() {
+    use test_schema::{visit::Visit, visit_mut::VisitMut};
+
+    struct Nothing;
+    impl<'ast> Visit<'ast> for Nothing {}
+    impl VisitMut for Nothing {}
+
+    // `EXTENSIBLE SELECT` without items is an empty enum, and has nothing to walk
+    let _ = |node: &Empty| visit::visit_empty(&mut Nothing, node);
+    let _ = |node: &mut Empty| visit_mut::visit_empty_mut(&mut Nothing, node);
+
+    let item = Item::A(Box::new(A { x: 3.34 }));
+    Nothing.visit_item(&item);
+    Nothing.visit_item_mut(&mut item.clone());
+}
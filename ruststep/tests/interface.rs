@@ -0,0 +1,58 @@
+// Test for entities interfaced from other schemas by USE FROM
+
+use ruststep::tables::*;
+use std::str::FromStr;
+
+espr_derive::inline_express!(
+    r#"
+    SCHEMA shape;
+      USE FROM topology;
+      ENTITY face;
+        bound: LIST [1:?] OF edge;
+      END_ENTITY;
+    END_SCHEMA;
+
+    SCHEMA geometry;
+      ENTITY point;
+        x, y: REAL;
+      END_ENTITY;
+    END_SCHEMA;
+
+    SCHEMA topology;
+      USE FROM geometry (point AS vertex);
+      ENTITY edge;
+        head, tail: vertex;
+      END_ENTITY;
+    END_SCHEMA;
+    "#
+);
+
+const EXAMPLE: &str = r#"
+DATA;
+  #1 = POINT(0.0, 0.0);
+  #2 = POINT(1.0, 0.0);
+  #3 = EDGE(#1, #2);
+  #4 = FACE((#3, #3));
+ENDSEC;
+"#;
+
+#[test]
+fn resolve_interfaced_entities() {
+    let table = shape::Tables::from_str(EXAMPLE).unwrap();
+
+    // Records of interfaced entities are held in the nested tables of their schemas
+    let points = table.topology_tables().geometry_tables().point_holders();
+    assert_eq!(points.len(), 2);
+
+    let face = EntityTable::<shape::FaceHolder>::get_owned(&table, 4).unwrap();
+    let edge = topology::Edge {
+        head: geometry::Point { x: 0.0, y: 0.0 },
+        tail: geometry::Point { x: 1.0, y: 0.0 },
+    };
+    assert_eq!(
+        face,
+        shape::Face {
+            bound: vec![edge.clone(), edge]
+        }
+    );
+}
@@ -0,0 +1,116 @@
+// Test for inverse attributes
+
+use ruststep::tables::*;
+use std::str::FromStr;
+
+espr_derive::inline_express!(
+    r#"
+    SCHEMA test_schema;
+      ENTITY door;
+        handle: knob;
+      END_ENTITY;
+
+      ENTITY knob;
+        label: STRING;
+      INVERSE
+        opens: door FOR handle;
+      END_ENTITY;
+
+      ENTITY face;
+        label: STRING;
+      INVERSE
+        of_shell: SET [1:?] OF shell FOR faces;
+      END_ENTITY;
+
+      ENTITY shell;
+        faces: SET [1:?] OF face;
+      END_ENTITY;
+
+      ENTITY closed_shell SUBTYPE OF (shell);
+      END_ENTITY;
+
+      TYPE knob_ref = knob;
+      END_TYPE;
+
+      TYPE fitting = SELECT (knob_ref);
+      END_TYPE;
+
+      ENTITY cabinet;
+        fitting: fitting;
+      END_ENTITY;
+    END_SCHEMA;
+    "#
+);
+
+use test_schema::*;
+
+const EXAMPLE: &str = r#"
+DATA;
+  #1 = FACE('a');
+  #2 = FACE('b');
+  #3 = FACE('c');
+  #4 = SHELL((#1, #2));
+  #5 = CLOSED_SHELL(SHELL(((#1))));
+  #6 = KNOB('x');
+  #7 = KNOB('y');
+  #8 = DOOR(#6);
+ENDSEC;
+"#;
+
+#[test]
+fn inverse_methods() {
+    let table = Tables::from_str(EXAMPLE).unwrap();
+    let face = EntityTable::<FaceHolder>::get_owned(&table, 1).unwrap();
+    assert_eq!(face.of_shell(1, &table), vec![4, 5]);
+    assert_eq!(face.of_shell(2, &table), vec![4]);
+    assert_eq!(face.of_shell(3, &table), Vec::<u64>::new());
+
+    let knob = EntityTable::<KnobHolder>::get_owned(&table, 6).unwrap();
+    assert_eq!(knob.opens(6, &table), vec![8]);
+}
+
+#[test]
+fn check_cardinality() {
+    let table = Tables::from_str(EXAMPLE).unwrap();
+    let face = EntityTable::<FaceHolder>::get_owned(&table, 1).unwrap();
+    assert!(face.check_inverse(1, &table).is_ok());
+    assert!(face.check_inverse(3, &table).is_err());
+
+    let knob = EntityTable::<KnobHolder>::get_owned(&table, 6).unwrap();
+    assert!(knob.check_inverse(6, &table).is_ok());
+    assert!(knob.check_inverse(7, &table).is_err());
+}
+
+#[test]
+fn index_is_rebuilt() {
+    let mut table = Tables::from_str(EXAMPLE).unwrap();
+    let knob = EntityTable::<KnobHolder>::get_owned(&table, 7).unwrap();
+    assert_eq!(knob.opens(7, &table), Vec::<u64>::new());
+
+    // Index built by the lookup above is dropped when records are appended
+    let section = ruststep::ast::DataSection::from_str("DATA; #9 = DOOR(#7); ENDSEC;").unwrap();
+    table.append_data_section(&section).unwrap();
+    assert_eq!(knob.opens(7, &table), vec![9]);
+}
+
+#[test]
+fn index_is_not_compared() {
+    let table = Tables::from_str(EXAMPLE).unwrap();
+    let indexed = table.clone();
+    let knob = EntityTable::<KnobHolder>::get_owned(&indexed, 6).unwrap();
+    assert_eq!(knob.opens(6, &indexed), vec![8]);
+    assert_eq!(table, indexed);
+}
+
+#[test]
+fn refer_inline_value() {
+    use ruststep::ast::Name;
+    // `KNOB_REF(#7)` as the value of `fitting`
+    let fitting = PlaceHolder::Owned(FittingHolder::KnobRef(Box::new(KnobRefHolder(
+        PlaceHolder::Ref(Name::Entity(7)),
+    ))));
+    let mut ids = Vec::new();
+    fitting.referred_ids(&mut ids);
+    assert_eq!(ids, vec![7]);
+    assert!(fitting.refers_to(7));
+}
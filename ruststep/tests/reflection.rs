@@ -0,0 +1,160 @@
+use ruststep::reflection::*;
+
+espr_derive::inline_express!(
+    r#"
+    SCHEMA test_schema;
+      TYPE label = STRING;
+      END_TYPE;
+
+      TYPE color = ENUMERATION OF (red, green);
+      END_TYPE;
+
+      TYPE shape_select = SELECT (circle, square);
+      END_TYPE;
+
+      ENTITY shape SUPERTYPE OF (circle ANDOR square);
+        name: OPTIONAL label;
+        fill: color;
+      END_ENTITY;
+
+      ENTITY circle SUBTYPE OF (shape);
+        radius: REAL;
+      END_ENTITY;
+
+      ENTITY square SUBTYPE OF (shape);
+        corners: ARRAY [1:4] OF OPTIONAL REAL;
+      END_ENTITY;
+
+      ENTITY drawing;
+        shapes: SET [1:?] OF shape;
+      END_ENTITY;
+
+      ENTITY rectangle;
+        width: REAL;
+        height: REAL;
+      END_ENTITY;
+
+      ENTITY square_rectangle SUBTYPE OF (rectangle);
+      DERIVE
+        SELF\rectangle.height: REAL := width;
+      END_ENTITY;
+    END_SCHEMA;
+    "#,
+    reflection
+);
+
+use test_schema::*;
+
+#[test]
+fn entities() {
+    assert_eq!(SCHEMA_INFO.name, "test_schema");
+    let names: Vec<_> = SCHEMA_INFO.entities.iter().map(|e| e.name).collect();
+    assert_eq!(
+        names,
+        [
+            "shape",
+            "circle",
+            "square",
+            "drawing",
+            "rectangle",
+            "square_rectangle"
+        ]
+    );
+
+    let shape = entity_info("SHAPE").unwrap();
+    assert_eq!(shape.subtypes, ["circle", "square"]);
+    assert_eq!(
+        shape.instantiables(),
+        [vec!["circle"], vec!["square"], vec!["circle", "square"]]
+    );
+    let constraint = shape.subtype_constraint.unwrap();
+    assert!(constraint.is_instantiable(&["SQUARE", "CIRCLE"]));
+    assert!(!constraint.is_instantiable(&["shape"]));
+    let name = shape.attribute("name").unwrap();
+    assert_eq!(name.ty, AttributeType::Named("label"));
+    assert!(name.optional);
+    assert!(!shape.attribute("fill").unwrap().optional);
+
+    let square = entity_info("square").unwrap();
+    assert_eq!(square.supertypes, ["shape"]);
+    assert_eq!(
+        square.attributes[0].ty,
+        AttributeType::Array {
+            base: &AttributeType::Simple("REAL"),
+            lower: Some(1),
+            upper: Some(4),
+            optional: true,
+        }
+    );
+
+    let drawing = entity_info("drawing").unwrap();
+    assert_eq!(
+        drawing.attributes[0].ty,
+        AttributeType::Set {
+            base: &AttributeType::Entity("shape"),
+            lower: Some(1),
+            upper: None,
+        }
+    );
+
+    // `height` is `*` in the exchange structure of `square_rectangle`
+    let rectangle = entity_info("rectangle").unwrap();
+    let height = rectangle.attribute("height").unwrap();
+    assert!(height.redeclared_as_derived);
+    assert!(!height.optional);
+    assert!(!rectangle.attribute("width").unwrap().redeclared_as_derived);
+
+    assert!(entity_info("line").is_none());
+}
+
+#[test]
+fn types() {
+    let kinds: Vec<_> = SCHEMA_INFO.types.iter().map(|t| (t.name, t.kind)).collect();
+    assert_eq!(
+        kinds,
+        [
+            ("label", TypeKind::Defined(AttributeType::Simple("STRING"))),
+            ("color", TypeKind::Enumeration(&["red", "green"])),
+            ("shape_select", TypeKind::Select(&["circle", "square"])),
+        ]
+    );
+}
+
+#[test]
+fn subtype_constraint() {
+    use SubtypeConstraint::*;
+    // (male AND (citizen ANDOR alien)) ONEOF robot
+    static EXPR: SubtypeConstraint = OneOf(&[
+        And(&[
+            Reference("male"),
+            AndOr(&[Reference("citizen"), Reference("alien")]),
+        ]),
+        Reference("robot"),
+    ]);
+    assert_eq!(
+        EXPR.instantiables(),
+        [
+            vec!["citizen", "male"],
+            vec!["alien", "male"],
+            vec!["alien", "citizen", "male"],
+            vec!["robot"],
+        ]
+    );
+    for names in EXPR.instantiables() {
+        assert!(EXPR.is_instantiable(&names));
+    }
+    assert!(!EXPR.is_instantiable(&["male"]));
+    assert!(!EXPR.is_instantiable(&["male", "robot"]));
+    assert!(!EXPR.is_instantiable(&["citizen", "alien"]));
+    assert!(!EXPR.is_instantiable(&[]));
+
+    // (a ONEOF b) ANDOR (b ONEOF c), where operands share `b`
+    static SHARED: SubtypeConstraint = AndOr(&[
+        OneOf(&[Reference("a"), Reference("b")]),
+        OneOf(&[Reference("b"), Reference("c")]),
+    ]);
+    assert!(SHARED.is_instantiable(&["b"]));
+    assert!(SHARED.is_instantiable(&["a", "b"]));
+    assert!(SHARED.is_instantiable(&["a", "c"]));
+    assert!(!SHARED.is_instantiable(&["a", "b", "c"]));
+}
@@ -0,0 +1,166 @@
+#![cfg(feature = "sqlite")]
+
+use espr::{ast::SyntaxTree, ir::IR};
+use ruststep::{ast::DataSection, dynamic::Model, error::Error, sqlite};
+use std::str::FromStr;
+
+const EXPRESS: &str = r#"
+SCHEMA test_schema;
+  TYPE label = STRING;
+  END_TYPE;
+
+  TYPE tag = SELECT (label, point);
+  END_TYPE;
+
+  ENTITY named;
+    name: OPTIONAL label;
+  END_ENTITY;
+
+  ENTITY point SUBTYPE OF (named);
+    coordinates: LIST [2:3] OF REAL;
+  END_ENTITY;
+
+  ENTITY line SUBTYPE OF (named);
+    start: point;
+    finish: point;
+    tags: SET [1:?] OF tag;
+  END_ENTITY;
+END_SCHEMA;
+"#;
+
+fn load(data: &str) -> rusqlite::Connection {
+    let ir = IR::from_syntax_tree(&SyntaxTree::parse(EXPRESS).unwrap()).unwrap();
+    sqlite::load(":memory:", &ir, &DataSection::from_str(data).unwrap()).unwrap()
+}
+
+fn query<T: rusqlite::types::FromSql>(conn: &rusqlite::Connection, sql: &str) -> Vec<T> {
+    let mut statement = conn.prepare(sql).unwrap();
+    let rows = statement.query_map([], |row| row.get(0)).unwrap();
+    rows.map(Result::unwrap).collect()
+}
+
+#[test]
+fn load_data_section() {
+    let conn = load(
+        r#"
+        DATA;
+          #1 = LINE('l', #2, POINT(($, (1.0, 1.0))), (LABEL('a'), #2));
+          #2 = POINT('origin', (0.0, 0.0, 0.0));
+        ENDSEC;
+        "#,
+    );
+
+    // Subtypes have rows in supertype tables
+    let names: Vec<Option<String>> = query(&conn, r#"SELECT "name" FROM "named" ORDER BY "_id""#);
+    assert_eq!(
+        names,
+        [Some("l".to_string()), Some("origin".to_string()), None]
+    );
+
+    // Inline instance is inserted with a new id
+    let ends: Vec<i64> = query(&conn, r#"SELECT "finish" FROM "line""#);
+    assert_eq!(ends, [3]);
+
+    let coordinates: Vec<f64> = query(
+        &conn,
+        r#"SELECT "value" FROM "point.coordinates" WHERE "owner" = 2 ORDER BY "position""#,
+    );
+    assert_eq!(coordinates, [0.0, 0.0, 0.0]);
+
+    // Select of defined type is encoded as text
+    let tags: Vec<String> = query(
+        &conn,
+        r#"SELECT "value" FROM "line.tags" ORDER BY "position""#,
+    );
+    assert_eq!(tags, ["LABEL('a')", "#2"]);
+
+    // Join through the foreign key
+    let start: Vec<String> = query(
+        &conn,
+        r#"SELECT "named"."name" FROM "line" JOIN "named" ON "line"."start" = "named"."_id""#,
+    );
+    assert_eq!(start, ["origin"]);
+}
+
+#[test]
+fn several_files() {
+    let ir = IR::from_syntax_tree(&SyntaxTree::parse(EXPRESS).unwrap()).unwrap();
+    let mut conn = rusqlite::Connection::open_in_memory().unwrap();
+    for (name, data) in [
+        ("a.stp", "DATA; #1 = POINT('a', (0.0, 0.0)); ENDSEC;"),
+        ("b.stp", "DATA; #1 = POINT('b', (1.0, 1.0)); ENDSEC;"),
+    ] {
+        // Tables are kept if exist
+        sqlite::create_tables(&conn, &ir).unwrap();
+        let section = DataSection::from_str(data).unwrap();
+        let model = Model::from_data_section(&ir, &section).unwrap();
+        sqlite::insert(&mut conn, &ir, Some(name), &model).unwrap();
+    }
+
+    let names: Vec<String> = query(
+        &conn,
+        r#"SELECT "_file"."name" FROM "named" JOIN "_file" ON "named"."_file" = "_file"."_id" WHERE "named"."name" = 'b'"#,
+    );
+    assert_eq!(names, ["b.stp"]);
+    let xs: Vec<f64> = query(
+        &conn,
+        r#"SELECT "value" FROM "point.coordinates" ORDER BY "_file", "position""#,
+    );
+    assert_eq!(xs, [0.0, 0.0, 1.0, 1.0]);
+}
+
+#[test]
+fn ambiguous_entity() {
+    let ir = IR::from_syntax_tree(
+        &SyntaxTree::parse(
+            r#"
+            SCHEMA metric; ENTITY point; x: REAL; END_ENTITY; END_SCHEMA;
+            SCHEMA grid; ENTITY point; x: INTEGER; END_ENTITY; END_SCHEMA;
+            "#,
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    let conn = rusqlite::Connection::open_in_memory().unwrap();
+    assert!(matches!(
+        sqlite::create_tables(&conn, &ir),
+        Err(Error::AmbiguousEntityName { entity_name, .. }) if entity_name == "point"
+    ));
+}
+
+#[test]
+fn missing_table() {
+    // Instances typed by another schema than the tables
+    let other = IR::from_syntax_tree(
+        &SyntaxTree::parse("SCHEMA other; ENTITY circle; r: REAL; END_ENTITY; END_SCHEMA;")
+            .unwrap(),
+    )
+    .unwrap();
+    let data = DataSection::from_str("DATA; #1 = CIRCLE(1.0); ENDSEC;").unwrap();
+    let model = Model::from_data_section(&other, &data).unwrap();
+
+    let ir = IR::from_syntax_tree(&SyntaxTree::parse(EXPRESS).unwrap()).unwrap();
+    let mut conn = rusqlite::Connection::open_in_memory().unwrap();
+    sqlite::create_tables(&conn, &ir).unwrap();
+    assert!(matches!(
+        sqlite::insert(&mut conn, &ir, None, &model),
+        Err(Error::MissingTable { table }) if table == "circle"
+    ));
+}
+
+#[test]
+fn ap203_tables() {
+    let schemas = std::path::Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/../schemas"));
+    let st = SyntaxTree::load(&[schemas.join("APs/10303-203-aim-long.exp")], &[]).unwrap();
+    let ir = IR::from_syntax_tree(&st).unwrap();
+    let conn = rusqlite::Connection::open_in_memory().unwrap();
+    sqlite::create_tables(&conn, &ir).unwrap();
+    // Fails if a foreign key does not refer the primary key
+    conn.execute_batch("PRAGMA foreign_key_check").unwrap();
+
+    let count: Vec<i64> = query(
+        &conn,
+        "SELECT COUNT(*) FROM sqlite_master WHERE type = 'table'",
+    );
+    assert_eq!(count, [espr::codegen::sql::tables(&ir).len() as i64]);
+}
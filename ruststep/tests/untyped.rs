@@ -0,0 +1,69 @@
+//! Records of unknown entities kept by `#[table_init(untyped)]`
+
+use ruststep::{ast::*, error::Error, tables::*};
+use ruststep_derive::{as_holder, Holder, TableInit};
+use std::{collections::HashMap, str::FromStr};
+
+#[derive(Debug, Default, TableInit)]
+pub struct Table {
+    a: HashMap<u64, as_holder!(A)>,
+    #[table_init(untyped)]
+    others: HashMap<u64, Record>,
+}
+
+#[derive(Debug, Default, TableInit)]
+pub struct StrictTable {
+    a: HashMap<u64, as_holder!(A)>,
+}
+
+#[derive(Debug, Clone, PartialEq, Holder)]
+#[holder(table = Table)]
+#[holder(field = a)]
+#[holder(generate_deserialize)]
+pub struct A {
+    pub x: f64,
+}
+
+const EXAMPLE: &str = r#"
+DATA;
+  #1 = A(1.0);
+  #2 = B(2.0, #1);
+ENDSEC;
+"#;
+
+#[test]
+fn untyped() {
+    let table = Table::from_str(EXAMPLE).unwrap();
+    let a = EntityTable::<AHolder>::get_owned(&table, 1).unwrap();
+    assert_eq!(a, A { x: 1.0 });
+
+    let b = &table.others[&2];
+    assert_eq!(b.name, "B");
+    assert_eq!(
+        b.parameter,
+        Parameter::List(vec![Parameter::Real(2.0), Parameter::Ref(Name::Entity(1))])
+    );
+}
+
+#[test]
+fn unknown_entity() {
+    let err = StrictTable::from_str(EXAMPLE).unwrap_err();
+    assert!(matches!(err, Error::UnknownEntityName { entity_name, .. } if entity_name == "B"));
+}
+
+#[test]
+fn complex_entity() {
+    let err = Table::from_str(
+        r#"
+        DATA;
+          #1 = A(1.0);
+          #2 = (B(2.0) C(#1));
+        ENDSEC;
+        "#,
+    )
+    .unwrap_err();
+    assert!(matches!(
+        err,
+        Error::UnsupportedComplexEntity { id: 2, entities } if entities == "B, C"
+    ));
+}
@@ -0,0 +1,113 @@
+espr_derive::inline_express!(
+    r#"
+    SCHEMA test_schema;
+      TYPE label = STRING;
+      END_TYPE;
+
+      TYPE points = LIST [1:?] OF point;
+      END_TYPE;
+
+      ENTITY named SUPERTYPE OF (ONEOF (point, polyline));
+        name: label;
+      END_ENTITY;
+
+      ENTITY point SUBTYPE OF (named);
+        x: REAL;
+        y: REAL;
+      END_ENTITY;
+
+      ENTITY polyline SUBTYPE OF (named);
+        vertices: points;
+        closing: OPTIONAL point;
+      END_ENTITY;
+
+      TYPE shape = SELECT (point, polyline);
+      END_TYPE;
+
+      ENTITY drawing;
+        items: SET [1:?] OF named;
+        highlighted: OPTIONAL shape;
+      END_ENTITY;
+    END_SCHEMA;
+    "#,
+    builder,
+    visit
+);
+
+use test_schema::{visit::*, visit_mut::*, *};
+
+fn point(name: &str, x: f64, y: f64) -> Point {
+    Point::builder()
+        .name(Label(name.to_string()))
+        .x(x)
+        .y(y)
+        .build()
+        .unwrap()
+}
+
+fn drawing() -> Drawing {
+    let polyline = Polyline::builder()
+        .name(Label("line".to_string()))
+        .vertices(Points(vec![point("a", 0.0, 0.0), point("b", 1.0, 0.0)]))
+        .closing(point("c", 0.0, 1.0))
+        .build()
+        .unwrap();
+    Drawing::builder()
+        .items(vec![polyline.into(), point("d", 2.0, 2.0).into()])
+        .highlighted(point("e", 3.0, 3.0))
+        .build()
+        .unwrap()
+}
+
+#[derive(Default)]
+struct Collect<'ast> {
+    names: Vec<&'ast str>,
+    points: usize,
+}
+
+impl<'ast> Visit<'ast> for Collect<'ast> {
+    fn visit_label(&mut self, node: &'ast Label) {
+        self.names.push(&node.0);
+    }
+
+    fn visit_point(&mut self, node: &'ast Point) {
+        self.points += 1;
+        // Continue to the supertype part
+        visit::visit_point(self, node);
+    }
+}
+
+#[test]
+fn visit() {
+    let drawing = drawing();
+    let mut collect = Collect::default();
+    collect.visit_drawing(&drawing);
+    assert_eq!(collect.names, ["line", "a", "b", "c", "d", "e"]);
+    assert_eq!(collect.points, 5);
+}
+
+struct Scale(f64);
+
+impl VisitMut for Scale {
+    fn visit_point_mut(&mut self, node: &mut Point) {
+        node.x *= self.0;
+        node.y *= self.0;
+    }
+}
+
+#[test]
+fn visit_mut() {
+    let mut drawing = drawing();
+    Scale(2.0).visit_drawing_mut(&mut drawing);
+    match &drawing.highlighted {
+        Some(Shape::Point(p)) => assert_eq!((p.x, p.y), (6.0, 6.0)),
+        _ => unreachable!(),
+    }
+    match &drawing.items[0] {
+        NamedAny::Polyline(polyline) => {
+            assert_eq!(polyline.vertices.0[1].x, 2.0);
+            assert_eq!(polyline.closing.as_ref().unwrap().y, 2.0);
+        }
+        _ => unreachable!(),
+    }
+}